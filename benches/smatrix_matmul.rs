@@ -0,0 +1,158 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use topohedral_linalg::{current_backend, Backend, MatMul, SMatrix, Transpose};
+
+#[cfg(system_backend)]
+use topohedral_linalg::SystemBackend as DefaultBackend;
+
+#[cfg(not(system_backend))]
+use topohedral_linalg::ReferenceBackend as DefaultBackend;
+
+use nalgebra::SMatrix as NASMatrix;
+
+use rand::prelude::*;
+
+//{{{ collection: SMatrix matmul benches
+// Small products are dominated by the per-call cost of reaching the backend. The `matmul` bench
+// goes through the wrappers, `direct` calls the default backend with no lookup at all and
+// `locked` pays for the `current_backend()` lock and reference count on every call, so `matmul`
+// should track `direct` rather than `locked`.
+macro_rules! matmul_benches_smatrix {
+    ($dim: expr, $name1: ident, $name2: ident, $name3: ident, $name4: ident) => {
+        pub fn $name1(crit: &mut Criterion) {
+            let a = SMatrix::<f64, $dim, $dim>::from_uniform_random(0.0, 10.0);
+            let b = SMatrix::<f64, $dim, $dim>::from_uniform_random(0.0, 10.0);
+
+            crit.bench_function(
+                format!("topohedral-linalg_smatrix_matmul{}", $dim).as_str(),
+                |be| {
+                    be.iter(|| {
+                        let c: SMatrix<f64, $dim, $dim> = (&a).matmul(&b);
+
+                        std::hint::black_box(c);
+                    })
+                },
+            );
+        }
+
+        pub fn $name2(crit: &mut Criterion) {
+            let (a, b) = random_arrays::<{ $dim * $dim }>();
+
+            crit.bench_function(format!("direct_backend_matmul{}", $dim).as_str(), |be| {
+                be.iter(|| {
+                    let mut c = [0.0f64; $dim * $dim];
+                    DefaultBackend.dgemm(
+                        Transpose::NoTrans,
+                        Transpose::NoTrans,
+                        $dim,
+                        $dim,
+                        $dim,
+                        1.0,
+                        &a,
+                        $dim,
+                        &b,
+                        $dim,
+                        0.0,
+                        &mut c,
+                        $dim,
+                    );
+
+                    std::hint::black_box(c);
+                })
+            });
+        }
+
+        pub fn $name3(crit: &mut Criterion) {
+            let (a, b) = random_arrays::<{ $dim * $dim }>();
+
+            crit.bench_function(format!("locked_backend_matmul{}", $dim).as_str(), |be| {
+                be.iter(|| {
+                    let mut c = [0.0f64; $dim * $dim];
+                    current_backend().dgemm(
+                        Transpose::NoTrans,
+                        Transpose::NoTrans,
+                        $dim,
+                        $dim,
+                        $dim,
+                        1.0,
+                        &a,
+                        $dim,
+                        &b,
+                        $dim,
+                        0.0,
+                        &mut c,
+                        $dim,
+                    );
+
+                    std::hint::black_box(c);
+                })
+            });
+        }
+
+        pub fn $name4(crit: &mut Criterion) {
+            let range = rand::distr::Uniform::<f64>::new(0.0, 10.0).unwrap();
+
+            let mut rng = rand::rng();
+
+            let a = NASMatrix::<f64, $dim, $dim>::from_distribution(&range, &mut rng);
+
+            let b = NASMatrix::<f64, $dim, $dim>::from_distribution(&range, &mut rng);
+
+            crit.bench_function(format!("nalgebra_smatrix_matmul{}", $dim).as_str(), |be| {
+                be.iter(|| {
+                    let c = a * b;
+
+                    std::hint::black_box(c);
+                })
+            });
+        }
+    };
+}
+
+fn random_arrays<const L: usize>() -> ([f64; L], [f64; L]) {
+    let range = rand::distr::Uniform::<f64>::new(0.0, 10.0).unwrap();
+
+    let mut rng = rand::rng();
+
+    let mut a = [0.0f64; L];
+
+    let mut b = [0.0f64; L];
+
+    for ii in 0..L {
+        a[ii] = range.sample(&mut rng);
+
+        b[ii] = range.sample(&mut rng);
+    }
+    (a, b)
+}
+
+matmul_benches_smatrix!(
+    3,
+    topohedral_linalg_smatrix_matmul_3,
+    direct_backend_matmul_3,
+    locked_backend_matmul_3,
+    nalgebra_smatrix_matmul_3
+);
+
+matmul_benches_smatrix!(
+    4,
+    topohedral_linalg_smatrix_matmul_4,
+    direct_backend_matmul_4,
+    locked_backend_matmul_4,
+    nalgebra_smatrix_matmul_4
+);
+//}}}
+
+criterion_group!(
+    benches_smatrix_matmul,
+    topohedral_linalg_smatrix_matmul_3,
+    direct_backend_matmul_3,
+    locked_backend_matmul_3,
+    nalgebra_smatrix_matmul_3,
+    topohedral_linalg_smatrix_matmul_4,
+    direct_backend_matmul_4,
+    locked_backend_matmul_4,
+    nalgebra_smatrix_matmul_4,
+);
+
+criterion_main!(benches_smatrix_matmul);
@@ -1,14 +1,27 @@
 use std::env;
+use std::path::Path;
 
 fn main() {
-    if cfg!(target_os = "macos") {
+    // The system backend needs the `system` feature, and `pure_rust` overrides it so that a
+    // dependent crate can opt out even if another one enables the default features
+    let system = env::var_os("CARGO_FEATURE_SYSTEM").is_some()
+        && env::var_os("CARGO_FEATURE_PURE_RUST").is_none();
+    println!("cargo:rustc-check-cfg=cfg(system_backend)");
+
+    if !system {
+        // Nothing to link
+    } else if cfg!(target_os = "macos") {
         // Link against the Accelerate framework on macOS
         println!("cargo:rustc-link-lib=framework=Accelerate");
     } else if cfg!(target_os = "linux") {
         // Link against OpenBLAS on Linux
-        println!("cargo:rustc-link-search=native=/usr/lib/x86_64-linux-gnu");
+        let lib_dir = Path::new("/usr/lib/x86_64-linux-gnu");
+        println!("cargo:rustc-link-search=native={}", lib_dir.display());
         println!("cargo:rustc-link-lib=dylib=openblas");
     }
+    if system {
+        println!("cargo:rustc-cfg=system_backend");
+    }
 
     // Print a message for debugging purposes
     let target = env::var("TARGET").unwrap();
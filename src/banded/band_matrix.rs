@@ -0,0 +1,392 @@
+//! General band matrix in LAPACK band storage.
+//!
+//! Provides [`BandMatrix<T>`], which stores an m×n matrix with `kl` sub-diagonals and `ku`
+//! super-diagonals in a (kl + ku + 1)×n column-major array, with `A[i, j]` held at row
+//! `ku + i - j` of column `j`. Entries of the array that fall outside the matrix are never read.
+//! Matrix–vector products go through BLAS `?gbmv`, general solves through LAPACK `?gbsv` and
+//! symmetric positive-definite solves through LAPACK `?pbsv`.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use crate::blaslapack::{
+    band_solve_raw, band_spd_solve_raw, BandSolveRawError, BandSpdSolveRawError, Gbmv, Gbsv, Pbsv,
+};
+use crate::common::{One, Transpose, Zero};
+use crate::dmatrix::DMatrix;
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors that can occur when solving a linear system with a [`BandMatrix`].
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("Error in BandMatrix::solve(), matrix is singular")]
+    /// A pivot of the LU factorisation is exactly zero.
+    Singular,
+    #[error("Error in BandMatrix::solve_spd(), matrix is not positive definite")]
+    /// A leading minor of the matrix is not positive.
+    NotPositiveDefinite,
+    #[error("Error in BandMatrix::solve(), exited with error:\n{0}")]
+    /// LAPACK `gbsv` reported a failure.
+    GbsvError(BandSolveRawError),
+    #[error("Error in BandMatrix::solve_spd(), exited with error:\n{0}")]
+    /// LAPACK `pbsv` reported a failure.
+    PbsvError(BandSpdSolveRawError),
+}
+
+impl From<BandSolveRawError> for Error {
+    fn from(err: BandSolveRawError) -> Self {
+        match err {
+            BandSolveRawError::Singular(_) => Error::Singular,
+            err => Error::GbsvError(err),
+        }
+    }
+}
+
+impl From<BandSpdSolveRawError> for Error {
+    fn from(err: BandSpdSolveRawError) -> Self {
+        match err {
+            BandSpdSolveRawError::NotPositiveDefinite(_) => Error::NotPositiveDefinite,
+            err => Error::PbsvError(err),
+        }
+    }
+}
+//}}}
+//{{{ struct: BandMatrix
+/// An m×n matrix with `kl` sub-diagonals and `ku` super-diagonals, stored in LAPACK band layout.
+///
+/// Column `j` of the matrix occupies column `j` of a (kl + ku + 1)×n column-major array, with
+/// `A[i, j]` at row `ku + i - j`. So, for example, the 4×4 matrix with `kl = 1` and `ku = 2`:
+/// ```ignore
+/// a00 a01 a02  0
+/// a10 a11 a12 a13
+///  0  a21 a22 a23
+///  0   0  a32 a33
+/// ```
+/// is stored as the array:
+/// ```ignore
+///  *   *  a02 a13
+///  *  a01 a12 a23
+/// a00 a11 a22 a33
+/// a10 a21 a32  *
+/// ```
+/// where `*` marks entries that are never read.
+#[derive(Debug, Clone)]
+pub struct BandMatrix<T>
+where
+    T: Copy,
+{
+    /// The band array, stored in column-major order with leading dimension `kl + ku + 1`.
+    pub(crate) data: Vec<T>,
+    /// Number of rows in the matrix.
+    pub(crate) nrows: usize,
+    /// Number of columns in the matrix.
+    pub(crate) ncols: usize,
+    /// Number of sub-diagonals.
+    pub(crate) kl: usize,
+    /// Number of super-diagonals.
+    pub(crate) ku: usize,
+}
+//}}}
+//{{{ impl: BandMatrix<T>
+impl<T> BandMatrix<T>
+where
+    T: Copy,
+{
+    //{{{ fun: zeros
+    /// Creates an `nrows`×`ncols` band matrix with `kl` sub-diagonals and `ku` super-diagonals,
+    /// initialized with zeros.
+    pub fn zeros(
+        nrows: usize,
+        ncols: usize,
+        kl: usize,
+        ku: usize,
+    ) -> Self
+    where
+        T: Zero,
+    {
+        Self {
+            data: vec![T::zero(); (kl + ku + 1) * ncols],
+            nrows,
+            ncols,
+            kl,
+            ku,
+        }
+    }
+    //}}}
+    //{{{ fun: from_dmatrix
+    /// Creates a band matrix from the band of `a` with `kl` sub-diagonals and `ku`
+    /// super-diagonals. Entries of `a` outside the band are discarded.
+    pub fn from_dmatrix(
+        a: &DMatrix<T>,
+        kl: usize,
+        ku: usize,
+    ) -> Self
+    where
+        T: Zero,
+    {
+        let mut band = Self::zeros(a.nrows, a.ncols, kl, ku);
+        for j in 0..a.ncols {
+            for i in band.band_rows(j) {
+                let idx = band.index(i, j);
+                band.data[idx] = a.data[i + j * a.nrows];
+            }
+        }
+        band
+    }
+    //}}}
+    //{{{ fun: to_dmatrix
+    /// Expands the band matrix into a dense [`DMatrix`].
+    pub fn to_dmatrix(&self) -> DMatrix<T>
+    where
+        T: Zero,
+    {
+        let mut a = DMatrix::zeros(self.nrows, self.ncols);
+        for j in 0..self.ncols {
+            for i in self.band_rows(j) {
+                a.data[i + j * self.nrows] = self.data[self.index(i, j)];
+            }
+        }
+        a
+    }
+    //}}}
+    //{{{ fun: nrows
+    /// Returns the number of rows.
+    pub fn nrows(&self) -> usize {
+        self.nrows
+    }
+    //}}}
+    //{{{ fun: ncols
+    /// Returns the number of columns.
+    pub fn ncols(&self) -> usize {
+        self.ncols
+    }
+    //}}}
+    //{{{ fun: kl
+    /// Returns the number of sub-diagonals.
+    pub fn kl(&self) -> usize {
+        self.kl
+    }
+    //}}}
+    //{{{ fun: ku
+    /// Returns the number of super-diagonals.
+    pub fn ku(&self) -> usize {
+        self.ku
+    }
+    //}}}
+    //{{{ fun: band_data
+    /// Returns the band array in column-major order, with leading dimension `kl + ku + 1`.
+    pub fn band_data(&self) -> &[T] {
+        &self.data
+    }
+    //}}}
+    //{{{ fun: get
+    /// Returns `A[i, j]`, which is zero outside the band.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `(i, j)` is out of bounds.
+    pub fn get(
+        &self,
+        i: usize,
+        j: usize,
+    ) -> T
+    where
+        T: Zero,
+    {
+        assert!(i < self.nrows && j < self.ncols, "Index out of bounds");
+        if self.in_band(i, j) {
+            self.data[self.index(i, j)]
+        } else {
+            T::zero()
+        }
+    }
+    //}}}
+    //{{{ fun: set
+    /// Sets `A[i, j]` to `value`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `(i, j)` is out of bounds or lies outside the band.
+    pub fn set(
+        &mut self,
+        i: usize,
+        j: usize,
+        value: T,
+    ) {
+        assert!(i < self.nrows && j < self.ncols, "Index out of bounds");
+        assert!(self.in_band(i, j), "Index ({i}, {j}) lies outside the band");
+        let idx = self.index(i, j);
+        self.data[idx] = value;
+    }
+    //}}}
+    //{{{ fun: in_band
+    fn in_band(
+        &self,
+        i: usize,
+        j: usize,
+    ) -> bool {
+        i + self.ku >= j && i <= j + self.kl
+    }
+    //}}}
+    //{{{ fun: index
+    fn index(
+        &self,
+        i: usize,
+        j: usize,
+    ) -> usize {
+        self.ku + i - j + j * (self.kl + self.ku + 1)
+    }
+    //}}}
+    //{{{ fun: band_rows
+    fn band_rows(
+        &self,
+        j: usize,
+    ) -> std::ops::Range<usize> {
+        j.saturating_sub(self.ku)..(j + self.kl + 1).min(self.nrows)
+    }
+    //}}}
+}
+//}}}
+//{{{ impl: BandMatrix<T> BLAS/LAPACK
+#[allow(private_bounds)]
+impl<T> BandMatrix<T>
+where
+    T: Zero + One + Copy,
+{
+    //{{{ fun: matvec
+    /// Computes the matrix-vector product `A x` with BLAS `?gbmv`, returning a column vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x` does not have as many elements as the matrix has columns.
+    pub fn matvec(
+        &self,
+        x: &DMatrix<T>,
+    ) -> DMatrix<T>
+    where
+        T: Gbmv,
+    {
+        assert_eq!(
+            x.data.len(),
+            self.ncols,
+            "Vector must have as many elements as the matrix has columns"
+        );
+        let mut y = DMatrix::zeros(self.nrows, 1);
+        T::gbmv(
+            Transpose::NoTrans,
+            self.nrows as i32,
+            self.ncols as i32,
+            self.kl as i32,
+            self.ku as i32,
+            T::one(),
+            &self.data,
+            (self.kl + self.ku + 1) as i32,
+            &x.data,
+            1,
+            T::zero(),
+            &mut y.data,
+            1,
+        );
+        y
+    }
+    //}}}
+    //{{{ fun: solve
+    /// Solves `A X = B` for `X` by banded LU factorisation with partial pivoting (LAPACK `?gbsv`).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Singular`] if the matrix is exactly singular, or [`Error::GbsvError`] if
+    /// the LAPACK routine fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square or `b` does not have as many rows as the matrix.
+    pub fn solve(
+        &self,
+        b: &DMatrix<T>,
+    ) -> Result<DMatrix<T>, Error>
+    where
+        T: Gbsv,
+    {
+        self.check_system(b);
+        let x = band_solve_raw(
+            &self.data,
+            self.nrows,
+            self.kl,
+            self.ku,
+            b.data.clone(),
+            b.ncols,
+        )?;
+        Ok(DMatrix {
+            data: x,
+            nrows: b.nrows,
+            ncols: b.ncols,
+        })
+    }
+    //}}}
+    //{{{ fun: solve_spd
+    /// Solves `A X = B` for `X` when `A` is symmetric (complex: Hermitian) positive definite, by
+    /// banded Cholesky factorisation (LAPACK `?pbsv`).
+    ///
+    /// Only the diagonal and the sub-diagonals are read; the super-diagonals are assumed to
+    /// mirror them.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NotPositiveDefinite`] if the matrix is not positive definite, or
+    /// [`Error::PbsvError`] if the LAPACK routine fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square, if `kl != ku`, or if `b` does not have as many rows as
+    /// the matrix.
+    pub fn solve_spd(
+        &self,
+        b: &DMatrix<T>,
+    ) -> Result<DMatrix<T>, Error>
+    where
+        T: Pbsv,
+    {
+        self.check_system(b);
+        assert_eq!(
+            self.kl, self.ku,
+            "Band matrix must have equal lower and upper bandwidths for solve_spd"
+        );
+        let x = band_spd_solve_raw(
+            &self.data,
+            self.nrows,
+            self.kl,
+            self.ku,
+            b.data.clone(),
+            b.ncols,
+        )?;
+        Ok(DMatrix {
+            data: x,
+            nrows: b.nrows,
+            ncols: b.ncols,
+        })
+    }
+    //}}}
+    //{{{ fun: check_system
+    fn check_system(
+        &self,
+        b: &DMatrix<T>,
+    ) {
+        assert_eq!(
+            self.nrows, self.ncols,
+            "Matrix must be square for band solve"
+        );
+        assert_eq!(
+            b.nrows, self.nrows,
+            "Right-hand side must have as many rows as the matrix"
+        );
+    }
+    //}}}
+}
+//}}}
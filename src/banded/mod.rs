@@ -0,0 +1,23 @@
+//! Banded and tridiagonal matrix types backed by LAPACK band storage.
+//!
+//! Defines [`BandMatrix<T>`], an m×n matrix with `kl` sub-diagonals and `ku` super-diagonals held
+//! in the compact column-major band layout that BLAS and LAPACK expect, and
+//! [`TridiagonalMatrix<T>`], a square matrix held as its three diagonals. Both convert to and from
+//! [`DMatrix`](crate::DMatrix), multiply vectors with `?gbmv`, and solve linear systems in O(n)
+//! work per right-hand side for fixed bandwidth: general systems by LU with partial pivoting
+//! (`?gbsv`, `?gtsv`) and symmetric positive-definite ones by Cholesky or LDLᵀ (`?pbsv`, `?ptsv`).
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+mod band_matrix;
+mod tridiagonal_matrix;
+
+pub use band_matrix::{BandMatrix, Error as BandMatrixError};
+pub use tridiagonal_matrix::{Error as TridiagonalMatrixError, TridiagonalMatrix};
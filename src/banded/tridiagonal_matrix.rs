@@ -0,0 +1,283 @@
+//! Square tridiagonal matrix stored as its three diagonals.
+//!
+//! Provides [`TridiagonalMatrix<T>`], an n×n matrix held as its sub-diagonal `dl`, diagonal `d` and
+//! super-diagonal `du`, which is the layout taken by LAPACK `?gtsv`. General solves go through
+//! `?gtsv`, symmetric positive-definite solves through `?ptsv`, and matrix–vector products through
+//! BLAS `?gbmv` on the equivalent [`BandMatrix`] with one sub- and one super-diagonal.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::band_matrix::BandMatrix;
+use crate::blaslapack::{
+    tridiagonal_solve_raw, tridiagonal_spd_solve_raw, Gbmv, Gtsv, Ptsv, TridiagonalSolveRawError,
+    TridiagonalSpdSolveRawError,
+};
+use crate::common::{One, Zero};
+use crate::dmatrix::DMatrix;
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors that can occur when solving a linear system with a [`TridiagonalMatrix`].
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("Error in TridiagonalMatrix::solve(), matrix is singular")]
+    /// A pivot of the LU factorisation is exactly zero.
+    Singular,
+    #[error("Error in TridiagonalMatrix::solve_spd(), matrix is not positive definite")]
+    /// A pivot of the LDLᵀ factorisation is not positive.
+    NotPositiveDefinite,
+    #[error("Error in TridiagonalMatrix::solve(), exited with error:\n{0}")]
+    /// LAPACK `gtsv` reported a failure.
+    GtsvError(TridiagonalSolveRawError),
+    #[error("Error in TridiagonalMatrix::solve_spd(), exited with error:\n{0}")]
+    /// LAPACK `ptsv` reported a failure.
+    PtsvError(TridiagonalSpdSolveRawError),
+}
+
+impl From<TridiagonalSolveRawError> for Error {
+    fn from(err: TridiagonalSolveRawError) -> Self {
+        match err {
+            TridiagonalSolveRawError::Singular(_) => Error::Singular,
+            err => Error::GtsvError(err),
+        }
+    }
+}
+
+impl From<TridiagonalSpdSolveRawError> for Error {
+    fn from(err: TridiagonalSpdSolveRawError) -> Self {
+        match err {
+            TridiagonalSpdSolveRawError::NotPositiveDefinite(_) => Error::NotPositiveDefinite,
+            err => Error::PtsvError(err),
+        }
+    }
+}
+//}}}
+//{{{ struct: TridiagonalMatrix
+/// An n×n tridiagonal matrix stored as its sub-diagonal, diagonal and super-diagonal.
+///
+/// `A[i + 1, i] = dl[i]`, `A[i, i] = d[i]` and `A[i, i + 1] = du[i]`, so `dl` and `du` have
+/// `n - 1` elements each.
+#[derive(Debug, Clone)]
+pub struct TridiagonalMatrix<T>
+where
+    T: Copy,
+{
+    /// The sub-diagonal, of length `n - 1`.
+    pub(crate) dl: Vec<T>,
+    /// The diagonal, of length `n`.
+    pub(crate) d: Vec<T>,
+    /// The super-diagonal, of length `n - 1`.
+    pub(crate) du: Vec<T>,
+}
+//}}}
+//{{{ impl: TridiagonalMatrix<T>
+impl<T> TridiagonalMatrix<T>
+where
+    T: Copy,
+{
+    //{{{ fun: new
+    /// Creates a tridiagonal matrix from its sub-diagonal, diagonal and super-diagonal.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dl` or `du` does not have exactly one element fewer than `d`.
+    pub fn new(
+        dl: Vec<T>,
+        d: Vec<T>,
+        du: Vec<T>,
+    ) -> Self {
+        let n_off = d.len().saturating_sub(1);
+        assert_eq!(dl.len(), n_off, "Sub-diagonal must have n - 1 elements");
+        assert_eq!(du.len(), n_off, "Super-diagonal must have n - 1 elements");
+        Self { dl, d, du }
+    }
+    //}}}
+    //{{{ fun: from_dmatrix
+    /// Creates a tridiagonal matrix from the three central diagonals of `a`. Entries of `a`
+    /// outside them are discarded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` is not square.
+    pub fn from_dmatrix(a: &DMatrix<T>) -> Self {
+        assert_eq!(
+            a.nrows, a.ncols,
+            "Matrix must be square for TridiagonalMatrix::from_dmatrix"
+        );
+        let n = a.nrows;
+        let n_off = n.saturating_sub(1);
+        Self {
+            dl: (0..n_off).map(|i| a.data[(i + 1) + i * n]).collect(),
+            d: (0..n).map(|i| a.data[i + i * n]).collect(),
+            du: (0..n_off).map(|i| a.data[i + (i + 1) * n]).collect(),
+        }
+    }
+    //}}}
+    //{{{ fun: to_dmatrix
+    /// Expands the tridiagonal matrix into a dense [`DMatrix`].
+    pub fn to_dmatrix(&self) -> DMatrix<T>
+    where
+        T: Zero,
+    {
+        let n = self.size();
+        let mut a = DMatrix::zeros(n, n);
+        for i in 0..n {
+            a.data[i + i * n] = self.d[i];
+        }
+        for i in 0..n.saturating_sub(1) {
+            a.data[(i + 1) + i * n] = self.dl[i];
+            a.data[i + (i + 1) * n] = self.du[i];
+        }
+        a
+    }
+    //}}}
+    //{{{ fun: to_band
+    /// Converts the tridiagonal matrix into a [`BandMatrix`] with `kl = ku = 1`.
+    pub fn to_band(&self) -> BandMatrix<T>
+    where
+        T: Zero,
+    {
+        let n = self.size();
+        let mut band = BandMatrix::zeros(n, n, 1, 1);
+        for i in 0..n {
+            band.data[1 + 3 * i] = self.d[i];
+        }
+        for i in 0..n.saturating_sub(1) {
+            band.data[2 + 3 * i] = self.dl[i];
+            band.data[3 * (i + 1)] = self.du[i];
+        }
+        band
+    }
+    //}}}
+    //{{{ fun: size
+    /// Returns the number of rows, which equals the number of columns.
+    pub fn size(&self) -> usize {
+        self.d.len()
+    }
+    //}}}
+    //{{{ fun: dl
+    /// Returns the sub-diagonal.
+    pub fn dl(&self) -> &[T] {
+        &self.dl
+    }
+    //}}}
+    //{{{ fun: d
+    /// Returns the diagonal.
+    pub fn d(&self) -> &[T] {
+        &self.d
+    }
+    //}}}
+    //{{{ fun: du
+    /// Returns the super-diagonal.
+    pub fn du(&self) -> &[T] {
+        &self.du
+    }
+    //}}}
+}
+//}}}
+//{{{ impl: TridiagonalMatrix<T> BLAS/LAPACK
+#[allow(private_bounds)]
+impl<T> TridiagonalMatrix<T>
+where
+    T: Zero + One + Copy,
+{
+    //{{{ fun: matvec
+    /// Computes the matrix-vector product `A x` with BLAS `?gbmv`, returning a column vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x` does not have as many elements as the matrix has columns.
+    pub fn matvec(
+        &self,
+        x: &DMatrix<T>,
+    ) -> DMatrix<T>
+    where
+        T: Gbmv,
+    {
+        self.to_band().matvec(x)
+    }
+    //}}}
+    //{{{ fun: solve
+    /// Solves `A X = B` for `X` by Gaussian elimination with partial pivoting (LAPACK `?gtsv`).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Singular`] if the matrix is exactly singular, or [`Error::GtsvError`] if
+    /// the LAPACK routine fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `b` does not have as many rows as the matrix.
+    pub fn solve(
+        &self,
+        b: &DMatrix<T>,
+    ) -> Result<DMatrix<T>, Error>
+    where
+        T: Gtsv,
+    {
+        self.check_rhs(b);
+        let x = tridiagonal_solve_raw(
+            self.dl.clone(),
+            self.d.clone(),
+            self.du.clone(),
+            b.data.clone(),
+            b.ncols,
+        )?;
+        Ok(DMatrix {
+            data: x,
+            nrows: b.nrows,
+            ncols: b.ncols,
+        })
+    }
+    //}}}
+    //{{{ fun: solve_spd
+    /// Solves `A X = B` for `X` when `A` is symmetric (complex: Hermitian) positive definite, by
+    /// LDLᵀ factorisation (LAPACK `?ptsv`).
+    ///
+    /// Only the real part of the diagonal and the sub-diagonal are read; the super-diagonal is
+    /// assumed to mirror the sub-diagonal.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NotPositiveDefinite`] if the matrix is not positive definite, or
+    /// [`Error::PtsvError`] if the LAPACK routine fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `b` does not have as many rows as the matrix.
+    pub fn solve_spd(
+        &self,
+        b: &DMatrix<T>,
+    ) -> Result<DMatrix<T>, Error>
+    where
+        T: Ptsv,
+    {
+        self.check_rhs(b);
+        let x = tridiagonal_spd_solve_raw(&self.d, self.dl.clone(), b.data.clone(), b.ncols)?;
+        Ok(DMatrix {
+            data: x,
+            nrows: b.nrows,
+            ncols: b.ncols,
+        })
+    }
+    //}}}
+    //{{{ fun: check_rhs
+    fn check_rhs(
+        &self,
+        b: &DMatrix<T>,
+    ) {
+        assert_eq!(
+            b.nrows,
+            self.size(),
+            "Right-hand side must have as many rows as the matrix"
+        );
+    }
+    //}}}
+}
+//}}}
@@ -0,0 +1,1138 @@
+//! Selectable implementations of the BLAS and LAPACK routines.
+//!
+//! Defines the [`Backend`] trait, through which every BLAS/LAPACK wrapper of the crate reaches its
+//! routine, so that the library behind them can be swapped without forking the crate: MKL, BLIS, a
+//! reference implementation or an instrumented mock. Methods are named after the routine they stand
+//! for (`dgemm`, `zheev`, ...) and follow its calling convention: column-major storage with
+//! explicit leading dimensions, an `info` return code, and a workspace query when `lwork` is −1.
+//! Every method is required, so an implementation states explicitly where each routine comes from;
+//! one that provides only a few can forward the rest to [`ReferenceBackend`].
+//!
+//! Two implementations ship with the crate: [`SystemBackend`], which calls the system BLAS/LAPACK
+//! library and is the default, and [`ReferenceBackend`], which runs the pure-Rust routines of the
+//! `native` module and is the default when the `pure_rust` feature is enabled or the default
+//! `system` feature is disabled, in which case [`SystemBackend`] does not exist. The active backend
+//! is process-wide and replaced with [`set`]; [`with`] overrides it for the current thread for the
+//! duration of a closure, which is how tests diff one backend against another.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::native;
+use crate::common::{Complex, Diagonal, Side, Transpose, Triangle};
+//}}}
+//{{{ std imports
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, OnceLock, PoisonError, RwLock};
+//}}}
+//{{{ dep imports
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+system_only! {
+    mod system;
+
+    pub use system::SystemBackend;
+}
+
+//{{{ fun: query
+/// Answers a workspace query (`lwork = -1`) for the native routines, which need no workspace
+/// beyond the single entry reporting its size. Returns `true` if the call was a query.
+fn query<T>(
+    work: &mut [T],
+    lwork: i32,
+    n: i32,
+) -> bool
+where
+    T: native::Scalar,
+{
+    if lwork == -1 {
+        work[0] = native::workspace(n.max(0) as usize);
+        return true;
+    }
+    false
+}
+//}}}
+// Each `reference_*` macro below declares a group of `Backend` methods when invoked with
+// `declare`, and otherwise implements them on the native routines for `ReferenceBackend`.
+//{{{ macro: reference_gemm
+macro_rules! reference_gemm {
+    (declare $($name:ident: $t:ty),*) => {$(
+        #[doc = concat!("`", stringify!($name), "`: computes `C ← α op(A) op(B) + β C`.")]
+        fn $name(&self, transa: Transpose, transb: Transpose, m: i32, n: i32, k: i32, alpha: $t,
+                 a: &[$t], lda: i32, b: &[$t], ldb: i32, beta: $t, c: &mut [$t], ldc: i32);
+    )*};
+    ($($name:ident: $t:ty),*) => {$(
+        fn $name(&self, transa: Transpose, transb: Transpose, m: i32, n: i32, k: i32, alpha: $t,
+                 a: &[$t], lda: i32, b: &[$t], ldb: i32, beta: $t, c: &mut [$t], ldc: i32) {
+            native::gemm(transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+        }
+    )*};
+}
+//}}}
+//{{{ macro: reference_gemv
+macro_rules! reference_gemv {
+    (declare $($name:ident: $t:ty),*) => {$(
+        #[doc = concat!("`", stringify!($name), "`: computes `y ← α op(A) x + β y`.")]
+        fn $name(&self, trans: Transpose, m: i32, n: i32, alpha: $t, a: &[$t], lda: i32,
+                 x: &[$t], incx: i32, beta: $t, y: &mut [$t], incy: i32);
+    )*};
+    ($($name:ident: $t:ty),*) => {$(
+        fn $name(&self, trans: Transpose, m: i32, n: i32, alpha: $t, a: &[$t], lda: i32,
+                 x: &[$t], incx: i32, beta: $t, y: &mut [$t], incy: i32) {
+            native::gemv(trans, m, n, alpha, a, lda, x, incx, beta, y, incy)
+        }
+    )*};
+}
+//}}}
+//{{{ macro: reference_getrf
+macro_rules! reference_getrf {
+    (declare $($name:ident: $t:ty),*) => {$(
+        #[doc = concat!("`", stringify!($name), "`: LU factorisation with partial pivoting.")]
+        fn $name(&self, m: i32, n: i32, a: &mut [$t], lda: i32, ipiv: &mut [i32]) -> i32;
+    )*};
+    ($($name:ident: $t:ty),*) => {$(
+        fn $name(&self, m: i32, n: i32, a: &mut [$t], lda: i32, ipiv: &mut [i32]) -> i32 {
+            native::getrf(m, n, a, lda, ipiv)
+        }
+    )*};
+}
+//}}}
+//{{{ macro: reference_gesv
+macro_rules! reference_gesv {
+    (declare $($name:ident: $t:ty),*) => {$(
+        #[doc = concat!("`", stringify!($name), "`: solves `A X = B` through an LU factorisation.")]
+        fn $name(&self, n: i32, nrhs: i32, a: &mut [$t], lda: i32, ipiv: &mut [i32],
+                 b: &mut [$t], ldb: i32) -> i32;
+    )*};
+    ($($name:ident: $t:ty),*) => {$(
+        fn $name(&self, n: i32, nrhs: i32, a: &mut [$t], lda: i32, ipiv: &mut [i32],
+                 b: &mut [$t], ldb: i32) -> i32 {
+            native::gesv(n, nrhs, a, lda, ipiv, b, ldb)
+        }
+    )*};
+}
+//}}}
+//{{{ macro: reference_potrf
+macro_rules! reference_potrf {
+    (declare $($name:ident: $t:ty),*) => {$(
+        #[doc = concat!("`", stringify!($name), "`: Cholesky factorisation of one triangle.")]
+        fn $name(&self, uplo: u8, n: i32, a: &mut [$t], lda: i32) -> i32;
+    )*};
+    ($($name:ident: $t:ty),*) => {$(
+        fn $name(&self, uplo: u8, n: i32, a: &mut [$t], lda: i32) -> i32 {
+            native::potrf(uplo, n, a, lda)
+        }
+    )*};
+}
+//}}}
+//{{{ macro: reference_geqrf
+macro_rules! reference_geqrf {
+    (declare $($name:ident: $t:ty),*) => {$(
+        #[doc = concat!("`", stringify!($name), "`: QR factorisation by Householder reflectors.")]
+        fn $name(&self, m: i32, n: i32, a: &mut [$t], lda: i32, tau: &mut [$t],
+                 work: &mut [$t], lwork: i32) -> i32;
+    )*};
+    ($($name:ident: $t:ty),*) => {$(
+        fn $name(&self, m: i32, n: i32, a: &mut [$t], lda: i32, tau: &mut [$t],
+                 work: &mut [$t], lwork: i32) -> i32 {
+            if !query(work, lwork, n) {
+                native::geqrf(m, n, a, lda, tau);
+            }
+            0
+        }
+    )*};
+}
+//}}}
+//{{{ macro: reference_orgqr
+macro_rules! reference_orgqr {
+    (declare $($name:ident: $t:ty),*) => {$(
+        #[doc = concat!("`", stringify!($name), "`: forms Q from the reflectors left by `?geqrf`.")]
+        fn $name(&self, m: i32, n: i32, k: i32, a: &mut [$t], lda: i32, tau: &[$t],
+                 work: &mut [$t], lwork: i32) -> i32;
+    )*};
+    ($($name:ident: $t:ty),*) => {$(
+        fn $name(&self, m: i32, n: i32, k: i32, a: &mut [$t], lda: i32, tau: &[$t],
+                 work: &mut [$t], lwork: i32) -> i32 {
+            if !query(work, lwork, n) {
+                native::orgqr(m, n, k, a, lda, tau);
+            }
+            0
+        }
+    )*};
+}
+//}}}
+//{{{ macro: reference_syev
+macro_rules! reference_syev {
+    (declare $($name:ident: $t:ty),*) => {$(
+        #[doc = concat!("`", stringify!($name), "`: symmetric eigendecomposition.")]
+        fn $name(&self, jobz: u8, uplo: u8, n: i32, a: &mut [$t], lda: i32, w: &mut [$t],
+                 work: &mut [$t], lwork: i32) -> i32;
+    )*};
+    ($($name:ident: $t:ty),*) => {$(
+        fn $name(&self, jobz: u8, uplo: u8, n: i32, a: &mut [$t], lda: i32, w: &mut [$t],
+                 work: &mut [$t], lwork: i32) -> i32 {
+            if query(work, lwork, n) {
+                return 0;
+            }
+            native::syev(jobz, uplo, n, a, lda, w)
+        }
+    )*};
+}
+//}}}
+//{{{ macro: reference_heev
+macro_rules! reference_heev {
+    (declare $($name:ident: $t:ty),*) => {$(
+        #[doc = concat!("`", stringify!($name), "`: Hermitian eigendecomposition.")]
+        fn $name(&self, jobz: u8, uplo: u8, n: i32, a: &mut [Complex<$t>], lda: i32,
+                 w: &mut [$t], work: &mut [Complex<$t>], lwork: i32, rwork: &mut [$t]) -> i32;
+    )*};
+    ($($name:ident: $t:ty),*) => {$(
+        fn $name(&self, jobz: u8, uplo: u8, n: i32, a: &mut [Complex<$t>], lda: i32,
+                 w: &mut [$t], work: &mut [Complex<$t>], lwork: i32, _rwork: &mut [$t]) -> i32 {
+            if query(work, lwork, n) {
+                return 0;
+            }
+            native::syev(jobz, uplo, n, a, lda, w)
+        }
+    )*};
+}
+//}}}
+//{{{ macro: reference_geev_real
+macro_rules! reference_geev_real {
+    (declare $($name:ident: $t:ty),*) => {$(
+        #[doc = concat!("`", stringify!($name), "`: eigenvalues and eigenvectors, real matrix.")]
+        fn $name(&self, jobvl: u8, jobvr: u8, n: i32, a: &mut [$t], lda: i32, wr: &mut [$t],
+                 wi: &mut [$t], vl: &mut [$t], ldvl: i32, vr: &mut [$t], ldvr: i32,
+                 work: &mut [$t], lwork: i32) -> i32;
+    )*};
+    ($($name:ident: $t:ty),*) => {$(
+        fn $name(&self, jobvl: u8, jobvr: u8, n: i32, a: &mut [$t], lda: i32, wr: &mut [$t],
+                 wi: &mut [$t], vl: &mut [$t], ldvl: i32, vr: &mut [$t], ldvr: i32,
+                 work: &mut [$t], lwork: i32) -> i32 {
+            if query(work, lwork, n) {
+                return 0;
+            }
+            native::geev_real(jobvl, jobvr, n, a, lda, wr, wi, vl, ldvl, vr, ldvr)
+        }
+    )*};
+}
+//}}}
+//{{{ macro: reference_geev_complex
+macro_rules! reference_geev_complex {
+    (declare $($name:ident: $t:ty),*) => {$(
+        #[doc = concat!("`", stringify!($name), "`: eigenvalues and eigenvectors, complex matrix.")]
+        fn $name(&self, jobvl: u8, jobvr: u8, n: i32, a: &mut [Complex<$t>], lda: i32,
+                 w: &mut [Complex<$t>], vl: &mut [Complex<$t>], ldvl: i32,
+                 vr: &mut [Complex<$t>], ldvr: i32, work: &mut [Complex<$t>], lwork: i32,
+                 rwork: &mut [$t]) -> i32;
+    )*};
+    ($($name:ident: $t:ty),*) => {$(
+        fn $name(&self, jobvl: u8, jobvr: u8, n: i32, a: &mut [Complex<$t>], lda: i32,
+                 w: &mut [Complex<$t>], vl: &mut [Complex<$t>], ldvl: i32,
+                 vr: &mut [Complex<$t>], ldvr: i32, work: &mut [Complex<$t>], lwork: i32,
+                 _rwork: &mut [$t]) -> i32 {
+            if query(work, lwork, n) {
+                return 0;
+            }
+            native::geev_complex(jobvl, jobvr, n, a, lda, w, vl, ldvl, vr, ldvr)
+        }
+    )*};
+}
+//}}}
+//{{{ macro: reference_gees
+macro_rules! reference_gees {
+    (declare $($name:ident: $t:ty),*) => {$(
+        #[doc = concat!("`", stringify!($name), "`: real Schur form, unordered.")]
+        fn $name(&self, jobvs: u8, n: i32, a: &mut [$t], lda: i32, wr: &mut [$t], wi: &mut [$t],
+                 vs: &mut [$t], ldvs: i32, work: &mut [$t], lwork: i32) -> i32;
+    )*};
+    ($($name:ident: $t:ty),*) => {$(
+        fn $name(&self, jobvs: u8, n: i32, a: &mut [$t], lda: i32, wr: &mut [$t], wi: &mut [$t],
+                 vs: &mut [$t], ldvs: i32, work: &mut [$t], lwork: i32) -> i32 {
+            if query(work, lwork, n) {
+                return 0;
+            }
+            native::gees(jobvs, n, a, lda, wr, wi, vs, ldvs)
+        }
+    )*};
+}
+//}}}
+//{{{ macro: reference_trsv
+macro_rules! reference_trsv {
+    (declare $($name:ident: $t:ty),*) => {$(
+        #[doc = concat!("`", stringify!($name), "`: computes `x ← op(A)⁻¹ x`, `A` triangular.")]
+        fn $name(&self, uplo: Triangle, trans: Transpose, diag: Diagonal, n: i32, a: &[$t],
+                 lda: i32, x: &mut [$t], incx: i32);
+    )*};
+    ($($name:ident: $t:ty),*) => {$(
+        fn $name(&self, uplo: Triangle, trans: Transpose, diag: Diagonal, n: i32, a: &[$t],
+                 lda: i32, x: &mut [$t], incx: i32) {
+            native::trsv(uplo, trans, diag, n, a, lda, x, incx)
+        }
+    )*};
+}
+//}}}
+//{{{ macro: reference_trsm
+macro_rules! reference_trsm {
+    (declare $($name:ident: $t:ty),*) => {$(
+        #[doc = concat!("`", stringify!($name), "`: computes `α op(A)⁻¹ B` or `α B op(A)⁻¹`.")]
+        fn $name(&self, side: Side, uplo: Triangle, transa: Transpose, diag: Diagonal, m: i32,
+                 n: i32, alpha: $t, a: &[$t], lda: i32, b: &mut [$t], ldb: i32);
+    )*};
+    ($($name:ident: $t:ty),*) => {$(
+        fn $name(&self, side: Side, uplo: Triangle, transa: Transpose, diag: Diagonal, m: i32,
+                 n: i32, alpha: $t, a: &[$t], lda: i32, b: &mut [$t], ldb: i32) {
+            native::trsm(side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb)
+        }
+    )*};
+}
+//}}}
+//{{{ macro: reference_trmm
+macro_rules! reference_trmm {
+    (declare $($name:ident: $t:ty),*) => {$(
+        #[doc = concat!("`", stringify!($name), "`: computes `α op(A) B` or `α B op(A)`.")]
+        fn $name(&self, side: Side, uplo: Triangle, transa: Transpose, diag: Diagonal, m: i32,
+                 n: i32, alpha: $t, a: &[$t], lda: i32, b: &mut [$t], ldb: i32);
+    )*};
+    ($($name:ident: $t:ty),*) => {$(
+        fn $name(&self, side: Side, uplo: Triangle, transa: Transpose, diag: Diagonal, m: i32,
+                 n: i32, alpha: $t, a: &[$t], lda: i32, b: &mut [$t], ldb: i32) {
+            native::trmm(side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb)
+        }
+    )*};
+}
+//}}}
+//{{{ macro: reference_gbmv
+macro_rules! reference_gbmv {
+    (declare $($name:ident: $t:ty),*) => {$(
+        #[doc = concat!("`", stringify!($name), "`: computes `y ← α op(A) x + β y`, `A` banded.")]
+        fn $name(&self, trans: Transpose, m: i32, n: i32, kl: i32, ku: i32, alpha: $t, a: &[$t],
+                 lda: i32, x: &[$t], incx: i32, beta: $t, y: &mut [$t], incy: i32);
+    )*};
+    ($($name:ident: $t:ty),*) => {$(
+        fn $name(&self, trans: Transpose, m: i32, n: i32, kl: i32, ku: i32, alpha: $t, a: &[$t],
+                 lda: i32, x: &[$t], incx: i32, beta: $t, y: &mut [$t], incy: i32) {
+            native::gbmv(trans, m, n, kl, ku, alpha, a, lda, x, incx, beta, y, incy)
+        }
+    )*};
+}
+//}}}
+//{{{ macro: reference_getrs
+macro_rules! reference_getrs {
+    (declare $($name:ident: $t:ty),*) => {$(
+        #[doc = concat!("`", stringify!($name), "`: solves `op(A) X = B` given `?getrf`.")]
+        fn $name(&self, trans: u8, n: i32, nrhs: i32, a: &[$t], lda: i32, ipiv: &[i32],
+                 b: &mut [$t], ldb: i32) -> i32;
+    )*};
+    ($($name:ident: $t:ty),*) => {$(
+        fn $name(&self, trans: u8, n: i32, nrhs: i32, a: &[$t], lda: i32, ipiv: &[i32],
+                 b: &mut [$t], ldb: i32) -> i32 {
+            native::getrs(trans, n, nrhs, a, lda, ipiv, b, ldb)
+        }
+    )*};
+}
+//}}}
+//{{{ macro: reference_getri
+macro_rules! reference_getri {
+    (declare $($name:ident: $t:ty),*) => {$(
+        #[doc = concat!("`", stringify!($name), "`: inverse from the factors of `?getrf`.")]
+        fn $name(&self, n: i32, a: &mut [$t], lda: i32, ipiv: &[i32], work: &mut [$t],
+                 lwork: i32) -> i32;
+    )*};
+    ($($name:ident: $t:ty),*) => {$(
+        fn $name(&self, n: i32, a: &mut [$t], lda: i32, ipiv: &[i32], work: &mut [$t],
+                 lwork: i32) -> i32 {
+            if query(work, lwork, n) {
+                return 0;
+            }
+            native::getri(n, a, lda, ipiv)
+        }
+    )*};
+}
+//}}}
+//{{{ macro: reference_gecon_real
+macro_rules! reference_gecon_real {
+    (declare $($name:ident: $t:ty),*) => {$(
+        #[doc = concat!("`", stringify!($name), "`: reciprocal condition number, real.")]
+        fn $name(&self, norm: u8, n: i32, a: &[$t], lda: i32, anorm: $t, rcond: &mut $t,
+                 work: &mut [$t], iwork: &mut [i32]) -> i32;
+    )*};
+    ($($name:ident: $t:ty),*) => {$(
+        fn $name(&self, norm: u8, n: i32, a: &[$t], lda: i32, anorm: $t, rcond: &mut $t,
+                 _work: &mut [$t], _iwork: &mut [i32]) -> i32 {
+            native::gecon(norm, n, a, lda, anorm, rcond)
+        }
+    )*};
+}
+//}}}
+//{{{ macro: reference_gecon_complex
+macro_rules! reference_gecon_complex {
+    (declare $($name:ident: $t:ty),*) => {$(
+        #[doc = concat!("`", stringify!($name), "`: reciprocal condition number, complex.")]
+        fn $name(&self, norm: u8, n: i32, a: &[Complex<$t>], lda: i32, anorm: $t, rcond: &mut $t,
+                 work: &mut [Complex<$t>], rwork: &mut [$t]) -> i32;
+    )*};
+    ($($name:ident: $t:ty),*) => {$(
+        fn $name(&self, norm: u8, n: i32, a: &[Complex<$t>], lda: i32, anorm: $t, rcond: &mut $t,
+                 _work: &mut [Complex<$t>], _rwork: &mut [$t]) -> i32 {
+            native::gecon(norm, n, a, lda, anorm, rcond)
+        }
+    )*};
+}
+//}}}
+//{{{ macro: reference_gesvx_real
+macro_rules! reference_gesvx_real {
+    (declare $($name:ident: $t:ty),*) => {$(
+        #[doc = concat!("`", stringify!($name), "`: expert linear solve, real matrix. The ")]
+        #[doc = "reciprocal pivot growth factor is returned in `work[0]`."]
+        fn $name(&self, fact: u8, trans: u8, n: i32, nrhs: i32, a: &mut [$t], lda: i32,
+                 af: &mut [$t], ldaf: i32, ipiv: &mut [i32], equed: &mut u8, r: &mut [$t],
+                 c: &mut [$t], b: &mut [$t], ldb: i32, x: &mut [$t], ldx: i32, rcond: &mut $t,
+                 ferr: &mut [$t], berr: &mut [$t], work: &mut [$t], iwork: &mut [i32]) -> i32;
+    )*};
+    ($($name:ident: $t:ty),*) => {$(
+        fn $name(&self, fact: u8, trans: u8, n: i32, nrhs: i32, a: &mut [$t], lda: i32,
+                 af: &mut [$t], ldaf: i32, ipiv: &mut [i32], equed: &mut u8, r: &mut [$t],
+                 c: &mut [$t], b: &mut [$t], ldb: i32, x: &mut [$t], ldx: i32, rcond: &mut $t,
+                 ferr: &mut [$t], berr: &mut [$t], work: &mut [$t], _iwork: &mut [i32]) -> i32 {
+            native::gesvx(fact, trans, n, nrhs, a, lda, af, ldaf, ipiv, equed, r, c, b, ldb, x,
+                          ldx, rcond, ferr, berr, &mut work[0])
+        }
+    )*};
+}
+//}}}
+//{{{ macro: reference_gesvx_complex
+macro_rules! reference_gesvx_complex {
+    (declare $($name:ident: $t:ty),*) => {$(
+        #[doc = concat!("`", stringify!($name), "`: expert linear solve, complex matrix. The ")]
+        #[doc = "reciprocal pivot growth factor is returned in `rwork[0]`."]
+        fn $name(&self, fact: u8, trans: u8, n: i32, nrhs: i32, a: &mut [Complex<$t>], lda: i32,
+                 af: &mut [Complex<$t>], ldaf: i32, ipiv: &mut [i32], equed: &mut u8,
+                 r: &mut [$t], c: &mut [$t], b: &mut [Complex<$t>], ldb: i32,
+                 x: &mut [Complex<$t>], ldx: i32, rcond: &mut $t, ferr: &mut [$t],
+                 berr: &mut [$t], work: &mut [Complex<$t>], rwork: &mut [$t]) -> i32;
+    )*};
+    ($($name:ident: $t:ty),*) => {$(
+        fn $name(&self, fact: u8, trans: u8, n: i32, nrhs: i32, a: &mut [Complex<$t>], lda: i32,
+                 af: &mut [Complex<$t>], ldaf: i32, ipiv: &mut [i32], equed: &mut u8,
+                 r: &mut [$t], c: &mut [$t], b: &mut [Complex<$t>], ldb: i32,
+                 x: &mut [Complex<$t>], ldx: i32, rcond: &mut $t, ferr: &mut [$t],
+                 berr: &mut [$t], _work: &mut [Complex<$t>], rwork: &mut [$t]) -> i32 {
+            native::gesvx(fact, trans, n, nrhs, a, lda, af, ldaf, ipiv, equed, r, c, b, ldb, x,
+                          ldx, rcond, ferr, berr, &mut rwork[0])
+        }
+    )*};
+}
+//}}}
+//{{{ macro: reference_potrs
+macro_rules! reference_potrs {
+    (declare $($name:ident: $t:ty),*) => {$(
+        #[doc = concat!("`", stringify!($name), "`: solves `A X = B` given `?potrf`.")]
+        fn $name(&self, uplo: u8, n: i32, nrhs: i32, a: &[$t], lda: i32, b: &mut [$t],
+                 ldb: i32) -> i32;
+    )*};
+    ($($name:ident: $t:ty),*) => {$(
+        fn $name(&self, uplo: u8, n: i32, nrhs: i32, a: &[$t], lda: i32, b: &mut [$t],
+                 ldb: i32) -> i32 {
+            native::potrs(uplo, n, nrhs, a, lda, b, ldb)
+        }
+    )*};
+}
+//}}}
+//{{{ macro: reference_sytrf
+macro_rules! reference_sytrf {
+    (declare $($name:ident: $t:ty),*) => {$(
+        #[doc = concat!("`", stringify!($name), "`: Bunch–Kaufman `L D Lᴴ` factorisation.")]
+        fn $name(&self, uplo: u8, n: i32, a: &mut [$t], lda: i32, ipiv: &mut [i32],
+                 work: &mut [$t], lwork: i32) -> i32;
+    )*};
+    ($($name:ident: $t:ty),*) => {$(
+        fn $name(&self, uplo: u8, n: i32, a: &mut [$t], lda: i32, ipiv: &mut [i32],
+                 work: &mut [$t], lwork: i32) -> i32 {
+            if query(work, lwork, n) {
+                return 0;
+            }
+            native::sytrf(uplo, n, a, lda, ipiv)
+        }
+    )*};
+}
+//}}}
+//{{{ macro: reference_sytrs
+macro_rules! reference_sytrs {
+    (declare $($name:ident: $t:ty),*) => {$(
+        #[doc = concat!("`", stringify!($name), "`: solves `A X = B` given `?sytrf`.")]
+        fn $name(&self, uplo: u8, n: i32, nrhs: i32, a: &[$t], lda: i32, ipiv: &[i32],
+                 b: &mut [$t], ldb: i32) -> i32;
+    )*};
+    ($($name:ident: $t:ty),*) => {$(
+        fn $name(&self, uplo: u8, n: i32, nrhs: i32, a: &[$t], lda: i32, ipiv: &[i32],
+                 b: &mut [$t], ldb: i32) -> i32 {
+            native::sytrs(uplo, n, nrhs, a, lda, ipiv, b, ldb)
+        }
+    )*};
+}
+//}}}
+//{{{ macro: reference_trtrs
+macro_rules! reference_trtrs {
+    (declare $($name:ident: $t:ty),*) => {$(
+        #[doc = concat!("`", stringify!($name), "`: solves `op(A) X = B` for a triangular `A`.")]
+        fn $name(&self, uplo: u8, trans: u8, diag: u8, n: i32, nrhs: i32, a: &[$t], lda: i32,
+                 b: &mut [$t], ldb: i32) -> i32;
+    )*};
+    ($($name:ident: $t:ty),*) => {$(
+        fn $name(&self, uplo: u8, trans: u8, diag: u8, n: i32, nrhs: i32, a: &[$t], lda: i32,
+                 b: &mut [$t], ldb: i32) -> i32 {
+            native::trtrs(uplo, trans, diag, n, nrhs, a, lda, b, ldb)
+        }
+    )*};
+}
+//}}}
+//{{{ macro: reference_gbsv
+macro_rules! reference_gbsv {
+    (declare $($name:ident: $t:ty),*) => {$(
+        #[doc = concat!("`", stringify!($name), "`: solves `A X = B` for a band `A`.")]
+        fn $name(&self, n: i32, kl: i32, ku: i32, nrhs: i32, ab: &mut [$t], ldab: i32,
+                 ipiv: &mut [i32], b: &mut [$t], ldb: i32) -> i32;
+    )*};
+    ($($name:ident: $t:ty),*) => {$(
+        fn $name(&self, n: i32, kl: i32, ku: i32, nrhs: i32, ab: &mut [$t], ldab: i32,
+                 ipiv: &mut [i32], b: &mut [$t], ldb: i32) -> i32 {
+            native::gbsv(n, kl, ku, nrhs, ab, ldab, ipiv, b, ldb)
+        }
+    )*};
+}
+//}}}
+//{{{ macro: reference_pbsv
+macro_rules! reference_pbsv {
+    (declare $($name:ident: $t:ty),*) => {$(
+        #[doc = concat!("`", stringify!($name), "`: positive definite band solve.")]
+        fn $name(&self, uplo: u8, n: i32, kd: i32, nrhs: i32, ab: &mut [$t], ldab: i32,
+                 b: &mut [$t], ldb: i32) -> i32;
+    )*};
+    ($($name:ident: $t:ty),*) => {$(
+        fn $name(&self, uplo: u8, n: i32, kd: i32, nrhs: i32, ab: &mut [$t], ldab: i32,
+                 b: &mut [$t], ldb: i32) -> i32 {
+            native::pbsv(uplo, n, kd, nrhs, ab, ldab, b, ldb)
+        }
+    )*};
+}
+//}}}
+//{{{ macro: reference_gtsv
+macro_rules! reference_gtsv {
+    (declare $($name:ident: $t:ty),*) => {$(
+        #[doc = concat!("`", stringify!($name), "`: solves `A X = B` for a tridiagonal `A`.")]
+        fn $name(&self, n: i32, nrhs: i32, dl: &mut [$t], d: &mut [$t], du: &mut [$t],
+                 b: &mut [$t], ldb: i32) -> i32;
+    )*};
+    ($($name:ident: $t:ty),*) => {$(
+        fn $name(&self, n: i32, nrhs: i32, dl: &mut [$t], d: &mut [$t], du: &mut [$t],
+                 b: &mut [$t], ldb: i32) -> i32 {
+            native::gtsv(n, nrhs, dl, d, du, b, ldb)
+        }
+    )*};
+}
+//}}}
+//{{{ macro: reference_ptsv
+macro_rules! reference_ptsv {
+    (declare $($name:ident: $t:ty, $r:ty),*) => {$(
+        #[doc = concat!("`", stringify!($name), "`: solves `A X = B` for a positive definite ")]
+        #[doc = "tridiagonal `A`."]
+        fn $name(&self, n: i32, nrhs: i32, d: &mut [$r], e: &mut [$t], b: &mut [$t],
+                 ldb: i32) -> i32;
+    )*};
+    ($($name:ident: $t:ty, $r:ty),*) => {$(
+        fn $name(&self, n: i32, nrhs: i32, d: &mut [$r], e: &mut [$t], b: &mut [$t],
+                 ldb: i32) -> i32 {
+            native::ptsv(n, nrhs, d, e, b, ldb)
+        }
+    )*};
+}
+//}}}
+//{{{ macro: reference_ormqr
+macro_rules! reference_ormqr {
+    (declare $($name:ident: $t:ty),*) => {$(
+        #[doc = concat!("`", stringify!($name), "`: applies Q from `?geqrf` to a matrix.")]
+        fn $name(&self, side: u8, trans: u8, m: i32, n: i32, k: i32, a: &[$t], lda: i32,
+                 tau: &[$t], c: &mut [$t], ldc: i32, work: &mut [$t], lwork: i32) -> i32;
+    )*};
+    ($($name:ident: $t:ty),*) => {$(
+        fn $name(&self, side: u8, trans: u8, m: i32, n: i32, k: i32, a: &[$t], lda: i32,
+                 tau: &[$t], c: &mut [$t], ldc: i32, work: &mut [$t], lwork: i32) -> i32 {
+            if query(work, lwork, n) {
+                return 0;
+            }
+            native::ormqr(side, trans, m, n, k, a, lda, tau, c, ldc)
+        }
+    )*};
+}
+//}}}
+//{{{ macro: reference_geqp3_real
+macro_rules! reference_geqp3_real {
+    (declare $($name:ident: $t:ty),*) => {$(
+        #[doc = concat!("`", stringify!($name), "`: QR factorisation with column pivoting.")]
+        fn $name(&self, m: i32, n: i32, a: &mut [$t], lda: i32, jpvt: &mut [i32],
+                 tau: &mut [$t], work: &mut [$t], lwork: i32) -> i32;
+    )*};
+    ($($name:ident: $t:ty),*) => {$(
+        fn $name(&self, m: i32, n: i32, a: &mut [$t], lda: i32, jpvt: &mut [i32],
+                 tau: &mut [$t], work: &mut [$t], lwork: i32) -> i32 {
+            if !query(work, lwork, n) {
+                native::geqp3(m, n, a, lda, jpvt, tau);
+            }
+            0
+        }
+    )*};
+}
+//}}}
+//{{{ macro: reference_geqp3_complex
+macro_rules! reference_geqp3_complex {
+    (declare $($name:ident: $t:ty),*) => {$(
+        #[doc = concat!("`", stringify!($name), "`: QR factorisation with column pivoting.")]
+        fn $name(&self, m: i32, n: i32, a: &mut [Complex<$t>], lda: i32, jpvt: &mut [i32],
+                 tau: &mut [Complex<$t>], work: &mut [Complex<$t>], lwork: i32,
+                 rwork: &mut [$t]) -> i32;
+    )*};
+    ($($name:ident: $t:ty),*) => {$(
+        fn $name(&self, m: i32, n: i32, a: &mut [Complex<$t>], lda: i32, jpvt: &mut [i32],
+                 tau: &mut [Complex<$t>], work: &mut [Complex<$t>], lwork: i32,
+                 _rwork: &mut [$t]) -> i32 {
+            if !query(work, lwork, n) {
+                native::geqp3(m, n, a, lda, jpvt, tau);
+            }
+            0
+        }
+    )*};
+}
+//}}}
+//{{{ macro: reference_gels
+macro_rules! reference_gels {
+    (declare $($name:ident: $t:ty),*) => {$(
+        #[doc = concat!("`", stringify!($name), "`: least squares or minimum norm solve by QR.")]
+        fn $name(&self, trans: u8, m: i32, n: i32, nrhs: i32, a: &mut [$t], lda: i32,
+                 b: &mut [$t], ldb: i32, work: &mut [$t], lwork: i32) -> i32;
+    )*};
+    ($($name:ident: $t:ty),*) => {$(
+        fn $name(&self, trans: u8, m: i32, n: i32, nrhs: i32, a: &mut [$t], lda: i32,
+                 b: &mut [$t], ldb: i32, work: &mut [$t], lwork: i32) -> i32 {
+            if query(work, lwork, n) {
+                return 0;
+            }
+            native::gels(trans, m, n, nrhs, a, lda, b, ldb)
+        }
+    )*};
+}
+//}}}
+//{{{ macro: reference_gelsd
+macro_rules! reference_gelsd {
+    (declare $($name:ident: $t:ty),*) => {$(
+        #[doc = concat!("`", stringify!($name), "`: minimum norm least squares solve by SVD.")]
+        fn $name(&self, m: i32, n: i32, nrhs: i32, a: &mut [$t], lda: i32, b: &mut [$t],
+                 ldb: i32, s: &mut [$t], rcond: $t, rank: &mut i32, work: &mut [$t],
+                 lwork: i32, iwork: &mut [i32]) -> i32;
+    )*};
+    ($($name:ident: $t:ty),*) => {$(
+        fn $name(&self, m: i32, n: i32, nrhs: i32, a: &mut [$t], lda: i32, b: &mut [$t],
+                 ldb: i32, s: &mut [$t], rcond: $t, rank: &mut i32, work: &mut [$t],
+                 lwork: i32, iwork: &mut [i32]) -> i32 {
+            if query(work, lwork, n) {
+                iwork[0] = 1;
+                return 0;
+            }
+            native::gelsd(m, n, nrhs, a, lda, b, ldb, s, rcond, rank)
+        }
+    )*};
+}
+//}}}
+//{{{ macro: reference_gesdd_real
+macro_rules! reference_gesdd_real {
+    (declare $($name:ident: $t:ty),*) => {$(
+        #[doc = concat!("`", stringify!($name), "`: singular value decomposition, real matrix.")]
+        fn $name(&self, jobz: u8, m: i32, n: i32, a: &mut [$t], lda: i32, s: &mut [$t],
+                 u: &mut [$t], ldu: i32, vt: &mut [$t], ldvt: i32, work: &mut [$t], lwork: i32,
+                 iwork: &mut [i32]) -> i32;
+    )*};
+    ($($name:ident: $t:ty),*) => {$(
+        fn $name(&self, jobz: u8, m: i32, n: i32, a: &mut [$t], lda: i32, s: &mut [$t],
+                 u: &mut [$t], ldu: i32, vt: &mut [$t], ldvt: i32, work: &mut [$t], lwork: i32,
+                 _iwork: &mut [i32]) -> i32 {
+            if query(work, lwork, n) {
+                return 0;
+            }
+            native::gesdd(jobz, m, n, a, lda, s, u, ldu, vt, ldvt)
+        }
+    )*};
+}
+//}}}
+//{{{ macro: reference_gesdd_complex
+macro_rules! reference_gesdd_complex {
+    (declare $($name:ident: $t:ty),*) => {$(
+        #[doc = concat!("`", stringify!($name), "`: singular value decomposition, complex matrix.")]
+        fn $name(&self, jobz: u8, m: i32, n: i32, a: &mut [Complex<$t>], lda: i32, s: &mut [$t],
+                 u: &mut [Complex<$t>], ldu: i32, vt: &mut [Complex<$t>], ldvt: i32,
+                 work: &mut [Complex<$t>], lwork: i32, rwork: &mut [$t],
+                 iwork: &mut [i32]) -> i32;
+    )*};
+    ($($name:ident: $t:ty),*) => {$(
+        fn $name(&self, jobz: u8, m: i32, n: i32, a: &mut [Complex<$t>], lda: i32, s: &mut [$t],
+                 u: &mut [Complex<$t>], ldu: i32, vt: &mut [Complex<$t>], ldvt: i32,
+                 work: &mut [Complex<$t>], lwork: i32, _rwork: &mut [$t],
+                 _iwork: &mut [i32]) -> i32 {
+            if query(work, lwork, n) {
+                return 0;
+            }
+            native::gesdd(jobz, m, n, a, lda, s, u, ldu, vt, ldvt)
+        }
+    )*};
+}
+//}}}
+//{{{ macro: reference_gesvd_real
+macro_rules! reference_gesvd_real {
+    (declare $($name:ident: $t:ty),*) => {$(
+        #[doc = concat!("`", stringify!($name), "`: singular value decomposition, real matrix.")]
+        fn $name(&self, jobu: u8, jobvt: u8, m: i32, n: i32, a: &mut [$t], lda: i32,
+                 s: &mut [$t], u: &mut [$t], ldu: i32, vt: &mut [$t], ldvt: i32,
+                 work: &mut [$t], lwork: i32) -> i32;
+    )*};
+    ($($name:ident: $t:ty),*) => {$(
+        fn $name(&self, jobu: u8, jobvt: u8, m: i32, n: i32, a: &mut [$t], lda: i32,
+                 s: &mut [$t], u: &mut [$t], ldu: i32, vt: &mut [$t], ldvt: i32,
+                 work: &mut [$t], lwork: i32) -> i32 {
+            if query(work, lwork, n) {
+                return 0;
+            }
+            native::gesvd(jobu, jobvt, m, n, a, lda, s, u, ldu, vt, ldvt)
+        }
+    )*};
+}
+//}}}
+//{{{ macro: reference_gesvd_complex
+macro_rules! reference_gesvd_complex {
+    (declare $($name:ident: $t:ty),*) => {$(
+        #[doc = concat!("`", stringify!($name), "`: singular value decomposition, complex matrix.")]
+        fn $name(&self, jobu: u8, jobvt: u8, m: i32, n: i32, a: &mut [Complex<$t>], lda: i32,
+                 s: &mut [$t], u: &mut [Complex<$t>], ldu: i32, vt: &mut [Complex<$t>],
+                 ldvt: i32, work: &mut [Complex<$t>], lwork: i32, rwork: &mut [$t]) -> i32;
+    )*};
+    ($($name:ident: $t:ty),*) => {$(
+        fn $name(&self, jobu: u8, jobvt: u8, m: i32, n: i32, a: &mut [Complex<$t>], lda: i32,
+                 s: &mut [$t], u: &mut [Complex<$t>], ldu: i32, vt: &mut [Complex<$t>],
+                 ldvt: i32, work: &mut [Complex<$t>], lwork: i32, _rwork: &mut [$t]) -> i32 {
+            if query(work, lwork, n) {
+                return 0;
+            }
+            native::gesvd(jobu, jobvt, m, n, a, lda, s, u, ldu, vt, ldvt)
+        }
+    )*};
+}
+//}}}
+//{{{ macro: reference_syevr
+macro_rules! reference_syevr {
+    (declare $($name:ident: $t:ty),*) => {$(
+        #[doc = concat!("`", stringify!($name), "`: selected eigenpairs of a symmetric matrix.")]
+        fn $name(&self, jobz: u8, range: u8, uplo: u8, n: i32, a: &mut [$t], lda: i32, vl: $t,
+                 vu: $t, il: i32, iu: i32, abstol: $t, m: &mut i32, w: &mut [$t], z: &mut [$t],
+                 ldz: i32, isuppz: &mut [i32], work: &mut [$t], lwork: i32, iwork: &mut [i32],
+                 liwork: i32) -> i32;
+    )*};
+    ($($name:ident: $t:ty),*) => {$(
+        fn $name(&self, jobz: u8, range: u8, uplo: u8, n: i32, a: &mut [$t], lda: i32, vl: $t,
+                 vu: $t, il: i32, iu: i32, _abstol: $t, m: &mut i32, w: &mut [$t], z: &mut [$t],
+                 ldz: i32, isuppz: &mut [i32], work: &mut [$t], lwork: i32, iwork: &mut [i32],
+                 liwork: i32) -> i32 {
+            if lwork == -1 || liwork == -1 {
+                query(work, -1, n);
+                iwork[0] = 1;
+                return 0;
+            }
+            native::syevr(jobz, range, uplo, n, a, lda, vl, vu, il, iu, m, w, z, ldz, isuppz)
+        }
+    )*};
+}
+//}}}
+//{{{ macro: reference_heevr
+macro_rules! reference_heevr {
+    (declare $($name:ident: $t:ty),*) => {$(
+        #[doc = concat!("`", stringify!($name), "`: selected eigenpairs of a Hermitian matrix.")]
+        fn $name(&self, jobz: u8, range: u8, uplo: u8, n: i32, a: &mut [Complex<$t>], lda: i32,
+                 vl: $t, vu: $t, il: i32, iu: i32, abstol: $t, m: &mut i32, w: &mut [$t],
+                 z: &mut [Complex<$t>], ldz: i32, isuppz: &mut [i32], work: &mut [Complex<$t>],
+                 lwork: i32, rwork: &mut [$t], lrwork: i32, iwork: &mut [i32],
+                 liwork: i32) -> i32;
+    )*};
+    ($($name:ident: $t:ty),*) => {$(
+        fn $name(&self, jobz: u8, range: u8, uplo: u8, n: i32, a: &mut [Complex<$t>], lda: i32,
+                 vl: $t, vu: $t, il: i32, iu: i32, _abstol: $t, m: &mut i32, w: &mut [$t],
+                 z: &mut [Complex<$t>], ldz: i32, isuppz: &mut [i32], work: &mut [Complex<$t>],
+                 lwork: i32, rwork: &mut [$t], lrwork: i32, iwork: &mut [i32],
+                 liwork: i32) -> i32 {
+            if lwork == -1 || lrwork == -1 || liwork == -1 {
+                query(work, -1, n);
+                rwork[0] = 1.0;
+                iwork[0] = 1;
+                return 0;
+            }
+            native::syevr(jobz, range, uplo, n, a, lda, vl, vu, il, iu, m, w, z, ldz, isuppz)
+        }
+    )*};
+}
+//}}}
+//{{{ macro: reference_sygv
+macro_rules! reference_sygv {
+    (declare $($name:ident: $t:ty),*) => {$(
+        #[doc = concat!("`", stringify!($name), "`: symmetric-definite generalised eigenproblem.")]
+        fn $name(&self, itype: i32, jobz: u8, uplo: u8, n: i32, a: &mut [$t], lda: i32,
+                 b: &mut [$t], ldb: i32, w: &mut [$t], work: &mut [$t], lwork: i32) -> i32;
+    )*};
+    ($($name:ident: $t:ty),*) => {$(
+        fn $name(&self, itype: i32, jobz: u8, uplo: u8, n: i32, a: &mut [$t], lda: i32,
+                 b: &mut [$t], ldb: i32, w: &mut [$t], work: &mut [$t], lwork: i32) -> i32 {
+            if query(work, lwork, n) {
+                return 0;
+            }
+            native::sygv(itype, jobz, uplo, n, a, lda, b, ldb, w)
+        }
+    )*};
+}
+//}}}
+//{{{ macro: reference_hegv
+macro_rules! reference_hegv {
+    (declare $($name:ident: $t:ty),*) => {$(
+        #[doc = concat!("`", stringify!($name), "`: Hermitian-definite generalised eigenproblem.")]
+        fn $name(&self, itype: i32, jobz: u8, uplo: u8, n: i32, a: &mut [Complex<$t>], lda: i32,
+                 b: &mut [Complex<$t>], ldb: i32, w: &mut [$t], work: &mut [Complex<$t>],
+                 lwork: i32, rwork: &mut [$t]) -> i32;
+    )*};
+    ($($name:ident: $t:ty),*) => {$(
+        fn $name(&self, itype: i32, jobz: u8, uplo: u8, n: i32, a: &mut [Complex<$t>], lda: i32,
+                 b: &mut [Complex<$t>], ldb: i32, w: &mut [$t], work: &mut [Complex<$t>],
+                 lwork: i32, _rwork: &mut [$t]) -> i32 {
+            if query(work, lwork, n) {
+                return 0;
+            }
+            native::sygv(itype, jobz, uplo, n, a, lda, b, ldb, w)
+        }
+    )*};
+}
+//}}}
+//{{{ macro: reference_ggev_real
+macro_rules! reference_ggev_real {
+    (declare $($name:ident: $t:ty),*) => {$(
+        #[doc = concat!("`", stringify!($name), "`: generalised eigenproblem, real pencil.")]
+        fn $name(&self, jobvl: u8, jobvr: u8, n: i32, a: &mut [$t], lda: i32, b: &mut [$t],
+                 ldb: i32, alphar: &mut [$t], alphai: &mut [$t], beta: &mut [$t],
+                 vl: &mut [$t], ldvl: i32, vr: &mut [$t], ldvr: i32, work: &mut [$t],
+                 lwork: i32) -> i32;
+    )*};
+    ($($name:ident: $t:ty),*) => {$(
+        fn $name(&self, jobvl: u8, jobvr: u8, n: i32, a: &mut [$t], lda: i32, b: &mut [$t],
+                 ldb: i32, alphar: &mut [$t], alphai: &mut [$t], beta: &mut [$t],
+                 vl: &mut [$t], ldvl: i32, vr: &mut [$t], ldvr: i32, work: &mut [$t],
+                 lwork: i32) -> i32 {
+            if query(work, lwork, n) {
+                return 0;
+            }
+            native::ggev_real(jobvl, jobvr, n, a, lda, b, ldb, alphar, alphai, beta, vl, ldvl,
+                              vr, ldvr)
+        }
+    )*};
+}
+//}}}
+//{{{ macro: reference_ggev_complex
+macro_rules! reference_ggev_complex {
+    (declare $($name:ident: $t:ty),*) => {$(
+        #[doc = concat!("`", stringify!($name), "`: generalised eigenproblem, complex pencil.")]
+        fn $name(&self, jobvl: u8, jobvr: u8, n: i32, a: &mut [Complex<$t>], lda: i32,
+                 b: &mut [Complex<$t>], ldb: i32, alpha: &mut [Complex<$t>],
+                 beta: &mut [Complex<$t>], vl: &mut [Complex<$t>], ldvl: i32,
+                 vr: &mut [Complex<$t>], ldvr: i32, work: &mut [Complex<$t>], lwork: i32,
+                 rwork: &mut [$t]) -> i32;
+    )*};
+    ($($name:ident: $t:ty),*) => {$(
+        fn $name(&self, jobvl: u8, jobvr: u8, n: i32, a: &mut [Complex<$t>], lda: i32,
+                 b: &mut [Complex<$t>], ldb: i32, alpha: &mut [Complex<$t>],
+                 beta: &mut [Complex<$t>], vl: &mut [Complex<$t>], ldvl: i32,
+                 vr: &mut [Complex<$t>], ldvr: i32, work: &mut [Complex<$t>], lwork: i32,
+                 _rwork: &mut [$t]) -> i32 {
+            if query(work, lwork, n) {
+                return 0;
+            }
+            native::ggev_complex(jobvl, jobvr, n, a, lda, b, ldb, alpha, beta, vl, ldvl, vr,
+                                 ldvr)
+        }
+    )*};
+}
+//}}}
+//{{{ macro: reference_gehrd
+macro_rules! reference_gehrd {
+    (declare $($name:ident: $t:ty),*) => {$(
+        #[doc = concat!("`", stringify!($name), "`: reduction to Hessenberg form.")]
+        fn $name(&self, n: i32, ilo: i32, ihi: i32, a: &mut [$t], lda: i32, tau: &mut [$t],
+                 work: &mut [$t], lwork: i32) -> i32;
+    )*};
+    ($($name:ident: $t:ty),*) => {$(
+        fn $name(&self, n: i32, ilo: i32, ihi: i32, a: &mut [$t], lda: i32, tau: &mut [$t],
+                 work: &mut [$t], lwork: i32) -> i32 {
+            if !query(work, lwork, n) {
+                native::gehrd(n, ilo, ihi, a, lda, tau);
+            }
+            0
+        }
+    )*};
+}
+//}}}
+//{{{ macro: reference_orghr
+macro_rules! reference_orghr {
+    (declare $($name:ident: $t:ty),*) => {$(
+        #[doc = concat!("`", stringify!($name), "`: forms Q from the reflectors left by `?gehrd`.")]
+        fn $name(&self, n: i32, ilo: i32, ihi: i32, a: &mut [$t], lda: i32, tau: &[$t],
+                 work: &mut [$t], lwork: i32) -> i32;
+    )*};
+    ($($name:ident: $t:ty),*) => {$(
+        fn $name(&self, n: i32, ilo: i32, ihi: i32, a: &mut [$t], lda: i32, tau: &[$t],
+                 work: &mut [$t], lwork: i32) -> i32 {
+            if !query(work, lwork, n) {
+                native::orghr(n, ilo, ihi, a, lda, tau);
+            }
+            0
+        }
+    )*};
+}
+//}}}
+//{{{ macro: reference_trsen
+macro_rules! reference_trsen {
+    (declare $($name:ident: $t:ty),*) => {$(
+        #[doc = concat!("`", stringify!($name), "`: reorders a real Schur form.")]
+        fn $name(&self, job: u8, compq: u8, select: &[i32], n: i32, t: &mut [$t], ldt: i32,
+                 q: &mut [$t], ldq: i32, wr: &mut [$t], wi: &mut [$t], m: &mut i32,
+                 s: &mut $t, sep: &mut $t, work: &mut [$t], lwork: i32, iwork: &mut [i32],
+                 liwork: i32) -> i32;
+    )*};
+    ($($name:ident: $t:ty),*) => {$(
+        fn $name(&self, job: u8, compq: u8, select: &[i32], n: i32, t: &mut [$t], ldt: i32,
+                 q: &mut [$t], ldq: i32, wr: &mut [$t], wi: &mut [$t], m: &mut i32,
+                 s: &mut $t, sep: &mut $t, work: &mut [$t], lwork: i32, iwork: &mut [i32],
+                 liwork: i32) -> i32 {
+            if lwork == -1 || liwork == -1 {
+                query(work, -1, n);
+                iwork[0] = 1;
+                return 0;
+            }
+            native::trsen(job, compq, select, n, t, ldt, q, ldq, wr, wi, m, s, sep)
+        }
+    )*};
+}
+//}}}
+
+//{{{ trait: Backend
+/// An implementation of the BLAS and LAPACK routines behind the dense matrix operations.
+///
+/// Each method has the name and argument order of its BLAS/LAPACK routine, minus the trailing
+/// `info` argument which is returned instead, and the CBLAS `Layout`, which is always column-major.
+/// All methods are required; [`ReferenceBackend`] implements every one of them and can be delegated
+/// to for routines an implementation does not provide. Workspace queries (`lwork = -1`) must write
+/// the required size to `work[0]` and return `0`. `?gees` takes no eigenvalue selection: the
+/// wrappers always compute an unordered Schur form and reorder it with `?trsen` when asked.
+///
+/// Backends are installed process-wide with [`set_backend`](crate::set_backend) or for a single
+/// thread and closure with [`with_backend`](crate::with_backend).
+#[allow(clippy::too_many_arguments)]
+pub trait Backend: Send + Sync {
+    reference_gemm!(declare sgemm: f32, dgemm: f64, cgemm: Complex<f32>, zgemm: Complex<f64>);
+    reference_gemv!(declare sgemv: f32, dgemv: f64, cgemv: Complex<f32>, zgemv: Complex<f64>);
+    reference_getrf!(declare sgetrf: f32, dgetrf: f64, cgetrf: Complex<f32>, zgetrf: Complex<f64>);
+    reference_gesv!(declare sgesv: f32, dgesv: f64);
+    reference_potrf!(declare spotrf: f32, dpotrf: f64, cpotrf: Complex<f32>, zpotrf: Complex<f64>);
+    reference_geqrf!(declare sgeqrf: f32, dgeqrf: f64, cgeqrf: Complex<f32>, zgeqrf: Complex<f64>);
+    reference_orgqr!(declare sorgqr: f32, dorgqr: f64, cungqr: Complex<f32>, zungqr: Complex<f64>);
+    reference_syev!(declare ssyev: f32, dsyev: f64);
+    reference_heev!(declare cheev: f32, zheev: f64);
+    reference_geev_real!(declare sgeev: f32, dgeev: f64);
+    reference_geev_complex!(declare cgeev: f32, zgeev: f64);
+    reference_gees!(declare sgees: f32, dgees: f64);
+    reference_trsv!(declare strsv: f32, dtrsv: f64, ctrsv: Complex<f32>, ztrsv: Complex<f64>);
+    reference_trsm!(declare strsm: f32, dtrsm: f64, ctrsm: Complex<f32>, ztrsm: Complex<f64>);
+    reference_trmm!(declare strmm: f32, dtrmm: f64, ctrmm: Complex<f32>, ztrmm: Complex<f64>);
+    reference_gbmv!(declare sgbmv: f32, dgbmv: f64, cgbmv: Complex<f32>, zgbmv: Complex<f64>);
+    reference_getrs!(declare sgetrs: f32, dgetrs: f64, cgetrs: Complex<f32>, zgetrs: Complex<f64>);
+    reference_getri!(declare sgetri: f32, dgetri: f64);
+    reference_gecon_real!(declare sgecon: f32, dgecon: f64);
+    reference_gecon_complex!(declare cgecon: f32, zgecon: f64);
+    reference_gesvx_real!(declare sgesvx: f32, dgesvx: f64);
+    reference_gesvx_complex!(declare cgesvx: f32, zgesvx: f64);
+    reference_potrs!(declare spotrs: f32, dpotrs: f64);
+    reference_sytrf!(declare ssytrf: f32, dsytrf: f64, chetrf: Complex<f32>, zhetrf: Complex<f64>);
+    reference_sytrs!(declare ssytrs: f32, dsytrs: f64, chetrs: Complex<f32>, zhetrs: Complex<f64>);
+    reference_trtrs!(declare strtrs: f32, dtrtrs: f64, ctrtrs: Complex<f32>, ztrtrs: Complex<f64>);
+    reference_gbsv!(declare sgbsv: f32, dgbsv: f64, cgbsv: Complex<f32>, zgbsv: Complex<f64>);
+    reference_pbsv!(declare spbsv: f32, dpbsv: f64, cpbsv: Complex<f32>, zpbsv: Complex<f64>);
+    reference_gtsv!(declare sgtsv: f32, dgtsv: f64, cgtsv: Complex<f32>, zgtsv: Complex<f64>);
+    reference_ptsv!(declare sptsv: f32, f32, dptsv: f64, f64, cptsv: Complex<f32>, f32,
+        zptsv: Complex<f64>, f64);
+    reference_ormqr!(declare sormqr: f32, dormqr: f64, cunmqr: Complex<f32>, zunmqr: Complex<f64>);
+    reference_geqp3_real!(declare sgeqp3: f32, dgeqp3: f64);
+    reference_geqp3_complex!(declare cgeqp3: f32, zgeqp3: f64);
+    reference_gels!(declare sgels: f32, dgels: f64);
+    reference_gelsd!(declare sgelsd: f32, dgelsd: f64);
+    reference_gesdd_real!(declare sgesdd: f32, dgesdd: f64);
+    reference_gesdd_complex!(declare cgesdd: f32, zgesdd: f64);
+    reference_gesvd_real!(declare sgesvd: f32, dgesvd: f64);
+    reference_gesvd_complex!(declare cgesvd: f32, zgesvd: f64);
+    reference_syevr!(declare ssyevr: f32, dsyevr: f64);
+    reference_heevr!(declare cheevr: f32, zheevr: f64);
+    reference_sygv!(declare ssygv: f32, dsygv: f64);
+    reference_hegv!(declare chegv: f32, zhegv: f64);
+    reference_ggev_real!(declare sggev: f32, dggev: f64);
+    reference_ggev_complex!(declare cggev: f32, zggev: f64);
+    reference_gehrd!(declare sgehrd: f32, dgehrd: f64, cgehrd: Complex<f32>, zgehrd: Complex<f64>);
+    reference_orghr!(declare sorghr: f32, dorghr: f64, cunghr: Complex<f32>, zunghr: Complex<f64>);
+    reference_trsen!(declare strsen: f32, dtrsen: f64);
+}
+//}}}
+//{{{ struct: ReferenceBackend
+/// The pure-Rust reference implementation of every [`Backend`] routine.
+///
+/// Slower than an optimised library but free of any system dependency, and deterministic across
+/// platforms, which makes it the baseline to diff other backends against.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ReferenceBackend;
+
+impl Backend for ReferenceBackend {
+    reference_gemm!(sgemm: f32, dgemm: f64, cgemm: Complex<f32>, zgemm: Complex<f64>);
+    reference_gemv!(sgemv: f32, dgemv: f64, cgemv: Complex<f32>, zgemv: Complex<f64>);
+    reference_getrf!(sgetrf: f32, dgetrf: f64, cgetrf: Complex<f32>, zgetrf: Complex<f64>);
+    reference_gesv!(sgesv: f32, dgesv: f64);
+    reference_potrf!(spotrf: f32, dpotrf: f64, cpotrf: Complex<f32>, zpotrf: Complex<f64>);
+    reference_geqrf!(sgeqrf: f32, dgeqrf: f64, cgeqrf: Complex<f32>, zgeqrf: Complex<f64>);
+    reference_orgqr!(sorgqr: f32, dorgqr: f64, cungqr: Complex<f32>, zungqr: Complex<f64>);
+    reference_syev!(ssyev: f32, dsyev: f64);
+    reference_heev!(cheev: f32, zheev: f64);
+    reference_geev_real!(sgeev: f32, dgeev: f64);
+    reference_geev_complex!(cgeev: f32, zgeev: f64);
+    reference_gees!(sgees: f32, dgees: f64);
+    reference_trsv!(strsv: f32, dtrsv: f64, ctrsv: Complex<f32>, ztrsv: Complex<f64>);
+    reference_trsm!(strsm: f32, dtrsm: f64, ctrsm: Complex<f32>, ztrsm: Complex<f64>);
+    reference_trmm!(strmm: f32, dtrmm: f64, ctrmm: Complex<f32>, ztrmm: Complex<f64>);
+    reference_gbmv!(sgbmv: f32, dgbmv: f64, cgbmv: Complex<f32>, zgbmv: Complex<f64>);
+    reference_getrs!(sgetrs: f32, dgetrs: f64, cgetrs: Complex<f32>, zgetrs: Complex<f64>);
+    reference_getri!(sgetri: f32, dgetri: f64);
+    reference_gecon_real!(sgecon: f32, dgecon: f64);
+    reference_gecon_complex!(cgecon: f32, zgecon: f64);
+    reference_gesvx_real!(sgesvx: f32, dgesvx: f64);
+    reference_gesvx_complex!(cgesvx: f32, zgesvx: f64);
+    reference_potrs!(spotrs: f32, dpotrs: f64);
+    reference_sytrf!(ssytrf: f32, dsytrf: f64, chetrf: Complex<f32>, zhetrf: Complex<f64>);
+    reference_sytrs!(ssytrs: f32, dsytrs: f64, chetrs: Complex<f32>, zhetrs: Complex<f64>);
+    reference_trtrs!(strtrs: f32, dtrtrs: f64, ctrtrs: Complex<f32>, ztrtrs: Complex<f64>);
+    reference_gbsv!(sgbsv: f32, dgbsv: f64, cgbsv: Complex<f32>, zgbsv: Complex<f64>);
+    reference_pbsv!(spbsv: f32, dpbsv: f64, cpbsv: Complex<f32>, zpbsv: Complex<f64>);
+    reference_gtsv!(sgtsv: f32, dgtsv: f64, cgtsv: Complex<f32>, zgtsv: Complex<f64>);
+    reference_ptsv!(sptsv: f32, f32, dptsv: f64, f64, cptsv: Complex<f32>, f32, zptsv: Complex<f64>,
+        f64);
+    reference_ormqr!(sormqr: f32, dormqr: f64, cunmqr: Complex<f32>, zunmqr: Complex<f64>);
+    reference_geqp3_real!(sgeqp3: f32, dgeqp3: f64);
+    reference_geqp3_complex!(cgeqp3: f32, zgeqp3: f64);
+    reference_gels!(sgels: f32, dgels: f64);
+    reference_gelsd!(sgelsd: f32, dgelsd: f64);
+    reference_gesdd_real!(sgesdd: f32, dgesdd: f64);
+    reference_gesdd_complex!(cgesdd: f32, zgesdd: f64);
+    reference_gesvd_real!(sgesvd: f32, dgesvd: f64);
+    reference_gesvd_complex!(cgesvd: f32, zgesvd: f64);
+    reference_syevr!(ssyevr: f32, dsyevr: f64);
+    reference_heevr!(cheevr: f32, zheevr: f64);
+    reference_sygv!(ssygv: f32, dsygv: f64);
+    reference_hegv!(chegv: f32, zhegv: f64);
+    reference_ggev_real!(sggev: f32, dggev: f64);
+    reference_ggev_complex!(cggev: f32, zggev: f64);
+    reference_gehrd!(sgehrd: f32, dgehrd: f64, cgehrd: Complex<f32>, zgehrd: Complex<f64>);
+    reference_orghr!(sorghr: f32, dorghr: f64, cunghr: Complex<f32>, zunghr: Complex<f64>);
+    reference_trsen!(strsen: f32, dtrsen: f64);
+}
+//}}}
+
+//{{{ collection: registry
+static GLOBAL: OnceLock<RwLock<Arc<dyn Backend>>> = OnceLock::new();
+
+/// Set by the first [`set`] call. Until then the process-wide backend is [`DEFAULT`], which
+/// [`with_current`] borrows without touching [`GLOBAL`].
+static REPLACED: AtomicBool = AtomicBool::new(false);
+
+#[cfg(system_backend)]
+static DEFAULT: SystemBackend = SystemBackend;
+
+#[cfg(not(system_backend))]
+static DEFAULT: ReferenceBackend = ReferenceBackend;
+
+thread_local! {
+    static SCOPED: RefCell<Vec<Arc<dyn Backend>>> = const { RefCell::new(Vec::new()) };
+}
+
+fn global() -> &'static RwLock<Arc<dyn Backend>> {
+    GLOBAL.get_or_init(|| RwLock::new(Arc::new(DEFAULT)))
+}
+
+/// Pops the innermost scoped backend when a [`with`] call returns or unwinds.
+struct ScopeGuard;
+
+impl Drop for ScopeGuard {
+    fn drop(&mut self) {
+        SCOPED.with(|scoped| scoped.borrow_mut().pop());
+    }
+}
+//}}}
+//{{{ fun: current
+/// Returns the backend used by calls on this thread: the innermost [`with`] override if there is
+/// one, otherwise the process-wide backend.
+pub fn current() -> Arc<dyn Backend> {
+    if let Some(backend) = SCOPED.with(|scoped| scoped.borrow().last().cloned()) {
+        return backend;
+    }
+    global()
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone()
+}
+//}}}
+//{{{ fun: with_current
+/// Runs `f` on the backend that [`current`] would return.
+///
+/// This is the dispatch path of the wrappers, called once per BLAS/LAPACK routine. While no
+/// [`with`] override is active and [`set`] has never been called it borrows the static default
+/// backend, skipping the lock and reference count that [`current`] pays for.
+pub(crate) fn with_current<F, R>(f: F) -> R
+where
+    F: FnOnce(&dyn Backend) -> R,
+{
+    if let Some(backend) = SCOPED.with(|scoped| scoped.borrow().last().cloned()) {
+        return f(&*backend);
+    }
+    if !REPLACED.load(Ordering::Acquire) {
+        return f(&DEFAULT);
+    }
+    f(&*current())
+}
+//}}}
+//{{{ fun: set
+/// Replaces the process-wide backend and returns the previous one. Threads inside a [`with`]
+/// call keep their override until it returns.
+pub fn set(backend: Arc<dyn Backend>) -> Arc<dyn Backend> {
+    let mut global = global().write().unwrap_or_else(PoisonError::into_inner);
+    REPLACED.store(true, Ordering::Release);
+    std::mem::replace(&mut *global, backend)
+}
+//}}}
+//{{{ fun: with
+/// Runs `f` with `backend` as the backend of the current thread, restoring the previous one when
+/// `f` returns or panics. Calls nest, and other threads are unaffected.
+pub fn with<F, R>(
+    backend: Arc<dyn Backend>,
+    f: F,
+) -> R
+where
+    F: FnOnce() -> R,
+{
+    SCOPED.with(|scoped| scoped.borrow_mut().push(backend));
+    let _guard = ScopeGuard;
+    f()
+}
+//}}}
@@ -0,0 +1,461 @@
+//! The system BLAS/LAPACK library as a [`Backend`].
+//!
+//! [`SystemBackend`] forwards every routine to the `cblas` and `lapack` bindings, which link
+//! against OpenBLAS on Linux and Accelerate on macOS. It is the default backend, and is compiled
+//! out, along with the bindings, unless the `system` feature is enabled and `pure_rust` is not.
+//! The `unsafe` blocks are the only ones in the dispatch path: the bindings are sound for any
+//! slices long enough for the dimensions passed, which the wrapper traits guarantee.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::Backend;
+use crate::common::{Complex, Diagonal, Side, Transpose, Triangle};
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ macro: system_blas
+/// Forwards a BLAS method to the `cblas` routine of the same name in column-major layout. The
+/// flags before the `;` are converted from the crate's enums into their `cblas` counterparts.
+macro_rules! system_blas {
+    ($name:ident($($flag:ident: $fty:ty),*; $($arg:ident: $ty:ty),*)) => {
+        fn $name(&self, $($flag: $fty,)* $($arg: $ty),*) {
+            unsafe {
+                cblas::$name(cblas::Layout::ColumnMajor, $($flag.into(),)* $($arg),*)
+            }
+        }
+    };
+}
+//}}}
+//{{{ macro: system_lapack
+/// Forwards a LAPACK method to the `lapack` routine of the same name, returning its `info`.
+macro_rules! system_lapack {
+    ($name:ident($($arg:ident: $ty:ty),*)) => {
+        fn $name(&self, $($arg: $ty),*) -> i32 {
+            let mut info = 0;
+            unsafe {
+                lapack::$name($($arg,)* &mut info);
+            }
+            info
+        }
+    };
+}
+//}}}
+//{{{ macro: system_gees
+/// Forwards `?gees` without eigenvalue selection, so `select`, `sdim` and `bwork` are unused.
+macro_rules! system_gees {
+    ($name:ident: $t:ty) => {
+        fn $name(
+            &self,
+            jobvs: u8,
+            n: i32,
+            a: &mut [$t],
+            lda: i32,
+            wr: &mut [$t],
+            wi: &mut [$t],
+            vs: &mut [$t],
+            ldvs: i32,
+            work: &mut [$t],
+            lwork: i32,
+        ) -> i32 {
+            let mut info = 0;
+            let mut sdim = 0;
+            unsafe {
+                lapack::$name(
+                    jobvs,
+                    b'N',
+                    None,
+                    n,
+                    a,
+                    lda,
+                    &mut sdim,
+                    wr,
+                    wi,
+                    vs,
+                    ldvs,
+                    work,
+                    lwork,
+                    &mut [],
+                    &mut info,
+                );
+            }
+            info
+        }
+    };
+}
+//}}}
+
+//{{{ struct: SystemBackend
+/// The system BLAS/LAPACK library, called through the `cblas` and `lapack` bindings.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemBackend;
+//}}}
+//{{{ impl: Backend for SystemBackend
+#[rustfmt::skip]
+impl Backend for SystemBackend {
+    system_blas!(sgemm(transa: Transpose, transb: Transpose; m: i32, n: i32, k: i32, alpha: f32,
+        a: &[f32], lda: i32, b: &[f32], ldb: i32, beta: f32, c: &mut [f32], ldc: i32));
+    system_blas!(dgemm(transa: Transpose, transb: Transpose; m: i32, n: i32, k: i32, alpha: f64,
+        a: &[f64], lda: i32, b: &[f64], ldb: i32, beta: f64, c: &mut [f64], ldc: i32));
+    system_blas!(cgemm(transa: Transpose, transb: Transpose; m: i32, n: i32, k: i32,
+        alpha: Complex<f32>, a: &[Complex<f32>], lda: i32, b: &[Complex<f32>], ldb: i32,
+        beta: Complex<f32>, c: &mut [Complex<f32>], ldc: i32));
+    system_blas!(zgemm(transa: Transpose, transb: Transpose; m: i32, n: i32, k: i32,
+        alpha: Complex<f64>, a: &[Complex<f64>], lda: i32, b: &[Complex<f64>], ldb: i32,
+        beta: Complex<f64>, c: &mut [Complex<f64>], ldc: i32));
+
+    system_blas!(sgemv(trans: Transpose; m: i32, n: i32, alpha: f32, a: &[f32], lda: i32,
+        x: &[f32], incx: i32, beta: f32, y: &mut [f32], incy: i32));
+    system_blas!(dgemv(trans: Transpose; m: i32, n: i32, alpha: f64, a: &[f64], lda: i32,
+        x: &[f64], incx: i32, beta: f64, y: &mut [f64], incy: i32));
+    system_blas!(cgemv(trans: Transpose; m: i32, n: i32, alpha: Complex<f32>,
+        a: &[Complex<f32>], lda: i32, x: &[Complex<f32>], incx: i32, beta: Complex<f32>,
+        y: &mut [Complex<f32>], incy: i32));
+    system_blas!(zgemv(trans: Transpose; m: i32, n: i32, alpha: Complex<f64>,
+        a: &[Complex<f64>], lda: i32, x: &[Complex<f64>], incx: i32, beta: Complex<f64>,
+        y: &mut [Complex<f64>], incy: i32));
+
+    system_blas!(strsv(uplo: Triangle, trans: Transpose, diag: Diagonal; n: i32, a: &[f32],
+        lda: i32, x: &mut [f32], incx: i32));
+    system_blas!(dtrsv(uplo: Triangle, trans: Transpose, diag: Diagonal; n: i32, a: &[f64],
+        lda: i32, x: &mut [f64], incx: i32));
+    system_blas!(ctrsv(uplo: Triangle, trans: Transpose, diag: Diagonal; n: i32, a: &[Complex<f32>],
+        lda: i32, x: &mut [Complex<f32>], incx: i32));
+    system_blas!(ztrsv(uplo: Triangle, trans: Transpose, diag: Diagonal; n: i32, a: &[Complex<f64>],
+        lda: i32, x: &mut [Complex<f64>], incx: i32));
+
+    system_blas!(strsm(side: Side, uplo: Triangle, transa: Transpose, diag: Diagonal; m: i32,
+        n: i32, alpha: f32, a: &[f32], lda: i32, b: &mut [f32], ldb: i32));
+    system_blas!(dtrsm(side: Side, uplo: Triangle, transa: Transpose, diag: Diagonal; m: i32,
+        n: i32, alpha: f64, a: &[f64], lda: i32, b: &mut [f64], ldb: i32));
+    system_blas!(ctrsm(side: Side, uplo: Triangle, transa: Transpose, diag: Diagonal; m: i32,
+        n: i32, alpha: Complex<f32>, a: &[Complex<f32>], lda: i32, b: &mut [Complex<f32>],
+        ldb: i32));
+    system_blas!(ztrsm(side: Side, uplo: Triangle, transa: Transpose, diag: Diagonal; m: i32,
+        n: i32, alpha: Complex<f64>, a: &[Complex<f64>], lda: i32, b: &mut [Complex<f64>],
+        ldb: i32));
+
+    system_blas!(strmm(side: Side, uplo: Triangle, transa: Transpose, diag: Diagonal; m: i32,
+        n: i32, alpha: f32, a: &[f32], lda: i32, b: &mut [f32], ldb: i32));
+    system_blas!(dtrmm(side: Side, uplo: Triangle, transa: Transpose, diag: Diagonal; m: i32,
+        n: i32, alpha: f64, a: &[f64], lda: i32, b: &mut [f64], ldb: i32));
+    system_blas!(ctrmm(side: Side, uplo: Triangle, transa: Transpose, diag: Diagonal; m: i32,
+        n: i32, alpha: Complex<f32>, a: &[Complex<f32>], lda: i32, b: &mut [Complex<f32>],
+        ldb: i32));
+    system_blas!(ztrmm(side: Side, uplo: Triangle, transa: Transpose, diag: Diagonal; m: i32,
+        n: i32, alpha: Complex<f64>, a: &[Complex<f64>], lda: i32, b: &mut [Complex<f64>],
+        ldb: i32));
+
+    system_blas!(sgbmv(trans: Transpose; m: i32, n: i32, kl: i32, ku: i32, alpha: f32, a: &[f32],
+        lda: i32, x: &[f32], incx: i32, beta: f32, y: &mut [f32], incy: i32));
+    system_blas!(dgbmv(trans: Transpose; m: i32, n: i32, kl: i32, ku: i32, alpha: f64, a: &[f64],
+        lda: i32, x: &[f64], incx: i32, beta: f64, y: &mut [f64], incy: i32));
+    system_blas!(cgbmv(trans: Transpose; m: i32, n: i32, kl: i32, ku: i32, alpha: Complex<f32>,
+        a: &[Complex<f32>], lda: i32, x: &[Complex<f32>], incx: i32, beta: Complex<f32>,
+        y: &mut [Complex<f32>], incy: i32));
+    system_blas!(zgbmv(trans: Transpose; m: i32, n: i32, kl: i32, ku: i32, alpha: Complex<f64>,
+        a: &[Complex<f64>], lda: i32, x: &[Complex<f64>], incx: i32, beta: Complex<f64>,
+        y: &mut [Complex<f64>], incy: i32));
+
+    system_lapack!(sgetrf(m: i32, n: i32, a: &mut [f32], lda: i32, ipiv: &mut [i32]));
+    system_lapack!(dgetrf(m: i32, n: i32, a: &mut [f64], lda: i32, ipiv: &mut [i32]));
+    system_lapack!(cgetrf(m: i32, n: i32, a: &mut [Complex<f32>], lda: i32, ipiv: &mut [i32]));
+    system_lapack!(zgetrf(m: i32, n: i32, a: &mut [Complex<f64>], lda: i32, ipiv: &mut [i32]));
+
+    system_lapack!(sgesv(n: i32, nrhs: i32, a: &mut [f32], lda: i32, ipiv: &mut [i32],
+        b: &mut [f32], ldb: i32));
+    system_lapack!(dgesv(n: i32, nrhs: i32, a: &mut [f64], lda: i32, ipiv: &mut [i32],
+        b: &mut [f64], ldb: i32));
+
+    system_lapack!(spotrf(uplo: u8, n: i32, a: &mut [f32], lda: i32));
+    system_lapack!(dpotrf(uplo: u8, n: i32, a: &mut [f64], lda: i32));
+    system_lapack!(cpotrf(uplo: u8, n: i32, a: &mut [Complex<f32>], lda: i32));
+    system_lapack!(zpotrf(uplo: u8, n: i32, a: &mut [Complex<f64>], lda: i32));
+
+    system_lapack!(sgeqrf(m: i32, n: i32, a: &mut [f32], lda: i32, tau: &mut [f32],
+        work: &mut [f32], lwork: i32));
+    system_lapack!(dgeqrf(m: i32, n: i32, a: &mut [f64], lda: i32, tau: &mut [f64],
+        work: &mut [f64], lwork: i32));
+    system_lapack!(cgeqrf(m: i32, n: i32, a: &mut [Complex<f32>], lda: i32,
+        tau: &mut [Complex<f32>], work: &mut [Complex<f32>], lwork: i32));
+    system_lapack!(zgeqrf(m: i32, n: i32, a: &mut [Complex<f64>], lda: i32,
+        tau: &mut [Complex<f64>], work: &mut [Complex<f64>], lwork: i32));
+
+    system_lapack!(sorgqr(m: i32, n: i32, k: i32, a: &mut [f32], lda: i32, tau: &[f32],
+        work: &mut [f32], lwork: i32));
+    system_lapack!(dorgqr(m: i32, n: i32, k: i32, a: &mut [f64], lda: i32, tau: &[f64],
+        work: &mut [f64], lwork: i32));
+    system_lapack!(cungqr(m: i32, n: i32, k: i32, a: &mut [Complex<f32>], lda: i32,
+        tau: &[Complex<f32>], work: &mut [Complex<f32>], lwork: i32));
+    system_lapack!(zungqr(m: i32, n: i32, k: i32, a: &mut [Complex<f64>], lda: i32,
+        tau: &[Complex<f64>], work: &mut [Complex<f64>], lwork: i32));
+
+    system_lapack!(ssyev(jobz: u8, uplo: u8, n: i32, a: &mut [f32], lda: i32, w: &mut [f32],
+        work: &mut [f32], lwork: i32));
+    system_lapack!(dsyev(jobz: u8, uplo: u8, n: i32, a: &mut [f64], lda: i32, w: &mut [f64],
+        work: &mut [f64], lwork: i32));
+    system_lapack!(cheev(jobz: u8, uplo: u8, n: i32, a: &mut [Complex<f32>], lda: i32,
+        w: &mut [f32], work: &mut [Complex<f32>], lwork: i32, rwork: &mut [f32]));
+    system_lapack!(zheev(jobz: u8, uplo: u8, n: i32, a: &mut [Complex<f64>], lda: i32,
+        w: &mut [f64], work: &mut [Complex<f64>], lwork: i32, rwork: &mut [f64]));
+
+    system_lapack!(sgeev(jobvl: u8, jobvr: u8, n: i32, a: &mut [f32], lda: i32, wr: &mut [f32],
+        wi: &mut [f32], vl: &mut [f32], ldvl: i32, vr: &mut [f32], ldvr: i32, work: &mut [f32],
+        lwork: i32));
+    system_lapack!(dgeev(jobvl: u8, jobvr: u8, n: i32, a: &mut [f64], lda: i32, wr: &mut [f64],
+        wi: &mut [f64], vl: &mut [f64], ldvl: i32, vr: &mut [f64], ldvr: i32, work: &mut [f64],
+        lwork: i32));
+    system_lapack!(cgeev(jobvl: u8, jobvr: u8, n: i32, a: &mut [Complex<f32>], lda: i32,
+        w: &mut [Complex<f32>], vl: &mut [Complex<f32>], ldvl: i32, vr: &mut [Complex<f32>],
+        ldvr: i32, work: &mut [Complex<f32>], lwork: i32, rwork: &mut [f32]));
+    system_lapack!(zgeev(jobvl: u8, jobvr: u8, n: i32, a: &mut [Complex<f64>], lda: i32,
+        w: &mut [Complex<f64>], vl: &mut [Complex<f64>], ldvl: i32, vr: &mut [Complex<f64>],
+        ldvr: i32, work: &mut [Complex<f64>], lwork: i32, rwork: &mut [f64]));
+
+    system_gees!(sgees: f32);
+    system_gees!(dgees: f64);
+
+    system_lapack!(sgetrs(trans: u8, n: i32, nrhs: i32, a: &[f32], lda: i32, ipiv: &[i32],
+        b: &mut [f32], ldb: i32));
+    system_lapack!(dgetrs(trans: u8, n: i32, nrhs: i32, a: &[f64], lda: i32, ipiv: &[i32],
+        b: &mut [f64], ldb: i32));
+    system_lapack!(cgetrs(trans: u8, n: i32, nrhs: i32, a: &[Complex<f32>], lda: i32, ipiv: &[i32],
+        b: &mut [Complex<f32>], ldb: i32));
+    system_lapack!(zgetrs(trans: u8, n: i32, nrhs: i32, a: &[Complex<f64>], lda: i32, ipiv: &[i32],
+        b: &mut [Complex<f64>], ldb: i32));
+
+    system_lapack!(sgetri(n: i32, a: &mut [f32], lda: i32, ipiv: &[i32], work: &mut [f32],
+        lwork: i32));
+    system_lapack!(dgetri(n: i32, a: &mut [f64], lda: i32, ipiv: &[i32], work: &mut [f64],
+        lwork: i32));
+
+    system_lapack!(sgecon(norm: u8, n: i32, a: &[f32], lda: i32, anorm: f32, rcond: &mut f32,
+        work: &mut [f32], iwork: &mut [i32]));
+    system_lapack!(dgecon(norm: u8, n: i32, a: &[f64], lda: i32, anorm: f64, rcond: &mut f64,
+        work: &mut [f64], iwork: &mut [i32]));
+
+    system_lapack!(cgecon(norm: u8, n: i32, a: &[Complex<f32>], lda: i32, anorm: f32,
+        rcond: &mut f32, work: &mut [Complex<f32>], rwork: &mut [f32]));
+    system_lapack!(zgecon(norm: u8, n: i32, a: &[Complex<f64>], lda: i32, anorm: f64,
+        rcond: &mut f64, work: &mut [Complex<f64>], rwork: &mut [f64]));
+
+    system_lapack!(sgesvx(fact: u8, trans: u8, n: i32, nrhs: i32, a: &mut [f32], lda: i32,
+        af: &mut [f32], ldaf: i32, ipiv: &mut [i32], equed: &mut u8, r: &mut [f32], c: &mut [f32],
+        b: &mut [f32], ldb: i32, x: &mut [f32], ldx: i32, rcond: &mut f32, ferr: &mut [f32],
+        berr: &mut [f32], work: &mut [f32], iwork: &mut [i32]));
+    system_lapack!(dgesvx(fact: u8, trans: u8, n: i32, nrhs: i32, a: &mut [f64], lda: i32,
+        af: &mut [f64], ldaf: i32, ipiv: &mut [i32], equed: &mut u8, r: &mut [f64], c: &mut [f64],
+        b: &mut [f64], ldb: i32, x: &mut [f64], ldx: i32, rcond: &mut f64, ferr: &mut [f64],
+        berr: &mut [f64], work: &mut [f64], iwork: &mut [i32]));
+
+    system_lapack!(cgesvx(fact: u8, trans: u8, n: i32, nrhs: i32, a: &mut [Complex<f32>], lda: i32,
+        af: &mut [Complex<f32>], ldaf: i32, ipiv: &mut [i32], equed: &mut u8, r: &mut [f32],
+        c: &mut [f32], b: &mut [Complex<f32>], ldb: i32, x: &mut [Complex<f32>], ldx: i32,
+        rcond: &mut f32, ferr: &mut [f32], berr: &mut [f32], work: &mut [Complex<f32>],
+        rwork: &mut [f32]));
+    system_lapack!(zgesvx(fact: u8, trans: u8, n: i32, nrhs: i32, a: &mut [Complex<f64>], lda: i32,
+        af: &mut [Complex<f64>], ldaf: i32, ipiv: &mut [i32], equed: &mut u8, r: &mut [f64],
+        c: &mut [f64], b: &mut [Complex<f64>], ldb: i32, x: &mut [Complex<f64>], ldx: i32,
+        rcond: &mut f64, ferr: &mut [f64], berr: &mut [f64], work: &mut [Complex<f64>],
+        rwork: &mut [f64]));
+
+    system_lapack!(spotrs(uplo: u8, n: i32, nrhs: i32, a: &[f32], lda: i32, b: &mut [f32],
+        ldb: i32));
+    system_lapack!(dpotrs(uplo: u8, n: i32, nrhs: i32, a: &[f64], lda: i32, b: &mut [f64],
+        ldb: i32));
+
+    system_lapack!(ssytrf(uplo: u8, n: i32, a: &mut [f32], lda: i32, ipiv: &mut [i32],
+        work: &mut [f32], lwork: i32));
+    system_lapack!(dsytrf(uplo: u8, n: i32, a: &mut [f64], lda: i32, ipiv: &mut [i32],
+        work: &mut [f64], lwork: i32));
+    system_lapack!(chetrf(uplo: u8, n: i32, a: &mut [Complex<f32>], lda: i32, ipiv: &mut [i32],
+        work: &mut [Complex<f32>], lwork: i32));
+    system_lapack!(zhetrf(uplo: u8, n: i32, a: &mut [Complex<f64>], lda: i32, ipiv: &mut [i32],
+        work: &mut [Complex<f64>], lwork: i32));
+
+    system_lapack!(ssytrs(uplo: u8, n: i32, nrhs: i32, a: &[f32], lda: i32, ipiv: &[i32],
+        b: &mut [f32], ldb: i32));
+    system_lapack!(dsytrs(uplo: u8, n: i32, nrhs: i32, a: &[f64], lda: i32, ipiv: &[i32],
+        b: &mut [f64], ldb: i32));
+    system_lapack!(chetrs(uplo: u8, n: i32, nrhs: i32, a: &[Complex<f32>], lda: i32, ipiv: &[i32],
+        b: &mut [Complex<f32>], ldb: i32));
+    system_lapack!(zhetrs(uplo: u8, n: i32, nrhs: i32, a: &[Complex<f64>], lda: i32, ipiv: &[i32],
+        b: &mut [Complex<f64>], ldb: i32));
+
+    system_lapack!(strtrs(uplo: u8, trans: u8, diag: u8, n: i32, nrhs: i32, a: &[f32], lda: i32,
+        b: &mut [f32], ldb: i32));
+    system_lapack!(dtrtrs(uplo: u8, trans: u8, diag: u8, n: i32, nrhs: i32, a: &[f64], lda: i32,
+        b: &mut [f64], ldb: i32));
+    system_lapack!(ctrtrs(uplo: u8, trans: u8, diag: u8, n: i32, nrhs: i32, a: &[Complex<f32>],
+        lda: i32, b: &mut [Complex<f32>], ldb: i32));
+    system_lapack!(ztrtrs(uplo: u8, trans: u8, diag: u8, n: i32, nrhs: i32, a: &[Complex<f64>],
+        lda: i32, b: &mut [Complex<f64>], ldb: i32));
+
+    system_lapack!(sgbsv(n: i32, kl: i32, ku: i32, nrhs: i32, ab: &mut [f32], ldab: i32,
+        ipiv: &mut [i32], b: &mut [f32], ldb: i32));
+    system_lapack!(dgbsv(n: i32, kl: i32, ku: i32, nrhs: i32, ab: &mut [f64], ldab: i32,
+        ipiv: &mut [i32], b: &mut [f64], ldb: i32));
+    system_lapack!(cgbsv(n: i32, kl: i32, ku: i32, nrhs: i32, ab: &mut [Complex<f32>], ldab: i32,
+        ipiv: &mut [i32], b: &mut [Complex<f32>], ldb: i32));
+    system_lapack!(zgbsv(n: i32, kl: i32, ku: i32, nrhs: i32, ab: &mut [Complex<f64>], ldab: i32,
+        ipiv: &mut [i32], b: &mut [Complex<f64>], ldb: i32));
+
+    system_lapack!(spbsv(uplo: u8, n: i32, kd: i32, nrhs: i32, ab: &mut [f32], ldab: i32,
+        b: &mut [f32], ldb: i32));
+    system_lapack!(dpbsv(uplo: u8, n: i32, kd: i32, nrhs: i32, ab: &mut [f64], ldab: i32,
+        b: &mut [f64], ldb: i32));
+    system_lapack!(cpbsv(uplo: u8, n: i32, kd: i32, nrhs: i32, ab: &mut [Complex<f32>], ldab: i32,
+        b: &mut [Complex<f32>], ldb: i32));
+    system_lapack!(zpbsv(uplo: u8, n: i32, kd: i32, nrhs: i32, ab: &mut [Complex<f64>], ldab: i32,
+        b: &mut [Complex<f64>], ldb: i32));
+
+    system_lapack!(sgtsv(n: i32, nrhs: i32, dl: &mut [f32], d: &mut [f32], du: &mut [f32],
+        b: &mut [f32], ldb: i32));
+    system_lapack!(dgtsv(n: i32, nrhs: i32, dl: &mut [f64], d: &mut [f64], du: &mut [f64],
+        b: &mut [f64], ldb: i32));
+    system_lapack!(cgtsv(n: i32, nrhs: i32, dl: &mut [Complex<f32>], d: &mut [Complex<f32>],
+        du: &mut [Complex<f32>], b: &mut [Complex<f32>], ldb: i32));
+    system_lapack!(zgtsv(n: i32, nrhs: i32, dl: &mut [Complex<f64>], d: &mut [Complex<f64>],
+        du: &mut [Complex<f64>], b: &mut [Complex<f64>], ldb: i32));
+
+    system_lapack!(sptsv(n: i32, nrhs: i32, d: &mut [f32], e: &mut [f32], b: &mut [f32], ldb: i32));
+    system_lapack!(dptsv(n: i32, nrhs: i32, d: &mut [f64], e: &mut [f64], b: &mut [f64], ldb: i32));
+    system_lapack!(cptsv(n: i32, nrhs: i32, d: &mut [f32], e: &mut [Complex<f32>],
+        b: &mut [Complex<f32>], ldb: i32));
+    system_lapack!(zptsv(n: i32, nrhs: i32, d: &mut [f64], e: &mut [Complex<f64>],
+        b: &mut [Complex<f64>], ldb: i32));
+
+    system_lapack!(sormqr(side: u8, trans: u8, m: i32, n: i32, k: i32, a: &[f32], lda: i32,
+        tau: &[f32], c: &mut [f32], ldc: i32, work: &mut [f32], lwork: i32));
+    system_lapack!(dormqr(side: u8, trans: u8, m: i32, n: i32, k: i32, a: &[f64], lda: i32,
+        tau: &[f64], c: &mut [f64], ldc: i32, work: &mut [f64], lwork: i32));
+    system_lapack!(cunmqr(side: u8, trans: u8, m: i32, n: i32, k: i32, a: &[Complex<f32>], lda: i32,
+        tau: &[Complex<f32>], c: &mut [Complex<f32>], ldc: i32, work: &mut [Complex<f32>],
+        lwork: i32));
+    system_lapack!(zunmqr(side: u8, trans: u8, m: i32, n: i32, k: i32, a: &[Complex<f64>], lda: i32,
+        tau: &[Complex<f64>], c: &mut [Complex<f64>], ldc: i32, work: &mut [Complex<f64>],
+        lwork: i32));
+
+    system_lapack!(sgeqp3(m: i32, n: i32, a: &mut [f32], lda: i32, jpvt: &mut [i32],
+        tau: &mut [f32], work: &mut [f32], lwork: i32));
+    system_lapack!(dgeqp3(m: i32, n: i32, a: &mut [f64], lda: i32, jpvt: &mut [i32],
+        tau: &mut [f64], work: &mut [f64], lwork: i32));
+
+    system_lapack!(cgeqp3(m: i32, n: i32, a: &mut [Complex<f32>], lda: i32, jpvt: &mut [i32],
+        tau: &mut [Complex<f32>], work: &mut [Complex<f32>], lwork: i32, rwork: &mut [f32]));
+    system_lapack!(zgeqp3(m: i32, n: i32, a: &mut [Complex<f64>], lda: i32, jpvt: &mut [i32],
+        tau: &mut [Complex<f64>], work: &mut [Complex<f64>], lwork: i32, rwork: &mut [f64]));
+
+    system_lapack!(sgels(trans: u8, m: i32, n: i32, nrhs: i32, a: &mut [f32], lda: i32,
+        b: &mut [f32], ldb: i32, work: &mut [f32], lwork: i32));
+    system_lapack!(dgels(trans: u8, m: i32, n: i32, nrhs: i32, a: &mut [f64], lda: i32,
+        b: &mut [f64], ldb: i32, work: &mut [f64], lwork: i32));
+
+    system_lapack!(sgelsd(m: i32, n: i32, nrhs: i32, a: &mut [f32], lda: i32, b: &mut [f32],
+        ldb: i32, s: &mut [f32], rcond: f32, rank: &mut i32, work: &mut [f32], lwork: i32,
+        iwork: &mut [i32]));
+    system_lapack!(dgelsd(m: i32, n: i32, nrhs: i32, a: &mut [f64], lda: i32, b: &mut [f64],
+        ldb: i32, s: &mut [f64], rcond: f64, rank: &mut i32, work: &mut [f64], lwork: i32,
+        iwork: &mut [i32]));
+
+    system_lapack!(sgesdd(jobz: u8, m: i32, n: i32, a: &mut [f32], lda: i32, s: &mut [f32],
+        u: &mut [f32], ldu: i32, vt: &mut [f32], ldvt: i32, work: &mut [f32], lwork: i32,
+        iwork: &mut [i32]));
+    system_lapack!(dgesdd(jobz: u8, m: i32, n: i32, a: &mut [f64], lda: i32, s: &mut [f64],
+        u: &mut [f64], ldu: i32, vt: &mut [f64], ldvt: i32, work: &mut [f64], lwork: i32,
+        iwork: &mut [i32]));
+
+    system_lapack!(cgesdd(jobz: u8, m: i32, n: i32, a: &mut [Complex<f32>], lda: i32, s: &mut [f32],
+        u: &mut [Complex<f32>], ldu: i32, vt: &mut [Complex<f32>], ldvt: i32,
+        work: &mut [Complex<f32>], lwork: i32, rwork: &mut [f32], iwork: &mut [i32]));
+    system_lapack!(zgesdd(jobz: u8, m: i32, n: i32, a: &mut [Complex<f64>], lda: i32, s: &mut [f64],
+        u: &mut [Complex<f64>], ldu: i32, vt: &mut [Complex<f64>], ldvt: i32,
+        work: &mut [Complex<f64>], lwork: i32, rwork: &mut [f64], iwork: &mut [i32]));
+
+    system_lapack!(sgesvd(jobu: u8, jobvt: u8, m: i32, n: i32, a: &mut [f32], lda: i32,
+        s: &mut [f32], u: &mut [f32], ldu: i32, vt: &mut [f32], ldvt: i32, work: &mut [f32],
+        lwork: i32));
+    system_lapack!(dgesvd(jobu: u8, jobvt: u8, m: i32, n: i32, a: &mut [f64], lda: i32,
+        s: &mut [f64], u: &mut [f64], ldu: i32, vt: &mut [f64], ldvt: i32, work: &mut [f64],
+        lwork: i32));
+
+    system_lapack!(cgesvd(jobu: u8, jobvt: u8, m: i32, n: i32, a: &mut [Complex<f32>], lda: i32,
+        s: &mut [f32], u: &mut [Complex<f32>], ldu: i32, vt: &mut [Complex<f32>], ldvt: i32,
+        work: &mut [Complex<f32>], lwork: i32, rwork: &mut [f32]));
+    system_lapack!(zgesvd(jobu: u8, jobvt: u8, m: i32, n: i32, a: &mut [Complex<f64>], lda: i32,
+        s: &mut [f64], u: &mut [Complex<f64>], ldu: i32, vt: &mut [Complex<f64>], ldvt: i32,
+        work: &mut [Complex<f64>], lwork: i32, rwork: &mut [f64]));
+
+    system_lapack!(ssyevr(jobz: u8, range: u8, uplo: u8, n: i32, a: &mut [f32], lda: i32, vl: f32,
+        vu: f32, il: i32, iu: i32, abstol: f32, m: &mut i32, w: &mut [f32], z: &mut [f32], ldz: i32,
+        isuppz: &mut [i32], work: &mut [f32], lwork: i32, iwork: &mut [i32], liwork: i32));
+    system_lapack!(dsyevr(jobz: u8, range: u8, uplo: u8, n: i32, a: &mut [f64], lda: i32, vl: f64,
+        vu: f64, il: i32, iu: i32, abstol: f64, m: &mut i32, w: &mut [f64], z: &mut [f64], ldz: i32,
+        isuppz: &mut [i32], work: &mut [f64], lwork: i32, iwork: &mut [i32], liwork: i32));
+
+    system_lapack!(cheevr(jobz: u8, range: u8, uplo: u8, n: i32, a: &mut [Complex<f32>], lda: i32,
+        vl: f32, vu: f32, il: i32, iu: i32, abstol: f32, m: &mut i32, w: &mut [f32],
+        z: &mut [Complex<f32>], ldz: i32, isuppz: &mut [i32], work: &mut [Complex<f32>], lwork: i32,
+        rwork: &mut [f32], lrwork: i32, iwork: &mut [i32], liwork: i32));
+    system_lapack!(zheevr(jobz: u8, range: u8, uplo: u8, n: i32, a: &mut [Complex<f64>], lda: i32,
+        vl: f64, vu: f64, il: i32, iu: i32, abstol: f64, m: &mut i32, w: &mut [f64],
+        z: &mut [Complex<f64>], ldz: i32, isuppz: &mut [i32], work: &mut [Complex<f64>], lwork: i32,
+        rwork: &mut [f64], lrwork: i32, iwork: &mut [i32], liwork: i32));
+
+    system_lapack!(ssygv(itype: i32, jobz: u8, uplo: u8, n: i32, a: &mut [f32], lda: i32,
+        b: &mut [f32], ldb: i32, w: &mut [f32], work: &mut [f32], lwork: i32));
+    system_lapack!(dsygv(itype: i32, jobz: u8, uplo: u8, n: i32, a: &mut [f64], lda: i32,
+        b: &mut [f64], ldb: i32, w: &mut [f64], work: &mut [f64], lwork: i32));
+
+    system_lapack!(chegv(itype: i32, jobz: u8, uplo: u8, n: i32, a: &mut [Complex<f32>], lda: i32,
+        b: &mut [Complex<f32>], ldb: i32, w: &mut [f32], work: &mut [Complex<f32>], lwork: i32,
+        rwork: &mut [f32]));
+    system_lapack!(zhegv(itype: i32, jobz: u8, uplo: u8, n: i32, a: &mut [Complex<f64>], lda: i32,
+        b: &mut [Complex<f64>], ldb: i32, w: &mut [f64], work: &mut [Complex<f64>], lwork: i32,
+        rwork: &mut [f64]));
+
+    system_lapack!(sggev(jobvl: u8, jobvr: u8, n: i32, a: &mut [f32], lda: i32, b: &mut [f32],
+        ldb: i32, alphar: &mut [f32], alphai: &mut [f32], beta: &mut [f32], vl: &mut [f32],
+        ldvl: i32, vr: &mut [f32], ldvr: i32, work: &mut [f32], lwork: i32));
+    system_lapack!(dggev(jobvl: u8, jobvr: u8, n: i32, a: &mut [f64], lda: i32, b: &mut [f64],
+        ldb: i32, alphar: &mut [f64], alphai: &mut [f64], beta: &mut [f64], vl: &mut [f64],
+        ldvl: i32, vr: &mut [f64], ldvr: i32, work: &mut [f64], lwork: i32));
+
+    system_lapack!(cggev(jobvl: u8, jobvr: u8, n: i32, a: &mut [Complex<f32>], lda: i32,
+        b: &mut [Complex<f32>], ldb: i32, alpha: &mut [Complex<f32>], beta: &mut [Complex<f32>],
+        vl: &mut [Complex<f32>], ldvl: i32, vr: &mut [Complex<f32>], ldvr: i32,
+        work: &mut [Complex<f32>], lwork: i32, rwork: &mut [f32]));
+    system_lapack!(zggev(jobvl: u8, jobvr: u8, n: i32, a: &mut [Complex<f64>], lda: i32,
+        b: &mut [Complex<f64>], ldb: i32, alpha: &mut [Complex<f64>], beta: &mut [Complex<f64>],
+        vl: &mut [Complex<f64>], ldvl: i32, vr: &mut [Complex<f64>], ldvr: i32,
+        work: &mut [Complex<f64>], lwork: i32, rwork: &mut [f64]));
+
+    system_lapack!(sgehrd(n: i32, ilo: i32, ihi: i32, a: &mut [f32], lda: i32, tau: &mut [f32],
+        work: &mut [f32], lwork: i32));
+    system_lapack!(dgehrd(n: i32, ilo: i32, ihi: i32, a: &mut [f64], lda: i32, tau: &mut [f64],
+        work: &mut [f64], lwork: i32));
+    system_lapack!(cgehrd(n: i32, ilo: i32, ihi: i32, a: &mut [Complex<f32>], lda: i32,
+        tau: &mut [Complex<f32>], work: &mut [Complex<f32>], lwork: i32));
+    system_lapack!(zgehrd(n: i32, ilo: i32, ihi: i32, a: &mut [Complex<f64>], lda: i32,
+        tau: &mut [Complex<f64>], work: &mut [Complex<f64>], lwork: i32));
+
+    system_lapack!(sorghr(n: i32, ilo: i32, ihi: i32, a: &mut [f32], lda: i32, tau: &[f32],
+        work: &mut [f32], lwork: i32));
+    system_lapack!(dorghr(n: i32, ilo: i32, ihi: i32, a: &mut [f64], lda: i32, tau: &[f64],
+        work: &mut [f64], lwork: i32));
+    system_lapack!(cunghr(n: i32, ilo: i32, ihi: i32, a: &mut [Complex<f32>], lda: i32,
+        tau: &[Complex<f32>], work: &mut [Complex<f32>], lwork: i32));
+    system_lapack!(zunghr(n: i32, ilo: i32, ihi: i32, a: &mut [Complex<f64>], lda: i32,
+        tau: &[Complex<f64>], work: &mut [Complex<f64>], lwork: i32));
+
+    system_lapack!(strsen(job: u8, compq: u8, select: &[i32], n: i32, t: &mut [f32], ldt: i32,
+        q: &mut [f32], ldq: i32, wr: &mut [f32], wi: &mut [f32], m: &mut i32, s: &mut f32,
+        sep: &mut f32, work: &mut [f32], lwork: i32, iwork: &mut [i32], liwork: i32));
+    system_lapack!(dtrsen(job: u8, compq: u8, select: &[i32], n: i32, t: &mut [f64], ldt: i32,
+        q: &mut [f64], ldq: i32, wr: &mut [f64], wi: &mut [f64], m: &mut i32, s: &mut f64,
+        sep: &mut f64, work: &mut [f64], lwork: i32, iwork: &mut [i32], liwork: i32));
+}
+//}}}
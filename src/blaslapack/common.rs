@@ -2,13 +2,20 @@
 //!
 //! Provides the [`AsI32`] conversion trait used across the BLAS/LAPACK wrappers to convert Rust
 //! floating-point dimension and parameter values into the `i32` integers that LAPACK routines
-//! expect. Implementations are provided for `f32` and `f64`. Keeping this in a dedicated module
-//! avoids repetition and provides a single place to adjust the conversion strategy if the
-//! underlying BLAS/LAPACK ABI ever changes.
+//! expect. Implementations are provided for `f32`, `f64` and their complex counterparts, for which
+//! the real part is used since LAPACK returns workspace sizes in complex work arrays. Keeping this
+//! in a dedicated module avoids repetition and provides a single place to adjust the conversion
+//! strategy if the underlying BLAS/LAPACK ABI ever changes. The module also converts the public
+//! [`Triangle`], [`Transpose`], [`Diagonal`] and [`Side`] option enums into their `cblas`
+//! counterparts for the system backend.
 //!
 //--------------------------------------------------------------------------------------------------
 
 //{{{ crate imports
+use crate::common::Complex;
+system_only! {
+    use crate::common::{Diagonal, Side, Transpose, Triangle};
+}
 //}}}
 //{{{ std imports
 //}}}
@@ -36,4 +43,61 @@ impl AsI32 for f64 {
     }
 }
 //}}}
+//{{{ impl: AsI32 for Complex<f32>
+impl AsI32 for Complex<f32> {
+    fn as_i32(&self) -> i32 {
+        self.re as i32
+    }
+}
+//}}}
+//{{{ impl: AsI32 for Complex<f64>
+impl AsI32 for Complex<f64> {
+    fn as_i32(&self) -> i32 {
+        self.re as i32
+    }
+}
+//}}}
 //}}}
+system_only! {
+    //{{{ impl: From<Triangle> for cblas::Part
+    impl From<Triangle> for cblas::Part {
+        fn from(triangle: Triangle) -> Self {
+            match triangle {
+                Triangle::Lower => cblas::Part::Lower,
+                Triangle::Upper => cblas::Part::Upper,
+            }
+        }
+    }
+    //}}}
+    //{{{ impl: From<Transpose> for cblas::Transpose
+    impl From<Transpose> for cblas::Transpose {
+        fn from(trans: Transpose) -> Self {
+            match trans {
+                Transpose::NoTrans => cblas::Transpose::None,
+                Transpose::Trans => cblas::Transpose::Ordinary,
+                Transpose::ConjTrans => cblas::Transpose::Conjugate,
+            }
+        }
+    }
+    //}}}
+    //{{{ impl: From<Diagonal> for cblas::Diagonal
+    impl From<Diagonal> for cblas::Diagonal {
+        fn from(diag: Diagonal) -> Self {
+            match diag {
+                Diagonal::NonUnit => cblas::Diagonal::Generic,
+                Diagonal::Unit => cblas::Diagonal::Unit,
+            }
+        }
+    }
+    //}}}
+    //{{{ impl: From<Side> for cblas::Side
+    impl From<Side> for cblas::Side {
+        fn from(side: Side) -> Self {
+            match side {
+                Side::Left => cblas::Side::Left,
+                Side::Right => cblas::Side::Right,
+            }
+        }
+    }
+    //}}}
+}
@@ -0,0 +1,144 @@
+//! BLAS `?gbmv` wrapper for band matrix–vector multiplication.
+//!
+//! Provides the [`Gbmv`] trait, wrapping the BLAS Level-2 `?gbmv` routine
+//! (y ← α · op(A) · x + β · y) for an m×n matrix A with `kl` sub-diagonals and `ku`
+//! super-diagonals held in LAPACK band storage, where column j of A occupies column j of the
+//! (kl + ku + 1)-row array with `A[i, j]` at row `ku + i - j`. The product costs O((kl + ku) n)
+//! instead of the O(m n) of `gemv`. Implementations for `f64`, `f32`, `Complex<f64>` and
+//! `Complex<f32>` dispatch to `?gbmv` on the current [`Backend`](super::backend::Backend).
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::backend;
+use crate::common::{Complex, Transpose};
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ trait: Gbmv
+/// Trait for types that support band matrix-vector multiplication (GBMV).
+#[allow(clippy::too_many_arguments)]
+pub trait Gbmv: Copy {
+    /// Computes `y = alpha * op(A) * x + beta * y` for an m×n band matrix `A` with `kl`
+    /// sub-diagonals and `ku` super-diagonals, stored in band layout with leading dimension `lda`.
+    fn gbmv(
+        tr: Transpose,
+        m: i32,
+        n: i32,
+        kl: i32,
+        ku: i32,
+        alpha: Self,
+        a: &[Self],
+        lda: i32,
+        x: &[Self],
+        incx: i32,
+        beta: Self,
+        y: &mut [Self],
+        incy: i32,
+    );
+}
+
+//}}}
+//{{{ impl: Gbmv for f64
+impl Gbmv for f64 {
+    #[inline]
+    fn gbmv(
+        tr: Transpose,
+        m: i32,
+        n: i32,
+        kl: i32,
+        ku: i32,
+        alpha: Self,
+        a: &[Self],
+        lda: i32,
+        x: &[Self],
+        incx: i32,
+        beta: Self,
+        y: &mut [Self],
+        incy: i32,
+    ) {
+        backend::with_current(|backend| {
+            backend.dgbmv(tr, m, n, kl, ku, alpha, a, lda, x, incx, beta, y, incy)
+        })
+    }
+}
+
+//}}}
+//{{{ impl: Gbmv for f32
+impl Gbmv for f32 {
+    #[inline]
+    fn gbmv(
+        tr: Transpose,
+        m: i32,
+        n: i32,
+        kl: i32,
+        ku: i32,
+        alpha: Self,
+        a: &[Self],
+        lda: i32,
+        x: &[Self],
+        incx: i32,
+        beta: Self,
+        y: &mut [Self],
+        incy: i32,
+    ) {
+        backend::with_current(|backend| {
+            backend.sgbmv(tr, m, n, kl, ku, alpha, a, lda, x, incx, beta, y, incy)
+        })
+    }
+}
+
+//}}}
+//{{{ impl: Gbmv for Complex<f64>
+impl Gbmv for Complex<f64> {
+    #[inline]
+    fn gbmv(
+        tr: Transpose,
+        m: i32,
+        n: i32,
+        kl: i32,
+        ku: i32,
+        alpha: Self,
+        a: &[Self],
+        lda: i32,
+        x: &[Self],
+        incx: i32,
+        beta: Self,
+        y: &mut [Self],
+        incy: i32,
+    ) {
+        backend::with_current(|backend| {
+            backend.zgbmv(tr, m, n, kl, ku, alpha, a, lda, x, incx, beta, y, incy)
+        })
+    }
+}
+
+//}}}
+//{{{ impl: Gbmv for Complex<f32>
+impl Gbmv for Complex<f32> {
+    #[inline]
+    fn gbmv(
+        tr: Transpose,
+        m: i32,
+        n: i32,
+        kl: i32,
+        ku: i32,
+        alpha: Self,
+        a: &[Self],
+        lda: i32,
+        x: &[Self],
+        incx: i32,
+        beta: Self,
+        y: &mut [Self],
+        incy: i32,
+    ) {
+        backend::with_current(|backend| {
+            backend.cgbmv(tr, m, n, kl, ku, alpha, a, lda, x, incx, beta, y, incy)
+        })
+    }
+}
+
+//}}}
@@ -0,0 +1,198 @@
+//! LAPACK `?gbsv` wrapper for solving banded linear systems.
+//!
+//! Provides the [`Gbsv`] trait, wrapping the LAPACK `?gbsv` routine that solves A X = B for an
+//! n×n band matrix A with `kl` sub-diagonals and `ku` super-diagonals by LU factorisation with
+//! partial pivoting. Row interchanges fill in up to `kl` extra super-diagonals, so `?gbsv` expects
+//! the band in an array with `2 kl + ku + 1` rows whose first `kl` rows are workspace;
+//! [`band_solve_raw`] builds that array from the compact `kl + ku + 1` row layout. The cost is
+//! O(kl (kl + ku) n) rather than the O(n³) of `?gesv`.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::backend;
+use crate::common::{Complex, Zero};
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors returned by the [`Gbsv`] LAPACK wrapper.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// LAPACK returned a non-zero info code indicating an invalid argument or a zero pivot.
+    #[error("Error in gbsv, exited with code {0}")]
+    LapackError(i32),
+}
+//}}}
+
+//{{{ trait: Gbsv
+/// Trait for types that support solving a banded linear system A X = B via LU factorisation.
+#[allow(clippy::too_many_arguments)]
+pub trait Gbsv: Copy {
+    /// Solves the band system A X = B, overwriting `ab` with the LU factors and B with X.
+    fn gbsv(
+        n: i32,
+        kl: i32,
+        ku: i32,
+        nrhs: i32,
+        ab: &mut [Self],
+        ldab: i32,
+        ipiv: &mut [i32],
+        b: &mut [Self],
+        ldb: i32,
+    ) -> Result<(), Error>;
+}
+//}}}
+
+//{{{ impl: Gbsv for f64
+impl Gbsv for f64 {
+    #[inline]
+    fn gbsv(
+        n: i32,
+        kl: i32,
+        ku: i32,
+        nrhs: i32,
+        ab: &mut [Self],
+        ldab: i32,
+        ipiv: &mut [i32],
+        b: &mut [Self],
+        ldb: i32,
+    ) -> Result<(), Error> {
+        let info =
+            backend::with_current(|backend| backend.dgbsv(n, kl, ku, nrhs, ab, ldab, ipiv, b, ldb));
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ impl: Gbsv for f32
+impl Gbsv for f32 {
+    #[inline]
+    fn gbsv(
+        n: i32,
+        kl: i32,
+        ku: i32,
+        nrhs: i32,
+        ab: &mut [Self],
+        ldab: i32,
+        ipiv: &mut [i32],
+        b: &mut [Self],
+        ldb: i32,
+    ) -> Result<(), Error> {
+        let info =
+            backend::with_current(|backend| backend.sgbsv(n, kl, ku, nrhs, ab, ldab, ipiv, b, ldb));
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ impl: Gbsv for Complex<f64>
+impl Gbsv for Complex<f64> {
+    #[inline]
+    fn gbsv(
+        n: i32,
+        kl: i32,
+        ku: i32,
+        nrhs: i32,
+        ab: &mut [Self],
+        ldab: i32,
+        ipiv: &mut [i32],
+        b: &mut [Self],
+        ldb: i32,
+    ) -> Result<(), Error> {
+        let info =
+            backend::with_current(|backend| backend.zgbsv(n, kl, ku, nrhs, ab, ldab, ipiv, b, ldb));
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ impl: Gbsv for Complex<f32>
+impl Gbsv for Complex<f32> {
+    #[inline]
+    fn gbsv(
+        n: i32,
+        kl: i32,
+        ku: i32,
+        nrhs: i32,
+        ab: &mut [Self],
+        ldab: i32,
+        ipiv: &mut [i32],
+        b: &mut [Self],
+        ldb: i32,
+    ) -> Result<(), Error> {
+        let info =
+            backend::with_current(|backend| backend.cgbsv(n, kl, ku, nrhs, ab, ldab, ipiv, b, ldb));
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ enum: BandSolveRawError
+/// Errors returned by [`band_solve_raw`].
+#[derive(Error, Debug)]
+pub enum BandSolveRawError {
+    /// `U[i, i]` is exactly zero, so the matrix is singular.
+    #[error("Matrix is singular, U({0},{0}) is exactly zero")]
+    Singular(i32),
+    /// LAPACK `gbsv` rejected its arguments.
+    #[error(transparent)]
+    Gbsv(#[from] Error),
+}
+//}}}
+//{{{ fun: band_solve_raw
+/// Shared GBSV algorithm. `ab` holds an n×n band matrix in compact band storage with leading
+/// dimension `kl + ku + 1`; `b_data` is the n×nrhs right-hand side, consumed and returned as the
+/// solution.
+pub(crate) fn band_solve_raw<T>(
+    ab: &[T],
+    n: usize,
+    kl: usize,
+    ku: usize,
+    mut b_data: Vec<T>,
+    nrhs: usize,
+) -> Result<Vec<T>, BandSolveRawError>
+where
+    T: Gbsv + Zero,
+{
+    let ld = kl + ku + 1;
+    let ldab = 2 * kl + ku + 1;
+    let mut lu = vec![T::zero(); ldab * n];
+    for j in 0..n {
+        lu[kl + j * ldab..ldab + j * ldab].copy_from_slice(&ab[j * ld..(j + 1) * ld]);
+    }
+
+    let mut ipiv = vec![0; n];
+    match T::gbsv(
+        n as i32,
+        kl as i32,
+        ku as i32,
+        nrhs as i32,
+        &mut lu,
+        ldab as i32,
+        &mut ipiv,
+        &mut b_data,
+        n.max(1) as i32,
+    ) {
+        Ok(()) => Ok(b_data),
+        Err(Error::LapackError(info)) if info > 0 => Err(BandSolveRawError::Singular(info)),
+        Err(err) => Err(err.into()),
+    }
+}
+//}}}
@@ -1,13 +1,14 @@
 //! LAPACK `dgees`/`sgees` wrapper for the Schur decomposition.
 //!
 //! Provides the [`Gees`] trait, wrapping the LAPACK `?gees` routine that computes the Schur form
-//! of a real general matrix, optionally reordering the Schur form so that selected eigenvalues
-//! appear first. The `job` flag controls whether Schur vectors (Q) are computed; `sort` enables
-//! eigenvalue reordering. This is the LAPACK driver used by both `DMatrix::schur()` and
-//! `SMatrix::schur()`.
+//! of a real general matrix. The `jobvs` flag controls whether Schur vectors (Q) are computed.
+//! The eigenvalues are left unordered, so the `sort`, `select`, `sdim` and `bwork` arguments of
+//! the LAPACK routine are not exposed; ordering is done by [`trsen`](super::trsen). This is the
+//! LAPACK driver used by both `DMatrix::schur()` and `SMatrix::schur()`.
 //--------------------------------------------------------------------------------------------------
 
 //{{{ crate imports
+use super::backend;
 //}}}
 //{{{ std imports
 //}}}
@@ -30,21 +31,19 @@ pub enum Error {
 /// Trait for types that support the Schur decomposition of a real general matrix.
 #[allow(clippy::too_many_arguments)]
 pub trait Gees: Copy {
-    /// Computes the Schur form of a real general matrix, optionally computing Schur vectors.
+    /// Computes the unordered Schur form of a real general matrix, optionally computing Schur
+    /// vectors.
     fn gees(
         jobvs: u8,
-        sort: u8,
         n: i32,
         a: &mut [Self],
         lda: i32,
-        sdim: &mut i32,
         wr: &mut [Self],
         wi: &mut [Self],
         vs: &mut [Self],
         ldvs: i32,
         work: &mut [Self],
         lwork: i32,
-        bwork: &mut [i32],
     ) -> Result<(), Error>;
 }
 //}}}
@@ -54,25 +53,19 @@ impl Gees for f64 {
     #[inline]
     fn gees(
         jobvs: u8,
-        sort: u8,
         n: i32,
         a: &mut [Self],
         lda: i32,
-        sdim: &mut i32,
         wr: &mut [Self],
         wi: &mut [Self],
         vs: &mut [Self],
         ldvs: i32,
         work: &mut [Self],
         lwork: i32,
-        bwork: &mut [i32],
     ) -> Result<(), Error> {
-        let mut info = 0;
-        unsafe {
-            lapack::dgees(
-                jobvs, sort, None, n, a, lda, sdim, wr, wi, vs, ldvs, work, lwork, bwork, &mut info,
-            );
-        }
+        let info = backend::with_current(|backend| {
+            backend.dgees(jobvs, n, a, lda, wr, wi, vs, ldvs, work, lwork)
+        });
         if info != 0 {
             return Err(Error::LapackError(info));
         }
@@ -86,25 +79,19 @@ impl Gees for f32 {
     #[inline]
     fn gees(
         jobvs: u8,
-        sort: u8,
         n: i32,
         a: &mut [Self],
         lda: i32,
-        sdim: &mut i32,
         wr: &mut [Self],
         wi: &mut [Self],
         vs: &mut [Self],
         ldvs: i32,
         work: &mut [Self],
         lwork: i32,
-        bwork: &mut [i32],
     ) -> Result<(), Error> {
-        let mut info = 0;
-        unsafe {
-            lapack::sgees(
-                jobvs, sort, None, n, a, lda, sdim, wr, wi, vs, ldvs, work, lwork, bwork, &mut info,
-            );
-        }
+        let info = backend::with_current(|backend| {
+            backend.sgees(jobvs, n, a, lda, wr, wi, vs, ldvs, work, lwork)
+        });
         if info != 0 {
             return Err(Error::LapackError(info));
         }
@@ -117,10 +104,13 @@ impl Gees for f32 {
 pub(crate) struct SchurRaw<T> {
     pub q_data: Vec<T>,
     pub t_data: Vec<T>,
+    pub wr: Vec<T>,
+    pub wi: Vec<T>,
 }
 //}}}
 //{{{ fun: schur_raw
-/// Shared GEES algorithm. Consumes the cloned matrix data; returns raw Q/T buffers.
+/// Shared GEES algorithm. Consumes the cloned matrix data; returns raw Q/T buffers and the real
+/// and imaginary parts of the eigenvalues in the order they appear on the diagonal of T.
 pub(crate) fn schur_raw<T>(
     mut a_data: Vec<T>,
     n: usize,
@@ -132,28 +122,25 @@ where
     let mut vs = vec![T::zero(); n * m];
     let mut wr = vec![T::zero(); n];
     let mut wi = vec![T::zero(); n];
-    let mut sdim = 0;
     let mut work = vec![T::zero(); n * 5];
     let lwork = (n * 5) as i32;
-    let mut bwork = vec![0; n];
     T::gees(
         b'V',
-        b'N',
         n as i32,
         &mut a_data,
         n as i32,
-        &mut sdim,
         &mut wr,
         &mut wi,
         &mut vs,
         n as i32,
         &mut work,
         lwork,
-        &mut bwork,
     )?;
     Ok(SchurRaw {
         q_data: vs,
         t_data: a_data,
+        wr,
+        wi,
     })
 }
 //}}}
@@ -1,13 +1,19 @@
-//! LAPACK `dgeev`/`sgeev` wrapper for general (non-symmetric) eigendecomposition.
+//! LAPACK `?geev` wrapper for general (non-symmetric) eigendecomposition.
 //!
 //! Provides the [`Geev`] trait, wrapping the LAPACK `?geev` routine that computes all eigenvalues
-//! and, optionally, the left and right eigenvectors of a general real matrix. Eigenvalues are
-//! returned as separate real and imaginary part arrays to match the LAPACK interface; callers in
-//! [`eig`] combine them into `Complex<T>` values. A workspace query is supported to obtain the
-//! optimal workspace size before the main computation.
+//! and, optionally, the left and right eigenvectors of a general real or complex matrix. The real
+//! LAPACK interface returns eigenvalues as separate real and imaginary part arrays; the real
+//! implementations combine them so that every element type reports its eigenvalues as
+//! `T::Complex` values. A workspace query is supported to obtain the optimal workspace size before
+//! the main computation.
+//!
+//! Real implementations return complex-conjugate eigenvector pairs packed as two real columns;
+//! `Geev::unpack_eigvecs` expands them into one complex eigenvector per column.
 //--------------------------------------------------------------------------------------------------
 
 //{{{ crate imports
+use super::backend;
+use crate::common::{Complex, ComplexField, Zero};
 //}}}
 //{{{ std imports
 //}}}
@@ -29,23 +35,40 @@ pub enum Error {
 //{{{ trait: Geev
 /// Trait for types that support general (non-symmetric) eigendecomposition.
 #[allow(clippy::too_many_arguments)]
-pub trait Geev: Copy {
-    /// Computes all eigenvalues and optionally left/right eigenvectors of a general real matrix.
+pub trait Geev: ComplexField {
+    /// Computes all eigenvalues and optionally left/right eigenvectors of a general matrix.
     fn geev(
         jobvl: u8,
         jobvr: u8,
         n: i32,
         a: &mut [Self],
         lda: i32,
-        wr: &mut [Self],
-        wi: &mut [Self],
+        w: &mut [Self::Complex], // Contains the eigenvalues on exit
         vl: &mut [Self],
         ldvl: i32,
         vr: &mut [Self],
         ldvr: i32,
         work: &mut [Self],
         lwork: i32,
+        rwork: &mut [Self::Real], // At least 2n for complex types, unused for real types
     ) -> Result<(), Error>;
+
+    /// Converts an `n`×`n` eigenvector matrix returned by `geev` into one complex eigenvector
+    /// per column, using the eigenvalues `w` to locate complex-conjugate pairs.
+    fn unpack_eigvecs(
+        v: &[Self],
+        w: &[Self::Complex],
+        n: usize,
+    ) -> Vec<Self::Complex>;
+
+    /// Converts column `i` of an `n`×`n` eigenvector matrix returned by `geev` into a complex
+    /// eigenvector, reading only that column and its conjugate partner.
+    fn unpack_eigvec(
+        v: &[Self],
+        w: &[Self::Complex],
+        n: usize,
+        i: usize,
+    ) -> Vec<Self::Complex>;
 }
 //}}}
 
@@ -58,27 +81,50 @@ impl Geev for f64 {
         n: i32,
         a: &mut [Self],
         lda: i32,
-        wr: &mut [Self],
-        wi: &mut [Self],
+        w: &mut [Complex<f64>],
         vl: &mut [Self],
         ldvl: i32,
         vr: &mut [Self],
         ldvr: i32,
         work: &mut [Self],
         lwork: i32,
+        _rwork: &mut [Self],
     ) -> Result<(), Error> {
-        let mut info = 0;
-        unsafe {
-            lapack::dgeev(
-                jobvl, jobvr, n, a, lda, wr, wi, vl, ldvl, vr, ldvr, work, lwork, &mut info,
-            );
-        }
+        let mut wr = vec![0.0; w.len()];
+        let mut wi = vec![0.0; w.len()];
+        let info = backend::with_current(|backend| {
+            backend.dgeev(
+                jobvl, jobvr, n, a, lda, &mut wr, &mut wi, vl, ldvl, vr, ldvr, work, lwork,
+            )
+        });
 
         if info != 0 {
             return Err(Error::LapackError(info));
         }
+        for (wk, (&r, &i)) in w.iter_mut().zip(wr.iter().zip(wi.iter())) {
+            *wk = Complex::new(r, i);
+        }
         Ok(())
     }
+
+    #[inline]
+    fn unpack_eigvecs(
+        v: &[Self],
+        w: &[Complex<f64>],
+        n: usize,
+    ) -> Vec<Complex<f64>> {
+        unpack_real_eigvecs(v, w, n)
+    }
+
+    #[inline]
+    fn unpack_eigvec(
+        v: &[Self],
+        w: &[Complex<f64>],
+        n: usize,
+        i: usize,
+    ) -> Vec<Complex<f64>> {
+        unpack_real_eigvec(v, w, n, i)
+    }
 }
 //}}}
 
@@ -91,97 +137,311 @@ impl Geev for f32 {
         n: i32,
         a: &mut [Self],
         lda: i32,
-        wr: &mut [Self],
-        wi: &mut [Self],
+        w: &mut [Complex<f32>],
         vl: &mut [Self],
         ldvl: i32,
         vr: &mut [Self],
         ldvr: i32,
         work: &mut [Self],
         lwork: i32,
+        _rwork: &mut [Self],
     ) -> Result<(), Error> {
-        let mut info = 0;
-        unsafe {
-            lapack::sgeev(
-                jobvl, jobvr, n, a, lda, wr, wi, vl, ldvl, vr, ldvr, work, lwork, &mut info,
-            );
+        let mut wr = vec![0.0; w.len()];
+        let mut wi = vec![0.0; w.len()];
+        let info = backend::with_current(|backend| {
+            backend.sgeev(
+                jobvl, jobvr, n, a, lda, &mut wr, &mut wi, vl, ldvl, vr, ldvr, work, lwork,
+            )
+        });
+        if info != 0 {
+            return Err(Error::LapackError(info));
         }
+        for (wk, (&r, &i)) in w.iter_mut().zip(wr.iter().zip(wi.iter())) {
+            *wk = Complex::new(r, i);
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn unpack_eigvecs(
+        v: &[Self],
+        w: &[Complex<f32>],
+        n: usize,
+    ) -> Vec<Complex<f32>> {
+        unpack_real_eigvecs(v, w, n)
+    }
+
+    #[inline]
+    fn unpack_eigvec(
+        v: &[Self],
+        w: &[Complex<f32>],
+        n: usize,
+        i: usize,
+    ) -> Vec<Complex<f32>> {
+        unpack_real_eigvec(v, w, n, i)
+    }
+}
+//}}}
+
+//{{{ impl: Geev for Complex<f64>
+impl Geev for Complex<f64> {
+    #[inline]
+    fn geev(
+        jobvl: u8,
+        jobvr: u8,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        w: &mut [Self],
+        vl: &mut [Self],
+        ldvl: i32,
+        vr: &mut [Self],
+        ldvr: i32,
+        work: &mut [Self],
+        lwork: i32,
+        rwork: &mut [f64],
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| {
+            backend.zgeev(
+                jobvl, jobvr, n, a, lda, w, vl, ldvl, vr, ldvr, work, lwork, rwork,
+            )
+        });
         if info != 0 {
             return Err(Error::LapackError(info));
         }
         Ok(())
     }
+
+    #[inline]
+    fn unpack_eigvecs(
+        v: &[Self],
+        _w: &[Self],
+        _n: usize,
+    ) -> Vec<Self> {
+        v.to_vec()
+    }
+
+    #[inline]
+    fn unpack_eigvec(
+        v: &[Self],
+        _w: &[Self],
+        n: usize,
+        i: usize,
+    ) -> Vec<Self> {
+        v[i * n..(i + 1) * n].to_vec()
+    }
+}
+//}}}
+
+//{{{ impl: Geev for Complex<f32>
+impl Geev for Complex<f32> {
+    #[inline]
+    fn geev(
+        jobvl: u8,
+        jobvr: u8,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        w: &mut [Self],
+        vl: &mut [Self],
+        ldvl: i32,
+        vr: &mut [Self],
+        ldvr: i32,
+        work: &mut [Self],
+        lwork: i32,
+        rwork: &mut [f32],
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| {
+            backend.cgeev(
+                jobvl, jobvr, n, a, lda, w, vl, ldvl, vr, ldvr, work, lwork, rwork,
+            )
+        });
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn unpack_eigvecs(
+        v: &[Self],
+        _w: &[Self],
+        _n: usize,
+    ) -> Vec<Self> {
+        v.to_vec()
+    }
+
+    #[inline]
+    fn unpack_eigvec(
+        v: &[Self],
+        _w: &[Self],
+        n: usize,
+        i: usize,
+    ) -> Vec<Self> {
+        v[i * n..(i + 1) * n].to_vec()
+    }
+}
+//}}}
+
+//{{{ fun: unpack_real_eigvecs
+/// Expands the packed real eigenvector format of `?geev` into complex eigenvectors.
+///
+/// For a real eigenvalue `w[j]`, column `j` of `v` is the eigenvector. For a complex-conjugate pair
+/// `w[j]`, `w[j + 1]` (with positive imaginary part first), the eigenvectors are
+/// `v[:, j] + i * v[:, j + 1]` and `v[:, j] - i * v[:, j + 1]`.
+fn unpack_real_eigvecs<T>(
+    v: &[T],
+    w: &[Complex<T>],
+    n: usize,
+) -> Vec<Complex<T>>
+where
+    T: Copy + Zero + PartialEq + std::ops::Neg<Output = T>,
+{
+    let mut out = vec![Complex::new(T::zero(), T::zero()); n * n];
+    let mut j = 0;
+    while j < n {
+        if w[j].im == T::zero() || j + 1 == n {
+            for i in 0..n {
+                out[i + j * n] = Complex::new(v[i + j * n], T::zero());
+            }
+            j += 1;
+        } else {
+            for i in 0..n {
+                let re = v[i + j * n];
+                let im = v[i + (j + 1) * n];
+                out[i + j * n] = Complex::new(re, im);
+                out[i + (j + 1) * n] = Complex::new(re, -im);
+            }
+            j += 2;
+        }
+    }
+    out
+}
+//}}}
+//{{{ fun: unpack_real_eigvec
+/// Expands column `i` of the packed real eigenvector format of `?geev` into a complex eigenvector.
+///
+/// Only the eigenvalues are scanned to find the conjugate pair containing `i`, so the cost is O(n)
+/// rather than the O(n²) of unpacking every column with [`unpack_real_eigvecs`].
+fn unpack_real_eigvec<T>(
+    v: &[T],
+    w: &[Complex<T>],
+    n: usize,
+    i: usize,
+) -> Vec<Complex<T>>
+where
+    T: Copy + Zero + PartialEq + std::ops::Neg<Output = T>,
+{
+    let mut j = 0;
+    while j < i {
+        j += if w[j].im == T::zero() || j + 1 == n {
+            1
+        } else {
+            2
+        };
+    }
+    let column = |k: usize| &v[k * n..(k + 1) * n];
+    if j > i {
+        // Second column of a conjugate pair starting at i - 1
+        let im = column(i);
+        column(i - 1)
+            .iter()
+            .zip(im)
+            .map(|(&re, &im)| Complex::new(re, -im))
+            .collect()
+    } else if w[i].im == T::zero() || i + 1 == n {
+        column(i)
+            .iter()
+            .map(|&re| Complex::new(re, T::zero()))
+            .collect()
+    } else {
+        let im = column(i + 1);
+        column(i)
+            .iter()
+            .zip(im)
+            .map(|(&re, &im)| Complex::new(re, im))
+            .collect()
+    }
 }
 //}}}
 
 //{{{ struct: EigRaw
-pub(crate) struct EigRaw<T> {
+pub(crate) struct EigRaw<T>
+where
+    T: ComplexField,
+{
+    /// Left eigenvectors in the packed `geev` layout; empty unless requested.
     pub vl: Vec<T>,
+    /// Right eigenvectors in the packed `geev` layout; empty unless requested.
     pub vr: Vec<T>,
-    pub eigvals: Vec<crate::common::Complex<T>>,
+    pub eigvals: Vec<T::Complex>,
 }
 //}}}
 //{{{ fun: eig_raw
 /// Shared GEEV algorithm. Consumes the cloned matrix data; returns raw eigenvector/eigenvalue buffers.
+///
+/// `left` and `right` select which eigenvectors LAPACK computes (`jobvl`/`jobvr`); when neither is
+/// requested only the eigenvalues are formed.
 pub(crate) fn eig_raw<T>(
     mut a_data: Vec<T>,
     n: usize,
+    left: bool,
+    right: bool,
 ) -> Result<EigRaw<T>, Error>
 where
-    T: Geev
-        + crate::common::One
-        + crate::common::Zero
-        + crate::common::Field
-        + Default
-        + Copy
-        + super::common::AsI32,
+    T: Geev + super::common::AsI32,
 {
-    let mut vl = vec![T::zero(); n * n];
-    let mut vr = vec![T::zero(); n * n];
-    let mut wr = vec![T::zero(); n];
-    let mut wi = vec![T::zero(); n];
+    let jobvl = if left { b'V' } else { b'N' };
+    let jobvr = if right { b'V' } else { b'N' };
+    let ldvl = if left { n.max(1) } else { 1 };
+    let ldvr = if right { n.max(1) } else { 1 };
+    let mut vl = vec![T::zero(); if left { n * n } else { 1 }];
+    let mut vr = vec![T::zero(); if right { n * n } else { 1 }];
+    let mut eigvals = vec![T::Complex::zero(); n];
+    let mut rwork = vec![T::Real::zero(); (2 * n).max(1)];
 
     let mut work = vec![T::zero(); 1];
     T::geev(
-        b'V',
-        b'V',
+        jobvl,
+        jobvr,
         n as i32,
         &mut a_data,
         n as i32,
-        &mut wr,
-        &mut wi,
+        &mut eigvals,
         &mut vl,
-        n as i32,
+        ldvl as i32,
         &mut vr,
-        n as i32,
+        ldvr as i32,
         &mut work,
         -1,
+        &mut rwork,
     )?;
 
     let lwork = work[0].as_i32();
     let mut work = vec![T::zero(); lwork as usize];
     T::geev(
-        b'V',
-        b'V',
+        jobvl,
+        jobvr,
         n as i32,
         &mut a_data,
         n as i32,
-        &mut wr,
-        &mut wi,
+        &mut eigvals,
         &mut vl,
-        n as i32,
+        ldvl as i32,
         &mut vr,
-        n as i32,
+        ldvr as i32,
         &mut work,
         lwork,
+        &mut rwork,
     )?;
 
-    let eigvals: Vec<crate::common::Complex<T>> = wr
-        .iter()
-        .zip(wi.iter())
-        .take(n)
-        .map(|(&r, &i)| crate::common::Complex::new(r, i))
-        .collect();
+    if !left {
+        vl.clear();
+    }
+    if !right {
+        vr.clear();
+    }
+
     Ok(EigRaw { vl, vr, eigvals })
 }
 //}}}
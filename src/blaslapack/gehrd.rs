@@ -0,0 +1,201 @@
+//! LAPACK `?gehrd` wrapper for the reduction of a general matrix to upper Hessenberg form.
+//!
+//! Provides the [`Gehrd`] trait, wrapping the LAPACK `?gehrd` routine that reduces a general square
+//! matrix A to upper Hessenberg form H by an orthogonal (unitary) similarity transformation
+//! A = Q H Q^H. Q is returned in compact Householder form and expanded by
+//! [`Orghr`](super::orghr::Orghr). The Hessenberg form is the first stage of the QR algorithm
+//! used by the eigenvalue and Schur drivers, and is useful in its own right for repeated shifted
+//! solves since it is preserved by diagonal shifts.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::backend;
+use super::orghr::{self, Orghr};
+use crate::common::Complex;
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors returned by the [`Gehrd`] LAPACK wrapper.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// LAPACK returned a non-zero info code indicating an invalid argument.
+    #[error("Error in gehrd, exited with code {0}")]
+    LapackError(i32),
+}
+//}}}
+
+//{{{ trait: Gehrd
+/// Trait for types that support the reduction of a general matrix to upper Hessenberg form.
+#[allow(clippy::too_many_arguments)]
+pub trait Gehrd: Copy {
+    /// Reduces rows and columns `ilo..=ihi` (1-based) of a general matrix to upper Hessenberg
+    /// form, storing the Householder reflectors below the first subdiagonal and in `tau`.
+    fn gehrd(
+        n: i32,
+        ilo: i32,
+        ihi: i32,
+        a: &mut [Self],
+        lda: i32,
+        tau: &mut [Self],
+        work: &mut [Self],
+        lwork: i32,
+    ) -> Result<(), Error>;
+}
+//}}}
+//{{{ impl: Gehrd for f64
+impl Gehrd for f64 {
+    #[inline]
+    fn gehrd(
+        n: i32,
+        ilo: i32,
+        ihi: i32,
+        a: &mut [Self],
+        lda: i32,
+        tau: &mut [Self],
+        work: &mut [Self],
+        lwork: i32,
+    ) -> Result<(), Error> {
+        let info =
+            backend::with_current(|backend| backend.dgehrd(n, ilo, ihi, a, lda, tau, work, lwork));
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ impl: Gehrd for f32
+impl Gehrd for f32 {
+    #[inline]
+    fn gehrd(
+        n: i32,
+        ilo: i32,
+        ihi: i32,
+        a: &mut [Self],
+        lda: i32,
+        tau: &mut [Self],
+        work: &mut [Self],
+        lwork: i32,
+    ) -> Result<(), Error> {
+        let info =
+            backend::with_current(|backend| backend.sgehrd(n, ilo, ihi, a, lda, tau, work, lwork));
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ impl: Gehrd for Complex<f64>
+impl Gehrd for Complex<f64> {
+    #[inline]
+    fn gehrd(
+        n: i32,
+        ilo: i32,
+        ihi: i32,
+        a: &mut [Self],
+        lda: i32,
+        tau: &mut [Self],
+        work: &mut [Self],
+        lwork: i32,
+    ) -> Result<(), Error> {
+        let info =
+            backend::with_current(|backend| backend.zgehrd(n, ilo, ihi, a, lda, tau, work, lwork));
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ impl: Gehrd for Complex<f32>
+impl Gehrd for Complex<f32> {
+    #[inline]
+    fn gehrd(
+        n: i32,
+        ilo: i32,
+        ihi: i32,
+        a: &mut [Self],
+        lda: i32,
+        tau: &mut [Self],
+        work: &mut [Self],
+        lwork: i32,
+    ) -> Result<(), Error> {
+        let info =
+            backend::with_current(|backend| backend.cgehrd(n, ilo, ihi, a, lda, tau, work, lwork));
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ struct: HessenbergRaw
+pub(crate) struct HessenbergRaw<T> {
+    pub q_data: Vec<T>,
+    pub h_data: Vec<T>,
+}
+//}}}
+//{{{ enum: HessenbergRawError
+#[derive(Error, Debug)]
+pub enum HessenbergRawError {
+    #[error(transparent)]
+    Gehrd(#[from] Error),
+    #[error(transparent)]
+    Orghr(#[from] orghr::Error),
+}
+//}}}
+//{{{ fun: hessenberg_raw
+/// Shared GEHRD + ORGHR algorithm. Consumes the cloned n×n matrix data; returns raw Q/H buffers.
+pub(crate) fn hessenberg_raw<T>(
+    mut a_data: Vec<T>,
+    n: usize,
+) -> Result<HessenbergRaw<T>, HessenbergRawError>
+where
+    T: Gehrd + Orghr + crate::common::Zero + crate::common::Field + Copy + super::common::AsI32,
+{
+    if n == 0 {
+        return Ok(HessenbergRaw {
+            q_data: Vec::new(),
+            h_data: Vec::new(),
+        });
+    }
+    let ld = n as i32;
+    let mut tau = vec![T::zero(); n.max(2) - 1];
+
+    let mut work = vec![T::zero(); 1];
+    T::gehrd(ld, 1, ld, &mut a_data, ld, &mut tau, &mut work, -1)?;
+    let lwork = work[0].as_i32().max(1);
+    let mut work = vec![T::zero(); lwork as usize];
+    T::gehrd(ld, 1, ld, &mut a_data, ld, &mut tau, &mut work, lwork)?;
+
+    // H is the upper Hessenberg part; the reflectors below the subdiagonal are dropped.
+    let mut h_data = vec![T::zero(); n * n];
+    for j in 0..n {
+        for i in 0..n.min(j + 2) {
+            h_data[i + j * n] = a_data[i + j * n];
+        }
+    }
+
+    let mut work = vec![T::zero(); 1];
+    T::orghr(ld, 1, ld, &mut a_data, ld, &tau, &mut work, -1)?;
+    let lwork = work[0].as_i32().max(1);
+    let mut work = vec![T::zero(); lwork as usize];
+    T::orghr(ld, 1, ld, &mut a_data, ld, &tau, &mut work, lwork)?;
+
+    Ok(HessenbergRaw {
+        q_data: a_data,
+        h_data,
+    })
+}
+//}}}
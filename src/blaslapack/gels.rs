@@ -0,0 +1,252 @@
+//! LAPACK `dgels`/`sgels` wrapper for least-squares solves of full-rank systems.
+//!
+//! Provides the [`Gels`] trait, wrapping the LAPACK `?gels` routine that solves overdetermined
+//! systems in the least-squares sense and underdetermined systems in the minimum-norm sense using
+//! a QR or LQ factorisation of A. The shared raw helper used by `DMatrix::lstsq()` and
+//! `SMatrix::lstsq()` dispatches to `?gels`, or to the rank-revealing [`Gelsd`] driver when a
+//! singular value cutoff is supplied.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::backend;
+use super::gelsd::Gelsd;
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors returned by the [`Gels`] LAPACK wrapper.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// LAPACK returned a non-zero info code indicating an invalid argument or a coefficient matrix
+    /// that is not of full rank.
+    #[error("Error in gels, exited with code {0}")]
+    LapackError(i32),
+}
+//}}}
+
+//{{{ trait: Gels
+/// Trait for types that support least-squares solves via QR or LQ factorisation.
+#[allow(clippy::too_many_arguments)]
+pub trait Gels: Copy {
+    /// Solves min ‖B − A X‖₂ (or the minimum-norm problem when M < N) for a full-rank M-by-N
+    /// matrix A, overwriting A with its factorisation and B with X.
+    fn gels(
+        trans: u8, // 'N' solves with A, 'T' solves with Aᵀ
+        m: i32,
+        n: i32,
+        nrhs: i32,
+        a: &mut [Self],
+        lda: i32,
+        b: &mut [Self],
+        ldb: i32,
+        work: &mut [Self],
+        lwork: i32,
+    ) -> Result<(), Error>;
+}
+//}}}
+
+//{{{ impl: Gels for f64
+impl Gels for f64 {
+    #[inline]
+    fn gels(
+        trans: u8,
+        m: i32,
+        n: i32,
+        nrhs: i32,
+        a: &mut [Self],
+        lda: i32,
+        b: &mut [Self],
+        ldb: i32,
+        work: &mut [Self],
+        lwork: i32,
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| {
+            backend.dgels(trans, m, n, nrhs, a, lda, b, ldb, work, lwork)
+        });
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ impl: Gels for f32
+impl Gels for f32 {
+    #[inline]
+    fn gels(
+        trans: u8,
+        m: i32,
+        n: i32,
+        nrhs: i32,
+        a: &mut [Self],
+        lda: i32,
+        b: &mut [Self],
+        ldb: i32,
+        work: &mut [Self],
+        lwork: i32,
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| {
+            backend.sgels(trans, m, n, nrhs, a, lda, b, ldb, work, lwork)
+        });
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ struct: LstsqRaw
+pub(crate) struct LstsqRaw<T> {
+    pub x_data: Vec<T>,
+    pub residuals: Vec<T>,
+    pub rank: usize,
+    pub s: Vec<T>,
+}
+//}}}
+//{{{ enum: LstsqRawError
+#[derive(Error, Debug)]
+pub enum LstsqRawError {
+    #[error(transparent)]
+    Gels(#[from] Error),
+    #[error(transparent)]
+    Gelsd(#[from] super::gelsd::Error),
+}
+//}}}
+//{{{ fun: lstsq_raw
+/// Shared least-squares algorithm. Consumes the cloned n×m matrix data and n×nrhs right-hand
+/// side; returns the raw m×nrhs solution together with the residuals, rank and singular values.
+///
+/// With `rcond` set to `None` the system is assumed to be of full rank and solved with GELS, in
+/// which case the rank is `min(n, m)` and no singular values are computed. With `Some(rcond)` the
+/// system is solved with GELSD, treating singular values no larger than `rcond * σ_max` as zero.
+/// The residual sum of squares of each column is only returned for overdetermined systems of full
+/// column rank and is empty otherwise.
+pub(crate) fn lstsq_raw<T>(
+    a_data: Vec<T>,
+    b_data: Vec<T>,
+    n: usize,
+    m: usize,
+    nrhs: usize,
+    rcond: Option<T>,
+) -> Result<LstsqRaw<T>, LstsqRawError>
+where
+    T: Gels + Gelsd + crate::common::Zero + crate::common::Field + Copy + super::common::AsI32,
+{
+    let mut a_data = a_data;
+    let k = n.min(m);
+    let lda = n.max(1);
+    let ldb = n.max(m).max(1);
+
+    // B must have room for the m×nrhs solution when the system is underdetermined
+    let mut b = vec![T::zero(); ldb * nrhs];
+    for j in 0..nrhs {
+        b[j * ldb..j * ldb + n].copy_from_slice(&b_data[j * n..(j + 1) * n]);
+    }
+
+    let mut work = vec![T::zero(); 1];
+    let (rank, s) = match rcond {
+        None => {
+            T::gels(
+                b'N',
+                n as i32,
+                m as i32,
+                nrhs as i32,
+                &mut a_data,
+                lda as i32,
+                &mut b,
+                ldb as i32,
+                &mut work,
+                -1,
+            )?;
+
+            let lwork = work[0].as_i32();
+            let mut work = vec![T::zero(); lwork as usize];
+            T::gels(
+                b'N',
+                n as i32,
+                m as i32,
+                nrhs as i32,
+                &mut a_data,
+                lda as i32,
+                &mut b,
+                ldb as i32,
+                &mut work,
+                lwork,
+            )?;
+            (k, Vec::new())
+        }
+        Some(rcond) => {
+            let mut s = vec![T::zero(); k];
+            let mut rank = 0;
+            let mut iwork = vec![0; 1];
+            T::gelsd(
+                n as i32,
+                m as i32,
+                nrhs as i32,
+                &mut a_data,
+                lda as i32,
+                &mut b,
+                ldb as i32,
+                &mut s,
+                rcond,
+                &mut rank,
+                &mut work,
+                -1,
+                &mut iwork,
+            )?;
+
+            let lwork = work[0].as_i32();
+            let liwork = iwork[0].max(1);
+            let mut work = vec![T::zero(); lwork as usize];
+            let mut iwork = vec![0; liwork as usize];
+            T::gelsd(
+                n as i32,
+                m as i32,
+                nrhs as i32,
+                &mut a_data,
+                lda as i32,
+                &mut b,
+                ldb as i32,
+                &mut s,
+                rcond,
+                &mut rank,
+                &mut work,
+                lwork,
+                &mut iwork,
+            )?;
+            (rank as usize, s)
+        }
+    };
+
+    // Rows m..n of B hold the components of the residual for full-rank overdetermined systems
+    let residuals = if n > m && rank == m {
+        (0..nrhs)
+            .map(|j| {
+                b[j * ldb + m..j * ldb + n]
+                    .iter()
+                    .fold(T::zero(), |acc, &r| acc + r * r)
+            })
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    let mut x_data = Vec::with_capacity(m * nrhs);
+    for j in 0..nrhs {
+        x_data.extend_from_slice(&b[j * ldb..j * ldb + m]);
+    }
+    Ok(LstsqRaw {
+        x_data,
+        residuals,
+        rank,
+        s,
+    })
+}
+//}}}
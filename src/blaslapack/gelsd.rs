@@ -0,0 +1,114 @@
+//! LAPACK `dgelsd`/`sgelsd` wrapper for rank-revealing least-squares solves.
+//!
+//! Provides the [`Gelsd`] trait, wrapping the LAPACK `?gelsd` routine that computes the
+//! minimum-norm solution of min ‖B − A X‖₂ for a possibly rank-deficient m×n matrix A using the
+//! singular value decomposition. Singular values no larger than `rcond` times the largest are
+//! treated as zero, which determines the effective rank returned alongside the solution. This is
+//! the driver behind the rank-revealing `lstsq_svd()` methods.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::backend;
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors returned by the [`Gelsd`] LAPACK wrapper.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// LAPACK returned a non-zero info code indicating an invalid argument or that the SVD
+    /// iteration did not converge.
+    #[error("Error in gelsd, exited with code {0}")]
+    LapackError(i32),
+}
+//}}}
+
+//{{{ trait: Gelsd
+/// Trait for types that support minimum-norm least-squares solves via the SVD.
+#[allow(clippy::too_many_arguments)]
+pub trait Gelsd: Copy {
+    /// Solves min ‖B − A X‖₂ for a general M-by-N matrix A, overwriting A and B with X.
+    fn gelsd(
+        m: i32,
+        n: i32,
+        nrhs: i32,
+        a: &mut [Self],
+        lda: i32,
+        b: &mut [Self],
+        ldb: i32,
+        s: &mut [Self], // Contains the singular values in descending order on exit
+        rcond: Self,    // Negative values select machine precision
+        rank: &mut i32,
+        work: &mut [Self],
+        lwork: i32,
+        iwork: &mut [i32],
+    ) -> Result<(), Error>;
+}
+//}}}
+
+//{{{ impl: Gelsd for f64
+impl Gelsd for f64 {
+    #[inline]
+    fn gelsd(
+        m: i32,
+        n: i32,
+        nrhs: i32,
+        a: &mut [Self],
+        lda: i32,
+        b: &mut [Self],
+        ldb: i32,
+        s: &mut [Self],
+        rcond: Self,
+        rank: &mut i32,
+        work: &mut [Self],
+        lwork: i32,
+        iwork: &mut [i32],
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| {
+            backend.dgelsd(
+                m, n, nrhs, a, lda, b, ldb, s, rcond, rank, work, lwork, iwork,
+            )
+        });
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ impl: Gelsd for f32
+impl Gelsd for f32 {
+    #[inline]
+    fn gelsd(
+        m: i32,
+        n: i32,
+        nrhs: i32,
+        a: &mut [Self],
+        lda: i32,
+        b: &mut [Self],
+        ldb: i32,
+        s: &mut [Self],
+        rcond: Self,
+        rank: &mut i32,
+        work: &mut [Self],
+        lwork: i32,
+        iwork: &mut [i32],
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| {
+            backend.sgelsd(
+                m, n, nrhs, a, lda, b, ldb, s, rcond, rank, work, lwork, iwork,
+            )
+        });
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
@@ -1,14 +1,17 @@
-//! BLAS `dgemm`/`sgemm` wrapper for general matrix–matrix multiplication.
+//! BLAS `?gemm` wrapper for general matrix–matrix multiplication.
 //!
 //! Provides the [`Gemm`] trait, wrapping the BLAS Level-3 `?gemm` routine
 //! (C ← α · op(A) · op(B) + β · C). Parameters mirror the BLAS interface: transpose flags for
-//! A and B, leading dimensions, and scaling scalars α and β. Implementations for `f64` (via
-//! `cblas_dgemm`) and `f32` (via `cblas_sgemm`) dispatch to the system BLAS library. All
-//! matrices are assumed to be in column-major order.
+//! A and B, leading dimensions, and scaling scalars α and β. Implementations for `f64`, `f32`,
+//! `Complex<f64>` and `Complex<f32>` dispatch to `?gemm` on the current
+//! [`Backend`](super::backend::Backend); integer types use a naive triple loop. All matrices are
+//! assumed to be in column-major order.
 //--------------------------------------------------------------------------------------------------
 
 //{{{ crate imports
+use super::backend;
 use crate::apply_for_all_integer_types;
+use crate::common::{Complex, Transpose};
 //}}}
 //{{{ std imports
 //}}}
@@ -47,8 +50,8 @@ pub trait Gemm: Copy {
     /// - `c`: the output matrix
     /// - `ldc`: the leading dimension of matrix `c`
     fn gemm(
-        tr1: cblas::Transpose,
-        tr2: cblas::Transpose,
+        tr1: Transpose,
+        tr2: Transpose,
         m: i32,
         n: i32,
         k: i32,
@@ -68,8 +71,8 @@ pub trait Gemm: Copy {
 impl Gemm for f64 {
     #[inline]
     fn gemm(
-        tr1: cblas::Transpose,
-        tr2: cblas::Transpose,
+        tr1: Transpose,
+        tr2: Transpose,
         m: i32,
         n: i32,
         k: i32,
@@ -82,24 +85,9 @@ impl Gemm for f64 {
         c: &mut [Self],
         ldc: i32,
     ) {
-        unsafe {
-            cblas::dgemm(
-                cblas::Layout::ColumnMajor,
-                tr1,
-                tr2,
-                m,
-                n,
-                k,
-                alpha,
-                a,
-                lda,
-                b,
-                ldb,
-                beta,
-                c,
-                ldc,
-            )
-        }
+        backend::with_current(|backend| {
+            backend.dgemm(tr1, tr2, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+        });
     }
 }
 
@@ -108,8 +96,8 @@ impl Gemm for f64 {
 impl Gemm for f32 {
     #[inline]
     fn gemm(
-        tr1: cblas::Transpose,
-        tr2: cblas::Transpose,
+        tr1: Transpose,
+        tr2: Transpose,
         m: i32,
         n: i32,
         k: i32,
@@ -122,24 +110,59 @@ impl Gemm for f32 {
         c: &mut [Self],
         ldc: i32,
     ) {
-        unsafe {
-            cblas::sgemm(
-                cblas::Layout::ColumnMajor,
-                tr1,
-                tr2,
-                m,
-                n,
-                k,
-                alpha,
-                a,
-                lda,
-                b,
-                ldb,
-                beta,
-                c,
-                ldc,
-            )
-        }
+        backend::with_current(|backend| {
+            backend.sgemm(tr1, tr2, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+        });
+    }
+}
+
+//}}}
+//{{{ impl: Gemm for Complex<f64>
+impl Gemm for Complex<f64> {
+    #[inline]
+    fn gemm(
+        tr1: Transpose,
+        tr2: Transpose,
+        m: i32,
+        n: i32,
+        k: i32,
+        alpha: Self,
+        a: &[Self],
+        lda: i32,
+        b: &[Self],
+        ldb: i32,
+        beta: Self,
+        c: &mut [Self],
+        ldc: i32,
+    ) {
+        backend::with_current(|backend| {
+            backend.zgemm(tr1, tr2, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+        });
+    }
+}
+
+//}}}
+//{{{ impl: Gemm for Complex<f32>
+impl Gemm for Complex<f32> {
+    #[inline]
+    fn gemm(
+        tr1: Transpose,
+        tr2: Transpose,
+        m: i32,
+        n: i32,
+        k: i32,
+        alpha: Self,
+        a: &[Self],
+        lda: i32,
+        b: &[Self],
+        ldb: i32,
+        beta: Self,
+        c: &mut [Self],
+        ldc: i32,
+    ) {
+        backend::with_current(|backend| {
+            backend.cgemm(tr1, tr2, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+        });
     }
 }
 
@@ -150,8 +173,8 @@ macro_rules! impl_naive_gemm {
         impl Gemm for $t {
             #[inline]
             fn gemm(
-                _tr1: cblas::Transpose,
-                _tr2: cblas::Transpose,
+                _tr1: Transpose,
+                _tr2: Transpose,
                 m: i32,
                 n: i32,
                 k: i32,
@@ -212,7 +235,7 @@ pub(crate) fn matmul_dispatch<T>(
 {
     if n == 1 {
         T::gemv(
-            cblas::Transpose::None,
+            Transpose::NoTrans,
             m as i32,
             k as i32,
             T::one(),
@@ -226,7 +249,7 @@ pub(crate) fn matmul_dispatch<T>(
         );
     } else if m == 1 {
         T::gemv(
-            cblas::Transpose::Ordinary,
+            Transpose::Trans,
             k as i32,
             n as i32,
             T::one(),
@@ -240,8 +263,8 @@ pub(crate) fn matmul_dispatch<T>(
         );
     } else {
         T::gemm(
-            cblas::Transpose::None,
-            cblas::Transpose::None,
+            Transpose::NoTrans,
+            Transpose::NoTrans,
             m as i32,
             n as i32,
             k as i32,
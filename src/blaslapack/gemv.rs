@@ -1,14 +1,16 @@
-//! BLAS `dgemv`/`sgemv` wrapper for general matrix–vector multiplication.
+//! BLAS `?gemv` wrapper for general matrix–vector multiplication.
 //!
 //! Provides the [`Gemv`] trait, wrapping the BLAS Level-2 `?gemv` routine
 //! (y ← α · op(A) · x + β · y). Used by the [`matmul`] modules to accelerate the common case
 //! where one operand is effectively a vector (one row or one column), avoiding the overhead of a
-//! full Level-3 `gemm` call. Implementations for `f64` and `f32` dispatch to the system BLAS
-//! library.
+//! full Level-3 `gemm` call. Implementations for `f64`, `f32`, `Complex<f64>` and `Complex<f32>`
+//! dispatch to `?gemv` on the current [`Backend`](super::backend::Backend).
 //--------------------------------------------------------------------------------------------------
 
 //{{{ crate imports
+use super::backend;
 use crate::apply_for_all_integer_types;
+use crate::common::{Complex, Transpose};
 //}}}
 //{{{ std imports
 //}}}
@@ -41,7 +43,7 @@ pub trait Gemv: Copy {
     /// - `y` is the output vector.
     /// - `incy` is the increment for the elements of `y`.
     fn gemv(
-        tr: cblas::Transpose,
+        tr: Transpose,
         m: i32,
         k: i32,
         alpha: Self,
@@ -60,7 +62,7 @@ pub trait Gemv: Copy {
 impl Gemv for f64 {
     #[inline]
     fn gemv(
-        tr: cblas::Transpose,
+        tr: Transpose,
         m: i32,
         k: i32,
         alpha: Self,
@@ -72,22 +74,9 @@ impl Gemv for f64 {
         y: &mut [Self],
         incy: i32,
     ) {
-        unsafe {
-            cblas::dgemv(
-                cblas::Layout::ColumnMajor,
-                tr,
-                m,
-                k,
-                alpha,
-                a,
-                lda,
-                x,
-                incx,
-                beta,
-                y,
-                incy,
-            )
-        }
+        backend::with_current(|backend| {
+            backend.dgemv(tr, m, k, alpha, a, lda, x, incx, beta, y, incy)
+        });
     }
 }
 
@@ -96,7 +85,7 @@ impl Gemv for f64 {
 impl Gemv for f32 {
     #[inline]
     fn gemv(
-        tr: cblas::Transpose,
+        tr: Transpose,
         m: i32,
         k: i32,
         alpha: Self,
@@ -108,22 +97,55 @@ impl Gemv for f32 {
         y: &mut [Self],
         incy: i32,
     ) {
-        unsafe {
-            cblas::sgemv(
-                cblas::Layout::ColumnMajor,
-                tr,
-                m,
-                k,
-                alpha,
-                a,
-                lda,
-                x,
-                incx,
-                beta,
-                y,
-                incy,
-            )
-        }
+        backend::with_current(|backend| {
+            backend.sgemv(tr, m, k, alpha, a, lda, x, incx, beta, y, incy)
+        });
+    }
+}
+
+//}}}
+//{{{ impl: Gemv for Complex<f64>
+impl Gemv for Complex<f64> {
+    #[inline]
+    fn gemv(
+        tr: Transpose,
+        m: i32,
+        k: i32,
+        alpha: Self,
+        a: &[Self],
+        lda: i32,
+        x: &[Self],
+        incx: i32,
+        beta: Self,
+        y: &mut [Self],
+        incy: i32,
+    ) {
+        backend::with_current(|backend| {
+            backend.zgemv(tr, m, k, alpha, a, lda, x, incx, beta, y, incy)
+        });
+    }
+}
+
+//}}}
+//{{{ impl: Gemv for Complex<f32>
+impl Gemv for Complex<f32> {
+    #[inline]
+    fn gemv(
+        tr: Transpose,
+        m: i32,
+        k: i32,
+        alpha: Self,
+        a: &[Self],
+        lda: i32,
+        x: &[Self],
+        incx: i32,
+        beta: Self,
+        y: &mut [Self],
+        incy: i32,
+    ) {
+        backend::with_current(|backend| {
+            backend.cgemv(tr, m, k, alpha, a, lda, x, incx, beta, y, incy)
+        });
     }
 }
 
@@ -134,7 +156,7 @@ macro_rules! impl_naive_gemv {
         impl Gemv for $t {
             #[inline]
             fn gemv(
-                tr: cblas::Transpose,
+                tr: Transpose,
                 m: i32,
                 k: i32,
                 alpha: Self,
@@ -149,7 +171,7 @@ macro_rules! impl_naive_gemv {
                 let get_a = |i, j| a[i as usize + (j as usize * lda as usize)];
 
                 match tr {
-                    cblas::Transpose::None => {
+                    Transpose::NoTrans => {
                         for i in 0..m {
                             let mut sum = Self::default();
 
@@ -160,7 +182,7 @@ macro_rules! impl_naive_gemv {
                             y[(i * incy) as usize] = alpha * sum + beta * y[(i * incy) as usize];
                         }
                     }
-                    cblas::Transpose::Ordinary => {
+                    Transpose::Trans => {
                         for i in 0..m {
                             let mut sum = Self::default();
 
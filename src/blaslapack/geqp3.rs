@@ -0,0 +1,270 @@
+//! LAPACK `?geqp3` wrapper for QR factorisation with column pivoting.
+//!
+//! Provides the [`Geqp3`] trait, wrapping the LAPACK `?geqp3` routine that computes A P = Q R with
+//! a column permutation P chosen so that the diagonal of R is non-increasing in modulus. This makes
+//! the factorisation rank-revealing: the numerical rank is the number of diagonal entries of R that
+//! are large relative to the first. Q is expanded explicitly with [`Orgqr`]. Implementations for
+//! `f64`, `f32`, `Complex<f64>` and `Complex<f32>` call the Fortran LAPACK ABI via the `lapack`
+//! crate.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::backend;
+use super::common::AsI32;
+use super::orgqr::Orgqr;
+use crate::common::{Complex, ComplexField, Zero};
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors returned by the [`Geqp3`] LAPACK wrapper.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// LAPACK returned a non-zero info code indicating an invalid argument.
+    #[error("Error in geqp3, exited with code {0}")]
+    LapackError(i32),
+}
+//}}}
+
+//{{{ trait: Geqp3
+/// Trait for types that support QR factorisation with column pivoting.
+#[allow(clippy::too_many_arguments)]
+pub trait Geqp3: ComplexField {
+    /// Computes A P = Q R for an M-by-N matrix A, storing R and the reflectors in-place.
+    ///
+    /// On entry, a zero in `jpvt[j]` marks column `j` as free to be pivoted. On exit, `jpvt[j]` is
+    /// the 1-based index of the original column that was moved to position `j`.
+    fn geqp3(
+        m: i32,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        jpvt: &mut [i32],
+        tau: &mut [Self],
+        work: &mut [Self],
+        lwork: i32,
+        rwork: &mut [Self::Real], // At least 2n for complex types, unused for real types
+    ) -> Result<(), Error>;
+}
+//}}}
+
+//{{{ impl: Geqp3 for f64
+impl Geqp3 for f64 {
+    #[inline]
+    fn geqp3(
+        m: i32,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        jpvt: &mut [i32],
+        tau: &mut [Self],
+        work: &mut [Self],
+        lwork: i32,
+        _rwork: &mut [Self],
+    ) -> Result<(), Error> {
+        let info =
+            backend::with_current(|backend| backend.dgeqp3(m, n, a, lda, jpvt, tau, work, lwork));
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+//{{{ impl: Geqp3 for f32
+impl Geqp3 for f32 {
+    #[inline]
+    fn geqp3(
+        m: i32,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        jpvt: &mut [i32],
+        tau: &mut [Self],
+        work: &mut [Self],
+        lwork: i32,
+        _rwork: &mut [Self],
+    ) -> Result<(), Error> {
+        let info =
+            backend::with_current(|backend| backend.sgeqp3(m, n, a, lda, jpvt, tau, work, lwork));
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+//{{{ impl: Geqp3 for Complex<f64>
+impl Geqp3 for Complex<f64> {
+    #[inline]
+    fn geqp3(
+        m: i32,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        jpvt: &mut [i32],
+        tau: &mut [Self],
+        work: &mut [Self],
+        lwork: i32,
+        rwork: &mut [f64],
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| {
+            backend.zgeqp3(m, n, a, lda, jpvt, tau, work, lwork, rwork)
+        });
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+//{{{ impl: Geqp3 for Complex<f32>
+impl Geqp3 for Complex<f32> {
+    #[inline]
+    fn geqp3(
+        m: i32,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        jpvt: &mut [i32],
+        tau: &mut [Self],
+        work: &mut [Self],
+        lwork: i32,
+        rwork: &mut [f32],
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| {
+            backend.cgeqp3(m, n, a, lda, jpvt, tau, work, lwork, rwork)
+        });
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ struct: QrPivotedRaw
+pub(crate) struct QrPivotedRaw<T> {
+    pub q_data: Vec<T>,
+    pub r_data: Vec<T>,
+    pub perm: Vec<usize>,
+    pub rank: usize,
+}
+//}}}
+//{{{ enum: QrPivotedRawError
+/// Errors returned by [`qr_pivoted_raw`].
+#[derive(Error, Debug)]
+pub enum QrPivotedRawError {
+    /// LAPACK `geqp3` rejected its arguments.
+    #[error(transparent)]
+    Geqp3(#[from] Error),
+    /// LAPACK `orgqr` rejected its arguments.
+    #[error(transparent)]
+    Orgqr(#[from] super::orgqr::Error),
+}
+//}}}
+//{{{ fun: qr_pivoted_raw
+/// Shared GEQP3 + ORGQR algorithm. Consumes the cloned n×m matrix data and returns the n×n Q, the
+/// n×m upper-trapezoidal R, the 0-based column permutation and the numerical rank.
+///
+/// The rank counts the diagonal entries of R whose modulus exceeds `tol · |R₀₀|`.
+pub(crate) fn qr_pivoted_raw<T>(
+    mut a_data: Vec<T>,
+    n: usize,
+    m: usize,
+    tol: T::Real,
+) -> Result<QrPivotedRaw<T>, QrPivotedRawError>
+where
+    T: Geqp3 + Orgqr + AsI32,
+{
+    let k = n.min(m);
+    let lda = n.max(1) as i32;
+    let mut jpvt = vec![0; m];
+    let mut tau = vec![T::zero(); k];
+    let mut rwork = vec![T::Real::zero(); 2 * m];
+
+    let mut work = vec![T::zero(); 1];
+    T::geqp3(
+        n as i32,
+        m as i32,
+        &mut a_data,
+        lda,
+        &mut jpvt,
+        &mut tau,
+        &mut work,
+        -1,
+        &mut rwork,
+    )?;
+
+    let lwork = work[0].as_i32().max(1);
+    let mut work = vec![T::zero(); lwork as usize];
+    T::geqp3(
+        n as i32,
+        m as i32,
+        &mut a_data,
+        lda,
+        &mut jpvt,
+        &mut tau,
+        &mut work,
+        lwork,
+        &mut rwork,
+    )?;
+
+    let mut r_data = vec![T::zero(); n * m];
+    for j in 0..m {
+        for i in 0..(j + 1).min(n) {
+            r_data[i + j * n] = a_data[i + j * n];
+        }
+    }
+
+    let rank = if k == 0 {
+        0
+    } else {
+        let threshold = tol * r_data[0].modulus();
+        (0..k)
+            .take_while(|&i| r_data[i + i * n].modulus() > threshold)
+            .count()
+    };
+
+    let mut q_data = vec![T::zero(); n * n];
+    q_data[..n * k].copy_from_slice(&a_data[..n * k]);
+    if n > 0 {
+        let mut work = vec![T::zero(); 1];
+        T::orgqr(
+            n as i32,
+            n as i32,
+            k as i32,
+            &mut q_data,
+            lda,
+            &tau,
+            &mut work,
+            -1,
+        )?;
+        let lwork = work[0].as_i32().max(1);
+        let mut work = vec![T::zero(); lwork as usize];
+        T::orgqr(
+            n as i32,
+            n as i32,
+            k as i32,
+            &mut q_data,
+            lda,
+            &tau,
+            &mut work,
+            lwork,
+        )?;
+    }
+
+    let perm = jpvt.iter().map(|&p| (p - 1) as usize).collect();
+    Ok(QrPivotedRaw {
+        q_data,
+        r_data,
+        perm,
+        rank,
+    })
+}
+//}}}
@@ -1,13 +1,15 @@
-//! LAPACK `dgeqrf`/`sgeqrf` wrapper for QR factorisation via Householder reflectors.
+//! LAPACK `?geqrf` wrapper for QR factorisation via Householder reflectors.
 //!
 //! Provides the [`Geqrf`] trait, wrapping the LAPACK `?geqrf` routine. On return, the upper
 //! triangle of the input contains R and the elementary reflectors encoding Q are stored in the
 //! lower triangle together with the `tau` array. A workspace query (lwork = −1) is supported to
-//! obtain the optimal workspace size before the main computation. Implementations for `f64` and
-//! `f32`.
+//! obtain the optimal workspace size before the main computation. Implementations for `f64`,
+//! `f32`, `Complex<f64>` and `Complex<f32>`.
 //--------------------------------------------------------------------------------------------------
 
 //{{{ crate imports
+use super::backend;
+use crate::common::Complex;
 //}}}
 //{{{ std imports
 //}}}
@@ -53,10 +55,7 @@ impl Geqrf for f64 {
         work: &mut [Self],
         lwork: i32,
     ) -> Result<(), Error> {
-        let mut info = 0;
-        unsafe {
-            lapack::dgeqrf(m, n, a, lda, tau, work, lwork, &mut info);
-        }
+        let info = backend::with_current(|backend| backend.dgeqrf(m, n, a, lda, tau, work, lwork));
         if info != 0 {
             return Err(Error::LapackError(info));
         }
@@ -76,10 +75,49 @@ impl Geqrf for f32 {
         work: &mut [Self],
         lwork: i32,
     ) -> Result<(), Error> {
-        let mut info = 0;
-        unsafe {
-            lapack::sgeqrf(m, n, a, lda, tau, work, lwork, &mut info);
+        let info = backend::with_current(|backend| backend.sgeqrf(m, n, a, lda, tau, work, lwork));
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ impl: Geqrf for Complex<f64>
+impl Geqrf for Complex<f64> {
+    #[inline]
+    fn geqrf(
+        m: i32,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        tau: &mut [Self],
+        work: &mut [Self],
+        lwork: i32,
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| backend.zgeqrf(m, n, a, lda, tau, work, lwork));
+        if info != 0 {
+            return Err(Error::LapackError(info));
         }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ impl: Geqrf for Complex<f32>
+impl Geqrf for Complex<f32> {
+    #[inline]
+    fn geqrf(
+        m: i32,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        tau: &mut [Self],
+        work: &mut [Self],
+        lwork: i32,
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| backend.cgeqrf(m, n, a, lda, tau, work, lwork));
         if info != 0 {
             return Err(Error::LapackError(info));
         }
@@ -0,0 +1,556 @@
+//! LAPACK `?gesdd` wrapper for the singular value decomposition via divide and conquer.
+//!
+//! Provides the [`Gesdd`] trait, wrapping the LAPACK `?gesdd` routine that computes the singular
+//! values and, optionally, the left and right singular vectors of a general m×n matrix. The `jobz`
+//! flag selects full (`'A'`), thin (`'S'`) or no (`'N'`) singular vectors. The shared raw helper
+//! used by `DMatrix::svd()` and `SMatrix::svd()` calls `?gesdd` first and falls back to the
+//! QR-iteration driver [`Gesvd`] if divide and conquer fails to converge. The numerical rank, null
+//! space and range basis helpers count the singular values above a [`RankTolerance`] cutoff.
+//! [`SingularValues`] computes the singular values alone for real and complex types through the
+//! same path with `jobz = 'N'`.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::backend;
+use super::gesvd::Gesvd;
+use crate::common::{Complex, ComplexField, RankTolerance, Transpose, Zero};
+use crate::float::Float;
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors returned by the [`Gesdd`] LAPACK wrapper.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// LAPACK returned a non-zero info code indicating an invalid argument or that the
+    /// divide-and-conquer iteration did not converge.
+    #[error("Error in gesdd, exited with code {0}")]
+    LapackError(i32),
+}
+//}}}
+
+//{{{ trait: Gesdd
+/// Trait for types that support the singular value decomposition via divide and conquer.
+#[allow(clippy::too_many_arguments)]
+pub trait Gesdd: ComplexField {
+    /// Computes the SVD A = U Σ Vᴴ of a general M-by-N matrix A, overwriting A.
+    fn gesdd(
+        jobz: u8, // 'A' full U and Vᵀ, 'S' the first min(m, n) columns/rows, 'N' values only
+        m: i32,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        s: &mut [Self::Real], // Contains the singular values in descending order on exit
+        u: &mut [Self],
+        ldu: i32,
+        vt: &mut [Self],
+        ldvt: i32,
+        work: &mut [Self],
+        lwork: i32,
+        rwork: &mut [Self::Real], // LAPACK's LRWORK for complex types, unused for real types
+        iwork: &mut [i32],
+    ) -> Result<(), Error>;
+}
+//}}}
+
+//{{{ impl: Gesdd for f64
+impl Gesdd for f64 {
+    #[inline]
+    fn gesdd(
+        jobz: u8,
+        m: i32,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        s: &mut [Self],
+        u: &mut [Self],
+        ldu: i32,
+        vt: &mut [Self],
+        ldvt: i32,
+        work: &mut [Self],
+        lwork: i32,
+        _rwork: &mut [Self],
+        iwork: &mut [i32],
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| {
+            backend.dgesdd(jobz, m, n, a, lda, s, u, ldu, vt, ldvt, work, lwork, iwork)
+        });
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ impl: Gesdd for f32
+impl Gesdd for f32 {
+    #[inline]
+    fn gesdd(
+        jobz: u8,
+        m: i32,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        s: &mut [Self],
+        u: &mut [Self],
+        ldu: i32,
+        vt: &mut [Self],
+        ldvt: i32,
+        work: &mut [Self],
+        lwork: i32,
+        _rwork: &mut [Self],
+        iwork: &mut [i32],
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| {
+            backend.sgesdd(jobz, m, n, a, lda, s, u, ldu, vt, ldvt, work, lwork, iwork)
+        });
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ impl: Gesdd for Complex<f64>
+impl Gesdd for Complex<f64> {
+    #[inline]
+    fn gesdd(
+        jobz: u8,
+        m: i32,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        s: &mut [f64],
+        u: &mut [Self],
+        ldu: i32,
+        vt: &mut [Self],
+        ldvt: i32,
+        work: &mut [Self],
+        lwork: i32,
+        rwork: &mut [f64],
+        iwork: &mut [i32],
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| {
+            backend.zgesdd(
+                jobz, m, n, a, lda, s, u, ldu, vt, ldvt, work, lwork, rwork, iwork,
+            )
+        });
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ impl: Gesdd for Complex<f32>
+impl Gesdd for Complex<f32> {
+    #[inline]
+    fn gesdd(
+        jobz: u8,
+        m: i32,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        s: &mut [f32],
+        u: &mut [Self],
+        ldu: i32,
+        vt: &mut [Self],
+        ldvt: i32,
+        work: &mut [Self],
+        lwork: i32,
+        rwork: &mut [f32],
+        iwork: &mut [i32],
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| {
+            backend.cgesdd(
+                jobz, m, n, a, lda, s, u, ldu, vt, ldvt, work, lwork, rwork, iwork,
+            )
+        });
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ trait: SingularValues
+/// Trait for real and complex types whose singular values can be computed.
+pub trait SingularValues: ComplexField {
+    /// Returns the singular values of the n×m column-major matrix `a_data` in descending order.
+    fn singular_values(
+        a_data: Vec<Self>,
+        n: usize,
+        m: usize,
+    ) -> Result<Vec<Self::Real>, SvdRawError>;
+}
+//}}}
+//{{{ macro: impl_singular_values
+macro_rules! impl_singular_values {
+    ($t:ty) => {
+        impl SingularValues for $t {
+            #[inline]
+            fn singular_values(
+                a_data: Vec<Self>,
+                n: usize,
+                m: usize,
+            ) -> Result<Vec<Self::Real>, SvdRawError> {
+                Ok(svd_raw(a_data, n, m, b'N')?.s)
+            }
+        }
+    };
+}
+//}}}
+//{{{ collection: SingularValues implementations
+impl_singular_values!(f64);
+impl_singular_values!(f32);
+impl_singular_values!(Complex<f64>);
+impl_singular_values!(Complex<f32>);
+//}}}
+
+//{{{ struct: SvdRaw
+pub(crate) struct SvdRaw<T>
+where
+    T: ComplexField,
+{
+    pub u_data: Vec<T>,
+    pub s: Vec<T::Real>,
+    pub vt_data: Vec<T>,
+}
+//}}}
+//{{{ enum: SvdRawError
+#[derive(Error, Debug)]
+pub enum SvdRawError {
+    #[error(transparent)]
+    Gesdd(#[from] Error),
+    #[error(transparent)]
+    Gesvd(#[from] super::gesvd::Error),
+}
+//}}}
+//{{{ fun: svd_raw
+/// Shared GESDD algorithm with a GESVD fallback. Consumes the cloned matrix data; returns raw
+/// U/Σ/Vᵀ buffers.
+///
+/// `job` follows the LAPACK convention: `b'A'` returns U (n×n) and Vᵀ (m×m), `b'S'` returns the
+/// thin factors U (n×k) and Vᵀ (k×m) with k = min(n, m), and `b'N'` returns empty U/Vᵀ buffers.
+pub(crate) fn svd_raw<T>(
+    a_data: Vec<T>,
+    n: usize,
+    m: usize,
+    job: u8,
+) -> Result<SvdRaw<T>, SvdRawError>
+where
+    T: Gesdd + Gesvd + super::common::AsI32,
+{
+    let k = n.min(m);
+    let (ucols, vtrows) = match job {
+        b'A' => (n, m),
+        b'S' => (k, k),
+        _ => (0, 0),
+    };
+    let ldu = n.max(1);
+    let ldvt = vtrows.max(1);
+    let mut s = vec![T::Real::zero(); k];
+    let mut u = vec![T::zero(); (n * ucols).max(1)];
+    let mut vt = vec![T::zero(); (vtrows * m).max(1)];
+
+    let mut a_gesdd = a_data.clone();
+    // Real workspace of the complex drivers; ?gesvd needs less than the ?gesdd minimum
+    let rwork_len = match job {
+        b'N' => 7 * k,
+        _ => (5 * k * k + 5 * k).max(2 * n.max(m) * k + 2 * k * k + k),
+    };
+    let mut rwork = vec![T::Real::zero(); rwork_len.max(1)];
+    let mut iwork = vec![0; (8 * k).max(1)];
+    let mut work = vec![T::zero(); 1];
+    T::gesdd(
+        job,
+        n as i32,
+        m as i32,
+        &mut a_gesdd,
+        n.max(1) as i32,
+        &mut s,
+        &mut u,
+        ldu as i32,
+        &mut vt,
+        ldvt as i32,
+        &mut work,
+        -1,
+        &mut rwork,
+        &mut iwork,
+    )?;
+
+    let lwork = work[0].as_i32();
+    let mut work = vec![T::zero(); lwork as usize];
+    match T::gesdd(
+        job,
+        n as i32,
+        m as i32,
+        &mut a_gesdd,
+        n.max(1) as i32,
+        &mut s,
+        &mut u,
+        ldu as i32,
+        &mut vt,
+        ldvt as i32,
+        &mut work,
+        lwork,
+        &mut rwork,
+        &mut iwork,
+    ) {
+        Ok(()) => {}
+        // info > 0 signals a convergence failure, which the QR-iteration driver may recover from
+        Err(Error::LapackError(info)) if info > 0 => {
+            let mut a_gesvd = a_data;
+            let mut work = vec![T::zero(); 1];
+            T::gesvd(
+                job,
+                job,
+                n as i32,
+                m as i32,
+                &mut a_gesvd,
+                n.max(1) as i32,
+                &mut s,
+                &mut u,
+                ldu as i32,
+                &mut vt,
+                ldvt as i32,
+                &mut work,
+                -1,
+                &mut rwork,
+            )?;
+
+            let lwork = work[0].as_i32();
+            let mut work = vec![T::zero(); lwork as usize];
+            T::gesvd(
+                job,
+                job,
+                n as i32,
+                m as i32,
+                &mut a_gesvd,
+                n.max(1) as i32,
+                &mut s,
+                &mut u,
+                ldu as i32,
+                &mut vt,
+                ldvt as i32,
+                &mut work,
+                lwork,
+                &mut rwork,
+            )?;
+        }
+        Err(err) => return Err(err.into()),
+    }
+
+    u.truncate(n * ucols);
+    vt.truncate(vtrows * m);
+    Ok(SvdRaw {
+        u_data: u,
+        s,
+        vt_data: vt,
+    })
+}
+//}}}
+//{{{ fun: pinv_raw
+/// Shared SVD-based Moore–Penrose pseudo-inverse. Consumes the cloned n×m matrix data; returns
+/// the raw m×n pseudo-inverse.
+///
+/// Singular values no larger than `rcond * σ_max` are treated as zero.
+pub(crate) fn pinv_raw<T>(
+    a_data: Vec<T>,
+    n: usize,
+    m: usize,
+    rcond: T,
+) -> Result<Vec<T>, SvdRawError>
+where
+    T: Gesdd<Real = T>
+        + Gesvd<Real = T>
+        + super::gemm::Gemm
+        + crate::common::Zero
+        + crate::common::One
+        + crate::common::Field
+        + PartialOrd
+        + Copy
+        + super::common::AsI32,
+{
+    let k = n.min(m);
+    let mut pinv = vec![T::zero(); m * n];
+    if k == 0 {
+        return Ok(pinv);
+    }
+    let SvdRaw {
+        u_data,
+        s,
+        vt_data: mut sigma_inv_vt,
+    } = svd_raw(a_data, n, m, b'S')?;
+
+    // Scale the rows of Vᵀ by 1/σ, dropping the directions below the cutoff
+    let cutoff = rcond * s[0];
+    for (l, &sigma) in s.iter().enumerate() {
+        let scale = if sigma > cutoff {
+            T::one() / sigma
+        } else {
+            T::zero()
+        };
+        for j in 0..m {
+            sigma_inv_vt[l + j * k] *= scale;
+        }
+    }
+
+    // A⁺ = V Σ⁺ Uᵀ = (Σ⁺ Vᵀ)ᵀ Uᵀ
+    T::gemm(
+        Transpose::Trans,
+        Transpose::Trans,
+        m as i32,
+        n as i32,
+        k as i32,
+        T::one(),
+        &sigma_inv_vt,
+        k as i32,
+        &u_data,
+        n as i32,
+        T::zero(),
+        &mut pinv,
+        m as i32,
+    );
+    Ok(pinv)
+}
+//}}}
+//{{{ fun: rank_cutoff
+/// Singular-value cutoff of an n×m matrix with largest singular value `sigma_max`.
+fn rank_cutoff<T>(
+    sigma_max: T,
+    n: usize,
+    m: usize,
+    tol: RankTolerance<T>,
+) -> T
+where
+    T: Float + Copy,
+{
+    match tol {
+        RankTolerance::Epsilon => T::from_f64(n.max(m) as f64) * T::small() * sigma_max,
+        RankTolerance::Absolute(tol) => tol,
+        RankTolerance::Relative(rtol) => rtol * sigma_max,
+    }
+}
+//}}}
+//{{{ fun: numerical_rank
+/// Number of singular values, sorted in descending order, above the cutoff.
+fn numerical_rank<T>(
+    s: &[T],
+    n: usize,
+    m: usize,
+    tol: RankTolerance<T>,
+) -> usize
+where
+    T: Float + crate::common::Zero + crate::common::One + Copy,
+{
+    match s.first() {
+        Some(&sigma_max) => {
+            let cutoff = rank_cutoff(sigma_max, n, m, tol);
+            s.iter().take_while(|&&sigma| sigma > cutoff).count()
+        }
+        None => 0,
+    }
+}
+//}}}
+//{{{ fun: rank_raw
+/// Shared SVD-based numerical rank. Consumes the cloned n×m matrix data and computes the singular
+/// values only.
+pub(crate) fn rank_raw<T>(
+    a_data: Vec<T>,
+    n: usize,
+    m: usize,
+    tol: RankTolerance<T>,
+) -> Result<usize, SvdRawError>
+where
+    T: Gesdd<Real = T>
+        + Gesvd<Real = T>
+        + Float
+        + crate::common::Zero
+        + crate::common::One
+        + Copy
+        + super::common::AsI32,
+{
+    if n.min(m) == 0 {
+        return Ok(0);
+    }
+    let SvdRaw { s, .. } = svd_raw(a_data, n, m, b'N')?;
+    Ok(numerical_rank(&s, n, m, tol))
+}
+//}}}
+//{{{ fun: null_space_raw
+/// Shared SVD-based null space basis. Consumes the cloned n×m matrix data; returns the raw m×d
+/// matrix whose orthonormal columns are the right singular vectors beyond the numerical rank,
+/// together with the nullity d.
+pub(crate) fn null_space_raw<T>(
+    a_data: Vec<T>,
+    n: usize,
+    m: usize,
+    tol: RankTolerance<T>,
+) -> Result<(Vec<T>, usize), SvdRawError>
+where
+    T: Gesdd<Real = T>
+        + Gesvd<Real = T>
+        + Float
+        + crate::common::Zero
+        + crate::common::One
+        + Copy
+        + super::common::AsI32,
+{
+    if n.min(m) == 0 {
+        let mut eye = vec![T::zero(); m * m];
+        for i in 0..m {
+            eye[i + i * m] = T::one();
+        }
+        return Ok((eye, m));
+    }
+    let SvdRaw { s, vt_data, .. } = svd_raw(a_data, n, m, b'A')?;
+    let rank = numerical_rank(&s, n, m, tol);
+    let nullity = m - rank;
+
+    // Column c of the basis is row rank + c of the m×m Vᵀ
+    let mut basis = vec![T::zero(); m * nullity];
+    for c in 0..nullity {
+        for j in 0..m {
+            basis[j + c * m] = vt_data[(rank + c) + j * m];
+        }
+    }
+    Ok((basis, nullity))
+}
+//}}}
+//{{{ fun: orth_raw
+/// Shared SVD-based orthonormal range basis. Consumes the cloned n×m matrix data; returns the raw
+/// n×r matrix of the leading left singular vectors together with the numerical rank r.
+pub(crate) fn orth_raw<T>(
+    a_data: Vec<T>,
+    n: usize,
+    m: usize,
+    tol: RankTolerance<T>,
+) -> Result<(Vec<T>, usize), SvdRawError>
+where
+    T: Gesdd<Real = T>
+        + Gesvd<Real = T>
+        + Float
+        + crate::common::Zero
+        + crate::common::One
+        + Copy
+        + super::common::AsI32,
+{
+    if n.min(m) == 0 {
+        return Ok((Vec::new(), 0));
+    }
+    let SvdRaw { mut u_data, s, .. } = svd_raw(a_data, n, m, b'S')?;
+    let rank = numerical_rank(&s, n, m, tol);
+    u_data.truncate(n * rank);
+    Ok((u_data, rank))
+}
+//}}}
@@ -8,6 +8,7 @@
 //--------------------------------------------------------------------------------------------------
 
 //{{{ crate imports
+use super::backend;
 //}}}
 //{{{ std imports
 //}}}
@@ -54,10 +55,7 @@ impl Gesv for f64 {
         b: &mut [Self],
         ldb: i32,
     ) -> Result<(), Error> {
-        let mut info = 0;
-        unsafe {
-            lapack::dgesv(n, nrhs, a, lda, ipiv, b, ldb, &mut info);
-        }
+        let info = backend::with_current(|backend| backend.dgesv(n, nrhs, a, lda, ipiv, b, ldb));
         if info != 0 {
             return Err(Error::LapackError(info));
         }
@@ -78,10 +76,7 @@ impl Gesv for f32 {
         b: &mut [Self],
         ldb: i32,
     ) -> Result<(), Error> {
-        let mut info = 0;
-        unsafe {
-            lapack::sgesv(n, nrhs, a, lda, ipiv, b, ldb, &mut info);
-        }
+        let info = backend::with_current(|backend| backend.sgesv(n, nrhs, a, lda, ipiv, b, ldb));
         if info != 0 {
             return Err(Error::LapackError(info));
         }
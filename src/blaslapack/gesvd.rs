@@ -0,0 +1,178 @@
+//! LAPACK `?gesvd` wrapper for the singular value decomposition via QR iteration.
+//!
+//! Provides the [`Gesvd`] trait, wrapping the LAPACK `?gesvd` routine that computes the singular
+//! values and, optionally, the left and right singular vectors of a general m×n matrix. The
+//! `jobu`/`jobvt` flags select full, thin or no singular vectors. This is the slower but more
+//! robust fallback used by the SVD path when the divide-and-conquer driver [`Gesdd`] fails to
+//! converge.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::backend;
+use crate::common::{Complex, ComplexField};
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors returned by the [`Gesvd`] LAPACK wrapper.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// LAPACK returned a non-zero info code indicating an invalid argument or that the
+    /// bidiagonal QR iteration did not converge.
+    #[error("Error in gesvd, exited with code {0}")]
+    LapackError(i32),
+}
+//}}}
+
+//{{{ trait: Gesvd
+/// Trait for types that support the singular value decomposition via bidiagonal QR iteration.
+#[allow(clippy::too_many_arguments)]
+pub trait Gesvd: ComplexField {
+    /// Computes the SVD A = U Σ Vᴴ of a general M-by-N matrix A, overwriting A.
+    fn gesvd(
+        jobu: u8,  // 'A' all columns of U, 'S' the first min(m, n) columns, 'N' none
+        jobvt: u8, // 'A' all rows of Vᵀ, 'S' the first min(m, n) rows, 'N' none
+        m: i32,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        s: &mut [Self::Real], // Contains the singular values in descending order on exit
+        u: &mut [Self],
+        ldu: i32,
+        vt: &mut [Self],
+        ldvt: i32,
+        work: &mut [Self],
+        lwork: i32,
+        rwork: &mut [Self::Real], // At least 5 min(m, n) for complex types, unused for real types
+    ) -> Result<(), Error>;
+}
+//}}}
+
+//{{{ impl: Gesvd for f64
+impl Gesvd for f64 {
+    #[inline]
+    fn gesvd(
+        jobu: u8,
+        jobvt: u8,
+        m: i32,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        s: &mut [Self],
+        u: &mut [Self],
+        ldu: i32,
+        vt: &mut [Self],
+        ldvt: i32,
+        work: &mut [Self],
+        lwork: i32,
+        _rwork: &mut [Self],
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| {
+            backend.dgesvd(jobu, jobvt, m, n, a, lda, s, u, ldu, vt, ldvt, work, lwork)
+        });
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ impl: Gesvd for f32
+impl Gesvd for f32 {
+    #[inline]
+    fn gesvd(
+        jobu: u8,
+        jobvt: u8,
+        m: i32,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        s: &mut [Self],
+        u: &mut [Self],
+        ldu: i32,
+        vt: &mut [Self],
+        ldvt: i32,
+        work: &mut [Self],
+        lwork: i32,
+        _rwork: &mut [Self],
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| {
+            backend.sgesvd(jobu, jobvt, m, n, a, lda, s, u, ldu, vt, ldvt, work, lwork)
+        });
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ impl: Gesvd for Complex<f64>
+impl Gesvd for Complex<f64> {
+    #[inline]
+    fn gesvd(
+        jobu: u8,
+        jobvt: u8,
+        m: i32,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        s: &mut [f64],
+        u: &mut [Self],
+        ldu: i32,
+        vt: &mut [Self],
+        ldvt: i32,
+        work: &mut [Self],
+        lwork: i32,
+        rwork: &mut [f64],
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| {
+            backend.zgesvd(
+                jobu, jobvt, m, n, a, lda, s, u, ldu, vt, ldvt, work, lwork, rwork,
+            )
+        });
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ impl: Gesvd for Complex<f32>
+impl Gesvd for Complex<f32> {
+    #[inline]
+    fn gesvd(
+        jobu: u8,
+        jobvt: u8,
+        m: i32,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        s: &mut [f32],
+        u: &mut [Self],
+        ldu: i32,
+        vt: &mut [Self],
+        ldvt: i32,
+        work: &mut [Self],
+        lwork: i32,
+        rwork: &mut [f32],
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| {
+            backend.cgesvd(
+                jobu, jobvt, m, n, a, lda, s, u, ldu, vt, ldvt, work, lwork, rwork,
+            )
+        });
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
@@ -0,0 +1,448 @@
+//! LAPACK `?gesvx` wrapper for the expert driver for general linear systems.
+//!
+//! Provides the [`Gesvx`] trait, wrapping the LAPACK `?gesvx` routine that solves op(A) X = B with
+//! an LU factorisation like `?gesv`, but additionally equilibrates A by row and column scaling
+//! when that improves its conditioning, estimates the reciprocal condition number, refines the
+//! solution iteratively and returns componentwise forward and backward error bounds for each
+//! right-hand side. A solution is still returned when A is singular to working precision; this is
+//! reported through `info = n + 1` rather than as a failure. This is the LAPACK driver used by
+//! both `DMatrix::solve_expert()` and `SMatrix::solve_expert()`; when refinement is turned off
+//! they instead run the same steps up to the refinement, `?getrf`, `?gecon` and `?getrs`.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::backend;
+use super::gecon::{lu_rcond_raw, Gecon};
+use super::getrf::Getrf;
+use super::getrs::{lu_factor_raw, Getrs, LuFactorRawError};
+use crate::common::{
+    Complex, ComplexField, Equilibration, One, SolveExpertOptions, Transpose, Zero,
+};
+use crate::float::Float;
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors returned by the [`Gesvx`] LAPACK wrapper.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// LAPACK returned a non-zero info code. `1..=n` means a zero pivot, `n + 1` means A is
+    /// singular to working precision but a solution was still computed.
+    #[error("Error in gesvx, exited with code {0}")]
+    LapackError(i32),
+}
+//}}}
+
+//{{{ trait: Gesvx
+/// Trait for types that support the expert linear system driver.
+#[allow(clippy::too_many_arguments)]
+pub trait Gesvx: ComplexField {
+    /// Solves op(A) X = B with optional equilibration, always refining X to get error bounds.
+    ///
+    /// The reciprocal pivot growth factor, which LAPACK returns in `work[0]` for real types and
+    /// `rwork[0]` for complex types, is copied to `rpvgrw`.
+    fn gesvx(
+        fact: u8,  // 'N' to factor A as is, 'E' to equilibrate it first if necessary
+        trans: u8, // 'N', 'T' or 'C'
+        n: i32,
+        nrhs: i32,
+        a: &mut [Self], // Overwritten with the equilibrated matrix if equed != 'N'
+        lda: i32,
+        af: &mut [Self], // Contains the LU factors on exit
+        ldaf: i32,
+        ipiv: &mut [i32],
+        equed: &mut u8, // 'N', 'R', 'C' or 'B' on exit
+        r: &mut [Self::Real],
+        c: &mut [Self::Real],
+        b: &mut [Self], // Overwritten with the scaled right-hand side if equed != 'N'
+        ldb: i32,
+        x: &mut [Self],
+        ldx: i32,
+        rcond: &mut Self::Real,
+        ferr: &mut [Self::Real],
+        berr: &mut [Self::Real],
+        rpvgrw: &mut Self::Real,
+        work: &mut [Self], // At least 4n for real types, 2n for complex types
+        rwork: &mut [Self::Real], // At least 2n for complex types, unused for real types
+        iwork: &mut [i32], // At least n for real types, unused for complex types
+    ) -> Result<(), Error>;
+}
+//}}}
+//{{{ impl: Gesvx for f64
+impl Gesvx for f64 {
+    #[inline]
+    fn gesvx(
+        fact: u8,
+        trans: u8,
+        n: i32,
+        nrhs: i32,
+        a: &mut [Self],
+        lda: i32,
+        af: &mut [Self],
+        ldaf: i32,
+        ipiv: &mut [i32],
+        equed: &mut u8,
+        r: &mut [Self::Real],
+        c: &mut [Self::Real],
+        b: &mut [Self],
+        ldb: i32,
+        x: &mut [Self],
+        ldx: i32,
+        rcond: &mut Self::Real,
+        ferr: &mut [Self::Real],
+        berr: &mut [Self::Real],
+        rpvgrw: &mut Self::Real,
+        work: &mut [Self],
+        _rwork: &mut [Self::Real],
+        iwork: &mut [i32],
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| {
+            backend.dgesvx(
+                fact, trans, n, nrhs, a, lda, af, ldaf, ipiv, equed, r, c, b, ldb, x, ldx, rcond,
+                ferr, berr, work, iwork,
+            )
+        });
+        *rpvgrw = work[0];
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ impl: Gesvx for f32
+impl Gesvx for f32 {
+    #[inline]
+    fn gesvx(
+        fact: u8,
+        trans: u8,
+        n: i32,
+        nrhs: i32,
+        a: &mut [Self],
+        lda: i32,
+        af: &mut [Self],
+        ldaf: i32,
+        ipiv: &mut [i32],
+        equed: &mut u8,
+        r: &mut [Self::Real],
+        c: &mut [Self::Real],
+        b: &mut [Self],
+        ldb: i32,
+        x: &mut [Self],
+        ldx: i32,
+        rcond: &mut Self::Real,
+        ferr: &mut [Self::Real],
+        berr: &mut [Self::Real],
+        rpvgrw: &mut Self::Real,
+        work: &mut [Self],
+        _rwork: &mut [Self::Real],
+        iwork: &mut [i32],
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| {
+            backend.sgesvx(
+                fact, trans, n, nrhs, a, lda, af, ldaf, ipiv, equed, r, c, b, ldb, x, ldx, rcond,
+                ferr, berr, work, iwork,
+            )
+        });
+        *rpvgrw = work[0];
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ impl: Gesvx for Complex<f64>
+impl Gesvx for Complex<f64> {
+    #[inline]
+    fn gesvx(
+        fact: u8,
+        trans: u8,
+        n: i32,
+        nrhs: i32,
+        a: &mut [Self],
+        lda: i32,
+        af: &mut [Self],
+        ldaf: i32,
+        ipiv: &mut [i32],
+        equed: &mut u8,
+        r: &mut [Self::Real],
+        c: &mut [Self::Real],
+        b: &mut [Self],
+        ldb: i32,
+        x: &mut [Self],
+        ldx: i32,
+        rcond: &mut Self::Real,
+        ferr: &mut [Self::Real],
+        berr: &mut [Self::Real],
+        rpvgrw: &mut Self::Real,
+        work: &mut [Self],
+        rwork: &mut [Self::Real],
+        _iwork: &mut [i32],
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| {
+            backend.zgesvx(
+                fact, trans, n, nrhs, a, lda, af, ldaf, ipiv, equed, r, c, b, ldb, x, ldx, rcond,
+                ferr, berr, work, rwork,
+            )
+        });
+        *rpvgrw = rwork[0];
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ impl: Gesvx for Complex<f32>
+impl Gesvx for Complex<f32> {
+    #[inline]
+    fn gesvx(
+        fact: u8,
+        trans: u8,
+        n: i32,
+        nrhs: i32,
+        a: &mut [Self],
+        lda: i32,
+        af: &mut [Self],
+        ldaf: i32,
+        ipiv: &mut [i32],
+        equed: &mut u8,
+        r: &mut [Self::Real],
+        c: &mut [Self::Real],
+        b: &mut [Self],
+        ldb: i32,
+        x: &mut [Self],
+        ldx: i32,
+        rcond: &mut Self::Real,
+        ferr: &mut [Self::Real],
+        berr: &mut [Self::Real],
+        rpvgrw: &mut Self::Real,
+        work: &mut [Self],
+        rwork: &mut [Self::Real],
+        _iwork: &mut [i32],
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| {
+            backend.cgesvx(
+                fact, trans, n, nrhs, a, lda, af, ldaf, ipiv, equed, r, c, b, ldb, x, ldx, rcond,
+                ferr, berr, work, rwork,
+            )
+        });
+        *rpvgrw = rwork[0];
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ struct: SolveExpertRaw
+pub(crate) struct SolveExpertRaw<T: ComplexField> {
+    pub x_data: Vec<T>,
+    pub rcond: T::Real,
+    pub ferr: Option<Vec<T::Real>>,
+    pub berr: Option<Vec<T::Real>>,
+    pub rpvgrw: T::Real,
+    pub equilibration: Equilibration,
+    pub singular: bool,
+}
+//}}}
+//{{{ enum: SolveExpertRawError
+#[derive(Error, Debug)]
+pub enum SolveExpertRawError {
+    #[error("Exactly zero pivot at U({0}, {0})")]
+    Singular(i32),
+    #[error(transparent)]
+    Gesvx(#[from] Error),
+    #[error(transparent)]
+    Lu(#[from] LuFactorRawError),
+    #[error(transparent)]
+    Gecon(#[from] super::gecon::Error),
+}
+//}}}
+//{{{ fun: solve_expert_raw
+/// Shared GESVX algorithm. Consumes the cloned n×n matrix and n×nrhs right-hand side; returns the
+/// solution with its condition estimate, error bounds and equilibration report. Without
+/// refinement the work is handed to [`solve_unrefined_raw`] and there are no error bounds.
+pub(crate) fn solve_expert_raw<T>(
+    mut a_data: Vec<T>,
+    mut b_data: Vec<T>,
+    n: usize,
+    nrhs: usize,
+    options: SolveExpertOptions,
+) -> Result<SolveExpertRaw<T>, SolveExpertRawError>
+where
+    T: Gesvx + Getrf + Getrs + Gecon,
+{
+    let trans = match options.transpose {
+        Transpose::NoTrans => b'N',
+        Transpose::Trans => b'T',
+        Transpose::ConjTrans => b'C',
+    };
+    if !options.refine {
+        return solve_unrefined_raw(a_data, b_data, n, nrhs, trans);
+    }
+    let fact = if options.equilibrate { b'E' } else { b'N' };
+    let ld = n.max(1) as i32;
+    let zero = T::Real::zero();
+    let mut af = vec![T::zero(); n * n];
+    let mut ipiv = vec![0; n];
+    let mut equed = b'N';
+    let mut r = vec![zero; n];
+    let mut c = vec![zero; n];
+    let mut x_data = vec![T::zero(); n * nrhs];
+    let mut rcond = zero;
+    let mut ferr = vec![zero; nrhs];
+    let mut berr = vec![zero; nrhs];
+    let mut rpvgrw = zero;
+    let mut work = vec![T::zero(); 4 * n.max(1)];
+    let mut rwork = vec![zero; 2 * n.max(1)];
+    let mut iwork = vec![0; n.max(1)];
+
+    let singular = match T::gesvx(
+        fact,
+        trans,
+        n as i32,
+        nrhs as i32,
+        &mut a_data,
+        ld,
+        &mut af,
+        ld,
+        &mut ipiv,
+        &mut equed,
+        &mut r,
+        &mut c,
+        &mut b_data,
+        ld,
+        &mut x_data,
+        ld,
+        &mut rcond,
+        &mut ferr,
+        &mut berr,
+        &mut rpvgrw,
+        &mut work,
+        &mut rwork,
+        &mut iwork,
+    ) {
+        Ok(()) => false,
+        Err(Error::LapackError(info)) if info == n as i32 + 1 => true,
+        Err(Error::LapackError(info)) if info > 0 => {
+            return Err(SolveExpertRawError::Singular(info))
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    let equilibration = match equed {
+        b'R' => Equilibration::Row,
+        b'C' => Equilibration::Column,
+        b'B' => Equilibration::Both,
+        _ => Equilibration::None,
+    };
+    Ok(SolveExpertRaw {
+        x_data,
+        rcond,
+        ferr: Some(ferr),
+        berr: Some(berr),
+        rpvgrw,
+        equilibration,
+        singular,
+    })
+}
+//}}}
+//{{{ fun: solve_unrefined_raw
+/// The steps GESVX takes before refining, for `refine: false`: factor A as is with GETRF,
+/// estimate its condition with GECON in the norm GESVX uses for `trans`, and solve with GETRS.
+/// The pivot growth factor and the singular flag are computed as GESVX computes them.
+fn solve_unrefined_raw<T>(
+    a_data: Vec<T>,
+    mut b_data: Vec<T>,
+    n: usize,
+    nrhs: usize,
+    trans: u8,
+) -> Result<SolveExpertRaw<T>, SolveExpertRawError>
+where
+    T: Getrf + Getrs + Gecon,
+{
+    let zero = T::Real::zero();
+    let larger = |m: T::Real, v: T| if v.modulus() > m { v.modulus() } else { m };
+    let amax = a_data.iter().fold(zero, |m, &v| larger(m, v));
+    // GESVX estimates the condition of op(A) in the 1-norm, i.e. of A in the ∞-norm if transposed
+    let (norm, anorm) = if trans == b'N' {
+        (b'1', column_sums_max(&a_data, n, |i, j| i + j * n))
+    } else {
+        (b'I', column_sums_max(&a_data, n, |i, j| j + i * n))
+    };
+
+    let (lu, ipiv) = lu_factor_raw(a_data, n).map_err(|err| match err {
+        LuFactorRawError::Singular(info) => SolveExpertRawError::Singular(info),
+        err => err.into(),
+    })?;
+
+    let umax = (0..n).fold(zero, |m, j| {
+        (0..=j).fold(m, |m, i| larger(m, lu[i + j * n]))
+    });
+    let rpvgrw = if umax == zero {
+        T::Real::one()
+    } else {
+        amax / umax
+    };
+    let rcond = lu_rcond_raw(&lu, n, norm, anorm)?;
+
+    if n > 0 && nrhs > 0 {
+        T::getrs(
+            trans,
+            n as i32,
+            nrhs as i32,
+            &lu,
+            n as i32,
+            &ipiv,
+            &mut b_data,
+            n as i32,
+        )
+        .map_err(LuFactorRawError::from)?;
+    }
+
+    Ok(SolveExpertRaw {
+        x_data: b_data,
+        rcond,
+        ferr: None,
+        berr: None,
+        rpvgrw,
+        equilibration: Equilibration::None,
+        singular: rcond < T::Real::small(),
+    })
+}
+//}}}
+//{{{ fun: column_sums_max
+/// Largest sum of moduli over the n lines of an n×n matrix, entry `l` of line `k` being stored at
+/// `at(l, k)`: the 1-norm when the lines are columns, the ∞-norm when they are rows.
+fn column_sums_max<T>(
+    a: &[T],
+    n: usize,
+    at: impl Fn(usize, usize) -> usize,
+) -> T::Real
+where
+    T: ComplexField,
+{
+    let mut out = T::Real::zero();
+    for k in 0..n {
+        let mut sum = T::Real::zero();
+        for l in 0..n {
+            sum += a[at(l, k)].modulus();
+        }
+        if sum > out {
+            out = sum;
+        }
+    }
+    out
+}
+//}}}
@@ -1,12 +1,15 @@
-//! LAPACK `dgetrf`/`sgetrf` wrapper for LU factorisation with partial pivoting.
+//! LAPACK `?getrf` wrapper for LU factorisation with partial pivoting.
 //!
 //! Provides the [`Getrf`] trait, wrapping the LAPACK `?getrf` routine that factors a general
 //! m×n matrix A into P·L·U using partial pivoting with row interchanges. The pivot array is
 //! returned alongside the factored storage; a typed error is produced when the matrix is singular.
-//! Implementations for `f64` and `f32` call the Fortran LAPACK ABI via the `lapack` crate.
+//! Implementations for `f64`, `f32`, `Complex<f64>` and `Complex<f32>` dispatch to `?getrf` on the
+//! current [`Backend`](super::backend::Backend).
 //--------------------------------------------------------------------------------------------------
 
 //{{{ crate imports
+use super::backend;
+use crate::common::Complex;
 //}}}
 //{{{ std imports
 //}}}
@@ -55,10 +58,7 @@ impl Getrf for f64 {
         lda: i32,
         ipiv: &mut [i32],
     ) -> Result<(), Error> {
-        let mut info = 0;
-        unsafe {
-            lapack::dgetrf(m, n, a, lda, ipiv, &mut info);
-        }
+        let info = backend::with_current(|backend| backend.dgetrf(m, n, a, lda, ipiv));
         if info != 0 {
             return Err(Error::LapackError(info));
         }
@@ -76,10 +76,45 @@ impl Getrf for f32 {
         lda: i32,
         ipiv: &mut [i32],
     ) -> Result<(), Error> {
-        let mut info = 0;
-        unsafe {
-            lapack::sgetrf(m, n, a, lda, ipiv, &mut info);
+        let info = backend::with_current(|backend| backend.sgetrf(m, n, a, lda, ipiv));
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ impl: Getrf for Complex<f64>
+impl Getrf for Complex<f64> {
+    #[inline]
+    fn getrf(
+        m: i32,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        ipiv: &mut [i32],
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| backend.zgetrf(m, n, a, lda, ipiv));
+        if info != 0 {
+            return Err(Error::LapackError(info));
         }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ impl: Getrf for Complex<f32>
+impl Getrf for Complex<f32> {
+    #[inline]
+    fn getrf(
+        m: i32,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        ipiv: &mut [i32],
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| backend.cgetrf(m, n, a, lda, ipiv));
         if info != 0 {
             return Err(Error::LapackError(info));
         }
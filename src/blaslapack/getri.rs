@@ -0,0 +1,123 @@
+//! LAPACK `dgetri`/`sgetri` wrapper for matrix inversion from an LU factorisation.
+//!
+//! Provides the [`Getri`] trait, wrapping the LAPACK `?getri` routine that computes the inverse of
+//! a general n×n matrix from the packed LU factors and pivot indices produced by [`Getrf`]. A
+//! workspace query (lwork = −1) is supported to obtain the optimal workspace size. The shared raw
+//! helper used by `DMatrix::inverse()` and `SMatrix::inverse()` runs both LAPACK calls and reports
+//! exactly singular input through a dedicated error variant.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::backend;
+use super::getrf::Getrf;
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors returned by the [`Getri`] LAPACK wrapper.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// LAPACK returned a non-zero info code indicating an invalid argument or a zero pivot.
+    #[error("Error in getri, exited with code {0}")]
+    LapackError(i32),
+}
+//}}}
+
+//{{{ trait: Getri
+/// Trait for types that support matrix inversion from an LU factorisation.
+pub trait Getri: Copy {
+    /// Computes the inverse of a matrix from its LU factorisation, overwriting the factors.
+    fn getri(
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        ipiv: &[i32],
+        work: &mut [Self],
+        lwork: i32,
+    ) -> Result<(), Error>;
+}
+//}}}
+//{{{ impl: Getri for f64
+impl Getri for f64 {
+    #[inline]
+    fn getri(
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        ipiv: &[i32],
+        work: &mut [Self],
+        lwork: i32,
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| backend.dgetri(n, a, lda, ipiv, work, lwork));
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+//{{{ impl: Getri for f32
+impl Getri for f32 {
+    #[inline]
+    fn getri(
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        ipiv: &[i32],
+        work: &mut [Self],
+        lwork: i32,
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| backend.sgetri(n, a, lda, ipiv, work, lwork));
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ enum: InverseRawError
+#[derive(Error, Debug)]
+pub enum InverseRawError {
+    /// The matrix is exactly singular: the U factor has a zero on its diagonal at this
+    /// (1-based) position.
+    #[error("matrix is singular, U({0},{0}) is exactly zero")]
+    Singular(i32),
+    #[error(transparent)]
+    Getrf(super::getrf::Error),
+    #[error(transparent)]
+    Getri(#[from] Error),
+}
+//}}}
+//{{{ fun: inverse_raw
+/// Shared GETRF + GETRI algorithm. Consumes the cloned matrix data; returns the raw inverse.
+pub(crate) fn inverse_raw<T>(
+    mut a_data: Vec<T>,
+    n: usize,
+) -> Result<Vec<T>, InverseRawError>
+where
+    T: Getrf + Getri + crate::common::Zero + crate::common::Field + Copy + super::common::AsI32,
+{
+    let mut ipiv = vec![0; n];
+    match T::getrf(n as i32, n as i32, &mut a_data, n as i32, &mut ipiv) {
+        Ok(()) => {}
+        Err(super::getrf::Error::LapackError(info)) if info > 0 => {
+            return Err(InverseRawError::Singular(info));
+        }
+        Err(err) => return Err(InverseRawError::Getrf(err)),
+    }
+
+    let mut work = vec![T::zero(); 1];
+    T::getri(n as i32, &mut a_data, n as i32, &ipiv, &mut work, -1)?;
+
+    let lwork = work[0].as_i32().max(1);
+    let mut work = vec![T::zero(); lwork as usize];
+    T::getri(n as i32, &mut a_data, n as i32, &ipiv, &mut work, lwork)?;
+    Ok(a_data)
+}
+//}}}
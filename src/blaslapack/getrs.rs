@@ -0,0 +1,270 @@
+//! LAPACK `?getrs` wrapper for solving linear systems from an LU factorisation.
+//!
+//! Provides the [`Getrs`] trait, wrapping the LAPACK `?getrs` routine that solves `A X = B` (or
+//! `Aᵀ X = B`) for a general n×n matrix A given the packed LU factors and pivot indices produced by
+//! [`Getrf`]. The shared raw helpers back the `LuFactor` handles of `DMatrix` and `SMatrix`: they
+//! factor once with `getrf`, keep LAPACK's compact output, and then solve any number of
+//! right-hand sides in O(n²) each. Implementations for `f64`, `f32`, `Complex<f64>` and
+//! `Complex<f32>` call the Fortran LAPACK ABI via the `lapack` crate.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::backend;
+use super::getrf::Getrf;
+use super::potrf::Potrf;
+use super::potrs::{cholesky_factor_raw, cholesky_logdet_raw};
+use crate::common::{Complex, ComplexField, Field, One, Zero};
+use crate::float::Float;
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors returned by the [`Getrs`] LAPACK wrapper.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// LAPACK returned a non-zero info code indicating an invalid argument.
+    #[error("Error in getrs, exited with code {0}")]
+    LapackError(i32),
+}
+//}}}
+
+//{{{ trait: Getrs
+/// Trait for types that support solving linear systems from an LU factorisation.
+#[allow(clippy::too_many_arguments)]
+pub trait Getrs: Copy {
+    /// Solves `op(A) X = B` using the LU factors of A computed by [`Getrf`].
+    ///
+    /// `trans` is `b'N'` for `A X = B`, `b'T'` for `Aᵀ X = B` or `b'C'` for `Aᴴ X = B`. On exit
+    /// `b` is overwritten by the solution X.
+    fn getrs(
+        trans: u8,
+        n: i32,
+        nrhs: i32,
+        a: &[Self],
+        lda: i32,
+        ipiv: &[i32],
+        b: &mut [Self],
+        ldb: i32,
+    ) -> Result<(), Error>;
+}
+//}}}
+//{{{ impl: Getrs for f64
+impl Getrs for f64 {
+    #[inline]
+    fn getrs(
+        trans: u8,
+        n: i32,
+        nrhs: i32,
+        a: &[Self],
+        lda: i32,
+        ipiv: &[i32],
+        b: &mut [Self],
+        ldb: i32,
+    ) -> Result<(), Error> {
+        let info =
+            backend::with_current(|backend| backend.dgetrs(trans, n, nrhs, a, lda, ipiv, b, ldb));
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+//{{{ impl: Getrs for f32
+impl Getrs for f32 {
+    #[inline]
+    fn getrs(
+        trans: u8,
+        n: i32,
+        nrhs: i32,
+        a: &[Self],
+        lda: i32,
+        ipiv: &[i32],
+        b: &mut [Self],
+        ldb: i32,
+    ) -> Result<(), Error> {
+        let info =
+            backend::with_current(|backend| backend.sgetrs(trans, n, nrhs, a, lda, ipiv, b, ldb));
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+//{{{ impl: Getrs for Complex<f64>
+impl Getrs for Complex<f64> {
+    #[inline]
+    fn getrs(
+        trans: u8,
+        n: i32,
+        nrhs: i32,
+        a: &[Self],
+        lda: i32,
+        ipiv: &[i32],
+        b: &mut [Self],
+        ldb: i32,
+    ) -> Result<(), Error> {
+        let info =
+            backend::with_current(|backend| backend.zgetrs(trans, n, nrhs, a, lda, ipiv, b, ldb));
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+//{{{ impl: Getrs for Complex<f32>
+impl Getrs for Complex<f32> {
+    #[inline]
+    fn getrs(
+        trans: u8,
+        n: i32,
+        nrhs: i32,
+        a: &[Self],
+        lda: i32,
+        ipiv: &[i32],
+        b: &mut [Self],
+        ldb: i32,
+    ) -> Result<(), Error> {
+        let info =
+            backend::with_current(|backend| backend.cgetrs(trans, n, nrhs, a, lda, ipiv, b, ldb));
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ enum: LuFactorRawError
+#[derive(Error, Debug)]
+pub enum LuFactorRawError {
+    /// The matrix is exactly singular: the U factor has a zero on its diagonal at this
+    /// (1-based) position.
+    #[error("matrix is singular, U({0},{0}) is exactly zero")]
+    Singular(i32),
+    #[error(transparent)]
+    Getrf(super::getrf::Error),
+    #[error(transparent)]
+    Getrs(#[from] Error),
+}
+//}}}
+//{{{ fun: lu_factor_raw
+/// Shared GETRF step of the LU factor handles. Consumes the cloned n×n matrix data and returns the
+/// packed LU factors together with LAPACK's 1-based pivot indices.
+pub(crate) fn lu_factor_raw<T>(
+    mut a_data: Vec<T>,
+    n: usize,
+) -> Result<(Vec<T>, Vec<i32>), LuFactorRawError>
+where
+    T: Getrf,
+{
+    let mut ipiv = vec![0; n];
+    match T::getrf(n as i32, n as i32, &mut a_data, n as i32, &mut ipiv) {
+        Ok(()) => Ok((a_data, ipiv)),
+        Err(super::getrf::Error::LapackError(info)) if info > 0 => {
+            Err(LuFactorRawError::Singular(info))
+        }
+        Err(err) => Err(LuFactorRawError::Getrf(err)),
+    }
+}
+//}}}
+//{{{ fun: lu_solve_raw
+/// Shared GETRS step of the LU factor handles. Overwrites the n×`nrhs` column-major `b` with the
+/// solution of `A X = B`.
+pub(crate) fn lu_solve_raw<T>(
+    lu: &[T],
+    ipiv: &[i32],
+    n: usize,
+    b: &mut [T],
+    nrhs: usize,
+) -> Result<(), LuFactorRawError>
+where
+    T: Getrs,
+{
+    if n == 0 || nrhs == 0 {
+        return Ok(());
+    }
+    T::getrs(b'N', n as i32, nrhs as i32, lu, n as i32, ipiv, b, n as i32)?;
+    Ok(())
+}
+//}}}
+//{{{ fun: lu_det_raw
+/// Determinant from packed LU factors: the product of the diagonal of U, negated once for every
+/// row interchange recorded in `ipiv`.
+pub(crate) fn lu_det_raw<T>(
+    lu: &[T],
+    ipiv: &[i32],
+    n: usize,
+) -> T
+where
+    T: Field + One + Copy,
+{
+    let mut det = T::one();
+    for i in 0..n {
+        det *= lu[i + i * n];
+        if ipiv[i] != (i + 1) as i32 {
+            det = -det;
+        }
+    }
+    det
+}
+//}}}
+//{{{ fun: lu_slogdet_raw
+/// Sign and natural log of the absolute determinant from packed LU factors. The sign is the
+/// product of the unit phases `u_ii / |u_ii|` of the diagonal of U, negated once for every row
+/// interchange; the logarithm is the sum of `ln |u_ii|`, so neither overflows for large `n`.
+pub(crate) fn lu_slogdet_raw<T>(
+    lu: &[T],
+    ipiv: &[i32],
+    n: usize,
+) -> (T, T::Real)
+where
+    T: ComplexField,
+{
+    let mut sign = T::one();
+    let mut logdet = T::Real::zero();
+    for i in 0..n {
+        let u = lu[i + i * n];
+        let modulus = u.modulus();
+        sign *= u / T::from_real(modulus);
+        logdet += modulus.ln();
+        if ipiv[i] != (i + 1) as i32 {
+            sign = -sign;
+        }
+    }
+    (sign, logdet)
+}
+//}}}
+//{{{ fun: slogdet_raw
+/// Shared sign and log-determinant computation of `DMatrix` and `SMatrix`. Exactly Hermitian input,
+/// including a real diagonal, is first offered to POTRF: on success the determinant is positive
+/// and its logarithm comes from the Cholesky diagonal. Otherwise the matrix is factored by GETRF.
+/// An exactly singular matrix yields a zero sign and a logarithm of -∞ rather than an error.
+pub(crate) fn slogdet_raw<T>(
+    a_data: Vec<T>,
+    n: usize,
+) -> Result<(T, T::Real), LuFactorRawError>
+where
+    T: Getrf + Potrf + ComplexField,
+{
+    // POTRF reads only the real part of the diagonal, so it must be checked as well
+    let hermitian = (0..n).all(|j| (0..=j).all(|i| a_data[i + j * n] == a_data[j + i * n].conj()));
+    if hermitian {
+        if let Ok(l) = cholesky_factor_raw(a_data.clone(), n) {
+            return Ok((T::one(), cholesky_logdet_raw(&l, n)));
+        }
+    }
+    match lu_factor_raw(a_data, n) {
+        Ok((lu, ipiv)) => Ok(lu_slogdet_raw(&lu, &ipiv, n)),
+        Err(LuFactorRawError::Singular(_)) => Ok((T::zero(), T::Real::zero().ln())),
+        Err(err) => Err(err),
+    }
+}
+//}}}
@@ -0,0 +1,324 @@
+//! LAPACK `?ggev` wrapper for the generalized (non-symmetric) eigenproblem.
+//!
+//! Provides the [`Ggev`] trait, wrapping the LAPACK `?ggev` routine that computes the generalized
+//! eigenvalues of a matrix pair (A, B), i.e. the scalars λ with A x = λ B x, as ratios α/β, and
+//! optionally the left and right generalized eigenvectors. Keeping α and β separate lets a
+//! singular B be reported as β = 0 (an infinite eigenvalue) instead of overflowing. The real
+//! LAPACK interface splits α into real and imaginary parts; the real implementations combine them,
+//! so every element type reports α and β as `T::Complex` values. Real eigenvectors use the same
+//! packed conjugate-pair layout as [`Geev`] and are unpacked with `Geev::unpack_eigvecs`.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::backend;
+use super::common::AsI32;
+use super::geev::Geev;
+use crate::common::{Complex, ComplexField, Zero};
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors returned by the [`Ggev`] LAPACK wrapper.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// LAPACK returned a non-zero info code indicating a failure in the QZ algorithm.
+    #[error("Error in ggev, exited with code {0}")]
+    LapackError(i32),
+}
+//}}}
+
+//{{{ trait: Ggev
+/// Trait for types that support the generalized (non-symmetric) eigenproblem.
+#[allow(clippy::too_many_arguments)]
+pub trait Ggev: Geev {
+    /// Computes the generalized eigenvalues `alpha / beta` and optionally the left/right
+    /// generalized eigenvectors of the pair (A, B). Both `a` and `b` are overwritten.
+    fn ggev(
+        jobvl: u8,
+        jobvr: u8,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        b: &mut [Self],
+        ldb: i32,
+        alpha: &mut [Self::Complex],
+        beta: &mut [Self::Complex],
+        vl: &mut [Self],
+        ldvl: i32,
+        vr: &mut [Self],
+        ldvr: i32,
+        work: &mut [Self],
+        lwork: i32,
+        rwork: &mut [Self::Real], // At least 8n for complex types, unused for real types
+    ) -> Result<(), Error>;
+}
+//}}}
+
+//{{{ impl: Ggev for f64
+impl Ggev for f64 {
+    #[inline]
+    fn ggev(
+        jobvl: u8,
+        jobvr: u8,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        b: &mut [Self],
+        ldb: i32,
+        alpha: &mut [Complex<f64>],
+        beta: &mut [Complex<f64>],
+        vl: &mut [Self],
+        ldvl: i32,
+        vr: &mut [Self],
+        ldvr: i32,
+        work: &mut [Self],
+        lwork: i32,
+        _rwork: &mut [Self],
+    ) -> Result<(), Error> {
+        let mut alphar = vec![0.0; alpha.len()];
+        let mut alphai = vec![0.0; alpha.len()];
+        let mut betar = vec![0.0; beta.len()];
+        let info = backend::with_current(|backend| {
+            backend.dggev(
+                jobvl,
+                jobvr,
+                n,
+                a,
+                lda,
+                b,
+                ldb,
+                &mut alphar,
+                &mut alphai,
+                &mut betar,
+                vl,
+                ldvl,
+                vr,
+                ldvr,
+                work,
+                lwork,
+            )
+        });
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        for (ak, (&r, &i)) in alpha.iter_mut().zip(alphar.iter().zip(alphai.iter())) {
+            *ak = Complex::new(r, i);
+        }
+        for (bk, &r) in beta.iter_mut().zip(betar.iter()) {
+            *bk = Complex::new(r, 0.0);
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ impl: Ggev for f32
+impl Ggev for f32 {
+    #[inline]
+    fn ggev(
+        jobvl: u8,
+        jobvr: u8,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        b: &mut [Self],
+        ldb: i32,
+        alpha: &mut [Complex<f32>],
+        beta: &mut [Complex<f32>],
+        vl: &mut [Self],
+        ldvl: i32,
+        vr: &mut [Self],
+        ldvr: i32,
+        work: &mut [Self],
+        lwork: i32,
+        _rwork: &mut [Self],
+    ) -> Result<(), Error> {
+        let mut alphar = vec![0.0; alpha.len()];
+        let mut alphai = vec![0.0; alpha.len()];
+        let mut betar = vec![0.0; beta.len()];
+        let info = backend::with_current(|backend| {
+            backend.sggev(
+                jobvl,
+                jobvr,
+                n,
+                a,
+                lda,
+                b,
+                ldb,
+                &mut alphar,
+                &mut alphai,
+                &mut betar,
+                vl,
+                ldvl,
+                vr,
+                ldvr,
+                work,
+                lwork,
+            )
+        });
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        for (ak, (&r, &i)) in alpha.iter_mut().zip(alphar.iter().zip(alphai.iter())) {
+            *ak = Complex::new(r, i);
+        }
+        for (bk, &r) in beta.iter_mut().zip(betar.iter()) {
+            *bk = Complex::new(r, 0.0);
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ impl: Ggev for Complex<f64>
+impl Ggev for Complex<f64> {
+    #[inline]
+    fn ggev(
+        jobvl: u8,
+        jobvr: u8,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        b: &mut [Self],
+        ldb: i32,
+        alpha: &mut [Self],
+        beta: &mut [Self],
+        vl: &mut [Self],
+        ldvl: i32,
+        vr: &mut [Self],
+        ldvr: i32,
+        work: &mut [Self],
+        lwork: i32,
+        rwork: &mut [f64],
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| {
+            backend.zggev(
+                jobvl, jobvr, n, a, lda, b, ldb, alpha, beta, vl, ldvl, vr, ldvr, work, lwork,
+                rwork,
+            )
+        });
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ impl: Ggev for Complex<f32>
+impl Ggev for Complex<f32> {
+    #[inline]
+    fn ggev(
+        jobvl: u8,
+        jobvr: u8,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        b: &mut [Self],
+        ldb: i32,
+        alpha: &mut [Self],
+        beta: &mut [Self],
+        vl: &mut [Self],
+        ldvl: i32,
+        vr: &mut [Self],
+        ldvr: i32,
+        work: &mut [Self],
+        lwork: i32,
+        rwork: &mut [f32],
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| {
+            backend.cggev(
+                jobvl, jobvr, n, a, lda, b, ldb, alpha, beta, vl, ldvl, vr, ldvr, work, lwork,
+                rwork,
+            )
+        });
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ struct: GeneralizedEigRaw
+pub(crate) struct GeneralizedEigRaw<T>
+where
+    T: ComplexField,
+{
+    /// Right generalized eigenvectors, one complex eigenvector per column.
+    pub eigvecs: Vec<T::Complex>,
+    pub alpha: Vec<T::Complex>,
+    pub beta: Vec<T::Complex>,
+}
+//}}}
+//{{{ fun: generalized_eig_raw
+/// Shared GGEV algorithm. Consumes the cloned data of both n×n matrices; returns the α/β pairs and
+/// the unpacked right generalized eigenvectors.
+pub(crate) fn generalized_eig_raw<T>(
+    mut a_data: Vec<T>,
+    mut b_data: Vec<T>,
+    n: usize,
+) -> Result<GeneralizedEigRaw<T>, Error>
+where
+    T: Ggev + AsI32,
+{
+    let ld = n.max(1) as i32;
+    let mut alpha = vec![T::Complex::zero(); n];
+    let mut beta = vec![T::Complex::zero(); n];
+    let mut vl = vec![T::zero(); 1];
+    let mut vr = vec![T::zero(); n * n];
+    let mut rwork = vec![T::Real::zero(); (8 * n).max(1)];
+
+    let mut work = vec![T::zero(); 1];
+    T::ggev(
+        b'N',
+        b'V',
+        n as i32,
+        &mut a_data,
+        ld,
+        &mut b_data,
+        ld,
+        &mut alpha,
+        &mut beta,
+        &mut vl,
+        1,
+        &mut vr,
+        ld,
+        &mut work,
+        -1,
+        &mut rwork,
+    )?;
+
+    let lwork = work[0].as_i32().max(1);
+    let mut work = vec![T::zero(); lwork as usize];
+    T::ggev(
+        b'N',
+        b'V',
+        n as i32,
+        &mut a_data,
+        ld,
+        &mut b_data,
+        ld,
+        &mut alpha,
+        &mut beta,
+        &mut vl,
+        1,
+        &mut vr,
+        ld,
+        &mut work,
+        lwork,
+        &mut rwork,
+    )?;
+
+    Ok(GeneralizedEigRaw {
+        eigvecs: T::unpack_eigvecs(&vr, &alpha, n),
+        alpha,
+        beta,
+    })
+}
+//}}}
@@ -0,0 +1,171 @@
+//! LAPACK `?gtsv` wrapper for solving tridiagonal linear systems.
+//!
+//! Provides the [`Gtsv`] trait, wrapping the LAPACK `?gtsv` routine that solves A X = B for a
+//! general n×n tridiagonal matrix A given by its sub-diagonal, diagonal and super-diagonal, using
+//! Gaussian elimination with partial pivoting in O(n) operations per right-hand side. The three
+//! diagonals are overwritten by the factorisation, so [`tridiagonal_solve_raw`] consumes copies.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::backend;
+use crate::common::Complex;
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors returned by the [`Gtsv`] LAPACK wrapper.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// LAPACK returned a non-zero info code indicating an invalid argument or a zero pivot.
+    #[error("Error in gtsv, exited with code {0}")]
+    LapackError(i32),
+}
+//}}}
+
+//{{{ trait: Gtsv
+/// Trait for types that support solving a tridiagonal linear system A X = B.
+pub trait Gtsv: Copy {
+    /// Solves the tridiagonal system A X = B, overwriting the diagonals with the factorisation
+    /// and B with X.
+    fn gtsv(
+        n: i32,
+        nrhs: i32,
+        dl: &mut [Self],
+        d: &mut [Self],
+        du: &mut [Self],
+        b: &mut [Self],
+        ldb: i32,
+    ) -> Result<(), Error>;
+}
+//}}}
+
+//{{{ impl: Gtsv for f64
+impl Gtsv for f64 {
+    #[inline]
+    fn gtsv(
+        n: i32,
+        nrhs: i32,
+        dl: &mut [Self],
+        d: &mut [Self],
+        du: &mut [Self],
+        b: &mut [Self],
+        ldb: i32,
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| backend.dgtsv(n, nrhs, dl, d, du, b, ldb));
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ impl: Gtsv for f32
+impl Gtsv for f32 {
+    #[inline]
+    fn gtsv(
+        n: i32,
+        nrhs: i32,
+        dl: &mut [Self],
+        d: &mut [Self],
+        du: &mut [Self],
+        b: &mut [Self],
+        ldb: i32,
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| backend.sgtsv(n, nrhs, dl, d, du, b, ldb));
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ impl: Gtsv for Complex<f64>
+impl Gtsv for Complex<f64> {
+    #[inline]
+    fn gtsv(
+        n: i32,
+        nrhs: i32,
+        dl: &mut [Self],
+        d: &mut [Self],
+        du: &mut [Self],
+        b: &mut [Self],
+        ldb: i32,
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| backend.zgtsv(n, nrhs, dl, d, du, b, ldb));
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ impl: Gtsv for Complex<f32>
+impl Gtsv for Complex<f32> {
+    #[inline]
+    fn gtsv(
+        n: i32,
+        nrhs: i32,
+        dl: &mut [Self],
+        d: &mut [Self],
+        du: &mut [Self],
+        b: &mut [Self],
+        ldb: i32,
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| backend.cgtsv(n, nrhs, dl, d, du, b, ldb));
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ enum: TridiagonalSolveRawError
+/// Errors returned by [`tridiagonal_solve_raw`].
+#[derive(Error, Debug)]
+pub enum TridiagonalSolveRawError {
+    /// `U[i, i]` is exactly zero, so the matrix is singular.
+    #[error("Matrix is singular, U({0},{0}) is exactly zero")]
+    Singular(i32),
+    /// LAPACK `gtsv` rejected its arguments.
+    #[error(transparent)]
+    Gtsv(#[from] Error),
+}
+//}}}
+//{{{ fun: tridiagonal_solve_raw
+/// Shared GTSV algorithm. Consumes copies of the sub-diagonal, diagonal and super-diagonal of an
+/// n×n tridiagonal matrix and the n×nrhs right-hand side, and returns the solution.
+pub(crate) fn tridiagonal_solve_raw<T>(
+    mut dl: Vec<T>,
+    mut d: Vec<T>,
+    mut du: Vec<T>,
+    mut b_data: Vec<T>,
+    nrhs: usize,
+) -> Result<Vec<T>, TridiagonalSolveRawError>
+where
+    T: Gtsv,
+{
+    let n = d.len();
+    match T::gtsv(
+        n as i32,
+        nrhs as i32,
+        &mut dl,
+        &mut d,
+        &mut du,
+        &mut b_data,
+        n.max(1) as i32,
+    ) {
+        Ok(()) => Ok(b_data),
+        Err(Error::LapackError(info)) if info > 0 => Err(TridiagonalSolveRawError::Singular(info)),
+        Err(err) => Err(err.into()),
+    }
+}
+//}}}
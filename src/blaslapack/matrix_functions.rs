@@ -0,0 +1,657 @@
+//! Shared algorithms for the matrix exponential, logarithm, square root and integer powers.
+//!
+//! Provides the [`MatFun`] trait, which gathers the BLAS/LAPACK wrappers the algorithms need, and
+//! the raw helpers behind `expm()`, `logm()`, `sqrtm()` and `powm()`. The exponential uses the
+//! scaling and squaring method with Padé approximants of degree 3 to 13 (Higham, 2005), solving
+//! the Padé denominator with [`Gesv`]. The square root and logarithm start from the real Schur
+//! form computed by [`Gees`], rotate it to a complex upper-triangular Schur form, and work on the
+//! triangle: the square root by the Björck–Hammarling recurrence and the logarithm by inverse
+//! scaling and squaring with a Gauss–Legendre Padé approximant. Both are principal functions, so
+//! they exist as real matrices only when no eigenvalue lies on the closed negative real axis.
+//! Integer powers use binary powering with [`Gemm`]. Only `f64` and `f32` implement [`MatFun`].
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::gees::{self, schur_raw, Gees};
+use super::gemm::{matmul_dispatch, Gemm};
+use super::gemv::Gemv;
+use super::gesv::{self, solve_raw, Gesv};
+use crate::common::{One, Zero};
+use crate::float::Float;
+//}}}
+//{{{ std imports
+use std::ops::{Add, Div, Mul, Sub};
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ trait: MatFun
+/// Trait for real scalar types that support the matrix functions.
+pub trait MatFun: Gemm + Gemv + Gesv + Gees + Float + Zero + One + Default + Copy {}
+//}}}
+//{{{ collection: MatFun implementations
+impl MatFun for f64 {}
+impl MatFun for f32 {}
+//}}}
+
+//{{{ enum: MatFunRawError
+/// Errors returned by the matrix function helpers.
+#[derive(Error, Debug)]
+pub enum MatFunRawError {
+    /// The matrix has an eigenvalue on the closed negative real axis, or repeated zero
+    /// eigenvalues, so it has no real principal square root.
+    #[error("Matrix has no real principal square root")]
+    NoRealSquareRoot,
+    /// The matrix has an eigenvalue on the closed negative real axis, so it has no real principal
+    /// logarithm.
+    #[error("Matrix has no real principal logarithm")]
+    NoRealLogarithm,
+    /// A matrix that had to be inverted is exactly singular.
+    #[error("Matrix is singular")]
+    Singular,
+    /// LAPACK `gees` failed to compute the Schur form.
+    #[error(transparent)]
+    Gees(#[from] gees::Error),
+    /// LAPACK `gesv` rejected its arguments.
+    #[error(transparent)]
+    Gesv(gesv::Error),
+}
+
+impl From<gesv::Error> for MatFunRawError {
+    fn from(err: gesv::Error) -> Self {
+        match err {
+            gesv::Error::LapackError(info) if info > 0 => MatFunRawError::Singular,
+            err => MatFunRawError::Gesv(err),
+        }
+    }
+}
+//}}}
+
+//{{{ collection: real helpers
+//{{{ fun: identity
+fn identity<T: MatFun>(n: usize) -> Vec<T> {
+    let mut eye = vec![T::zero(); n * n];
+    for i in 0..n {
+        eye[i + i * n] = T::one();
+    }
+    eye
+}
+//}}}
+//{{{ fun: matmul
+fn matmul<T: MatFun>(
+    a: &[T],
+    b: &[T],
+    n: usize,
+) -> Vec<T> {
+    let mut c = vec![T::zero(); n * n];
+    matmul_dispatch(n, n, n, a, b, &mut c);
+    c
+}
+//}}}
+//{{{ fun: norm_one
+fn norm_one<T: MatFun>(
+    a: &[T],
+    n: usize,
+) -> T {
+    a.chunks(n.max(1)).fold(T::zero(), |acc, col| {
+        acc.max(col.iter().fold(T::zero(), |sum, &x| sum + x.abs()))
+    })
+}
+//}}}
+//{{{ fun: lincomb
+/// Returns `Σ c_k A_k + c_I I` for the given terms.
+fn lincomb<T: MatFun>(
+    terms: &[(f64, &[T])],
+    c_eye: f64,
+    n: usize,
+) -> Vec<T> {
+    let mut acc = vec![T::zero(); n * n];
+    for &(c, a) in terms {
+        let c = T::from_f64(c);
+        for (x, &y) in acc.iter_mut().zip(a) {
+            *x += c * y;
+        }
+    }
+    let c_eye = T::from_f64(c_eye);
+    for i in 0..n {
+        acc[i + i * n] += c_eye;
+    }
+    acc
+}
+//}}}
+//}}}
+
+//{{{ collection: complex helpers
+//{{{ struct: Cx
+/// Minimal complex scalar over a real [`MatFun`] type, used for the complex Schur form.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Cx<T> {
+    re: T,
+    im: T,
+}
+//}}}
+//{{{ impl: Cx<T>
+impl<T: MatFun> Cx<T> {
+    fn new(
+        re: T,
+        im: T,
+    ) -> Self {
+        Self { re, im }
+    }
+
+    fn zero() -> Self {
+        Self::new(T::zero(), T::zero())
+    }
+
+    fn one() -> Self {
+        Self::new(T::one(), T::zero())
+    }
+
+    fn conj(self) -> Self {
+        Self::new(self.re, -self.im)
+    }
+
+    fn abs(self) -> T {
+        self.re.hypot(self.im)
+    }
+
+    fn scale(
+        self,
+        s: T,
+    ) -> Self {
+        Self::new(self.re * s, self.im * s)
+    }
+
+    /// Principal square root, with a non-negative real part.
+    fn sqrt(self) -> Self {
+        let r = self.abs();
+        if r == T::zero() {
+            return Self::zero();
+        }
+        let two = T::one() + T::one();
+        let re = ((r + self.re.abs()) / two).sqrt();
+        let im = self.im.abs() / (two * re);
+        if self.re >= T::zero() {
+            Self::new(re, im.copysign(self.im))
+        } else {
+            Self::new(im, re.copysign(self.im))
+        }
+    }
+
+    /// Principal logarithm, with an imaginary part in `(-π, π]`.
+    fn ln(self) -> Self {
+        Self::new(self.abs().ln(), self.im.atan2(self.re))
+    }
+}
+
+impl<T: MatFun> Add for Cx<T> {
+    type Output = Self;
+
+    fn add(
+        self,
+        rhs: Self,
+    ) -> Self {
+        Self::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl<T: MatFun> Sub for Cx<T> {
+    type Output = Self;
+
+    fn sub(
+        self,
+        rhs: Self,
+    ) -> Self {
+        Self::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl<T: MatFun> Mul for Cx<T> {
+    type Output = Self;
+
+    fn mul(
+        self,
+        rhs: Self,
+    ) -> Self {
+        Self::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+impl<T: MatFun> Div for Cx<T> {
+    type Output = Self;
+
+    fn div(
+        self,
+        rhs: Self,
+    ) -> Self {
+        let d = rhs.re * rhs.re + rhs.im * rhs.im;
+        Self::new(
+            (self.re * rhs.re + self.im * rhs.im) / d,
+            (self.im * rhs.re - self.re * rhs.im) / d,
+        )
+    }
+}
+//}}}
+//{{{ struct: ComplexSchur
+/// Complex Schur form `A = Z T Zᴴ` with unitary `Z` and upper-triangular `T`.
+struct ComplexSchur<T> {
+    z: Vec<Cx<T>>,
+    t: Vec<Cx<T>>,
+}
+//}}}
+//{{{ fun: complex_schur
+/// Computes the real Schur form with GEES and rotates each 2×2 diagonal block to upper-triangular
+/// form with a complex Givens rotation, in the manner of MATLAB's `rsf2csf`.
+fn complex_schur<T: MatFun>(
+    a: &[T],
+    n: usize,
+) -> Result<ComplexSchur<T>, MatFunRawError> {
+    let raw = schur_raw(a.to_vec(), n, n)?;
+    let mut z: Vec<Cx<T>> = raw.q_data.iter().map(|&x| Cx::new(x, T::zero())).collect();
+    let mut t: Vec<Cx<T>> = raw.t_data.iter().map(|&x| Cx::new(x, T::zero())).collect();
+    let two = T::one() + T::one();
+
+    for m in (1..n).rev() {
+        let sub = t[m + (m - 1) * n].re;
+        if sub == T::zero() {
+            continue;
+        }
+        // Eigenvalue of the 2×2 block, shifted by its trailing diagonal entry.
+        let a11 = t[(m - 1) + (m - 1) * n].re;
+        let a12 = t[(m - 1) + m * n].re;
+        let a22 = t[m + m * n].re;
+        let p = (a11 - a22) / two;
+        let disc = p * p + a12 * sub;
+        let mu = if disc >= T::zero() {
+            Cx::new(p + disc.sqrt(), T::zero())
+        } else {
+            Cx::new(p, (-disc).sqrt())
+        };
+        let r = mu.abs().hypot(sub);
+        let c = mu.scale(T::one() / r);
+        let s = sub / r;
+
+        // T[m-1..=m, m-1..] = G T[m-1..=m, m-1..] with G = [c̄ s; -s c].
+        for j in (m - 1)..n {
+            let t1 = t[(m - 1) + j * n];
+            let t2 = t[m + j * n];
+            t[(m - 1) + j * n] = c.conj() * t1 + t2.scale(s);
+            t[m + j * n] = c * t2 - t1.scale(s);
+        }
+        // T[..=m, m-1..=m] and Z[.., m-1..=m] are multiplied by Gᴴ on the right.
+        for i in 0..=m {
+            let t1 = t[i + (m - 1) * n];
+            let t2 = t[i + m * n];
+            t[i + (m - 1) * n] = t1 * c + t2.scale(s);
+            t[i + m * n] = t2 * c.conj() - t1.scale(s);
+        }
+        for i in 0..n {
+            let z1 = z[i + (m - 1) * n];
+            let z2 = z[i + m * n];
+            z[i + (m - 1) * n] = z1 * c + z2.scale(s);
+            z[i + m * n] = z2 * c.conj() - z1.scale(s);
+        }
+        t[m + (m - 1) * n] = Cx::zero();
+    }
+
+    Ok(ComplexSchur { z, t })
+}
+//}}}
+//{{{ fun: on_negative_real_axis
+/// Returns true if any eigenvalue on the diagonal of the triangular factor is real and not
+/// positive.
+fn on_negative_real_axis<T: MatFun>(
+    t: &[Cx<T>],
+    n: usize,
+) -> bool {
+    (0..n).any(|i| {
+        let lambda = t[i + i * n];
+        lambda.im == T::zero() && lambda.re <= T::zero()
+    })
+}
+//}}}
+//{{{ fun: triangular_sqrt
+/// Principal square root of an upper-triangular matrix by the Björck–Hammarling recurrence.
+fn triangular_sqrt<T: MatFun>(
+    t: &[Cx<T>],
+    n: usize,
+) -> Result<Vec<Cx<T>>, MatFunRawError> {
+    let mut r = vec![Cx::zero(); n * n];
+    for j in 0..n {
+        r[j + j * n] = t[j + j * n].sqrt();
+        for i in (0..j).rev() {
+            let mut s = t[i + j * n];
+            for k in (i + 1)..j {
+                s = s - r[i + k * n] * r[k + j * n];
+            }
+            let den = r[i + i * n] + r[j + j * n];
+            r[i + j * n] = if den != Cx::zero() {
+                s / den
+            } else if s == Cx::zero() {
+                Cx::zero()
+            } else {
+                return Err(MatFunRawError::NoRealSquareRoot);
+            };
+        }
+    }
+    Ok(r)
+}
+//}}}
+//{{{ fun: triangular_solve
+/// Solves `A Y = B` for upper-triangular `A` and `B` by back substitution.
+fn triangular_solve<T: MatFun>(
+    a: &[Cx<T>],
+    b: &[Cx<T>],
+    n: usize,
+) -> Vec<Cx<T>> {
+    let mut y = vec![Cx::zero(); n * n];
+    for j in 0..n {
+        for i in (0..=j).rev() {
+            let mut s = b[i + j * n];
+            for k in (i + 1)..=j {
+                s = s - a[i + k * n] * y[k + j * n];
+            }
+            y[i + j * n] = s / a[i + i * n];
+        }
+    }
+    y
+}
+//}}}
+//{{{ fun: back_transform
+/// Returns the real part of `Z F Zᴴ`.
+fn back_transform<T: MatFun>(
+    z: &[Cx<T>],
+    f: &[Cx<T>],
+    n: usize,
+) -> Vec<T> {
+    let mut zf = vec![Cx::zero(); n * n];
+    for j in 0..n {
+        for k in 0..n {
+            let fkj = f[k + j * n];
+            if fkj == Cx::zero() {
+                continue;
+            }
+            for i in 0..n {
+                zf[i + j * n] = zf[i + j * n] + z[i + k * n] * fkj;
+            }
+        }
+    }
+    let mut x = vec![T::zero(); n * n];
+    for j in 0..n {
+        for i in 0..n {
+            let mut s = Cx::zero();
+            for k in 0..n {
+                s = s + zf[i + k * n] * z[j + k * n].conj();
+            }
+            x[i + j * n] = s.re;
+        }
+    }
+    x
+}
+//}}}
+//}}}
+
+//{{{ fun: expm_raw
+/// Shared scaling and squaring algorithm for the matrix exponential of an n×n matrix.
+///
+/// Picks the lowest Padé degree m ∈ {3, 5, 7, 9, 13} whose error bound θ_m covers ‖A‖₁; beyond
+/// θ₁₃ the matrix is scaled by 2⁻ˢ, the degree-13 approximant is used and the result is squared
+/// `s` times.
+pub(crate) fn expm_raw<T: MatFun>(
+    a: &[T],
+    n: usize,
+) -> Result<Vec<T>, MatFunRawError> {
+    const B3: [f64; 4] = [120.0, 60.0, 12.0, 1.0];
+    const B5: [f64; 6] = [30240.0, 15120.0, 3360.0, 420.0, 30.0, 1.0];
+    const B7: [f64; 8] = [
+        17297280.0, 8648640.0, 1995840.0, 277200.0, 25200.0, 1512.0, 56.0, 1.0,
+    ];
+    const B9: [f64; 10] = [
+        17643225600.0,
+        8821612800.0,
+        2075673600.0,
+        302702400.0,
+        30270240.0,
+        2162160.0,
+        110880.0,
+        3960.0,
+        90.0,
+        1.0,
+    ];
+    const B13: [f64; 14] = [
+        64764752532480000.0,
+        32382376266240000.0,
+        7771770303897600.0,
+        1187353796428800.0,
+        129060195264000.0,
+        10559470521600.0,
+        670442572800.0,
+        33522128640.0,
+        1323241920.0,
+        40840800.0,
+        960960.0,
+        16380.0,
+        182.0,
+        1.0,
+    ];
+    const THETA: [f64; 5] = [
+        1.495585217958292e-2,
+        2.53939833006323e-1,
+        9.504178996162932e-1,
+        2.097847961257068e0,
+        5.371920351148152e0,
+    ];
+
+    if n == 0 {
+        return Ok(Vec::new());
+    }
+    let norm = norm_one(a, n);
+
+    // Low degrees: U = A Σ b_{2k+1} A^{2k} and V = Σ b_{2k} A^{2k}.
+    for (b, theta) in [&B3[..], &B5[..], &B7[..], &B9[..]].into_iter().zip(THETA) {
+        if norm <= T::from_f64(theta) {
+            let a2 = matmul(a, a, n);
+            let mut powers = vec![a2];
+            while 2 * (powers.len() + 1) < b.len() {
+                let next = matmul(&powers[powers.len() - 1], &powers[0], n);
+                powers.push(next);
+            }
+            let odd: Vec<(f64, &[T])> = powers
+                .iter()
+                .enumerate()
+                .map(|(k, p)| (b[2 * k + 3], &p[..]))
+                .collect();
+            let even: Vec<(f64, &[T])> = powers
+                .iter()
+                .enumerate()
+                .map(|(k, p)| (b[2 * k + 2], &p[..]))
+                .collect();
+            let u = matmul(a, &lincomb(&odd, b[1], n), n);
+            let v = lincomb(&even, b[0], n);
+            return pade_quotient(&u, &v, n);
+        }
+    }
+
+    // Degree 13 with scaling: find s with ‖A‖₁ / 2ˢ ≤ θ₁₃.
+    let two = T::one() + T::one();
+    let mut s = 0;
+    let mut scale = T::one();
+    let mut scaled_norm = norm;
+    while scaled_norm > T::from_f64(THETA[4]) {
+        scaled_norm /= two;
+        scale /= two;
+        s += 1;
+    }
+    let a1: Vec<T> = a.iter().map(|&x| x * scale).collect();
+    let a2 = matmul(&a1, &a1, n);
+    let a4 = matmul(&a2, &a2, n);
+    let a6 = matmul(&a4, &a2, n);
+
+    let u_high = lincomb(&[(B13[13], &a6), (B13[11], &a4), (B13[9], &a2)], 0.0, n);
+    let u_low = lincomb(&[(B13[7], &a6), (B13[5], &a4), (B13[3], &a2)], B13[1], n);
+    let u_inner = lincomb(&[(1.0, &matmul(&a6, &u_high, n)), (1.0, &u_low)], 0.0, n);
+    let u = matmul(&a1, &u_inner, n);
+
+    let v_high = lincomb(&[(B13[12], &a6), (B13[10], &a4), (B13[8], &a2)], 0.0, n);
+    let v_low = lincomb(&[(B13[6], &a6), (B13[4], &a4), (B13[2], &a2)], B13[0], n);
+    let v = lincomb(&[(1.0, &matmul(&a6, &v_high, n)), (1.0, &v_low)], 0.0, n);
+
+    let mut x = pade_quotient(&u, &v, n)?;
+    for _ in 0..s {
+        x = matmul(&x, &x, n);
+    }
+    Ok(x)
+}
+//}}}
+//{{{ fun: pade_quotient
+/// Solves `(V - U) X = V + U` for the Padé approximant `X`.
+fn pade_quotient<T: MatFun>(
+    u: &[T],
+    v: &[T],
+    n: usize,
+) -> Result<Vec<T>, MatFunRawError> {
+    let p: Vec<T> = v.iter().zip(u).map(|(&v, &u)| v + u).collect();
+    let q: Vec<T> = v.iter().zip(u).map(|(&v, &u)| v - u).collect();
+    Ok(solve_raw(q, p, n, n)?)
+}
+//}}}
+//{{{ fun: powm_raw
+/// Shared binary-powering algorithm for `A^k`. A negative `k` inverts `A` first with GESV.
+pub(crate) fn powm_raw<T: MatFun>(
+    a: &[T],
+    n: usize,
+    k: i32,
+) -> Result<Vec<T>, MatFunRawError> {
+    let mut base = if k < 0 {
+        solve_raw(a.to_vec(), identity(n), n, n)?
+    } else {
+        a.to_vec()
+    };
+    let mut e = k.unsigned_abs();
+    let mut result = identity(n);
+    while e > 0 {
+        if e & 1 == 1 {
+            result = matmul(&result, &base, n);
+        }
+        e >>= 1;
+        if e > 0 {
+            base = matmul(&base, &base, n);
+        }
+    }
+    Ok(result)
+}
+//}}}
+//{{{ fun: sqrtm_raw
+/// Shared Schur algorithm for the principal square root of an n×n matrix.
+pub(crate) fn sqrtm_raw<T: MatFun>(
+    a: &[T],
+    n: usize,
+) -> Result<Vec<T>, MatFunRawError> {
+    let schur = complex_schur(a, n)?;
+    if (0..n).any(|i| {
+        let lambda = schur.t[i + i * n];
+        lambda.im == T::zero() && lambda.re < T::zero()
+    }) {
+        return Err(MatFunRawError::NoRealSquareRoot);
+    }
+    let r = triangular_sqrt(&schur.t, n)?;
+    Ok(back_transform(&schur.z, &r, n))
+}
+//}}}
+//{{{ fun: logm_raw
+/// Shared inverse scaling and squaring algorithm for the principal logarithm of an n×n matrix.
+///
+/// Takes square roots of the triangular Schur factor until it is within 1/4 of the identity in
+/// the 1-norm, evaluates log(I + X) with an 8-point Gauss–Legendre quadrature of
+/// `∫₀¹ X (I + t X)⁻¹ dt`, which is the [8/8] Padé approximant, and scales back by 2ˢ. The
+/// diagonal is then replaced by the exact logarithms of the eigenvalues.
+pub(crate) fn logm_raw<T: MatFun>(
+    a: &[T],
+    n: usize,
+) -> Result<Vec<T>, MatFunRawError> {
+    const NODES: [f64; 8] = [
+        0.019855071751231856,
+        0.10166676129318664,
+        0.2372337950418355,
+        0.4082826787521751,
+        0.591717321247825,
+        0.7627662049581645,
+        0.8983332387068134,
+        0.9801449282487681,
+    ];
+    const WEIGHTS: [f64; 8] = [
+        0.05061426814518813,
+        0.11119051722668724,
+        0.15685332293894363,
+        0.181341891689181,
+        0.181341891689181,
+        0.15685332293894363,
+        0.11119051722668724,
+        0.05061426814518813,
+    ];
+    const MAX_SQRTS: usize = 64;
+
+    let schur = complex_schur(a, n)?;
+    if on_negative_real_axis(&schur.t, n) {
+        return Err(MatFunRawError::NoRealLogarithm);
+    }
+
+    let eye: Vec<Cx<T>> = (0..n * n)
+        .map(|idx| {
+            if idx % (n + 1) == 0 {
+                Cx::one()
+            } else {
+                Cx::zero()
+            }
+        })
+        .collect();
+    let distance = |t: &[Cx<T>]| {
+        t.chunks(n)
+            .zip(eye.chunks(n))
+            .map(|(col, e)| {
+                col.iter()
+                    .zip(e)
+                    .fold(T::zero(), |s, (&x, &y)| s + (x - y).abs())
+            })
+            .fold(T::zero(), |acc, s| acc.max(s))
+    };
+
+    let mut t = schur.t.clone();
+    let mut s = 0;
+    while s < MAX_SQRTS && distance(&t) > T::from_f64(0.25) {
+        t = triangular_sqrt(&t, n).map_err(|_| MatFunRawError::NoRealLogarithm)?;
+        s += 1;
+    }
+
+    let x: Vec<Cx<T>> = t.iter().zip(&eye).map(|(&t, &e)| t - e).collect();
+    let mut l = vec![Cx::zero(); n * n];
+    for (&node, &weight) in NODES.iter().zip(&WEIGHTS) {
+        let node = T::from_f64(node);
+        let weight = T::from_f64(weight);
+        let shifted: Vec<Cx<T>> = eye
+            .iter()
+            .zip(&x)
+            .map(|(&e, &x)| e + x.scale(node))
+            .collect();
+        for (l, y) in l.iter_mut().zip(triangular_solve(&shifted, &x, n)) {
+            *l = *l + y.scale(weight);
+        }
+    }
+
+    let two = T::one() + T::one();
+    let factor = (0..s).fold(T::one(), |f, _| f * two);
+    for l in l.iter_mut() {
+        *l = l.scale(factor);
+    }
+    for i in 0..n {
+        l[i + i * n] = schur.t[i + i * n].ln();
+    }
+
+    Ok(back_transform(&schur.z, &l, n))
+}
+//}}}
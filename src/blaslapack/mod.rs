@@ -3,10 +3,22 @@
 //! Groups all raw BLAS and LAPACK bindings used by the crate into named sub-modules, each
 //! corresponding to a single subroutine family. The wrappers expose Rust traits rather than raw
 //! `unsafe` function pointers, so callers work with typed inputs and outputs and `unsafe` blocks
-//! are confined to the implementation files. Sub-modules: [`common`] (shared types), [`gemm`]
-//! (matrix–matrix multiply), [`gemv`] (matrix–vector multiply), [`getrf`] (LU), [`potrf`]
-//! (Cholesky), [`geqrf`] + [`orgqr`] (QR), [`geev`] (general eigenvalues), [`syev`] (symmetric
-//! eigenvalues), [`gees`] (Schur), [`gesv`] (linear solve).
+//! are confined to the system backend. Sub-modules: [`common`] (shared types), [`gemm`]
+//! (matrix–matrix multiply), [`gemv`] (matrix–vector multiply), [`gbmv`] (band matrix–vector
+//! multiply), [`getrf`] (LU), [`getri`] (inverse), [`potrf`] (Cholesky), [`geqrf`] + [`orgqr`]
+//! (QR), [`geqp3`] (column-pivoted QR), [`geev`] (general eigenvalues), [`syev`] + [`syevr`]
+//! (symmetric eigenvalues), [`ggev`] + [`sygv`] (generalized eigenvalues), [`gees`] + [`trsen`]
+//! (Schur and reordered Schur), [`gehrd`] + [`orghr`] (Hessenberg), [`gesv`] + [`gesvx`] (linear
+//! solve, plain and expert), [`gesdd`] + [`gesvd`] (singular value decomposition), [`gels`] +
+//! [`gelsd`] (least squares), [`gecon`] (condition number estimation), [`sytrf`]
+//! (symmetric-indefinite LDLᵀ), [`getrs`] + [`potrs`] + [`ormqr`] + [`sytrs`] (solves from stored
+//! factorisations), [`trtrs`] + [`trsv`] + [`trsm`] (triangular solve), [`trmm`] (triangular
+//! multiply), [`gbsv`] + [`pbsv`] (banded solves), [`gtsv`] + [`ptsv`] (tridiagonal solves),
+//! [`matrix_functions`] (matrix exponential, logarithm, square root and powers), [`polar`] (polar
+//! decomposition and orthogonal Procrustes). Every impl dispatches through the selectable
+//! [`Backend`] of the [`backend`] sub-module, whose reference implementation runs the pure-Rust
+//! routines of the `native` sub-module; no wrapper calls the system library directly, and only the
+//! system backend is compiled out when that library is not linked.
 //--------------------------------------------------------------------------------------------------
 
 //{{{ crate imports
@@ -17,17 +29,49 @@
 //}}}
 //--------------------------------------------------------------------------------------------------
 
+mod backend;
 mod common;
+mod gbmv;
+mod gbsv;
+mod gecon;
 mod gees;
 mod geev;
+mod gehrd;
+mod gels;
+mod gelsd;
 mod gemm;
 mod gemv;
+mod geqp3;
 mod geqrf;
+mod gesdd;
 mod gesv;
+mod gesvd;
+mod gesvx;
 mod getrf;
+mod getri;
+mod getrs;
+mod ggev;
+mod gtsv;
+mod matrix_functions;
+mod native;
+mod orghr;
 mod orgqr;
+mod ormqr;
+mod pbsv;
+mod polar;
 mod potrf;
+mod potrs;
+mod ptsv;
 mod syev;
+mod syevr;
+mod sygv;
+mod sytrf;
+mod sytrs;
+mod trmm;
+mod trsen;
+mod trsm;
+mod trsv;
+mod trtrs;
 
 /// Abstracts over matrix storage for generic LAPACK dispatch.
 #[allow(dead_code)]
@@ -37,14 +81,63 @@ pub(crate) trait MatrixBuffer: crate::common::Shape {
     fn as_mut_slice(&mut self) -> &mut [Self::Scalar];
 }
 
+pub use backend::{
+    current as current_backend, set as set_backend, with as with_backend, Backend, ReferenceBackend,
+};
 pub(crate) use common::AsI32;
+pub(crate) use gbmv::Gbmv;
+pub(crate) use gbsv::{band_solve_raw, BandSolveRawError, Gbsv};
+pub(crate) use gecon::{lu_rcond_raw, Error as GeconError, Gecon};
 pub(crate) use gees::{schur_raw, Error as ShurRawError, Gees};
 pub(crate) use geev::{eig_raw, Error as EigRawError, Geev};
+pub(crate) use gehrd::{hessenberg_raw, Gehrd, HessenbergRawError};
+pub(crate) use gels::{lstsq_raw, Gels, LstsqRawError};
+pub(crate) use gelsd::Gelsd;
 pub(crate) use gemm::{matmul_dispatch, Gemm};
 pub(crate) use gemv::Gemv;
+pub(crate) use geqp3::{qr_pivoted_raw, Geqp3, QrPivotedRawError};
 pub(crate) use geqrf::{qr_raw, Geqrf, QrRawError};
+pub(crate) use gesdd::{
+    null_space_raw, orth_raw, pinv_raw, rank_raw, svd_raw, Gesdd, SingularValues, SvdRawError,
+};
 pub(crate) use gesv::{solve_raw, Error as SolveRawError, Gesv};
+pub(crate) use gesvd::Gesvd;
+pub(crate) use gesvx::{solve_expert_raw, Gesvx, SolveExpertRawError};
 pub(crate) use getrf::{lu_raw, Error as LuRawError, Getrf};
+pub(crate) use getri::{inverse_raw, Getri, InverseRawError};
+pub(crate) use getrs::{
+    lu_det_raw, lu_factor_raw, lu_slogdet_raw, lu_solve_raw, slogdet_raw, Getrs, LuFactorRawError,
+};
+pub(crate) use ggev::{generalized_eig_raw, Error as GeneralizedEigRawError, Ggev};
+pub(crate) use gtsv::{tridiagonal_solve_raw, Gtsv, TridiagonalSolveRawError};
+pub(crate) use matrix_functions::{
+    expm_raw, logm_raw, powm_raw, sqrtm_raw, MatFun, MatFunRawError,
+};
+pub(crate) use orghr::Orghr;
 pub(crate) use orgqr::Orgqr;
+pub(crate) use ormqr::{qr_det_raw, qr_factor_raw, qr_solve_raw, Ormqr, QrFactorRawError};
+pub(crate) use pbsv::{band_spd_solve_raw, BandSpdSolveRawError, Pbsv};
+pub(crate) use polar::{polar_raw, procrustes_raw, PolarRaw, PolarRawError};
 pub(crate) use potrf::{cholesky_raw, Error as CholeskyRawError, Potrf};
+pub(crate) use potrs::{
+    cholesky_det_raw, cholesky_factor_raw, cholesky_logdet_raw, cholesky_solve_raw,
+    CholeskyFactorRawError, Potrs,
+};
+pub(crate) use ptsv::{tridiagonal_spd_solve_raw, Ptsv, TridiagonalSpdSolveRawError};
 pub(crate) use syev::{symeig_raw, Error as SymEigRawError, Syev};
+pub(crate) use syevr::{symeig_range_raw, Error as SymEigRangeRawError, Syevr};
+pub(crate) use sygv::{generalized_symeig_raw, GeneralizedSymEigRawError, Sygv};
+pub(crate) use sytrf::Sytrf;
+pub(crate) use sytrs::{
+    ldlt_d_raw, ldlt_det_raw, ldlt_factor_raw, ldlt_inertia_raw, ldlt_l_raw, ldlt_solve_raw,
+    LdltRawError, Sytrs,
+};
+pub(crate) use trmm::{triangular_matmul_raw, Trmm};
+pub(crate) use trsen::{schur_ordered_raw, Error as TrsenRawError, SchurOrderedRawError, Trsen};
+pub(crate) use trsm::{triangular_solve_raw, TriangularSolveRawError, Trsm};
+pub(crate) use trsv::Trsv;
+pub(crate) use trtrs::Trtrs;
+
+system_only! {
+    pub use backend::SystemBackend;
+}
@@ -0,0 +1,317 @@
+//! Native band and tridiagonal solvers.
+//!
+//! [`gbsv`] follows LAPACK `?gbsv` through ports of the unblocked `?gbtf2` and `?gbtrs`: the band
+//! LU factors overwrite `ab` in LAPACK's layout, with `kl` extra rows for the fill-in created by
+//! pivoting, so that the output matches the library's. [`pbsv`] factors a Hermitian
+//! positive-definite band matrix as `L Lᴴ` or `Uᴴ U` within its band, [`gtsv`] eliminates a
+//! tridiagonal system with partial pivoting as `?gtsv` does, and [`ptsv`] factors a Hermitian
+//! positive-definite tridiagonal matrix as `L D Lᴴ` as `?ptsv` does. All return LAPACK's `info`
+//! code and leave `b` holding the solution.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::Scalar;
+use crate::common::Zero;
+use crate::float::Float;
+//}}}
+//{{{ std imports
+use std::cmp::Ordering;
+//}}}
+//{{{ dep imports
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ fun: gbsv
+/// Solves `A X = B` for the n×n band matrix `A` with `kl` sub- and `ku` superdiagonals, stored in
+/// rows `kl..2 kl + ku` of `ab` as `A[i, j]` at `ab[kl + ku + i − j + j ldab]`.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn gbsv<T>(
+    n: i32,
+    kl: i32,
+    ku: i32,
+    nrhs: i32,
+    ab: &mut [T],
+    ldab: i32,
+    ipiv: &mut [i32],
+    b: &mut [T],
+    ldb: i32,
+) -> i32
+where
+    T: Scalar,
+{
+    let (n, nrhs) = (n as usize, nrhs as usize);
+    let (kl, ku, ldab, ldb) = (kl as usize, ku as usize, ldab as usize, ldb as usize);
+    let kv = ku + kl;
+    let at = |r: usize, c: usize| r + c * ldab;
+
+    // Factorisation, as ?gbtf2 with m = n
+    for j in ku + 1..kv.min(n) {
+        for i in kv - j..kl {
+            ab[at(i, j)] = T::zero();
+        }
+    }
+    let mut info = 0;
+    let mut ju = 0;
+    for j in 0..n {
+        if j + kv < n {
+            for i in 0..kl {
+                ab[at(i, j + kv)] = T::zero();
+            }
+        }
+        let km = kl.min(n - 1 - j);
+        let mut jp = 0;
+        for p in 1..=km {
+            if ab[at(kv + p, j)].modulus() > ab[at(kv + jp, j)].modulus() {
+                jp = p;
+            }
+        }
+        ipiv[j] = (j + jp + 1) as i32;
+        if ab[at(kv + jp, j)] == T::zero() {
+            if info == 0 {
+                info = (j + 1) as i32;
+            }
+            continue;
+        }
+        ju = ju.max((j + ku + jp).min(n - 1));
+        if jp != 0 {
+            for c in 0..=ju - j {
+                ab.swap(at(kv + jp - c, j + c), at(kv - c, j + c));
+            }
+        }
+        if km > 0 {
+            let pivot = ab[at(kv, j)];
+            for p in 1..=km {
+                ab[at(kv + p, j)] /= pivot;
+            }
+            for c in 1..=ju - j {
+                let t = ab[at(kv - c, j + c)];
+                if t != T::zero() {
+                    for p in 1..=km {
+                        let l = ab[at(kv + p, j)];
+                        ab[at(kv + p - c, j + c)] -= l * t;
+                    }
+                }
+            }
+        }
+    }
+    if info != 0 {
+        return info;
+    }
+
+    // Solution, as ?gbtrs with trans = 'N'
+    for r in 0..nrhs {
+        let x = &mut b[r * ldb..r * ldb + n];
+        for j in 0..n.saturating_sub(1) {
+            x.swap(j, (ipiv[j] - 1) as usize);
+            let xj = x[j];
+            for p in 1..=kl.min(n - 1 - j) {
+                x[j + p] -= ab[at(kv + p, j)] * xj;
+            }
+        }
+        for j in (0..n).rev() {
+            x[j] /= ab[at(kv, j)];
+            let xj = x[j];
+            for i in j.saturating_sub(kv)..j {
+                x[i] -= ab[at(kv + i - j, j)] * xj;
+            }
+        }
+    }
+    0
+}
+//}}}
+//{{{ fun: pbsv
+/// Solves `A X = B` for the Hermitian positive-definite n×n band matrix `A` with `kd`
+/// off-diagonals, stored in triangle `uplo` in LAPACK's band layout, overwriting `ab` with its
+/// Cholesky factor.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn pbsv<T>(
+    uplo: u8,
+    n: i32,
+    kd: i32,
+    nrhs: i32,
+    ab: &mut [T],
+    ldab: i32,
+    b: &mut [T],
+    ldb: i32,
+) -> i32
+where
+    T: Scalar,
+{
+    let (n, nrhs) = (n as usize, nrhs as usize);
+    let (kd, ldab, ldb) = (kd as usize, ldab as usize, ldb as usize);
+    let lower = uplo == b'L' || uplo == b'l';
+    // Storage index of entry (i, k), i ≥ k, of L, or of its mirror in U = Lᴴ
+    let at = |i: usize, k: usize| {
+        if lower {
+            i - k + k * ldab
+        } else {
+            kd + k - i + i * ldab
+        }
+    };
+    let get = |ab: &[T], i: usize, k: usize| {
+        if lower {
+            ab[at(i, k)]
+        } else {
+            ab[at(i, k)].conj()
+        }
+    };
+
+    for j in 0..n {
+        let mut ajj = ab[at(j, j)].real_part();
+        for k in j.saturating_sub(kd)..j {
+            let v = ab[at(j, k)].modulus();
+            ajj -= v * v;
+        }
+        // Also stops on NaN
+        if ajj.partial_cmp(&T::Real::zero()) != Some(Ordering::Greater) {
+            ab[at(j, j)] = T::from_real(ajj);
+            return (j + 1) as i32;
+        }
+        let ajj = ajj.sqrt();
+        ab[at(j, j)] = T::from_real(ajj);
+        for i in j + 1..n.min(j + kd + 1) {
+            let mut s = get(ab, i, j);
+            for k in i.saturating_sub(kd)..j {
+                s -= get(ab, i, k) * get(ab, j, k).conj();
+            }
+            let lij = s / T::from_real(ajj);
+            ab[at(i, j)] = if lower { lij } else { lij.conj() };
+        }
+    }
+
+    for r in 0..nrhs {
+        let x = &mut b[r * ldb..r * ldb + n];
+        for i in 0..n {
+            let mut s = x[i];
+            for (k, &xk) in x.iter().enumerate().take(i).skip(i.saturating_sub(kd)) {
+                s -= get(ab, i, k) * xk;
+            }
+            x[i] = s / get(ab, i, i);
+        }
+        for i in (0..n).rev() {
+            let mut s = x[i];
+            for (k, &xk) in x.iter().enumerate().take(n.min(i + kd + 1)).skip(i + 1) {
+                s -= get(ab, k, i).conj() * xk;
+            }
+            x[i] = s / get(ab, i, i);
+        }
+    }
+    0
+}
+//}}}
+//{{{ fun: gtsv
+/// Solves `A X = B` for the n×n tridiagonal `A` with sub-diagonal `dl`, diagonal `d` and
+/// super-diagonal `du`, which are overwritten by the elimination as in `?gtsv`.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn gtsv<T>(
+    n: i32,
+    nrhs: i32,
+    dl: &mut [T],
+    d: &mut [T],
+    du: &mut [T],
+    b: &mut [T],
+    ldb: i32,
+) -> i32
+where
+    T: Scalar,
+{
+    let (n, nrhs, ldb) = (n as usize, nrhs as usize, ldb as usize);
+    if n == 0 {
+        return 0;
+    }
+    for i in 0..n - 1 {
+        if d[i].modulus() >= dl[i].modulus() {
+            // No row interchange
+            if d[i] == T::zero() {
+                return (i + 1) as i32;
+            }
+            let fact = dl[i] / d[i];
+            d[i + 1] -= fact * du[i];
+            for r in 0..nrhs {
+                let bi = b[i + r * ldb];
+                b[i + 1 + r * ldb] -= fact * bi;
+            }
+            if i + 2 < n {
+                dl[i] = T::zero();
+            }
+        } else {
+            // Interchange rows i and i + 1
+            let fact = d[i] / dl[i];
+            d[i] = dl[i];
+            let temp = d[i + 1];
+            d[i + 1] = du[i] - fact * temp;
+            if i + 2 < n {
+                dl[i] = du[i + 1];
+                du[i + 1] = -fact * dl[i];
+            }
+            du[i] = temp;
+            for r in 0..nrhs {
+                let temp = b[i + r * ldb];
+                b[i + r * ldb] = b[i + 1 + r * ldb];
+                b[i + 1 + r * ldb] = temp - fact * b[i + 1 + r * ldb];
+            }
+        }
+    }
+    if d[n - 1] == T::zero() {
+        return n as i32;
+    }
+
+    for r in 0..nrhs {
+        let x = &mut b[r * ldb..r * ldb + n];
+        x[n - 1] /= d[n - 1];
+        if n > 1 {
+            x[n - 2] = (x[n - 2] - du[n - 2] * x[n - 1]) / d[n - 2];
+        }
+        for i in (0..n.saturating_sub(2)).rev() {
+            x[i] = (x[i] - du[i] * x[i + 1] - dl[i] * x[i + 2]) / d[i];
+        }
+    }
+    0
+}
+//}}}
+//{{{ fun: ptsv
+/// Solves `A X = B` for the Hermitian positive-definite n×n tridiagonal `A` with real diagonal
+/// `d` and sub-diagonal `e`, overwriting them with `D` and the sub-diagonal of the unit `L` in
+/// `A = L D Lᴴ`.
+pub(crate) fn ptsv<T>(
+    n: i32,
+    nrhs: i32,
+    d: &mut [T::Real],
+    e: &mut [T],
+    b: &mut [T],
+    ldb: i32,
+) -> i32
+where
+    T: Scalar,
+{
+    let (n, nrhs, ldb) = (n as usize, nrhs as usize, ldb as usize);
+    let zero = T::Real::zero();
+    for i in 0..n {
+        // Also stops on NaN
+        if d[i].partial_cmp(&zero) != Some(Ordering::Greater) {
+            return (i + 1) as i32;
+        }
+        if i + 1 < n {
+            let ei = e[i];
+            e[i] = ei / T::from_real(d[i]);
+            d[i + 1] -= (e[i] * ei.conj()).real_part();
+        }
+    }
+
+    for r in 0..nrhs {
+        let x = &mut b[r * ldb..r * ldb + n];
+        for i in 1..n {
+            let prev = x[i - 1];
+            x[i] -= e[i - 1] * prev;
+        }
+        for i in 0..n {
+            x[i] /= T::from_real(d[i]);
+        }
+        for i in (0..n.saturating_sub(1)).rev() {
+            let next = x[i + 1];
+            x[i] -= e[i].conj() * next;
+        }
+    }
+    0
+}
+//}}}
@@ -0,0 +1,225 @@
+//! Native general matrix–matrix and matrix–vector products.
+//!
+//! Provides [`gemm`], [`gemv`] and [`gbmv`] with the semantics of BLAS `?gemm`, `?gemv` and `?gbmv`
+//! on column-major storage, including the conjugate-transpose option. As in BLAS, the output is not
+//! read when `beta` is zero, so it may hold uninitialised or non-finite values on entry.
+//! Untransposed operands are traversed column by column with `axpy` updates; transposed ones by dot
+//! products.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::Scalar;
+use crate::common::Transpose;
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ fun: op_at
+/// Entry `(i, j)` of `op(A)` for the column-major `a` with leading dimension `lda`.
+#[inline]
+fn op_at<T>(
+    tr: Transpose,
+    a: &[T],
+    lda: usize,
+    i: usize,
+    j: usize,
+) -> T
+where
+    T: Scalar,
+{
+    match tr {
+        Transpose::NoTrans => a[i + j * lda],
+        Transpose::Trans => a[j + i * lda],
+        Transpose::ConjTrans => a[j + i * lda].conj(),
+    }
+}
+//}}}
+//{{{ fun: gemm
+/// Computes `C ← α op(A) op(B) + β C`, where `op(A)` is m×k, `op(B)` is k×n and `C` is m×n.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn gemm<T>(
+    tr1: Transpose,
+    tr2: Transpose,
+    m: i32,
+    n: i32,
+    k: i32,
+    alpha: T,
+    a: &[T],
+    lda: i32,
+    b: &[T],
+    ldb: i32,
+    beta: T,
+    c: &mut [T],
+    ldc: i32,
+) where
+    T: Scalar,
+{
+    let (m, n, k) = (m as usize, n as usize, k as usize);
+    let (lda, ldb, ldc) = (lda as usize, ldb as usize, ldc as usize);
+    for j in 0..n {
+        let cj = &mut c[j * ldc..j * ldc + m];
+        if beta == T::zero() {
+            cj.fill(T::zero());
+        } else if beta != T::one() {
+            for v in cj.iter_mut() {
+                *v *= beta;
+            }
+        }
+        if alpha == T::zero() {
+            continue;
+        }
+        match tr1 {
+            Transpose::NoTrans => {
+                for l in 0..k {
+                    let t = alpha * op_at(tr2, b, ldb, l, j);
+                    if t != T::zero() {
+                        let al = &a[l * lda..l * lda + m];
+                        for (v, &x) in cj.iter_mut().zip(al) {
+                            *v += t * x;
+                        }
+                    }
+                }
+            }
+            _ => {
+                for (i, v) in cj.iter_mut().enumerate() {
+                    let mut sum = T::zero();
+                    for l in 0..k {
+                        sum += op_at(tr1, a, lda, i, l) * op_at(tr2, b, ldb, l, j);
+                    }
+                    *v += alpha * sum;
+                }
+            }
+        }
+    }
+}
+//}}}
+//{{{ fun: gemv
+/// Computes `y ← α op(A) x + β y` for the m×k matrix `A`, with strides `incx` and `incy`.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn gemv<T>(
+    tr: Transpose,
+    m: i32,
+    k: i32,
+    alpha: T,
+    a: &[T],
+    lda: i32,
+    x: &[T],
+    incx: i32,
+    beta: T,
+    y: &mut [T],
+    incy: i32,
+) where
+    T: Scalar,
+{
+    let (m, k, lda) = (m as usize, k as usize, lda as usize);
+    let (incx, incy) = (incx as usize, incy as usize);
+    let (leny, lenx) = match tr {
+        Transpose::NoTrans => (m, k),
+        _ => (k, m),
+    };
+    for i in 0..leny {
+        let yi = &mut y[i * incy];
+        *yi = if beta == T::zero() {
+            T::zero()
+        } else {
+            beta * *yi
+        };
+    }
+    if alpha == T::zero() {
+        return;
+    }
+    match tr {
+        Transpose::NoTrans => {
+            for j in 0..lenx {
+                let t = alpha * x[j * incx];
+                if t != T::zero() {
+                    for i in 0..leny {
+                        y[i * incy] += t * a[i + j * lda];
+                    }
+                }
+            }
+        }
+        _ => {
+            for i in 0..leny {
+                let mut sum = T::zero();
+                for j in 0..lenx {
+                    sum += op_at(tr, a, lda, i, j) * x[j * incx];
+                }
+                y[i * incy] += alpha * sum;
+            }
+        }
+    }
+}
+//}}}
+//{{{ fun: gbmv
+/// Computes `y ← α op(A) x + β y` for the m×k band matrix `A` with `kl` sub- and `ku`
+/// superdiagonals, stored in BLAS band format: `A[i, j]` at `a[ku + i − j + j lda]`.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn gbmv<T>(
+    tr: Transpose,
+    m: i32,
+    k: i32,
+    kl: i32,
+    ku: i32,
+    alpha: T,
+    a: &[T],
+    lda: i32,
+    x: &[T],
+    incx: i32,
+    beta: T,
+    y: &mut [T],
+    incy: i32,
+) where
+    T: Scalar,
+{
+    let (m, k, lda) = (m as usize, k as usize, lda as usize);
+    let (kl, ku) = (kl as usize, ku as usize);
+    let (incx, incy) = (incx as usize, incy as usize);
+    let leny = match tr {
+        Transpose::NoTrans => m,
+        _ => k,
+    };
+    for i in 0..leny {
+        let yi = &mut y[i * incy];
+        *yi = if beta == T::zero() {
+            T::zero()
+        } else {
+            beta * *yi
+        };
+    }
+    if alpha == T::zero() {
+        return;
+    }
+    for j in 0..k {
+        let rows = j.saturating_sub(ku)..m.min(j + kl + 1);
+        let band = |i: usize| a[ku + i - j + j * lda];
+        match tr {
+            Transpose::NoTrans => {
+                let t = alpha * x[j * incx];
+                if t != T::zero() {
+                    for i in rows {
+                        y[i * incy] += t * band(i);
+                    }
+                }
+            }
+            Transpose::Trans => {
+                let mut sum = T::zero();
+                for i in rows {
+                    sum += band(i) * x[i * incx];
+                }
+                y[j * incy] += alpha * sum;
+            }
+            Transpose::ConjTrans => {
+                let mut sum = T::zero();
+                for i in rows {
+                    sum += band(i).conj() * x[i * incx];
+                }
+                y[j * incy] += alpha * sum;
+            }
+        }
+    }
+}
+//}}}
@@ -0,0 +1,114 @@
+//! Native Cholesky factorisation.
+//!
+//! [`potrf`] follows LAPACK `?potrf`: with `uplo = 'L'` it computes `A = L Lᴴ` in the lower
+//! triangle, with `uplo = 'U'` it computes `A = Uᴴ U` in the upper triangle, and the other triangle
+//! is neither read nor written. The factorisation stops at the first leading minor that is not
+//! positive definite and returns its order as `info`. [`potrs`] solves with the factor by two
+//! triangular solves, as `?potrs` does.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::triangular::{triangle, trsm};
+use super::Scalar;
+use crate::common::{Diagonal, Side, Transpose, Triangle, Zero};
+use crate::float::Float;
+//}}}
+//{{{ std imports
+use std::cmp::Ordering;
+//}}}
+//{{{ dep imports
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ fun: potrf
+/// Factors the Hermitian positive-definite n×n matrix `a` in place, returning LAPACK's `info`.
+pub(crate) fn potrf<T>(
+    uplo: u8,
+    n: i32,
+    a: &mut [T],
+    lda: i32,
+) -> i32
+where
+    T: Scalar,
+{
+    let (n, lda) = (n as usize, lda as usize);
+    let lower = uplo == b'L' || uplo == b'l';
+    // Storage index of entry (i, k) of L, or of its mirror in U = Lᴴ
+    let at = |i: usize, k: usize| if lower { i + k * lda } else { k + i * lda };
+    for j in 0..n {
+        let mut ajj = a[at(j, j)].real_part();
+        for k in 0..j {
+            let v = a[at(j, k)].modulus();
+            ajj -= v * v;
+        }
+        // Also stops on NaN
+        if ajj.partial_cmp(&T::Real::zero()) != Some(Ordering::Greater) {
+            a[at(j, j)] = T::from_real(ajj);
+            return (j + 1) as i32;
+        }
+        let ajj = ajj.sqrt();
+        a[at(j, j)] = T::from_real(ajj);
+        for i in j + 1..n {
+            let mut s = if lower {
+                a[at(i, j)]
+            } else {
+                a[at(i, j)].conj()
+            };
+            for k in 0..j {
+                s -= factor(a, lower, at(i, k)) * factor(a, lower, at(j, k)).conj();
+            }
+            let lij = s / T::from_real(ajj);
+            a[at(i, j)] = if lower { lij } else { lij.conj() };
+        }
+    }
+    0
+}
+//}}}
+//{{{ fun: potrs
+/// Solves `A X = B` for the n×nrhs `b`, given the Cholesky factor of the n×n `A` from [`potrf`]
+/// in triangle `uplo`, overwriting `b` with `X`. Always returns 0.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn potrs<T>(
+    uplo: u8,
+    n: i32,
+    nrhs: i32,
+    a: &[T],
+    lda: i32,
+    b: &mut [T],
+    ldb: i32,
+) -> i32
+where
+    T: Scalar,
+{
+    let uplo = triangle(uplo);
+    let one = T::one();
+    let diag = Diagonal::NonUnit;
+    // A = L Lᴴ or Uᴴ U: solve with the factor that is lower triangular first
+    let (first, second) = match uplo {
+        Triangle::Lower => (Transpose::NoTrans, Transpose::ConjTrans),
+        Triangle::Upper => (Transpose::ConjTrans, Transpose::NoTrans),
+    };
+    trsm(Side::Left, uplo, first, diag, n, nrhs, one, a, lda, b, ldb);
+    trsm(Side::Left, uplo, second, diag, n, nrhs, one, a, lda, b, ldb);
+    0
+}
+//}}}
+//{{{ fun: factor
+/// Entry of `L` stored at `idx`: as is in the lower triangle, conjugated in the upper one where
+/// `U = Lᴴ` is stored.
+#[inline]
+fn factor<T>(
+    a: &[T],
+    lower: bool,
+    idx: usize,
+) -> T
+where
+    T: Scalar,
+{
+    if lower {
+        a[idx]
+    } else {
+        a[idx].conj()
+    }
+}
+//}}}
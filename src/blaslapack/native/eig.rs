@@ -0,0 +1,312 @@
+//! Native general eigenvalue and Schur drivers.
+//!
+//! [`gees`] computes the real Schur form and Schur vectors of a real matrix, as LAPACK `?gees` does
+//! without eigenvalue ordering. [`geev_real`] and [`geev_complex`] compute eigenvalues and left and
+//! right eigenvectors with the layout and normalisation of `?geev`. Eigenvectors come from the
+//! complex triangular Schur form by back substitution; for real matrices the standardised 2×2
+//! blocks of the real Schur form are first split with a unitary rotation built from the block's
+//! eigenvector. Diagonal differences that vanish, as for repeated eigenvalues, are perturbed to
+//! `ε ‖T‖` as in LAPACK `?trevc`.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::schur::{complex_schur, hessenberg, real_schur};
+use super::{normalise_phase, Scalar};
+use crate::common::{ComplexField, Zero};
+use crate::float::Float;
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ fun: gees
+/// Overwrites the real n×n `a` with its real Schur form `T`, writing the eigenvalues to `wr` and
+/// `wi` and, with `jobvs = 'V'`, the Schur vectors to `vs`. Returns LAPACK's `info` code.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn gees<T>(
+    jobvs: u8,
+    n: i32,
+    a: &mut [T],
+    lda: i32,
+    wr: &mut [T],
+    wi: &mut [T],
+    vs: &mut [T],
+    ldvs: i32,
+) -> i32
+where
+    T: Scalar<Real = T> + Float,
+{
+    let (n, lda, ldvs) = (n as usize, lda as usize, ldvs as usize);
+    let (mut h, mut z) = (load(a, n, lda), identity(n));
+    hessenberg(&mut h, &mut z, n);
+    let info = real_schur(&mut h, &mut z, n, wr, wi);
+    if info != 0 {
+        return info;
+    }
+    store(&h, a, n, lda);
+    if jobvs == b'V' || jobvs == b'v' {
+        store(&z, vs, n, ldvs);
+    }
+    0
+}
+//}}}
+//{{{ fun: geev_complex
+/// Computes the eigenvalues `w` of the complex n×n `a` and, as selected by `jobvl` and `jobvr`,
+/// its left and right eigenvectors. Returns LAPACK's `info` code.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn geev_complex<T>(
+    jobvl: u8,
+    jobvr: u8,
+    n: i32,
+    a: &[T],
+    lda: i32,
+    w: &mut [T],
+    vl: &mut [T],
+    ldvl: i32,
+    vr: &mut [T],
+    ldvr: i32,
+) -> i32
+where
+    T: Scalar,
+{
+    let (n, lda) = (n as usize, lda as usize);
+    let (mut t, mut z) = (load(a, n, lda), identity(n));
+    hessenberg(&mut t, &mut z, n);
+    let info = complex_schur(&mut t, &mut z, n);
+    if info != 0 {
+        return info;
+    }
+    for (i, wi) in w[..n].iter_mut().enumerate() {
+        *wi = t[i + i * n];
+    }
+    if jobvr == b'V' || jobvr == b'v' {
+        store(&eigvecs(&t, &z, n, false), vr, n, ldvr as usize);
+    }
+    if jobvl == b'V' || jobvl == b'v' {
+        store(&eigvecs(&t, &z, n, true), vl, n, ldvl as usize);
+    }
+    0
+}
+//}}}
+//{{{ fun: geev_real
+/// Computes the eigenvalues `wr + i wi` of the real n×n `a` and, as selected by `jobvl` and
+/// `jobvr`, its left and right eigenvectors in `?geev`'s packed real layout: a conjugate pair
+/// occupies two columns holding the real and imaginary parts of the vector belonging to the
+/// eigenvalue with positive imaginary part. Returns LAPACK's `info` code.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn geev_real<T>(
+    jobvl: u8,
+    jobvr: u8,
+    n: i32,
+    a: &[T],
+    lda: i32,
+    wr: &mut [T],
+    wi: &mut [T],
+    vl: &mut [T],
+    ldvl: i32,
+    vr: &mut [T],
+    ldvr: i32,
+) -> i32
+where
+    T: Scalar<Real = T> + Float,
+    T::Complex: Scalar<Real = T>,
+{
+    let (n, lda) = (n as usize, lda as usize);
+    let (mut h, mut z) = (load(a, n, lda), identity(n));
+    hessenberg(&mut h, &mut z, n);
+    let info = real_schur(&mut h, &mut z, n, wr, wi);
+    if info != 0 {
+        return info;
+    }
+    let want_left = jobvl == b'V' || jobvl == b'v';
+    let want_right = jobvr == b'V' || jobvr == b'v';
+    if !want_left && !want_right {
+        return 0;
+    }
+
+    // Complex triangular Schur form, splitting each 2×2 block with a unitary G whose first column
+    // is the block's eigenvector for wr + i wi
+    let mut t: Vec<T::Complex> = h.iter().map(|&v| T::Complex::from_real(v)).collect();
+    let mut zc: Vec<T::Complex> = z.iter().map(|&v| T::Complex::from_real(v)).collect();
+    let mut i = 0;
+    while i < n {
+        if wi[i] == T::zero() {
+            i += 1;
+            continue;
+        }
+        let lambda = T::Complex::from_parts(wr[i], wi[i]);
+        let u1 = t[i + (i + 1) * n];
+        let u2 = lambda - t[i + i * n];
+        let norm = u1.modulus().hypot(u2.modulus());
+        let norm = T::Complex::from_real(norm);
+        let (u1, u2) = (u1 / norm, u2 / norm);
+        for j in i..n {
+            let (x, y) = (t[i + j * n], t[i + 1 + j * n]);
+            t[i + j * n] = u1.conj() * x + u2.conj() * y;
+            t[i + 1 + j * n] = u1 * y - u2 * x;
+        }
+        for r in 0..i + 2 {
+            let (x, y) = (t[r + i * n], t[r + (i + 1) * n]);
+            t[r + i * n] = u1 * x + u2 * y;
+            t[r + (i + 1) * n] = u1.conj() * y - u2.conj() * x;
+        }
+        for r in 0..n {
+            let (x, y) = (zc[r + i * n], zc[r + (i + 1) * n]);
+            zc[r + i * n] = u1 * x + u2 * y;
+            zc[r + (i + 1) * n] = u1.conj() * y - u2.conj() * x;
+        }
+        t[i + 1 + i * n] = T::Complex::zero();
+        t[i + i * n] = lambda;
+        t[i + 1 + (i + 1) * n] = lambda.conj();
+        i += 2;
+    }
+
+    if want_right {
+        pack_real(&eigvecs(&t, &zc, n, false), wi, vr, n, ldvr as usize);
+    }
+    if want_left {
+        pack_real(&eigvecs(&t, &zc, n, true), wi, vl, n, ldvl as usize);
+    }
+    0
+}
+//}}}
+//{{{ fun: eigvecs
+/// Eigenvectors of `A = Z T Zᴴ` for the upper triangular n×n `t`, one per column in diagonal
+/// order, normalised as `?geev` does. `left` selects `yᴴ A = λ yᴴ` over `A x = λ x`.
+fn eigvecs<T>(
+    t: &[T],
+    z: &[T],
+    n: usize,
+    left: bool,
+) -> Vec<T>
+where
+    T: Scalar,
+{
+    let tnorm = t.iter().fold(T::Real::zero(), |acc, v| acc + v.modulus());
+    let smin = if tnorm > T::Real::zero() {
+        T::Real::small() * tnorm
+    } else {
+        T::Real::small()
+    };
+    let solve = |d: T| {
+        if d.modulus() < smin {
+            T::from_real(smin)
+        } else {
+            d
+        }
+    };
+
+    let mut out = vec![T::zero(); n * n];
+    let mut x = vec![T::zero(); n];
+    for k in 0..n {
+        let lambda = t[k + k * n];
+        x.fill(T::zero());
+        x[k] = T::one();
+        if left {
+            // Tᴴ x = conj(λ) x by forward substitution
+            for i in k + 1..n {
+                let mut s = T::zero();
+                for j in k..i {
+                    s += t[j + i * n].conj() * x[j];
+                }
+                x[i] = -s / solve((t[i + i * n] - lambda).conj());
+            }
+        } else {
+            // T x = λ x by back substitution
+            for i in (0..k).rev() {
+                let mut s = T::zero();
+                for j in i + 1..=k {
+                    s += t[i + j * n] * x[j];
+                }
+                x[i] = -s / solve(t[i + i * n] - lambda);
+            }
+        }
+        let col = &mut out[k * n..(k + 1) * n];
+        for (j, &xj) in x.iter().enumerate() {
+            if xj != T::zero() {
+                for (c, &zv) in col.iter_mut().zip(&z[j * n..(j + 1) * n]) {
+                    *c += zv * xj;
+                }
+            }
+        }
+        normalise_phase(col);
+    }
+    out
+}
+//}}}
+//{{{ fun: pack_real
+/// Writes the complex eigenvectors `v` of a real matrix into `out` in `?geev`'s packed layout.
+fn pack_real<T>(
+    v: &[T::Complex],
+    wi: &[T],
+    out: &mut [T],
+    n: usize,
+    ld: usize,
+) where
+    T: Scalar<Real = T> + Float,
+    T::Complex: Scalar<Real = T>,
+{
+    let mut j = 0;
+    while j < n {
+        if wi[j] == T::zero() || j + 1 == n {
+            for i in 0..n {
+                out[i + j * ld] = v[i + j * n].real_part();
+            }
+            j += 1;
+        } else {
+            for i in 0..n {
+                out[i + j * ld] = v[i + j * n].real_part();
+                out[i + (j + 1) * ld] = v[i + j * n].imag_part();
+            }
+            j += 2;
+        }
+    }
+}
+//}}}
+//{{{ fun: load
+/// Copies the n×n matrix `a` with leading dimension `lda` into a contiguous buffer.
+fn load<T>(
+    a: &[T],
+    n: usize,
+    lda: usize,
+) -> Vec<T>
+where
+    T: Scalar,
+{
+    let mut out = Vec::with_capacity(n * n);
+    for j in 0..n {
+        out.extend_from_slice(&a[j * lda..j * lda + n]);
+    }
+    out
+}
+//}}}
+//{{{ fun: store
+/// Copies the contiguous n×n `h` into `a` with leading dimension `lda`.
+fn store<T>(
+    h: &[T],
+    a: &mut [T],
+    n: usize,
+    lda: usize,
+) where
+    T: Scalar,
+{
+    for j in 0..n {
+        a[j * lda..j * lda + n].copy_from_slice(&h[j * n..(j + 1) * n]);
+    }
+}
+//}}}
+//{{{ fun: identity
+/// The n×n identity in a contiguous buffer.
+fn identity<T>(n: usize) -> Vec<T>
+where
+    T: Scalar,
+{
+    let mut out = vec![T::zero(); n * n];
+    for i in 0..n {
+        out[i + i * n] = T::one();
+    }
+    out
+}
+//}}}
@@ -0,0 +1,395 @@
+//! Native expert driver for general linear systems.
+//!
+//! [`gesvx`] follows LAPACK `?gesvx` step by step: `?geequ` and `?laqge` for the optional
+//! equilibration, [`getrf`] and [`gecon`] for the factorisation and reciprocal condition number,
+//! [`getrs`] for the solve, and `?gerfs` for the iterative refinement and the error bounds. The
+//! backward error and the refinement stopping rule are `?gerfs`'s; the forward error bound, which
+//! `?gerfs` estimates, is computed exactly from the inverse of `op(A)`.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::lu::{gecon, getrf, getri, getrs};
+use super::Scalar;
+use crate::common::{One, Zero};
+use crate::float::Float;
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+/// Iterative refinement steps after which `?gerfs` stops.
+const ITMAX: usize = 5;
+
+/// Row or column scaling ratio below which `?laqge` scales.
+const THRESH: f64 = 0.1;
+
+//{{{ fun: gesvx
+/// Solves `op(A) X = B` for the n×n `a` and the n×nrhs `b`, writing `X` to `x`. With
+/// `fact = 'E'`, `a` and `b` are first equilibrated in place when that improves the conditioning,
+/// the scalings going to `r` and `c` and their kind to `equed`. The LU factors and pivots of the
+/// equilibrated `A` go to `af` and `ipiv`, its reciprocal condition number to `rcond`, the
+/// reciprocal pivot growth factor to `rpvgrw`, and the forward and backward error bounds of each
+/// column of `X` to `ferr` and `berr`. Returns LAPACK's `info` code: the 1-based index of a zero
+/// pivot, or `n + 1` when `A` is singular to working precision but `X` was still computed.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn gesvx<T>(
+    fact: u8,
+    trans: u8,
+    n: i32,
+    nrhs: i32,
+    a: &mut [T],
+    lda: i32,
+    af: &mut [T],
+    ldaf: i32,
+    ipiv: &mut [i32],
+    equed: &mut u8,
+    r: &mut [T::Real],
+    c: &mut [T::Real],
+    b: &mut [T],
+    ldb: i32,
+    x: &mut [T],
+    ldx: i32,
+    rcond: &mut T::Real,
+    ferr: &mut [T::Real],
+    berr: &mut [T::Real],
+    rpvgrw: &mut T::Real,
+) -> i32
+where
+    T: Scalar,
+{
+    let (nu, ns) = (n as usize, nrhs as usize);
+    let (lda, ldaf, ldb, ldx) = (lda as usize, ldaf as usize, ldb as usize, ldx as usize);
+    let zero = T::Real::zero();
+    let one = T::Real::one();
+    let notrans = trans == b'N' || trans == b'n';
+    *equed = b'N';
+    *rpvgrw = one;
+    if nu == 0 {
+        *rcond = one;
+        ferr[..ns].fill(zero);
+        berr[..ns].fill(zero);
+        return 0;
+    }
+
+    let (mut rowcnd, mut colcnd) = (one, one);
+    if fact == b'E' || fact == b'e' {
+        if let Some((rc, cc, amax)) = geequ(nu, a, lda, r, c) {
+            (rowcnd, colcnd) = (rc, cc);
+            *equed = laqge(nu, a, lda, r, c, rowcnd, colcnd, amax);
+        }
+    }
+    let rowequ = *equed == b'R' || *equed == b'B';
+    let colequ = *equed == b'C' || *equed == b'B';
+    let scale = if notrans {
+        rowequ.then_some(&*r)
+    } else {
+        colequ.then_some(&*c)
+    };
+    if let Some(s) = scale {
+        for j in 0..ns {
+            for (v, &si) in b[j * ldb..j * ldb + nu].iter_mut().zip(s) {
+                *v *= T::from_real(si);
+            }
+        }
+    }
+
+    for j in 0..nu {
+        af[j * ldaf..j * ldaf + nu].copy_from_slice(&a[j * lda..j * lda + nu]);
+    }
+    let info = getrf(n, n, af, ldaf as i32, ipiv);
+    let max_abs = |m: T::Real, v: &T| m.max(v.modulus());
+    let growth = |cols: usize| {
+        let umax = (0..cols).fold(zero, |m, j| {
+            af[j * ldaf..=j + j * ldaf].iter().fold(m, max_abs)
+        });
+        let amax = (0..cols).fold(zero, |m, j| {
+            a[j * lda..j * lda + nu].iter().fold(m, max_abs)
+        });
+        if umax == zero {
+            one
+        } else {
+            amax / umax
+        }
+    };
+    if info > 0 {
+        *rpvgrw = growth(info as usize);
+        *rcond = zero;
+        return info;
+    }
+    *rpvgrw = growth(nu);
+
+    // The condition of op(A) in the 1-norm is that of A in the 1-norm or the ∞-norm
+    let (norm, anorm) = if notrans {
+        (b'1', line_sums_max(nu, |l, k| a[l + k * lda]))
+    } else {
+        (b'I', line_sums_max(nu, |l, k| a[k + l * lda]))
+    };
+    gecon(norm, n, af, ldaf as i32, anorm, rcond);
+
+    for j in 0..ns {
+        x[j * ldx..j * ldx + nu].copy_from_slice(&b[j * ldb..j * ldb + nu]);
+    }
+    getrs(trans, n, nrhs, af, ldaf as i32, ipiv, x, ldx as i32);
+    gerfs(
+        trans, nu, ns, a, lda, af, ldaf, ipiv, b, ldb, x, ldx, ferr, berr,
+    );
+
+    let unscale = if notrans {
+        colequ.then_some((&*c, colcnd))
+    } else {
+        rowequ.then_some((&*r, rowcnd))
+    };
+    if let Some((s, cnd)) = unscale {
+        for j in 0..ns {
+            for (v, &si) in x[j * ldx..j * ldx + nu].iter_mut().zip(s) {
+                *v *= T::from_real(si);
+            }
+            ferr[j] /= cnd;
+        }
+    }
+
+    if *rcond < T::Real::small() {
+        n + 1
+    } else {
+        0
+    }
+}
+//}}}
+//{{{ fun: geequ
+/// Row and column scalings `r` and `c` that bring the largest entry of each row and column of the
+/// n×n `a` to 1, as `?geequ` computes them. Returns the ratios of the smallest to the largest
+/// scaling and the largest entry of `A`, or `None` if `A` has a zero row or column.
+fn geequ<T>(
+    n: usize,
+    a: &[T],
+    lda: usize,
+    r: &mut [T::Real],
+    c: &mut [T::Real],
+) -> Option<(T::Real, T::Real, T::Real)>
+where
+    T: Scalar,
+{
+    let zero = T::Real::zero();
+    let smlnum = safe_min::<T>();
+    let bignum = T::Real::one() / smlnum;
+    let scale = |s: &mut [T::Real]| {
+        let (lo, hi) = s[..n]
+            .iter()
+            .fold((bignum, zero), |(lo, hi), &v| (lo.min(v), hi.max(v)));
+        if lo == zero {
+            return None;
+        }
+        for v in s[..n].iter_mut() {
+            *v = T::Real::one() / v.max(smlnum).min(bignum);
+        }
+        Some((lo.max(smlnum) / hi.min(bignum), hi))
+    };
+
+    r[..n].fill(zero);
+    for j in 0..n {
+        for i in 0..n {
+            r[i] = r[i].max(cabs1(a[i + j * lda]));
+        }
+    }
+    let (rowcnd, amax) = scale(r)?;
+    for j in 0..n {
+        c[j] = (0..n).fold(zero, |m, i| m.max(cabs1(a[i + j * lda]) * r[i]));
+    }
+    let (colcnd, _) = scale(c)?;
+    Some((rowcnd, colcnd, amax))
+}
+//}}}
+//{{{ fun: laqge
+/// Applies the scalings from [`geequ`] to the n×n `a` where `?laqge` would: rows when their
+/// scalings vary by more than [`THRESH`] or the entries of `A` are near underflow or overflow,
+/// columns when their scalings vary by more than [`THRESH`]. Returns the resulting `equed`.
+#[allow(clippy::too_many_arguments)]
+fn laqge<T>(
+    n: usize,
+    a: &mut [T],
+    lda: usize,
+    r: &[T::Real],
+    c: &[T::Real],
+    rowcnd: T::Real,
+    colcnd: T::Real,
+    amax: T::Real,
+) -> u8
+where
+    T: Scalar,
+{
+    let thresh = T::real_from_f64(THRESH);
+    let small = safe_min::<T>() / T::Real::small();
+    let large = T::Real::one() / small;
+    let rows = rowcnd < thresh || amax < small || amax > large;
+    let cols = colcnd < thresh;
+    for j in 0..n {
+        for i in 0..n {
+            let mut s = T::Real::one();
+            if rows {
+                s *= r[i];
+            }
+            if cols {
+                s *= c[j];
+            }
+            a[i + j * lda] *= T::from_real(s);
+        }
+    }
+    match (rows, cols) {
+        (false, false) => b'N',
+        (true, false) => b'R',
+        (false, true) => b'C',
+        (true, true) => b'B',
+    }
+}
+//}}}
+//{{{ fun: gerfs
+/// Refines each column of the n×nrhs `x` towards the solution of `op(A) X = B` as `?gerfs` does,
+/// writing its componentwise backward error to `berr` and the bound on its relative forward error
+/// in the ∞-norm to `ferr`.
+#[allow(clippy::too_many_arguments)]
+fn gerfs<T>(
+    trans: u8,
+    n: usize,
+    nrhs: usize,
+    a: &[T],
+    lda: usize,
+    af: &[T],
+    ldaf: usize,
+    ipiv: &[i32],
+    b: &[T],
+    ldb: usize,
+    x: &mut [T],
+    ldx: usize,
+    ferr: &mut [T::Real],
+    berr: &mut [T::Real],
+) where
+    T: Scalar,
+{
+    let zero = T::Real::zero();
+    let eps = T::Real::small();
+    let nz = T::real_from_f64((n + 1) as f64);
+    let safe1 = nz * safe_min::<T>();
+    let safe2 = safe1 / eps;
+    let notrans = trans == b'N' || trans == b'n';
+    let conj = trans == b'C' || trans == b'c';
+    let op = |i: usize, k: usize| match (notrans, conj) {
+        (true, _) => a[i + k * lda],
+        (false, false) => a[k + i * lda],
+        (false, true) => a[k + i * lda].conj(),
+    };
+    let mut inv = vec![T::zero(); n * n];
+    for j in 0..n {
+        inv[j * n..(j + 1) * n].copy_from_slice(&af[j * ldaf..j * ldaf + n]);
+    }
+    getri(n as i32, &mut inv, n as i32, ipiv);
+    // |op(A)⁻¹| is |A⁻¹| or its transpose
+    let inv_op = |i: usize, k: usize| {
+        if notrans {
+            inv[i + k * n].modulus()
+        } else {
+            inv[k + i * n].modulus()
+        }
+    };
+
+    let mut res = vec![T::zero(); n];
+    let mut mag = vec![zero; n];
+    for j in 0..nrhs {
+        let xj = &mut x[j * ldx..j * ldx + n];
+        let bj = &b[j * ldb..j * ldb + n];
+        let mut count = 0;
+        let mut lstres = T::real_from_f64(3.0);
+        loop {
+            // r = b − op(A) x and its componentwise scale |b| + |op(A)| |x|
+            for i in 0..n {
+                let (mut ri, mut mi) = (bj[i], cabs1(bj[i]));
+                for (k, &xk) in xj.iter().enumerate() {
+                    ri -= op(i, k) * xk;
+                    mi += cabs1(op(i, k)) * cabs1(xk);
+                }
+                res[i] = ri;
+                mag[i] = mi;
+            }
+            let s = (0..n).fold(zero, |s, i| {
+                let ri = cabs1(res[i]);
+                s.max(if mag[i] > safe2 {
+                    ri / mag[i]
+                } else {
+                    (ri + safe1) / (mag[i] + safe1)
+                })
+            });
+            berr[j] = s;
+            if s > eps && s + s <= lstres && count < ITMAX {
+                let mut dx = res.clone();
+                getrs(trans, n as i32, 1, af, ldaf as i32, ipiv, &mut dx, n as i32);
+                for (v, d) in xj.iter_mut().zip(dx) {
+                    *v += d;
+                }
+                lstres = s;
+                count += 1;
+                continue;
+            }
+            break;
+        }
+
+        let f: Vec<T::Real> = (0..n)
+            .map(|i| {
+                let bound = cabs1(res[i]) + nz * eps * mag[i];
+                if mag[i] > safe2 {
+                    bound
+                } else {
+                    bound + safe1
+                }
+            })
+            .collect();
+        let err = (0..n).fold(zero, |m, i| {
+            m.max(
+                f.iter()
+                    .enumerate()
+                    .fold(zero, |s, (k, &fk)| s + inv_op(i, k) * fk),
+            )
+        });
+        let xnorm = xj.iter().fold(zero, |m, v| m.max(v.modulus()));
+        ferr[j] = if xnorm == zero { err } else { err / xnorm };
+    }
+}
+//}}}
+//{{{ fun: line_sums_max
+/// Largest sum of moduli over the n lines of an n×n matrix, entry `l` of line `k` being `at(l, k)`.
+fn line_sums_max<T>(
+    n: usize,
+    at: impl Fn(usize, usize) -> T,
+) -> T::Real
+where
+    T: Scalar,
+{
+    (0..n)
+        .map(|k| (0..n).fold(T::Real::zero(), |s, l| s + at(l, k).modulus()))
+        .fold(T::Real::zero(), |m, v| m.max(v))
+}
+//}}}
+//{{{ fun: cabs1
+/// `|Re| + |Im|`, the cheap modulus LAPACK's complex routines scale and bound with.
+#[inline]
+fn cabs1<T>(v: T) -> T::Real
+where
+    T: Scalar,
+{
+    v.real_part().abs() + v.imag_part().abs()
+}
+//}}}
+//{{{ fun: safe_min
+/// The smallest positive normal number of `T`'s precision.
+fn safe_min<T>() -> T::Real
+where
+    T: Scalar,
+{
+    let eps = T::Real::small();
+    if eps > T::real_from_f64(f64::from(f32::EPSILON) / 2.0) {
+        T::real_from_f64(f64::from(f32::MIN_POSITIVE))
+    } else {
+        T::real_from_f64(f64::MIN_POSITIVE)
+    }
+}
+//}}}
@@ -0,0 +1,359 @@
+//! Native generalised non-symmetric eigensolver.
+//!
+//! [`ggev_real`] and [`ggev_complex`] follow LAPACK `?ggev` on their interface but use a
+//! shift-and-invert reduction in place of the QZ algorithm: for a shift `σ` with `A − σB`
+//! nonsingular, the eigenpairs `(μ, x)` of `C = (A − σB)⁻¹ B` give the generalised eigenpairs
+//! `(σ + 1/μ, x)` of `(A, B)`, with `μ = 0` for the infinite eigenvalues. The shift is the
+//! best-conditioned of a few fixed multiples of `‖A‖ / ‖B‖`, so only a singular pencil, for which
+//! `A − σB` is singular for every `σ`, cannot be reduced; it is reported with `info = n + 1`.
+//! Eigenvalues are returned as `α / β = (1 + σμ) / μ`, scaled so that `β` is real and
+//! non-negative, and eigenvectors are normalised as in `?ggev`, with the largest component having
+//! `|Re| + |Im| = 1`. For real pencils the eigenvectors use `?ggev`'s packed layout, with the first
+//! eigenvalue of each conjugate pair the one with positive imaginary part.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::lu::{gecon, getrf, getrs};
+use super::{geev_complex, geev_real, Scalar};
+use crate::common::{ComplexField, One, Zero};
+use crate::float::Float;
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+/// Multiples of `‖A‖_F / ‖B‖_F` tried as shifts; irregular values avoid hitting eigenvalues of
+/// matrices with structured spectra.
+const SHIFTS: [f64; 6] = [0.0, 0.618, -1.324, 2.414, -0.173, 5.117];
+
+//{{{ fun: ggev_real
+/// Computes the generalised eigenvalues `(alphar + i alphai) / beta` of the real n×n pair
+/// `(A, B)` and, as selected by `jobvl` and `jobvr`, its left and right eigenvectors in `?ggev`'s
+/// packed real layout. Returns LAPACK's `info` code.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn ggev_real<T>(
+    jobvl: u8,
+    jobvr: u8,
+    n: i32,
+    a: &[T],
+    lda: i32,
+    b: &[T],
+    ldb: i32,
+    alphar: &mut [T],
+    alphai: &mut [T],
+    beta: &mut [T],
+    vl: &mut [T],
+    ldvl: i32,
+    vr: &mut [T],
+    ldvr: i32,
+) -> i32
+where
+    T: Scalar<Real = T> + Float,
+    T::Complex: Scalar<Real = T>,
+{
+    let nu = n as usize;
+    let Some(shift) = Shift::new(nu, a, lda as usize, b, ldb as usize) else {
+        return n + 1;
+    };
+    let ld = n.max(1);
+    let (mut wr, mut wi) = (vec![T::zero(); nu], vec![T::zero(); nu]);
+    let (mut cl, mut cr) = (vec![T::zero(); nu * nu], vec![T::zero(); nu * nu]);
+    let info = geev_real(
+        jobvl, jobvr, n, &shift.c, ld, &mut wr, &mut wi, &mut cl, ld, &mut cr, ld,
+    );
+    if info != 0 {
+        return info;
+    }
+
+    let mut pairs = vec![false; nu];
+    let mut j = 0;
+    while j < nu {
+        let (al, be) = shift.alpha_beta(T::Complex::from_parts(wr[j], wi[j]));
+        beta[j] = be;
+        alphar[j] = al.real_part();
+        if wi[j] == T::zero() || j + 1 == nu {
+            alphai[j] = T::zero();
+            j += 1;
+            continue;
+        }
+        // The eigenvectors belong to σ + 1/μ, whose imaginary part has the opposite sign to μ's
+        alphai[j] = -al.imag_part();
+        alphar[j + 1] = al.real_part();
+        alphai[j + 1] = al.imag_part();
+        beta[j + 1] = be;
+        for i in 0..nu {
+            cl[i + (j + 1) * nu] = -cl[i + (j + 1) * nu];
+            cr[i + (j + 1) * nu] = -cr[i + (j + 1) * nu];
+        }
+        pairs[j] = true;
+        j += 2;
+    }
+
+    if jobvl == b'V' || jobvl == b'v' {
+        shift.left(&mut cl);
+        normalise_real(&mut cl, &pairs, nu);
+        store(&cl, vl, nu, ldvl as usize);
+    }
+    if jobvr == b'V' || jobvr == b'v' {
+        normalise_real(&mut cr, &pairs, nu);
+        store(&cr, vr, nu, ldvr as usize);
+    }
+    0
+}
+//}}}
+//{{{ fun: ggev_complex
+/// Computes the generalised eigenvalues `alpha / beta` of the complex n×n pair `(A, B)` and, as
+/// selected by `jobvl` and `jobvr`, its left and right eigenvectors. Returns LAPACK's `info`
+/// code.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn ggev_complex<T>(
+    jobvl: u8,
+    jobvr: u8,
+    n: i32,
+    a: &[T],
+    lda: i32,
+    b: &[T],
+    ldb: i32,
+    alpha: &mut [T],
+    beta: &mut [T],
+    vl: &mut [T],
+    ldvl: i32,
+    vr: &mut [T],
+    ldvr: i32,
+) -> i32
+where
+    T: Scalar,
+{
+    let nu = n as usize;
+    let Some(shift) = Shift::new(nu, a, lda as usize, b, ldb as usize) else {
+        return n + 1;
+    };
+    let ld = n.max(1);
+    let mut w = vec![T::zero(); nu];
+    let (mut cl, mut cr) = (vec![T::zero(); nu * nu], vec![T::zero(); nu * nu]);
+    let info = geev_complex(
+        jobvl, jobvr, n, &shift.c, ld, &mut w, &mut cl, ld, &mut cr, ld,
+    );
+    if info != 0 {
+        return info;
+    }
+    for j in 0..nu {
+        let (al, be) = shift.alpha_beta(w[j]);
+        alpha[j] = al;
+        beta[j] = T::from_real(be);
+    }
+
+    let normalise = |v: &mut [T]| {
+        for col in v.chunks_mut(nu.max(1)) {
+            let scale = largest(col);
+            if scale > T::Real::zero() {
+                for x in col.iter_mut() {
+                    *x /= T::from_real(scale);
+                }
+            }
+        }
+    };
+    if jobvl == b'V' || jobvl == b'v' {
+        shift.left(&mut cl);
+        normalise(&mut cl);
+        store(&cl, vl, nu, ldvl as usize);
+    }
+    if jobvr == b'V' || jobvr == b'v' {
+        normalise(&mut cr);
+        store(&cr, vr, nu, ldvr as usize);
+    }
+    0
+}
+//}}}
+//{{{ struct: Shift
+/// The shift-and-invert reduction of a pencil: the LU factors of `A − σB` and the n×n
+/// `C = (A − σB)⁻¹ B`.
+struct Shift<T>
+where
+    T: Scalar,
+{
+    n: usize,
+    sigma: T::Real,
+    lu: Vec<T>,
+    ipiv: Vec<i32>,
+    c: Vec<T>,
+}
+//}}}
+//{{{ impl: Shift
+impl<T> Shift<T>
+where
+    T: Scalar,
+{
+    /// Reduces the n×n pair `(A, B)` with the best-conditioned shift, or returns `None` if every
+    /// shift tried leaves `A − σB` singular.
+    fn new(
+        n: usize,
+        a: &[T],
+        lda: usize,
+        b: &[T],
+        ldb: usize,
+    ) -> Option<Self> {
+        let zero = T::Real::zero();
+        let fro = |x: &[T], ld: usize| {
+            let mut s = zero;
+            for j in 0..n {
+                for v in &x[j * ld..j * ld + n] {
+                    s = s.hypot(v.modulus());
+                }
+            }
+            s
+        };
+        let (anorm, bnorm) = (fro(a, lda), fro(b, ldb));
+        let scale = if anorm > zero && bnorm > zero {
+            anorm / bnorm
+        } else {
+            T::Real::one()
+        };
+
+        let ld = n.max(1) as i32;
+        let mut best: Option<(T::Real, Self)> = None;
+        for f in SHIFTS {
+            let sigma = scale * T::real_from_f64(f);
+            let mut lu = vec![T::zero(); n * n];
+            for j in 0..n {
+                for i in 0..n {
+                    lu[i + j * n] = a[i + j * lda] - T::from_real(sigma) * b[i + j * ldb];
+                }
+            }
+            let norm1 = (0..n)
+                .map(|j| {
+                    lu[j * n..(j + 1) * n]
+                        .iter()
+                        .fold(zero, |s, v| s + v.modulus())
+                })
+                .fold(zero, |m, v| m.max(v));
+            let mut ipiv = vec![0; n];
+            if getrf(n as i32, n as i32, &mut lu, ld, &mut ipiv) != 0 {
+                continue;
+            }
+            let mut rcond = zero;
+            gecon(b'1', n as i32, &lu, ld, norm1, &mut rcond);
+            if best.as_ref().is_none_or(|(r, _)| rcond > *r) {
+                let c = Vec::new();
+                best = Some((
+                    rcond,
+                    Shift {
+                        n,
+                        sigma,
+                        lu,
+                        ipiv,
+                        c,
+                    },
+                ));
+            }
+        }
+        let (_, mut shift) = best?;
+        let mut c = vec![T::zero(); n * n];
+        for j in 0..n {
+            c[j * n..(j + 1) * n].copy_from_slice(&b[j * ldb..j * ldb + n]);
+        }
+        getrs(
+            b'N',
+            n as i32,
+            n as i32,
+            &shift.lu,
+            ld,
+            &shift.ipiv,
+            &mut c,
+            ld,
+        );
+        shift.c = c;
+        Some(shift)
+    }
+
+    /// `α` and `β` with `α / β = σ + 1/μ` for the eigenvalue `μ` of `C`, scaled so that `β` is
+    /// real and non-negative.
+    fn alpha_beta<C>(
+        &self,
+        mu: C,
+    ) -> (C, T::Real)
+    where
+        C: Scalar<Real = T::Real>,
+    {
+        let m = mu.modulus();
+        if m == T::Real::zero() {
+            return (C::one(), m);
+        }
+        let alpha = (C::one() + C::from_real(self.sigma) * mu) * mu.conj() / C::from_real(m);
+        (alpha, m)
+    }
+
+    /// Maps the left eigenvectors of `C`, one per column of the n×n `z`, to those of the pencil,
+    /// `y = (A − σB)⁻ᴴ z`.
+    fn left(
+        &self,
+        z: &mut [T],
+    ) {
+        let ld = self.n.max(1) as i32;
+        getrs(
+            b'C',
+            self.n as i32,
+            self.n as i32,
+            &self.lu,
+            ld,
+            &self.ipiv,
+            z,
+            ld,
+        );
+    }
+}
+//}}}
+//{{{ fun: largest
+/// The largest `|Re| + |Im|` over the entries of `x`.
+fn largest<T>(x: &[T]) -> T::Real
+where
+    T: Scalar,
+{
+    x.iter().fold(T::Real::zero(), |m, v| {
+        m.max(v.real_part().abs() + v.imag_part().abs())
+    })
+}
+//}}}
+//{{{ fun: normalise_real
+/// Normalises the real eigenvectors in the columns of the n×n `v`, where `pairs[j]` marks the
+/// first column of a conjugate pair holding a vector's real and imaginary parts.
+fn normalise_real<T>(
+    v: &mut [T],
+    pairs: &[bool],
+    n: usize,
+) where
+    T: Scalar<Real = T> + Float,
+{
+    let mut j = 0;
+    while j < n {
+        let width = if pairs[j] { 2 } else { 1 };
+        let cols = &mut v[j * n..(j + width) * n];
+        let scale = (0..n)
+            .map(|i| (0..width).fold(T::zero(), |s, k| s + cols[i + k * n].abs()))
+            .fold(T::zero(), |m, x| m.max(x));
+        if scale > T::zero() {
+            for x in cols.iter_mut() {
+                *x /= scale;
+            }
+        }
+        j += width;
+    }
+}
+//}}}
+//{{{ fun: store
+/// Copies the contiguous n×n `v` into `out` with leading dimension `ld`.
+fn store<T>(
+    v: &[T],
+    out: &mut [T],
+    n: usize,
+    ld: usize,
+) where
+    T: Scalar,
+{
+    for j in 0..n {
+        out[j * ld..j * ld + n].copy_from_slice(&v[j * n..(j + 1) * n]);
+    }
+}
+//}}}
@@ -0,0 +1,85 @@
+//! Native Hessenberg reduction in LAPACK's compact form.
+//!
+//! [`gehrd`] follows the unblocked `?gehd2`: reflector `i` annihilates column `i` below the first
+//! subdiagonal and is stored there with `v[0] = 1` implied, so that the output can be passed to
+//! `?orghr`. [`orghr`] follows `?orghr` by shifting the reflectors one column to the right and
+//! forming `Q` with [`orgqr`](super::orgqr). Unlike the reduction used by [`schur`](super::schur),
+//! which accumulates `Q` as it goes, this keeps the reflectors for the caller.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::qr::{apply_left, apply_right, larfg};
+use super::{orgqr, Scalar};
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ fun: gehrd
+/// Reduces rows and columns `ilo..=ihi` (1-based) of the n×n `a` to upper Hessenberg form
+/// `Qᴴ A Q` in place, storing the reflectors below the subdiagonal and their scalars in `tau`.
+pub(crate) fn gehrd<T>(
+    n: i32,
+    ilo: i32,
+    ihi: i32,
+    a: &mut [T],
+    lda: i32,
+    tau: &mut [T],
+) where
+    T: Scalar,
+{
+    let (n, lda) = (n as usize, lda as usize);
+    let (lo, hi) = (ilo as usize - 1, ihi as usize);
+    tau[..lo.min(n.saturating_sub(1))].fill(T::zero());
+    if hi >= 1 {
+        tau[(hi - 1).min(n.saturating_sub(1))..n.saturating_sub(1)].fill(T::zero());
+    }
+    for i in lo..hi.saturating_sub(1) {
+        let (head, rest) = a.split_at_mut(i + 1 + i * lda + 1);
+        let alpha = &mut head[i + 1 + i * lda];
+        tau[i] = larfg(alpha, &mut rest[..hi - i - 2]);
+        let tail = a[i + 2 + i * lda..hi + i * lda].to_vec();
+        apply_right(tau[i], &tail, a, lda, i + 1, 0..hi);
+        apply_left(tau[i].conj(), &tail, a, lda, i + 1, i + 1..n);
+    }
+}
+//}}}
+//{{{ fun: orghr
+/// Overwrites the reflectors of [`gehrd`] in the n×n `a` with the unitary `Q` they define.
+pub(crate) fn orghr<T>(
+    n: i32,
+    ilo: i32,
+    ihi: i32,
+    a: &mut [T],
+    lda: i32,
+    tau: &[T],
+) where
+    T: Scalar,
+{
+    let (nu, ld) = (n as usize, lda as usize);
+    let (lo, hi) = (ilo as usize - 1, ihi as usize);
+    let nh = hi.saturating_sub(lo + 1);
+    // Shift the reflectors one column right and set the rest of Q to the identity
+    for j in (lo + 1..hi).rev() {
+        for i in 0..nu {
+            a[i + j * ld] = if i > j && i < hi {
+                a[i + (j - 1) * ld]
+            } else {
+                T::zero()
+            };
+        }
+    }
+    for j in (0..=lo).chain(hi..nu) {
+        let col = &mut a[j * ld..j * ld + nu];
+        col.fill(T::zero());
+        col[j] = T::one();
+    }
+    if nh > 0 {
+        let k = nh as i32;
+        let start = (lo + 1) + (lo + 1) * ld;
+        orgqr(k, k, k, &mut a[start..], lda, &tau[lo..]);
+    }
+}
+//}}}
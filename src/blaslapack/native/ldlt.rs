@@ -0,0 +1,327 @@
+//! Native symmetric indefinite factorisation and solve.
+//!
+//! [`sytrf`] ports the unblocked Bunch–Kaufman factorisation of LAPACK `?hetf2`, `A = L D Lᴴ`
+//! with 1×1 and 2×2 diagonal blocks in `D`, and [`sytrs`] ports `?hetrs`; the factors and pivot
+//! indices are stored exactly as LAPACK stores them, so that code reading the blocks of `D` works
+//! with either backend. For real types the Hermitian algorithm is the symmetric one of `?sytf2`.
+//! The upper-triangle variant, `A = U D Uᴴ`, is the lower one applied to `A` with its rows and
+//! columns in reverse order, which is how LAPACK's two variants relate.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::Scalar;
+use crate::common::{One, Zero};
+use crate::float::Float;
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ fun: sytrf
+/// Factors the Hermitian n×n matrix held in triangle `uplo` of `a` in place as `L D Lᴴ` or
+/// `U D Uᴴ`, writing LAPACK's pivot indices to `ipiv`. Returns LAPACK's `info` code: the 1-based
+/// index of the first exactly zero diagonal entry of `D`.
+pub(crate) fn sytrf<T>(
+    uplo: u8,
+    n: i32,
+    a: &mut [T],
+    lda: i32,
+    ipiv: &mut [i32],
+) -> i32
+where
+    T: Scalar,
+{
+    let (nu, ld) = (n as usize, lda as usize);
+    let upper = uplo == b'U' || uplo == b'u';
+    let mut f = load_lower(a, nu, ld, upper);
+    let (info, piv) = factor_lower(&mut f, nu);
+    for j in 0..nu {
+        for i in j..nu {
+            a[storage(i, j, nu, ld, upper)] = f[i + j * nu];
+        }
+    }
+    for (k, &pk) in piv.iter().enumerate() {
+        let (dst, p) = logical(k, nu, upper);
+        ipiv[dst] = p(pk);
+    }
+    if upper && info != 0 {
+        n + 1 - info
+    } else {
+        info
+    }
+}
+//}}}
+//{{{ fun: sytrs
+/// Solves `A X = B` for the n×nrhs `b`, given the factorisation of the Hermitian n×n `A` from
+/// [`sytrf`], overwriting `b` with `X`. Always returns 0.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn sytrs<T>(
+    uplo: u8,
+    n: i32,
+    nrhs: i32,
+    a: &[T],
+    lda: i32,
+    ipiv: &[i32],
+    b: &mut [T],
+    ldb: i32,
+) -> i32
+where
+    T: Scalar,
+{
+    let (nu, ld, ldb) = (n as usize, lda as usize, ldb as usize);
+    let upper = uplo == b'U' || uplo == b'u';
+    let f = load_lower(a, nu, ld, upper);
+    let mut piv = vec![0; nu];
+    for (k, p) in piv.iter_mut().enumerate() {
+        let (src, map) = logical(k, nu, upper);
+        *p = map(ipiv[src]);
+    }
+    let at = |i: usize, j: usize| f[i + j * nu];
+    let reverse = |b: &mut [T]| {
+        if upper {
+            for r in 0..nrhs as usize {
+                b[r * ldb..r * ldb + nu].reverse();
+            }
+        }
+    };
+
+    reverse(b);
+    for r in 0..nrhs as usize {
+        let x = &mut b[r * ldb..r * ldb + nu];
+        // L D y = P b
+        let mut k = 0;
+        while k < nu {
+            if piv[k] > 0 {
+                x.swap(k, (piv[k] - 1) as usize);
+                let xk = x[k];
+                for (i, xi) in x.iter_mut().enumerate().skip(k + 1) {
+                    *xi -= at(i, k) * xk;
+                }
+                x[k] /= T::from_real(at(k, k).real_part());
+                k += 1;
+            } else {
+                x.swap(k + 1, (-piv[k] - 1) as usize);
+                let (xk, xk1) = (x[k], x[k + 1]);
+                for (i, xi) in x.iter_mut().enumerate().skip(k + 2) {
+                    *xi -= at(i, k) * xk + at(i, k + 1) * xk1;
+                }
+                let akm1k = at(k + 1, k);
+                let akm1 = at(k, k) / akm1k.conj();
+                let ak = at(k + 1, k + 1) / akm1k;
+                let denom = akm1 * ak - T::one();
+                let bkm1 = x[k] / akm1k.conj();
+                let bk = x[k + 1] / akm1k;
+                x[k] = (ak * bkm1 - bk) / denom;
+                x[k + 1] = (akm1 * bk - bkm1) / denom;
+                k += 2;
+            }
+        }
+        // Lᴴ Pᵀ x = y
+        let mut k = nu;
+        while k > 0 {
+            let k0 = k - 1;
+            let width = if piv[k0] > 0 { 1 } else { 2 };
+            for c in k0 + 1 - width..=k0 {
+                let mut s = x[c];
+                for (i, &xi) in x.iter().enumerate().skip(k0 + 1) {
+                    s -= at(i, c).conj() * xi;
+                }
+                x[c] = s;
+            }
+            x.swap(k0, (piv[k0].abs() - 1) as usize);
+            k -= width;
+        }
+    }
+    reverse(b);
+    0
+}
+//}}}
+//{{{ fun: factor_lower
+/// Bunch–Kaufman factorisation of the lower triangle of the contiguous Hermitian n×n `f`, as
+/// `?hetf2` with `uplo = 'L'`. Returns `info` and the pivot indices.
+fn factor_lower<T>(
+    f: &mut [T],
+    n: usize,
+) -> (i32, Vec<i32>)
+where
+    T: Scalar,
+{
+    let at = |i: usize, j: usize| i + j * n;
+    let real = |v: T| T::from_real(v.real_part());
+    let one = T::Real::one();
+    let alpha = (one + T::real_from_f64(17.0).sqrt()) / T::real_from_f64(8.0);
+    let mut info = 0;
+    let mut ipiv = vec![0; n];
+    let mut k = 0;
+    while k < n {
+        let mut kstep = 1;
+        let absakk = f[at(k, k)].real_part().abs();
+        let (mut imax, mut colmax) = (k, T::Real::zero());
+        for i in k + 1..n {
+            let v = f[at(i, k)].modulus();
+            if v > colmax {
+                imax = i;
+                colmax = v;
+            }
+        }
+
+        let kp;
+        // Also catches NaN
+        if absakk.max(colmax).partial_cmp(&T::Real::zero()) != Some(std::cmp::Ordering::Greater) {
+            if info == 0 {
+                info = (k + 1) as i32;
+            }
+            kp = k;
+            f[at(k, k)] = real(f[at(k, k)]);
+        } else {
+            if absakk >= alpha * colmax {
+                kp = k;
+            } else {
+                let mut rowmax = T::Real::zero();
+                for j in k..imax {
+                    rowmax = rowmax.max(f[at(imax, j)].modulus());
+                }
+                for i in imax + 1..n {
+                    rowmax = rowmax.max(f[at(i, imax)].modulus());
+                }
+                if absakk >= alpha * colmax * (colmax / rowmax) {
+                    kp = k;
+                } else if f[at(imax, imax)].real_part().abs() >= alpha * rowmax {
+                    kp = imax;
+                } else {
+                    kp = imax;
+                    kstep = 2;
+                }
+            }
+
+            // Interchange rows and columns kk and kp of the trailing submatrix
+            let kk = k + kstep - 1;
+            if kp != kk {
+                for i in kp + 1..n {
+                    f.swap(at(i, kk), at(i, kp));
+                }
+                for j in kk + 1..kp {
+                    let t = f[at(j, kk)].conj();
+                    f[at(j, kk)] = f[at(kp, j)].conj();
+                    f[at(kp, j)] = t;
+                }
+                f[at(kp, kk)] = f[at(kp, kk)].conj();
+                let r1 = real(f[at(kk, kk)]);
+                f[at(kk, kk)] = real(f[at(kp, kp)]);
+                f[at(kp, kp)] = r1;
+                if kstep == 2 {
+                    f[at(k, k)] = real(f[at(k, k)]);
+                    f.swap(at(k + 1, k), at(kp, k));
+                }
+            } else {
+                f[at(k, k)] = real(f[at(k, k)]);
+                if kstep == 2 {
+                    f[at(k + 1, k + 1)] = real(f[at(k + 1, k + 1)]);
+                }
+            }
+
+            if kstep == 1 {
+                // A ← A − x xᴴ / d11 on the trailing submatrix, then x ← x / d11
+                let r1 = T::from_real(one / f[at(k, k)].real_part());
+                for j in k + 1..n {
+                    let xj = f[at(j, k)].conj();
+                    for i in j..n {
+                        let xi = f[at(i, k)];
+                        f[at(i, j)] -= r1 * xi * xj;
+                    }
+                    f[at(j, j)] = real(f[at(j, j)]);
+                }
+                for i in k + 1..n {
+                    f[at(i, k)] *= r1;
+                }
+            } else if k + 2 < n {
+                let d = f[at(k + 1, k)].modulus();
+                let d11 = f[at(k + 1, k + 1)].real_part() / d;
+                let d22 = f[at(k, k)].real_part() / d;
+                let tt = one / (d11 * d22 - one);
+                let d21 = f[at(k + 1, k)] / T::from_real(d);
+                let d = T::from_real(tt / d);
+                let (d11, d22) = (T::from_real(d11), T::from_real(d22));
+                for j in k + 2..n {
+                    let wk = d * (d11 * f[at(j, k)] - d21 * f[at(j, k + 1)]);
+                    let wkp1 = d * (d22 * f[at(j, k + 1)] - d21.conj() * f[at(j, k)]);
+                    for i in j..n {
+                        let (lik, lik1) = (f[at(i, k)], f[at(i, k + 1)]);
+                        f[at(i, j)] -= lik * wk.conj() + lik1 * wkp1.conj();
+                    }
+                    f[at(j, k)] = wk;
+                    f[at(j, k + 1)] = wkp1;
+                    f[at(j, j)] = real(f[at(j, j)]);
+                }
+            }
+        }
+
+        if kstep == 1 {
+            ipiv[k] = (kp + 1) as i32;
+        } else {
+            ipiv[k] = -((kp + 1) as i32);
+            ipiv[k + 1] = -((kp + 1) as i32);
+        }
+        k += kstep;
+    }
+    (info, ipiv)
+}
+//}}}
+//{{{ fun: storage
+/// Storage index in `a` of entry `(i, j)`, `i ≥ j`, of the lower triangle of the matrix the
+/// lower algorithm works on: `A` itself, or `A` with rows and columns reversed for `uplo = 'U'`.
+#[inline]
+fn storage(
+    i: usize,
+    j: usize,
+    n: usize,
+    lda: usize,
+    upper: bool,
+) -> usize {
+    if upper {
+        (n - 1 - i) + (n - 1 - j) * lda
+    } else {
+        i + j * lda
+    }
+}
+//}}}
+//{{{ fun: load_lower
+/// The lower triangle of the matrix the lower algorithm works on, as a contiguous n×n buffer.
+fn load_lower<T>(
+    a: &[T],
+    n: usize,
+    lda: usize,
+    upper: bool,
+) -> Vec<T>
+where
+    T: Scalar,
+{
+    let mut f = vec![T::zero(); n * n];
+    for j in 0..n {
+        for i in j..n {
+            f[i + j * n] = a[storage(i, j, n, lda, upper)];
+        }
+    }
+    f
+}
+//}}}
+//{{{ fun: logical
+/// The index in `ipiv` of logical pivot `k`, and the map between logical and stored pivot
+/// values, which is its own inverse; both are the identity for `uplo = 'L'`.
+fn logical(
+    k: usize,
+    n: usize,
+    upper: bool,
+) -> (usize, impl Fn(i32) -> i32) {
+    let n = n as i32;
+    let map = move |p: i32| match (upper, p > 0) {
+        (false, _) => p,
+        (true, true) => n + 1 - p,
+        (true, false) => -(n + 1 + p),
+    };
+    let idx = if upper { n as usize - 1 - k } else { k };
+    (idx, map)
+}
+//}}}
@@ -0,0 +1,294 @@
+//! Native LU factorisation with partial pivoting and the linear solve built on it.
+//!
+//! [`getrf`] follows LAPACK `?getrf`: the unit lower factor L is stored below the diagonal, U on
+//! and above it, and `ipiv[j]` holds the 1-based row swapped with row `j`. A zero pivot does not
+//! stop the factorisation; the index of the first one is returned as `info`. [`gesv`] factors and
+//! then solves by forward and back substitution, as `?gesv` does, and [`getrs`] solves with given
+//! factors for any `op(A)`. [`getri`] forms the inverse from the factors, and [`gecon`] computes
+//! the reciprocal condition number from it exactly where `?gecon` estimates it.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::triangular::{transpose, trsm};
+use super::Scalar;
+use crate::common::{Diagonal, One, Side, Transpose, Triangle, Zero};
+use crate::float::Float;
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ fun: getrf
+/// Factors the m×n matrix `a` in place as `P L U`, returning LAPACK's `info` code.
+pub(crate) fn getrf<T>(
+    m: i32,
+    n: i32,
+    a: &mut [T],
+    lda: i32,
+    ipiv: &mut [i32],
+) -> i32
+where
+    T: Scalar,
+{
+    let (m, n, lda) = (m as usize, n as usize, lda as usize);
+    let mut info = 0;
+    for j in 0..m.min(n) {
+        let mut p = j;
+        for i in j + 1..m {
+            if a[i + j * lda].modulus() > a[p + j * lda].modulus() {
+                p = i;
+            }
+        }
+        ipiv[j] = (p + 1) as i32;
+        let pivot = a[p + j * lda];
+        if pivot != T::zero() {
+            if p != j {
+                for col in 0..n {
+                    a.swap(j + col * lda, p + col * lda);
+                }
+            }
+            for i in j + 1..m {
+                a[i + j * lda] /= pivot;
+            }
+        } else if info == 0 {
+            info = (j + 1) as i32;
+        }
+        for col in j + 1..n {
+            let t = a[j + col * lda];
+            if t != T::zero() {
+                for i in j + 1..m {
+                    let l = a[i + j * lda];
+                    a[i + col * lda] -= l * t;
+                }
+            }
+        }
+    }
+    info
+}
+//}}}
+//{{{ fun: gesv
+/// Solves `A X = B` for the n×n `a` and n×nrhs `b`, overwriting `a` with its LU factors and `b`
+/// with `X`. Returns LAPACK's `info` code; `b` is left unchanged when `A` is singular.
+pub(crate) fn gesv<T>(
+    n: i32,
+    nrhs: i32,
+    a: &mut [T],
+    lda: i32,
+    ipiv: &mut [i32],
+    b: &mut [T],
+    ldb: i32,
+) -> i32
+where
+    T: Scalar,
+{
+    let info = getrf(n, n, a, lda, ipiv);
+    if info != 0 {
+        return info;
+    }
+    let (n, lda, ldb) = (n as usize, lda as usize, ldb as usize);
+    for r in 0..nrhs as usize {
+        let x = &mut b[r * ldb..r * ldb + n];
+        for (j, &p) in ipiv[..n].iter().enumerate() {
+            x.swap(j, (p - 1) as usize);
+        }
+        for j in 0..n {
+            let xj = x[j];
+            for i in j + 1..n {
+                x[i] -= a[i + j * lda] * xj;
+            }
+        }
+        for j in (0..n).rev() {
+            x[j] /= a[j + j * lda];
+            let xj = x[j];
+            for i in 0..j {
+                x[i] -= a[i + j * lda] * xj;
+            }
+        }
+    }
+    0
+}
+//}}}
+//{{{ fun: getrs
+/// Solves `op(A) X = B` for the n×nrhs `b`, given the LU factors and pivots of the n×n `A` from
+/// [`getrf`], overwriting `b` with `X`. Always returns 0.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn getrs<T>(
+    trans: u8,
+    n: i32,
+    nrhs: i32,
+    a: &[T],
+    lda: i32,
+    ipiv: &[i32],
+    b: &mut [T],
+    ldb: i32,
+) -> i32
+where
+    T: Scalar,
+{
+    let one = T::one();
+    let trans = transpose(trans);
+    let (nu, ldbu) = (n as usize, ldb as usize);
+    let swap_rows = |b: &mut [T], j: usize| {
+        let p = (ipiv[j] - 1) as usize;
+        if p != j {
+            for r in 0..nrhs as usize {
+                b.swap(j + r * ldbu, p + r * ldbu);
+            }
+        }
+    };
+    match trans {
+        Transpose::NoTrans => {
+            for j in 0..nu {
+                swap_rows(b, j);
+            }
+            trsm(
+                Side::Left,
+                Triangle::Lower,
+                trans,
+                Diagonal::Unit,
+                n,
+                nrhs,
+                one,
+                a,
+                lda,
+                b,
+                ldb,
+            );
+            trsm(
+                Side::Left,
+                Triangle::Upper,
+                trans,
+                Diagonal::NonUnit,
+                n,
+                nrhs,
+                one,
+                a,
+                lda,
+                b,
+                ldb,
+            );
+        }
+        _ => {
+            trsm(
+                Side::Left,
+                Triangle::Upper,
+                trans,
+                Diagonal::NonUnit,
+                n,
+                nrhs,
+                one,
+                a,
+                lda,
+                b,
+                ldb,
+            );
+            trsm(
+                Side::Left,
+                Triangle::Lower,
+                trans,
+                Diagonal::Unit,
+                n,
+                nrhs,
+                one,
+                a,
+                lda,
+                b,
+                ldb,
+            );
+            for j in (0..nu).rev() {
+                swap_rows(b, j);
+            }
+        }
+    }
+    0
+}
+//}}}
+//{{{ fun: getri
+/// Overwrites the LU factors of the n×n `A` from [`getrf`] with `A⁻¹`. Returns LAPACK's `info`
+/// code: the 1-based index of the first zero on the diagonal of U, in which case `a` is left
+/// unchanged.
+pub(crate) fn getri<T>(
+    n: i32,
+    a: &mut [T],
+    lda: i32,
+    ipiv: &[i32],
+) -> i32
+where
+    T: Scalar,
+{
+    let (nu, ld) = (n as usize, lda as usize);
+    if let Some(i) = (0..nu).find(|&i| a[i + i * ld] == T::zero()) {
+        return (i + 1) as i32;
+    }
+    let inv = inverse(n, a, lda, ipiv);
+    for j in 0..nu {
+        a[j * ld..j * ld + nu].copy_from_slice(&inv[j * nu..(j + 1) * nu]);
+    }
+    0
+}
+//}}}
+//{{{ fun: gecon
+/// Computes the reciprocal condition number `1 / (‖A‖ ‖A⁻¹‖)` of the n×n `A` in the 1-norm
+/// (`norm = '1'` or `'O'`) or the infinity norm (`'I'`), given its LU factors from [`getrf`] and
+/// `anorm = ‖A‖`. `rcond` is zero when `A` is exactly singular. Always returns 0.
+pub(crate) fn gecon<T>(
+    norm: u8,
+    n: i32,
+    a: &[T],
+    lda: i32,
+    anorm: T::Real,
+    rcond: &mut T::Real,
+) -> i32
+where
+    T: Scalar,
+{
+    let (nu, ld) = (n as usize, lda as usize);
+    let zero = T::Real::zero();
+    if nu == 0 {
+        *rcond = T::Real::one();
+        return 0;
+    }
+    *rcond = zero;
+    if anorm == zero || (0..nu).any(|i| a[i + i * ld] == T::zero()) {
+        return 0;
+    }
+    let ipiv: Vec<i32> = (1..=n).collect();
+    let inv = inverse(n, a, lda, &ipiv);
+    // The permutation does not change either norm, so the factors can be inverted unpivoted
+    let entry = |i: usize, j: usize| inv[i + j * nu].modulus();
+    let sum = |f: &dyn Fn(usize) -> T::Real| (0..nu).map(f).fold(zero, |acc, v| acc + v);
+    let ainvnm = (0..nu)
+        .map(|k| match norm {
+            b'I' | b'i' => sum(&|j| entry(k, j)),
+            _ => sum(&|i| entry(i, k)),
+        })
+        .fold(zero, |acc, v| acc.max(v));
+    if ainvnm != zero {
+        *rcond = (T::Real::one() / ainvnm) / anorm;
+    }
+    0
+}
+//}}}
+//{{{ fun: inverse
+/// The n×n inverse, with leading dimension n, of the matrix whose LU factors and pivots are
+/// `a` and `ipiv`.
+fn inverse<T>(
+    n: i32,
+    a: &[T],
+    lda: i32,
+    ipiv: &[i32],
+) -> Vec<T>
+where
+    T: Scalar,
+{
+    let nu = n as usize;
+    let mut inv = vec![T::zero(); nu * nu];
+    for i in 0..nu {
+        inv[i + i * nu] = T::one();
+    }
+    getrs(b'N', n, n, a, lda, ipiv, &mut inv, n.max(1));
+    inv
+}
+//}}}
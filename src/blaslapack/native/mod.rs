@@ -0,0 +1,163 @@
+//! Pure-Rust implementations of the BLAS and LAPACK routines.
+//!
+//! Back the [`ReferenceBackend`](super::backend::ReferenceBackend) that is the default when the
+//! `pure_rust` feature is enabled or the `system` feature is disabled, so that the crate runs on
+//! targets without BLAS/LAPACK. The functions mirror the calling conventions of the routines they
+//! replace: column-major storage with explicit leading dimensions, 1-based pivot indices,
+//! Householder reflectors in LAPACK's compact form and an `info` code with LAPACK's meaning, so
+//! that a backend can mix them with the routines of a system library.
+//! Sub-modules: [`blas`] (`?gemm`, `?gemv`, `?gbmv`), [`triangular`] (`?trsv`, `?trsm`, `?trmm`,
+//! `?trtrs`), [`lu`] (`?getrf`, `?gesv`, `?getrs`, `?getri`, `?gecon`), [`expert`] (`?gesvx`),
+//! [`cholesky`] (`?potrf`, `?potrs`), [`ldlt`] (`?sytrf`, `?sytrs`), [`band`] (`?gbsv`, `?pbsv`,
+//! `?gtsv`, `?ptsv`), [`qr`] (`?geqrf`, `?orgqr`, `?ormqr`, `?geqp3`, `?gels`), [`svd`] (`?gesvd`,
+//! `?gesdd`, `?gelsd`, by one-sided Jacobi), [`symeig`] (`?syev`/`?heev`, by cyclic Jacobi,
+//! `?syevr`, `?sygv`), [`hessenberg`] (`?gehrd`, `?orghr`), [`schur`] (Hessenberg reduction, real
+//! and complex Schur forms), [`eig`] (`?gees`, `?geev`), [`reorder`] (`?trsen`) and
+//! [`generalized`] (`?ggev`, by shift-and-invert).
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use crate::common::{Complex, ComplexField, Zero};
+use crate::float::Float;
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+mod band;
+mod blas;
+mod cholesky;
+mod eig;
+mod expert;
+mod generalized;
+mod hessenberg;
+mod ldlt;
+mod lu;
+mod qr;
+mod reorder;
+mod schur;
+mod svd;
+mod symeig;
+mod triangular;
+
+pub(crate) use band::{gbsv, gtsv, pbsv, ptsv};
+pub(crate) use blas::{gbmv, gemm, gemv};
+pub(crate) use cholesky::{potrf, potrs};
+pub(crate) use eig::{gees, geev_complex, geev_real};
+pub(crate) use expert::gesvx;
+pub(crate) use generalized::{ggev_complex, ggev_real};
+pub(crate) use hessenberg::{gehrd, orghr};
+pub(crate) use ldlt::{sytrf, sytrs};
+pub(crate) use lu::{gecon, gesv, getrf, getri, getrs};
+pub(crate) use qr::{gels, geqp3, geqrf, orgqr, ormqr};
+pub(crate) use reorder::trsen;
+pub(crate) use svd::{gelsd, gesdd, gesvd};
+pub(crate) use symeig::{syev, syevr, sygv};
+pub(crate) use triangular::{trmm, trsm, trsv, trtrs};
+
+//{{{ trait: Scalar
+/// The scalar operations the native routines need beyond [`ComplexField`].
+pub(crate) trait Scalar: ComplexField {
+    /// Returns the imaginary part, which is zero for real types.
+    fn imag_part(self) -> Self::Real;
+    /// Builds `re + i im`; real types ignore `im`.
+    fn from_parts(
+        re: Self::Real,
+        im: Self::Real,
+    ) -> Self;
+    /// Converts a constant or a count into the real scalar of the same precision.
+    fn real_from_f64(x: f64) -> Self::Real;
+}
+
+macro_rules! impl_scalar {
+    ($t:ty) => {
+        impl Scalar for $t {
+            #[inline]
+            fn imag_part(self) -> Self::Real {
+                0.0
+            }
+
+            #[inline]
+            fn from_parts(
+                re: Self::Real,
+                _im: Self::Real,
+            ) -> Self {
+                re
+            }
+
+            #[inline]
+            fn real_from_f64(x: f64) -> Self::Real {
+                x as $t
+            }
+        }
+
+        impl Scalar for Complex<$t> {
+            #[inline]
+            fn imag_part(self) -> Self::Real {
+                self.im
+            }
+
+            #[inline]
+            fn from_parts(
+                re: Self::Real,
+                im: Self::Real,
+            ) -> Self {
+                Complex::new(re, im)
+            }
+
+            #[inline]
+            fn real_from_f64(x: f64) -> Self::Real {
+                x as $t
+            }
+        }
+    };
+}
+
+impl_scalar!(f32);
+impl_scalar!(f64);
+//}}}
+//{{{ fun: workspace
+/// The value reported by a workspace query (`lwork = -1`), which the native routines do not need
+/// beyond this single entry.
+pub(crate) fn workspace<T>(size: usize) -> T
+where
+    T: Scalar,
+{
+    T::from_real(T::real_from_f64(size.max(1) as f64))
+}
+//}}}
+//{{{ fun: norm2
+/// Euclidean norm of `x`, accumulated with `hypot` to avoid overflow.
+fn norm2<T>(x: &[T]) -> T::Real
+where
+    T: Scalar,
+{
+    x.iter()
+        .fold(T::Real::zero(), |acc, &v| acc.hypot(v.modulus()))
+}
+//}}}
+//{{{ fun: normalise_phase
+/// Scales `x` to unit Euclidean norm with its component of largest modulus real and positive, the
+/// normalisation LAPACK applies to eigenvectors.
+fn normalise_phase<T>(x: &mut [T])
+where
+    T: Scalar,
+{
+    let norm = norm2(x);
+    if norm == T::Real::zero() {
+        return;
+    }
+    let mut largest = T::zero();
+    for &v in x.iter() {
+        if v.modulus() > largest.modulus() {
+            largest = v;
+        }
+    }
+    let scale = largest.conj() / T::from_real(largest.modulus() * norm);
+    for v in x.iter_mut() {
+        *v *= scale;
+    }
+}
+//}}}
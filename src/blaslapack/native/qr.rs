@@ -0,0 +1,358 @@
+//! Native Householder QR factorisation and explicit Q formation.
+//!
+//! Reflectors follow LAPACK's conventions exactly, so the output of [`geqrf`] can be consumed by
+//! the LAPACK routines that are not replaced, such as `?ormqr`. Each `H = I − τ v vᴴ` has
+//! `v[0] = 1` implied and `v[1..]` stored below the diagonal, and is generated by [`larfg`], which
+//! makes `β` in `Hᴴ [α, x]ᵀ = [β, 0]ᵀ` real. [`geqrf`] applies `H(i)ᴴ` to the trailing columns and
+//! [`orgqr`] accumulates `Q = H(0) H(1) ⋯ H(k−1)` backwards, as the unblocked `?geqr2` and
+//! `?org2r` do. [`ormqr`] applies `Q` or `Qᴴ` to another matrix from either side, [`geqp3`] pivots
+//! the column of largest remaining norm to the front at every step, recomputing the norms rather
+//! than downdating them, and [`gels`] solves full-rank least-squares and minimum-norm problems
+//! through the QR factorisation of `op(A)` or of its conjugate transpose.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::triangular::{transpose, trsm};
+use super::{norm2, Scalar};
+use crate::common::{Diagonal, Side, Transpose, Triangle, Zero};
+use crate::float::Float;
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ fun: larfg
+/// Generates the reflector that maps `[alpha, x]` to `[β, 0]` with `β` real, overwriting `alpha`
+/// with `β` and `x` with `v[1..]`, and returns `τ`. `τ` is zero, and `H` the identity, when there
+/// is nothing to annihilate.
+pub(super) fn larfg<T>(
+    alpha: &mut T,
+    x: &mut [T],
+) -> T
+where
+    T: Scalar,
+{
+    let xnorm = norm2(x);
+    let (alphr, alphi) = (alpha.real_part(), alpha.imag_part());
+    if xnorm == T::Real::zero() && alphi == T::Real::zero() {
+        return T::zero();
+    }
+    let beta = -alphr.hypot(alphi).hypot(xnorm).copysign(alphr);
+    let tau = T::from_parts((beta - alphr) / beta, -alphi / beta);
+    let scale = T::one() / (*alpha - T::from_real(beta));
+    for v in x.iter_mut() {
+        *v *= scale;
+    }
+    *alpha = T::from_real(beta);
+    tau
+}
+//}}}
+//{{{ fun: apply_left
+/// Applies `I − τ v vᴴ` from the left to columns `cols` of the block of `a` whose first row is
+/// `row`, where `v[0] = 1` and `v[1..]` is `tail`.
+pub(super) fn apply_left<T>(
+    tau: T,
+    tail: &[T],
+    a: &mut [T],
+    lda: usize,
+    row: usize,
+    cols: std::ops::Range<usize>,
+) where
+    T: Scalar,
+{
+    if tau == T::zero() {
+        return;
+    }
+    for j in cols {
+        let col = &mut a[row + j * lda..row + j * lda + tail.len() + 1];
+        let mut w = col[0];
+        for (&v, &c) in tail.iter().zip(&col[1..]) {
+            w += v.conj() * c;
+        }
+        let tw = tau * w;
+        col[0] -= tw;
+        for (&v, c) in tail.iter().zip(&mut col[1..]) {
+            *c -= v * tw;
+        }
+    }
+}
+//}}}
+//{{{ fun: apply_right
+/// Applies `I − τ v vᴴ` from the right to rows `rows` of the block of `a` whose first column is
+/// `col`, where `v[0] = 1` and `v[1..]` is `tail`.
+pub(super) fn apply_right<T>(
+    tau: T,
+    tail: &[T],
+    a: &mut [T],
+    lda: usize,
+    col: usize,
+    rows: std::ops::Range<usize>,
+) where
+    T: Scalar,
+{
+    if tau == T::zero() {
+        return;
+    }
+    for i in rows {
+        let mut w = a[i + col * lda];
+        for (l, &v) in tail.iter().enumerate() {
+            w += a[i + (col + 1 + l) * lda] * v;
+        }
+        let tw = tau * w;
+        a[i + col * lda] -= tw;
+        for (l, &v) in tail.iter().enumerate() {
+            a[i + (col + 1 + l) * lda] -= tw * v.conj();
+        }
+    }
+}
+//}}}
+//{{{ fun: geqrf
+/// Factors the m×n matrix `a` in place as `Q R`, writing the reflector scalars to `tau`.
+pub(crate) fn geqrf<T>(
+    m: i32,
+    n: i32,
+    a: &mut [T],
+    lda: i32,
+    tau: &mut [T],
+) where
+    T: Scalar,
+{
+    let (m, n, lda) = (m as usize, n as usize, lda as usize);
+    for i in 0..m.min(n) {
+        let (head, rest) = a.split_at_mut(i + i * lda + 1);
+        let alpha = &mut head[i + i * lda];
+        tau[i] = larfg(alpha, &mut rest[..m - i - 1]);
+        let tail = a[i + 1 + i * lda..m + i * lda].to_vec();
+        apply_left(tau[i].conj(), &tail, a, lda, i, i + 1..n);
+    }
+}
+//}}}
+//{{{ fun: orgqr
+/// Overwrites the first `n` columns of the m×n `a`, holding `k` reflectors from [`geqrf`], with
+/// the corresponding columns of `Q`.
+pub(crate) fn orgqr<T>(
+    m: i32,
+    n: i32,
+    k: i32,
+    a: &mut [T],
+    lda: i32,
+    tau: &[T],
+) where
+    T: Scalar,
+{
+    let (m, n, k, lda) = (m as usize, n as usize, k as usize, lda as usize);
+    for j in k..n {
+        a[j * lda..j * lda + m].fill(T::zero());
+        if j < m {
+            a[j + j * lda] = T::one();
+        }
+    }
+    for i in (0..k).rev() {
+        let tail = a[i + 1 + i * lda..m + i * lda].to_vec();
+        if i + 1 < n {
+            a[i + i * lda] = T::one();
+            apply_left(tau[i], &tail, a, lda, i, i + 1..n);
+        }
+        for v in &mut a[i + 1 + i * lda..m + i * lda] {
+            *v *= -tau[i];
+        }
+        a[i + i * lda] = T::one() - tau[i];
+        a[i * lda..i + i * lda].fill(T::zero());
+    }
+}
+//}}}
+//{{{ fun: ormqr
+/// Overwrites the m×n `c` with `Q C`, `Qᴴ C`, `C Q` or `C Qᴴ` as selected by `side` and `trans`,
+/// where `Q` is the product of the `k` reflectors from [`geqrf`] in `a` and `tau`. Always returns
+/// 0.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn ormqr<T>(
+    side: u8,
+    trans: u8,
+    m: i32,
+    n: i32,
+    k: i32,
+    a: &[T],
+    lda: i32,
+    tau: &[T],
+    c: &mut [T],
+    ldc: i32,
+) -> i32
+where
+    T: Scalar,
+{
+    let (m, n, k) = (m as usize, n as usize, k as usize);
+    let (lda, ldc) = (lda as usize, ldc as usize);
+    let left = side == b'L' || side == b'l';
+    let notrans = transpose(trans) == Transpose::NoTrans;
+    let nq = if left { m } else { n };
+    // Q = H(0) ⋯ H(k−1): Q C and C Qᴴ apply the reflectors last to first
+    let backwards = left == notrans;
+    let order: Vec<usize> = if backwards {
+        (0..k).rev().collect()
+    } else {
+        (0..k).collect()
+    };
+    for i in order {
+        let tail = &a[i + 1 + i * lda..nq + i * lda];
+        let t = if notrans { tau[i] } else { tau[i].conj() };
+        if left {
+            apply_left(t, tail, c, ldc, i, 0..n);
+        } else {
+            apply_right(t, tail, c, ldc, i, 0..m);
+        }
+    }
+    0
+}
+//}}}
+//{{{ fun: geqp3
+/// Factors the m×n matrix `a` in place as `Q R Pᵀ` with column pivoting. On entry a nonzero
+/// `jpvt[j]` moves column `j` to the front, where it is factored without pivoting; on exit
+/// `jpvt[j]` is the 1-based index of the column of `A` that became column `j` of `A P`.
+pub(crate) fn geqp3<T>(
+    m: i32,
+    n: i32,
+    a: &mut [T],
+    lda: i32,
+    jpvt: &mut [i32],
+    tau: &mut [T],
+) where
+    T: Scalar,
+{
+    let (m, n, lda) = (m as usize, n as usize, lda as usize);
+    let swap_cols = |a: &mut [T], p: usize, q: usize| {
+        for i in 0..m {
+            a.swap(i + p * lda, i + q * lda);
+        }
+    };
+    let mut nfxd = 0;
+    for j in 0..n {
+        if jpvt[j] != 0 {
+            if j != nfxd {
+                swap_cols(a, j, nfxd);
+                jpvt[j] = jpvt[nfxd];
+            }
+            jpvt[nfxd] = (j + 1) as i32;
+            nfxd += 1;
+        } else {
+            jpvt[j] = (j + 1) as i32;
+        }
+    }
+    for i in 0..m.min(n) {
+        if i >= nfxd {
+            let norm = |j: usize| norm2(&a[i + j * lda..m + j * lda]);
+            let mut p = i;
+            let mut best = norm(i);
+            for j in i + 1..n {
+                let v = norm(j);
+                if v > best {
+                    p = j;
+                    best = v;
+                }
+            }
+            if p != i {
+                swap_cols(a, p, i);
+                jpvt.swap(p, i);
+            }
+        }
+        let (head, rest) = a.split_at_mut(i + i * lda + 1);
+        tau[i] = larfg(&mut head[i + i * lda], &mut rest[..m - i - 1]);
+        let tail = a[i + 1 + i * lda..m + i * lda].to_vec();
+        apply_left(tau[i].conj(), &tail, a, lda, i, i + 1..n);
+    }
+}
+//}}}
+//{{{ fun: gels
+/// Solves the least-squares problem `min ‖op(A) X − B‖` when `op(A)` has at least as many rows as
+/// columns, or the minimum-norm problem `op(A) X = B` otherwise, for the m×n `A` of full rank.
+/// `b` holds B in its leading rows on entry and X on exit; for least-squares problems the rows
+/// below X hold `Qᴴ B`, whose squared norms are the residual sums of squares. Returns LAPACK's
+/// `info` code: the 1-based index of the first zero on the diagonal of the triangular factor.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn gels<T>(
+    trans: u8,
+    m: i32,
+    n: i32,
+    nrhs: i32,
+    a: &[T],
+    lda: i32,
+    b: &mut [T],
+    ldb: i32,
+) -> i32
+where
+    T: Scalar,
+{
+    let notrans = transpose(trans) == Transpose::NoTrans;
+    let (mu, nu, ld) = (m as usize, n as usize, lda as usize);
+    let (rows, cols) = if notrans { (mu, nu) } else { (nu, mu) };
+    let tall = rows >= cols;
+    // The matrix to factor, op(A) when it is tall and op(A)ᴴ when it is wide, as r×c
+    let (r, c) = if tall { (rows, cols) } else { (cols, rows) };
+    let mut f = vec![T::zero(); r * c];
+    for j in 0..c {
+        for i in 0..r {
+            // Entry (i, j) of op(A) or of op(A)ᴴ, read from A or from Aᴴ
+            let v = if tall == notrans {
+                a[i + j * ld]
+            } else {
+                a[j + i * ld].conj()
+            };
+            f[i + j * r] = v;
+        }
+    }
+    let mut tau = vec![T::zero(); c];
+    geqrf(r as i32, c as i32, &mut f, r.max(1) as i32, &mut tau);
+    if let Some(i) = (0..c).find(|&i| f[i + i * r] == T::zero()) {
+        return (i + 1) as i32;
+    }
+
+    let (ldbu, nrhsu) = (ldb as usize, nrhs as usize);
+    let (c32, nrhs32, r32) = (c as i32, nrhs, r.max(1) as i32);
+    let one = T::one();
+    if tall {
+        for i in 0..c {
+            let tail = &f[i + 1 + i * r..r + i * r];
+            apply_left(tau[i].conj(), tail, b, ldbu, i, 0..nrhsu);
+        }
+        let (side, uplo, diag) = (Side::Left, Triangle::Upper, Diagonal::NonUnit);
+        trsm(
+            side,
+            uplo,
+            Transpose::NoTrans,
+            diag,
+            c32,
+            nrhs32,
+            one,
+            &f,
+            r32,
+            b,
+            ldb,
+        );
+    } else {
+        let (side, uplo, diag) = (Side::Left, Triangle::Upper, Diagonal::NonUnit);
+        trsm(
+            side,
+            uplo,
+            Transpose::ConjTrans,
+            diag,
+            c32,
+            nrhs32,
+            one,
+            &f,
+            r32,
+            b,
+            ldb,
+        );
+        for j in 0..nrhsu {
+            b[c + j * ldbu..r + j * ldbu].fill(T::zero());
+        }
+        for i in (0..c).rev() {
+            let tail = &f[i + 1 + i * r..r + i * r];
+            apply_left(tau[i], tail, b, ldbu, i, 0..nrhsu);
+        }
+    }
+    0
+}
+//}}}
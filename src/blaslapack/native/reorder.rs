@@ -0,0 +1,549 @@
+//! Native reordering of the real Schur form.
+//!
+//! [`trsen`] follows LAPACK `?trsen`: each selected 1×1 or 2×2 diagonal block is moved to the
+//! top-left, behind the blocks selected before it, by swapping it with its neighbours one at a
+//! time, as `?trexc` does. Each swap ports `?laexc`: two 1×1 blocks are exchanged by a Givens
+//! rotation, and any other pair by the orthogonal transformation built from the solution of the
+//! Sylvester equation `T11 X − X T22 = T12`, which is rejected, and the reordering reported as
+//! failed, when it would perturb the blocks by more than `10 ε ‖T‖`. Swapped 2×2 blocks are
+//! restandardised as in `?lanv2`. The condition numbers `s` and `sep` are computed exactly from
+//! the Kronecker form of the Sylvester operator, of order `m (n − m)`, rather than estimated.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::lu::{getrf, getri, getrs};
+use super::qr::larfg;
+use super::schur::standardise_block;
+use super::Scalar;
+use crate::float::Float;
+//}}}
+//{{{ std imports
+use std::ops::Range;
+//}}}
+//{{{ dep imports
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ fun: trsen
+/// Reorders the real Schur form in the n×n `t`, and with `compq = 'V'` the Schur vectors in `q`,
+/// so that the eigenvalues flagged in `select` lead the diagonal, writing their number to `m` and
+/// all eigenvalues in their new order to `wr` and `wi`. With `job` `'E'`, `'V'` or `'B'` also
+/// computes the reciprocal condition number `s` of the selected cluster and `sep` of its
+/// invariant subspace. Returns LAPACK's `info` code: 1 when a swap was rejected, in which case `t`
+/// and `q` are left partially reordered and `s` and `sep` are zero.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn trsen<T>(
+    job: u8,
+    compq: u8,
+    select: &[i32],
+    n: i32,
+    t: &mut [T],
+    ldt: i32,
+    q: &mut [T],
+    ldq: i32,
+    wr: &mut [T],
+    wi: &mut [T],
+    m: &mut i32,
+    s: &mut T,
+    sep: &mut T,
+) -> i32
+where
+    T: Scalar<Real = T> + Float,
+{
+    let (nu, ldt, ldq) = (n as usize, ldt as usize, ldq as usize);
+    let wantq = compq == b'V' || compq == b'v';
+    let job = job.to_ascii_uppercase();
+    let (wants, wantsp) = (job == b'E' || job == b'B', job == b'V' || job == b'B');
+    let mut h = vec![T::zero(); nu * nu];
+    let mut z = vec![T::zero(); nu * nu];
+    for j in 0..nu {
+        h[j * nu..(j + 1) * nu].copy_from_slice(&t[j * ldt..j * ldt + nu]);
+        if wantq {
+            z[j * nu..(j + 1) * nu].copy_from_slice(&q[j * ldq..j * ldq + nu]);
+        } else {
+            z[j + j * nu] = T::one();
+        }
+    }
+    let starts_pair = |h: &[T], k: usize| k + 1 < nu && h[k + 1 + k * nu] != T::zero();
+
+    let mut count = 0;
+    let mut k = 0;
+    while k < nu {
+        if starts_pair(&h, k) {
+            if select[k] != 0 || select[k + 1] != 0 {
+                count += 2;
+            }
+            k += 2;
+        } else {
+            if select[k] != 0 {
+                count += 1;
+            }
+            k += 1;
+        }
+    }
+    *m = count as i32;
+
+    let mut info = 0;
+    let mut ks = 0;
+    let mut k = 0;
+    while k < nu {
+        let width = if starts_pair(&h, k) { 2 } else { 1 };
+        if select[k] != 0 || (width == 2 && select[k + 1] != 0) {
+            if k != ks && !move_up(&mut h, &mut z, nu, k, ks) {
+                info = 1;
+                break;
+            }
+            ks += width;
+        }
+        k += width;
+    }
+
+    if wants || wantsp {
+        let (cs, csep) = if info != 0 {
+            (T::zero(), T::zero())
+        } else if count == 0 || count == nu {
+            let norm1 = (0..nu)
+                .map(|j| {
+                    h[j * nu..(j + 1) * nu]
+                        .iter()
+                        .fold(T::zero(), |a, v| a + v.abs())
+                })
+                .fold(T::zero(), |a, v| a.max(v));
+            (T::one(), norm1)
+        } else {
+            condition(&h, nu, count)
+        };
+        if wants {
+            *s = cs;
+        }
+        if wantsp {
+            *sep = csep;
+        }
+    }
+
+    for j in 0..nu {
+        t[j * ldt..j * ldt + nu].copy_from_slice(&h[j * nu..(j + 1) * nu]);
+        if wantq {
+            q[j * ldq..j * ldq + nu].copy_from_slice(&z[j * nu..(j + 1) * nu]);
+        }
+    }
+    let mut k = 0;
+    while k < nu {
+        wr[k] = h[k + k * nu];
+        if starts_pair(&h, k) {
+            let (b, c) = (h[k + (k + 1) * nu], h[k + 1 + k * nu]);
+            wr[k + 1] = h[k + 1 + (k + 1) * nu];
+            wi[k] = b.abs().sqrt() * c.abs().sqrt();
+            wi[k + 1] = -wi[k];
+            k += 2;
+        } else {
+            wi[k] = T::zero();
+            k += 1;
+        }
+    }
+    info
+}
+//}}}
+//{{{ fun: move_up
+/// Moves the diagonal block of the n×n `h` starting at `ifst` up to start at `ilst`, as `?trexc`
+/// does, accumulating the transformations into `z`. Returns `false` if a swap was rejected.
+fn move_up<T>(
+    h: &mut [T],
+    z: &mut [T],
+    n: usize,
+    ifst: usize,
+    ilst: usize,
+) -> bool
+where
+    T: Scalar<Real = T> + Float,
+{
+    let sub = |h: &[T], i: usize| h[i + 1 + i * n] != T::zero();
+    // A block of two 1×1 blocks, left when a 2×2 block splits during the swaps, is nbf = 3
+    let mut nbf = if ifst + 1 < n && sub(h, ifst) { 2 } else { 1 };
+    let mut here = ifst;
+    while here > ilst {
+        let mut nbnext = if here >= 2 && sub(h, here - 2) { 2 } else { 1 };
+        if nbf != 3 {
+            if !laexc(h, z, n, here - nbnext, nbnext, nbf) {
+                return false;
+            }
+            here -= nbnext;
+            if nbf == 2 && !sub(h, here) {
+                nbf = 3;
+            }
+            continue;
+        }
+        // Two 1×1 blocks, each swapped individually
+        if !laexc(h, z, n, here - nbnext, nbnext, 1) {
+            return false;
+        }
+        if nbnext == 1 {
+            laexc(h, z, n, here, 1, 1);
+            here -= 1;
+            continue;
+        }
+        if !sub(h, here - 1) {
+            nbnext = 1;
+        }
+        if nbnext == 2 {
+            if !laexc(h, z, n, here - 1, 2, 1) {
+                return false;
+            }
+        } else {
+            laexc(h, z, n, here, 1, 1);
+            laexc(h, z, n, here - 1, 1, 1);
+        }
+        here -= 2;
+    }
+    true
+}
+//}}}
+//{{{ fun: laexc
+/// Swaps the adjacent diagonal blocks of orders `n1` and `n2` of the n×n `h` starting at `j1`, as
+/// `?laexc` does, accumulating the transformation into `z`. Returns `false`, leaving `h` and `z`
+/// unchanged, if the swap would be unstable.
+fn laexc<T>(
+    h: &mut [T],
+    z: &mut [T],
+    n: usize,
+    j1: usize,
+    n1: usize,
+    n2: usize,
+) -> bool
+where
+    T: Scalar<Real = T> + Float,
+{
+    let (j2, j3, j4) = (j1 + 1, j1 + 2, j1 + 3);
+    let at = |i: usize, j: usize| i + j * n;
+    if n1 == 1 && n2 == 1 {
+        let (t11, t22) = (h[at(j1, j1)], h[at(j2, j2)]);
+        let (cs, sn) = lartg(h[at(j1, j2)], t22 - t11);
+        for c in j3..n {
+            let (x, y) = (h[at(j1, c)], h[at(j2, c)]);
+            h[at(j1, c)] = cs * x + sn * y;
+            h[at(j2, c)] = cs * y - sn * x;
+        }
+        rotate_cols(h, n, j1, 0..j1, cs, sn);
+        rotate_cols(z, n, j1, 0..n, cs, sn);
+        h[at(j1, j1)] = t22;
+        h[at(j2, j2)] = t11;
+        return true;
+    }
+
+    // Tentative swap of the diagonal block D, of order nd ≤ 4, to test its stability
+    let nd = n1 + n2;
+    let mut d = [T::zero(); 16];
+    let mut dnorm = T::zero();
+    for j in 0..nd {
+        for i in 0..nd {
+            d[i + j * 4] = h[at(j1 + i, j1 + j)];
+            dnorm = dnorm.max(d[i + j * 4].abs());
+        }
+    }
+    let eps = T::small();
+    let thresh = (T::real_from_f64(10.0) * eps * dnorm).max(smallest::<T>() / eps);
+    let x = sylvester(&d, n1, n2);
+    match (n1, n2) {
+        (1, 2) => {
+            let mut u = [T::one(), x[0], x[2]];
+            let (tail, alpha) = u.split_at_mut(2);
+            let tau = larfg(&mut alpha[0], tail);
+            u[2] = T::one();
+            let t11 = h[at(j1, j1)];
+            larfx_left(&u, tau, &mut d, 4, 0, 0..3);
+            larfx_right(&u, tau, &mut d, 4, 0, 0..3);
+            let err = d[2].abs().max(d[2 + 4].abs()).max((d[2 + 8] - t11).abs());
+            if err > thresh {
+                return false;
+            }
+            larfx_left(&u, tau, h, n, j1, j1..n);
+            larfx_right(&u, tau, h, n, j1, 0..j2 + 1);
+            larfx_right(&u, tau, z, n, j1, 0..n);
+            h[at(j3, j1)] = T::zero();
+            h[at(j3, j2)] = T::zero();
+            h[at(j3, j3)] = t11;
+        }
+        (2, 1) => {
+            let mut u = [-x[0], -x[1], T::one()];
+            let (alpha, tail) = u.split_at_mut(1);
+            let tau = larfg(&mut alpha[0], tail);
+            u[0] = T::one();
+            let t33 = h[at(j3, j3)];
+            larfx_left(&u, tau, &mut d, 4, 0, 0..3);
+            larfx_right(&u, tau, &mut d, 4, 0, 0..3);
+            let err = d[1].abs().max(d[2].abs()).max((d[0] - t33).abs());
+            if err > thresh {
+                return false;
+            }
+            larfx_right(&u, tau, h, n, j1, 0..j3 + 1);
+            larfx_left(&u, tau, h, n, j1, j2..n);
+            larfx_right(&u, tau, z, n, j1, 0..n);
+            h[at(j1, j1)] = t33;
+            h[at(j2, j1)] = T::zero();
+            h[at(j3, j1)] = T::zero();
+        }
+        _ => {
+            // x is 2×2, column-major
+            let mut u1 = [-x[0], -x[1], T::one()];
+            let (alpha, tail) = u1.split_at_mut(1);
+            let tau1 = larfg(&mut alpha[0], tail);
+            u1[0] = T::one();
+            let temp = -tau1 * (x[2] + u1[1] * x[3]);
+            let mut u2 = [-temp * u1[1] - x[3], -temp * u1[2], T::one()];
+            let (alpha, tail) = u2.split_at_mut(1);
+            let tau2 = larfg(&mut alpha[0], tail);
+            u2[0] = T::one();
+            larfx_left(&u1, tau1, &mut d, 4, 0, 0..4);
+            larfx_right(&u1, tau1, &mut d, 4, 0, 0..4);
+            larfx_left(&u2, tau2, &mut d, 4, 1, 0..4);
+            larfx_right(&u2, tau2, &mut d, 4, 1, 0..4);
+            let err = d[2]
+                .abs()
+                .max(d[3].abs())
+                .max(d[2 + 4].abs())
+                .max(d[3 + 4].abs());
+            if err > thresh {
+                return false;
+            }
+            larfx_left(&u1, tau1, h, n, j1, j1..n);
+            larfx_right(&u1, tau1, h, n, j1, 0..j4 + 1);
+            larfx_left(&u2, tau2, h, n, j2, j1..n);
+            larfx_right(&u2, tau2, h, n, j2, 0..j4 + 1);
+            larfx_right(&u1, tau1, z, n, j1, 0..n);
+            larfx_right(&u2, tau2, z, n, j2, 0..n);
+            for (r, c) in [(j3, j1), (j3, j2), (j4, j1), (j4, j2)] {
+                h[at(r, c)] = T::zero();
+            }
+        }
+    }
+
+    // Standardise the 2×2 blocks in their new positions
+    let (mut wr, mut wi) = (vec![T::zero(); n], vec![T::zero(); n]);
+    if n2 == 2 {
+        standardise_block(h, z, n, j1, &mut wr, &mut wi);
+    }
+    if n1 == 2 {
+        standardise_block(h, z, n, j1 + n2, &mut wr, &mut wi);
+    }
+    true
+}
+//}}}
+//{{{ fun: sylvester
+/// Solves `T11 X − X T22 = T12` for the n1×n2 `X`, where the blocks are those of the 4×4
+/// column-major `d` with `T11` of order n1, by Gaussian elimination with complete pivoting on the
+/// Kronecker form. Pivots below `ε max|K|` are perturbed to that value, as in `?lasy2`.
+fn sylvester<T>(
+    d: &[T; 16],
+    n1: usize,
+    n2: usize,
+) -> [T; 4]
+where
+    T: Scalar<Real = T> + Float,
+{
+    let nk = n1 * n2;
+    let mut k = [[T::zero(); 4]; 4];
+    let mut rhs = [T::zero(); 4];
+    for p in 0..n2 {
+        for i in 0..n1 {
+            let row = i + p * n1;
+            rhs[row] = d[i + (n1 + p) * 4];
+            for q in 0..n2 {
+                for j in 0..n1 {
+                    let mut v = T::zero();
+                    if p == q {
+                        v += d[i + j * 4];
+                    }
+                    if i == j {
+                        v -= d[n1 + q + (n1 + p) * 4];
+                    }
+                    k[row][j + q * n1] = v;
+                }
+            }
+        }
+    }
+    let kmax = k.iter().flatten().fold(T::zero(), |a, v| a.max(v.abs()));
+    let smin = (T::small() * kmax).max(smallest::<T>());
+
+    let mut perm = [0, 1, 2, 3];
+    for c in 0..nk {
+        let (mut pr, mut pc) = (c, c);
+        for r in c..nk {
+            for s in c..nk {
+                if k[r][s].abs() > k[pr][pc].abs() {
+                    (pr, pc) = (r, s);
+                }
+            }
+        }
+        k.swap(c, pr);
+        rhs.swap(c, pr);
+        for row in k.iter_mut() {
+            row.swap(c, pc);
+        }
+        perm.swap(c, pc);
+        if k[c][c].abs() < smin {
+            k[c][c] = smin;
+        }
+        for r in c + 1..nk {
+            let f = k[r][c] / k[c][c];
+            let pivot = k[c];
+            for (krs, &kcs) in k[r][c..nk].iter_mut().zip(&pivot[c..nk]) {
+                *krs -= f * kcs;
+            }
+            rhs[r] -= f * rhs[c];
+        }
+    }
+    let mut y = [T::zero(); 4];
+    for c in (0..nk).rev() {
+        let mut v = rhs[c];
+        for s in c + 1..nk {
+            v -= k[c][s] * y[s];
+        }
+        y[c] = v / k[c][c];
+    }
+    let mut x = [T::zero(); 4];
+    for c in 0..nk {
+        x[perm[c]] = y[c];
+    }
+    // Return X column-major with leading dimension n1, padded to 2×2
+    match (n1, n2) {
+        (2, 1) => [x[0], x[1], T::zero(), T::zero()],
+        (1, 2) => [x[0], T::zero(), x[1], T::zero()],
+        _ => x,
+    }
+}
+//}}}
+//{{{ fun: condition
+/// The reciprocal condition numbers `s` of the eigenvalues in the leading block of order `m` of
+/// the n×n `h` and `sep` of the corresponding invariant subspace, from the Kronecker form `K` of
+/// `X ↦ T11 X − X T22`: `s = 1 / √(1 + ‖X‖²_F)` for `K vec(X) = vec(T12)` and `sep = 1 / ‖K⁻¹‖₁`.
+fn condition<T>(
+    h: &[T],
+    n: usize,
+    m: usize,
+) -> (T, T)
+where
+    T: Scalar<Real = T> + Float,
+{
+    let n2 = n - m;
+    let nk = m * n2;
+    let mut k = vec![T::zero(); nk * nk];
+    let mut x = vec![T::zero(); nk];
+    for p in 0..n2 {
+        for i in 0..m {
+            let row = i + p * m;
+            x[row] = h[i + (m + p) * n];
+            for j in 0..m {
+                k[row + (j + p * m) * nk] += h[i + j * n];
+            }
+            for q in 0..n2 {
+                k[row + (i + q * m) * nk] -= h[m + q + (m + p) * n];
+            }
+        }
+    }
+    let nk32 = nk as i32;
+    let mut ipiv = vec![0; nk];
+    if getrf(nk32, nk32, &mut k, nk32, &mut ipiv) != 0 {
+        return (T::zero(), T::zero());
+    }
+    getrs(b'N', nk32, 1, &k, nk32, &ipiv, &mut x, nk32);
+    getri(nk32, &mut k, nk32, &ipiv);
+    let rnorm = x.iter().fold(T::zero(), |a, v| a.hypot(*v));
+    let s = T::one() / (T::one() + rnorm * rnorm).sqrt();
+    let kinv = (0..nk)
+        .map(|j| {
+            k[j * nk..(j + 1) * nk]
+                .iter()
+                .fold(T::zero(), |a, v| a + v.abs())
+        })
+        .fold(T::zero(), |a, v| a.max(v));
+    (s, T::one() / kinv)
+}
+//}}}
+//{{{ fun: lartg
+/// The rotation `[cs, sn]` with `cs f + sn g = r` and `cs g − sn f = 0`.
+fn lartg<T>(
+    f: T,
+    g: T,
+) -> (T, T)
+where
+    T: Scalar<Real = T> + Float,
+{
+    if g == T::zero() {
+        return (T::one(), T::zero());
+    }
+    if f == T::zero() {
+        return (T::zero(), T::one());
+    }
+    let r = f.hypot(g);
+    (f / r, g / r)
+}
+//}}}
+//{{{ fun: rotate_cols
+/// Applies the rotation `[cs, sn]` to columns `j` and `j + 1` of the rows `rows` of `a`.
+fn rotate_cols<T>(
+    a: &mut [T],
+    n: usize,
+    j: usize,
+    rows: Range<usize>,
+    cs: T,
+    sn: T,
+) where
+    T: Scalar<Real = T> + Float,
+{
+    for r in rows {
+        let (x, y) = (a[r + j * n], a[r + (j + 1) * n]);
+        a[r + j * n] = cs * x + sn * y;
+        a[r + (j + 1) * n] = cs * y - sn * x;
+    }
+}
+//}}}
+//{{{ fun: larfx_left
+/// Applies `I − τ v vᵀ` from the left to rows `r0..r0 + 3` and columns `cols` of `a`.
+fn larfx_left<T>(
+    v: &[T; 3],
+    tau: T,
+    a: &mut [T],
+    lda: usize,
+    r0: usize,
+    cols: Range<usize>,
+) where
+    T: Scalar<Real = T> + Float,
+{
+    for j in cols {
+        let sum = (0..3).fold(T::zero(), |s, l| s + v[l] * a[r0 + l + j * lda]);
+        for l in 0..3 {
+            a[r0 + l + j * lda] -= tau * v[l] * sum;
+        }
+    }
+}
+//}}}
+//{{{ fun: larfx_right
+/// Applies `I − τ v vᵀ` from the right to columns `c0..c0 + 3` and rows `rows` of `a`.
+fn larfx_right<T>(
+    v: &[T; 3],
+    tau: T,
+    a: &mut [T],
+    lda: usize,
+    c0: usize,
+    rows: Range<usize>,
+) where
+    T: Scalar<Real = T> + Float,
+{
+    for i in rows {
+        let sum = (0..3).fold(T::zero(), |s, l| s + a[i + (c0 + l) * lda] * v[l]);
+        for l in 0..3 {
+            a[i + (c0 + l) * lda] -= tau * sum * v[l];
+        }
+    }
+}
+//}}}
+//{{{ fun: smallest
+/// The smallest normal `f32`, which is representable at both precisions and serves as the
+/// underflow guard of the thresholds.
+fn smallest<T>() -> T
+where
+    T: Scalar<Real = T> + Float,
+{
+    T::real_from_f64(f32::MIN_POSITIVE as f64)
+}
+//}}}
@@ -0,0 +1,557 @@
+//! Native Hessenberg reduction and Schur decompositions.
+//!
+//! [`hessenberg`] reduces a square matrix to upper Hessenberg form by Householder similarities.
+//! [`real_schur`] then applies Francis double-shift QR steps, in the form of EISPACK `hqr2`, to
+//! reach the real Schur form, and standardises every remaining 2×2 block as LAPACK `?lanv2` does:
+//! blocks with real eigenvalues are split, and blocks with a complex-conjugate pair get equal
+//! diagonal entries and off-diagonal entries of opposite sign. [`complex_schur`] applies
+//! single-shift QR steps with Givens rotations to reach the triangular complex Schur form. All
+//! three work on dense n×n column-major buffers and accumulate the transformations into `z`, so
+//! that `A = Z T Zᴴ` on return.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::qr::{apply_left, apply_right, larfg};
+use super::Scalar;
+use crate::common::{One, Zero};
+use crate::float::Float;
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ fun: hessenberg
+/// Reduces the n×n `h` to upper Hessenberg form in place, right-multiplying `z` by the reflectors.
+/// Entries below the first sub-diagonal are set to zero.
+pub(super) fn hessenberg<T>(
+    h: &mut [T],
+    z: &mut [T],
+    n: usize,
+) where
+    T: Scalar,
+{
+    for k in 0..n.saturating_sub(2) {
+        let (head, rest) = h.split_at_mut(k + 1 + k * n + 1);
+        let tau = larfg(&mut head[k + 1 + k * n], &mut rest[..n - k - 2]);
+        let tail = h[k + 2 + k * n..(k + 1) * n].to_vec();
+        h[k + 2 + k * n..(k + 1) * n].fill(T::zero());
+        apply_left(tau.conj(), &tail, h, n, k + 1, k + 1..n);
+        apply_right(tau, &tail, h, n, k + 1, 0..n);
+        apply_right(tau, &tail, z, n, k + 1, 0..n);
+    }
+}
+//}}}
+//{{{ fun: real_schur
+/// Reduces the real upper Hessenberg n×n `h` to standardised real Schur form, writing the
+/// eigenvalues to `wr` and `wi` in diagonal order with the positive imaginary part of each pair
+/// first. Returns LAPACK's `info` code, positive when the iteration fails to converge.
+pub(super) fn real_schur<T>(
+    h: &mut [T],
+    z: &mut [T],
+    n: usize,
+    wr: &mut [T],
+    wi: &mut [T],
+) -> i32
+where
+    T: Scalar<Real = T> + Float,
+{
+    let ld = n as isize;
+    let at = |i: isize, j: isize| (i + j * ld) as usize;
+    let c = T::real_from_f64;
+    let eps = T::small();
+    let itmax = 30 * n.max(10);
+
+    let mut norm = T::zero();
+    for i in 0..ld {
+        for j in (i - 1).max(0)..ld {
+            norm += h[at(i, j)].abs();
+        }
+    }
+
+    let mut en = ld - 1;
+    let mut exshift = T::zero();
+    let mut iter = 0;
+    let (mut p, mut q, mut r, mut s, mut x, mut y, mut w, mut zz);
+    while en >= 0 {
+        // Look for a single small sub-diagonal element
+        let mut l = en;
+        while l > 0 {
+            s = h[at(l - 1, l - 1)].abs() + h[at(l, l)].abs();
+            if s == T::zero() {
+                s = norm;
+            }
+            if h[at(l, l - 1)].abs() < eps * s {
+                h[at(l, l - 1)] = T::zero();
+                break;
+            }
+            l -= 1;
+        }
+
+        if l == en {
+            // One root found
+            h[at(en, en)] += exshift;
+            wr[en as usize] = h[at(en, en)];
+            wi[en as usize] = T::zero();
+            en -= 1;
+            iter = 0;
+        } else if l == en - 1 {
+            // Two roots found
+            h[at(en, en)] += exshift;
+            h[at(en - 1, en - 1)] += exshift;
+            standardise_block(h, z, n, (en - 1) as usize, wr, wi);
+            en -= 2;
+            iter = 0;
+        } else {
+            // Form the shift
+            x = h[at(en, en)];
+            y = h[at(en - 1, en - 1)];
+            w = h[at(en, en - 1)] * h[at(en - 1, en)];
+
+            // Ad hoc exceptional shifts
+            if iter == 10 {
+                exshift += x;
+                for i in 0..=en {
+                    h[at(i, i)] -= x;
+                }
+                s = h[at(en, en - 1)].abs() + h[at(en - 1, en - 2)].abs();
+                x = c(0.75) * s;
+                y = x;
+                w = c(-0.4375) * s * s;
+            }
+            if iter == 30 {
+                s = (y - x) / c(2.0);
+                s = s * s + w;
+                if s > T::zero() {
+                    s = s.sqrt();
+                    if y < x {
+                        s = -s;
+                    }
+                    s = x - w / ((y - x) / c(2.0) + s);
+                    for i in 0..=en {
+                        h[at(i, i)] -= s;
+                    }
+                    exshift += s;
+                    x = c(0.964);
+                    y = x;
+                    w = x;
+                }
+            }
+            iter += 1;
+            if iter > itmax {
+                return (en + 1) as i32;
+            }
+
+            // Look for two consecutive small sub-diagonal elements
+            let mut m = en - 2;
+            loop {
+                zz = h[at(m, m)];
+                r = x - zz;
+                s = y - zz;
+                p = (r * s - w) / h[at(m + 1, m)] + h[at(m, m + 1)];
+                q = h[at(m + 1, m + 1)] - zz - r - s;
+                r = h[at(m + 2, m + 1)];
+                s = p.abs() + q.abs() + r.abs();
+                p /= s;
+                q /= s;
+                r /= s;
+                if m == l {
+                    break;
+                }
+                let lhs = h[at(m, m - 1)].abs() * (q.abs() + r.abs());
+                let rhs = eps
+                    * (p.abs()
+                        * (h[at(m - 1, m - 1)].abs() + zz.abs() + h[at(m + 1, m + 1)].abs()));
+                if lhs < rhs {
+                    break;
+                }
+                m -= 1;
+            }
+            for i in m + 2..=en {
+                h[at(i, i - 2)] = T::zero();
+                if i > m + 2 {
+                    h[at(i, i - 3)] = T::zero();
+                }
+            }
+
+            // Double QR step on rows l..=en and columns m..=en
+            for k in m..en {
+                let notlast = k != en - 1;
+                if k != m {
+                    p = h[at(k, k - 1)];
+                    q = h[at(k + 1, k - 1)];
+                    r = if notlast {
+                        h[at(k + 2, k - 1)]
+                    } else {
+                        T::zero()
+                    };
+                    x = p.abs() + q.abs() + r.abs();
+                    if x == T::zero() {
+                        continue;
+                    }
+                    p /= x;
+                    q /= x;
+                    r /= x;
+                }
+                s = (p * p + q * q + r * r).sqrt().copysign(p);
+                if s == T::zero() {
+                    continue;
+                }
+                if k != m {
+                    h[at(k, k - 1)] = -s * x;
+                } else if l != m {
+                    h[at(k, k - 1)] = -h[at(k, k - 1)];
+                }
+                p += s;
+                x = p / s;
+                y = q / s;
+                zz = r / s;
+                q /= p;
+                r /= p;
+
+                // Row modification
+                for j in k..ld {
+                    p = h[at(k, j)] + q * h[at(k + 1, j)];
+                    if notlast {
+                        p += r * h[at(k + 2, j)];
+                        h[at(k + 2, j)] -= p * zz;
+                    }
+                    h[at(k, j)] -= p * x;
+                    h[at(k + 1, j)] -= p * y;
+                }
+                // Column modification
+                for i in 0..=en.min(k + 3) {
+                    p = x * h[at(i, k)] + y * h[at(i, k + 1)];
+                    if notlast {
+                        p += zz * h[at(i, k + 2)];
+                        h[at(i, k + 2)] -= p * r;
+                    }
+                    h[at(i, k)] -= p;
+                    h[at(i, k + 1)] -= p * q;
+                }
+                // Accumulate transformations
+                for i in 0..ld {
+                    p = x * z[at(i, k)] + y * z[at(i, k + 1)];
+                    if notlast {
+                        p += zz * z[at(i, k + 2)];
+                        z[at(i, k + 2)] -= p * r;
+                    }
+                    z[at(i, k)] -= p;
+                    z[at(i, k + 1)] -= p * q;
+                }
+            }
+        }
+    }
+
+    for j in 0..n {
+        for i in j + 2..n {
+            h[i + j * n] = T::zero();
+        }
+    }
+    0
+}
+//}}}
+//{{{ fun: standardise_block
+/// Standardises the 2×2 diagonal block of `h` at `i`, as `?lanv2` does, applying the rotation to
+/// the rest of `h` and to `z`, and writes the block's eigenvalues to `wr` and `wi`.
+pub(super) fn standardise_block<T>(
+    h: &mut [T],
+    z: &mut [T],
+    n: usize,
+    i: usize,
+    wr: &mut [T],
+    wi: &mut [T],
+) where
+    T: Scalar<Real = T> + Float,
+{
+    let at = |r: usize, c: usize| r + c * n;
+    let (mut a, mut b, mut c, mut d) = (
+        h[at(i, i)],
+        h[at(i, i + 1)],
+        h[at(i + 1, i)],
+        h[at(i + 1, i + 1)],
+    );
+    let (cs, sn) = lanv2(&mut a, &mut b, &mut c, &mut d);
+    h[at(i, i)] = a;
+    h[at(i, i + 1)] = b;
+    h[at(i + 1, i)] = c;
+    h[at(i + 1, i + 1)] = d;
+
+    for j in i + 2..n {
+        let (x, y) = (h[at(i, j)], h[at(i + 1, j)]);
+        h[at(i, j)] = cs * x + sn * y;
+        h[at(i + 1, j)] = cs * y - sn * x;
+    }
+    for r in 0..i {
+        let (x, y) = (h[at(r, i)], h[at(r, i + 1)]);
+        h[at(r, i)] = cs * x + sn * y;
+        h[at(r, i + 1)] = cs * y - sn * x;
+    }
+    for r in 0..n {
+        let (x, y) = (z[at(r, i)], z[at(r, i + 1)]);
+        z[at(r, i)] = cs * x + sn * y;
+        z[at(r, i + 1)] = cs * y - sn * x;
+    }
+
+    wr[i] = a;
+    wr[i + 1] = d;
+    if c == T::zero() {
+        wi[i] = T::zero();
+        wi[i + 1] = T::zero();
+    } else {
+        wi[i] = b.abs().sqrt() * c.abs().sqrt();
+        wi[i + 1] = -wi[i];
+    }
+}
+//}}}
+//{{{ fun: lanv2
+/// Computes the rotation `[cs, sn]` that brings `[[a, b], [c, d]]` to standard form in place and
+/// returns it, following LAPACK `?lanv2`.
+fn lanv2<T>(
+    a: &mut T,
+    b: &mut T,
+    c: &mut T,
+    d: &mut T,
+) -> (T, T)
+where
+    T: Scalar<Real = T> + Float,
+{
+    let zero = T::zero();
+    let one = T::one();
+    let half = T::real_from_f64(0.5);
+    let sign = |x: T, y: T| x.abs().copysign(y);
+
+    let (mut cs, mut sn);
+    if *c == zero {
+        cs = one;
+        sn = zero;
+    } else if *b == zero {
+        // Swap rows and columns
+        cs = zero;
+        sn = one;
+        std::mem::swap(a, d);
+        *b = -*c;
+        *c = zero;
+    } else if *a - *d == zero && sign(one, *b) != sign(one, *c) {
+        cs = one;
+        sn = zero;
+    } else {
+        let temp = *a - *d;
+        let mut p = half * temp;
+        let bcmax = b.abs().max(c.abs());
+        let bcmis = b.abs().min(c.abs()) * sign(one, *b) * sign(one, *c);
+        let scale = p.abs().max(bcmax);
+        let zz = p / scale * p + bcmax / scale * bcmis;
+        if zz >= T::real_from_f64(4.0) * T::small() {
+            // Real eigenvalues
+            let zz = p + sign(scale.sqrt() * zz.sqrt(), p);
+            *a = *d + zz;
+            *d -= bcmax / zz * bcmis;
+            let tau = c.hypot(zz);
+            cs = zz / tau;
+            sn = *c / tau;
+            *b -= *c;
+            *c = zero;
+        } else {
+            // Complex or nearly equal real eigenvalues: make the diagonal entries equal
+            let sigma = *b + *c;
+            let tau = sigma.hypot(temp);
+            cs = (half * (one + sigma.abs() / tau)).sqrt();
+            sn = -(p / (tau * cs)) * sign(one, sigma);
+
+            let aa = *a * cs + *b * sn;
+            let bb = -*a * sn + *b * cs;
+            let cc = *c * cs + *d * sn;
+            let dd = -*c * sn + *d * cs;
+            *a = aa * cs + cc * sn;
+            *b = bb * cs + dd * sn;
+            *c = -aa * sn + cc * cs;
+            *d = -bb * sn + dd * cs;
+
+            let temp = half * (*a + *d);
+            *a = temp;
+            *d = temp;
+            if *c != zero {
+                if *b != zero {
+                    if sign(one, *b) == sign(one, *c) {
+                        // Real eigenvalues: reduce to upper triangular form
+                        let sab = b.abs().sqrt();
+                        let sac = c.abs().sqrt();
+                        p = sign(sab * sac, *c);
+                        let tau = one / (*b + *c).abs().sqrt();
+                        *a = temp + p;
+                        *d = temp - p;
+                        *b -= *c;
+                        *c = zero;
+                        let cs1 = sab * tau;
+                        let sn1 = sac * tau;
+                        let temp = cs * cs1 - sn * sn1;
+                        sn = cs * sn1 + sn * cs1;
+                        cs = temp;
+                    }
+                } else {
+                    *b = -*c;
+                    *c = zero;
+                    let temp = cs;
+                    cs = -sn;
+                    sn = temp;
+                }
+            }
+        }
+    }
+    (cs, sn)
+}
+//}}}
+//{{{ fun: complex_schur
+/// Reduces the upper Hessenberg n×n `h` to upper triangular Schur form. Returns LAPACK's `info`
+/// code, positive when the iteration fails to converge.
+pub(super) fn complex_schur<T>(
+    h: &mut [T],
+    z: &mut [T],
+    n: usize,
+) -> i32
+where
+    T: Scalar,
+{
+    let at = |i: usize, j: usize| i + j * n;
+    let eps = T::Real::small();
+    let itmax = 30 * n.max(10);
+    let mut norm = T::Real::zero();
+    for j in 0..n {
+        for i in 0..n.min(j + 2) {
+            norm += h[at(i, j)].modulus();
+        }
+    }
+
+    let mut ihi = n;
+    let mut iter = 0;
+    while ihi > 1 {
+        let en = ihi - 1;
+        // Look for a single small sub-diagonal element
+        let mut l = en;
+        while l > 0 {
+            let mut s = h[at(l - 1, l - 1)].modulus() + h[at(l, l)].modulus();
+            if s == T::Real::zero() {
+                s = norm;
+            }
+            if h[at(l, l - 1)].modulus() <= eps * s {
+                h[at(l, l - 1)] = T::zero();
+                break;
+            }
+            l -= 1;
+        }
+        if l == en {
+            ihi -= 1;
+            iter = 0;
+            continue;
+        }
+        iter += 1;
+        if iter > itmax {
+            return ihi as i32;
+        }
+
+        // Wilkinson shift, with exceptional shifts to break cycles
+        let mu = if iter % 10 == 0 {
+            h[at(en, en)] + T::from_real(T::real_from_f64(0.75) * h[at(en, en - 1)].modulus())
+        } else {
+            wilkinson_shift(
+                h[at(en - 1, en - 1)],
+                h[at(en - 1, en)],
+                h[at(en, en - 1)],
+                h[at(en, en)],
+            )
+        };
+
+        // Chase the bulge from column l down to en
+        for k in l..en {
+            let (x, y) = if k == l {
+                (h[at(l, l)] - mu, h[at(l + 1, l)])
+            } else {
+                (h[at(k, k - 1)], h[at(k + 1, k - 1)])
+            };
+            let (c, s) = givens(x, y);
+            let start = if k == l { l } else { k - 1 };
+            for j in start..n {
+                let (hk, hk1) = (h[at(k, j)], h[at(k + 1, j)]);
+                h[at(k, j)] = c * hk + s * hk1;
+                h[at(k + 1, j)] = c * hk1 - s.conj() * hk;
+            }
+            if k > l {
+                h[at(k + 1, k - 1)] = T::zero();
+            }
+            for i in 0..=(k + 2).min(en) {
+                let (hk, hk1) = (h[at(i, k)], h[at(i, k + 1)]);
+                h[at(i, k)] = c * hk + s.conj() * hk1;
+                h[at(i, k + 1)] = c * hk1 - s * hk;
+            }
+            for i in 0..n {
+                let (zk, zk1) = (z[at(i, k)], z[at(i, k + 1)]);
+                z[at(i, k)] = c * zk + s.conj() * zk1;
+                z[at(i, k + 1)] = c * zk1 - s * zk;
+            }
+        }
+    }
+    0
+}
+//}}}
+//{{{ fun: givens
+/// Returns `(c, s)`, with `c` real, such that `[[c, s], [-conj(s), c]]` maps `[x, y]` to
+/// `[r, 0]`.
+fn givens<T>(
+    x: T,
+    y: T,
+) -> (T, T)
+where
+    T: Scalar,
+{
+    let (ax, ay) = (x.modulus(), y.modulus());
+    let r = ax.hypot(ay);
+    if r == T::Real::zero() {
+        (T::one(), T::zero())
+    } else if ax == T::Real::zero() {
+        (T::zero(), y.conj() / T::from_real(ay))
+    } else {
+        let c = T::from_real(ax / r);
+        let s = x / T::from_real(ax) * y.conj() / T::from_real(r);
+        (c, s)
+    }
+}
+//}}}
+//{{{ fun: wilkinson_shift
+/// The eigenvalue of `[[a, b], [c, d]]` closer to `d`.
+fn wilkinson_shift<T>(
+    a: T,
+    b: T,
+    c: T,
+    d: T,
+) -> T
+where
+    T: Scalar,
+{
+    let two = T::one() + T::one();
+    let half = (a - d) / two;
+    let disc = sqrt(half * half + b * c);
+    let (mu1, mu2) = (d + half + disc, d + half - disc);
+    if (mu1 - d).modulus() <= (mu2 - d).modulus() {
+        mu1
+    } else {
+        mu2
+    }
+}
+//}}}
+//{{{ fun: sqrt
+/// Principal square root, built from the real and imaginary parts so that it also serves complex
+/// scalars.
+fn sqrt<T>(x: T) -> T
+where
+    T: Scalar,
+{
+    let (re, im) = (x.real_part(), x.imag_part());
+    let r = x.modulus();
+    let half = T::Real::one() / (T::Real::one() + T::Real::one());
+    let sr = (half * (r + re)).max(T::Real::zero()).sqrt();
+    let si = (half * (r - re)).max(T::Real::zero()).sqrt().copysign(im);
+    T::from_parts(sr, si)
+}
+//}}}
@@ -0,0 +1,348 @@
+//! Native singular value decomposition and the least-squares solver built on it.
+//!
+//! [`gesvd`] and [`gesdd`] follow LAPACK `?gesvd` and `?gesdd` on their interface but use the
+//! one-sided Jacobi method of Hestenes: plane rotations orthogonalise the columns of `A`, or of
+//! `Aᴴ` when `A` is wide, and the column norms are then the singular values. Complex column pairs
+//! are first brought to a real inner product by a unit phase, as in [`syev`](super::syev). Where
+//! the singular vectors are not determined by the rotations, as for zero singular values or for
+//! the full bases of `jobu = 'A'`, they are completed to an orthonormal basis by Gram–Schmidt.
+//! [`gelsd`] computes the minimum-norm least-squares solution from the decomposition, with
+//! singular values up to `rcond σ_max` treated as zero.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::{norm2, Scalar};
+use crate::common::{One, Zero};
+use crate::float::Float;
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+/// Sweeps after which the iteration is reported as not converged.
+const MAX_SWEEPS: usize = 100;
+
+//{{{ fun: gesvd
+/// Computes the singular values `s` of the m×n `a`, in descending order, and as selected by
+/// `jobu` and `jobvt` the left singular vectors `U` and the conjugate-transposed right singular
+/// vectors `Vᴴ`: all of them (`'A'`), the leading `min(m, n)` in `u` or `vt` (`'S'`) or in `a`
+/// (`'O'`), or none (`'N'`). Returns LAPACK's `info` code, positive when the iteration fails to
+/// converge.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn gesvd<T>(
+    jobu: u8,
+    jobvt: u8,
+    m: i32,
+    n: i32,
+    a: &mut [T],
+    lda: i32,
+    s: &mut [T::Real],
+    u: &mut [T],
+    ldu: i32,
+    vt: &mut [T],
+    ldvt: i32,
+) -> i32
+where
+    T: Scalar,
+{
+    let (m, n, lda) = (m as usize, n as usize, lda as usize);
+    let (ldu, ldvt) = (ldu as usize, ldvt as usize);
+    let k = m.min(n);
+    let (jobu, jobvt) = (jobu.to_ascii_uppercase(), jobvt.to_ascii_uppercase());
+    let wanted = |job: u8, full: usize| match job {
+        b'A' => full,
+        b'S' | b'O' => k,
+        _ => 0,
+    };
+    let (ucols, vrows) = (wanted(jobu, m), wanted(jobvt, n));
+
+    let Some((sigma, left, right)) = jacobi(m, n, a, lda, ucols, vrows) else {
+        return 1;
+    };
+    s[..k].copy_from_slice(&sigma[..k]);
+    let (dst, ld) = if jobu == b'O' {
+        (&mut *a, lda)
+    } else {
+        (&mut *u, ldu)
+    };
+    for j in 0..ucols {
+        dst[j * ld..j * ld + m].copy_from_slice(&left[j * m..(j + 1) * m]);
+    }
+    let (dst, ld) = if jobvt == b'O' {
+        (&mut *a, lda)
+    } else {
+        (&mut *vt, ldvt)
+    };
+    for i in 0..vrows {
+        for j in 0..n {
+            dst[i + j * ld] = right[j + i * n].conj();
+        }
+    }
+    0
+}
+//}}}
+//{{{ fun: gesdd
+/// Computes the singular value decomposition of the m×n `a` as [`gesvd`] does, with one `jobz`
+/// for both sets of vectors. With `jobz = 'O'` the leading singular vectors of the longer side
+/// overwrite `a` and the others go to `u` or `vt`.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn gesdd<T>(
+    jobz: u8,
+    m: i32,
+    n: i32,
+    a: &mut [T],
+    lda: i32,
+    s: &mut [T::Real],
+    u: &mut [T],
+    ldu: i32,
+    vt: &mut [T],
+    ldvt: i32,
+) -> i32
+where
+    T: Scalar,
+{
+    let (jobu, jobvt) = match jobz {
+        b'O' | b'o' if m >= n => (b'O', b'S'),
+        b'O' | b'o' => (b'S', b'O'),
+        _ => (jobz, jobz),
+    };
+    gesvd(jobu, jobvt, m, n, a, lda, s, u, ldu, vt, ldvt)
+}
+//}}}
+//{{{ fun: gelsd
+/// Solves `min ‖A X − B‖` for the m×n `A` with the minimum-norm `X`, treating singular values
+/// up to `rcond σ_max` as zero (machine precision when `rcond` is negative). `b` holds B in its
+/// leading m rows on entry and X in its leading n rows on exit; the singular values go to `s` and
+/// the number above the threshold to `rank`. Returns LAPACK's `info` code.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn gelsd<T>(
+    m: i32,
+    n: i32,
+    nrhs: i32,
+    a: &mut [T],
+    lda: i32,
+    b: &mut [T],
+    ldb: i32,
+    s: &mut [T::Real],
+    rcond: T::Real,
+    rank: &mut i32,
+) -> i32
+where
+    T: Scalar,
+{
+    let (mu, nu, lda) = (m as usize, n as usize, lda as usize);
+    let (nrhs, ldb) = (nrhs as usize, ldb as usize);
+    let k = mu.min(nu);
+    let Some((sigma, left, right)) = jacobi(mu, nu, a, lda, k, k) else {
+        return 1;
+    };
+    s[..k].copy_from_slice(&sigma[..k]);
+    let zero = T::Real::zero();
+    let rcond = if rcond < zero {
+        T::Real::small()
+    } else {
+        rcond
+    };
+    let threshold = rcond * sigma.first().copied().unwrap_or(zero);
+    let r = sigma[..k].iter().take_while(|&&v| v > threshold).count();
+    *rank = r as i32;
+
+    let mut x = vec![T::zero(); nu];
+    for j in 0..nrhs {
+        x.fill(T::zero());
+        let col = &mut b[j * ldb..j * ldb + mu.max(nu)];
+        for i in 0..r {
+            let mut c = T::zero();
+            for (l, &v) in col[..mu].iter().enumerate() {
+                c += left[l + i * mu].conj() * v;
+            }
+            c /= T::from_real(sigma[i]);
+            for (l, xl) in x.iter_mut().enumerate() {
+                *xl += right[l + i * nu] * c;
+            }
+        }
+        col[..nu].copy_from_slice(&x);
+    }
+    0
+}
+//}}}
+//{{{ fun: jacobi
+/// One-sided Jacobi decomposition of the m×n `a`. Returns the `min(m, n)` singular values in
+/// descending order with the leading `ucols` left singular vectors as an m×ucols buffer and the
+/// leading `vcols` right singular vectors as an n×vcols buffer, or `None` if the iteration does
+/// not converge.
+#[allow(clippy::type_complexity)]
+fn jacobi<T>(
+    m: usize,
+    n: usize,
+    a: &[T],
+    lda: usize,
+    ucols: usize,
+    vcols: usize,
+) -> Option<(Vec<T::Real>, Vec<T>, Vec<T>)>
+where
+    T: Scalar,
+{
+    // Work on W = A when A is tall and W = Aᴴ when it is wide, so that W = Uw Σ Vwᴴ is r×c with
+    // r ≥ c and Vw is square
+    let tall = m >= n;
+    let (r, c) = if tall { (m, n) } else { (n, m) };
+    let mut w = vec![T::zero(); r * c];
+    for j in 0..c {
+        for i in 0..r {
+            w[i + j * r] = if tall {
+                a[i + j * lda]
+            } else {
+                a[j + i * lda].conj()
+            };
+        }
+    }
+    let mut v = vec![T::zero(); c * c];
+    for i in 0..c {
+        v[i + i * c] = T::one();
+    }
+
+    let eps = T::Real::small();
+    let mut converged = false;
+    for _ in 0..MAX_SWEEPS {
+        let mut rotated = false;
+        for p in 0..c {
+            for q in p + 1..c {
+                rotated |= rotate(&mut w, &mut v, r, c, p, q, eps);
+            }
+        }
+        if !rotated {
+            converged = true;
+            break;
+        }
+    }
+    if !converged {
+        return None;
+    }
+
+    let norms: Vec<T::Real> = (0..c).map(|j| norm2(&w[j * r..(j + 1) * r])).collect();
+    let mut order: Vec<usize> = (0..c).collect();
+    order.sort_by(|&i, &j| {
+        norms[j]
+            .partial_cmp(&norms[i])
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    let sigma: Vec<T::Real> = order.iter().map(|&j| norms[j]).collect();
+
+    // Left singular vectors of W from its normalised columns, completed where σ = 0
+    let (uwcols, vwcols) = if tall { (ucols, vcols) } else { (vcols, ucols) };
+    let mut uw = vec![T::zero(); r * uwcols];
+    let mut have = 0;
+    for &j in order.iter().take(uwcols) {
+        if norms[j] == T::Real::zero() {
+            break;
+        }
+        let scale = T::from_real(norms[j]);
+        for i in 0..r {
+            uw[i + have * r] = w[i + j * r] / scale;
+        }
+        have += 1;
+    }
+    complete_basis(&mut uw, r, have, uwcols);
+    let mut vw = vec![T::zero(); c * vwcols];
+    for (k, &j) in order.iter().take(vwcols).enumerate() {
+        vw[k * c..(k + 1) * c].copy_from_slice(&v[j * c..(j + 1) * c]);
+    }
+
+    let (left, right) = if tall { (uw, vw) } else { (vw, uw) };
+    Some((sigma, left, right))
+}
+//}}}
+//{{{ fun: rotate
+/// Orthogonalises columns `p` and `q` of the r×c `w` by a unitary rotation, accumulated into the
+/// c×c `v`. Returns whether they were not already orthogonal to working precision.
+fn rotate<T>(
+    w: &mut [T],
+    v: &mut [T],
+    r: usize,
+    c: usize,
+    p: usize,
+    q: usize,
+    eps: T::Real,
+) -> bool
+where
+    T: Scalar,
+{
+    let (mut alpha, mut beta, mut gamma) = (T::Real::zero(), T::Real::zero(), T::zero());
+    for i in 0..r {
+        let (x, y) = (w[i + p * r], w[i + q * r]);
+        alpha += x.modulus() * x.modulus();
+        beta += y.modulus() * y.modulus();
+        gamma += x.conj() * y;
+    }
+    let g = gamma.modulus();
+    if g == T::Real::zero() || g <= eps * alpha.sqrt() * beta.sqrt() {
+        return false;
+    }
+    // Column q scaled by the phase has a real inner product with column p
+    let phase = gamma.conj() / T::from_real(g);
+    let one = T::Real::one();
+    let zeta = (beta - alpha) / (g + g);
+    let t = (one / (zeta.abs() + (one + zeta * zeta).sqrt())).copysign(zeta);
+    let cs = one / (one + t * t).sqrt();
+    let (cs, sn) = (T::from_real(cs), T::from_real(cs * t));
+    let apply = |x: &mut [T], len: usize| {
+        for i in 0..len {
+            let (xp, xq) = (x[i + p * len], x[i + q * len] * phase);
+            x[i + p * len] = cs * xp - sn * xq;
+            x[i + q * len] = sn * xp + cs * xq;
+        }
+    };
+    apply(w, r);
+    apply(v, c);
+    true
+}
+//}}}
+//{{{ fun: complete_basis
+/// Extends the `have` orthonormal leading columns of the dim×want `q` to `want` orthonormal
+/// columns, each new one the unit vector least represented in the columns so far,
+/// orthogonalised twice by modified Gram–Schmidt.
+fn complete_basis<T>(
+    q: &mut [T],
+    dim: usize,
+    have: usize,
+    want: usize,
+) where
+    T: Scalar,
+{
+    for col in have..want {
+        let weight = |i: usize| {
+            (0..col).fold(T::Real::zero(), |acc, j| {
+                let v = q[i + j * dim].modulus();
+                acc + v * v
+            })
+        };
+        let mut best = 0;
+        for i in 1..dim {
+            if weight(i) < weight(best) {
+                best = i;
+            }
+        }
+        let mut x = vec![T::zero(); dim];
+        x[best] = T::one();
+        for _ in 0..2 {
+            for j in 0..col {
+                let qj = &q[j * dim..(j + 1) * dim];
+                let mut d = T::zero();
+                for (&a, &b) in qj.iter().zip(&x) {
+                    d += a.conj() * b;
+                }
+                for (xi, &a) in x.iter_mut().zip(qj) {
+                    *xi -= a * d;
+                }
+            }
+        }
+        let norm = T::from_real(norm2(&x));
+        for (i, xi) in x.into_iter().enumerate() {
+            q[i + col * dim] = xi / norm;
+        }
+    }
+}
+//}}}
@@ -0,0 +1,285 @@
+//! Native symmetric and Hermitian eigensolver.
+//!
+//! [`syev`] follows LAPACK `?syev`/`?heev` on its interface but uses the cyclic Jacobi method,
+//! which is simple, unconditionally stable and accurate to high relative precision. Each rotation
+//! first scales row and column `q` by a unit phase so that the pivot `a_pq` becomes real, then
+//! annihilates it with a real plane rotation; the same transformations accumulate the eigenvectors.
+//! [`syevr`] selects a range of the eigenpairs [`syev`] computes, and [`sygv`] reduces a
+//! generalised problem with a positive-definite `B = L Lᴴ` to a standard one with
+//! [`potrf`](super::potrf), as `?sygv`/`?hegv` do.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::triangular::{trmm, trsm};
+use super::{potrf, Scalar};
+use crate::common::{Diagonal, One, Side, Transpose, Triangle, Zero};
+use crate::float::Float;
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+/// Sweeps after which the iteration is reported as not converged.
+const MAX_SWEEPS: usize = 100;
+
+//{{{ fun: syev
+/// Computes the eigenvalues of the Hermitian n×n matrix held in triangle `uplo` of `a`, in
+/// ascending order in `w`, and with `jobz = 'V'` overwrites `a` with the orthonormal eigenvectors.
+/// Returns LAPACK's `info` code, positive when the iteration fails to converge.
+pub(crate) fn syev<T>(
+    jobz: u8,
+    uplo: u8,
+    n: i32,
+    a: &mut [T],
+    lda: i32,
+    w: &mut [T::Real],
+) -> i32
+where
+    T: Scalar,
+{
+    let (n, lda) = (n as usize, lda as usize);
+    let upper = uplo == b'U' || uplo == b'u';
+    let mut h = vec![T::zero(); n * n];
+    for j in 0..n {
+        h[j + j * n] = T::from_real(a[j + j * lda].real_part());
+        for i in 0..j {
+            let v = if upper {
+                a[i + j * lda]
+            } else {
+                a[j + i * lda].conj()
+            };
+            h[i + j * n] = v;
+            h[j + i * n] = v.conj();
+        }
+    }
+    let mut v = vec![T::zero(); n * n];
+    for i in 0..n {
+        v[i + i * n] = T::one();
+    }
+
+    let eps = T::Real::small();
+    let mut converged = false;
+    for _ in 0..MAX_SWEEPS {
+        let (mut off, mut total) = (T::Real::zero(), T::Real::zero());
+        for j in 0..n {
+            for i in 0..n {
+                let m = h[i + j * n].modulus();
+                total += m * m;
+                if i != j {
+                    off += m * m;
+                }
+            }
+        }
+        if off <= eps * eps * total {
+            converged = true;
+            break;
+        }
+        for q in 1..n {
+            for p in 0..q {
+                rotate(&mut h, &mut v, n, p, q);
+            }
+        }
+    }
+    if !converged {
+        return 1;
+    }
+
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&i, &j| {
+        let (wi, wj) = (h[i + i * n].real_part(), h[j + j * n].real_part());
+        wi.partial_cmp(&wj).unwrap_or(std::cmp::Ordering::Equal)
+    });
+    for (k, &j) in order.iter().enumerate() {
+        w[k] = h[j + j * n].real_part();
+        if jobz == b'V' || jobz == b'v' {
+            a[k * lda..k * lda + n].copy_from_slice(&v[j * n..j * n + n]);
+        }
+    }
+    0
+}
+//}}}
+//{{{ fun: syevr
+/// Computes the eigenvalues of the Hermitian n×n matrix held in triangle `uplo` of `a` selected
+/// by `range`: all of them (`'A'`), those in `(vl, vu]` (`'V'`) or the `il`-th to `iu`-th in
+/// ascending order (`'I'`). Writes their number to `m`, the eigenvalues to `w` and, with
+/// `jobz = 'V'`, the eigenvectors to `z`, with every support in `isuppz` the full `1..=n`. `a` is
+/// destroyed and `abstol` is not needed. Returns LAPACK's `info` code.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn syevr<T>(
+    jobz: u8,
+    range: u8,
+    uplo: u8,
+    n: i32,
+    a: &mut [T],
+    lda: i32,
+    vl: T::Real,
+    vu: T::Real,
+    il: i32,
+    iu: i32,
+    m: &mut i32,
+    w: &mut [T::Real],
+    z: &mut [T],
+    ldz: i32,
+    isuppz: &mut [i32],
+) -> i32
+where
+    T: Scalar,
+{
+    let (nu, lda, ldz) = (n as usize, lda as usize, ldz as usize);
+    let mut all = vec![T::Real::zero(); nu];
+    let info = syev(jobz, uplo, n, a, lda as i32, &mut all);
+    if info != 0 {
+        return info;
+    }
+    let selected: Vec<usize> = match range {
+        b'V' | b'v' => (0..nu).filter(|&i| all[i] > vl && all[i] <= vu).collect(),
+        b'I' | b'i' => (il.max(1) as usize - 1..(iu.max(0) as usize).min(nu)).collect(),
+        _ => (0..nu).collect(),
+    };
+    *m = selected.len() as i32;
+    let wantz = jobz == b'V' || jobz == b'v';
+    for (k, &i) in selected.iter().enumerate() {
+        w[k] = all[i];
+        if wantz {
+            z[k * ldz..k * ldz + nu].copy_from_slice(&a[i * lda..i * lda + nu]);
+            isuppz[2 * k] = 1;
+            isuppz[2 * k + 1] = n;
+        }
+    }
+    0
+}
+//}}}
+//{{{ fun: sygv
+/// Computes the eigenvalues `w` of the Hermitian pair `(A, B)` held in triangle `uplo` of `a` and
+/// `b`, with `B` positive definite, for the problem `A x = λ B x` (`itype = 1`), `A B x = λ x`
+/// (2) or `B A x = λ x` (3). With `jobz = 'V'` overwrites `a` with the eigenvectors, normalised
+/// to `xᴴ B x = 1` for types 1 and 2 and `xᴴ B⁻¹ x = 1` for type 3. `b` holds the Cholesky factor
+/// on exit. Returns LAPACK's `info` code: `n + i` when the leading minor of order `i` of `B` is
+/// not positive definite.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn sygv<T>(
+    itype: i32,
+    jobz: u8,
+    uplo: u8,
+    n: i32,
+    a: &mut [T],
+    lda: i32,
+    b: &mut [T],
+    ldb: i32,
+    w: &mut [T::Real],
+) -> i32
+where
+    T: Scalar,
+{
+    let info = potrf(uplo, n, b, ldb);
+    if info != 0 {
+        return n + info;
+    }
+    let (nu, lda, ldb) = (n as usize, lda as usize, ldb as usize);
+    let upper = uplo == b'U' || uplo == b'u';
+    // Dense copies of the lower factor L and of the full A
+    let (mut l, mut c) = (vec![T::zero(); nu * nu], vec![T::zero(); nu * nu]);
+    for j in 0..nu {
+        for i in j..nu {
+            l[i + j * nu] = if upper {
+                b[j + i * ldb].conj()
+            } else {
+                b[i + j * ldb]
+            };
+            let v = if upper {
+                a[j + i * lda].conj()
+            } else {
+                a[i + j * lda]
+            };
+            c[i + j * nu] = v;
+            c[j + i * nu] = v.conj();
+        }
+    }
+
+    let ld = n.max(1);
+    let (lower, diag, one) = (Triangle::Lower, Diagonal::NonUnit, T::one());
+    let (nt, ct) = (Transpose::NoTrans, Transpose::ConjTrans);
+    if itype == 1 {
+        // C = L⁻¹ A L⁻ᴴ
+        trsm(Side::Left, lower, nt, diag, n, n, one, &l, ld, &mut c, ld);
+        trsm(Side::Right, lower, ct, diag, n, n, one, &l, ld, &mut c, ld);
+    } else {
+        // C = Lᴴ A L
+        trmm(Side::Left, lower, ct, diag, n, n, one, &l, ld, &mut c, ld);
+        trmm(Side::Right, lower, nt, diag, n, n, one, &l, ld, &mut c, ld);
+    }
+    let info = syev(jobz, b'L', n, &mut c, ld, w);
+    if info != 0 {
+        return info;
+    }
+    if jobz == b'V' || jobz == b'v' {
+        if itype == 3 {
+            trmm(Side::Left, lower, nt, diag, n, n, one, &l, ld, &mut c, ld);
+        } else {
+            trsm(Side::Left, lower, ct, diag, n, n, one, &l, ld, &mut c, ld);
+        }
+        for j in 0..nu {
+            a[j * lda..j * lda + nu].copy_from_slice(&c[j * nu..(j + 1) * nu]);
+        }
+    }
+    0
+}
+//}}}
+//{{{ fun: rotate
+/// Annihilates `h[p, q]` and `h[q, p]` of the n×n Hermitian `h` by a unitary similarity,
+/// accumulating it into the columns of `v`.
+fn rotate<T>(
+    h: &mut [T],
+    v: &mut [T],
+    n: usize,
+    p: usize,
+    q: usize,
+) where
+    T: Scalar,
+{
+    let g = h[p + q * n].modulus();
+    if g == T::Real::zero() {
+        return;
+    }
+    // Make the pivot real: h ← Dᴴ h D, v ← v D with D = diag(.., phase at q, ..)
+    let phase = h[p + q * n].conj() / T::from_real(g);
+    if phase != T::one() {
+        for i in 0..n {
+            h[i + q * n] *= phase;
+            v[i + q * n] *= phase;
+        }
+        for j in 0..n {
+            h[q + j * n] *= phase.conj();
+        }
+    }
+
+    let (app, aqq) = (h[p + p * n].real_part(), h[q + q * n].real_part());
+    let one = T::Real::one();
+    let two = one + one;
+    let theta = (aqq - app) / (two * g);
+    let t = one.copysign(theta) / (theta.abs() + theta.hypot(one));
+    let c = one / t.hypot(one);
+    let s = t * c;
+    let (c, s) = (T::from_real(c), T::from_real(s));
+
+    for i in 0..n {
+        let (hp, hq) = (h[i + p * n], h[i + q * n]);
+        h[i + p * n] = c * hp - s * hq;
+        h[i + q * n] = s * hp + c * hq;
+        let (vp, vq) = (v[i + p * n], v[i + q * n]);
+        v[i + p * n] = c * vp - s * vq;
+        v[i + q * n] = s * vp + c * vq;
+    }
+    for j in 0..n {
+        let (hp, hq) = (h[p + j * n], h[q + j * n]);
+        h[p + j * n] = c * hp - s * hq;
+        h[q + j * n] = s * hp + c * hq;
+    }
+    h[p + q * n] = T::zero();
+    h[q + p * n] = T::zero();
+    h[p + p * n] = T::from_real(h[p + p * n].real_part());
+    h[q + q * n] = T::from_real(h[q + q * n].real_part());
+}
+//}}}
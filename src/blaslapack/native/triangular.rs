@@ -0,0 +1,280 @@
+//! Native triangular solves and products.
+//!
+//! [`trsv`], [`trsm`] and [`trmm`] follow BLAS `?trsv`, `?trsm` and `?trmm`, and [`trtrs`] follows
+//! LAPACK `?trtrs`, which checks the diagonal for exact zeros before solving. Only the triangle
+//! `uplo` of `A` is read, and its diagonal is taken to be one when `diag` is [`Diagonal::Unit`].
+//! Solves are done by substitution in the order in which `op(A)` is triangular, with dot products
+//! against the already computed entries.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::Scalar;
+use crate::common::{Diagonal, Side, Transpose, Triangle};
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ fun: op_tri
+/// Entry `(i, j)` of `op(A)` for the triangular `A` described by `uplo` and `diag`, with the
+/// entries outside the triangle read as zero.
+#[inline]
+fn op_tri<T>(
+    uplo: Triangle,
+    trans: Transpose,
+    diag: Diagonal,
+    a: &[T],
+    lda: usize,
+    i: usize,
+    j: usize,
+) -> T
+where
+    T: Scalar,
+{
+    let (r, c) = match trans {
+        Transpose::NoTrans => (i, j),
+        _ => (j, i),
+    };
+    let v = if r == c {
+        match diag {
+            Diagonal::Unit => T::one(),
+            Diagonal::NonUnit => a[r + c * lda],
+        }
+    } else if (r > c) == (uplo == Triangle::Lower) {
+        a[r + c * lda]
+    } else {
+        T::zero()
+    };
+    match trans {
+        Transpose::ConjTrans => v.conj(),
+        _ => v,
+    }
+}
+//}}}
+//{{{ fun: substitute
+/// Overwrites the n-vector `x`, stored with stride `inc`, with `M⁻¹ x` for the triangular `M`
+/// whose entry `(i, j)` is `at(i, j)`, lower triangular if `lower`.
+fn substitute<T>(
+    n: usize,
+    lower: bool,
+    unit: bool,
+    at: impl Fn(usize, usize) -> T,
+    x: &mut [T],
+    inc: usize,
+) where
+    T: Scalar,
+{
+    let mut solve = |i: usize, known: std::ops::Range<usize>| {
+        let mut s = x[i * inc];
+        for j in known {
+            s -= at(i, j) * x[j * inc];
+        }
+        x[i * inc] = if unit { s } else { s / at(i, i) };
+    };
+    if lower {
+        for i in 0..n {
+            solve(i, 0..i);
+        }
+    } else {
+        for i in (0..n).rev() {
+            solve(i, i + 1..n);
+        }
+    }
+}
+//}}}
+//{{{ fun: trsv
+/// Overwrites `x`, with stride `incx`, with `op(A)⁻¹ x` for the triangular n×n `A`.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn trsv<T>(
+    uplo: Triangle,
+    trans: Transpose,
+    diag: Diagonal,
+    n: i32,
+    a: &[T],
+    lda: i32,
+    x: &mut [T],
+    incx: i32,
+) where
+    T: Scalar,
+{
+    let (n, lda) = (n as usize, lda as usize);
+    // op(A) is lower triangular when exactly one of "A is lower" and "A is not transposed" fails
+    let lower = (uplo == Triangle::Lower) == (trans == Transpose::NoTrans);
+    let at = |i, j| op_tri(uplo, trans, diag, a, lda, i, j);
+    substitute(n, lower, diag == Diagonal::Unit, at, x, incx as usize);
+}
+//}}}
+//{{{ fun: trsm
+/// Overwrites the m×n `b` with `α op(A)⁻¹ B` when `side` is [`Side::Left`], or `α B op(A)⁻¹` when
+/// it is [`Side::Right`], for the triangular `A`.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn trsm<T>(
+    side: Side,
+    uplo: Triangle,
+    trans: Transpose,
+    diag: Diagonal,
+    m: i32,
+    n: i32,
+    alpha: T,
+    a: &[T],
+    lda: i32,
+    b: &mut [T],
+    ldb: i32,
+) where
+    T: Scalar,
+{
+    let (m, n, lda, ldb) = (m as usize, n as usize, lda as usize, ldb as usize);
+    if m == 0 || n == 0 {
+        return;
+    }
+    for j in 0..n {
+        for v in &mut b[j * ldb..j * ldb + m] {
+            *v *= alpha;
+        }
+    }
+    let lower = (uplo == Triangle::Lower) == (trans == Transpose::NoTrans);
+    let unit = diag == Diagonal::Unit;
+    match side {
+        Side::Left => {
+            let at = |i, j| op_tri(uplo, trans, diag, a, lda, i, j);
+            for j in 0..n {
+                substitute(m, lower, unit, at, &mut b[j * ldb..], 1);
+            }
+        }
+        Side::Right => {
+            // Row i of X op(A) = B is op(A)ᵀ xᵢ = bᵢ, and op(A)ᵀ is triangular the other way
+            let at = |i, j| op_tri(uplo, trans, diag, a, lda, j, i);
+            for i in 0..m {
+                substitute(n, !lower, unit, at, &mut b[i..], ldb);
+            }
+        }
+    }
+}
+//}}}
+//{{{ fun: trmm
+/// Overwrites the m×n `b` with `α op(A) B` when `side` is [`Side::Left`], or `α B op(A)` when it is
+/// [`Side::Right`], for the triangular `A`.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn trmm<T>(
+    side: Side,
+    uplo: Triangle,
+    trans: Transpose,
+    diag: Diagonal,
+    m: i32,
+    n: i32,
+    alpha: T,
+    a: &[T],
+    lda: i32,
+    b: &mut [T],
+    ldb: i32,
+) where
+    T: Scalar,
+{
+    let (m, n, lda, ldb) = (m as usize, n as usize, lda as usize, ldb as usize);
+    let at = |i, j| op_tri(uplo, trans, diag, a, lda, i, j);
+    match side {
+        Side::Left => {
+            let mut col = vec![T::zero(); m];
+            for j in 0..n {
+                for (i, v) in col.iter_mut().enumerate() {
+                    let mut s = T::zero();
+                    for l in 0..m {
+                        s += at(i, l) * b[l + j * ldb];
+                    }
+                    *v = alpha * s;
+                }
+                b[j * ldb..j * ldb + m].copy_from_slice(&col);
+            }
+        }
+        Side::Right => {
+            let mut row = vec![T::zero(); n];
+            for i in 0..m {
+                for (j, v) in row.iter_mut().enumerate() {
+                    let mut s = T::zero();
+                    for l in 0..n {
+                        s += b[i + l * ldb] * at(l, j);
+                    }
+                    *v = alpha * s;
+                }
+                for (j, &v) in row.iter().enumerate() {
+                    b[i + j * ldb] = v;
+                }
+            }
+        }
+    }
+}
+//}}}
+//{{{ fun: trtrs
+/// Solves `op(A) X = B` for the triangular n×n `A` and n×nrhs `B`, overwriting `b` with `X`.
+/// Returns LAPACK's `info` code: the 1-based index of the first zero on a non-unit diagonal, in
+/// which case `b` is left unchanged.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn trtrs<T>(
+    uplo: u8,
+    trans: u8,
+    diag: u8,
+    n: i32,
+    nrhs: i32,
+    a: &[T],
+    lda: i32,
+    b: &mut [T],
+    ldb: i32,
+) -> i32
+where
+    T: Scalar,
+{
+    let uplo = triangle(uplo);
+    let trans = transpose(trans);
+    let diag = diagonal(diag);
+    if diag == Diagonal::NonUnit {
+        let ld = lda as usize;
+        if let Some(i) = (0..n as usize).find(|&i| a[i + i * ld] == T::zero()) {
+            return (i + 1) as i32;
+        }
+    }
+    trsm(
+        Side::Left,
+        uplo,
+        trans,
+        diag,
+        n,
+        nrhs,
+        T::one(),
+        a,
+        lda,
+        b,
+        ldb,
+    );
+    0
+}
+//}}}
+//{{{ fun: triangle
+/// The [`Triangle`] named by a LAPACK `uplo` character.
+pub(super) fn triangle(uplo: u8) -> Triangle {
+    match uplo {
+        b'L' | b'l' => Triangle::Lower,
+        _ => Triangle::Upper,
+    }
+}
+//}}}
+//{{{ fun: transpose
+/// The [`Transpose`] named by a LAPACK `trans` character.
+pub(super) fn transpose(trans: u8) -> Transpose {
+    match trans {
+        b'T' | b't' => Transpose::Trans,
+        b'C' | b'c' => Transpose::ConjTrans,
+        _ => Transpose::NoTrans,
+    }
+}
+//}}}
+//{{{ fun: diagonal
+/// The [`Diagonal`] named by a LAPACK `diag` character.
+fn diagonal(diag: u8) -> Diagonal {
+    match diag {
+        b'U' | b'u' => Diagonal::Unit,
+        _ => Diagonal::NonUnit,
+    }
+}
+//}}}
@@ -0,0 +1,137 @@
+//! LAPACK `?orghr`/`?unghr` wrapper for explicit Q reconstruction from a Hessenberg reduction.
+//!
+//! Provides the [`Orghr`] trait, wrapping the LAPACK `?orghr` routine that expands the compact
+//! Householder representation produced by [`Gehrd`](super::gehrd::Gehrd) into the explicit
+//! orthogonal matrix Q of A = Q H Q^H. Complex types dispatch to `?unghr`, the unitary
+//! counterpart. The `ilo`/`ihi` range and `tau` must match the preceding `?gehrd` call.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::backend;
+use crate::common::Complex;
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors returned by the [`Orghr`] LAPACK wrapper.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// LAPACK returned a non-zero info code indicating an invalid argument.
+    #[error("Error in orghr, exited with code {0}")]
+    LapackError(i32),
+}
+//}}}
+
+//{{{ trait: Orghr
+/// Trait for types that support explicit Q reconstruction from a Hessenberg reduction.
+#[allow(clippy::too_many_arguments)]
+pub trait Orghr: Copy {
+    /// Overwrites the reflectors stored by `gehrd` with the explicit orthogonal (unitary) matrix Q.
+    fn orghr(
+        n: i32,
+        ilo: i32,
+        ihi: i32,
+        a: &mut [Self],
+        lda: i32,
+        tau: &[Self],
+        work: &mut [Self],
+        lwork: i32,
+    ) -> Result<(), Error>;
+}
+//}}}
+//{{{ impl: Orghr for f64
+impl Orghr for f64 {
+    #[inline]
+    fn orghr(
+        n: i32,
+        ilo: i32,
+        ihi: i32,
+        a: &mut [Self],
+        lda: i32,
+        tau: &[Self],
+        work: &mut [Self],
+        lwork: i32,
+    ) -> Result<(), Error> {
+        let info =
+            backend::with_current(|backend| backend.dorghr(n, ilo, ihi, a, lda, tau, work, lwork));
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ impl: Orghr for f32
+impl Orghr for f32 {
+    #[inline]
+    fn orghr(
+        n: i32,
+        ilo: i32,
+        ihi: i32,
+        a: &mut [Self],
+        lda: i32,
+        tau: &[Self],
+        work: &mut [Self],
+        lwork: i32,
+    ) -> Result<(), Error> {
+        let info =
+            backend::with_current(|backend| backend.sorghr(n, ilo, ihi, a, lda, tau, work, lwork));
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ impl: Orghr for Complex<f64>
+impl Orghr for Complex<f64> {
+    #[inline]
+    fn orghr(
+        n: i32,
+        ilo: i32,
+        ihi: i32,
+        a: &mut [Self],
+        lda: i32,
+        tau: &[Self],
+        work: &mut [Self],
+        lwork: i32,
+    ) -> Result<(), Error> {
+        let info =
+            backend::with_current(|backend| backend.zunghr(n, ilo, ihi, a, lda, tau, work, lwork));
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ impl: Orghr for Complex<f32>
+impl Orghr for Complex<f32> {
+    #[inline]
+    fn orghr(
+        n: i32,
+        ilo: i32,
+        ihi: i32,
+        a: &mut [Self],
+        lda: i32,
+        tau: &[Self],
+        work: &mut [Self],
+        lwork: i32,
+    ) -> Result<(), Error> {
+        let info =
+            backend::with_current(|backend| backend.cunghr(n, ilo, ihi, a, lda, tau, work, lwork));
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
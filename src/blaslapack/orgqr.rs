@@ -1,12 +1,15 @@
-//! LAPACK `dorgqr`/`sorgqr` wrapper for explicit Q reconstruction from a QR factorisation.
+//! LAPACK `?orgqr`/`?ungqr` wrapper for explicit Q reconstruction from a QR factorisation.
 //!
 //! Provides the [`Orgqr`] trait, wrapping the LAPACK `?orgqr` routine that expands the compact
-//! Householder representation produced by [`Geqrf`] into an explicit orthogonal matrix Q.
+//! Householder representation produced by [`Geqrf`] into an explicit orthogonal matrix Q. Complex
+//! types dispatch to `?ungqr`, the unitary counterpart.
 //! Parameters k (the number of reflectors), tau, and the workspace follow the LAPACK convention.
 //! This is the second of the two LAPACK calls in the QR decomposition path.
 //--------------------------------------------------------------------------------------------------
 
 //{{{ crate imports
+use super::backend;
+use crate::common::Complex;
 //}}}
 //{{{ std imports
 //}}}
@@ -55,10 +58,8 @@ impl Orgqr for f64 {
         work: &mut [Self],
         lwork: i32,
     ) -> Result<(), Error> {
-        let mut info = 0;
-        unsafe {
-            lapack::dorgqr(m, n, k, a, lda, tau, work, lwork, &mut info);
-        }
+        let info =
+            backend::with_current(|backend| backend.dorgqr(m, n, k, a, lda, tau, work, lwork));
         if info != 0 {
             return Err(Error::LapackError(info));
         }
@@ -79,10 +80,54 @@ impl Orgqr for f32 {
         work: &mut [Self],
         lwork: i32,
     ) -> Result<(), Error> {
-        let mut info = 0;
-        unsafe {
-            lapack::sorgqr(m, n, k, a, lda, tau, work, lwork, &mut info);
+        let info =
+            backend::with_current(|backend| backend.sorgqr(m, n, k, a, lda, tau, work, lwork));
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ impl: Orqr for Complex<f64>
+impl Orgqr for Complex<f64> {
+    #[inline]
+    fn orgqr(
+        m: i32,
+        n: i32,
+        k: i32,
+        a: &mut [Self],
+        lda: i32,
+        tau: &[Self],
+        work: &mut [Self],
+        lwork: i32,
+    ) -> Result<(), Error> {
+        let info =
+            backend::with_current(|backend| backend.zungqr(m, n, k, a, lda, tau, work, lwork));
+        if info != 0 {
+            return Err(Error::LapackError(info));
         }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ impl: Orqr for Complex<f32>
+impl Orgqr for Complex<f32> {
+    #[inline]
+    fn orgqr(
+        m: i32,
+        n: i32,
+        k: i32,
+        a: &mut [Self],
+        lda: i32,
+        tau: &[Self],
+        work: &mut [Self],
+        lwork: i32,
+    ) -> Result<(), Error> {
+        let info =
+            backend::with_current(|backend| backend.cungqr(m, n, k, a, lda, tau, work, lwork));
         if info != 0 {
             return Err(Error::LapackError(info));
         }
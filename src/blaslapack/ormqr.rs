@@ -0,0 +1,319 @@
+//! LAPACK `?ormqr`/`?unmqr` wrapper for applying Q from a QR factorisation.
+//!
+//! Provides the [`Ormqr`] trait, wrapping the LAPACK `?ormqr` (real) and `?unmqr` (complex)
+//! routines that multiply a matrix by Q or Qᵀ/Qᴴ, where Q is held implicitly as the Householder
+//! reflectors and `tau` scalars produced by [`Geqrf`]. Q is never formed explicitly. The shared
+//! raw helpers back the `QrFactor` handles of `DMatrix` and `SMatrix`: a solve applies Qᵀ to the
+//! right-hand side and then back-substitutes with R via [`Trtrs`].
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::backend;
+use super::common::AsI32;
+use super::geqrf::Geqrf;
+use super::trtrs::Trtrs;
+use crate::common::{Complex, ComplexField, One};
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors returned by the [`Ormqr`] LAPACK wrapper.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// LAPACK returned a non-zero info code indicating an invalid argument.
+    #[error("Error in ormqr, exited with code {0}")]
+    LapackError(i32),
+}
+//}}}
+
+//{{{ trait: Ormqr
+/// Trait for types that can apply the orthogonal (unitary) factor of a QR factorisation.
+#[allow(clippy::too_many_arguments)]
+pub trait Ormqr: Copy {
+    /// Overwrites `c` with `op(Q) C` (`side = b'L'`) or `C op(Q)` (`side = b'R'`).
+    ///
+    /// `trans` is `b'N'` for Q or `b'T'` for the adjoint, which is Qᵀ for real types and Qᴴ for
+    /// complex types. `k` is the number of reflectors stored in `a` and `tau`.
+    fn ormqr(
+        side: u8,
+        trans: u8,
+        m: i32,
+        n: i32,
+        k: i32,
+        a: &[Self],
+        lda: i32,
+        tau: &[Self],
+        c: &mut [Self],
+        ldc: i32,
+        work: &mut [Self],
+        lwork: i32,
+    ) -> Result<(), Error>;
+}
+//}}}
+//{{{ impl: Ormqr for f64
+impl Ormqr for f64 {
+    #[inline]
+    fn ormqr(
+        side: u8,
+        trans: u8,
+        m: i32,
+        n: i32,
+        k: i32,
+        a: &[Self],
+        lda: i32,
+        tau: &[Self],
+        c: &mut [Self],
+        ldc: i32,
+        work: &mut [Self],
+        lwork: i32,
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| {
+            backend.dormqr(side, trans, m, n, k, a, lda, tau, c, ldc, work, lwork)
+        });
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+//{{{ impl: Ormqr for f32
+impl Ormqr for f32 {
+    #[inline]
+    fn ormqr(
+        side: u8,
+        trans: u8,
+        m: i32,
+        n: i32,
+        k: i32,
+        a: &[Self],
+        lda: i32,
+        tau: &[Self],
+        c: &mut [Self],
+        ldc: i32,
+        work: &mut [Self],
+        lwork: i32,
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| {
+            backend.sormqr(side, trans, m, n, k, a, lda, tau, c, ldc, work, lwork)
+        });
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+//{{{ impl: Ormqr for Complex<f64>
+impl Ormqr for Complex<f64> {
+    #[inline]
+    fn ormqr(
+        side: u8,
+        trans: u8,
+        m: i32,
+        n: i32,
+        k: i32,
+        a: &[Self],
+        lda: i32,
+        tau: &[Self],
+        c: &mut [Self],
+        ldc: i32,
+        work: &mut [Self],
+        lwork: i32,
+    ) -> Result<(), Error> {
+        // The adjoint of a unitary Q is its conjugate transpose.
+        let trans = if trans == b'T' { b'C' } else { trans };
+        let info = backend::with_current(|backend| {
+            backend.zunmqr(side, trans, m, n, k, a, lda, tau, c, ldc, work, lwork)
+        });
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+//{{{ impl: Ormqr for Complex<f32>
+impl Ormqr for Complex<f32> {
+    #[inline]
+    fn ormqr(
+        side: u8,
+        trans: u8,
+        m: i32,
+        n: i32,
+        k: i32,
+        a: &[Self],
+        lda: i32,
+        tau: &[Self],
+        c: &mut [Self],
+        ldc: i32,
+        work: &mut [Self],
+        lwork: i32,
+    ) -> Result<(), Error> {
+        // The adjoint of a unitary Q is its conjugate transpose.
+        let trans = if trans == b'T' { b'C' } else { trans };
+        let info = backend::with_current(|backend| {
+            backend.cunmqr(side, trans, m, n, k, a, lda, tau, c, ldc, work, lwork)
+        });
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ enum: QrFactorRawError
+#[derive(Error, Debug)]
+pub enum QrFactorRawError {
+    /// R has an exactly zero diagonal element at this (1-based) position, so the columns of A are
+    /// linearly dependent.
+    #[error("matrix is rank deficient, R({0},{0}) is exactly zero")]
+    RankDeficient(i32),
+    #[error(transparent)]
+    Geqrf(#[from] super::geqrf::Error),
+    #[error(transparent)]
+    Ormqr(#[from] Error),
+    #[error(transparent)]
+    Trtrs(super::trtrs::Error),
+}
+//}}}
+//{{{ fun: qr_factor_raw
+/// Shared GEQRF step of the QR factor handles. Consumes the cloned n×m matrix data and returns it
+/// with R in the upper triangle and the Householder vectors below it, together with `tau`.
+pub(crate) fn qr_factor_raw<T>(
+    mut a_data: Vec<T>,
+    n: usize,
+    m: usize,
+) -> Result<(Vec<T>, Vec<T>), QrFactorRawError>
+where
+    T: Geqrf + ComplexField + AsI32,
+{
+    let mut tau = vec![T::zero(); n.min(m)];
+    let mut work = vec![T::zero(); 1];
+    T::geqrf(
+        n as i32,
+        m as i32,
+        &mut a_data,
+        n.max(1) as i32,
+        &mut tau,
+        &mut work,
+        -1,
+    )?;
+
+    let lwork = work[0].as_i32().max(1);
+    let mut work = vec![T::zero(); lwork as usize];
+    T::geqrf(
+        n as i32,
+        m as i32,
+        &mut a_data,
+        n.max(1) as i32,
+        &mut tau,
+        &mut work,
+        lwork,
+    )?;
+    Ok((a_data, tau))
+}
+//}}}
+//{{{ fun: qr_solve_raw
+/// Shared ORMQR + TRTRS step of the QR factor handles, for an n×m factorisation with `n >= m`.
+///
+/// Overwrites the n×`nrhs` column-major `b` with `Qᵀ B` and then solves `R X = (Qᵀ B)[..m]`, so
+/// that the leading m rows of each column hold the (least-squares) solution.
+pub(crate) fn qr_solve_raw<T>(
+    qr: &[T],
+    tau: &[T],
+    n: usize,
+    m: usize,
+    b: &mut [T],
+    nrhs: usize,
+) -> Result<(), QrFactorRawError>
+where
+    T: Ormqr + Trtrs + ComplexField + AsI32,
+{
+    if n == 0 || nrhs == 0 {
+        return Ok(());
+    }
+
+    let mut work = vec![T::zero(); 1];
+    T::ormqr(
+        b'L',
+        b'T',
+        n as i32,
+        nrhs as i32,
+        m as i32,
+        qr,
+        n as i32,
+        tau,
+        b,
+        n as i32,
+        &mut work,
+        -1,
+    )?;
+
+    let lwork = work[0].as_i32().max(1);
+    let mut work = vec![T::zero(); lwork as usize];
+    T::ormqr(
+        b'L',
+        b'T',
+        n as i32,
+        nrhs as i32,
+        m as i32,
+        qr,
+        n as i32,
+        tau,
+        b,
+        n as i32,
+        &mut work,
+        lwork,
+    )?;
+
+    match T::trtrs(
+        b'U',
+        b'N',
+        b'N',
+        m as i32,
+        nrhs as i32,
+        qr,
+        n as i32,
+        b,
+        n as i32,
+    ) {
+        Ok(()) => Ok(()),
+        Err(super::trtrs::Error::LapackError(info)) if info > 0 => {
+            Err(QrFactorRawError::RankDeficient(info))
+        }
+        Err(err) => Err(QrFactorRawError::Trtrs(err)),
+    }
+}
+//}}}
+//{{{ fun: qr_det_raw
+/// Determinant of a square matrix from its QR factors.
+///
+/// Each reflector `H = I - τ v vᴴ` has determinant `1 - τ vᴴv` (`-1` for a real non-trivial
+/// reflector), and det(A) is their product times the product of the diagonal of R.
+pub(crate) fn qr_det_raw<T>(
+    qr: &[T],
+    tau: &[T],
+    n: usize,
+) -> T
+where
+    T: ComplexField,
+{
+    let mut det = T::one();
+    for i in 0..n {
+        let mut vnorm2 = T::Real::one();
+        for j in (i + 1)..n {
+            let v = qr[j + i * n].modulus();
+            vnorm2 += v * v;
+        }
+        det *= qr[i + i * n] * (T::one() - tau[i] * T::from_real(vnorm2));
+    }
+    det
+}
+//}}}
@@ -0,0 +1,193 @@
+//! LAPACK `?pbsv` wrapper for solving symmetric positive-definite banded systems.
+//!
+//! Provides the [`Pbsv`] trait, wrapping the LAPACK `?pbsv` routine that solves A X = B for a
+//! symmetric (complex: Hermitian) positive-definite band matrix A with `kd` off-diagonals through
+//! its banded Cholesky factorisation. Only one triangle of the band is referenced;
+//! [`band_spd_solve_raw`] extracts the lower one from the compact general band layout. A
+//! non-positive leading minor is reported as [`BandSpdSolveRawError::NotPositiveDefinite`].
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::backend;
+use crate::common::{Complex, Zero};
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors returned by the [`Pbsv`] LAPACK wrapper.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// LAPACK returned a non-zero info code indicating an invalid argument or a non-positive minor.
+    #[error("Error in pbsv, exited with code {0}")]
+    LapackError(i32),
+}
+//}}}
+
+//{{{ trait: Pbsv
+/// Trait for types that support solving a positive-definite band system via Cholesky
+/// factorisation.
+#[allow(clippy::too_many_arguments)]
+pub trait Pbsv: Copy {
+    /// Solves the band system A X = B, overwriting `ab` with the Cholesky factor and B with X.
+    fn pbsv(
+        uplo: u8,
+        n: i32,
+        kd: i32,
+        nrhs: i32,
+        ab: &mut [Self],
+        ldab: i32,
+        b: &mut [Self],
+        ldb: i32,
+    ) -> Result<(), Error>;
+}
+//}}}
+
+//{{{ impl: Pbsv for f64
+impl Pbsv for f64 {
+    #[inline]
+    fn pbsv(
+        uplo: u8,
+        n: i32,
+        kd: i32,
+        nrhs: i32,
+        ab: &mut [Self],
+        ldab: i32,
+        b: &mut [Self],
+        ldb: i32,
+    ) -> Result<(), Error> {
+        let info =
+            backend::with_current(|backend| backend.dpbsv(uplo, n, kd, nrhs, ab, ldab, b, ldb));
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ impl: Pbsv for f32
+impl Pbsv for f32 {
+    #[inline]
+    fn pbsv(
+        uplo: u8,
+        n: i32,
+        kd: i32,
+        nrhs: i32,
+        ab: &mut [Self],
+        ldab: i32,
+        b: &mut [Self],
+        ldb: i32,
+    ) -> Result<(), Error> {
+        let info =
+            backend::with_current(|backend| backend.spbsv(uplo, n, kd, nrhs, ab, ldab, b, ldb));
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ impl: Pbsv for Complex<f64>
+impl Pbsv for Complex<f64> {
+    #[inline]
+    fn pbsv(
+        uplo: u8,
+        n: i32,
+        kd: i32,
+        nrhs: i32,
+        ab: &mut [Self],
+        ldab: i32,
+        b: &mut [Self],
+        ldb: i32,
+    ) -> Result<(), Error> {
+        let info =
+            backend::with_current(|backend| backend.zpbsv(uplo, n, kd, nrhs, ab, ldab, b, ldb));
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ impl: Pbsv for Complex<f32>
+impl Pbsv for Complex<f32> {
+    #[inline]
+    fn pbsv(
+        uplo: u8,
+        n: i32,
+        kd: i32,
+        nrhs: i32,
+        ab: &mut [Self],
+        ldab: i32,
+        b: &mut [Self],
+        ldb: i32,
+    ) -> Result<(), Error> {
+        let info =
+            backend::with_current(|backend| backend.cpbsv(uplo, n, kd, nrhs, ab, ldab, b, ldb));
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ enum: BandSpdSolveRawError
+/// Errors returned by [`band_spd_solve_raw`].
+#[derive(Error, Debug)]
+pub enum BandSpdSolveRawError {
+    /// The leading minor of order `i` is not positive.
+    #[error("Matrix is not positive definite, leading minor of order {0} is not positive")]
+    NotPositiveDefinite(i32),
+    /// LAPACK `pbsv` rejected its arguments.
+    #[error(transparent)]
+    Pbsv(#[from] Error),
+}
+//}}}
+//{{{ fun: band_spd_solve_raw
+/// Shared PBSV algorithm. `ab` holds an n×n band matrix with `kd` sub-diagonals and `ku`
+/// super-diagonals in compact band storage; only the diagonal and the `kd` sub-diagonals are
+/// read. `b_data` is the n×nrhs right-hand side, consumed and returned as the solution.
+pub(crate) fn band_spd_solve_raw<T>(
+    ab: &[T],
+    n: usize,
+    kd: usize,
+    ku: usize,
+    mut b_data: Vec<T>,
+    nrhs: usize,
+) -> Result<Vec<T>, BandSpdSolveRawError>
+where
+    T: Pbsv + Zero,
+{
+    let ld = kd + ku + 1;
+    let ldab = kd + 1;
+    let mut chol = vec![T::zero(); ldab * n];
+    for j in 0..n {
+        chol[j * ldab..(j + 1) * ldab].copy_from_slice(&ab[ku + j * ld..(j + 1) * ld]);
+    }
+
+    match T::pbsv(
+        b'L',
+        n as i32,
+        kd as i32,
+        nrhs as i32,
+        &mut chol,
+        ldab as i32,
+        &mut b_data,
+        n.max(1) as i32,
+    ) {
+        Ok(()) => Ok(b_data),
+        Err(Error::LapackError(info)) if info > 0 => {
+            Err(BandSpdSolveRawError::NotPositiveDefinite(info))
+        }
+        Err(err) => Err(err.into()),
+    }
+}
+//}}}
@@ -0,0 +1,199 @@
+//! Shared SVD-based algorithms for the polar decomposition and the orthogonal Procrustes problem.
+//!
+//! Both helpers reduce to a singular value decomposition computed by [`Gesdd`]. For a square
+//! A = W Σ Vᵀ the polar factors are U = W Vᵀ and P = V Σ Vᵀ. The orthogonal matrix R minimising
+//! ‖A R − B‖_F is W Vᵀ for the SVD of M = Aᵀ B; restricting R to proper rotations (the Kabsch
+//! algorithm) flips the singular vector of the smallest singular value when det(W Vᵀ) = −1, with
+//! the sign of the determinant read from an LU factorisation by [`Getrf`]. Products are formed
+//! with [`Gemm`].
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::common::AsI32;
+use super::gemm::Gemm;
+use super::gesdd::{svd_raw, Gesdd, SvdRaw, SvdRawError};
+use super::gesvd::Gesvd;
+use super::getrf::Getrf;
+use super::getrs::{lu_det_raw, lu_factor_raw, LuFactorRawError};
+use crate::common::{One, ProcrustesMode, Transpose, Zero};
+use crate::float::Float;
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: PolarRawError
+#[derive(Error, Debug)]
+pub enum PolarRawError {
+    #[error(transparent)]
+    Svd(#[from] SvdRawError),
+    #[error(transparent)]
+    Getrf(#[from] LuFactorRawError),
+}
+//}}}
+//{{{ struct: PolarRaw
+pub(crate) struct PolarRaw<T> {
+    pub u_data: Vec<T>,
+    pub p_data: Vec<T>,
+}
+//}}}
+//{{{ fun: polar_raw
+/// Shared polar decomposition A = U P. Consumes the cloned n×n matrix data; returns the raw
+/// orthogonal factor U and the symmetric positive semidefinite factor P.
+pub(crate) fn polar_raw<T>(
+    a_data: Vec<T>,
+    n: usize,
+) -> Result<PolarRaw<T>, PolarRawError>
+where
+    T: Gesdd<Real = T> + Gesvd<Real = T> + Gemm + Float + Zero + One + Copy + AsI32,
+{
+    if n == 0 {
+        return Ok(PolarRaw {
+            u_data: Vec::new(),
+            p_data: Vec::new(),
+        });
+    }
+    let SvdRaw {
+        u_data: w,
+        s,
+        vt_data: vt,
+    } = svd_raw(a_data, n, n, b'S')?;
+
+    // U = W Vᵀ
+    let mut u = vec![T::zero(); n * n];
+    T::gemm(
+        Transpose::NoTrans,
+        Transpose::NoTrans,
+        n as i32,
+        n as i32,
+        n as i32,
+        T::one(),
+        &w,
+        n as i32,
+        &vt,
+        n as i32,
+        T::zero(),
+        &mut u,
+        n as i32,
+    );
+
+    // P = V Σ Vᵀ = Vᵀᵀ (Σ Vᵀ)
+    let mut sigma_vt = vt.clone();
+    for (l, &sigma) in s.iter().enumerate() {
+        for j in 0..n {
+            sigma_vt[l + j * n] *= sigma;
+        }
+    }
+    let mut p = vec![T::zero(); n * n];
+    T::gemm(
+        Transpose::Trans,
+        Transpose::NoTrans,
+        n as i32,
+        n as i32,
+        n as i32,
+        T::one(),
+        &vt,
+        n as i32,
+        &sigma_vt,
+        n as i32,
+        T::zero(),
+        &mut p,
+        n as i32,
+    );
+
+    // Remove the rounding asymmetry of the product
+    let half = T::one() / (T::one() + T::one());
+    for j in 0..n {
+        for i in 0..j {
+            let avg = half * (p[i + j * n] + p[j + i * n]);
+            p[i + j * n] = avg;
+            p[j + i * n] = avg;
+        }
+    }
+    Ok(PolarRaw {
+        u_data: u,
+        p_data: p,
+    })
+}
+//}}}
+//{{{ fun: procrustes_raw
+/// Shared orthogonal Procrustes solver. Takes the n×k column-major data of A and B; returns the
+/// raw k×k orthogonal matrix R minimising ‖A R − B‖_F, restricted to det(R) = +1 for
+/// [`ProcrustesMode::Rotation`].
+pub(crate) fn procrustes_raw<T>(
+    a_data: &[T],
+    b_data: &[T],
+    n: usize,
+    k: usize,
+    mode: ProcrustesMode,
+) -> Result<Vec<T>, PolarRawError>
+where
+    T: Gesdd<Real = T> + Gesvd<Real = T> + Gemm + Getrf + Float + Zero + One + Copy + AsI32,
+{
+    if k == 0 {
+        return Ok(Vec::new());
+    }
+
+    // M = Aᵀ B
+    let mut m = vec![T::zero(); k * k];
+    if n > 0 {
+        T::gemm(
+            Transpose::Trans,
+            Transpose::NoTrans,
+            k as i32,
+            k as i32,
+            n as i32,
+            T::one(),
+            a_data,
+            n as i32,
+            b_data,
+            n as i32,
+            T::zero(),
+            &mut m,
+            k as i32,
+        );
+    }
+    let SvdRaw {
+        u_data: mut w,
+        vt_data: vt,
+        ..
+    } = svd_raw(m, k, k, b'S')?;
+
+    let wvt = |w: &[T]| {
+        let mut r = vec![T::zero(); k * k];
+        T::gemm(
+            Transpose::NoTrans,
+            Transpose::NoTrans,
+            k as i32,
+            k as i32,
+            k as i32,
+            T::one(),
+            w,
+            k as i32,
+            &vt,
+            k as i32,
+            T::zero(),
+            &mut r,
+            k as i32,
+        );
+        r
+    };
+    let r = wvt(&w);
+    if mode == ProcrustesMode::Orthogonal {
+        return Ok(r);
+    }
+
+    // R is orthogonal, so its LU factors are well conditioned and only the sign of det matters
+    let (lu, ipiv) = lu_factor_raw(r.clone(), k)?;
+    if lu_det_raw(&lu, &ipiv, k) > T::zero() {
+        return Ok(r);
+    }
+    for i in 0..k {
+        w[i + (k - 1) * k] = -w[i + (k - 1) * k];
+    }
+    Ok(wvt(&w))
+}
+//}}}
@@ -1,12 +1,14 @@
-//! LAPACK `dpotrf`/`spotrf` wrapper for Cholesky factorisation.
+//! LAPACK `?potrf` wrapper for Cholesky factorisation.
 //!
 //! Provides the [`Potrf`] trait, wrapping the LAPACK `?potrf` routine that factors a symmetric
-//! positive-definite matrix A into either `A = L L^T` or `A = U^T U`. The shared raw helper used
-//! by the public matrix APIs computes the lower-triangular factor and zeros the unused upper
-//! triangle before returning it.
+//! (complex: Hermitian) positive-definite matrix A into either `A = L L^H` or `A = U^H U`. The
+//! shared raw helper used by the public matrix APIs computes the lower-triangular factor and zeros
+//! the unused upper triangle before returning it.
 //--------------------------------------------------------------------------------------------------
 
 //{{{ crate imports
+use super::backend;
+use crate::common::Complex;
 //}}}
 //{{{ std imports
 //}}}
@@ -28,7 +30,8 @@ pub enum Error {
 //{{{ trait: Potrf
 /// Trait for types that support Cholesky factorisation.
 pub trait Potrf: Copy {
-    /// Computes the Cholesky factorisation of a symmetric positive-definite matrix.
+    /// Computes the Cholesky factorisation of a symmetric (complex: Hermitian) positive-definite
+    /// matrix.
     fn potrf(
         uplo: u8,
         n: i32,
@@ -46,10 +49,7 @@ impl Potrf for f64 {
         a: &mut [Self],
         lda: i32,
     ) -> Result<(), Error> {
-        let mut info = 0;
-        unsafe {
-            lapack::dpotrf(uplo, n, a, lda, &mut info);
-        }
+        let info = backend::with_current(|backend| backend.dpotrf(uplo, n, a, lda));
         if info != 0 {
             return Err(Error::LapackError(info));
         }
@@ -66,10 +66,41 @@ impl Potrf for f32 {
         a: &mut [Self],
         lda: i32,
     ) -> Result<(), Error> {
-        let mut info = 0;
-        unsafe {
-            lapack::spotrf(uplo, n, a, lda, &mut info);
+        let info = backend::with_current(|backend| backend.spotrf(uplo, n, a, lda));
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+//{{{ impl: Potrf for Complex<f64>
+impl Potrf for Complex<f64> {
+    #[inline]
+    fn potrf(
+        uplo: u8,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| backend.zpotrf(uplo, n, a, lda));
+        if info != 0 {
+            return Err(Error::LapackError(info));
         }
+        Ok(())
+    }
+}
+//}}}
+//{{{ impl: Potrf for Complex<f32>
+impl Potrf for Complex<f32> {
+    #[inline]
+    fn potrf(
+        uplo: u8,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| backend.cpotrf(uplo, n, a, lda));
         if info != 0 {
             return Err(Error::LapackError(info));
         }
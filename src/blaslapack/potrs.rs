@@ -0,0 +1,173 @@
+//! LAPACK `dpotrs`/`spotrs` wrapper for solving linear systems from a Cholesky factorisation.
+//!
+//! Provides the [`Potrs`] trait, wrapping the LAPACK `?potrs` routine that solves `A X = B` for a
+//! symmetric positive-definite matrix A given the triangular factor produced by [`Potrf`]. The
+//! shared raw helpers back the `CholeskyFactor` handles of `DMatrix` and `SMatrix`, which keep the
+//! packed lower-triangular factor and reuse it for any number of right-hand sides.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::backend;
+use super::potrf::Potrf;
+use crate::common::{ComplexField, Field, One, Zero};
+use crate::float::Float;
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors returned by the [`Potrs`] LAPACK wrapper.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// LAPACK returned a non-zero info code indicating an invalid argument.
+    #[error("Error in potrs, exited with code {0}")]
+    LapackError(i32),
+}
+//}}}
+
+//{{{ trait: Potrs
+/// Trait for types that support solving linear systems from a Cholesky factorisation.
+pub trait Potrs: Copy {
+    /// Solves `A X = B` using the Cholesky factor of A computed by [`Potrf`] with the same `uplo`.
+    ///
+    /// On exit `b` is overwritten by the solution X.
+    fn potrs(
+        uplo: u8,
+        n: i32,
+        nrhs: i32,
+        a: &[Self],
+        lda: i32,
+        b: &mut [Self],
+        ldb: i32,
+    ) -> Result<(), Error>;
+}
+//}}}
+//{{{ impl: Potrs for f64
+impl Potrs for f64 {
+    #[inline]
+    fn potrs(
+        uplo: u8,
+        n: i32,
+        nrhs: i32,
+        a: &[Self],
+        lda: i32,
+        b: &mut [Self],
+        ldb: i32,
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| backend.dpotrs(uplo, n, nrhs, a, lda, b, ldb));
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+//{{{ impl: Potrs for f32
+impl Potrs for f32 {
+    #[inline]
+    fn potrs(
+        uplo: u8,
+        n: i32,
+        nrhs: i32,
+        a: &[Self],
+        lda: i32,
+        b: &mut [Self],
+        ldb: i32,
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| backend.spotrs(uplo, n, nrhs, a, lda, b, ldb));
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ enum: CholeskyFactorRawError
+#[derive(Error, Debug)]
+pub enum CholeskyFactorRawError {
+    /// The leading minor of this (1-based) order is not positive definite.
+    #[error("matrix is not positive definite, leading minor of order {0} is not positive")]
+    NotPositiveDefinite(i32),
+    #[error(transparent)]
+    Potrf(super::potrf::Error),
+    #[error(transparent)]
+    Potrs(#[from] Error),
+}
+//}}}
+//{{{ fun: cholesky_factor_raw
+/// Shared POTRF step of the Cholesky factor handles. Consumes the cloned n×n matrix data and
+/// returns it with the lower-triangular factor L in place; the strict upper triangle is left as
+/// LAPACK returned it.
+pub(crate) fn cholesky_factor_raw<T>(
+    mut a_data: Vec<T>,
+    n: usize,
+) -> Result<Vec<T>, CholeskyFactorRawError>
+where
+    T: Potrf,
+{
+    match T::potrf(b'L', n as i32, &mut a_data, n as i32) {
+        Ok(()) => Ok(a_data),
+        Err(super::potrf::Error::LapackError(info)) if info > 0 => {
+            Err(CholeskyFactorRawError::NotPositiveDefinite(info))
+        }
+        Err(err) => Err(CholeskyFactorRawError::Potrf(err)),
+    }
+}
+//}}}
+//{{{ fun: cholesky_solve_raw
+/// Shared POTRS step of the Cholesky factor handles. Overwrites the n×`nrhs` column-major `b` with
+/// the solution of `A X = B`.
+pub(crate) fn cholesky_solve_raw<T>(
+    l: &[T],
+    n: usize,
+    b: &mut [T],
+    nrhs: usize,
+) -> Result<(), CholeskyFactorRawError>
+where
+    T: Potrs,
+{
+    if n == 0 || nrhs == 0 {
+        return Ok(());
+    }
+    T::potrs(b'L', n as i32, nrhs as i32, l, n as i32, b, n as i32)?;
+    Ok(())
+}
+//}}}
+//{{{ fun: cholesky_det_raw
+/// Determinant from a Cholesky factor: the squared product of the diagonal of L.
+pub(crate) fn cholesky_det_raw<T>(
+    l: &[T],
+    n: usize,
+) -> T
+where
+    T: Field + One + Copy,
+{
+    let mut prod = T::one();
+    for i in 0..n {
+        prod *= l[i + i * n];
+    }
+    prod * prod
+}
+//}}}
+//{{{ fun: cholesky_logdet_raw
+/// Natural log of the determinant from a Cholesky factor: twice the sum of `ln L_ii`, which avoids
+/// the overflow of forming the product for large `n`.
+pub(crate) fn cholesky_logdet_raw<T>(
+    l: &[T],
+    n: usize,
+) -> T::Real
+where
+    T: ComplexField,
+{
+    let mut sum = T::Real::zero();
+    for i in 0..n {
+        sum += l[i + i * n].real_part().ln();
+    }
+    sum + sum
+}
+//}}}
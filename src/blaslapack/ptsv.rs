@@ -0,0 +1,169 @@
+//! LAPACK `?ptsv` wrapper for solving symmetric positive-definite tridiagonal systems.
+//!
+//! Provides the [`Ptsv`] trait, wrapping the LAPACK `?ptsv` routine that solves A X = B for a
+//! symmetric (complex: Hermitian) positive-definite tridiagonal matrix A by its L D Lᵀ
+//! factorisation. A is given by its real diagonal and its sub-diagonal; the super-diagonal is
+//! implied by symmetry. A non-positive pivot is reported by [`tridiagonal_spd_solve_raw`] as
+//! [`TridiagonalSpdSolveRawError::NotPositiveDefinite`].
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::backend;
+use crate::common::{Complex, ComplexField};
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors returned by the [`Ptsv`] LAPACK wrapper.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// LAPACK returned a non-zero info code indicating an invalid argument or a non-positive pivot.
+    #[error("Error in ptsv, exited with code {0}")]
+    LapackError(i32),
+}
+//}}}
+
+//{{{ trait: Ptsv
+/// Trait for types that support solving a positive-definite tridiagonal system A X = B.
+pub trait Ptsv: ComplexField {
+    /// Solves the tridiagonal system A X = B, overwriting `d` and `e` with the factorisation and
+    /// B with X.
+    fn ptsv(
+        n: i32,
+        nrhs: i32,
+        d: &mut [Self::Real],
+        e: &mut [Self],
+        b: &mut [Self],
+        ldb: i32,
+    ) -> Result<(), Error>;
+}
+//}}}
+
+//{{{ impl: Ptsv for f64
+impl Ptsv for f64 {
+    #[inline]
+    fn ptsv(
+        n: i32,
+        nrhs: i32,
+        d: &mut [Self],
+        e: &mut [Self],
+        b: &mut [Self],
+        ldb: i32,
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| backend.dptsv(n, nrhs, d, e, b, ldb));
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ impl: Ptsv for f32
+impl Ptsv for f32 {
+    #[inline]
+    fn ptsv(
+        n: i32,
+        nrhs: i32,
+        d: &mut [Self],
+        e: &mut [Self],
+        b: &mut [Self],
+        ldb: i32,
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| backend.sptsv(n, nrhs, d, e, b, ldb));
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ impl: Ptsv for Complex<f64>
+impl Ptsv for Complex<f64> {
+    #[inline]
+    fn ptsv(
+        n: i32,
+        nrhs: i32,
+        d: &mut [f64],
+        e: &mut [Self],
+        b: &mut [Self],
+        ldb: i32,
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| backend.zptsv(n, nrhs, d, e, b, ldb));
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ impl: Ptsv for Complex<f32>
+impl Ptsv for Complex<f32> {
+    #[inline]
+    fn ptsv(
+        n: i32,
+        nrhs: i32,
+        d: &mut [f32],
+        e: &mut [Self],
+        b: &mut [Self],
+        ldb: i32,
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| backend.cptsv(n, nrhs, d, e, b, ldb));
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ enum: TridiagonalSpdSolveRawError
+/// Errors returned by [`tridiagonal_spd_solve_raw`].
+#[derive(Error, Debug)]
+pub enum TridiagonalSpdSolveRawError {
+    /// The leading minor of order `i` is not positive.
+    #[error("Matrix is not positive definite, leading minor of order {0} is not positive")]
+    NotPositiveDefinite(i32),
+    /// LAPACK `ptsv` rejected its arguments.
+    #[error(transparent)]
+    Ptsv(#[from] Error),
+}
+//}}}
+//{{{ fun: tridiagonal_spd_solve_raw
+/// Shared PTSV algorithm. Takes the diagonal and sub-diagonal of an n×n symmetric (Hermitian)
+/// tridiagonal matrix, consumes the n×nrhs right-hand side and returns the solution. Only the real
+/// part of the diagonal is used.
+pub(crate) fn tridiagonal_spd_solve_raw<T>(
+    d: &[T],
+    mut e: Vec<T>,
+    mut b_data: Vec<T>,
+    nrhs: usize,
+) -> Result<Vec<T>, TridiagonalSpdSolveRawError>
+where
+    T: Ptsv,
+{
+    let n = d.len();
+    let mut d_real: Vec<T::Real> = d.iter().map(|x| x.real_part()).collect();
+    match T::ptsv(
+        n as i32,
+        nrhs as i32,
+        &mut d_real,
+        &mut e,
+        &mut b_data,
+        n.max(1) as i32,
+    ) {
+        Ok(()) => Ok(b_data),
+        Err(Error::LapackError(info)) if info > 0 => {
+            Err(TridiagonalSpdSolveRawError::NotPositiveDefinite(info))
+        }
+        Err(err) => Err(err.into()),
+    }
+}
+//}}}
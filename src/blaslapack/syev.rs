@@ -1,13 +1,16 @@
-//! LAPACK `dsyev`/`ssyev` wrapper for symmetric (real) eigendecomposition.
+//! LAPACK `?syev`/`?heev` wrapper for symmetric and Hermitian eigendecomposition.
 //!
 //! Provides the [`Syev`] trait, wrapping the LAPACK `?syev` routine for computing eigenvalues and
-//! eigenvectors of a real symmetric matrix. The `jobz` parameter controls whether eigenvectors are
-//! computed; `uplo` selects which triangle is used. Eigenvalues are written to a separate array in
-//! ascending order; eigenvectors (if requested) overwrite the input. This is the LAPACK driver used
-//! by both `DMatrix::symeig()` and `SMatrix::symeig()`.
+//! eigenvectors of a real symmetric matrix, and `?heev` for a complex Hermitian one. The `jobz`
+//! parameter controls whether eigenvectors are computed; `uplo` selects which triangle is used.
+//! Eigenvalues are always real and are written to a separate array in ascending order;
+//! eigenvectors (if requested) overwrite the input. This is the LAPACK driver used by both
+//! `DMatrix::symeig()` and `SMatrix::symeig()`.
 //--------------------------------------------------------------------------------------------------
 
 //{{{ crate imports
+use super::backend;
+use crate::common::{Complex, ComplexField, Zero};
 //}}}
 //{{{ std imports
 //}}}
@@ -29,17 +32,19 @@ pub enum Error {
 //{{{ trait: Syev
 /// Trait for LAPACK's symmetric eigenvalue computation routine
 #[allow(clippy::too_many_arguments)]
-pub trait Syev: Copy {
-    /// Computes eigenvalues and optionally eigenvectors of a real symmetric matrix.
+pub trait Syev: ComplexField {
+    /// Computes eigenvalues and optionally eigenvectors of a real symmetric or complex Hermitian
+    /// matrix.
     fn syev(
         jobz: u8, // 'N' for eigenvalues only, 'V' for eigenvalues and eigenvectors
         uplo: u8, // 'U' for upper triangle, 'L' for lower triangle
         n: i32,
         a: &mut [Self], // On exit, contains eigenvectors if jobz = 'V'
         lda: i32,
-        w: &mut [Self], // Contains eigenvalues on exit
+        w: &mut [Self::Real], // Contains eigenvalues on exit
         work: &mut [Self],
         lwork: i32,
+        rwork: &mut [Self::Real], // At least 3n - 2 for complex types, unused for real types
     ) -> Result<(), Error>;
 }
 //}}}
@@ -56,11 +61,10 @@ impl Syev for f64 {
         w: &mut [Self],
         work: &mut [Self],
         lwork: i32,
+        _rwork: &mut [Self],
     ) -> Result<(), Error> {
-        let mut info = 0;
-        unsafe {
-            lapack::dsyev(jobz, uplo, n, a, lda, w, work, lwork, &mut info);
-        }
+        let info =
+            backend::with_current(|backend| backend.dsyev(jobz, uplo, n, a, lda, w, work, lwork));
         if info != 0 {
             return Err(Error::LapackError(info));
         }
@@ -81,11 +85,60 @@ impl Syev for f32 {
         w: &mut [Self],
         work: &mut [Self],
         lwork: i32,
+        _rwork: &mut [Self],
+    ) -> Result<(), Error> {
+        let info =
+            backend::with_current(|backend| backend.ssyev(jobz, uplo, n, a, lda, w, work, lwork));
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ impl: Syev for Complex<f64>
+impl Syev for Complex<f64> {
+    #[inline]
+    fn syev(
+        jobz: u8,
+        uplo: u8,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        w: &mut [f64],
+        work: &mut [Self],
+        lwork: i32,
+        rwork: &mut [f64],
     ) -> Result<(), Error> {
-        let mut info = 0;
-        unsafe {
-            lapack::ssyev(jobz, uplo, n, a, lda, w, work, lwork, &mut info);
+        let info = backend::with_current(|backend| {
+            backend.zheev(jobz, uplo, n, a, lda, w, work, lwork, rwork)
+        });
+        if info != 0 {
+            return Err(Error::LapackError(info));
         }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ impl: Syev for Complex<f32>
+impl Syev for Complex<f32> {
+    #[inline]
+    fn syev(
+        jobz: u8,
+        uplo: u8,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        w: &mut [f32],
+        work: &mut [Self],
+        lwork: i32,
+        rwork: &mut [f32],
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| {
+            backend.cheev(jobz, uplo, n, a, lda, w, work, lwork, rwork)
+        });
         if info != 0 {
             return Err(Error::LapackError(info));
         }
@@ -95,9 +148,12 @@ impl Syev for f32 {
 //}}}
 
 //{{{ struct: SymEigRaw
-pub(crate) struct SymEigRaw<T> {
+pub(crate) struct SymEigRaw<T>
+where
+    T: ComplexField,
+{
     pub eigvecs_data: Vec<T>,
-    pub eigvals: Vec<T>,
+    pub eigvals: Vec<T::Real>,
 }
 //}}}
 //{{{ fun: symeig_raw
@@ -107,15 +163,10 @@ pub(crate) fn symeig_raw<T>(
     n: usize,
 ) -> Result<SymEigRaw<T>, Error>
 where
-    T: Syev
-        + crate::common::One
-        + crate::common::Zero
-        + crate::common::Field
-        + Default
-        + Copy
-        + super::common::AsI32,
+    T: Syev + super::common::AsI32,
 {
-    let mut eigvals = vec![T::zero(); n];
+    let mut eigvals = vec![T::Real::zero(); n];
+    let mut rwork = vec![T::Real::zero(); (3 * n).saturating_sub(2).max(1)];
 
     let mut work = vec![T::zero(); 1];
     T::syev(
@@ -127,6 +178,7 @@ where
         &mut eigvals,
         &mut work,
         -1,
+        &mut rwork,
     )?;
 
     let lwork = work[0].as_i32();
@@ -140,6 +192,7 @@ where
         &mut eigvals,
         &mut work,
         lwork,
+        &mut rwork,
     )?;
 
     Ok(SymEigRaw {
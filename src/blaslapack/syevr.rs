@@ -0,0 +1,336 @@
+//! LAPACK `?syevr`/`?heevr` wrapper for selected eigenpairs of a symmetric or Hermitian matrix.
+//!
+//! Provides the [`Syevr`] trait, wrapping the LAPACK `?syevr` routine (real symmetric) and
+//! `?heevr` (complex Hermitian). Unlike [`Syev`](super::syev::Syev), the caller selects a subset
+//! of the spectrum, either by index or by a value interval, and may skip the eigenvectors. The
+//! matrix is reduced to tridiagonal form and the selected eigenpairs are found with the MRRR
+//! algorithm, so the cost of the eigenvector stage scales with the number of eigenpairs
+//! requested. Eigenvalues are returned in ascending order.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::backend;
+use super::common::AsI32;
+use super::syev::SymEigRaw;
+use crate::common::{Complex, ComplexField, SymEigRange, Zero};
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors returned by the [`Syevr`] LAPACK wrapper.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// LAPACK returned a non-zero info code indicating an invalid argument or an internal error.
+    #[error("Error in syevr, exited with code {0}")]
+    LapackError(i32),
+}
+//}}}
+
+//{{{ trait: Syevr
+/// Trait for LAPACK's selected symmetric eigenvalue computation routine.
+#[allow(clippy::too_many_arguments)]
+pub trait Syevr: ComplexField {
+    /// Computes selected eigenvalues and optionally eigenvectors of a real symmetric or complex
+    /// Hermitian matrix.
+    fn syevr(
+        jobz: u8,  // 'N' for eigenvalues only, 'V' for eigenvalues and eigenvectors
+        range: u8, // 'A' for all, 'V' for values in (vl, vu], 'I' for indices il..=iu (1-based)
+        uplo: u8,  // 'U' for upper triangle, 'L' for lower triangle
+        n: i32,
+        a: &mut [Self], // Destroyed on exit
+        lda: i32,
+        vl: Self::Real,
+        vu: Self::Real,
+        il: i32,
+        iu: i32,
+        abstol: Self::Real,
+        m: &mut i32,          // Number of eigenvalues found
+        w: &mut [Self::Real], // Contains the selected eigenvalues on exit
+        z: &mut [Self],       // Contains the selected eigenvectors if jobz = 'V'
+        ldz: i32,
+        isuppz: &mut [i32],
+        work: &mut [Self],
+        lwork: i32,
+        rwork: &mut [Self::Real], // At least 24n for complex types, unused for real types
+        iwork: &mut [i32],
+        liwork: i32,
+    ) -> Result<(), Error>;
+}
+//}}}
+
+//{{{ impl: Syevr for f64
+impl Syevr for f64 {
+    #[inline]
+    fn syevr(
+        jobz: u8,
+        range: u8,
+        uplo: u8,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        vl: Self,
+        vu: Self,
+        il: i32,
+        iu: i32,
+        abstol: Self,
+        m: &mut i32,
+        w: &mut [Self],
+        z: &mut [Self],
+        ldz: i32,
+        isuppz: &mut [i32],
+        work: &mut [Self],
+        lwork: i32,
+        _rwork: &mut [Self],
+        iwork: &mut [i32],
+        liwork: i32,
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| {
+            backend.dsyevr(
+                jobz, range, uplo, n, a, lda, vl, vu, il, iu, abstol, m, w, z, ldz, isuppz, work,
+                lwork, iwork, liwork,
+            )
+        });
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ impl: Syevr for f32
+impl Syevr for f32 {
+    #[inline]
+    fn syevr(
+        jobz: u8,
+        range: u8,
+        uplo: u8,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        vl: Self,
+        vu: Self,
+        il: i32,
+        iu: i32,
+        abstol: Self,
+        m: &mut i32,
+        w: &mut [Self],
+        z: &mut [Self],
+        ldz: i32,
+        isuppz: &mut [i32],
+        work: &mut [Self],
+        lwork: i32,
+        _rwork: &mut [Self],
+        iwork: &mut [i32],
+        liwork: i32,
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| {
+            backend.ssyevr(
+                jobz, range, uplo, n, a, lda, vl, vu, il, iu, abstol, m, w, z, ldz, isuppz, work,
+                lwork, iwork, liwork,
+            )
+        });
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ impl: Syevr for Complex<f64>
+impl Syevr for Complex<f64> {
+    #[inline]
+    fn syevr(
+        jobz: u8,
+        range: u8,
+        uplo: u8,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        vl: f64,
+        vu: f64,
+        il: i32,
+        iu: i32,
+        abstol: f64,
+        m: &mut i32,
+        w: &mut [f64],
+        z: &mut [Self],
+        ldz: i32,
+        isuppz: &mut [i32],
+        work: &mut [Self],
+        lwork: i32,
+        rwork: &mut [f64],
+        iwork: &mut [i32],
+        liwork: i32,
+    ) -> Result<(), Error> {
+        let lrwork = rwork.len() as i32;
+        let info = backend::with_current(|backend| {
+            backend.zheevr(
+                jobz, range, uplo, n, a, lda, vl, vu, il, iu, abstol, m, w, z, ldz, isuppz, work,
+                lwork, rwork, lrwork, iwork, liwork,
+            )
+        });
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ impl: Syevr for Complex<f32>
+impl Syevr for Complex<f32> {
+    #[inline]
+    fn syevr(
+        jobz: u8,
+        range: u8,
+        uplo: u8,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        vl: f32,
+        vu: f32,
+        il: i32,
+        iu: i32,
+        abstol: f32,
+        m: &mut i32,
+        w: &mut [f32],
+        z: &mut [Self],
+        ldz: i32,
+        isuppz: &mut [i32],
+        work: &mut [Self],
+        lwork: i32,
+        rwork: &mut [f32],
+        iwork: &mut [i32],
+        liwork: i32,
+    ) -> Result<(), Error> {
+        let lrwork = rwork.len() as i32;
+        let info = backend::with_current(|backend| {
+            backend.cheevr(
+                jobz, range, uplo, n, a, lda, vl, vu, il, iu, abstol, m, w, z, ldz, isuppz, work,
+                lwork, rwork, lrwork, iwork, liwork,
+            )
+        });
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ fun: symeig_range_raw
+/// Shared SYEVR algorithm. Consumes the cloned matrix data; returns the selected eigenvalues in
+/// ascending order and, if `eigvecs` is set, the matching n×k eigenvector buffer (empty otherwise).
+///
+/// # Panics
+///
+/// Panics if an index range is reversed or extends past the matrix order.
+pub(crate) fn symeig_range_raw<T>(
+    mut a_data: Vec<T>,
+    n: usize,
+    range: SymEigRange<T::Real>,
+    eigvecs: bool,
+) -> Result<SymEigRaw<T>, Error>
+where
+    T: Syevr + AsI32,
+{
+    let zero = T::Real::zero();
+    let (range, vl, vu, il, iu, max_m) = match range {
+        SymEigRange::Index(lo, hi) => {
+            assert!(
+                lo <= hi && hi < n,
+                "eigenvalue index range [{lo}, {hi}] out of bounds for order {n}"
+            );
+            (b'I', zero, zero, lo as i32 + 1, hi as i32 + 1, hi - lo + 1)
+        }
+        SymEigRange::Value(vl, vu) => (b'V', vl, vu, 0, 0, n),
+    };
+    if n == 0 {
+        return Ok(SymEigRaw {
+            eigvecs_data: Vec::new(),
+            eigvals: Vec::new(),
+        });
+    }
+
+    let jobz = if eigvecs { b'V' } else { b'N' };
+    let ld = n as i32;
+    let mut m = 0;
+    let mut eigvals = vec![zero; n];
+    let mut z = vec![T::zero(); if eigvecs { n * max_m } else { 1 }];
+    let mut isuppz = vec![0; 2 * max_m.max(1)];
+    let mut rwork = vec![zero; 24 * n];
+
+    let mut work = vec![T::zero(); 1];
+    let mut iwork = vec![0; 1];
+    T::syevr(
+        jobz,
+        range,
+        b'L',
+        ld,
+        &mut a_data,
+        ld,
+        vl,
+        vu,
+        il,
+        iu,
+        zero,
+        &mut m,
+        &mut eigvals,
+        &mut z,
+        ld,
+        &mut isuppz,
+        &mut work,
+        -1,
+        &mut rwork,
+        &mut iwork,
+        -1,
+    )?;
+
+    let lwork = work[0].as_i32().max(1);
+    let liwork = iwork[0].max(1);
+    let mut work = vec![T::zero(); lwork as usize];
+    let mut iwork = vec![0; liwork as usize];
+    T::syevr(
+        jobz,
+        range,
+        b'L',
+        ld,
+        &mut a_data,
+        ld,
+        vl,
+        vu,
+        il,
+        iu,
+        zero,
+        &mut m,
+        &mut eigvals,
+        &mut z,
+        ld,
+        &mut isuppz,
+        &mut work,
+        lwork,
+        &mut rwork,
+        &mut iwork,
+        liwork,
+    )?;
+
+    let m = m as usize;
+    eigvals.truncate(m);
+    if eigvecs {
+        z.truncate(n * m);
+    } else {
+        z.clear();
+    }
+    Ok(SymEigRaw {
+        eigvecs_data: z,
+        eigvals,
+    })
+}
+//}}}
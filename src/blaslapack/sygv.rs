@@ -0,0 +1,243 @@
+//! LAPACK `?sygv`/`?hegv` wrapper for the symmetric-definite generalized eigenproblem.
+//!
+//! Provides the [`Sygv`] trait, wrapping the LAPACK `?sygv` routine for a real symmetric A and
+//! symmetric positive-definite B, and `?hegv` for the complex Hermitian case. With `itype = 1` it
+//! solves A x = λ B x by reducing the pair to a standard symmetric problem through the Cholesky
+//! factor of B. Eigenvalues are real and returned in ascending order; the eigenvectors overwrite A
+//! and are normalised so that Zᴴ B Z = I. If B is not positive definite LAPACK reports
+//! `info = n + i`, which [`generalized_symeig_raw`] maps to
+//! [`GeneralizedSymEigRawError::NotPositiveDefinite`].
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::backend;
+use super::common::AsI32;
+use super::syev::SymEigRaw;
+use crate::common::{Complex, ComplexField, Zero};
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors returned by the [`Sygv`] LAPACK wrapper.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// LAPACK returned a non-zero info code.
+    #[error("Error in sygv, exited with code {0}")]
+    LapackError(i32),
+}
+//}}}
+
+//{{{ trait: Sygv
+/// Trait for types that support the symmetric-definite generalized eigenproblem.
+#[allow(clippy::too_many_arguments)]
+pub trait Sygv: ComplexField {
+    /// Computes the eigenvalues and optionally the eigenvectors of a symmetric (Hermitian)
+    /// matrix pair (A, B) with B positive definite.
+    fn sygv(
+        itype: i32, // 1: A x = λ B x, 2: A B x = λ x, 3: B A x = λ x
+        jobz: u8,   // 'N' for eigenvalues only, 'V' for eigenvalues and eigenvectors
+        uplo: u8,   // 'U' for upper triangle, 'L' for lower triangle
+        n: i32,
+        a: &mut [Self], // On exit, contains eigenvectors if jobz = 'V'
+        lda: i32,
+        b: &mut [Self], // On exit, contains the Cholesky factor of B
+        ldb: i32,
+        w: &mut [Self::Real], // Contains eigenvalues on exit
+        work: &mut [Self],
+        lwork: i32,
+        rwork: &mut [Self::Real], // At least 3n - 2 for complex types, unused for real types
+    ) -> Result<(), Error>;
+}
+//}}}
+
+//{{{ impl: Sygv for f64
+impl Sygv for f64 {
+    #[inline]
+    fn sygv(
+        itype: i32,
+        jobz: u8,
+        uplo: u8,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        b: &mut [Self],
+        ldb: i32,
+        w: &mut [Self],
+        work: &mut [Self],
+        lwork: i32,
+        _rwork: &mut [Self],
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| {
+            backend.dsygv(itype, jobz, uplo, n, a, lda, b, ldb, w, work, lwork)
+        });
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ impl: Sygv for f32
+impl Sygv for f32 {
+    #[inline]
+    fn sygv(
+        itype: i32,
+        jobz: u8,
+        uplo: u8,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        b: &mut [Self],
+        ldb: i32,
+        w: &mut [Self],
+        work: &mut [Self],
+        lwork: i32,
+        _rwork: &mut [Self],
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| {
+            backend.ssygv(itype, jobz, uplo, n, a, lda, b, ldb, w, work, lwork)
+        });
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ impl: Sygv for Complex<f64>
+impl Sygv for Complex<f64> {
+    #[inline]
+    fn sygv(
+        itype: i32,
+        jobz: u8,
+        uplo: u8,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        b: &mut [Self],
+        ldb: i32,
+        w: &mut [f64],
+        work: &mut [Self],
+        lwork: i32,
+        rwork: &mut [f64],
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| {
+            backend.zhegv(itype, jobz, uplo, n, a, lda, b, ldb, w, work, lwork, rwork)
+        });
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ impl: Sygv for Complex<f32>
+impl Sygv for Complex<f32> {
+    #[inline]
+    fn sygv(
+        itype: i32,
+        jobz: u8,
+        uplo: u8,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        b: &mut [Self],
+        ldb: i32,
+        w: &mut [f32],
+        work: &mut [Self],
+        lwork: i32,
+        rwork: &mut [f32],
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| {
+            backend.chegv(itype, jobz, uplo, n, a, lda, b, ldb, w, work, lwork, rwork)
+        });
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ enum: GeneralizedSymEigRawError
+/// Errors returned by [`generalized_symeig_raw`].
+#[derive(Error, Debug)]
+pub enum GeneralizedSymEigRawError {
+    /// The leading minor of order `i` of B is not positive definite.
+    #[error("Matrix B is not positive definite, leading minor of order {0} is not positive")]
+    NotPositiveDefinite(i32),
+    /// LAPACK `sygv` failed to converge or rejected its arguments.
+    #[error(transparent)]
+    Sygv(#[from] Error),
+}
+//}}}
+//{{{ fun: generalized_symeig_raw
+/// Shared SYGV algorithm for A x = λ B x. Consumes the cloned data of both n×n matrices; returns
+/// the B-orthonormal eigenvectors and the ascending real eigenvalues.
+pub(crate) fn generalized_symeig_raw<T>(
+    mut a_data: Vec<T>,
+    mut b_data: Vec<T>,
+    n: usize,
+) -> Result<SymEigRaw<T>, GeneralizedSymEigRawError>
+where
+    T: Sygv + AsI32,
+{
+    let ld = n.max(1) as i32;
+    let mut eigvals = vec![T::Real::zero(); n];
+    let mut rwork = vec![T::Real::zero(); (3 * n).saturating_sub(2).max(1)];
+
+    let mut work = vec![T::zero(); 1];
+    T::sygv(
+        1,
+        b'V',
+        b'L',
+        n as i32,
+        &mut a_data,
+        ld,
+        &mut b_data,
+        ld,
+        &mut eigvals,
+        &mut work,
+        -1,
+        &mut rwork,
+    )?;
+
+    let lwork = work[0].as_i32().max(1);
+    let mut work = vec![T::zero(); lwork as usize];
+    match T::sygv(
+        1,
+        b'V',
+        b'L',
+        n as i32,
+        &mut a_data,
+        ld,
+        &mut b_data,
+        ld,
+        &mut eigvals,
+        &mut work,
+        lwork,
+        &mut rwork,
+    ) {
+        Ok(()) => {}
+        Err(Error::LapackError(info)) if info > n as i32 => {
+            return Err(GeneralizedSymEigRawError::NotPositiveDefinite(
+                info - n as i32,
+            ));
+        }
+        Err(err) => return Err(err.into()),
+    }
+
+    Ok(SymEigRaw {
+        eigvecs_data: a_data,
+        eigvals,
+    })
+}
+//}}}
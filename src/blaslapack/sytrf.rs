@@ -0,0 +1,132 @@
+//! LAPACK `?sytrf`/`?hetrf` wrapper for the symmetric-indefinite LDLᵀ factorisation.
+//!
+//! Provides the [`Sytrf`] trait, wrapping the LAPACK `?sytrf` routine for a real symmetric matrix
+//! and `?hetrf` for a complex Hermitian one. The matrix is factored as A = L D Lᵀ (A = L D Lᴴ) with
+//! Bunch–Kaufman diagonal pivoting, where L is a product of permutations and unit lower-triangular
+//! factors and D is block diagonal with 1×1 and 2×2 blocks. Unlike Cholesky it does not require A
+//! to be positive definite, so it handles the saddle-point and KKT matrices for which `potrf`
+//! fails. A workspace query (lwork = −1) is supported.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::backend;
+use crate::common::{Complex, ComplexField};
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors returned by the [`Sytrf`] LAPACK wrapper.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// LAPACK returned a non-zero info code. A positive code `i` means `D(i,i)` is exactly zero;
+    /// the factorisation is still complete.
+    #[error("Error in sytrf, exited with code {0}")]
+    LapackError(i32),
+}
+//}}}
+
+//{{{ trait: Sytrf
+/// Trait for types that support the symmetric-indefinite (Bunch–Kaufman) factorisation.
+pub trait Sytrf: ComplexField {
+    /// Computes `A = L D Lᵀ` (`A = L D Lᴴ` for complex types), storing D and the multipliers of L
+    /// in the `uplo` triangle of `a` and the block structure and interchanges in `ipiv`.
+    fn sytrf(
+        uplo: u8,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        ipiv: &mut [i32],
+        work: &mut [Self],
+        lwork: i32,
+    ) -> Result<(), Error>;
+}
+//}}}
+//{{{ impl: Sytrf for f64
+impl Sytrf for f64 {
+    #[inline]
+    fn sytrf(
+        uplo: u8,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        ipiv: &mut [i32],
+        work: &mut [Self],
+        lwork: i32,
+    ) -> Result<(), Error> {
+        let info =
+            backend::with_current(|backend| backend.dsytrf(uplo, n, a, lda, ipiv, work, lwork));
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+//{{{ impl: Sytrf for f32
+impl Sytrf for f32 {
+    #[inline]
+    fn sytrf(
+        uplo: u8,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        ipiv: &mut [i32],
+        work: &mut [Self],
+        lwork: i32,
+    ) -> Result<(), Error> {
+        let info =
+            backend::with_current(|backend| backend.ssytrf(uplo, n, a, lda, ipiv, work, lwork));
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+//{{{ impl: Sytrf for Complex<f64>
+impl Sytrf for Complex<f64> {
+    #[inline]
+    fn sytrf(
+        uplo: u8,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        ipiv: &mut [i32],
+        work: &mut [Self],
+        lwork: i32,
+    ) -> Result<(), Error> {
+        let info =
+            backend::with_current(|backend| backend.zhetrf(uplo, n, a, lda, ipiv, work, lwork));
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+//{{{ impl: Sytrf for Complex<f32>
+impl Sytrf for Complex<f32> {
+    #[inline]
+    fn sytrf(
+        uplo: u8,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        ipiv: &mut [i32],
+        work: &mut [Self],
+        lwork: i32,
+    ) -> Result<(), Error> {
+        let info =
+            backend::with_current(|backend| backend.chetrf(uplo, n, a, lda, ipiv, work, lwork));
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
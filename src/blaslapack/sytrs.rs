@@ -0,0 +1,382 @@
+//! LAPACK `?sytrs`/`?hetrs` wrapper for solving linear systems from an LDLᵀ factorisation.
+//!
+//! Provides the [`Sytrs`] trait, wrapping the LAPACK `?sytrs` routine (real symmetric) and
+//! `?hetrs` (complex Hermitian) that solve `A X = B` given the block-diagonal factor D, the
+//! multipliers of L and the pivot information produced by [`Sytrf`]. The shared raw helpers back
+//! the `Ldlt` handles of `DMatrix` and `SMatrix`: besides solving, they expand LAPACK's compact
+//! output into explicit D and L matrices, count the inertia of A from the blocks of D, and
+//! compute the determinant as the product of the block determinants.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::backend;
+use super::common::AsI32;
+use super::sytrf::Sytrf;
+use crate::common::{Complex, ComplexField, Zero};
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors returned by the [`Sytrs`] LAPACK wrapper.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// LAPACK returned a non-zero info code indicating an invalid argument.
+    #[error("Error in sytrs, exited with code {0}")]
+    LapackError(i32),
+}
+//}}}
+
+//{{{ trait: Sytrs
+/// Trait for types that support solving linear systems from a symmetric-indefinite factorisation.
+#[allow(clippy::too_many_arguments)]
+pub trait Sytrs: Copy {
+    /// Solves `A X = B` using the factorisation computed by [`Sytrf`] with the same `uplo`. On
+    /// exit `b` is overwritten by the solution X.
+    fn sytrs(
+        uplo: u8,
+        n: i32,
+        nrhs: i32,
+        a: &[Self],
+        lda: i32,
+        ipiv: &[i32],
+        b: &mut [Self],
+        ldb: i32,
+    ) -> Result<(), Error>;
+}
+//}}}
+//{{{ impl: Sytrs for f64
+impl Sytrs for f64 {
+    #[inline]
+    fn sytrs(
+        uplo: u8,
+        n: i32,
+        nrhs: i32,
+        a: &[Self],
+        lda: i32,
+        ipiv: &[i32],
+        b: &mut [Self],
+        ldb: i32,
+    ) -> Result<(), Error> {
+        let info =
+            backend::with_current(|backend| backend.dsytrs(uplo, n, nrhs, a, lda, ipiv, b, ldb));
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+//{{{ impl: Sytrs for f32
+impl Sytrs for f32 {
+    #[inline]
+    fn sytrs(
+        uplo: u8,
+        n: i32,
+        nrhs: i32,
+        a: &[Self],
+        lda: i32,
+        ipiv: &[i32],
+        b: &mut [Self],
+        ldb: i32,
+    ) -> Result<(), Error> {
+        let info =
+            backend::with_current(|backend| backend.ssytrs(uplo, n, nrhs, a, lda, ipiv, b, ldb));
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+//{{{ impl: Sytrs for Complex<f64>
+impl Sytrs for Complex<f64> {
+    #[inline]
+    fn sytrs(
+        uplo: u8,
+        n: i32,
+        nrhs: i32,
+        a: &[Self],
+        lda: i32,
+        ipiv: &[i32],
+        b: &mut [Self],
+        ldb: i32,
+    ) -> Result<(), Error> {
+        let info =
+            backend::with_current(|backend| backend.zhetrs(uplo, n, nrhs, a, lda, ipiv, b, ldb));
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+//{{{ impl: Sytrs for Complex<f32>
+impl Sytrs for Complex<f32> {
+    #[inline]
+    fn sytrs(
+        uplo: u8,
+        n: i32,
+        nrhs: i32,
+        a: &[Self],
+        lda: i32,
+        ipiv: &[i32],
+        b: &mut [Self],
+        ldb: i32,
+    ) -> Result<(), Error> {
+        let info =
+            backend::with_current(|backend| backend.chetrs(uplo, n, nrhs, a, lda, ipiv, b, ldb));
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ enum: LdltRawError
+#[derive(Error, Debug)]
+pub enum LdltRawError {
+    /// The matrix is exactly singular: the 1×1 block `D({0},{0})` (1-based) is zero.
+    #[error("matrix is singular, D({0},{0}) is exactly zero")]
+    Singular(i32),
+    #[error(transparent)]
+    Sytrf(super::sytrf::Error),
+    #[error(transparent)]
+    Sytrs(#[from] Error),
+}
+//}}}
+//{{{ fun: ldlt_factor_raw
+/// Shared SYTRF step of the LDLᵀ handles. Consumes the cloned n×n matrix data and returns D and
+/// the multipliers of L in its lower triangle, together with LAPACK's pivot information.
+///
+/// A zero 1×1 block of D is not an error here: the factorisation is complete and still yields the
+/// inertia and determinant. It is reported when solving instead.
+pub(crate) fn ldlt_factor_raw<T>(
+    mut a_data: Vec<T>,
+    n: usize,
+) -> Result<(Vec<T>, Vec<i32>), LdltRawError>
+where
+    T: Sytrf + AsI32,
+{
+    let mut ipiv = vec![0; n];
+    if n == 0 {
+        return Ok((a_data, ipiv));
+    }
+
+    let mut work = vec![T::zero(); 1];
+    T::sytrf(
+        b'L',
+        n as i32,
+        &mut a_data,
+        n as i32,
+        &mut ipiv,
+        &mut work,
+        -1,
+    )
+    .map_err(LdltRawError::Sytrf)?;
+
+    let lwork = work[0].as_i32().max(1);
+    let mut work = vec![T::zero(); lwork as usize];
+    match T::sytrf(
+        b'L',
+        n as i32,
+        &mut a_data,
+        n as i32,
+        &mut ipiv,
+        &mut work,
+        lwork,
+    ) {
+        Ok(()) => {}
+        Err(super::sytrf::Error::LapackError(info)) if info > 0 => {}
+        Err(err) => return Err(LdltRawError::Sytrf(err)),
+    }
+    Ok((a_data, ipiv))
+}
+//}}}
+//{{{ fun: ldlt_solve_raw
+/// Shared SYTRS step of the LDLᵀ handles. Overwrites the n×`nrhs` column-major `b` with the
+/// solution of `A X = B`.
+pub(crate) fn ldlt_solve_raw<T>(
+    ldl: &[T],
+    ipiv: &[i32],
+    n: usize,
+    b: &mut [T],
+    nrhs: usize,
+) -> Result<(), LdltRawError>
+where
+    T: Sytrs + ComplexField,
+{
+    if let Some(k) = (0..n).find(|&k| ipiv[k] > 0 && ldl[k + k * n] == T::zero()) {
+        return Err(LdltRawError::Singular(k as i32 + 1));
+    }
+    if n == 0 || nrhs == 0 {
+        return Ok(());
+    }
+    T::sytrs(
+        b'L',
+        n as i32,
+        nrhs as i32,
+        ldl,
+        n as i32,
+        ipiv,
+        b,
+        n as i32,
+    )?;
+    Ok(())
+}
+//}}}
+//{{{ fun: ldlt_blocks
+/// Returns the starting index and size (1 or 2) of every diagonal block of D.
+fn ldlt_blocks(
+    ipiv: &[i32],
+    n: usize,
+) -> Vec<(usize, usize)> {
+    let mut blocks = Vec::with_capacity(n);
+    let mut k = 0;
+    while k < n {
+        let s = if ipiv[k] > 0 { 1 } else { 2 };
+        blocks.push((k, s));
+        k += s;
+    }
+    blocks
+}
+//}}}
+//{{{ fun: ldlt_d_raw
+/// Expands the block-diagonal factor D into a dense n×n column-major buffer.
+pub(crate) fn ldlt_d_raw<T>(
+    ldl: &[T],
+    ipiv: &[i32],
+    n: usize,
+) -> Vec<T>
+where
+    T: ComplexField,
+{
+    let mut d = vec![T::zero(); n * n];
+    for (k, s) in ldlt_blocks(ipiv, n) {
+        d[k + k * n] = ldl[k + k * n];
+        if s == 2 {
+            let off = ldl[(k + 1) + k * n];
+            d[(k + 1) + k * n] = off;
+            d[k + (k + 1) * n] = off.conj();
+            d[(k + 1) + (k + 1) * n] = ldl[(k + 1) + (k + 1) * n];
+        }
+    }
+    d
+}
+//}}}
+//{{{ fun: ldlt_l_raw
+/// Expands the factor L into a dense n×n column-major buffer.
+///
+/// LAPACK stores L as the product `P₁ L₁ P₂ L₂ ⋯` of interchanges and unit lower-triangular
+/// block factors; the product is accumulated column by column so that `A = L D Lᵀ` holds.
+pub(crate) fn ldlt_l_raw<T>(
+    ldl: &[T],
+    ipiv: &[i32],
+    n: usize,
+) -> Vec<T>
+where
+    T: ComplexField,
+{
+    let mut l = vec![T::zero(); n * n];
+    for i in 0..n {
+        l[i + i * n] = T::one();
+    }
+    for (k, s) in ldlt_blocks(ipiv, n) {
+        // Right-multiply by the interchange P(k) of column k + s - 1 with column p.
+        let p = (ipiv[k].unsigned_abs() - 1) as usize;
+        let c = k + s - 1;
+        if p != c {
+            for i in 0..n {
+                l.swap(i + c * n, i + p * n);
+            }
+        }
+        // Right-multiply by the unit lower-triangular block factor L(k).
+        for j in k..k + s {
+            for r in k + s..n {
+                let v = ldl[r + j * n];
+                if v != T::zero() {
+                    for i in 0..n {
+                        let lir = l[i + r * n];
+                        l[i + j * n] += lir * v;
+                    }
+                }
+            }
+        }
+    }
+    l
+}
+//}}}
+//{{{ fun: ldlt_inertia_raw
+/// Counts the positive, negative and zero eigenvalues of A from the blocks of D (Sylvester's law
+/// of inertia). Only exactly zero eigenvalues of D are counted as zero.
+pub(crate) fn ldlt_inertia_raw<T>(
+    ldl: &[T],
+    ipiv: &[i32],
+    n: usize,
+) -> (usize, usize, usize)
+where
+    T: ComplexField,
+{
+    let zero = T::Real::zero();
+    let mut signs = [0usize; 3];
+    let sign = |x: T::Real| {
+        if x > zero {
+            0
+        } else if x < zero {
+            1
+        } else {
+            2
+        }
+    };
+    for (k, s) in ldlt_blocks(ipiv, n) {
+        let a = ldl[k + k * n].real_part();
+        if s == 1 {
+            signs[sign(a)] += 1;
+            continue;
+        }
+        // The eigenvalues of a Hermitian 2×2 block have product `det` and sum `trace`.
+        let b = ldl[(k + 1) + k * n].modulus();
+        let c = ldl[(k + 1) + (k + 1) * n].real_part();
+        let det = a * c - b * b;
+        let trace = a + c;
+        if det < zero {
+            signs[0] += 1;
+            signs[1] += 1;
+        } else if det > zero {
+            signs[sign(trace)] += 2;
+        } else {
+            signs[sign(trace)] += 1;
+            signs[2] += 1;
+        }
+    }
+    (signs[0], signs[1], signs[2])
+}
+//}}}
+//{{{ fun: ldlt_det_raw
+/// Determinant from the blocks of D. The interchanges are symmetric and L has unit determinant,
+/// so `det(A) = det(D)`.
+pub(crate) fn ldlt_det_raw<T>(
+    ldl: &[T],
+    ipiv: &[i32],
+    n: usize,
+) -> T
+where
+    T: ComplexField,
+{
+    let mut det = T::one();
+    for (k, s) in ldlt_blocks(ipiv, n) {
+        if s == 1 {
+            det *= ldl[k + k * n];
+        } else {
+            let b = ldl[(k + 1) + k * n];
+            det *= ldl[k + k * n] * ldl[(k + 1) + (k + 1) * n] - b * b.conj();
+        }
+    }
+    det
+}
+//}}}
@@ -0,0 +1,173 @@
+//! BLAS `?trmm` wrapper for triangular matrix–matrix multiplication.
+//!
+//! Provides the [`Trmm`] trait, wrapping the BLAS Level-3 `?trmm` routine
+//! (B ← α · op(A) · B or B ← α · B · op(A)) for a triangular matrix A. Only the selected triangle
+//! of A is read, so the product costs half of a `gemm` and ignores whatever the other triangle
+//! holds. Used by the [`MatMul`](crate::MatMul) implementations of the `Triangular` view.
+//! Implementations for `f64`, `f32`, `Complex<f64>` and `Complex<f32>` dispatch to the `?trmm` of
+//! the current [`Backend`](super::backend::Backend).
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::backend;
+use crate::common::{Complex, Diagonal, One, Side, Transpose, Triangle};
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ trait: Trmm
+/// Trait for types that support triangular matrix-matrix multiplication (TRMM).
+#[allow(clippy::too_many_arguments)]
+pub trait Trmm: Copy {
+    /// Computes `B = alpha op(A) B` (`side` left) or `B = alpha B op(A)` (`side` right) for
+    /// triangular `A`, overwriting the m×n matrix `b`.
+    fn trmm(
+        side: Side,
+        uplo: Triangle,
+        trans: Transpose,
+        diag: Diagonal,
+        m: i32,
+        n: i32,
+        alpha: Self,
+        a: &[Self],
+        lda: i32,
+        b: &mut [Self],
+        ldb: i32,
+    );
+}
+
+//}}}
+//{{{ impl: Trmm for f64
+impl Trmm for f64 {
+    #[inline]
+    fn trmm(
+        side: Side,
+        uplo: Triangle,
+        trans: Transpose,
+        diag: Diagonal,
+        m: i32,
+        n: i32,
+        alpha: Self,
+        a: &[Self],
+        lda: i32,
+        b: &mut [Self],
+        ldb: i32,
+    ) {
+        backend::with_current(|backend| {
+            backend.dtrmm(side, uplo, trans, diag, m, n, alpha, a, lda, b, ldb)
+        })
+    }
+}
+
+//}}}
+//{{{ impl: Trmm for f32
+impl Trmm for f32 {
+    #[inline]
+    fn trmm(
+        side: Side,
+        uplo: Triangle,
+        trans: Transpose,
+        diag: Diagonal,
+        m: i32,
+        n: i32,
+        alpha: Self,
+        a: &[Self],
+        lda: i32,
+        b: &mut [Self],
+        ldb: i32,
+    ) {
+        backend::with_current(|backend| {
+            backend.strmm(side, uplo, trans, diag, m, n, alpha, a, lda, b, ldb)
+        })
+    }
+}
+
+//}}}
+//{{{ impl: Trmm for Complex<f64>
+impl Trmm for Complex<f64> {
+    #[inline]
+    fn trmm(
+        side: Side,
+        uplo: Triangle,
+        trans: Transpose,
+        diag: Diagonal,
+        m: i32,
+        n: i32,
+        alpha: Self,
+        a: &[Self],
+        lda: i32,
+        b: &mut [Self],
+        ldb: i32,
+    ) {
+        backend::with_current(|backend| {
+            backend.ztrmm(side, uplo, trans, diag, m, n, alpha, a, lda, b, ldb)
+        })
+    }
+}
+
+//}}}
+//{{{ impl: Trmm for Complex<f32>
+impl Trmm for Complex<f32> {
+    #[inline]
+    fn trmm(
+        side: Side,
+        uplo: Triangle,
+        trans: Transpose,
+        diag: Diagonal,
+        m: i32,
+        n: i32,
+        alpha: Self,
+        a: &[Self],
+        lda: i32,
+        b: &mut [Self],
+        ldb: i32,
+    ) {
+        backend::with_current(|backend| {
+            backend.ctrmm(side, uplo, trans, diag, m, n, alpha, a, lda, b, ldb)
+        })
+    }
+}
+
+//}}}
+//{{{ fun: triangular_matmul_raw
+/// Shared TRMM algorithm. Multiplies the m×n matrix `b_data` by the triangular matrix `a` from the
+/// left (`side` left, `a` is m×m) or from the right (`side` right, `a` is n×n), and returns the
+/// product.
+pub(crate) fn triangular_matmul_raw<T>(
+    side: Side,
+    a: &[T],
+    uplo: Triangle,
+    diag: Diagonal,
+    mut b_data: Vec<T>,
+    m: usize,
+    n: usize,
+) -> Vec<T>
+where
+    T: Trmm + One,
+{
+    if m == 0 || n == 0 {
+        return b_data;
+    }
+    let lda = match side {
+        Side::Left => m,
+        Side::Right => n,
+    };
+    T::trmm(
+        side,
+        uplo,
+        Transpose::NoTrans,
+        diag,
+        m as i32,
+        n as i32,
+        T::one(),
+        a,
+        lda as i32,
+        &mut b_data,
+        m as i32,
+    );
+    b_data
+}
+//}}}
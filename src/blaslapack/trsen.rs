@@ -0,0 +1,235 @@
+//! LAPACK `dtrsen`/`strsen` wrapper for reordering a real Schur form.
+//!
+//! Provides the [`Trsen`] trait, wrapping the LAPACK `?trsen` routine that reorders the real Schur
+//! factorisation A = Q T Q^T so that a selected cluster of eigenvalues appears in the leading
+//! diagonal blocks of T, updating Q to match. The leading columns of the reordered Q then span the
+//! invariant subspace belonging to the selected eigenvalues. Complex-conjugate pairs are moved
+//! together: selecting either member of a pair selects both. `job` additionally requests
+//! condition number estimates for the cluster and its invariant subspace, which this crate does
+//! not use.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::backend;
+use super::common::AsI32;
+use super::gees::{self, schur_raw, Gees};
+use crate::common::{Complex, Field, One, Zero};
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors returned by the [`Trsen`] LAPACK wrapper.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// LAPACK returned a non-zero info code. A positive code means the reordering failed because
+    /// the selected and unselected eigenvalues are too close to swap reliably.
+    #[error("Error in trsen, exited with code {0}")]
+    LapackError(i32),
+}
+//}}}
+
+//{{{ trait: Trsen
+/// Trait for types that support reordering of a real Schur factorisation.
+#[allow(clippy::too_many_arguments)]
+pub trait Trsen: Copy {
+    /// Reorders the Schur form `t` and Schur vectors `q` so that the eigenvalues flagged in
+    /// `select` lead the diagonal, returning their count in `m`.
+    fn trsen(
+        job: u8,
+        compq: u8,
+        select: &[i32],
+        n: i32,
+        t: &mut [Self],
+        ldt: i32,
+        q: &mut [Self],
+        ldq: i32,
+        wr: &mut [Self],
+        wi: &mut [Self],
+        m: &mut i32,
+        s: &mut Self,
+        sep: &mut Self,
+        work: &mut [Self],
+        lwork: i32,
+        iwork: &mut [i32],
+        liwork: i32,
+    ) -> Result<(), Error>;
+}
+//}}}
+//{{{ impl: Trsen for f64
+impl Trsen for f64 {
+    #[inline]
+    fn trsen(
+        job: u8,
+        compq: u8,
+        select: &[i32],
+        n: i32,
+        t: &mut [Self],
+        ldt: i32,
+        q: &mut [Self],
+        ldq: i32,
+        wr: &mut [Self],
+        wi: &mut [Self],
+        m: &mut i32,
+        s: &mut Self,
+        sep: &mut Self,
+        work: &mut [Self],
+        lwork: i32,
+        iwork: &mut [i32],
+        liwork: i32,
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| {
+            backend.dtrsen(
+                job, compq, select, n, t, ldt, q, ldq, wr, wi, m, s, sep, work, lwork, iwork,
+                liwork,
+            )
+        });
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ impl: Trsen for f32
+impl Trsen for f32 {
+    #[inline]
+    fn trsen(
+        job: u8,
+        compq: u8,
+        select: &[i32],
+        n: i32,
+        t: &mut [Self],
+        ldt: i32,
+        q: &mut [Self],
+        ldq: i32,
+        wr: &mut [Self],
+        wi: &mut [Self],
+        m: &mut i32,
+        s: &mut Self,
+        sep: &mut Self,
+        work: &mut [Self],
+        lwork: i32,
+        iwork: &mut [i32],
+        liwork: i32,
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| {
+            backend.strsen(
+                job, compq, select, n, t, ldt, q, ldq, wr, wi, m, s, sep, work, lwork, iwork,
+                liwork,
+            )
+        });
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+
+//{{{ struct: SchurOrderedRaw
+pub(crate) struct SchurOrderedRaw<T> {
+    pub q_data: Vec<T>,
+    pub t_data: Vec<T>,
+    pub sdim: usize,
+}
+//}}}
+//{{{ enum: SchurOrderedRawError
+#[derive(Error, Debug)]
+pub enum SchurOrderedRawError {
+    #[error(transparent)]
+    Gees(#[from] gees::Error),
+    #[error(transparent)]
+    Trsen(#[from] Error),
+}
+//}}}
+//{{{ fun: schur_ordered_raw
+/// Shared GEES + TRSEN algorithm. Consumes the cloned n×n matrix data, computes its Schur form and
+/// moves the eigenvalues accepted by `select` to the top-left; returns raw Q/T buffers and the
+/// number of selected eigenvalues.
+pub(crate) fn schur_ordered_raw<T, F>(
+    a_data: Vec<T>,
+    n: usize,
+    mut select: F,
+) -> Result<SchurOrderedRaw<T>, SchurOrderedRawError>
+where
+    T: Gees + Trsen + One + Zero + Field + Default + Copy + AsI32,
+    F: FnMut(Complex<T>) -> bool,
+{
+    let mut raw = schur_raw(a_data, n, n)?;
+    if n == 0 {
+        return Ok(SchurOrderedRaw {
+            q_data: raw.q_data,
+            t_data: raw.t_data,
+            sdim: 0,
+        });
+    }
+    let flags: Vec<i32> = raw
+        .wr
+        .iter()
+        .zip(&raw.wi)
+        .map(|(&re, &im)| select(Complex::new(re, im)) as i32)
+        .collect();
+
+    let ld = n as i32;
+    let mut m = 0;
+    let mut s = T::zero();
+    let mut sep = T::zero();
+    let mut work = vec![T::zero(); 1];
+    let mut iwork = vec![0; 1];
+    T::trsen(
+        b'N',
+        b'V',
+        &flags,
+        ld,
+        &mut raw.t_data,
+        ld,
+        &mut raw.q_data,
+        ld,
+        &mut raw.wr,
+        &mut raw.wi,
+        &mut m,
+        &mut s,
+        &mut sep,
+        &mut work,
+        -1,
+        &mut iwork,
+        -1,
+    )?;
+
+    let lwork = work[0].as_i32().max(1);
+    let liwork = iwork[0].max(1);
+    let mut work = vec![T::zero(); lwork as usize];
+    let mut iwork = vec![0; liwork as usize];
+    T::trsen(
+        b'N',
+        b'V',
+        &flags,
+        ld,
+        &mut raw.t_data,
+        ld,
+        &mut raw.q_data,
+        ld,
+        &mut raw.wr,
+        &mut raw.wi,
+        &mut m,
+        &mut s,
+        &mut sep,
+        &mut work,
+        lwork,
+        &mut iwork,
+        liwork,
+    )?;
+
+    Ok(SchurOrderedRaw {
+        q_data: raw.q_data,
+        t_data: raw.t_data,
+        sdim: m as usize,
+    })
+}
+//}}}
@@ -0,0 +1,193 @@
+//! BLAS `?trsm` wrapper for triangular solves with multiple right-hand sides.
+//!
+//! Provides the [`Trsm`] trait, wrapping the BLAS Level-3 `?trsm` routine
+//! (B ← α · op(A)⁻¹ · B or B ← α · B · op(A)⁻¹) for a triangular matrix A, together with
+//! [`triangular_solve_raw`], the shared solve used by the `solve_lower_triangular` and
+//! `solve_upper_triangular` methods. Unlike LAPACK `?trtrs`, the BLAS routines do not check for a
+//! zero diagonal, so the raw helper does. Implementations for `f64`, `f32`, `Complex<f64>` and
+//! `Complex<f32>` dispatch to `?trsm` on the current [`Backend`](super::backend::Backend).
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::backend;
+use super::trsv::Trsv;
+use crate::common::{Complex, ComplexField, Diagonal, Side, Transpose, Triangle};
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ trait: Trsm
+/// Trait for types that support solving a triangular system with many right-hand sides (TRSM).
+#[allow(clippy::too_many_arguments)]
+pub trait Trsm: Copy {
+    /// Solves `op(A) X = alpha B` (`side` left) or `X op(A) = alpha B` (`side` right) for
+    /// triangular `A`, overwriting the m×n matrix `b` with the solution. No check is made for a
+    /// zero diagonal.
+    fn trsm(
+        side: Side,
+        uplo: Triangle,
+        trans: Transpose,
+        diag: Diagonal,
+        m: i32,
+        n: i32,
+        alpha: Self,
+        a: &[Self],
+        lda: i32,
+        b: &mut [Self],
+        ldb: i32,
+    );
+}
+
+//}}}
+//{{{ impl: Trsm for f64
+impl Trsm for f64 {
+    #[inline]
+    fn trsm(
+        side: Side,
+        uplo: Triangle,
+        trans: Transpose,
+        diag: Diagonal,
+        m: i32,
+        n: i32,
+        alpha: Self,
+        a: &[Self],
+        lda: i32,
+        b: &mut [Self],
+        ldb: i32,
+    ) {
+        backend::with_current(|backend| {
+            backend.dtrsm(side, uplo, trans, diag, m, n, alpha, a, lda, b, ldb)
+        })
+    }
+}
+
+//}}}
+//{{{ impl: Trsm for f32
+impl Trsm for f32 {
+    #[inline]
+    fn trsm(
+        side: Side,
+        uplo: Triangle,
+        trans: Transpose,
+        diag: Diagonal,
+        m: i32,
+        n: i32,
+        alpha: Self,
+        a: &[Self],
+        lda: i32,
+        b: &mut [Self],
+        ldb: i32,
+    ) {
+        backend::with_current(|backend| {
+            backend.strsm(side, uplo, trans, diag, m, n, alpha, a, lda, b, ldb)
+        })
+    }
+}
+
+//}}}
+//{{{ impl: Trsm for Complex<f64>
+impl Trsm for Complex<f64> {
+    #[inline]
+    fn trsm(
+        side: Side,
+        uplo: Triangle,
+        trans: Transpose,
+        diag: Diagonal,
+        m: i32,
+        n: i32,
+        alpha: Self,
+        a: &[Self],
+        lda: i32,
+        b: &mut [Self],
+        ldb: i32,
+    ) {
+        backend::with_current(|backend| {
+            backend.ztrsm(side, uplo, trans, diag, m, n, alpha, a, lda, b, ldb)
+        })
+    }
+}
+
+//}}}
+//{{{ impl: Trsm for Complex<f32>
+impl Trsm for Complex<f32> {
+    #[inline]
+    fn trsm(
+        side: Side,
+        uplo: Triangle,
+        trans: Transpose,
+        diag: Diagonal,
+        m: i32,
+        n: i32,
+        alpha: Self,
+        a: &[Self],
+        lda: i32,
+        b: &mut [Self],
+        ldb: i32,
+    ) {
+        backend::with_current(|backend| {
+            backend.ctrsm(side, uplo, trans, diag, m, n, alpha, a, lda, b, ldb)
+        })
+    }
+}
+
+//}}}
+//{{{ enum: TriangularSolveRawError
+/// Errors returned by [`triangular_solve_raw`].
+#[derive(Error, Debug)]
+pub enum TriangularSolveRawError {
+    /// The (1-based) diagonal element `i` is exactly zero, so the matrix is singular.
+    #[error("Matrix is singular, A({0},{0}) is exactly zero")]
+    Singular(i32),
+}
+//}}}
+//{{{ fun: triangular_solve_raw
+/// Shared TRSV/TRSM algorithm for `op(A) X = B` with a triangular n×n `A`. Consumes the n×nrhs
+/// right-hand side and returns the solution.
+///
+/// BLAS performs no singularity check, so a non-unit diagonal is scanned for exact zeros first.
+/// Dispatches to `trsv` for a single right-hand side and to `trsm` otherwise.
+pub(crate) fn triangular_solve_raw<T>(
+    a: &[T],
+    n: usize,
+    mut b_data: Vec<T>,
+    nrhs: usize,
+    uplo: Triangle,
+    trans: Transpose,
+    diag: Diagonal,
+) -> Result<Vec<T>, TriangularSolveRawError>
+where
+    T: Trsv + Trsm + ComplexField,
+{
+    if diag == Diagonal::NonUnit {
+        if let Some(i) = (0..n).find(|&i| a[i + i * n] == T::zero()) {
+            return Err(TriangularSolveRawError::Singular(i as i32 + 1));
+        }
+    }
+    if n == 0 || nrhs == 0 {
+        return Ok(b_data);
+    }
+
+    if nrhs == 1 {
+        T::trsv(uplo, trans, diag, n as i32, a, n as i32, &mut b_data, 1);
+    } else {
+        T::trsm(
+            Side::Left,
+            uplo,
+            trans,
+            diag,
+            n as i32,
+            nrhs as i32,
+            T::one(),
+            a,
+            n as i32,
+            &mut b_data,
+            n as i32,
+        );
+    }
+    Ok(b_data)
+}
+//}}}
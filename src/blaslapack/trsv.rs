@@ -0,0 +1,110 @@
+//! BLAS `?trsv` wrapper for triangular solves with a single right-hand side.
+//!
+//! Provides the [`Trsv`] trait, wrapping the BLAS Level-2 `?trsv` routine (x ← op(A)⁻¹ · x) for a
+//! triangular n×n matrix A. Used by [`triangular_solve_raw`](super::trsm::triangular_solve_raw)
+//! when the right-hand side is a single column, avoiding the overhead of a Level-3 `trsm` call.
+//! Implementations for `f64`, `f32`, `Complex<f64>` and `Complex<f32>` dispatch to the `?trsv` of
+//! the current [`Backend`](super::backend::Backend).
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::backend;
+use crate::common::{Complex, Diagonal, Transpose, Triangle};
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ trait: Trsv
+/// Trait for types that support solving a triangular system with one right-hand side (TRSV).
+#[allow(clippy::too_many_arguments)]
+pub trait Trsv: Copy {
+    /// Solves `op(A) x = b` for triangular `A`, overwriting `x` (which holds `b` on entry) with
+    /// the solution. No check is made for a zero diagonal.
+    fn trsv(
+        uplo: Triangle,
+        trans: Transpose,
+        diag: Diagonal,
+        n: i32,
+        a: &[Self],
+        lda: i32,
+        x: &mut [Self],
+        incx: i32,
+    );
+}
+
+//}}}
+//{{{ impl: Trsv for f64
+impl Trsv for f64 {
+    #[inline]
+    fn trsv(
+        uplo: Triangle,
+        trans: Transpose,
+        diag: Diagonal,
+        n: i32,
+        a: &[Self],
+        lda: i32,
+        x: &mut [Self],
+        incx: i32,
+    ) {
+        backend::with_current(|backend| backend.dtrsv(uplo, trans, diag, n, a, lda, x, incx))
+    }
+}
+
+//}}}
+//{{{ impl: Trsv for f32
+impl Trsv for f32 {
+    #[inline]
+    fn trsv(
+        uplo: Triangle,
+        trans: Transpose,
+        diag: Diagonal,
+        n: i32,
+        a: &[Self],
+        lda: i32,
+        x: &mut [Self],
+        incx: i32,
+    ) {
+        backend::with_current(|backend| backend.strsv(uplo, trans, diag, n, a, lda, x, incx))
+    }
+}
+
+//}}}
+//{{{ impl: Trsv for Complex<f64>
+impl Trsv for Complex<f64> {
+    #[inline]
+    fn trsv(
+        uplo: Triangle,
+        trans: Transpose,
+        diag: Diagonal,
+        n: i32,
+        a: &[Self],
+        lda: i32,
+        x: &mut [Self],
+        incx: i32,
+    ) {
+        backend::with_current(|backend| backend.ztrsv(uplo, trans, diag, n, a, lda, x, incx))
+    }
+}
+
+//}}}
+//{{{ impl: Trsv for Complex<f32>
+impl Trsv for Complex<f32> {
+    #[inline]
+    fn trsv(
+        uplo: Triangle,
+        trans: Transpose,
+        diag: Diagonal,
+        n: i32,
+        a: &[Self],
+        lda: i32,
+        x: &mut [Self],
+        incx: i32,
+    ) {
+        backend::with_current(|backend| backend.ctrsv(uplo, trans, diag, n, a, lda, x, incx))
+    }
+}
+
+//}}}
@@ -0,0 +1,148 @@
+//! LAPACK `?trtrs` wrapper for triangular linear systems.
+//!
+//! Provides the [`Trtrs`] trait, wrapping the LAPACK `?trtrs` routine that solves `op(A) X = B`
+//! for a triangular n×n matrix A by back or forward substitution. A zero on the diagonal is
+//! reported as a positive info code rather than producing infinities. Implementations for `f64`,
+//! `f32`, `Complex<f64>` and `Complex<f32>` call the Fortran LAPACK ABI via the `lapack` crate.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::backend;
+use crate::common::Complex;
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors returned by the [`Trtrs`] LAPACK wrapper.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// LAPACK returned a non-zero info code: negative for an invalid argument, positive for a
+    /// zero diagonal element at that (1-based) position.
+    #[error("Error in trtrs, exited with code {0}")]
+    LapackError(i32),
+}
+//}}}
+
+//{{{ trait: Trtrs
+/// Trait for types that support solving triangular linear systems.
+#[allow(clippy::too_many_arguments)]
+pub trait Trtrs: Copy {
+    /// Solves `op(A) X = B` for triangular A.
+    ///
+    /// `uplo` is `b'U'` or `b'L'`, `trans` is `b'N'`, `b'T'` or `b'C'`, and `diag` is `b'N'` for
+    /// a general diagonal or `b'U'` for an implicit unit diagonal. On exit `b` is overwritten by
+    /// the solution X.
+    fn trtrs(
+        uplo: u8,
+        trans: u8,
+        diag: u8,
+        n: i32,
+        nrhs: i32,
+        a: &[Self],
+        lda: i32,
+        b: &mut [Self],
+        ldb: i32,
+    ) -> Result<(), Error>;
+}
+//}}}
+//{{{ impl: Trtrs for f64
+impl Trtrs for f64 {
+    #[inline]
+    fn trtrs(
+        uplo: u8,
+        trans: u8,
+        diag: u8,
+        n: i32,
+        nrhs: i32,
+        a: &[Self],
+        lda: i32,
+        b: &mut [Self],
+        ldb: i32,
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| {
+            backend.dtrtrs(uplo, trans, diag, n, nrhs, a, lda, b, ldb)
+        });
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+//{{{ impl: Trtrs for f32
+impl Trtrs for f32 {
+    #[inline]
+    fn trtrs(
+        uplo: u8,
+        trans: u8,
+        diag: u8,
+        n: i32,
+        nrhs: i32,
+        a: &[Self],
+        lda: i32,
+        b: &mut [Self],
+        ldb: i32,
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| {
+            backend.strtrs(uplo, trans, diag, n, nrhs, a, lda, b, ldb)
+        });
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+//{{{ impl: Trtrs for Complex<f64>
+impl Trtrs for Complex<f64> {
+    #[inline]
+    fn trtrs(
+        uplo: u8,
+        trans: u8,
+        diag: u8,
+        n: i32,
+        nrhs: i32,
+        a: &[Self],
+        lda: i32,
+        b: &mut [Self],
+        ldb: i32,
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| {
+            backend.ztrtrs(uplo, trans, diag, n, nrhs, a, lda, b, ldb)
+        });
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
+//{{{ impl: Trtrs for Complex<f32>
+impl Trtrs for Complex<f32> {
+    #[inline]
+    fn trtrs(
+        uplo: u8,
+        trans: u8,
+        diag: u8,
+        n: i32,
+        nrhs: i32,
+        a: &[Self],
+        lda: i32,
+        b: &mut [Self],
+        ldb: i32,
+    ) -> Result<(), Error> {
+        let info = backend::with_current(|backend| {
+            backend.ctrtrs(uplo, trans, diag, n, nrhs, a, lda, b, ldb)
+        });
+        if info != 0 {
+            return Err(Error::LapackError(info));
+        }
+        Ok(())
+    }
+}
+//}}}
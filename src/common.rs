@@ -3,12 +3,15 @@
 //! Defines the primitive abstractions shared by all matrix types in the crate. The [`Field`]
 //! trait bounds element types to those supporting arithmetic operations. [`Zero`] and [`One`]
 //! supply additive and multiplicative identities. [`Shape`] exposes runtime matrix dimensions.
-//! [`MatrixExpr`] powers the lazy expression-template pipeline.
+//! [`MatrixExpr`] powers the lazy expression-template pipeline. [`MatrixNorms`] provides matrix
+//! norms and condition numbers for any indexable matrix type. [`ReduceOps`] reduces all elements
+//! and [`AxisReduceOps`] reduces each row or column selected by a [`Dimension`].
 //!
 //--------------------------------------------------------------------------------------------------
 
 //{{{ crate imports
-use crate::blaslapack::Getrf;
+use crate::blaslapack::{Gecon, Getrf, Getrs, SingularValues, SvdRawError};
+use crate::dmatrix::{DLuFactorError, DMatrix};
 use crate::float::Float;
 //}}}
 //{{{ std imports
@@ -19,12 +22,16 @@ use std::ops::{AddAssign, DivAssign, Index, IndexMut, MulAssign, SubAssign};
 
 //}}}
 //{{{ dep imports
+use thiserror::Error;
 //}}}
 //--------------------------------------------------------------------------------------------------
 
 //{{{ trait: Field
 /// Algebraic field over which matrix operations are defined, requiring the four arithmetic
-/// operations, their assignment variants, negation, and a total order.
+/// operations, their assignment variants, negation and equality.
+///
+/// No ordering is required so that complex numbers qualify; algorithms that compare elements
+/// additionally bound on [`PartialOrd`].
 pub trait Field:
     Sized
     + Add<Output = Self>
@@ -36,7 +43,6 @@ pub trait Field:
     + MulAssign
     + DivAssign
     + Neg<Output = Self>
-    + PartialOrd
     + PartialEq
 {
 }
@@ -80,6 +86,18 @@ macro_rules! apply_for_all_integer_types {
     };
 }
 
+//}}}
+//{{{ macro: apply_for_all_complex_types
+#[macro_export]
+#[doc(hidden)]
+macro_rules! apply_for_all_complex_types {
+    ($macro:ident) => {
+        $macro!($crate::Complex<f32>);
+
+        $macro!($crate::Complex<f64>);
+    };
+}
+
 //}}}
 //{{{ macro: impl_field
 macro_rules! impl_field {
@@ -91,6 +109,7 @@ macro_rules! impl_field {
 //}}}
 //{{{ collection: impl_field implementations
 apply_for_all_types!(impl_field);
+apply_for_all_complex_types!(impl_field);
 //}}}
 //{{{ trait: Zero
 /// Supplies the additive identity element for a type.
@@ -129,6 +148,18 @@ macro_rules! impl_zero {
 }
 
 apply_for_all_integer_types!(impl_zero);
+
+macro_rules! impl_complex_zero {
+    ($type:ty) => {
+        impl Zero for $type {
+            fn zero() -> Self {
+                Complex::new(Zero::zero(), Zero::zero())
+            }
+        }
+    };
+}
+
+apply_for_all_complex_types!(impl_complex_zero);
 //}}}
 //{{{ trait: One
 /// Supplies the multiplicative identity element for a type.
@@ -167,6 +198,18 @@ macro_rules! impl_zero {
 }
 
 apply_for_all_integer_types!(impl_zero);
+
+macro_rules! impl_complex_one {
+    ($type:ty) => {
+        impl One for $type {
+            fn one() -> Self {
+                Complex::new(One::one(), Zero::zero())
+            }
+        }
+    };
+}
+
+apply_for_all_complex_types!(impl_complex_one);
 //}}}
 //{{{ trait: Abs
 /// Provides an absolute-value operation for scalar types.
@@ -224,6 +267,7 @@ macro_rules! impl_matrix_element_display_numeric {
 }
 
 apply_for_all_types!(impl_matrix_element_display_numeric);
+apply_for_all_complex_types!(impl_matrix_element_display_numeric);
 
 impl MatrixElementDisplay for bool {
     fn fmt_matrix_element(
@@ -237,6 +281,86 @@ impl MatrixElementDisplay for bool {
 //{{{ collection: re-exports
 pub use num_complex::Complex;
 //}}}
+//{{{ trait: ComplexField
+/// Associates a floating-point element type with the real and complex scalar types of the same
+/// precision.
+///
+/// Implemented for `f32`, `f64`, `Complex<f32>` and `Complex<f64>`. Decompositions use it to name
+/// the type of their outputs generically, e.g. the eigenvalues of a Hermitian matrix are
+/// `T::Real` and those of a general matrix are `T::Complex`.
+pub trait ComplexField: Field + Zero + One + Copy + Default {
+    /// Real scalar of the same precision, `Self` for real types.
+    type Real: Float + Zero + One + Copy + Default;
+    /// Complex scalar of the same precision, `Self` for complex types.
+    type Complex: Field + Zero + One + Copy + Default;
+
+    /// Returns the complex conjugate, which is the identity for real types.
+    fn conj(self) -> Self;
+    /// Returns the modulus (absolute value).
+    fn modulus(self) -> Self::Real;
+    /// Returns the real part, which is the identity for real types.
+    fn real_part(self) -> Self::Real;
+    /// Converts a real scalar of the same precision into `Self`.
+    fn from_real(re: Self::Real) -> Self;
+}
+
+macro_rules! impl_complex_field_real {
+    ($type:ty) => {
+        impl ComplexField for $type {
+            type Real = $type;
+            type Complex = Complex<$type>;
+
+            #[inline]
+            fn conj(self) -> Self {
+                self
+            }
+
+            #[inline]
+            fn modulus(self) -> Self::Real {
+                self.abs()
+            }
+
+            #[inline]
+            fn real_part(self) -> Self::Real {
+                self
+            }
+
+            #[inline]
+            fn from_real(re: Self::Real) -> Self {
+                re
+            }
+        }
+
+        impl ComplexField for Complex<$type> {
+            type Real = $type;
+            type Complex = Complex<$type>;
+
+            #[inline]
+            fn conj(self) -> Self {
+                Complex::conj(&self)
+            }
+
+            #[inline]
+            fn modulus(self) -> Self::Real {
+                self.norm()
+            }
+
+            #[inline]
+            fn real_part(self) -> Self::Real {
+                self.re
+            }
+
+            #[inline]
+            fn from_real(re: Self::Real) -> Self {
+                Complex::new(re, 0.0)
+            }
+        }
+    };
+}
+
+impl_complex_field_real!(f32);
+impl_complex_field_real!(f64);
+//}}}
 //{{{ trait: MatrixOps
 /// Core linear-algebra operations common to all matrix types.
 pub trait MatrixOps
@@ -256,6 +380,211 @@ where
     fn trace(&self) -> Self::ScalarType;
 }
 //}}}
+//{{{ enum: MatrixNormsError
+/// Errors returned by the LAPACK-backed [`MatrixNorms`] methods.
+#[derive(Error, Debug)]
+pub enum MatrixNormsError {
+    /// The LAPACK SVD drivers failed to converge.
+    #[error("Error in the singular values, exited with error:\n{0}")]
+    SvdError(#[from] SvdRawError),
+    /// The LU factorisation or the LAPACK `gecon` estimate behind `rcond()` failed.
+    #[error("Error in rcond(), exited with error:\n{0}")]
+    RcondError(#[from] DLuFactorError),
+}
+//}}}
+//{{{ trait: MatrixNorms
+/// Matrix norms and condition numbers.
+///
+/// The elementwise norms (Frobenius, 1, ∞ and max-abs) are computed directly from the entries and
+/// are available for any [`ComplexField`] element type. The spectral 2-norm and `cond()` use the
+/// singular values from LAPACK `?gesdd`, and `rcond()` estimates the reciprocal 1-norm condition
+/// number from an LU factorisation via `?getrf` + `?gecon`; these three return a
+/// [`MatrixNormsError`] if LAPACK fails. All norms are returned as `T::Real`, so a complex matrix
+/// has a real norm.
+pub trait MatrixNorms: Shape + Index<(usize, usize), Output = Self::ScalarType> {
+    type ScalarType: ComplexField;
+
+    //{{{ fn: norm_fro
+    /// Returns the Frobenius norm, the square root of the sum of squared moduli of all entries.
+    ///
+    /// The sum is accumulated as `scale² · ssq` like LAPACK `?lassq`, so it neither overflows nor
+    /// underflows unless the norm itself does.
+    fn norm_fro(&self) -> <Self::ScalarType as ComplexField>::Real {
+        let zero = <Self::ScalarType as ComplexField>::Real::zero();
+        let one = <Self::ScalarType as ComplexField>::Real::one();
+        let mut scale = zero;
+        let mut ssq = one;
+        for j in 0..self.ncols() {
+            for i in 0..self.nrows() {
+                let v = self[(i, j)].modulus();
+                if v == zero {
+                    continue;
+                }
+                if scale < v {
+                    let r = scale / v;
+                    ssq = one + ssq * r * r;
+                    scale = v;
+                } else {
+                    let r = v / scale;
+                    ssq += r * r;
+                }
+            }
+        }
+        scale * ssq.sqrt()
+    }
+    //}}}
+    //{{{ fn: norm_one
+    /// Returns the 1-norm, the maximum absolute column sum.
+    fn norm_one(&self) -> <Self::ScalarType as ComplexField>::Real {
+        let mut out = <Self::ScalarType as ComplexField>::Real::zero();
+        for j in 0..self.ncols() {
+            let mut sum = <Self::ScalarType as ComplexField>::Real::zero();
+            for i in 0..self.nrows() {
+                sum += self[(i, j)].modulus();
+            }
+            if sum > out {
+                out = sum;
+            }
+        }
+        out
+    }
+    //}}}
+    //{{{ fn: norm_inf
+    /// Returns the ∞-norm, the maximum absolute row sum.
+    fn norm_inf(&self) -> <Self::ScalarType as ComplexField>::Real {
+        let mut out = <Self::ScalarType as ComplexField>::Real::zero();
+        for i in 0..self.nrows() {
+            let mut sum = <Self::ScalarType as ComplexField>::Real::zero();
+            for j in 0..self.ncols() {
+                sum += self[(i, j)].modulus();
+            }
+            if sum > out {
+                out = sum;
+            }
+        }
+        out
+    }
+    //}}}
+    //{{{ fn: norm_max
+    /// Returns the largest absolute value of any entry.
+    ///
+    /// This is not a submultiplicative matrix norm, but it is a cheap measure of scale.
+    fn norm_max(&self) -> <Self::ScalarType as ComplexField>::Real {
+        let mut out = <Self::ScalarType as ComplexField>::Real::zero();
+        for j in 0..self.ncols() {
+            for i in 0..self.nrows() {
+                let v = self[(i, j)].modulus();
+                if v > out {
+                    out = v;
+                }
+            }
+        }
+        out
+    }
+    //}}}
+    //{{{ fn: norm_2
+    /// Returns the spectral 2-norm, the largest singular value.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MatrixNormsError::SvdError`] if the LAPACK SVD drivers fail to converge.
+    fn norm_2(&self) -> Result<<Self::ScalarType as ComplexField>::Real, MatrixNormsError>
+    where
+        Self::ScalarType: SingularValues,
+    {
+        let s = singular_values(self)?;
+        Ok(s.first()
+            .copied()
+            .unwrap_or(<Self::ScalarType as ComplexField>::Real::zero()))
+    }
+    //}}}
+    //{{{ fn: cond
+    /// Returns the 2-norm condition number, the ratio of the largest to the smallest singular
+    /// value.
+    ///
+    /// A rank-deficient matrix has an infinite condition number, and an empty matrix, like the
+    /// identity, a condition number of `1`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MatrixNormsError::SvdError`] if the LAPACK SVD drivers fail to converge.
+    fn cond(&self) -> Result<<Self::ScalarType as ComplexField>::Real, MatrixNormsError>
+    where
+        Self::ScalarType: SingularValues,
+    {
+        let s = singular_values(self)?;
+        Ok(match (s.first(), s.last()) {
+            (Some(&max), Some(&min)) => max / min,
+            _ => <Self::ScalarType as ComplexField>::Real::one(),
+        })
+    }
+    //}}}
+    //{{{ fn: rcond
+    /// Returns an estimate of the reciprocal 1-norm condition number,
+    /// `1 / (‖A‖₁ ‖A⁻¹‖₁)`.
+    ///
+    /// The estimate needs only an LU factorisation and is much cheaper than [`cond`]; it is
+    /// [`DLuFactor::rcond`](crate::DLuFactor::rcond) of a fresh factorisation, so call that
+    /// directly when the factors are needed anyway. Values near `0` indicate an ill-conditioned
+    /// matrix; an exactly singular matrix returns `0` and, as in LAPACK `?gecon`, an empty matrix
+    /// returns `1`.
+    ///
+    /// [`cond`]: MatrixNorms::cond
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MatrixNormsError::RcondError`] if LAPACK rejects the matrix, which happens when
+    /// it contains NaN.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square.
+    fn rcond(&self) -> Result<<Self::ScalarType as ComplexField>::Real, MatrixNormsError>
+    where
+        Self::ScalarType: Getrf + Getrs + Gecon,
+    {
+        if self.nrows() != self.ncols() {
+            panic!("Condition number estimate is only defined for square matrices");
+        }
+        let a = DMatrix {
+            data: to_col_vec(self),
+            nrows: self.nrows(),
+            ncols: self.ncols(),
+        };
+        match a.lu_factor() {
+            Ok(lu) => Ok(lu.rcond()?),
+            Err(DLuFactorError::Singular) => Ok(<Self::ScalarType as ComplexField>::Real::zero()),
+            Err(err) => Err(err.into()),
+        }
+    }
+    //}}}
+}
+//{{{ fun: to_col_vec
+/// Copies the entries of an indexable matrix into a column-major buffer.
+fn to_col_vec<M>(m: &M) -> Vec<M::ScalarType>
+where
+    M: MatrixNorms,
+{
+    let mut out = Vec::with_capacity(m.nrows() * m.ncols());
+    for j in 0..m.ncols() {
+        for i in 0..m.nrows() {
+            out.push(m[(i, j)]);
+        }
+    }
+    out
+}
+//}}}
+//{{{ fun: singular_values
+/// Singular values of an indexable matrix in descending order.
+fn singular_values<M>(m: &M) -> Result<Vec<<M::ScalarType as ComplexField>::Real>, SvdRawError>
+where
+    M: MatrixNorms,
+    M::ScalarType: SingularValues,
+{
+    M::ScalarType::singular_values(to_col_vec(m), m.nrows(), m.ncols())
+}
+//}}}
+//}}}
 //{{{ trait: Shape
 /// Exposes the runtime dimensions of a two-dimensional array or matrix.
 pub trait Shape
@@ -894,14 +1223,387 @@ pub trait ReduceOps {
     }
 }
 //}}}
+//{{{ trait: AxisReduceOps
+/// Reductions along one axis of a matrix, producing one value per row or per column.
+///
+/// Every method is derived from [`ReduceOps::fold_indexed`]. Each row ([`Dimension::Rows`]) or
+/// column ([`Dimension::Cols`]) is reduced to one value, giving an `nrows`×1 column vector or a
+/// 1×`ncols` row vector; [`Dimension::All`] reduces the whole matrix to 1×1. Results are
+/// [`DMatrix`](crate::DMatrix) values for every implementor, since their shape depends on the
+/// runtime `dim`; [`SMatrix`](crate::SMatrix) additionally has `sum_rows()`, `sum_cols()` and
+/// the like, whose axis is fixed at compile time and whose results are `SMatrix<T, N, 1>` and
+/// `SMatrix<T, 1, M>`. Methods that need at least one element per row or column return `None`
+/// when the reduced axis is empty.
+pub trait AxisReduceOps: ReduceOps<Index = (usize, usize)> + Shape {
+    /// Folds each row or column into an accumulator using `f`, starting every one from `init`.
+    fn fold_along<B, F>(
+        &self,
+        dim: Dimension,
+        init: B,
+        mut f: F,
+    ) -> crate::DMatrix<B>
+    where
+        B: Copy,
+        F: FnMut(B, Self::Item) -> B,
+    {
+        self.fold_indexed_along(dim, init, |acc, _, value| f(acc, value))
+    }
+
+    /// Folds each row or column together with the position of every element within it: the
+    /// column index in a row, the row index in a column, or the column-major linear index for
+    /// [`Dimension::All`].
+    fn fold_indexed_along<B, F>(
+        &self,
+        dim: Dimension,
+        init: B,
+        mut f: F,
+    ) -> crate::DMatrix<B>
+    where
+        B: Copy,
+        F: FnMut(B, usize, Self::Item) -> B,
+    {
+        let (nrows, ncols) = self.size();
+        let (out_rows, out_cols) = match dim {
+            Dimension::Rows => (nrows, 1),
+            Dimension::Cols => (1, ncols),
+            Dimension::All => (1, 1),
+        };
+        let data = self.fold_indexed(
+            vec![init; out_rows * out_cols],
+            |mut acc, (row, col), value| {
+                let (lane, position) = match dim {
+                    Dimension::Rows => (row, col),
+                    Dimension::Cols => (col, row),
+                    Dimension::All => (0, row + col * nrows),
+                };
+                acc[lane] = f(acc[lane], position, value);
+                acc
+            },
+        );
+        crate::DMatrix {
+            data,
+            nrows: out_rows,
+            ncols: out_cols,
+        }
+    }
+
+    /// Returns the sum of each row or column.
+    fn sum_along(
+        &self,
+        dim: Dimension,
+    ) -> crate::DMatrix<Self::Item>
+    where
+        Self::Item: Add<Output = Self::Item> + Zero,
+    {
+        self.fold_along(dim, Self::Item::zero(), |acc, value| acc + value)
+    }
+
+    /// Returns the product of each row or column.
+    fn product_along(
+        &self,
+        dim: Dimension,
+    ) -> crate::DMatrix<Self::Item>
+    where
+        Self::Item: Mul<Output = Self::Item> + One,
+    {
+        self.fold_along(dim, Self::Item::one(), |acc, value| acc * value)
+    }
+
+    /// Returns the arithmetic mean of each row or column, or `None` if the reduced axis is empty.
+    /// Integer means are truncated by the integer division.
+    fn mean_along(
+        &self,
+        dim: Dimension,
+    ) -> Option<crate::DMatrix<Self::Item>>
+    where
+        Self::Item: Add<Output = Self::Item> + Div<Output = Self::Item> + One,
+    {
+        let sums = self.fold_along(dim, None, mean_step);
+        collect_lanes(sums, |(sum, count)| sum / count)
+    }
+
+    /// Returns the minimum of each row or column, or `None` if the reduced axis is empty.
+    fn min_along(
+        &self,
+        dim: Dimension,
+    ) -> Option<crate::DMatrix<Self::Item>>
+    where
+        Self::Item: PartialOrd,
+    {
+        let mins = self.fold_along(dim, None, min_step);
+        collect_lanes(mins, |value| value)
+    }
+
+    /// Returns the maximum of each row or column, or `None` if the reduced axis is empty.
+    fn max_along(
+        &self,
+        dim: Dimension,
+    ) -> Option<crate::DMatrix<Self::Item>>
+    where
+        Self::Item: PartialOrd,
+    {
+        let maxs = self.fold_along(dim, None, max_step);
+        collect_lanes(maxs, |value| value)
+    }
+
+    /// Returns the position of the minimum within each row or column, as defined by
+    /// [`fold_indexed_along`](AxisReduceOps::fold_indexed_along), or `None` if the reduced axis
+    /// is empty. Ties resolve to the first position.
+    fn argmin_along(
+        &self,
+        dim: Dimension,
+    ) -> Option<crate::DMatrix<usize>>
+    where
+        Self::Item: PartialOrd,
+    {
+        let mins = self.fold_indexed_along(dim, None, argmin_step);
+        collect_lanes(mins, |(index, _)| index)
+    }
+
+    /// Returns the position of the maximum within each row or column, as defined by
+    /// [`fold_indexed_along`](AxisReduceOps::fold_indexed_along), or `None` if the reduced axis
+    /// is empty. Ties resolve to the first position.
+    fn argmax_along(
+        &self,
+        dim: Dimension,
+    ) -> Option<crate::DMatrix<usize>>
+    where
+        Self::Item: PartialOrd,
+    {
+        let maxs = self.fold_indexed_along(dim, None, argmax_step);
+        collect_lanes(maxs, |(index, _)| index)
+    }
+}
+//}}}
+//{{{ collection: lane steps
+// Accumulator steps shared by the axis reductions. A lane starts at `None` and holds `Some` once
+// it has seen an element, so that empty lanes can be told apart.
+
+/// Adds `value` to the running `(sum, count)` of a lane.
+pub(crate) fn mean_step<T>(
+    acc: Option<(T, T)>,
+    value: T,
+) -> Option<(T, T)>
+where
+    T: Add<Output = T> + One,
+{
+    Some(match acc {
+        Some((sum, count)) => (sum + value, count + T::one()),
+        None => (value, T::one()),
+    })
+}
+
+/// Keeps the smaller of the lane minimum and `value`, preferring the earlier one on ties.
+pub(crate) fn min_step<T>(
+    acc: Option<T>,
+    value: T,
+) -> Option<T>
+where
+    T: PartialOrd,
+{
+    Some(match acc {
+        Some(current) if current <= value => current,
+        _ => value,
+    })
+}
+
+/// Keeps the larger of the lane maximum and `value`, preferring the earlier one on ties.
+pub(crate) fn max_step<T>(
+    acc: Option<T>,
+    value: T,
+) -> Option<T>
+where
+    T: PartialOrd,
+{
+    Some(match acc {
+        Some(current) if current >= value => current,
+        _ => value,
+    })
+}
+
+/// Keeps the position and value of the lane minimum, preferring the earlier one on ties.
+pub(crate) fn argmin_step<T>(
+    acc: Option<(usize, T)>,
+    index: usize,
+    value: T,
+) -> Option<(usize, T)>
+where
+    T: PartialOrd,
+{
+    Some(match acc {
+        Some((best_index, best_value)) if best_value <= value => (best_index, best_value),
+        _ => (index, value),
+    })
+}
+
+/// Keeps the position and value of the lane maximum, preferring the earlier one on ties.
+pub(crate) fn argmax_step<T>(
+    acc: Option<(usize, T)>,
+    index: usize,
+    value: T,
+) -> Option<(usize, T)>
+where
+    T: PartialOrd,
+{
+    Some(match acc {
+        Some((best_index, best_value)) if best_value >= value => (best_index, best_value),
+        _ => (index, value),
+    })
+}
+//}}}
+//{{{ fun: collect_lanes
+/// Maps the per-lane accumulators of an axis reduction through `f`, or returns `None` if any lane
+/// saw no elements.
+fn collect_lanes<B, C, F>(
+    lanes: crate::DMatrix<Option<B>>,
+    mut f: F,
+) -> Option<crate::DMatrix<C>>
+where
+    B: Copy,
+    C: Copy,
+    F: FnMut(B) -> C,
+{
+    let data = lanes
+        .data
+        .into_iter()
+        .map(|lane| lane.map(&mut f))
+        .collect::<Option<Vec<C>>>()?;
+    Some(crate::DMatrix {
+        data,
+        nrows: lanes.nrows,
+        ncols: lanes.ncols,
+    })
+}
+//}}}
 //{{{ enum: Dimension
 /// Selects which axis (or both axes) a reduction or transform operates over.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Dimension {
-    /// Operate along the row axis (i.e., reduce or transform each column).
+    /// Operate on each row independently (i.e., reduce or transform each row).
     Rows,
-    /// Operate along the column axis (i.e., reduce or transform each row).
+    /// Operate on each column independently (i.e., reduce or transform each column).
     Cols,
     /// Operate over all elements regardless of axis.
     All,
 }
 //}}}
+//{{{ enum: SymEigRange
+/// Selects the eigenpairs computed by `symeig_range()`, in terms of the ascending spectrum.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SymEigRange<R> {
+    /// Eigenpairs with 0-based indices in the closed interval `[il, iu]`, so `Index(0, k - 1)`
+    /// selects the `k` smallest eigenvalues.
+    Index(usize, usize),
+    /// Eigenpairs whose eigenvalues lie in the half-open interval `(vl, vu]`.
+    Value(R, R),
+}
+//}}}
+//{{{ enum: Triangle
+/// Selects the triangle of a square matrix that holds a triangular operand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Triangle {
+    /// The diagonal and the entries below it; the strictly upper triangle is ignored.
+    Lower,
+    /// The diagonal and the entries above it; the strictly lower triangle is ignored.
+    Upper,
+}
+//}}}
+//{{{ enum: Transpose
+/// Selects the operation applied to a matrix operand before it is used, `op(A)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Transpose {
+    /// `op(A) = A`.
+    NoTrans,
+    /// `op(A) = Aᵀ`.
+    Trans,
+    /// `op(A) = Aᴴ`, the same as [`Transpose::Trans`] for real types.
+    ConjTrans,
+}
+//}}}
+//{{{ enum: Diagonal
+/// Selects whether the diagonal of a triangular operand is read or taken to be all ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Diagonal {
+    /// The diagonal entries are read from the matrix.
+    NonUnit,
+    /// The diagonal entries are assumed to be one and are not read, as for the L factor of an LU
+    /// decomposition.
+    Unit,
+}
+//}}}
+//{{{ enum: Side
+/// Selects the side on which a triangular operand multiplies, or is solved against, another matrix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    /// `op(A)` appears on the left, as in `op(A) X = B`.
+    Left,
+    /// `op(A)` appears on the right, as in `X op(A) = B`.
+    Right,
+}
+//}}}
+//{{{ enum: Equilibration
+/// Reports the scaling applied to a coefficient matrix before it was factored, `diag(r) A diag(c)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Equilibration {
+    /// No scaling was applied.
+    None,
+    /// Rows were scaled by `r`.
+    Row,
+    /// Columns were scaled by `c`.
+    Column,
+    /// Rows were scaled by `r` and columns by `c`.
+    Both,
+}
+//}}}
+//{{{ struct: SolveExpertOptions
+/// Options for `solve_expert()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SolveExpertOptions {
+    /// Whether to scale rows and columns of A before factoring it, when doing so improves its
+    /// conditioning. Defaults to `true`.
+    pub equilibrate: bool,
+    /// Whether to solve `A X = B`, `Aᵀ X = B` or `Aᴴ X = B`. Defaults to
+    /// [`Transpose::NoTrans`].
+    pub transpose: Transpose,
+    /// Whether to improve the solution by iterative refinement, which also produces the forward
+    /// and backward error bounds. Defaults to `true`. When `false`, A is factored as given and
+    /// `equilibrate` is ignored; the condition estimate and the singular flag are still reported
+    /// but the error bounds are not.
+    pub refine: bool,
+}
+
+impl Default for SolveExpertOptions {
+    fn default() -> Self {
+        Self {
+            equilibrate: true,
+            transpose: Transpose::NoTrans,
+            refine: true,
+        }
+    }
+}
+//}}}
+//{{{ enum: RankTolerance
+/// Selects the cutoff below which singular values are treated as zero by `rank()`,
+/// `null_space()` and `orth()`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum RankTolerance<R> {
+    /// Singular values no larger than `max(n, m) · ε · σ_max` are zero, where ε is the machine
+    /// epsilon. This is the default, and matches NumPy and SciPy.
+    #[default]
+    Epsilon,
+    /// Singular values no larger than the given absolute value are zero.
+    Absolute(R),
+    /// Singular values no larger than the given multiple of `σ_max` are zero.
+    Relative(R),
+}
+//}}}
+//{{{ enum: ProcrustesMode
+/// Selects the set of matrices searched by `procrustes()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProcrustesMode {
+    /// Any orthogonal matrix, so the result may be a reflection with determinant −1.
+    Orthogonal,
+    /// Proper rotations only, with determinant +1, as in the Kabsch algorithm.
+    Rotation,
+}
+//}}}
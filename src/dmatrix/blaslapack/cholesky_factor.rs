@@ -0,0 +1,169 @@
+//! Reusable Cholesky factorisation of a [`DMatrix`] via LAPACK `dpotrf`/`spotrf` and
+//! `dpotrs`/`spotrs`.
+//!
+//! Provides the `cholesky_factor()` method on symmetric positive-definite [`DMatrix<T>`]
+//! instances, returning a [`CholeskyFactor`] handle that keeps the lower-triangular factor L in
+//! LAPACK's in-place layout. The handle solves any number of right-hand sides against the stored
+//! factor with `potrs`. Input that is not positive definite is reported through
+//! [`Error::NotPositiveDefinite`].
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use crate::blaslapack::{
+    cholesky_det_raw, cholesky_factor_raw, cholesky_logdet_raw, cholesky_solve_raw,
+    CholeskyFactorRawError, Potrf, Potrs,
+};
+use crate::common::{ComplexField, Field, One, Zero};
+use crate::dmatrix::DMatrix;
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors that can occur when computing or using a Cholesky factorisation.
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("Error in cholesky_factor(), matrix is not positive definite")]
+    /// The matrix is not positive definite, so no Cholesky factor exists.
+    NotPositiveDefinite,
+    #[error("Error in cholesky_factor(), exited with error:\n{0}")]
+    /// LAPACK `potrf` or `potrs` reported a failure.
+    PotrsError(CholeskyFactorRawError),
+}
+
+impl From<CholeskyFactorRawError> for Error {
+    fn from(err: CholeskyFactorRawError) -> Self {
+        match err {
+            CholeskyFactorRawError::NotPositiveDefinite(_) => Error::NotPositiveDefinite,
+            err => Error::PotrsError(err),
+        }
+    }
+}
+//}}}
+//{{{ struct: CholeskyFactor
+/// Cholesky factorisation `A = L Lᵀ` of a symmetric positive-definite matrix.
+#[derive(Debug, Clone)]
+pub struct CholeskyFactor<T>
+where
+    T: Field + Copy,
+{
+    /// L on and below the diagonal; the strict upper triangle is not referenced.
+    pub(crate) l: DMatrix<T>,
+}
+//}}}
+//{{{ impl: CholeskyFactor<T>
+#[allow(private_bounds)]
+impl<T> CholeskyFactor<T>
+where
+    T: Potrs + Field + Zero + One + Copy,
+{
+    /// Returns the factor in LAPACK's layout: L on and below the diagonal, with the strict upper
+    /// triangle holding the corresponding entries of the original matrix.
+    pub fn packed(&self) -> &DMatrix<T> {
+        &self.l
+    }
+
+    /// Solves `A X = B` for `X` using the stored factor.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::PotrsError`] if the LAPACK `potrs` routine fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `b` does not have as many rows as `A`.
+    pub fn solve(
+        &self,
+        b: &DMatrix<T>,
+    ) -> Result<DMatrix<T>, Error> {
+        let mut x = b.clone();
+        self.solve_in_place(&mut x)?;
+        Ok(x)
+    }
+
+    /// Solves `A X = B` using the stored factor, overwriting `b` with `X`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::PotrsError`] if the LAPACK `potrs` routine fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `b` does not have as many rows as `A`.
+    pub fn solve_in_place(
+        &self,
+        b: &mut DMatrix<T>,
+    ) -> Result<(), Error> {
+        let n = self.l.nrows;
+        assert_eq!(
+            b.nrows, n,
+            "Right-hand side must have as many rows as the matrix"
+        );
+        cholesky_solve_raw(&self.l.data, n, &mut b.data, b.ncols)?;
+        Ok(())
+    }
+
+    /// Returns the determinant of `A`, the squared product of the diagonal of L.
+    pub fn det(&self) -> T {
+        cholesky_det_raw(&self.l.data, self.l.nrows)
+    }
+
+    /// Returns the inverse of `A` by solving against the identity.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::PotrsError`] if the LAPACK `potrs` routine fails.
+    pub fn inverse(&self) -> Result<DMatrix<T>, Error> {
+        let mut inv = DMatrix::<T>::identity(self.l.nrows, self.l.nrows);
+        self.solve_in_place(&mut inv)?;
+        Ok(inv)
+    }
+}
+//}}}
+//{{{ impl: CholeskyFactor<T> (log-determinant)
+#[allow(private_bounds)]
+impl<T> CholeskyFactor<T>
+where
+    T: Potrs + ComplexField,
+{
+    /// Returns the natural log of the determinant of `A`, twice the sum of the logs of the
+    /// diagonal of L.
+    ///
+    /// The determinant of a positive definite matrix is positive, so no sign is needed.
+    pub fn logdet(&self) -> T::Real {
+        cholesky_logdet_raw(&self.l.data, self.l.nrows)
+    }
+}
+//}}}
+//{{{ impl DMatrix<T>
+#[allow(private_bounds)]
+impl<T> DMatrix<T>
+where
+    T: Potrf + Potrs + Field + Zero + One + Copy,
+{
+    /// Computes a Cholesky factorisation that can be reused for many solves.
+    ///
+    /// Only the lower triangle of `self` is referenced. `self` must be square.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NotPositiveDefinite`] if the matrix is not positive definite, or
+    /// [`Error::PotrsError`] if the LAPACK `potrf` routine fails for any other reason.
+    pub fn cholesky_factor(&self) -> Result<CholeskyFactor<T>, Error> {
+        let n = self.nrows;
+        if n != self.ncols {
+            panic!("Matrix must be square for a Cholesky factorisation");
+        }
+        let l = cholesky_factor_raw(self.data.clone(), n)?;
+        Ok(CholeskyFactor {
+            l: DMatrix {
+                data: l,
+                nrows: n,
+                ncols: n,
+            },
+        })
+    }
+}
+//}}}
@@ -1,16 +1,21 @@
-//! General (non-symmetric) eigendecomposition of a [`DMatrix`] via LAPACK `dgeev`/`sgeev`.
+//! General (non-symmetric) eigendecomposition of a [`DMatrix`] via LAPACK `?geev`.
 //!
 //! Provides the `eig()` method on [`DMatrix<T>`], computing all eigenvalues and both the left
-//! and right eigenvectors of a general square matrix. The computation is delegated to the
-//! `Geev` LAPACK driver. Eigenvalues are returned as complex numbers even when the input is
+//! and right eigenvectors of a general square real or complex matrix. The computation is delegated
+//! to the `Geev` LAPACK driver. Eigenvalues are returned as complex numbers even when the input is
 //! real-valued; eigenvector matrices are stored column-major in the `Return<T>` struct. LAPACK
 //! errors propagate as a typed `Error`.
+//!
+//! For real input the eigenvector matrices use the packed `geev` layout, where a complex-conjugate
+//! pair occupies two real columns. `Return::left_eigvec`, `Return::right_eigvec` and the
+//! `complex_*_eigvecs` methods unpack them. `eig_right()` and `eig_left()` compute only one side
+//! and return complex eigenvectors directly, and `eigvals()` skips the eigenvectors entirely.
 //--------------------------------------------------------------------------------------------------
 
 //{{{ crate imports
 use crate::blaslapack::AsI32;
 use crate::blaslapack::{eig_raw, EigRawError, Geev};
-use crate::common::{Complex, Field, One, Zero};
+use crate::common::ComplexField;
 use crate::dmatrix::DMatrix;
 //}}}
 //{{{ dep imports
@@ -40,14 +45,103 @@ pub enum Error {
 #[derive(Debug)]
 pub struct Return<T>
 where
-    T: Field + Default + Copy,
+    T: ComplexField,
 {
     /// Matrix whose columns are the left eigenvectors of A.
     pub left_eigvecs: DMatrix<T>,
     /// Matrix whose columns are the right eigenvectors of A.
     pub right_eigvecs: DMatrix<T>,
-    /// Eigenvalues as complex numbers (real part from `wr`, imaginary part from `wi`).
-    pub eigvals: Vec<Complex<T>>,
+    /// Eigenvalues as complex numbers of the same precision as `T`.
+    pub eigvals: Vec<T::Complex>,
+}
+//}}}
+//{{{ impl: Return<T>
+#[allow(private_bounds)]
+impl<T> Return<T>
+where
+    T: Geev,
+{
+    /// Returns the `i`-th left eigenvector as complex values, pairing the packed real columns of
+    /// a complex-conjugate eigenvalue pair.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is not less than the matrix order.
+    pub fn left_eigvec(
+        &self,
+        i: usize,
+    ) -> Vec<T::Complex> {
+        eigvec_column(&self.left_eigvecs, &self.eigvals, i)
+    }
+
+    /// Returns the `i`-th right eigenvector as complex values, pairing the packed real columns of
+    /// a complex-conjugate eigenvalue pair.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is not less than the matrix order.
+    pub fn right_eigvec(
+        &self,
+        i: usize,
+    ) -> Vec<T::Complex> {
+        eigvec_column(&self.right_eigvecs, &self.eigvals, i)
+    }
+
+    /// Returns all left eigenvectors as the columns of a complex matrix.
+    pub fn complex_left_eigvecs(&self) -> DMatrix<T::Complex> {
+        unpack(&self.left_eigvecs, &self.eigvals)
+    }
+
+    /// Returns all right eigenvectors as the columns of a complex matrix.
+    pub fn complex_right_eigvecs(&self) -> DMatrix<T::Complex> {
+        unpack(&self.right_eigvecs, &self.eigvals)
+    }
+}
+//}}}
+//{{{ struct: EigVecsReturn
+/// Eigenvalues together with one side (left or right) of complex eigenvectors.
+///
+/// Returned by [`DMatrix::eig_right`] and [`DMatrix::eig_left`]. Column `i` of `eigvecs` belongs to
+/// `eigvals[i]`.
+#[derive(Debug)]
+pub struct EigVecsReturn<T>
+where
+    T: ComplexField,
+{
+    /// Matrix whose columns are the complex eigenvectors.
+    pub eigvecs: DMatrix<T::Complex>,
+    /// Eigenvalues as complex numbers of the same precision as `T`.
+    pub eigvals: Vec<T::Complex>,
+}
+//}}}
+//{{{ fun: unpack
+fn unpack<T>(
+    v: &DMatrix<T>,
+    eigvals: &[T::Complex],
+) -> DMatrix<T::Complex>
+where
+    T: Geev,
+{
+    let n = v.nrows;
+    DMatrix {
+        data: T::unpack_eigvecs(&v.data, eigvals, n),
+        nrows: n,
+        ncols: n,
+    }
+}
+//}}}
+//{{{ fun: eigvec_column
+fn eigvec_column<T>(
+    v: &DMatrix<T>,
+    eigvals: &[T::Complex],
+    i: usize,
+) -> Vec<T::Complex>
+where
+    T: Geev,
+{
+    let n = v.nrows;
+    assert!(i < n, "eigenvector index {i} out of range for order {n}");
+    T::unpack_eigvec(&v.data, eigvals, n, i)
 }
 //}}}
 
@@ -55,7 +149,7 @@ where
 #[allow(private_bounds)]
 impl<T> DMatrix<T>
 where
-    T: One + Zero + Geev + Field + Default + Copy + AsI32,
+    T: Geev + AsI32,
 {
     /// Computes the eigendecomposition of a general square matrix.
     ///
@@ -67,7 +161,7 @@ where
     /// Returns [`Error::GeevError`] if the LAPACK `geev` routine fails.
     pub fn eig(&self) -> Result<Return<T>, Error> {
         let n = self.nrows;
-        let raw = eig_raw(self.data.clone(), n)?;
+        let raw = eig_raw(self.data.clone(), n, true, true)?;
         Ok(Return {
             left_eigvecs: DMatrix {
                 data: raw.vl,
@@ -82,5 +176,57 @@ where
             eigvals: raw.eigvals,
         })
     }
+
+    /// Computes the eigenvalues and right eigenvectors of a general square matrix.
+    ///
+    /// Left eigenvectors are not formed. The returned eigenvectors are complex, with
+    /// complex-conjugate pairs already unpacked, and satisfy `A v = λ v`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::GeevError`] if the LAPACK `geev` routine fails.
+    pub fn eig_right(&self) -> Result<EigVecsReturn<T>, Error> {
+        let n = self.nrows;
+        let raw = eig_raw(self.data.clone(), n, false, true)?;
+        Ok(EigVecsReturn {
+            eigvecs: DMatrix {
+                data: T::unpack_eigvecs(&raw.vr, &raw.eigvals, n),
+                nrows: n,
+                ncols: n,
+            },
+            eigvals: raw.eigvals,
+        })
+    }
+
+    /// Computes the eigenvalues and left eigenvectors of a general square matrix.
+    ///
+    /// Right eigenvectors are not formed. The returned eigenvectors are complex, with
+    /// complex-conjugate pairs already unpacked, and satisfy `u^H A = λ u^H`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::GeevError`] if the LAPACK `geev` routine fails.
+    pub fn eig_left(&self) -> Result<EigVecsReturn<T>, Error> {
+        let n = self.nrows;
+        let raw = eig_raw(self.data.clone(), n, true, false)?;
+        Ok(EigVecsReturn {
+            eigvecs: DMatrix {
+                data: T::unpack_eigvecs(&raw.vl, &raw.eigvals, n),
+                nrows: n,
+                ncols: n,
+            },
+            eigvals: raw.eigvals,
+        })
+    }
+
+    /// Computes the eigenvalues of a general square matrix without forming any eigenvectors.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::GeevError`] if the LAPACK `geev` routine fails.
+    pub fn eigvals(&self) -> Result<Vec<T::Complex>, Error> {
+        let raw = eig_raw(self.data.clone(), self.nrows, false, false)?;
+        Ok(raw.eigvals)
+    }
 }
 //}}}
@@ -0,0 +1,103 @@
+//! Generalized eigendecomposition of a [`DMatrix`] pair via LAPACK `?ggev`.
+//!
+//! Provides the `generalized_eig()` method on [`DMatrix<T>`], solving A x = λ B x for a general
+//! square pair (A, B) with the QZ algorithm. Each eigenvalue is returned as a pair (α, β) with
+//! λ = α/β, so that a singular B shows up as β = 0 (an infinite eigenvalue) rather than an
+//! overflow; `Return::eigvals` performs the division. The right generalized eigenvectors are
+//! returned as complex columns, with conjugate pairs of real input already unpacked.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use crate::blaslapack::{generalized_eig_raw, AsI32, GeneralizedEigRawError, Ggev};
+use crate::common::ComplexField;
+use crate::dmatrix::DMatrix;
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors that can occur during generalized eigendecomposition.
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("Error in generalized_eig(), exited with error:\n{0}")]
+    /// LAPACK `ggev` failed to compute the generalized eigenvalues or eigenvectors.
+    GgevError(#[from] GeneralizedEigRawError),
+}
+//}}}
+
+//{{{ struct: Return
+/// Represents the generalized eigendecomposition of a square matrix pair (A, B).
+///
+/// Column `i` of `eigvecs` satisfies `β_i A x = α_i B x`.
+#[derive(Debug)]
+pub struct Return<T>
+where
+    T: ComplexField,
+{
+    /// Matrix whose columns are the right generalized eigenvectors.
+    pub eigvecs: DMatrix<T::Complex>,
+    /// Numerators α of the generalized eigenvalues.
+    pub alpha: Vec<T::Complex>,
+    /// Denominators β of the generalized eigenvalues, real-valued for real input.
+    pub beta: Vec<T::Complex>,
+}
+//}}}
+//{{{ impl: Return<T>
+impl<T> Return<T>
+where
+    T: ComplexField,
+{
+    /// Returns the generalized eigenvalues `α / β`.
+    ///
+    /// An eigenvalue with `β = 0` is infinite and comes out non-finite.
+    pub fn eigvals(&self) -> Vec<T::Complex> {
+        self.alpha
+            .iter()
+            .zip(self.beta.iter())
+            .map(|(&a, &b)| a / b)
+            .collect()
+    }
+}
+//}}}
+
+//{{{ impl DMatrix<T>
+#[allow(private_bounds)]
+impl<T> DMatrix<T>
+where
+    T: Ggev + AsI32,
+{
+    /// Computes the generalized eigendecomposition `A x = λ B x` of the pair (`self`, `b`).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::GgevError`] if the LAPACK `ggev` routine fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is not square or `b` does not have the same shape.
+    pub fn generalized_eig(
+        &self,
+        b: &DMatrix<T>,
+    ) -> Result<Return<T>, Error> {
+        let n = self.nrows;
+        if n != self.ncols {
+            panic!("Matrix must be square for eigenvalue decomposition");
+        }
+        if b.nrows != n || b.ncols != n {
+            panic!("Matrix B must have the same shape as A");
+        }
+        let raw = generalized_eig_raw(self.data.clone(), b.data.clone(), n)?;
+        Ok(Return {
+            eigvecs: DMatrix {
+                data: raw.eigvecs,
+                nrows: n,
+                ncols: n,
+            },
+            alpha: raw.alpha,
+            beta: raw.beta,
+        })
+    }
+}
+//}}}
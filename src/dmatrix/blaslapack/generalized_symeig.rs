@@ -0,0 +1,105 @@
+//! Symmetric-definite generalized eigendecomposition of a [`DMatrix`] pair via LAPACK
+//! `?sygv`/`?hegv`.
+//!
+//! Provides the `generalized_symeig()` method on [`DMatrix<T>`], solving A x = λ B x for a real
+//! symmetric (complex Hermitian) A and a positive-definite B, as arises with stiffness and mass
+//! matrices. The pair is reduced to a standard symmetric problem through the Cholesky factor of
+//! B, so the eigenvalues are real and returned in ascending order, and the eigenvectors are
+//! B-orthonormal (Xᴴ B X = I). A B that is not positive definite is reported through
+//! [`Error::NotPositiveDefinite`].
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use crate::blaslapack::{generalized_symeig_raw, AsI32, GeneralizedSymEigRawError, Sygv};
+use crate::common::ComplexField;
+use crate::dmatrix::DMatrix;
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors that can occur during symmetric-definite generalized eigendecomposition.
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("Error in generalized_symeig(), matrix B is not positive definite")]
+    /// The matrix B is not positive definite.
+    NotPositiveDefinite,
+    #[error("Error in generalized_symeig(), exited with error:\n{0}")]
+    /// LAPACK `sygv` failed to compute eigenvalues or eigenvectors.
+    SygvError(GeneralizedSymEigRawError),
+}
+
+impl From<GeneralizedSymEigRawError> for Error {
+    fn from(err: GeneralizedSymEigRawError) -> Self {
+        match err {
+            GeneralizedSymEigRawError::NotPositiveDefinite(_) => Error::NotPositiveDefinite,
+            err => Error::SygvError(err),
+        }
+    }
+}
+//}}}
+
+//{{{ struct: Return
+/// Represents the generalized eigendecomposition of a symmetric-definite matrix pair (A, B).
+///
+/// The eigenvalues are real, and the eigenvectors are normalised against B, so that
+/// `X^H A X = D` and `X^H B X = I`, where `X` is the matrix of eigenvectors and `D` is the
+/// diagonal matrix of eigenvalues.
+#[derive(Debug)]
+pub struct Return<T>
+where
+    T: ComplexField,
+{
+    /// Matrix of eigenvectors (columns are the eigenvectors)
+    pub eigvecs: DMatrix<T>,
+
+    /// Real eigenvalues
+    pub eigvals: Vec<T::Real>,
+}
+//}}}
+
+//{{{ impl DMatrix<T>
+#[allow(private_bounds)]
+impl<T> DMatrix<T>
+where
+    T: Sygv + AsI32,
+{
+    /// Computes the generalized eigendecomposition `A x = λ B x` of a symmetric matrix `self`
+    /// and a symmetric positive-definite matrix `b`.
+    ///
+    /// Only the lower triangles of `self` and `b` are referenced. The eigenvalues are sorted in
+    /// ascending order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NotPositiveDefinite`] if `b` is not positive definite, or
+    /// [`Error::SygvError`] if the LAPACK routine fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is not square or `b` does not have the same shape.
+    pub fn generalized_symeig(
+        &self,
+        b: &DMatrix<T>,
+    ) -> Result<Return<T>, Error> {
+        let n = self.nrows;
+        if n != self.ncols {
+            panic!("Matrix must be square for eigenvalue decomposition");
+        }
+        if b.nrows != n || b.ncols != n {
+            panic!("Matrix B must have the same shape as A");
+        }
+        let raw = generalized_symeig_raw(self.data.clone(), b.data.clone(), n)?;
+        Ok(Return {
+            eigvecs: DMatrix {
+                data: raw.eigvecs_data,
+                nrows: n,
+                ncols: n,
+            },
+            eigvals: raw.eigvals,
+        })
+    }
+}
+//}}}
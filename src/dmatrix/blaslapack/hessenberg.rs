@@ -0,0 +1,80 @@
+//! Hessenberg reduction of a [`DMatrix`] via LAPACK `?gehrd` and `?orghr`/`?unghr`.
+//!
+//! Provides the `hessenberg()` method on square [`DMatrix<T>`] instances, computing the similarity
+//! transformation A = Q H Q^H where Q is orthogonal (unitary) and H is upper Hessenberg, i.e. zero
+//! below the first subdiagonal. `Gehrd` performs the reduction and `Orghr` expands the Householder
+//! reflectors into Q. Results are returned in a `Return<T>` struct containing H and Q; LAPACK
+//! errors propagate as a typed `Error`.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use crate::blaslapack::{hessenberg_raw, AsI32, Gehrd, HessenbergRawError, Orghr};
+use crate::common::{Field, Zero};
+use crate::dmatrix::DMatrix;
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors that can occur during Hessenberg reduction.
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("Error in hessenberg(), exited with error:\n{0}")]
+    /// LAPACK `gehrd` or `orghr` reported a failure.
+    GehrdError(#[from] HessenbergRawError),
+}
+//}}}
+
+//{{{ struct: Return
+/// Represents the Hessenberg reduction of a square matrix.
+///
+/// The reduction satisfies `A = Q H Q^H`, where `Q` is orthogonal (unitary) and `H` is upper
+/// Hessenberg.
+pub struct Return<T>
+where
+    T: Field + Copy,
+{
+    /// Upper Hessenberg matrix H.
+    pub h: DMatrix<T>,
+    /// Orthogonal (unitary) matrix Q.
+    pub q: DMatrix<T>,
+}
+//}}}
+
+//{{{ impl DMatrix<T>
+#[allow(private_bounds)]
+impl<T> DMatrix<T>
+where
+    T: Zero + Gehrd + Orghr + Field + Copy + AsI32,
+{
+    /// Reduces the matrix to upper Hessenberg form.
+    ///
+    /// Factors `self` into `H` and `Q` such that `A = Q H Q^H`, where `H` is zero below the first
+    /// subdiagonal. For Hermitian input `H` is tridiagonal. `self` must be square.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::GehrdError`] if the LAPACK `gehrd` or `orghr` routine fails.
+    pub fn hessenberg(&self) -> Result<Return<T>, Error> {
+        let n = self.nrows;
+        if n != self.ncols {
+            panic!("Matrix must be square for hessenberg()");
+        }
+        let raw = hessenberg_raw(self.data.clone(), n)?;
+        Ok(Return {
+            h: DMatrix {
+                data: raw.h_data,
+                nrows: n,
+                ncols: n,
+            },
+            q: DMatrix {
+                data: raw.q_data,
+                nrows: n,
+                ncols: n,
+            },
+        })
+    }
+}
+//}}}
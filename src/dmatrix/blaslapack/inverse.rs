@@ -0,0 +1,67 @@
+//! Matrix inverse of a [`DMatrix`] via LAPACK `dgetrf`/`sgetrf` and `dgetri`/`sgetri`.
+//!
+//! Provides the `inverse()` method on square [`DMatrix<T>`] instances. The matrix is first
+//! factored by `Getrf` into P·L·U, after which `Getri` overwrites the packed factors with the
+//! inverse. Exactly singular input, detected as a zero pivot in U, is reported through the typed
+//! [`Error::Singular`] variant; other LAPACK failures propagate as [`Error::GetriError`].
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use crate::blaslapack::{inverse_raw, AsI32, Getrf, Getri, InverseRawError};
+use crate::common::{Field, Zero};
+use crate::dmatrix::DMatrix;
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors that can occur when inverting a matrix.
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("Error in inverse(), matrix is singular")]
+    /// The matrix is exactly singular, so no inverse exists.
+    Singular,
+    #[error("Error in inverse(), exited with error:\n{0}")]
+    /// LAPACK `getrf` or `getri` reported a failure.
+    GetriError(InverseRawError),
+}
+
+impl From<InverseRawError> for Error {
+    fn from(err: InverseRawError) -> Self {
+        match err {
+            InverseRawError::Singular(_) => Error::Singular,
+            err => Error::GetriError(err),
+        }
+    }
+}
+//}}}
+//{{{ impl DMatrix<T>
+#[allow(private_bounds)]
+impl<T> DMatrix<T>
+where
+    T: Zero + Getrf + Getri + Field + Copy + AsI32,
+{
+    /// Computes the inverse of the matrix.
+    ///
+    /// Uses an LU factorisation with partial pivoting. `self` must be square.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Singular`] if the matrix is exactly singular, or [`Error::GetriError`] if
+    /// the LAPACK routines fail for any other reason.
+    pub fn inverse(&self) -> Result<DMatrix<T>, Error> {
+        let n = self.nrows;
+        if n != self.ncols {
+            panic!("Matrix must be square for inversion");
+        }
+        let data = inverse_raw(self.data.clone(), n)?;
+        Ok(DMatrix {
+            data,
+            nrows: n,
+            ncols: n,
+        })
+    }
+}
+//}}}
@@ -0,0 +1,207 @@
+//! Symmetric-indefinite LDLᵀ factorisation of a [`DMatrix`] via LAPACK `?sytrf` and `?sytrs`.
+//!
+//! Provides the `ldlt()` method on square symmetric (complex: Hermitian) [`DMatrix<T>`] instances,
+//! returning an [`Ldlt`] handle for the Bunch–Kaufman factorisation A = L D Lᵀ. Unlike
+//! `cholesky()` it does not require A to be positive definite, so it applies to saddle-point and
+//! KKT matrices. The handle keeps LAPACK's compact output and exposes the block-diagonal D, the
+//! factor L and the pivot information, solves any number of right-hand sides with `sytrs`, and
+//! reads the inertia and determinant of A directly off D.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use crate::blaslapack::{
+    ldlt_d_raw, ldlt_det_raw, ldlt_factor_raw, ldlt_inertia_raw, ldlt_l_raw, ldlt_solve_raw, AsI32,
+    LdltRawError, Sytrf, Sytrs,
+};
+use crate::common::ComplexField;
+use crate::dmatrix::DMatrix;
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors that can occur when computing or using an LDLᵀ factorisation.
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("Error in ldlt(), matrix is singular")]
+    /// D has an exactly zero 1×1 block, so the factorisation cannot be used to solve.
+    Singular,
+    #[error("Error in ldlt(), exited with error:\n{0}")]
+    /// LAPACK `sytrf` or `sytrs` reported a failure.
+    SytrsError(LdltRawError),
+}
+
+impl From<LdltRawError> for Error {
+    fn from(err: LdltRawError) -> Self {
+        match err {
+            LdltRawError::Singular(_) => Error::Singular,
+            err => Error::SytrsError(err),
+        }
+    }
+}
+//}}}
+//{{{ struct: Ldlt
+/// Bunch–Kaufman factorisation `A = L D Lᵀ` of a symmetric matrix, stored in LAPACK's compact
+/// form.
+#[derive(Debug, Clone)]
+pub struct Ldlt<T>
+where
+    T: ComplexField,
+{
+    /// D on the block diagonal and the multipliers of L below it.
+    pub(crate) ldl: DMatrix<T>,
+    /// 1-based pivot information: a positive entry marks a 1×1 block and its interchange, a pair
+    /// of equal negative entries marks a 2×2 block.
+    pub(crate) ipiv: Vec<i32>,
+}
+//}}}
+//{{{ impl: Ldlt<T>
+#[allow(private_bounds)]
+impl<T> Ldlt<T>
+where
+    T: Sytrs + ComplexField,
+{
+    /// Returns the packed factors: D on the block diagonal and the multipliers of L below it.
+    pub fn packed(&self) -> &DMatrix<T> {
+        &self.ldl
+    }
+
+    /// Returns LAPACK's 1-based pivot information.
+    ///
+    /// If `ipiv[k] > 0`, `D[k, k]` is a 1×1 block and rows and columns `k` and `ipiv[k] - 1` were
+    /// interchanged. If `ipiv[k] == ipiv[k + 1] < 0`, `D[k..k + 2, k..k + 2]` is a 2×2 block and
+    /// rows and columns `k + 1` and `-ipiv[k] - 1` were interchanged.
+    pub fn ipiv(&self) -> &[i32] {
+        &self.ipiv
+    }
+
+    /// Returns the block-diagonal factor D, with 1×1 and 2×2 blocks.
+    pub fn d(&self) -> DMatrix<T> {
+        let n = self.ldl.nrows;
+        DMatrix {
+            data: ldlt_d_raw(&self.ldl.data, &self.ipiv, n),
+            nrows: n,
+            ncols: n,
+        }
+    }
+
+    /// Returns the factor L with the interchanges folded in, so that `A = L D Lᵀ` (`L D Lᴴ` for
+    /// complex types).
+    ///
+    /// L is unit lower-triangular up to the symmetric row permutation recorded in
+    /// [`ipiv`](Ldlt::ipiv).
+    pub fn l(&self) -> DMatrix<T> {
+        let n = self.ldl.nrows;
+        DMatrix {
+            data: ldlt_l_raw(&self.ldl.data, &self.ipiv, n),
+            nrows: n,
+            ncols: n,
+        }
+    }
+
+    /// Solves `A X = B` for `X` using the stored factorisation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Singular`] if D is exactly singular, or [`Error::SytrsError`] if the
+    /// LAPACK `sytrs` routine fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `b` does not have as many rows as `A`.
+    pub fn solve(
+        &self,
+        b: &DMatrix<T>,
+    ) -> Result<DMatrix<T>, Error> {
+        let mut x = b.clone();
+        self.solve_in_place(&mut x)?;
+        Ok(x)
+    }
+
+    /// Solves `A X = B` using the stored factorisation, overwriting `b` with `X`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Singular`] if D is exactly singular, or [`Error::SytrsError`] if the
+    /// LAPACK `sytrs` routine fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `b` does not have as many rows as `A`.
+    pub fn solve_in_place(
+        &self,
+        b: &mut DMatrix<T>,
+    ) -> Result<(), Error> {
+        let n = self.ldl.nrows;
+        assert_eq!(
+            b.nrows, n,
+            "Right-hand side must have as many rows as the matrix"
+        );
+        ldlt_solve_raw(&self.ldl.data, &self.ipiv, n, &mut b.data, b.ncols)?;
+        Ok(())
+    }
+
+    /// Returns the inertia of `A` as `(positive, negative, zero)` eigenvalue counts.
+    ///
+    /// By Sylvester's law of inertia these are the counts for D. Only exactly zero eigenvalues
+    /// of D are counted as zero.
+    pub fn inertia(&self) -> (usize, usize, usize) {
+        ldlt_inertia_raw(&self.ldl.data, &self.ipiv, self.ldl.nrows)
+    }
+
+    /// Returns the determinant of `A`, the product of the determinants of the blocks of D.
+    pub fn det(&self) -> T {
+        ldlt_det_raw(&self.ldl.data, &self.ipiv, self.ldl.nrows)
+    }
+
+    /// Returns the inverse of `A` by solving against the identity.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Singular`] if D is exactly singular, or [`Error::SytrsError`] if the
+    /// LAPACK `sytrs` routine fails.
+    pub fn inverse(&self) -> Result<DMatrix<T>, Error> {
+        let mut inv = DMatrix::<T>::identity(self.ldl.nrows, self.ldl.nrows);
+        self.solve_in_place(&mut inv)?;
+        Ok(inv)
+    }
+}
+//}}}
+//{{{ impl DMatrix<T>
+#[allow(private_bounds)]
+impl<T> DMatrix<T>
+where
+    T: Sytrf + Sytrs + AsI32,
+{
+    /// Computes the symmetric-indefinite factorisation `A = L D Lᵀ` with Bunch–Kaufman pivoting.
+    ///
+    /// Only the lower triangle of `self` is referenced; for complex types the matrix is treated
+    /// as Hermitian. An exactly singular matrix still factors, so that its inertia and
+    /// determinant are available; solving with it returns [`Error::Singular`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::SytrsError`] if the LAPACK `sytrf` routine fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square.
+    pub fn ldlt(&self) -> Result<Ldlt<T>, Error> {
+        let n = self.nrows;
+        if n != self.ncols {
+            panic!("Matrix must be square for an LDLT factorisation");
+        }
+        let (ldl, ipiv) = ldlt_factor_raw(self.data.clone(), n)?;
+        Ok(Ldlt {
+            ldl: DMatrix {
+                data: ldl,
+                nrows: n,
+                ncols: n,
+            },
+            ipiv,
+        })
+    }
+}
+//}}}
@@ -0,0 +1,119 @@
+//! Least-squares solver for [`DMatrix`] via LAPACK `dgels`/`sgels` and `dgelsd`/`sgelsd`.
+//!
+//! Provides the `lstsq()` and `lstsq_svd()` methods on [`DMatrix<T>`], solving min ‖B − A X‖₂
+//! for overdetermined systems and the minimum-norm problem for underdetermined ones, without
+//! forming the normal equations. `lstsq()` uses the QR-based `Gels` driver and requires A to have
+//! full rank; `lstsq_svd()` uses the SVD-based `Gelsd` driver and handles rank-deficient A.
+//! Results are returned in a `Return<T>` struct; LAPACK errors propagate as a typed `Error`.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use crate::blaslapack::{lstsq_raw, AsI32, Gels, Gelsd, LstsqRawError};
+use crate::common::{Field, Zero};
+use crate::dmatrix::DMatrix;
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors that can occur when solving a least-squares problem.
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("Error in lstsq(), exited with error:\n{0}")]
+    /// LAPACK `gels` or `gelsd` failed, e.g. because the matrix is rank deficient in `lstsq()`.
+    GelsError(#[from] LstsqRawError),
+}
+//}}}
+//{{{ struct: Return
+/// Represents the solution of a least-squares problem.
+#[derive(Debug)]
+pub struct Return<T>
+where
+    T: Field + Copy,
+{
+    /// Least-squares (or minimum-norm) solution X, with one column per column of B.
+    pub x: DMatrix<T>,
+    /// Residual sum of squares for each column of B.
+    ///
+    /// Only populated for overdetermined systems of full column rank, empty otherwise.
+    pub residuals: Vec<T>,
+    /// Effective rank of A.
+    pub rank: usize,
+    /// Singular values of A in descending order, empty for `lstsq()`.
+    pub s: Vec<T>,
+}
+//}}}
+//{{{ impl DMatrix<T>
+#[allow(private_bounds)]
+impl<T> DMatrix<T>
+where
+    T: Zero + Gels + Gelsd + Field + Copy + AsI32,
+{
+    /// Solves the least-squares problem `min ‖B − A X‖₂` for `X`.
+    ///
+    /// Uses LAPACK `gels`, which factors `self` by QR (or LQ when `self` has fewer rows than
+    /// columns, giving the minimum-norm solution). `self` must have full rank; the returned rank
+    /// is `min(n, m)` and no singular values are computed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::GelsError`] if the LAPACK `gels` routine fails.
+    pub fn lstsq(
+        &self,
+        b: &DMatrix<T>,
+    ) -> Result<Return<T>, Error> {
+        self.lstsq_impl(b, None)
+    }
+
+    /// Solves the least-squares problem `min ‖B − A X‖₂` for the minimum-norm `X`, allowing
+    /// `self` to be rank deficient.
+    ///
+    /// Uses LAPACK `gelsd`, which computes the singular value decomposition of `self`. Singular
+    /// values no larger than `rcond * σ_max` are treated as zero when determining the effective
+    /// rank; a negative `rcond` selects machine precision.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::GelsError`] if the LAPACK `gelsd` routine fails.
+    pub fn lstsq_svd(
+        &self,
+        b: &DMatrix<T>,
+        rcond: T,
+    ) -> Result<Return<T>, Error> {
+        self.lstsq_impl(b, Some(rcond))
+    }
+
+    fn lstsq_impl(
+        &self,
+        b: &DMatrix<T>,
+        rcond: Option<T>,
+    ) -> Result<Return<T>, Error> {
+        assert_eq!(
+            self.nrows, b.nrows,
+            "lstsq(): A and B must have the same number of rows"
+        );
+        let m = self.ncols;
+        let nrhs = b.ncols;
+        let raw = lstsq_raw(
+            self.data.clone(),
+            b.data.clone(),
+            self.nrows,
+            m,
+            nrhs,
+            rcond,
+        )?;
+        Ok(Return {
+            x: DMatrix {
+                data: raw.x_data,
+                nrows: m,
+                ncols: nrhs,
+            },
+            residuals: raw.residuals,
+            rank: raw.rank,
+            s: raw.s,
+        })
+    }
+}
+//}}}
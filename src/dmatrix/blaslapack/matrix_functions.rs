@@ -0,0 +1,142 @@
+//! Matrix exponential, logarithm, square root and integer powers of a [`DMatrix`].
+//!
+//! Provides the `expm()`, `logm()`, `sqrtm()` and `powm()` methods on square [`DMatrix<T>`]
+//! instances. The exponential uses scaling and squaring with a Padé approximant solved by `Gesv`;
+//! the logarithm and square root are computed from the Schur form given by `Gees`, and integer
+//! powers use repeated squaring. `logm()` and `sqrtm()` return the principal branch, which is
+//! real only when no eigenvalue lies on the negative real axis; such matrices are reported
+//! through the typed [`Error::NoRealLogarithm`] and [`Error::NoRealSquareRoot`] variants.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use crate::blaslapack::{expm_raw, logm_raw, powm_raw, sqrtm_raw, MatFun, MatFunRawError};
+use crate::dmatrix::DMatrix;
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors that can occur when evaluating a matrix function.
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("Error in logm(), matrix has an eigenvalue on the closed negative real axis")]
+    /// The matrix has no real principal logarithm.
+    NoRealLogarithm,
+    #[error("Error in sqrtm(), matrix has an eigenvalue on the negative real axis")]
+    /// The matrix has no real principal square root.
+    NoRealSquareRoot,
+    #[error("Error in powm(), matrix is singular")]
+    /// A negative power was requested of an exactly singular matrix.
+    Singular,
+    #[error("Error in matrix function, exited with error:\n{0}")]
+    /// LAPACK `gees` or `gesv` reported a failure.
+    LapackError(MatFunRawError),
+}
+
+impl From<MatFunRawError> for Error {
+    fn from(err: MatFunRawError) -> Self {
+        match err {
+            MatFunRawError::NoRealLogarithm => Error::NoRealLogarithm,
+            MatFunRawError::NoRealSquareRoot => Error::NoRealSquareRoot,
+            MatFunRawError::Singular => Error::Singular,
+            err => Error::LapackError(err),
+        }
+    }
+}
+//}}}
+//{{{ impl DMatrix<T>
+#[allow(private_bounds)]
+impl<T> DMatrix<T>
+where
+    T: MatFun,
+{
+    /// Computes the matrix exponential `e^A`.
+    ///
+    /// Uses scaling and squaring with a Padé approximant of degree 3 to 13, chosen from the
+    /// 1-norm of the matrix. `self` must be square.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Singular`] if the Padé denominator is singular, which only happens for
+    /// non-finite input, or [`Error::LapackError`] if `gesv` fails for any other reason.
+    pub fn expm(&self) -> Result<DMatrix<T>, Error> {
+        let n = self.nrows;
+        if n != self.ncols {
+            panic!("Matrix must be square for expm()");
+        }
+        Ok(DMatrix {
+            data: expm_raw(&self.data, n)?,
+            nrows: n,
+            ncols: n,
+        })
+    }
+
+    /// Computes the principal matrix logarithm, the unique real `X` with `e^X = A` whose
+    /// eigenvalues have imaginary parts in `(-π, π)`.
+    ///
+    /// `self` must be square.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NoRealLogarithm`] if the matrix has a zero or negative real eigenvalue,
+    /// or [`Error::LapackError`] if `gees` fails.
+    pub fn logm(&self) -> Result<DMatrix<T>, Error> {
+        let n = self.nrows;
+        if n != self.ncols {
+            panic!("Matrix must be square for logm()");
+        }
+        Ok(DMatrix {
+            data: logm_raw(&self.data, n)?,
+            nrows: n,
+            ncols: n,
+        })
+    }
+
+    /// Computes the principal matrix square root, the unique real `X` with `X X = A` whose
+    /// eigenvalues have non-negative real parts.
+    ///
+    /// `self` must be square.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NoRealSquareRoot`] if the matrix has a negative real eigenvalue or a
+    /// defective zero eigenvalue, or [`Error::LapackError`] if `gees` fails.
+    pub fn sqrtm(&self) -> Result<DMatrix<T>, Error> {
+        let n = self.nrows;
+        if n != self.ncols {
+            panic!("Matrix must be square for sqrtm()");
+        }
+        Ok(DMatrix {
+            data: sqrtm_raw(&self.data, n)?,
+            nrows: n,
+            ncols: n,
+        })
+    }
+
+    /// Computes the integer matrix power `A^k` by repeated squaring.
+    ///
+    /// `powm(0)` is the identity and a negative `k` raises the inverse of the matrix to `-k`.
+    /// `self` must be square.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Singular`] if `k` is negative and the matrix is exactly singular, or
+    /// [`Error::LapackError`] if `gesv` fails for any other reason.
+    pub fn powm(
+        &self,
+        k: i32,
+    ) -> Result<DMatrix<T>, Error> {
+        let n = self.nrows;
+        if n != self.ncols {
+            panic!("Matrix must be square for powm()");
+        }
+        Ok(DMatrix {
+            data: powm_raw(&self.data, n, k)?,
+            nrows: n,
+            ncols: n,
+        })
+    }
+}
+//}}}
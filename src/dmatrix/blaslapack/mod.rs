@@ -12,18 +12,58 @@
 //--------------------------------------------------------------------------------------------------
 
 mod cholesky;
+mod cholesky_factor;
 mod eig;
+mod generalized_eig;
+mod generalized_symeig;
+mod hessenberg;
+mod inverse;
+mod ldlt;
+mod lstsq;
 mod lu;
+mod lu_factor;
 mod matmul;
+mod matrix_functions;
+mod pinv;
+mod polar;
 mod qr;
+mod qr_factor;
+mod qr_pivoted;
+mod rank;
 mod schur;
+mod slogdet;
 mod solve;
+mod solve_expert;
+mod svd;
 mod symeig;
+mod triangular;
 
 pub use cholesky::{Error as DCholeskyError, Return as DCholeskyReturn};
-pub use eig::{Error as DEigError, Return as DEigReturn};
+pub use cholesky_factor::{CholeskyFactor as DCholeskyFactor, Error as DCholeskyFactorError};
+pub use eig::{EigVecsReturn as DEigVecsReturn, Error as DEigError, Return as DEigReturn};
+pub use generalized_eig::{Error as DGeneralizedEigError, Return as DGeneralizedEigReturn};
+pub use generalized_symeig::{
+    Error as DGeneralizedSymEigError, Return as DGeneralizedSymEigReturn,
+};
+pub use hessenberg::{Error as DHessenbergError, Return as DHessenbergReturn};
+pub use inverse::Error as DInverseError;
+pub use ldlt::{Error as DLdltError, Ldlt as DLdlt};
+pub use lstsq::{Error as DLstsqError, Return as DLstsqReturn};
 pub use lu::{Error as DLuError, Return as DLuReturn};
+pub use lu_factor::{Error as DLuFactorError, LuFactor as DLuFactor};
+pub use matrix_functions::Error as DMatrixFunctionError;
+pub use pinv::Error as DPinvError;
+pub use polar::{Error as DPolarError, Return as DPolarReturn};
 pub use qr::{Error as DQrError, Return as DQrReturn};
-pub use schur::{Error as DSchurError, Return as DSchurReturn};
+pub use qr_factor::{Error as DQrFactorError, QrFactor as DQrFactor};
+pub use qr_pivoted::{Error as DQrPivotedError, Return as DQrPivotedReturn};
+pub use rank::Error as DRankError;
+pub use schur::{
+    Error as DSchurError, OrderedReturn as DSchurOrderedReturn, Return as DSchurReturn,
+};
+pub use slogdet::Error as DSlogdetError;
 pub use solve::Error as DSolveError;
+pub use solve_expert::{Error as DSolveExpertError, Return as DSolveExpertReturn};
+pub use svd::{Error as DSvdError, Return as DSvdReturn};
 pub use symeig::{Error as DSymEigError, Return as DSymEigReturn};
+pub use triangular::Error as DTriangularSolveError;
@@ -0,0 +1,56 @@
+//! Moore–Penrose pseudo-inverse of a [`DMatrix`] via the singular value decomposition.
+//!
+//! Provides the `pinv()` method on [`DMatrix<T>`] of any shape. The thin SVD A = U Σ Vᵀ is
+//! computed by the `Gesdd` LAPACK driver, singular values below a relative cutoff are discarded,
+//! and the pseudo-inverse A⁺ = V Σ⁺ Uᵀ is assembled with a single `Gemm` call. LAPACK errors
+//! propagate as a typed `Error`.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use crate::blaslapack::{pinv_raw, AsI32, Gemm, Gesdd, Gesvd, SvdRawError};
+use crate::common::{Field, One, Zero};
+use crate::dmatrix::DMatrix;
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors that can occur when computing a pseudo-inverse.
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("Error in pinv(), exited with error:\n{0}")]
+    /// The LAPACK SVD drivers failed to compute the decomposition.
+    GesddError(#[from] SvdRawError),
+}
+//}}}
+//{{{ impl DMatrix<T>
+#[allow(private_bounds)]
+impl<T> DMatrix<T>
+where
+    T: One + Zero + Gesdd<Real = T> + Gesvd<Real = T> + Gemm + Field + PartialOrd + Copy + AsI32,
+{
+    /// Computes the Moore–Penrose pseudo-inverse of the matrix.
+    ///
+    /// For an `n x m` matrix the result is `m x n`. Singular values no larger than
+    /// `rcond * σ_max` are treated as zero, which controls how rank deficiency is resolved.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::GesddError`] if the LAPACK SVD drivers fail.
+    pub fn pinv(
+        &self,
+        rcond: T,
+    ) -> Result<DMatrix<T>, Error> {
+        let n = self.nrows;
+        let m = self.ncols;
+        let data = pinv_raw(self.data.clone(), n, m, rcond)?;
+        Ok(DMatrix {
+            data,
+            nrows: m,
+            ncols: n,
+        })
+    }
+}
+//}}}
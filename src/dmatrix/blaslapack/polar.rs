@@ -0,0 +1,112 @@
+//! Polar decomposition and orthogonal Procrustes solver for [`DMatrix`] via the singular value
+//! decomposition.
+//!
+//! Provides the `polar()` method on square [`DMatrix<T>`] instances, factoring A = U P with U
+//! orthogonal and P symmetric positive semidefinite, and the `procrustes()` method, which finds
+//! the orthogonal matrix R that best maps the rows of one matrix onto another. Both are built on
+//! the SVD computed by the `Gesdd` LAPACK driver; [`ProcrustesMode::Rotation`] restricts the
+//! Procrustes solution to proper rotations (the Kabsch algorithm). LAPACK errors propagate as a
+//! typed `Error`.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use crate::blaslapack::{
+    polar_raw, procrustes_raw, AsI32, Gemm, Gesdd, Gesvd, Getrf, PolarRaw, PolarRawError,
+};
+use crate::common::{Field, One, ProcrustesMode, Zero};
+use crate::dmatrix::DMatrix;
+use crate::float::Float;
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors that can occur during the polar decomposition or a Procrustes solve.
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("Error in polar(), exited with error:\n{0}")]
+    /// The LAPACK SVD drivers, or the LU factorisation checking the sign of a rotation, failed.
+    GesddError(#[from] PolarRawError),
+}
+//}}}
+
+//{{{ struct: Return
+/// Represents the polar decomposition of a square matrix.
+///
+/// The decomposition satisfies `A = U P`, where `U` is orthogonal and `P` is symmetric positive
+/// semidefinite.
+pub struct Return<T>
+where
+    T: Field + Copy,
+{
+    /// Orthogonal factor U, the orthogonal matrix nearest to A in the Frobenius norm.
+    pub u: DMatrix<T>,
+    /// Symmetric positive semidefinite factor P = (AᵀA)^½.
+    pub p: DMatrix<T>,
+}
+//}}}
+
+//{{{ impl DMatrix<T>
+#[allow(private_bounds)]
+impl<T> DMatrix<T>
+where
+    T: Gesdd<Real = T> + Gesvd<Real = T> + Gemm + Getrf + Float + Zero + One + Copy + AsI32,
+{
+    /// Computes the polar decomposition of the matrix.
+    ///
+    /// Factors `self` into `U` and `P` such that `A = U P`. `P` is unique, and `U` is unique when
+    /// `A` is nonsingular. `self` must be square.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::GesddError`] if the LAPACK SVD drivers fail.
+    pub fn polar(&self) -> Result<Return<T>, Error> {
+        let n = self.nrows;
+        if n != self.ncols {
+            panic!("Matrix must be square for polar()");
+        }
+        let PolarRaw { u_data, p_data } = polar_raw(self.data.clone(), n)?;
+        Ok(Return {
+            u: DMatrix {
+                data: u_data,
+                nrows: n,
+                ncols: n,
+            },
+            p: DMatrix {
+                data: p_data,
+                nrows: n,
+                ncols: n,
+            },
+        })
+    }
+
+    /// Solves the orthogonal Procrustes problem for `self` (A) and `b` (B).
+    ///
+    /// Returns the `m x m` orthogonal matrix `R` minimising `‖A R − B‖_F` for `n x m` matrices A
+    /// and B, i.e. the best orthogonal map of the rows of A onto the rows of B. For point sets
+    /// stored one point per row, centre both sets first to fit a rigid-body rotation.
+    /// [`ProcrustesMode::Rotation`] forbids reflections, so that `det(R) = 1`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::GesddError`] if the LAPACK SVD drivers or the LU factorisation fail.
+    pub fn procrustes(
+        &self,
+        b: &DMatrix<T>,
+        mode: ProcrustesMode,
+    ) -> Result<DMatrix<T>, Error> {
+        if self.nrows != b.nrows || self.ncols != b.ncols {
+            panic!("Matrices must have the same shape for procrustes()");
+        }
+        let m = self.ncols;
+        let data = procrustes_raw(&self.data, &b.data, self.nrows, m, mode)?;
+        Ok(DMatrix {
+            data,
+            nrows: m,
+            ncols: m,
+        })
+    }
+}
+//}}}
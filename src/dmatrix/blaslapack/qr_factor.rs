@@ -0,0 +1,195 @@
+//! Reusable QR factorisation of a [`DMatrix`] via LAPACK `?geqrf`, `?ormqr` and `?trtrs`.
+//!
+//! Provides the `qr_factor()` method on [`DMatrix<T>`] instances with at least as many rows as
+//! columns, returning a [`QrFactor`] handle that keeps LAPACK's compact output: R in the upper
+//! triangle, the Householder vectors below it, and their `tau` scalars. Q is never formed; each
+//! solve applies Qᵀ with `ormqr` and back-substitutes with R using `trtrs`. For a tall matrix the
+//! solve returns the least-squares solution. Exactly zero diagonal entries of R are reported
+//! through [`Error::RankDeficient`].
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use crate::blaslapack::{
+    qr_det_raw, qr_factor_raw, qr_solve_raw, AsI32, Geqrf, Ormqr, QrFactorRawError, Trtrs,
+};
+use crate::common::ComplexField;
+use crate::dmatrix::DMatrix;
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors that can occur when computing or using a QR factorisation.
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("Error in qr_factor(), matrix is rank deficient")]
+    /// R has an exactly zero diagonal entry, so the columns of the matrix are linearly dependent.
+    RankDeficient,
+    #[error("Error in qr_factor(), exited with error:\n{0}")]
+    /// LAPACK `geqrf`, `ormqr` or `trtrs` reported a failure.
+    OrmqrError(QrFactorRawError),
+}
+
+impl From<QrFactorRawError> for Error {
+    fn from(err: QrFactorRawError) -> Self {
+        match err {
+            QrFactorRawError::RankDeficient(_) => Error::RankDeficient,
+            err => Error::OrmqrError(err),
+        }
+    }
+}
+//}}}
+//{{{ struct: QrFactor
+/// QR factorisation `A = QR` of an n×m matrix with `n >= m`, stored in LAPACK's compact form.
+#[derive(Debug, Clone)]
+pub struct QrFactor<T>
+where
+    T: ComplexField,
+{
+    /// R on and above the diagonal, Householder vectors below it.
+    pub(crate) qr: DMatrix<T>,
+    /// Scalar factors of the Householder reflectors.
+    pub(crate) tau: Vec<T>,
+}
+//}}}
+//{{{ impl: QrFactor<T>
+#[allow(private_bounds)]
+impl<T> QrFactor<T>
+where
+    T: Ormqr + Trtrs + ComplexField + AsI32,
+{
+    /// Returns the packed factors: R on and above the diagonal and the Householder vectors
+    /// (without their unit leading entries) below it.
+    pub fn packed(&self) -> &DMatrix<T> {
+        &self.qr
+    }
+
+    /// Returns the scalar factors of the Householder reflectors.
+    pub fn tau(&self) -> &[T] {
+        &self.tau
+    }
+
+    /// Solves `A X = B` for `X` using the stored factorisation.
+    ///
+    /// For a tall `A` this is the least-squares solution minimising `‖A X - B‖₂`. The result has
+    /// as many rows as `A` has columns.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::RankDeficient`] if R is singular, or [`Error::OrmqrError`] if the LAPACK
+    /// routines fail for any other reason.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `b` does not have as many rows as `A`.
+    pub fn solve(
+        &self,
+        b: &DMatrix<T>,
+    ) -> Result<DMatrix<T>, Error> {
+        let m = self.qr.ncols;
+        let mut qtb = b.clone();
+        self.solve_in_place(&mut qtb)?;
+        let mut x = DMatrix::<T>::zeros(m, b.ncols);
+        for j in 0..b.ncols {
+            for i in 0..m {
+                x[(i, j)] = qtb[(i, j)];
+            }
+        }
+        Ok(x)
+    }
+
+    /// Solves `A X = B` using the stored factorisation, overwriting `b`.
+    ///
+    /// On exit the leading rows of `b` (as many as `A` has columns) hold `X`; for a tall `A` the
+    /// remaining rows hold the components of `QᵀB` orthogonal to the range of `A`, whose squared
+    /// norm is the least-squares residual.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::RankDeficient`] if R is singular, or [`Error::OrmqrError`] if the LAPACK
+    /// routines fail for any other reason.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `b` does not have as many rows as `A`.
+    pub fn solve_in_place(
+        &self,
+        b: &mut DMatrix<T>,
+    ) -> Result<(), Error> {
+        let n = self.qr.nrows;
+        let m = self.qr.ncols;
+        assert_eq!(
+            b.nrows, n,
+            "Right-hand side must have as many rows as the matrix"
+        );
+        qr_solve_raw(&self.qr.data, &self.tau, n, m, &mut b.data, b.ncols)?;
+        Ok(())
+    }
+
+    /// Returns the determinant of a square `A`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `A` is not square.
+    pub fn det(&self) -> T {
+        let n = self.qr.nrows;
+        assert_eq!(
+            n, self.qr.ncols,
+            "Determinant is only defined for square matrices"
+        );
+        qr_det_raw(&self.qr.data, &self.tau, n)
+    }
+
+    /// Returns the inverse of a square `A` by solving against the identity.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::RankDeficient`] if R is singular, or [`Error::OrmqrError`] if the LAPACK
+    /// routines fail for any other reason.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `A` is not square.
+    pub fn inverse(&self) -> Result<DMatrix<T>, Error> {
+        let n = self.qr.nrows;
+        assert_eq!(n, self.qr.ncols, "Matrix must be square for inversion");
+        let mut inv = DMatrix::<T>::identity(n, n);
+        self.solve_in_place(&mut inv)?;
+        Ok(inv)
+    }
+}
+//}}}
+//{{{ impl DMatrix<T>
+#[allow(private_bounds)]
+impl<T> DMatrix<T>
+where
+    T: Geqrf + Ormqr + Trtrs + ComplexField + AsI32,
+{
+    /// Computes a Householder QR factorisation that can be reused for many solves.
+    ///
+    /// Unlike [`qr`](DMatrix::qr), Q is kept implicitly as Householder reflectors. `self` must
+    /// have at least as many rows as columns.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OrmqrError`] if the LAPACK `geqrf` routine fails.
+    pub fn qr_factor(&self) -> Result<QrFactor<T>, Error> {
+        let n = self.nrows;
+        let m = self.ncols;
+        if n < m {
+            panic!("Matrix must have at least as many rows as columns for a QR factorisation");
+        }
+        let (qr, tau) = qr_factor_raw(self.data.clone(), n, m)?;
+        Ok(QrFactor {
+            qr: DMatrix {
+                data: qr,
+                nrows: n,
+                ncols: m,
+            },
+            tau,
+        })
+    }
+}
+//}}}
@@ -0,0 +1,83 @@
+//! Rank-revealing QR decomposition of a [`DMatrix`] via LAPACK `?geqp3` and `?orgqr`.
+//!
+//! Provides the `qr_pivoted()` method on [`DMatrix<T>`], computing A P = Q R where P is a column
+//! permutation chosen by `geqp3` so that the diagonal of R is non-increasing in modulus, Q is an
+//! n×n orthogonal (unitary) matrix and R is n×m upper-trapezoidal. The permutation is returned as
+//! an index vector and the numerical rank is estimated from the diagonal of R against a
+//! caller-supplied relative tolerance. Q is expanded explicitly with `orgqr`.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use crate::blaslapack::{qr_pivoted_raw, AsI32, Geqp3, Orgqr, QrPivotedRawError};
+use crate::common::ComplexField;
+use crate::dmatrix::DMatrix;
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors that can occur during a column-pivoted QR decomposition.
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("Error in qr_pivoted(), exited with error:\n{0}")]
+    /// LAPACK `geqp3` or `orgqr` reported a failure.
+    Geqp3Error(#[from] QrPivotedRawError),
+}
+//}}}
+//{{{ struct: Return
+/// Represents the column-pivoted QR decomposition `A P = Q R` of a matrix.
+pub struct Return<T>
+where
+    T: ComplexField,
+{
+    /// Orthogonal (unitary) factor Q, n×n.
+    pub q: DMatrix<T>,
+    /// Upper-trapezoidal factor R, n×m, with non-increasing diagonal moduli.
+    pub r: DMatrix<T>,
+    /// Column permutation: column `j` of `A P` is column `perm[j]` of `A`.
+    pub perm: Vec<usize>,
+    /// Numerical rank: the number of diagonal entries of R with `|R_ii| > tol · |R_00|`.
+    pub rank: usize,
+}
+//}}}
+//{{{ impl DMatrix<T>
+#[allow(private_bounds)]
+impl<T> DMatrix<T>
+where
+    T: Geqp3 + Orgqr + AsI32,
+{
+    /// Computes the rank-revealing QR decomposition of the matrix with column pivoting.
+    ///
+    /// Factors `self` into `Q`, `R` and a column permutation `P` such that `A P = QR`. The
+    /// numerical rank counts the diagonal entries of `R` whose modulus exceeds `tol` times that
+    /// of the first; a typical choice is a small multiple of machine epsilon.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Geqp3Error`] if `geqp3` or `orgqr` fails.
+    pub fn qr_pivoted(
+        &self,
+        tol: T::Real,
+    ) -> Result<Return<T>, Error> {
+        let n = self.nrows;
+        let m = self.ncols;
+        let raw = qr_pivoted_raw(self.data.clone(), n, m, tol)?;
+        Ok(Return {
+            q: DMatrix {
+                data: raw.q_data,
+                nrows: n,
+                ncols: n,
+            },
+            r: DMatrix {
+                data: raw.r_data,
+                nrows: n,
+                ncols: m,
+            },
+            perm: raw.perm,
+            rank: raw.rank,
+        })
+    }
+}
+//}}}
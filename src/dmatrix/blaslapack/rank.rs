@@ -0,0 +1,93 @@
+//! Numerical rank, null space and range basis of a [`DMatrix`] via the singular value
+//! decomposition.
+//!
+//! Provides the `rank()`, `null_space()` and `orth()` methods on [`DMatrix<T>`] of any shape. The
+//! SVD A = U Σ Vᵀ is computed by the `Gesdd` LAPACK driver and the singular values above a
+//! [`RankTolerance`] cutoff are counted as the numerical rank r. The leading r columns of U span
+//! the range of A and the trailing columns of V span its null space. LAPACK errors propagate as a
+//! typed `Error`.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use crate::blaslapack::{null_space_raw, orth_raw, rank_raw, AsI32, Gesdd, Gesvd, SvdRawError};
+use crate::common::{One, RankTolerance, Zero};
+use crate::dmatrix::DMatrix;
+use crate::float::Float;
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors that can occur when computing the rank or a subspace basis.
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("Error in rank(), exited with error:\n{0}")]
+    /// The LAPACK SVD drivers failed to compute the decomposition.
+    GesddError(#[from] SvdRawError),
+}
+//}}}
+//{{{ impl DMatrix<T>
+#[allow(private_bounds)]
+impl<T> DMatrix<T>
+where
+    T: Gesdd<Real = T> + Gesvd<Real = T> + Float + Zero + One + Copy + AsI32,
+{
+    /// Computes the numerical rank of the matrix.
+    ///
+    /// The rank is the number of singular values above the cutoff selected by `tol`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::GesddError`] if the LAPACK SVD drivers fail.
+    pub fn rank(
+        &self,
+        tol: RankTolerance<T>,
+    ) -> Result<usize, Error> {
+        Ok(rank_raw(self.data.clone(), self.nrows, self.ncols, tol)?)
+    }
+
+    /// Computes an orthonormal basis for the null space of the matrix.
+    ///
+    /// For an `n x m` matrix of numerical rank `r` the result is `m x (m - r)`, with columns `x`
+    /// satisfying `A x ≈ 0`. A matrix of full column rank returns an `m x 0` matrix.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::GesddError`] if the LAPACK SVD drivers fail.
+    pub fn null_space(
+        &self,
+        tol: RankTolerance<T>,
+    ) -> Result<DMatrix<T>, Error> {
+        let m = self.ncols;
+        let (data, nullity) = null_space_raw(self.data.clone(), self.nrows, m, tol)?;
+        Ok(DMatrix {
+            data,
+            nrows: m,
+            ncols: nullity,
+        })
+    }
+
+    /// Computes an orthonormal basis for the range (column space) of the matrix.
+    ///
+    /// For an `n x m` matrix of numerical rank `r` the result is `n x r`, formed by the left
+    /// singular vectors of the `r` largest singular values.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::GesddError`] if the LAPACK SVD drivers fail.
+    pub fn orth(
+        &self,
+        tol: RankTolerance<T>,
+    ) -> Result<DMatrix<T>, Error> {
+        let n = self.nrows;
+        let (data, rank) = orth_raw(self.data.clone(), n, self.ncols, tol)?;
+        Ok(DMatrix {
+            data,
+            nrows: n,
+            ncols: rank,
+        })
+    }
+}
+//}}}
@@ -4,12 +4,16 @@
 //! where Q is orthogonal and T is quasi-upper-triangular (block upper-triangular with 1×1 and 2×2
 //! diagonal blocks for real inputs). The factorisation is computed by the `Gees` LAPACK driver.
 //! Results are returned in a `Return<T>` struct containing Q and T; LAPACK errors propagate as a
-//! typed `Error`.
+//! typed `Error`. `schur_ordered()` additionally takes an eigenvalue predicate and reorders the
+//! factorisation with `Trsen` so that the selected eigenvalues lead the diagonal of T.
 //--------------------------------------------------------------------------------------------------
 
 //{{{ crate imports
-use crate::blaslapack::{schur_raw, Gees, ShurRawError};
-use crate::common::{Field, One, Zero};
+use crate::blaslapack::{
+    schur_ordered_raw, schur_raw, AsI32, Gees, SchurOrderedRawError, ShurRawError, Trsen,
+    TrsenRawError,
+};
+use crate::common::{Complex, Field, One, Zero};
 use crate::dmatrix::DMatrix;
 //}}}
 //{{{ dep imports
@@ -23,6 +27,23 @@ pub enum Error {
     #[error("Error in schur(), exited with error:\n{0}")]
     /// LAPACK `gees` failed to compute the Schur decomposition.
     GeesError(#[from] ShurRawError),
+    #[error("Error in schur_ordered(), selected eigenvalues are too close to reorder")]
+    /// LAPACK `trsen` could not swap the selected eigenvalues past the others because the two
+    /// groups are too close; the problem is very ill-conditioned.
+    ReorderFailed,
+    #[error("Error in schur_ordered(), exited with error:\n{0}")]
+    /// LAPACK `trsen` rejected its arguments.
+    TrsenError(SchurOrderedRawError),
+}
+
+impl From<SchurOrderedRawError> for Error {
+    fn from(err: SchurOrderedRawError) -> Self {
+        match err {
+            SchurOrderedRawError::Gees(err) => Error::GeesError(err),
+            SchurOrderedRawError::Trsen(TrsenRawError::LapackError(1)) => Error::ReorderFailed,
+            err => Error::TrsenError(err),
+        }
+    }
 }
 //}}}
 
@@ -42,6 +63,25 @@ where
 }
 //}}}
 
+//{{{ struct: OrderedReturn
+/// Represents a reordered Schur decomposition of a square matrix.
+///
+/// The decomposition satisfies `A = Q T Q^T`, with the `sdim` selected eigenvalues in the leading
+/// diagonal blocks of `T`. The first `sdim` columns of `Q` span the corresponding invariant
+/// subspace.
+pub struct OrderedReturn<T>
+where
+    T: Field + Copy,
+{
+    /// Orthogonal Schur vector matrix Q.
+    pub q: DMatrix<T>,
+    /// Quasi-upper-triangular Schur form T.
+    pub t: DMatrix<T>,
+    /// Number of selected eigenvalues, counting both members of a complex-conjugate pair.
+    pub sdim: usize,
+}
+//}}}
+
 //{{{ impl DMatrix<T>
 #[allow(private_bounds)]
 impl<T> DMatrix<T>
@@ -76,6 +116,53 @@ where
 }
 //}}}
 
+//{{{ impl DMatrix<T>
+#[allow(private_bounds)]
+impl<T> DMatrix<T>
+where
+    T: One + Zero + Gees + Trsen + Field + Default + Copy + AsI32,
+{
+    /// Computes the Schur decomposition with the selected eigenvalues ordered first.
+    ///
+    /// `select` is called once per eigenvalue; the eigenvalues for which it returns `true` are
+    /// moved to the top-left of `T`, so the leading `sdim` columns of `Q` span their invariant
+    /// subspace. Selecting either member of a complex-conjugate pair selects both. `self` must be
+    /// square.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::GeesError`] if the LAPACK `gees` routine fails, [`Error::ReorderFailed`]
+    /// if the selected eigenvalues are too close to the others to be reordered reliably, or
+    /// [`Error::TrsenError`] if `trsen` fails for any other reason.
+    pub fn schur_ordered<F>(
+        &self,
+        select: F,
+    ) -> Result<OrderedReturn<T>, Error>
+    where
+        F: FnMut(Complex<T>) -> bool,
+    {
+        let n = self.nrows;
+        if n != self.ncols {
+            panic!("Matrix must be square for schur_ordered()");
+        }
+        let raw = schur_ordered_raw(self.data.clone(), n, select)?;
+        Ok(OrderedReturn {
+            q: DMatrix {
+                data: raw.q_data,
+                nrows: n,
+                ncols: n,
+            },
+            t: DMatrix {
+                data: raw.t_data,
+                nrows: n,
+                ncols: n,
+            },
+            sdim: raw.sdim,
+        })
+    }
+}
+//}}}
+
 //-------------------------------------------------------------------------------------------------
 //{{{ mod: tests
 #[cfg(test)]
@@ -0,0 +1,55 @@
+//! Sign and log-determinant of a [`DMatrix`] via LAPACK `?potrf` or `?getrf`.
+//!
+//! Provides the `slogdet()` method on square [`DMatrix<T>`] instances, returning the determinant as
+//! a sign and the natural log of its absolute value. Summing logs of the factor diagonal instead of
+//! multiplying it keeps the result finite for large or badly scaled matrices, such as the
+//! covariance matrices of Gaussian log-likelihoods. Symmetric positive definite input takes a
+//! Cholesky fast path through `Potrf`; everything else is factored by `Getrf`. LAPACK errors
+//! propagate as a typed `Error`.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use crate::blaslapack::{slogdet_raw, Getrf, LuFactorRawError, Potrf};
+use crate::common::ComplexField;
+use crate::dmatrix::DMatrix;
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors that can occur when computing the log-determinant.
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("Error in slogdet(), exited with error:\n{0}")]
+    /// LAPACK `getrf` reported a failure.
+    GetrfError(#[from] LuFactorRawError),
+}
+//}}}
+//{{{ impl DMatrix<T>
+#[allow(private_bounds)]
+impl<T> DMatrix<T>
+where
+    T: Getrf + Potrf + ComplexField,
+{
+    /// Computes the sign and the natural log of the absolute determinant.
+    ///
+    /// Returns `(sign, logdet)` with `det(A) = sign * exp(logdet)`. Exactly symmetric (complex:
+    /// Hermitian) input is first tried with a Cholesky factorisation, which gives `sign = 1` when
+    /// it succeeds; otherwise an LU factorisation with partial pivoting is used and `sign` is ±1
+    /// for real `T` and a complex number of modulus 1 for complex `T`. A singular matrix returns
+    /// `(0, -∞)`. `self` must be square.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::GetrfError`] if the LAPACK `getrf` routine fails with an invalid argument.
+    pub fn slogdet(&self) -> Result<(T, T::Real), Error> {
+        let n = self.nrows;
+        if n != self.ncols {
+            panic!("Matrix must be square for slogdet()");
+        }
+        Ok(slogdet_raw(self.data.clone(), n)?)
+    }
+}
+//}}}
@@ -0,0 +1,118 @@
+//! Expert linear system solver for [`DMatrix`] via LAPACK `?gesvx`.
+//!
+//! Provides the `solve_expert` method on [`DMatrix<T>`], solving op(A) X = B like `solve()` but
+//! with optional row and column equilibration and iterative refinement of the solution, as
+//! configured by [`SolveExpertOptions`]. Alongside the solution, the returned report carries the
+//! reciprocal condition estimate, forward and backward error bounds for each right-hand side when
+//! the solution was refined, the reciprocal pivot growth factor and whether A is singular to
+//! working precision. Only an exactly zero pivot is an error; near-singular systems still yield a solution
+//! together with the flag.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use crate::blaslapack::{solve_expert_raw, Gecon, Gesvx, Getrf, Getrs, SolveExpertRawError};
+use crate::common::{ComplexField, Equilibration, SolveExpertOptions};
+use crate::dmatrix::DMatrix;
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors that can occur when solving a linear system with the expert driver.
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("Error in solve_expert(), matrix is exactly singular")]
+    /// The LU factorisation has an exactly zero pivot, so no solution was computed.
+    Singular,
+    #[error("Error in solve_expert(), exited with error:\n{0}")]
+    /// LAPACK `gesvx`, or `getrf`, `gecon` or `getrs` without refinement, rejected its arguments.
+    GesvxError(SolveExpertRawError),
+}
+
+impl From<SolveExpertRawError> for Error {
+    fn from(err: SolveExpertRawError) -> Self {
+        match err {
+            SolveExpertRawError::Singular(_) => Error::Singular,
+            err => Error::GesvxError(err),
+        }
+    }
+}
+//}}}
+
+//{{{ struct: Return
+/// Solution of a linear system together with its quality report.
+pub struct Return<T>
+where
+    T: ComplexField,
+{
+    /// Solution matrix X, with one column per right-hand side.
+    pub x: DMatrix<T>,
+    /// Reciprocal condition number estimate of the (equilibrated) matrix in the 1-norm, or the
+    /// infinity-norm for transposed solves.
+    pub rcond: T::Real,
+    /// Estimated relative forward error bound `‖x - x_true‖∞ / ‖x‖∞` for each column of X, or
+    /// `None` if the solution was not refined.
+    pub ferr: Option<Vec<T::Real>>,
+    /// Componentwise relative backward error of each column of X, or `None` if the solution was
+    /// not refined.
+    pub berr: Option<Vec<T::Real>>,
+    /// Reciprocal pivot growth factor `‖A‖ / ‖U‖`. Values much smaller than one mean the LU
+    /// factorisation, and hence `rcond` and the solution, may be unreliable.
+    pub rpvgrw: T::Real,
+    /// Scaling applied to A before it was factored.
+    pub equilibration: Equilibration,
+    /// Whether A is singular to working precision, i.e. `rcond` is below machine epsilon.
+    pub singular: bool,
+}
+//}}}
+
+//{{{ impl DMatrix<T>
+#[allow(private_bounds)]
+impl<T> DMatrix<T>
+where
+    T: Gesvx + Getrf + Getrs + Gecon,
+{
+    /// Solves `op(A) X = B` with the LAPACK expert driver, reporting the quality of the solution.
+    ///
+    /// `options` selects whether A is equilibrated, whether A, Aᵀ or Aᴴ is used and whether the
+    /// solution is refined iteratively. The report includes the reciprocal condition estimate and,
+    /// for a refined solution, forward and backward error bounds for every right-hand side. `self`
+    /// must be square and `b` must have as many rows as `self`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Singular`] if the matrix has an exactly zero pivot, or
+    /// [`Error::GesvxError`] if the LAPACK routines fail for any other reason. A matrix
+    /// that is only singular to working precision is not an error; see [`Return::singular`].
+    pub fn solve_expert(
+        &self,
+        b: &DMatrix<T>,
+        options: SolveExpertOptions,
+    ) -> Result<Return<T>, Error> {
+        let n = self.nrows;
+        if n != self.ncols {
+            panic!("Matrix must be square for solve_expert()");
+        }
+        if b.nrows != n {
+            panic!("Right-hand side must have as many rows as the matrix in solve_expert()");
+        }
+        let nrhs = b.ncols;
+        let raw = solve_expert_raw(self.data.clone(), b.data.clone(), n, nrhs, options)?;
+        Ok(Return {
+            x: DMatrix {
+                data: raw.x_data,
+                nrows: n,
+                ncols: nrhs,
+            },
+            rcond: raw.rcond,
+            ferr: raw.ferr,
+            berr: raw.berr,
+            rpvgrw: raw.rpvgrw,
+            equilibration: raw.equilibration,
+            singular: raw.singular,
+        })
+    }
+}
+//}}}
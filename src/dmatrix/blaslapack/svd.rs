@@ -0,0 +1,120 @@
+//! Singular value decomposition of a [`DMatrix`] via LAPACK `dgesdd`/`sgesdd`.
+//!
+//! Provides the `svd()`, `svd_thin()` and `singular_values()` methods on [`DMatrix<T>`],
+//! computing the factorisation A = U Σ Vᵀ where U and V are orthogonal and Σ is diagonal with
+//! non-negative entries in descending order. The computation is delegated to the `Gesdd`
+//! divide-and-conquer driver, falling back to `Gesvd` if it fails to converge. Results are
+//! returned in a `Return<T>` struct; LAPACK errors propagate as a typed `Error`.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use crate::blaslapack::{svd_raw, AsI32, Gesdd, Gesvd, SvdRawError};
+use crate::common::{Field, Zero};
+use crate::dmatrix::DMatrix;
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors that can occur during singular value decomposition.
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("Error in svd(), exited with error:\n{0}")]
+    /// LAPACK `gesdd` and the `gesvd` fallback failed to compute the decomposition.
+    GesddError(#[from] SvdRawError),
+}
+//}}}
+//{{{ struct: Return
+/// Represents the singular value decomposition of a matrix.
+///
+/// The decomposition satisfies `A = U Σ Vᵀ`, where the columns of `U` are the left singular
+/// vectors, the rows of `Vᵀ` are the right singular vectors and `Σ` holds the singular values on
+/// its diagonal.
+#[derive(Debug)]
+pub struct Return<T>
+where
+    T: Field + Copy,
+{
+    /// Orthogonal factor U whose columns are the left singular vectors.
+    pub u: DMatrix<T>,
+    /// Singular values in descending order.
+    pub s: Vec<T>,
+    /// Orthogonal factor Vᵀ whose rows are the right singular vectors.
+    pub vt: DMatrix<T>,
+}
+//}}}
+//{{{ impl DMatrix<T>
+#[allow(private_bounds)]
+impl<T> DMatrix<T>
+where
+    T: Zero + Gesdd<Real = T> + Gesvd<Real = T> + Field + Copy + AsI32,
+{
+    /// Computes the full singular value decomposition of the matrix.
+    ///
+    /// For an `n x m` matrix, `U` is `n x n` and `Vᵀ` is `m x m`; `s` holds the `min(n, m)`
+    /// singular values in descending order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::GesddError`] if the LAPACK SVD drivers fail.
+    pub fn svd(&self) -> Result<Return<T>, Error> {
+        let n = self.nrows;
+        let m = self.ncols;
+        let raw = svd_raw(self.data.clone(), n, m, b'A')?;
+        Ok(Return {
+            u: DMatrix {
+                data: raw.u_data,
+                nrows: n,
+                ncols: n,
+            },
+            s: raw.s,
+            vt: DMatrix {
+                data: raw.vt_data,
+                nrows: m,
+                ncols: m,
+            },
+        })
+    }
+
+    /// Computes the thin (economy) singular value decomposition of the matrix.
+    ///
+    /// For an `n x m` matrix with `k = min(n, m)`, `U` is `n x k` and `Vᵀ` is `k x m`, which is
+    /// sufficient to reconstruct `A` while avoiding the orthogonal complement of its range.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::GesddError`] if the LAPACK SVD drivers fail.
+    pub fn svd_thin(&self) -> Result<Return<T>, Error> {
+        let n = self.nrows;
+        let m = self.ncols;
+        let k = n.min(m);
+        let raw = svd_raw(self.data.clone(), n, m, b'S')?;
+        Ok(Return {
+            u: DMatrix {
+                data: raw.u_data,
+                nrows: n,
+                ncols: k,
+            },
+            s: raw.s,
+            vt: DMatrix {
+                data: raw.vt_data,
+                nrows: k,
+                ncols: m,
+            },
+        })
+    }
+
+    /// Computes the singular values of the matrix in descending order without forming the
+    /// singular vectors.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::GesddError`] if the LAPACK SVD drivers fail.
+    pub fn singular_values(&self) -> Result<Vec<T>, Error> {
+        let raw = svd_raw(self.data.clone(), self.nrows, self.ncols, b'N')?;
+        Ok(raw.s)
+    }
+}
+//}}}
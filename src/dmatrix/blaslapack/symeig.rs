@@ -1,15 +1,20 @@
-//! Symmetric eigendecomposition of a [`DMatrix`] via LAPACK `dsyev`/`ssyev`.
+//! Symmetric and Hermitian eigendecomposition of a [`DMatrix`] via LAPACK `?syev`/`?heev`.
 //!
 //! Provides the `symeig()` method on [`DMatrix<T>`], computing all eigenvalues and eigenvectors
-//! of a real symmetric square matrix. The `Syev` LAPACK driver is used, which exploits symmetry
-//! for a significantly more efficient computation than the general `eig` path. Eigenvalues are
-//! returned as real scalars in ascending order in a `Vec<T>`; eigenvectors are stored column-major
-//! in the `Return<T>` struct. LAPACK errors propagate as a typed `Error`.
+//! of a real symmetric or complex Hermitian square matrix. The `Syev` LAPACK driver is used, which
+//! exploits symmetry for a significantly more efficient computation than the general `eig` path.
+//! Eigenvalues are returned as real scalars in ascending order in a `Vec<T::Real>`; eigenvectors
+//! are stored column-major in the `Return<T>` struct. LAPACK errors propagate as a typed `Error`.
+//!
+//! `symeig_range()` computes only a selected part of the spectrum via `?syevr`/`?heevr`, chosen
+//! by index or by value interval, and can skip the eigenvectors altogether.
 //--------------------------------------------------------------------------------------------------
 
 //{{{ crate imports
-use crate::blaslapack::{symeig_raw, AsI32, Syev, SymEigRawError};
-use crate::common::{Field, One, Zero};
+use crate::blaslapack::{
+    symeig_range_raw, symeig_raw, AsI32, Syev, Syevr, SymEigRangeRawError, SymEigRawError,
+};
+use crate::common::{ComplexField, SymEigRange};
 use crate::dmatrix::DMatrix;
 //}}}
 //{{{ dep imports
@@ -24,25 +29,28 @@ pub enum Error {
     #[error("Error in symeig(), exited with error:\n{0}")]
     /// LAPACK `syev` failed to compute eigenvalues or eigenvectors.
     SyevError(#[from] SymEigRawError),
+    #[error("Error in symeig_range(), exited with error:\n{0}")]
+    /// LAPACK `syevr` failed to compute the selected eigenvalues or eigenvectors.
+    SyevrError(#[from] SymEigRangeRawError),
 }
 //}}}
 
 //{{{ struct: Return
 /// Represents the eigenvalue decomposition of a symmetric matrix.
 ///
-/// For symmetric matrices, the eigenvalues are always real, and the eigenvectors
-/// form an orthogonal basis. The decomposition is of the form `A = QDQ^T`,
+/// For symmetric (or Hermitian) matrices, the eigenvalues are always real, and the eigenvectors
+/// form an orthogonal (or unitary) basis. The decomposition is of the form `A = QDQ^H`,
 /// where `Q` is the matrix of eigenvectors, and `D` is the diagonal matrix of eigenvalues.
 #[derive(Debug)]
 pub struct Return<T>
 where
-    T: Field + Default + Copy,
+    T: ComplexField,
 {
     /// Matrix of eigenvectors (columns are the eigenvectors)
     pub eigvecs: DMatrix<T>,
 
     /// Real eigenvalues
-    pub eigvals: Vec<T>,
+    pub eigvals: Vec<T::Real>,
 }
 //}}}
 
@@ -50,7 +58,7 @@ where
 #[allow(private_bounds)]
 impl<T> DMatrix<T>
 where
-    T: One + Zero + Syev + Field + Default + Copy + AsI32,
+    T: Syev + AsI32,
 {
     /// Computes the eigendecomposition of a symmetric matrix.
     ///
@@ -77,3 +85,45 @@ where
     }
 }
 //}}}
+//{{{ impl DMatrix<T>: symeig_range
+#[allow(private_bounds)]
+impl<T> DMatrix<T>
+where
+    T: Syevr + AsI32,
+{
+    /// Computes selected eigenvalues and, optionally, eigenvectors of a symmetric matrix.
+    ///
+    /// `range` picks the eigenpairs by 0-based index in the ascending spectrum or by value
+    /// interval. The returned eigenvalues are sorted in ascending order and `eigvecs` has one
+    /// column per eigenvalue found; it has no columns when `eigvecs` is `false`. Only the lower
+    /// triangle of `self` is referenced.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::SyevrError`] if the LAPACK routine fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square or an index range is out of bounds.
+    pub fn symeig_range(
+        &self,
+        range: SymEigRange<T::Real>,
+        eigvecs: bool,
+    ) -> Result<Return<T>, Error> {
+        let n = self.nrows;
+        if n != self.ncols {
+            panic!("Matrix must be square for eigenvalue decomposition");
+        }
+        let raw = symeig_range_raw(self.data.clone(), n, range, eigvecs)?;
+        let k = if eigvecs { raw.eigvals.len() } else { 0 };
+        Ok(Return {
+            eigvecs: DMatrix {
+                data: raw.eigvecs_data,
+                nrows: n,
+                ncols: k,
+            },
+            eigvals: raw.eigvals,
+        })
+    }
+}
+//}}}
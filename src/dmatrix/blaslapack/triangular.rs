@@ -0,0 +1,207 @@
+//! Triangular solves and products for [`DMatrix`] via BLAS `?trsv`, `?trsm` and `?trmm`.
+//!
+//! Provides `solve_lower_triangular()` and `solve_upper_triangular()` on square [`DMatrix<T>`]
+//! instances, which solve `op(A) X = B` by forward or back substitution in O(n²) work per
+//! right-hand side, reading only the selected triangle of A. The [`Triangular`] view gains a
+//! `solve()` method and [`MatMul`] implementations from either side, which multiply by the
+//! triangle alone through `trmm`. An exactly zero diagonal element is reported as
+//! [`Error::Singular`] rather than producing infinities.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use crate::blaslapack::{
+    triangular_matmul_raw, triangular_solve_raw, TriangularSolveRawError, Trmm, Trsm, Trsv,
+};
+use crate::common::{ComplexField, Diagonal, Field, MatMul, One, Side, Transpose, Triangle};
+use crate::dmatrix::DMatrix;
+use crate::triangular::Triangular;
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors that can occur when solving a triangular system.
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("Error in triangular solve, matrix is singular")]
+    /// A diagonal element of the triangular matrix is exactly zero.
+    Singular,
+}
+
+impl From<TriangularSolveRawError> for Error {
+    fn from(err: TriangularSolveRawError) -> Self {
+        match err {
+            TriangularSolveRawError::Singular(_) => Error::Singular,
+        }
+    }
+}
+//}}}
+//{{{ fun: triangular_solve
+fn triangular_solve<T>(
+    a: &DMatrix<T>,
+    b: &DMatrix<T>,
+    uplo: Triangle,
+    trans: Transpose,
+    diag: Diagonal,
+) -> Result<DMatrix<T>, Error>
+where
+    T: Trsv + Trsm + ComplexField,
+{
+    assert_eq!(
+        a.nrows, a.ncols,
+        "Matrix must be square for triangular solve"
+    );
+    assert_eq!(
+        b.nrows, a.nrows,
+        "Right-hand side must have as many rows as the matrix"
+    );
+    let data = triangular_solve_raw(&a.data, a.nrows, b.data.clone(), b.ncols, uplo, trans, diag)?;
+    Ok(DMatrix {
+        data,
+        nrows: b.nrows,
+        ncols: b.ncols,
+    })
+}
+//}}}
+//{{{ impl: DMatrix<T>
+#[allow(private_bounds)]
+impl<T> DMatrix<T>
+where
+    T: Trsv + Trsm + ComplexField,
+{
+    /// Solves `op(L) X = B` for `X`, where `L` is the lower triangle of `self`.
+    ///
+    /// The strictly upper triangle is never read, nor is the diagonal when `diag` is
+    /// [`Diagonal::Unit`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Singular`] if a diagonal element is exactly zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is not square or `b` does not have as many rows as `self`.
+    pub fn solve_lower_triangular(
+        &self,
+        b: &DMatrix<T>,
+        trans: Transpose,
+        diag: Diagonal,
+    ) -> Result<DMatrix<T>, Error> {
+        triangular_solve(self, b, Triangle::Lower, trans, diag)
+    }
+
+    /// Solves `op(U) X = B` for `X`, where `U` is the upper triangle of `self`.
+    ///
+    /// The strictly lower triangle is never read, nor is the diagonal when `diag` is
+    /// [`Diagonal::Unit`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Singular`] if a diagonal element is exactly zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is not square or `b` does not have as many rows as `self`.
+    pub fn solve_upper_triangular(
+        &self,
+        b: &DMatrix<T>,
+        trans: Transpose,
+        diag: Diagonal,
+    ) -> Result<DMatrix<T>, Error> {
+        triangular_solve(self, b, Triangle::Upper, trans, diag)
+    }
+}
+//}}}
+//{{{ impl: Triangular<'a, DMatrix<T>>
+#[allow(private_bounds)]
+impl<T> Triangular<'_, DMatrix<T>>
+where
+    T: Trsv + Trsm + ComplexField,
+{
+    /// Solves `A X = B` for `X`, where `A` is the viewed triangle.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Singular`] if a diagonal element is exactly zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `b` does not have as many rows as the matrix.
+    pub fn solve(
+        &self,
+        b: &DMatrix<T>,
+    ) -> Result<DMatrix<T>, Error> {
+        triangular_solve(self.matrix, b, self.triangle, Transpose::NoTrans, self.diag)
+    }
+}
+//}}}
+//{{{ impl MatMul<&'a DMatrix<T>> for Triangular<'a, DMatrix<T>>
+#[allow(private_bounds)]
+impl<'a, T> MatMul<&'a DMatrix<T>> for Triangular<'a, DMatrix<T>>
+where
+    T: Trmm + Field + One + Copy,
+{
+    type Output = DMatrix<T>;
+
+    fn matmul(
+        self,
+        rhs: &'a DMatrix<T>,
+    ) -> Self::Output {
+        let n = self.matrix.nrows;
+        assert_eq!(
+            n, rhs.nrows,
+            "Matrix dimensions are incompatible for multiplication: {}x{} and {}x{}",
+            n, n, rhs.nrows, rhs.ncols
+        );
+        DMatrix {
+            data: triangular_matmul_raw(
+                Side::Left,
+                &self.matrix.data,
+                self.triangle,
+                self.diag,
+                rhs.data.clone(),
+                rhs.nrows,
+                rhs.ncols,
+            ),
+            nrows: rhs.nrows,
+            ncols: rhs.ncols,
+        }
+    }
+}
+//}}}
+//{{{ impl MatMul<Triangular<'a, DMatrix<T>>> for &'a DMatrix<T>
+#[allow(private_bounds)]
+impl<'a, T> MatMul<Triangular<'a, DMatrix<T>>> for &'a DMatrix<T>
+where
+    T: Trmm + Field + One + Copy,
+{
+    type Output = DMatrix<T>;
+
+    fn matmul(
+        self,
+        rhs: Triangular<'a, DMatrix<T>>,
+    ) -> Self::Output {
+        let n = rhs.matrix.nrows;
+        assert_eq!(
+            self.ncols, n,
+            "Matrix dimensions are incompatible for multiplication: {}x{} and {}x{}",
+            self.nrows, self.ncols, n, n
+        );
+        DMatrix {
+            data: triangular_matmul_raw(
+                Side::Right,
+                &rhs.matrix.data,
+                rhs.triangle,
+                rhs.diag,
+                self.data.clone(),
+                self.nrows,
+                self.ncols,
+            ),
+            nrows: self.nrows,
+            ncols: self.ncols,
+        }
+    }
+}
+//}}}
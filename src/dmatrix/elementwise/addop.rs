@@ -9,7 +9,6 @@
 //--------------------------------------------------------------------------------------------------
 
 //{{{ crate imports
-use crate::apply_for_all_types;
 #[cfg(feature = "enable_checks")]
 use crate::common::Shape;
 use crate::common::{Field, MatrixExpr, ScalarExpr};
@@ -17,6 +16,7 @@ use crate::dmatrix::DMatrix;
 use crate::expression::binary_expr::{AddOp, BinOp, BinopExpr};
 use crate::expression::outer_product_expr::OuterProductExpr;
 use crate::expression::unary_expr::{UnaryExpr, UnaryOp};
+use crate::{apply_for_all_complex_types, apply_for_all_types};
 //}}}
 //{{{ std imports
 use std::ops::{Add, AddAssign};
@@ -120,6 +120,7 @@ macro_rules! impl_dmatrix_add {
     };
 }
 apply_for_all_types!(impl_dmatrix_add);
+apply_for_all_complex_types!(impl_dmatrix_add);
 //}}}
 //{{{ impl AddAssign<T> for DMatrix
 impl<T> AddAssign<T> for DMatrix<T>
@@ -251,6 +252,7 @@ macro_rules! impl_dmatrix_add_scalar_rhs {
 }
 
 apply_for_all_types!(impl_dmatrix_add_scalar_rhs);
+apply_for_all_complex_types!(impl_dmatrix_add_scalar_rhs);
 
 //}}}
 //{{{ impl: Add<Dmatrix> for $type
@@ -279,6 +281,7 @@ macro_rules! impl_dmatrix_ref_add {
     };
 }
 apply_for_all_types!(impl_dmatrix_ref_add);
+apply_for_all_complex_types!(impl_dmatrix_ref_add);
 //}}}
 //{{{ impl: Add<&mut Dmatrix> for $type
 macro_rules! impl_dmatrix_ref_mut_add {
@@ -298,6 +301,7 @@ macro_rules! impl_dmatrix_ref_mut_add {
     };
 }
 apply_for_all_types!(impl_dmatrix_ref_mut_add);
+apply_for_all_complex_types!(impl_dmatrix_ref_mut_add);
 //}}}
 //{{{ impl: Add<Rhs> for &'a DMatrix
 impl<'a, T, Rhs> Add<Rhs> for &'a DMatrix<T>
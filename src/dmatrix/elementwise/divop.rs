@@ -7,7 +7,6 @@
 //--------------------------------------------------------------------------------------------------
 
 //{{{ crate imports
-use crate::apply_for_all_types;
 #[cfg(feature = "enable_checks")]
 use crate::common::Shape;
 use crate::common::{Field, MatrixExpr, ScalarExpr};
@@ -15,6 +14,7 @@ use crate::dmatrix::DMatrix;
 use crate::expression::binary_expr::{BinOp, BinopExpr, DivOp};
 use crate::expression::outer_product_expr::OuterProductExpr;
 use crate::expression::unary_expr::{UnaryExpr, UnaryOp};
+use crate::{apply_for_all_complex_types, apply_for_all_types};
 //}}}
 //{{{ std imports
 use std::ops::{Div, DivAssign};
@@ -118,6 +118,7 @@ macro_rules! impl_dmatrix_div_scalar {
     };
 }
 apply_for_all_types!(impl_dmatrix_div_scalar);
+apply_for_all_complex_types!(impl_dmatrix_div_scalar);
 //}}}
 //{{{ impl DivAssign<T> for DMatrix
 impl<T> DivAssign<T> for DMatrix<T>
@@ -249,6 +250,7 @@ macro_rules! impl_dmatrix_div_scalar_rhs {
 }
 
 apply_for_all_types!(impl_dmatrix_div_scalar_rhs);
+apply_for_all_complex_types!(impl_dmatrix_div_scalar_rhs);
 
 //}}}
 //{{{ impl: Div<DMatrix> for $type
@@ -277,6 +279,7 @@ macro_rules! impl_dmatrix_div {
     };
 }
 apply_for_all_types!(impl_dmatrix_div);
+apply_for_all_complex_types!(impl_dmatrix_div);
 //}}}
 //{{{ impl: Div<&mut DMatrix> for $type
 macro_rules! impl_dmatrix_ref_mut_div {
@@ -296,6 +299,7 @@ macro_rules! impl_dmatrix_ref_mut_div {
     };
 }
 apply_for_all_types!(impl_dmatrix_ref_mut_div);
+apply_for_all_complex_types!(impl_dmatrix_ref_mut_div);
 //}}}
 //{{{ impl: Div<Rhs> for &'a DMatrix
 impl<'a, T, Rhs> Div<Rhs> for &'a DMatrix<T>
@@ -7,7 +7,6 @@
 //--------------------------------------------------------------------------------------------------
 
 //{{{ crate imports
-use crate::apply_for_all_types;
 #[cfg(feature = "enable_checks")]
 use crate::common::Shape;
 use crate::common::{Field, MatrixExpr, ScalarExpr};
@@ -15,6 +14,7 @@ use crate::dmatrix::DMatrix;
 use crate::expression::binary_expr::{BinOp, BinopExpr, MulOp};
 use crate::expression::outer_product_expr::OuterProductExpr;
 use crate::expression::unary_expr::{UnaryExpr, UnaryOp};
+use crate::{apply_for_all_complex_types, apply_for_all_types};
 //}}}
 //{{{ std imports
 use std::ops::{Mul, MulAssign};
@@ -118,6 +118,7 @@ macro_rules! impl_dmatrix_scalar_mul {
     };
 }
 apply_for_all_types!(impl_dmatrix_scalar_mul);
+apply_for_all_complex_types!(impl_dmatrix_scalar_mul);
 //}}}
 //{{{ impl MulAssign<T> for DMatrix
 impl<T> MulAssign<T> for DMatrix<T>
@@ -249,6 +250,7 @@ macro_rules! impl_dmatrix_mul_scalar_rhs {
 }
 
 apply_for_all_types!(impl_dmatrix_mul_scalar_rhs);
+apply_for_all_complex_types!(impl_dmatrix_mul_scalar_rhs);
 
 //}}}
 //{{{ impl: Mul<DMatrix> for $type
@@ -277,6 +279,7 @@ macro_rules! impl_dmatrix_mul {
     };
 }
 apply_for_all_types!(impl_dmatrix_mul);
+apply_for_all_complex_types!(impl_dmatrix_mul);
 //}}}
 //{{{ impl: Mul<&mut DMatrix> for $type
 macro_rules! impl_dmatrix_ref_mut_mul {
@@ -296,6 +299,7 @@ macro_rules! impl_dmatrix_ref_mut_mul {
     };
 }
 apply_for_all_types!(impl_dmatrix_ref_mut_mul);
+apply_for_all_complex_types!(impl_dmatrix_ref_mut_mul);
 //}}}
 //{{{ impl: Mul<Rhs> for &'a DMatrix
 impl<'a, T, Rhs> Mul<Rhs> for &'a DMatrix<T>
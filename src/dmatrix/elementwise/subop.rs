@@ -7,7 +7,6 @@
 //--------------------------------------------------------------------------------------------------
 
 //{{{ crate imports
-use crate::apply_for_all_types;
 #[cfg(feature = "enable_checks")]
 use crate::common::Shape;
 use crate::common::{Field, MatrixExpr, ScalarExpr};
@@ -15,6 +14,7 @@ use crate::dmatrix::DMatrix;
 use crate::expression::binary_expr::{BinOp, BinopExpr, SubOp};
 use crate::expression::outer_product_expr::OuterProductExpr;
 use crate::expression::unary_expr::{UnaryExpr, UnaryOp};
+use crate::{apply_for_all_complex_types, apply_for_all_types};
 //}}}
 //{{{ std imports
 use std::ops::{Sub, SubAssign};
@@ -118,6 +118,7 @@ macro_rules! impl_dmatrix_sub {
     };
 }
 apply_for_all_types!(impl_dmatrix_sub);
+apply_for_all_complex_types!(impl_dmatrix_sub);
 //}}}
 //{{{ impl SubAssign<T> for DMatrix
 impl<T> SubAssign<T> for DMatrix<T>
@@ -249,6 +250,7 @@ macro_rules! impl_dmatrix_sub_scalar_rhs {
 }
 
 apply_for_all_types!(impl_dmatrix_sub_scalar_rhs);
+apply_for_all_complex_types!(impl_dmatrix_sub_scalar_rhs);
 
 //}}}
 //{{{ impl: Sub<DMatrix> for $type
@@ -277,6 +279,7 @@ macro_rules! impl_dmatrix_sub {
     };
 }
 apply_for_all_types!(impl_dmatrix_sub);
+apply_for_all_complex_types!(impl_dmatrix_sub);
 //}}}
 //{{{ impl: Sub<&mut DMatrix> for $type
 macro_rules! impl_dmatrix_ref_mut_sub {
@@ -296,6 +299,7 @@ macro_rules! impl_dmatrix_ref_mut_sub {
     };
 }
 apply_for_all_types!(impl_dmatrix_ref_mut_sub);
+apply_for_all_complex_types!(impl_dmatrix_ref_mut_sub);
 //}}}
 //{{{ impl: Sub<Rhs> for &'a DMatrix
 impl<'a, T, Rhs> Sub<Rhs> for &'a DMatrix<T>
@@ -1,17 +1,19 @@
-//! Core matrix operations for [`DMatrix`]: shape, transpose, trace, and determinant.
+//! Core matrix operations for [`DMatrix`]: shape, transpose, trace, determinant, and norms.
 //!
-//! Implements the [`Shape`] and [`MatrixOps`] traits for [`DMatrix<T>`]. [`Shape`] exposes
-//! runtime `nrows` and `ncols`. [`MatrixOps`] adds `transpose()`, which produces a new matrix
-//! with rows and columns exchanged, `trace()`, computed as the sum of diagonal elements, and
-//! `determinant()`, derived from the LU factorisation via the product of diagonal entries of U
-//! scaled by the sign of the permutation.
+//! Implements the [`Shape`], [`MatrixOps`] and [`MatrixNorms`] traits for [`DMatrix<T>`].
+//! [`Shape`] exposes runtime `nrows` and `ncols`. [`MatrixOps`] adds `transpose()`, which produces
+//! a new matrix with rows and columns exchanged, `trace()`, computed as the sum of diagonal
+//! elements, and `determinant()`, derived from the LU factorisation via the product of diagonal
+//! entries of U scaled by the sign of the permutation. [`MatrixNorms`] gives real and complex
+//! matrices the Frobenius, 1, ∞, max and spectral norms, the 2-norm condition number and its
+//! LU-based reciprocal estimate.
 //--------------------------------------------------------------------------------------------------
 
 //{{{ crate imports
 use super::blaslapack::DLuReturn;
 use crate::blaslapack::Getrf;
 use crate::common::Shape;
-use crate::common::{Field, MatrixOps, One, Zero};
+use crate::common::{ComplexField, Field, MatrixNorms, MatrixOps, One, Zero};
 use crate::dmatrix::DMatrix;
 use crate::float::Float;
 //}}}
@@ -78,3 +80,12 @@ where
     }
 }
 //}}}
+
+//{{{ impl: MatrixNorms for DMatrix
+impl<T> MatrixNorms for DMatrix<T>
+where
+    T: ComplexField,
+{
+    type ScalarType = T;
+}
+//}}}
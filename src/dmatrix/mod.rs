@@ -32,7 +32,13 @@ mod subviews;
 mod transform_ops;
 
 pub use blaslapack::{
-    DCholeskyError, DCholeskyReturn, DEigError, DEigReturn, DLuError, DLuReturn, DQrError,
-    DQrReturn, DSchurError, DSchurReturn, DSolveError, DSymEigError, DSymEigReturn,
+    DCholeskyError, DCholeskyFactor, DCholeskyFactorError, DCholeskyReturn, DEigError, DEigReturn,
+    DEigVecsReturn, DGeneralizedEigError, DGeneralizedEigReturn, DGeneralizedSymEigError,
+    DGeneralizedSymEigReturn, DHessenbergError, DHessenbergReturn, DInverseError, DLdlt,
+    DLdltError, DLstsqError, DLstsqReturn, DLuError, DLuFactor, DLuFactorError, DLuReturn,
+    DMatrixFunctionError, DPinvError, DPolarError, DPolarReturn, DQrError, DQrFactor,
+    DQrFactorError, DQrPivotedError, DQrPivotedReturn, DQrReturn, DRankError, DSchurError,
+    DSchurOrderedReturn, DSchurReturn, DSlogdetError, DSolveError, DSolveExpertError,
+    DSolveExpertReturn, DSvdError, DSvdReturn, DSymEigError, DSymEigReturn, DTriangularSolveError,
 };
 pub use objects::{DMatrix, DVector, VecType};
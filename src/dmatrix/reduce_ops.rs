@@ -4,14 +4,15 @@
 //! methods, `fold` and `fold_indexed`, mirror [`Iterator::fold`] but operate over the matrix
 //! elements in column-major order. `fold_indexed` additionally passes the linear element index
 //! to the accumulator closure, enabling position-sensitive reductions such as computing the
-//! Frobenius norm or finding the index of the maximum element.
+//! Frobenius norm or finding the index of the maximum element. [`AxisReduceOps`], which derives
+//! its per-row and per-column reductions from `fold_indexed`, is implemented for the same types.
 //--------------------------------------------------------------------------------------------------
 
 //{{{ crate imports
 use super::DMatrix;
 use crate::common::tuple_index;
 use crate::subviews::{MatrixView, MatrixViewMut};
-use crate::ReduceOps;
+use crate::{AxisReduceOps, ReduceOps};
 //}}}
 //{{{ std imports
 //}}}
@@ -143,3 +144,13 @@ where
     }
 }
 //}}}
+
+//{{{ impl: AxisReduceOps for DMatrix
+impl<T: Copy> AxisReduceOps for DMatrix<T> {}
+//}}}
+//{{{ impl: AxisReduceOps for MatrixView
+impl<'a, T: Copy> AxisReduceOps for MatrixView<'a, DMatrix<T>> {}
+//}}}
+//{{{ impl: AxisReduceOps for MatrixViewMut
+impl<'a, T: Copy> AxisReduceOps for MatrixViewMut<'a, DMatrix<T>> {}
+//}}}
@@ -10,8 +10,8 @@
 //--------------------------------------------------------------------------------------------------
 
 //{{{ crate imports
-use crate::apply_for_all_types;
 use crate::common::{Field, MatrixExpr, ScalarExpr, Shape};
+use crate::{apply_for_all_complex_types, apply_for_all_types};
 //}}}
 //{{{ std imports
 use std::ops::{Add, Div, Mul, Sub};
@@ -256,6 +256,7 @@ macro_rules! impl_add_binop_expr_scalar_rhs {
 }
 
 apply_for_all_types!(impl_add_binop_expr_scalar_rhs);
+apply_for_all_complex_types!(impl_add_binop_expr_scalar_rhs);
 
 //}}}
 //{{{ impl: Add<BinopExpr> for $type
@@ -290,6 +291,7 @@ macro_rules! impl_add_binop_expr {
 }
 
 apply_for_all_types!(impl_add_binop_expr);
+apply_for_all_complex_types!(impl_add_binop_expr);
 
 //}}}
 //{{{ impl: Sub<$type> for BinopExpr
@@ -324,6 +326,7 @@ macro_rules! impl_sub_binop_expr_scalar_rhs {
 }
 
 apply_for_all_types!(impl_sub_binop_expr_scalar_rhs);
+apply_for_all_complex_types!(impl_sub_binop_expr_scalar_rhs);
 
 //}}}
 //{{{ impl: Sub<BinopExpr> for $type
@@ -358,6 +361,7 @@ macro_rules! impl_sub_binop_expr {
 }
 
 apply_for_all_types!(impl_sub_binop_expr);
+apply_for_all_complex_types!(impl_sub_binop_expr);
 
 //}}}
 //{{{ impl: Mul<$type> for BinopExpr
@@ -392,6 +396,7 @@ macro_rules! impl_mul_binop_expr_scalar_rhs {
 }
 
 apply_for_all_types!(impl_mul_binop_expr_scalar_rhs);
+apply_for_all_complex_types!(impl_mul_binop_expr_scalar_rhs);
 
 //}}}
 //{{{ impl: Mul<BinopExpr> for $type
@@ -426,6 +431,7 @@ macro_rules! impl_mul_binop_expr {
 }
 
 apply_for_all_types!(impl_mul_binop_expr);
+apply_for_all_complex_types!(impl_mul_binop_expr);
 
 //}}}
 //{{{ impl: Div<$type> for BinopExpr
@@ -460,6 +466,7 @@ macro_rules! impl_div_binop_expr_scalar_rhs {
 }
 
 apply_for_all_types!(impl_div_binop_expr_scalar_rhs);
+apply_for_all_complex_types!(impl_div_binop_expr_scalar_rhs);
 
 //}}}
 //{{{ impl: Div<BinopExpr> for $type
@@ -494,5 +501,6 @@ macro_rules! impl_div_binop_expr {
 }
 
 apply_for_all_types!(impl_div_binop_expr);
+apply_for_all_complex_types!(impl_div_binop_expr);
 
 //}}}
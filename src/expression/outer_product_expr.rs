@@ -6,10 +6,10 @@
 //--------------------------------------------------------------------------------------------------
 
 //{{{ crate imports
-use crate::apply_for_all_types;
 use crate::common::{tuple_index, Field, MatrixExpr, ScalarExpr, Shape};
 use crate::expression::binary_expr::{AddOp, BinopExpr, DivOp, MulOp, SubOp};
 use crate::expression::unary_expr::{NegOp, UnaryExpr};
+use crate::{apply_for_all_complex_types, apply_for_all_types};
 //}}}
 //{{{ std imports
 use std::ops::{Add, Div, Mul, Neg, Sub};
@@ -238,9 +238,13 @@ macro_rules! impl_div_outer_product_expr_scalar_rhs {
 }
 
 apply_for_all_types!(impl_add_outer_product_expr_scalar_rhs);
+apply_for_all_complex_types!(impl_add_outer_product_expr_scalar_rhs);
 apply_for_all_types!(impl_sub_outer_product_expr_scalar_rhs);
+apply_for_all_complex_types!(impl_sub_outer_product_expr_scalar_rhs);
 apply_for_all_types!(impl_mul_outer_product_expr_scalar_rhs);
+apply_for_all_complex_types!(impl_mul_outer_product_expr_scalar_rhs);
 apply_for_all_types!(impl_div_outer_product_expr_scalar_rhs);
+apply_for_all_complex_types!(impl_div_outer_product_expr_scalar_rhs);
 //}}}
 //{{{ macro: impl_scalar_lhs_outer_product_expr_op
 macro_rules! impl_scalar_lhs_outer_product_expr_op {
@@ -297,9 +301,13 @@ macro_rules! impl_div_outer_product_expr {
 }
 
 apply_for_all_types!(impl_add_outer_product_expr);
+apply_for_all_complex_types!(impl_add_outer_product_expr);
 apply_for_all_types!(impl_sub_outer_product_expr);
+apply_for_all_complex_types!(impl_sub_outer_product_expr);
 apply_for_all_types!(impl_mul_outer_product_expr);
+apply_for_all_complex_types!(impl_mul_outer_product_expr);
 apply_for_all_types!(impl_div_outer_product_expr);
+apply_for_all_complex_types!(impl_div_outer_product_expr);
 //}}}
 //{{{ impl: Neg for OuterProductExpr
 impl<L, R, T> Neg for OuterProductExpr<L, R, T>
@@ -8,10 +8,10 @@
 //--------------------------------------------------------------------------------------------------
 
 //{{{ crate imports
-use crate::apply_for_all_types;
 use crate::common::{Field, MatrixExpr, ScalarExpr, Shape};
 use crate::expression::binary_expr::{AddOp, BinopExpr, DivOp, MulOp, SubOp};
 use crate::float::Float;
+use crate::{apply_for_all_complex_types, apply_for_all_types};
 //}}}
 //{{{ std imports
 use std::ops::{Add, Div, Mul, Neg, Sub};
@@ -289,14 +289,22 @@ impl_unary_expr_binary_op!(Mul, mul, MulOp);
 impl_unary_expr_binary_op!(Div, div, DivOp);
 
 apply_for_all_types!(impl_add_unary_expr_scalar_rhs);
+apply_for_all_complex_types!(impl_add_unary_expr_scalar_rhs);
 apply_for_all_types!(impl_sub_unary_expr_scalar_rhs);
+apply_for_all_complex_types!(impl_sub_unary_expr_scalar_rhs);
 apply_for_all_types!(impl_mul_unary_expr_scalar_rhs);
+apply_for_all_complex_types!(impl_mul_unary_expr_scalar_rhs);
 apply_for_all_types!(impl_div_unary_expr_scalar_rhs);
+apply_for_all_complex_types!(impl_div_unary_expr_scalar_rhs);
 
 apply_for_all_types!(impl_add_unary_expr);
+apply_for_all_complex_types!(impl_add_unary_expr);
 apply_for_all_types!(impl_sub_unary_expr);
+apply_for_all_complex_types!(impl_sub_unary_expr);
 apply_for_all_types!(impl_mul_unary_expr);
+apply_for_all_complex_types!(impl_mul_unary_expr);
 apply_for_all_types!(impl_div_unary_expr);
+apply_for_all_complex_types!(impl_div_unary_expr);
 
 //}}}
 //{{{ impl: Neg for UnaryExpr
@@ -17,10 +17,10 @@ use crate::common::{Field, One, TransformOps, VectorOps, Zero};
 //--------------------------------------------------------------------------------------------------
 
 //{{{ trait: Float
-/// Extends [`Field`](crate::common::Field) with the full suite of floating-point mathematical
-/// operations required by numeric algorithms in this crate (trigonometric, exponential,
-/// logarithmic, rounding, etc.).
-pub trait Float: Field {
+/// Extends [`Field`](crate::common::Field) with a total order and the full suite of floating-point
+/// mathematical operations required by numeric algorithms in this crate (trigonometric,
+/// exponential, logarithmic, rounding, etc.).
+pub trait Float: Field + PartialOrd {
     fn abs(self) -> Self;
     fn abs_sub(
         self,
@@ -58,6 +58,8 @@ pub trait Float: Field {
     fn exp_m1(self) -> Self;
     fn floor(self) -> Self;
     fn fract(self) -> Self;
+    /// Converts an `f64` into `Self`, rounding to the nearest representable value.
+    fn from_f64(x: f64) -> Self;
     fn hypot(
         self,
         other: Self,
@@ -244,6 +246,11 @@ macro_rules! impl_float {
                 self.fract()
             }
 
+            #[inline]
+            fn from_f64(x: f64) -> Self {
+                x as $type
+            }
+
             #[inline]
             fn hypot(
                 self,
@@ -0,0 +1,226 @@
+//! Implicitly restarted Arnoldi method for a few eigenpairs of a general operator.
+//!
+//! Runs the restarted process of the [`eigensolver`](super::eigensolver) module with a general
+//! projection, whose eigenpairs are computed by LAPACK `?geev`. The operator is real but
+//! its eigenvalues may be complex, so eigenvalues and eigenvectors are returned as complex numbers.
+//! A restart never separates a complex-conjugate pair of Ritz values, so that the kept subspace has
+//! a real basis, spanned by the real and imaginary parts of the Ritz vectors. In shift-invert mode
+//! the Ritz values `ν` of `(A − σI)⁻¹` are mapped back to eigenvalues `σ + 1/ν` of `A`.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::eigensolver::{
+    converged, kept_dimension, subspace_dimension, ArnoldiProcess, Error, Options, Which,
+};
+use super::linear_operator::LinearOperator;
+use super::solver::{axpy, dot, norm2};
+use crate::blaslapack::{eig_raw, AsI32, Geev};
+use crate::common::{Complex, One, Zero};
+use crate::dmatrix::{DMatrix, DVector};
+use crate::float::Float;
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ struct: Return
+/// Represents the eigenpairs computed by [`arnoldi`].
+#[derive(Debug, Clone)]
+pub struct Return<T>
+where
+    T: Copy,
+{
+    /// The `nev` eigenvalues, most wanted first: in the order given by [`Options::which`], or
+    /// nearest `sigma` first in shift-invert mode. Complex-conjugate pairs are adjacent.
+    pub eigvals: Vec<Complex<T>>,
+    /// Matrix whose columns are the unit eigenvectors, in the same order as `eigvals`.
+    pub eigvecs: DMatrix<Complex<T>>,
+    /// Residual norm estimate of each Ritz pair of the operator passed in, i.e. of `(A − σI)⁻¹` in
+    /// shift-invert mode.
+    pub residuals: Vec<T>,
+    /// Number of eigenpairs that met the tolerance.
+    pub nconv: usize,
+    /// Whether all `nev` eigenpairs met the tolerance within `max_restarts` restarts.
+    pub converged: bool,
+    /// Number of implicit restarts performed.
+    pub restarts: usize,
+    /// Number of operator applications.
+    pub matvecs: usize,
+}
+//}}}
+//{{{ fun: arnoldi
+/// Computes `nev` eigenpairs of a general operator by the implicitly restarted Arnoldi method.
+///
+/// In shift-invert mode (`options.sigma = Some(σ)`) `op` must apply `(A − σI)⁻¹`, and the
+/// eigenpairs of `A` nearest `σ` are returned. The iteration starts from `v0`, or from a fixed
+/// pseudo-random vector if it is `None`. Running out of restarts is not an error; check
+/// `converged` or `nconv`.
+///
+/// When the `nev`-th and `nev + 1`-th eigenvalues form a complex-conjugate pair, only the first is
+/// returned.
+///
+/// # Errors
+///
+/// Returns [`Error::GeevError`] if LAPACK fails on the projected matrix.
+///
+/// # Panics
+///
+/// Panics if `op` is not square, if `nev` is not between 1 and `n − 2`, if `options.ncv` is not
+/// between `nev + 2` and `n`, or if `v0` is zero or does not match the size of `op`.
+#[allow(private_bounds)]
+pub fn arnoldi<T, L>(
+    op: &L,
+    nev: usize,
+    v0: Option<&DVector<T>>,
+    options: &Options<T>,
+) -> Result<Return<T>, Error>
+where
+    T: Float + Zero + One + Copy + Geev<Real = T, Complex = Complex<T>> + AsI32,
+    L: LinearOperator<T>,
+{
+    let ncv = subspace_dimension("arnoldi", op, nev, 2, v0, options);
+    let which = match options.sigma {
+        Some(_) => Which::LargestMagnitude,
+        None => options.which,
+    };
+    let mut process = ArnoldiProcess::new(op, ncv, false, v0);
+
+    let mut restarts = 0;
+    loop {
+        let raw = eig_raw(process.h().to_vec(), ncv, false, true)?;
+        let theta = raw.eigvals;
+        let y = T::unpack_eigvecs(&raw.vr, &theta, ncv);
+        let order = sorted(&theta, which);
+        let beta = process.beta();
+        let residuals: Vec<T> = order
+            .iter()
+            .map(|&i| {
+                let yi = y[ncv - 1 + i * ncv];
+                beta * yi.re.hypot(yi.im)
+            })
+            .collect();
+        let nconv = (0..nev)
+            .filter(|&i| {
+                let t = theta[order[i]];
+                converged(t.re.hypot(t.im), residuals[i], options.tol)
+            })
+            .count();
+
+        if nconv == nev || restarts == options.max_restarts {
+            let n = op.nrows();
+            let mut eigvecs = Vec::with_capacity(n * nev);
+            for &i in &order[..nev] {
+                let yi = &y[i * ncv..(i + 1) * ncv];
+                let re: Vec<T> = yi.iter().map(|c| c.re).collect();
+                let im: Vec<T> = yi.iter().map(|c| c.im).collect();
+                let x_re = process.basis_times(&re);
+                let x_im = process.basis_times(&im);
+                eigvecs.extend(x_re.into_iter().zip(x_im).map(|(a, b)| Complex::new(a, b)));
+            }
+            let eigvals = order[..nev]
+                .iter()
+                .map(|&i| match options.sigma {
+                    Some(sigma) => {
+                        let t = theta[i];
+                        let t2 = t.re * t.re + t.im * t.im;
+                        Complex::new(sigma + t.re / t2, -t.im / t2)
+                    }
+                    None => theta[i],
+                })
+                .collect();
+            return Ok(Return {
+                eigvals,
+                eigvecs: DMatrix {
+                    data: eigvecs,
+                    nrows: n,
+                    ncols: nev,
+                },
+                residuals: residuals[..nev].to_vec(),
+                nconv,
+                converged: nconv == nev,
+                restarts,
+                matvecs: process.matvecs,
+            });
+        }
+
+        let mut k = kept_dimension(nev, ncv);
+        if is_conjugate_pair(theta[order[k - 1]], theta[order[k]]) {
+            k = if k + 1 < ncv { k + 1 } else { k - 1 };
+        }
+        // Real basis of the kept invariant subspace: a conjugate pair spans Re y and Im y
+        let mut z = Vec::with_capacity(ncv * k);
+        for &i in &order[..k] {
+            let yi = &y[i * ncv..(i + 1) * ncv];
+            if theta[i].im >= T::zero() {
+                z.extend(yi.iter().map(|c| c.re));
+            }
+            if theta[i].im > T::zero() {
+                z.extend(yi.iter().map(|c| c.im));
+            }
+        }
+        orthonormalise_columns(&mut z, ncv, k);
+        process.restart(&z, k);
+        restarts += 1;
+    }
+}
+//}}}
+//{{{ fun: orthonormalise_columns
+/// Orthonormalises the `k` columns of the column-major `m × k` matrix `z` in place by modified
+/// Gram-Schmidt with one reorthogonalisation pass.
+fn orthonormalise_columns<T>(
+    z: &mut [T],
+    m: usize,
+    k: usize,
+) where
+    T: Float + Zero + Copy,
+{
+    for j in 0..k {
+        let (done, rest) = z.split_at_mut(j * m);
+        let zj = &mut rest[..m];
+        for _ in 0..2 {
+            for i in 0..j {
+                let zi = &done[i * m..(i + 1) * m];
+                let d = dot(zi, zj);
+                axpy(-d, zi, zj);
+            }
+        }
+        let norm = norm2(zj);
+        for x in zj.iter_mut() {
+            *x /= norm;
+        }
+    }
+}
+//}}}
+//{{{ fun: is_conjugate_pair
+fn is_conjugate_pair<T>(
+    a: Complex<T>,
+    b: Complex<T>,
+) -> bool
+where
+    T: Float + Zero + Copy,
+{
+    a.im != T::zero() && a.re == b.re && a.im == -b.im
+}
+//}}}
+//{{{ fun: sorted
+/// Indices of the Ritz values `theta`, most wanted first.
+fn sorted<T>(
+    theta: &[Complex<T>],
+    which: Which,
+) -> Vec<usize>
+where
+    T: Float + Copy,
+{
+    let key = |i: usize| match which {
+        Which::LargestMagnitude => -theta[i].re.hypot(theta[i].im),
+        Which::SmallestMagnitude => theta[i].re.hypot(theta[i].im),
+        Which::LargestReal => -theta[i].re,
+        Which::SmallestReal => theta[i].re,
+    };
+    let mut order: Vec<usize> = (0..theta.len()).collect();
+    order.sort_by(|&a, &b| key(a).partial_cmp(&key(b)).unwrap());
+    order
+}
+//}}}
@@ -0,0 +1,119 @@
+//! Right-preconditioned biconjugate gradient stabilised method (BiCGSTAB).
+//!
+//! Solves `A x = b` for a general square operator A using short recurrences, so memory stays
+//! constant, at the cost of two operator and two preconditioner applications per iteration (van
+//! der Vorst, 1992). Right preconditioning keeps the monitored residual the true residual of the
+//! original system.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::linear_operator::{LinearOperator, Preconditioner};
+use super::solver::{
+    axpy, dot, finish, initial_guess, norm2, precondition, residual, tolerance, Error, Options,
+    Return,
+};
+use crate::common::{One, Zero};
+use crate::dmatrix::DVector;
+use crate::float::Float;
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ fun: bicgstab
+/// Solves `A x = b` by the right-preconditioned BiCGSTAB method.
+///
+/// `op` may be nonsymmetric. The iteration starts from `x0`, or from zero if it is `None`.
+///
+/// # Errors
+///
+/// Returns [`Error::Breakdown`] if the shadow residual becomes orthogonal to the residual or the
+/// stabilising step length vanishes before convergence.
+///
+/// # Panics
+///
+/// Panics if `op` is not square or the vectors do not match its size.
+pub fn bicgstab<T, L>(
+    op: &L,
+    b: &DVector<T>,
+    x0: Option<&DVector<T>>,
+    precond: Option<&dyn Preconditioner<T>>,
+    options: &Options<T>,
+) -> Result<Return<T>, Error>
+where
+    T: Float + Zero + One + Copy,
+    L: LinearOperator<T>,
+{
+    let mut x = initial_guess("bicgstab", op, b, x0);
+    let n = x.len();
+    let tol = tolerance(norm2(&b.data), options);
+
+    let mut r = vec![T::zero(); n];
+    residual(op, &b.data, &x, &mut r);
+    let mut history = vec![norm2(&r)];
+    if history[0] <= tol {
+        return Ok(finish(x, true, history, tol));
+    }
+
+    let breakdown = |iteration| Error::Breakdown {
+        method: "bicgstab",
+        iteration,
+    };
+    let r_shadow = r.clone();
+    let (mut rho, mut alpha, mut omega) = (T::one(), T::one(), T::one());
+    let mut p = vec![T::zero(); n];
+    let mut v = vec![T::zero(); n];
+    let mut p_hat = vec![T::zero(); n];
+    let mut s_hat = vec![T::zero(); n];
+    let mut t = vec![T::zero(); n];
+    for iteration in 1..=options.max_iter {
+        let rho_next = dot(&r_shadow, &r);
+        if rho_next == T::zero() {
+            return Err(breakdown(iteration));
+        }
+        let beta = (rho_next / rho) * (alpha / omega);
+        rho = rho_next;
+        for ((pi, &ri), &vi) in p.iter_mut().zip(&r).zip(&v) {
+            *pi = ri + beta * (*pi - omega * vi);
+        }
+
+        precondition(precond, &p, &mut p_hat);
+        op.apply(&p_hat, &mut v);
+        let shadow_v = dot(&r_shadow, &v);
+        if shadow_v == T::zero() {
+            return Err(breakdown(iteration));
+        }
+        alpha = rho / shadow_v;
+        axpy(alpha, &p_hat, &mut x);
+        // r now holds the intermediate residual s = r − α v
+        axpy(-alpha, &v, &mut r);
+        let s_norm = norm2(&r);
+        if s_norm <= tol {
+            history.push(s_norm);
+            return Ok(finish(x, true, history, tol));
+        }
+
+        precondition(precond, &r, &mut s_hat);
+        op.apply(&s_hat, &mut t);
+        let tt = dot(&t, &t);
+        omega = if tt == T::zero() {
+            T::zero()
+        } else {
+            dot(&t, &r) / tt
+        };
+        if omega == T::zero() {
+            return Err(breakdown(iteration));
+        }
+        axpy(omega, &s_hat, &mut x);
+        axpy(-omega, &t, &mut r);
+        let r_norm = norm2(&r);
+        history.push(r_norm);
+        if r_norm <= tol {
+            return Ok(finish(x, true, history, tol));
+        }
+    }
+    Ok(finish(x, false, history, tol))
+}
+//}}}
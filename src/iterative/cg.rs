@@ -0,0 +1,93 @@
+//! Preconditioned conjugate gradient method.
+//!
+//! Solves `A x = b` for a symmetric positive definite operator A, minimising the A-norm of the
+//! error over a growing Krylov subspace with one operator application and one preconditioner
+//! application per iteration. The preconditioner must also be symmetric positive definite.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::linear_operator::{LinearOperator, Preconditioner};
+use super::solver::{
+    axpy, dot, finish, initial_guess, norm2, precondition, residual, tolerance, Error, Options,
+    Return,
+};
+use crate::common::{One, Zero};
+use crate::dmatrix::DVector;
+use crate::float::Float;
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ fun: cg
+/// Solves `A x = b` by the preconditioned conjugate gradient method.
+///
+/// `op` must be symmetric positive definite, as must `precond` if given. The iteration starts from
+/// `x0`, or from zero if it is `None`.
+///
+/// # Errors
+///
+/// Returns [`Error::Breakdown`] if a search direction has non-positive curvature `pᵀ A p ≤ 0`,
+/// which means `op` is not positive definite.
+///
+/// # Panics
+///
+/// Panics if `op` is not square or the vectors do not match its size.
+pub fn cg<T, L>(
+    op: &L,
+    b: &DVector<T>,
+    x0: Option<&DVector<T>>,
+    precond: Option<&dyn Preconditioner<T>>,
+    options: &Options<T>,
+) -> Result<Return<T>, Error>
+where
+    T: Float + Zero + One + Copy,
+    L: LinearOperator<T>,
+{
+    let mut x = initial_guess("cg", op, b, x0);
+    let n = x.len();
+    let tol = tolerance(norm2(&b.data), options);
+
+    let mut r = vec![T::zero(); n];
+    residual(op, &b.data, &x, &mut r);
+    let mut history = vec![norm2(&r)];
+    if history[0] <= tol {
+        return Ok(finish(x, true, history, tol));
+    }
+
+    let mut z = vec![T::zero(); n];
+    precondition(precond, &r, &mut z);
+    let mut p = z.clone();
+    let mut rz = dot(&r, &z);
+    let mut q = vec![T::zero(); n];
+    for iteration in 1..=options.max_iter {
+        op.apply(&p, &mut q);
+        let curvature = dot(&p, &q);
+        if curvature <= T::zero() {
+            return Err(Error::Breakdown {
+                method: "cg",
+                iteration,
+            });
+        }
+        let alpha = rz / curvature;
+        axpy(alpha, &p, &mut x);
+        axpy(-alpha, &q, &mut r);
+        let r_norm = norm2(&r);
+        history.push(r_norm);
+        if r_norm <= tol {
+            return Ok(finish(x, true, history, tol));
+        }
+
+        precondition(precond, &r, &mut z);
+        let rz_next = dot(&r, &z);
+        let beta = rz_next / rz;
+        rz = rz_next;
+        for (pi, &zi) in p.iter_mut().zip(&z) {
+            *pi = zi + beta * *pi;
+        }
+    }
+    Ok(finish(x, false, history, tol))
+}
+//}}}
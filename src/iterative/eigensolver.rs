@@ -0,0 +1,397 @@
+//! Options, errors and the restarted Arnoldi process shared by the Krylov eigensolvers.
+//!
+//! Both eigensolvers build an orthonormal basis `V` of a Krylov subspace of dimension `ncv` with
+//! the relation `A V = V H + f eₘᵀ`, and take the eigenpairs of the small projected matrix `H` as
+//! Ritz approximations. To restart, the basis is compressed onto an orthonormal basis `Z` of the
+//! invariant subspace of `H` belonging to the kept Ritz values, `V ← V Z` and `H ← Zᵀ H Z`, and
+//! the old residual becomes the next basis vector. This is the Krylov-Schur form of the implicit
+//! restart: it keeps the same subspace as applying the discarded Ritz values as exact shifts, as
+//! ARPACK does, but it also discards them when an invariant subspace has split `H` into blocks,
+//! where shifted QR steps cannot. The basis is fully reorthogonalised, so `H` stays accurate
+//! after many restarts. The solvers differ only in how they diagonalise `H` and form `Z`, which
+//! lives in their own modules.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::linear_operator::LinearOperator;
+use super::solver::{axpy, dot, norm2};
+use crate::blaslapack::{EigRawError, SymEigRawError};
+use crate::common::{One, Zero};
+use crate::dmatrix::DVector;
+use crate::float::Float;
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Which
+/// Selects which part of the spectrum a Krylov eigensolver computes.
+///
+/// For [`lanczos`](super::lanczos) the eigenvalues are real and `LargestReal`/`SmallestReal` mean
+/// the algebraically largest and smallest eigenvalues.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Which {
+    /// Eigenvalues of largest modulus.
+    #[default]
+    LargestMagnitude,
+    /// Eigenvalues of smallest modulus. Convergence is usually slow; shift-invert with
+    /// `sigma = 0` finds the same eigenvalues much faster.
+    SmallestMagnitude,
+    /// Eigenvalues of largest real part.
+    LargestReal,
+    /// Eigenvalues of smallest real part.
+    SmallestReal,
+}
+//}}}
+//{{{ enum: Error
+/// Errors that can occur in a Krylov eigensolver.
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("Error in lanczos(), exited with error:\n{0}")]
+    /// LAPACK `?syev` failed on the projected tridiagonal matrix.
+    SyevError(#[from] SymEigRawError),
+    #[error("Error in arnoldi(), exited with error:\n{0}")]
+    /// LAPACK `?geev` failed on the projected Hessenberg matrix.
+    GeevError(#[from] EigRawError),
+}
+//}}}
+//{{{ struct: Options
+/// Settings shared by the Krylov eigensolvers.
+///
+/// A Ritz pair `(θ, y)` is accepted once its residual norm `‖A y − θ y‖` is at most
+/// `tol · max(|θ|, ε^⅔)`, where `ε` is the machine epsilon.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Options<T> {
+    /// Part of the spectrum to compute. Ignored in shift-invert mode. Defaults to
+    /// [`Which::LargestMagnitude`].
+    pub which: Which,
+    /// Shift-invert mode. When `Some(σ)`, the operator passed to the solver must apply
+    /// `(A − σI)⁻¹`, typically through a factorisation of `A − σI`, and the eigenvalues of `A`
+    /// nearest `σ` are returned. Defaults to `None`.
+    pub sigma: Option<T>,
+    /// Dimension of the Krylov subspace. `None` picks `min(n, max(2 · nev + 1, 20))`.
+    pub ncv: Option<usize>,
+    /// Relative tolerance on the Ritz residuals. Defaults to `1e-10` for `f64` and `1e-5` for
+    /// `f32`.
+    pub tol: T,
+    /// Maximum number of implicit restarts. Defaults to 300.
+    pub max_restarts: usize,
+}
+
+impl Default for Options<f64> {
+    fn default() -> Self {
+        Self {
+            which: Which::LargestMagnitude,
+            sigma: None,
+            ncv: None,
+            tol: 1e-10,
+            max_restarts: 300,
+        }
+    }
+}
+
+impl Default for Options<f32> {
+    fn default() -> Self {
+        Self {
+            which: Which::LargestMagnitude,
+            sigma: None,
+            ncv: None,
+            tol: 1e-5,
+            max_restarts: 300,
+        }
+    }
+}
+//}}}
+//{{{ fun: subspace_dimension
+/// Checks the problem dimensions and returns the Krylov subspace dimension `ncv`.
+///
+/// `min_extra` is how many more basis vectors than wanted eigenpairs the method needs.
+pub(crate) fn subspace_dimension<T, L>(
+    method: &str,
+    op: &L,
+    nev: usize,
+    min_extra: usize,
+    v0: Option<&DVector<T>>,
+    options: &Options<T>,
+) -> usize
+where
+    T: Copy,
+    L: LinearOperator<T>,
+{
+    let n = op.nrows();
+    if n != op.ncols() {
+        panic!("Operator must be square for {}()", method);
+    }
+    assert!(
+        nev > 0 && nev + min_extra <= n,
+        "Number of eigenpairs must be between 1 and {} for {}()",
+        n.saturating_sub(min_extra),
+        method
+    );
+    if let Some(v0) = v0 {
+        assert_eq!(
+            v0.data.len(),
+            n,
+            "Starting vector must have as many elements as the operator"
+        );
+    }
+    let ncv = options.ncv.unwrap_or((2 * nev + 1).max(20)).min(n);
+    assert!(
+        ncv >= nev + min_extra && ncv <= n,
+        "Krylov subspace dimension must be between {} and {} for {}()",
+        nev + min_extra,
+        n,
+        method
+    );
+    ncv
+}
+//}}}
+//{{{ fun: pseudo_random
+/// Deterministic vector with entries `sin(k · s)`, `k = 1, …, n`, for `s = seed + 1`.
+///
+/// Used for the default starting vector and to leave an invariant subspace, so that results are
+/// reproducible.
+fn pseudo_random<T>(
+    n: usize,
+    seed: usize,
+) -> Vec<T>
+where
+    T: Float + Zero + One + Copy,
+{
+    let mut s = T::zero();
+    for _ in 0..=seed {
+        s += T::one();
+    }
+    let mut angle = T::zero();
+    (0..n)
+        .map(|_| {
+            angle += s;
+            angle.sin()
+        })
+        .collect()
+}
+//}}}
+//{{{ fun: converged
+/// Whether a Ritz pair with value modulus `theta_abs` and residual `res` meets the tolerance.
+pub(crate) fn converged<T>(
+    theta_abs: T,
+    res: T,
+    tol: T,
+) -> bool
+where
+    T: Float + One + Copy,
+{
+    let two = T::one() + T::one();
+    let floor = T::small().powf(two / (two + T::one()));
+    res <= tol * theta_abs.max(floor)
+}
+//}}}
+//{{{ fun: kept_dimension
+/// Number of Ritz vectors to keep at a restart: the wanted ones plus half of the others, which
+/// speeds up convergence when the wanted eigenvalues are clustered.
+pub(crate) fn kept_dimension(
+    nev: usize,
+    ncv: usize,
+) -> usize {
+    (nev + (ncv - nev) / 2).min(ncv - 1)
+}
+//}}}
+//{{{ struct: ArnoldiProcess
+/// State of a restarted Arnoldi factorisation `A V = V H + f eₘᵀ` with `m = ncv`.
+pub(crate) struct ArnoldiProcess<'a, T, L> {
+    op: &'a L,
+    n: usize,
+    m: usize,
+    symmetric: bool,
+    /// Orthonormal basis, `n × m` column-major.
+    v: Vec<T>,
+    /// Projected matrix, `m × m` column-major. Upper Hessenberg except for the row coupling the
+    /// kept vectors to the next one after a restart; symmetric for Lanczos.
+    h: Vec<T>,
+    /// Residual vector, orthogonal to the basis.
+    f: Vec<T>,
+    /// Number of fresh directions drawn after hitting an invariant subspace.
+    fresh: usize,
+    /// Number of operator applications.
+    pub(crate) matvecs: usize,
+}
+
+impl<'a, T, L> ArnoldiProcess<'a, T, L>
+where
+    T: Float + Zero + One + Copy,
+    L: LinearOperator<T>,
+{
+    /// Starts the process from `v0`, or from a fixed pseudo-random vector if it is `None`, and
+    /// builds the first `m` basis vectors.
+    pub(crate) fn new(
+        op: &'a L,
+        m: usize,
+        symmetric: bool,
+        v0: Option<&DVector<T>>,
+    ) -> Self {
+        let n = op.nrows();
+        let mut v = vec![T::zero(); n * m];
+        let v0 = match v0 {
+            Some(v0) => v0.data.clone(),
+            None => pseudo_random(n, 0),
+        };
+        let v0_norm = norm2(&v0);
+        assert!(v0_norm > T::zero(), "Starting vector must be non-zero");
+        for (vi, &x) in v.iter_mut().zip(&v0) {
+            *vi = x / v0_norm;
+        }
+        let mut process = Self {
+            op,
+            n,
+            m,
+            symmetric,
+            v,
+            h: vec![T::zero(); m * m],
+            f: vec![T::zero(); n],
+            fresh: 0,
+            matvecs: 0,
+        };
+        process.extend(0);
+        process
+    }
+
+    /// The projected matrix `H`.
+    pub(crate) fn h(&self) -> &[T] {
+        &self.h
+    }
+
+    /// The norm of the residual vector `f`, which scales every Ritz residual.
+    pub(crate) fn beta(&self) -> T {
+        norm2(&self.f)
+    }
+
+    /// Forms `V y` for a vector `y` of length `m`.
+    pub(crate) fn basis_times(
+        &self,
+        y: &[T],
+    ) -> Vec<T> {
+        let mut x = vec![T::zero(); self.n];
+        for (j, &yj) in y.iter().enumerate() {
+            axpy(yj, &self.v[j * self.n..(j + 1) * self.n], &mut x);
+        }
+        x
+    }
+
+    /// Orthogonalises `w` against the first `j` basis vectors with two passes of Gram-Schmidt,
+    /// returning the projection coefficients.
+    fn orthogonalise(
+        &self,
+        j: usize,
+        w: &mut [T],
+    ) -> Vec<T> {
+        let n = self.n;
+        let mut coeffs = vec![T::zero(); j];
+        for _ in 0..2 {
+            for (i, c) in coeffs.iter_mut().enumerate() {
+                let vi = &self.v[i * n..(i + 1) * n];
+                let d = dot(vi, w);
+                axpy(-d, vi, w);
+                *c += d;
+            }
+        }
+        coeffs
+    }
+
+    /// Turns the residual into basis vector `j`, drawing a fresh direction if the current basis
+    /// spans an invariant subspace, and returns its norm.
+    fn next_vector(
+        &mut self,
+        j: usize,
+    ) -> T {
+        let n = self.n;
+        let mut beta = norm2(&self.f);
+        let h_max = self.h.iter().fold(T::zero(), |acc, &x| acc.max(x.abs()));
+        let invariant = beta <= T::small() * h_max;
+        if invariant {
+            self.fresh += 1;
+            let mut f = pseudo_random(n, self.fresh);
+            self.orthogonalise(j, &mut f);
+            beta = norm2(&f);
+            self.f = f;
+        }
+        for (vi, &fi) in self.v[j * n..(j + 1) * n].iter_mut().zip(&self.f) {
+            *vi = fi / beta;
+        }
+        if invariant {
+            T::zero()
+        } else {
+            beta
+        }
+    }
+
+    /// Extends the factorisation from `k` to `m` basis vectors, where basis vector `k` and row
+    /// `k` of `H` are already in place.
+    fn extend(
+        &mut self,
+        k: usize,
+    ) {
+        let (n, m) = (self.n, self.m);
+        for j in k..m {
+            let mut w = vec![T::zero(); n];
+            self.op.apply(&self.v[j * n..(j + 1) * n], &mut w);
+            self.matvecs += 1;
+            let coeffs = self.orthogonalise(j + 1, &mut w);
+            self.h[j * m..j * m + j + 1].copy_from_slice(&coeffs);
+            if self.symmetric {
+                // The part above the diagonal mirrors the rows already computed
+                for i in 0..j {
+                    self.h[i + j * m] = self.h[j + i * m];
+                }
+            }
+            self.f = w;
+            if j + 1 < m {
+                let beta = self.next_vector(j + 1);
+                self.h[j + 1 + j * m] = beta;
+            }
+        }
+    }
+
+    /// Restarts from the `k` columns of `z`, an orthonormal basis of an invariant subspace of `H`
+    /// stored `m × k` column-major, and rebuilds the basis up to `m` vectors.
+    pub(crate) fn restart(
+        &mut self,
+        z: &[T],
+        k: usize,
+    ) {
+        let (n, m) = (self.n, self.m);
+        let v: Vec<T> = (0..k)
+            .flat_map(|j| self.basis_times(&z[j * m..(j + 1) * m]))
+            .collect();
+
+        // H ← Zᵀ H Z on the leading k × k block
+        let mut hz = vec![T::zero(); m * k];
+        for j in 0..k {
+            for l in 0..m {
+                axpy(
+                    z[l + j * m],
+                    &self.h[l * m..(l + 1) * m],
+                    &mut hz[j * m..(j + 1) * m],
+                );
+            }
+        }
+        let mut h = vec![T::zero(); m * m];
+        for j in 0..k {
+            for i in 0..k {
+                h[i + j * m] = dot(&z[i * m..(i + 1) * m], &hz[j * m..(j + 1) * m]);
+            }
+        }
+        self.h = h;
+        self.v[..n * k].copy_from_slice(&v);
+
+        // A V Z = V Z (Zᵀ H Z) + f (Zᵀ eₘ)ᵀ, so f / ‖f‖ continues the basis
+        let beta = self.next_vector(k);
+        for j in 0..k {
+            self.h[k + j * m] = beta * z[m - 1 + j * m];
+        }
+        self.extend(k);
+    }
+}
+//}}}
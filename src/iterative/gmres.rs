@@ -0,0 +1,154 @@
+//! Restarted, right-preconditioned generalised minimal residual method, GMRES(m).
+//!
+//! Solves `A x = b` for a general square operator A by minimising the residual norm over a Krylov
+//! subspace built with modified Gram–Schmidt Arnoldi (Saad and Schultz, 1986). The small
+//! least-squares problem is kept in upper-triangular form with Givens rotations, which yields the
+//! residual norm at every step without forming the iterate. After `restart` steps the iterate is
+//! updated and the subspace discarded, bounding memory at `restart + 1` basis vectors.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::linear_operator::{LinearOperator, Preconditioner};
+use super::solver::{
+    axpy, dot, finish, initial_guess, norm2, precondition, residual, tolerance, Error, Options,
+    Return,
+};
+use crate::common::{One, Zero};
+use crate::dmatrix::DVector;
+use crate::float::Float;
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ fun: gmres
+/// Solves `A x = b` by the restarted, right-preconditioned GMRES method.
+///
+/// `op` may be nonsymmetric. The Krylov subspace is rebuilt every `options.restart` iterations
+/// and every inner step counts towards `options.max_iter`. The iteration starts from `x0`, or from
+/// zero if it is `None`.
+///
+/// # Errors
+///
+/// GMRES does not break down: an invariant Krylov subspace means the residual has been minimised
+/// over the whole reachable space. The `Result` keeps the signature uniform with the other
+/// solvers.
+///
+/// # Panics
+///
+/// Panics if `op` is not square or the vectors do not match its size.
+pub fn gmres<T, L>(
+    op: &L,
+    b: &DVector<T>,
+    x0: Option<&DVector<T>>,
+    precond: Option<&dyn Preconditioner<T>>,
+    options: &Options<T>,
+) -> Result<Return<T>, Error>
+where
+    T: Float + Zero + One + Copy,
+    L: LinearOperator<T>,
+{
+    let mut x = initial_guess("gmres", op, b, x0);
+    let n = x.len();
+    let tol = tolerance(norm2(&b.data), options);
+    let restart = options.restart.clamp(1, n.max(1));
+
+    let mut r = vec![T::zero(); n];
+    residual(op, &b.data, &x, &mut r);
+    let mut beta = norm2(&r);
+    let mut history = vec![beta];
+    if beta <= tol {
+        return Ok(finish(x, true, history, tol));
+    }
+
+    let mut iterations = 0;
+    let mut z = vec![T::zero(); n];
+    let mut w = vec![T::zero(); n];
+    loop {
+        // Arnoldi basis V, Hessenberg columns H, rotations (c, s) and rotated right-hand side g
+        let mut v: Vec<Vec<T>> = vec![r.iter().map(|&ri| ri / beta).collect()];
+        let mut h: Vec<Vec<T>> = Vec::with_capacity(restart);
+        let mut c = Vec::with_capacity(restart);
+        let mut s = Vec::with_capacity(restart);
+        let mut g = vec![T::zero(); restart + 1];
+        g[0] = beta;
+        let mut converged = false;
+
+        for j in 0..restart {
+            precondition(precond, &v[j], &mut z);
+            op.apply(&z, &mut w);
+            let mut col = vec![T::zero(); j + 2];
+            for (i, vi) in v.iter().enumerate() {
+                col[i] = dot(&w, vi);
+                axpy(-col[i], vi, &mut w);
+            }
+            col[j + 1] = norm2(&w);
+            let invariant = col[j + 1] == T::zero();
+            if !invariant {
+                let scale = T::one() / col[j + 1];
+                v.push(w.iter().map(|&wi| wi * scale).collect());
+            }
+
+            for i in 0..j {
+                let rotated = c[i] * col[i] + s[i] * col[i + 1];
+                col[i + 1] = c[i] * col[i + 1] - s[i] * col[i];
+                col[i] = rotated;
+            }
+            let denom = col[j].hypot(col[j + 1]);
+            let (cj, sj) = if denom == T::zero() {
+                (T::one(), T::zero())
+            } else {
+                (col[j] / denom, col[j + 1] / denom)
+            };
+            col[j] = cj * col[j] + sj * col[j + 1];
+            col[j + 1] = T::zero();
+            g[j + 1] = -sj * g[j];
+            g[j] = cj * g[j];
+            c.push(cj);
+            s.push(sj);
+            h.push(col);
+
+            iterations += 1;
+            let r_norm = g[j + 1].abs();
+            history.push(r_norm);
+            converged = r_norm <= tol;
+            if converged || invariant || iterations >= options.max_iter {
+                break;
+            }
+        }
+
+        // Back substitution for the upper-triangular k×k system H y = g
+        let k = h.len();
+        let mut y = vec![T::zero(); k];
+        for i in (0..k).rev() {
+            let mut sum = g[i];
+            for l in i + 1..k {
+                sum -= h[l][i] * y[l];
+            }
+            y[i] = if h[i][i] == T::zero() {
+                T::zero()
+            } else {
+                sum / h[i][i]
+            };
+        }
+        // x += M V y
+        w.fill(T::zero());
+        for (vi, &yi) in v.iter().zip(&y) {
+            axpy(yi, vi, &mut w);
+        }
+        precondition(precond, &w, &mut z);
+        axpy(T::one(), &z, &mut x);
+
+        if converged || iterations >= options.max_iter {
+            return Ok(finish(x, converged, history, tol));
+        }
+        residual(op, &b.data, &x, &mut r);
+        beta = norm2(&r);
+        if beta <= tol {
+            return Ok(finish(x, true, history, tol));
+        }
+    }
+}
+//}}}
@@ -0,0 +1,154 @@
+//! Implicitly restarted Lanczos method for a few eigenpairs of a symmetric operator.
+//!
+//! Runs the restarted process of the [`eigensolver`](super::eigensolver) module with a symmetric
+//! projection, whose eigenpairs are computed by LAPACK `?syev`. The eigenvectors of the kept Ritz
+//! values are orthonormal, so they serve directly as the restart basis, which makes this a thick
+//! restart. Eigenvalues and eigenvectors are real. In shift-invert mode the Ritz values `ν` of
+//! `(A − σI)⁻¹` are mapped back to eigenvalues `σ + 1/ν` of `A`.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::eigensolver::{
+    converged, kept_dimension, subspace_dimension, ArnoldiProcess, Error, Options, Which,
+};
+use super::linear_operator::LinearOperator;
+use crate::blaslapack::{symeig_raw, AsI32, Syev};
+use crate::common::{One, Zero};
+use crate::dmatrix::{DMatrix, DVector};
+use crate::float::Float;
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ struct: Return
+/// Represents the eigenpairs computed by [`lanczos`].
+#[derive(Debug, Clone)]
+pub struct Return<T>
+where
+    T: Copy,
+{
+    /// The `nev` eigenvalues, most wanted first: in the order given by [`Options::which`], or
+    /// nearest `sigma` first in shift-invert mode.
+    pub eigvals: Vec<T>,
+    /// Matrix whose columns are the unit eigenvectors, in the same order as `eigvals`.
+    pub eigvecs: DMatrix<T>,
+    /// Residual norm estimate of each Ritz pair of the operator passed in, i.e. of `(A − σI)⁻¹` in
+    /// shift-invert mode.
+    pub residuals: Vec<T>,
+    /// Number of eigenpairs that met the tolerance.
+    pub nconv: usize,
+    /// Whether all `nev` eigenpairs met the tolerance within `max_restarts` restarts.
+    pub converged: bool,
+    /// Number of implicit restarts performed.
+    pub restarts: usize,
+    /// Number of operator applications.
+    pub matvecs: usize,
+}
+//}}}
+//{{{ fun: lanczos
+/// Computes `nev` eigenpairs of a symmetric operator by the implicitly restarted Lanczos method.
+///
+/// `op` must be symmetric. In shift-invert mode (`options.sigma = Some(σ)`) it must apply
+/// `(A − σI)⁻¹` instead, and the eigenpairs of `A` nearest `σ` are returned. The iteration starts
+/// from `v0`, or from a fixed pseudo-random vector if it is `None`. Running out of restarts is not
+/// an error; check `converged` or `nconv`.
+///
+/// # Errors
+///
+/// Returns [`Error::SyevError`] if LAPACK fails on the projected matrix.
+///
+/// # Panics
+///
+/// Panics if `op` is not square, if `nev` is not between 1 and `n − 1`, if `options.ncv` is not
+/// between `nev + 1` and `n`, or if `v0` is zero or does not match the size of `op`.
+#[allow(private_bounds)]
+pub fn lanczos<T, L>(
+    op: &L,
+    nev: usize,
+    v0: Option<&DVector<T>>,
+    options: &Options<T>,
+) -> Result<Return<T>, Error>
+where
+    T: Float + Zero + One + Copy + Syev<Real = T> + AsI32,
+    L: LinearOperator<T>,
+{
+    let ncv = subspace_dimension("lanczos", op, nev, 1, v0, options);
+    let which = match options.sigma {
+        Some(_) => Which::LargestMagnitude,
+        None => options.which,
+    };
+    let mut process = ArnoldiProcess::new(op, ncv, true, v0);
+
+    let mut restarts = 0;
+    loop {
+        let ritz = symeig_raw(process.h().to_vec(), ncv)?;
+        let order = sorted(&ritz.eigvals, which);
+        let beta = process.beta();
+        let residuals: Vec<T> = order
+            .iter()
+            .map(|&i| beta * ritz.eigvecs_data[ncv - 1 + i * ncv].abs())
+            .collect();
+        let nconv = (0..nev)
+            .filter(|&i| converged(ritz.eigvals[order[i]].abs(), residuals[i], options.tol))
+            .count();
+
+        if nconv == nev || restarts == options.max_restarts {
+            let mut eigvecs = Vec::with_capacity(op.nrows() * nev);
+            for &i in &order[..nev] {
+                eigvecs.extend(process.basis_times(&ritz.eigvecs_data[i * ncv..(i + 1) * ncv]));
+            }
+            let eigvals = order[..nev]
+                .iter()
+                .map(|&i| match options.sigma {
+                    Some(sigma) => sigma + ritz.eigvals[i].recip(),
+                    None => ritz.eigvals[i],
+                })
+                .collect();
+            return Ok(Return {
+                eigvals,
+                eigvecs: DMatrix {
+                    data: eigvecs,
+                    nrows: op.nrows(),
+                    ncols: nev,
+                },
+                residuals: residuals[..nev].to_vec(),
+                nconv,
+                converged: nconv == nev,
+                restarts,
+                matvecs: process.matvecs,
+            });
+        }
+
+        let k = kept_dimension(nev, ncv);
+        let z: Vec<T> = order[..k]
+            .iter()
+            .flat_map(|&i| ritz.eigvecs_data[i * ncv..(i + 1) * ncv].to_vec())
+            .collect();
+        process.restart(&z, k);
+        restarts += 1;
+    }
+}
+//}}}
+//{{{ fun: sorted
+/// Indices of the Ritz values `theta`, most wanted first.
+fn sorted<T>(
+    theta: &[T],
+    which: Which,
+) -> Vec<usize>
+where
+    T: Float + Copy,
+{
+    let key = |i: usize| match which {
+        Which::LargestMagnitude => -theta[i].abs(),
+        Which::SmallestMagnitude => theta[i].abs(),
+        Which::LargestReal => -theta[i],
+        Which::SmallestReal => theta[i],
+    };
+    let mut order: Vec<usize> = (0..theta.len()).collect();
+    order.sort_by(|&a, &b| key(a).partial_cmp(&key(b)).unwrap());
+    order
+}
+//}}}
@@ -0,0 +1,263 @@
+//! Matrix-free linear operators and preconditioners.
+//!
+//! Defines [`LinearOperator`], the abstraction the Krylov solvers work against: anything with a
+//! shape that can form the product `y = A x` into a caller-provided buffer. It is implemented for
+//! [`DMatrix`] and [`SMatrix`] through BLAS `?gemv`, for [`MatrixView`] subviews elementwise, for
+//! [`CsrMatrix`] and [`CscMatrix`] over their stored entries, and for closures through the
+//! [`FnOperator`] adapter, which supplies the shape a bare closure lacks. [`Preconditioner`] is the
+//! object-safe counterpart used for the optional preconditioner argument; every linear operator is
+//! one.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use crate::blaslapack::Gemv;
+use crate::common::{Field, One, Shape, Transpose, Zero};
+use crate::dmatrix::DMatrix;
+use crate::smatrix::SMatrix;
+use crate::sparse::{CscMatrix, CsrMatrix};
+use crate::subviews::MatrixView;
+//}}}
+//{{{ std imports
+use std::ops::Index;
+//}}}
+//{{{ dep imports
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ trait: LinearOperator
+/// A linear map `A` that can be applied to a vector without being stored as a dense matrix.
+///
+/// Implementors only need to form the product `y = A x`. `x` has [`Shape::ncols`] elements and
+/// `y` has [`Shape::nrows`] elements; `y` must be overwritten, not accumulated into.
+pub trait LinearOperator<T>: Shape {
+    /// Computes `y = A x`.
+    fn apply(
+        &self,
+        x: &[T],
+        y: &mut [T],
+    );
+}
+//}}}
+//{{{ trait: Preconditioner
+/// An approximate inverse `M ≈ A⁻¹` applied to residuals by the Krylov solvers.
+///
+/// Every [`LinearOperator`] is a preconditioner, so an explicit matrix or an [`FnOperator`] can be
+/// passed directly. The trait exists separately so it can be used as a trait object.
+pub trait Preconditioner<T> {
+    /// Computes `z = M r`.
+    fn precondition(
+        &self,
+        r: &[T],
+        z: &mut [T],
+    );
+}
+
+impl<T, L> Preconditioner<T> for L
+where
+    L: LinearOperator<T>,
+{
+    fn precondition(
+        &self,
+        r: &[T],
+        z: &mut [T],
+    ) {
+        self.apply(r, z);
+    }
+}
+//}}}
+//{{{ struct: FnOperator
+/// Adapts a closure `|x, y| { ... }` that writes `A x` into `y` to a [`LinearOperator`].
+pub struct FnOperator<F> {
+    nrows: usize,
+    ncols: usize,
+    f: F,
+}
+
+impl<F> FnOperator<F> {
+    /// Wraps `f` as an `nrows x ncols` operator.
+    pub fn new(
+        nrows: usize,
+        ncols: usize,
+        f: F,
+    ) -> Self {
+        Self { nrows, ncols, f }
+    }
+}
+
+impl<F> Shape for FnOperator<F> {
+    fn nrows(&self) -> usize {
+        self.nrows
+    }
+
+    fn ncols(&self) -> usize {
+        self.ncols
+    }
+}
+
+impl<T, F> LinearOperator<T> for FnOperator<F>
+where
+    F: Fn(&[T], &mut [T]),
+{
+    fn apply(
+        &self,
+        x: &[T],
+        y: &mut [T],
+    ) {
+        (self.f)(x, y);
+    }
+}
+//}}}
+//{{{ fun: gemv_apply
+/// Shared dense `y = A x` of the matrix implementations, for a column-major n×m `a`.
+fn gemv_apply<T>(
+    a: &[T],
+    n: usize,
+    m: usize,
+    x: &[T],
+    y: &mut [T],
+) where
+    T: Gemv + Field + Zero + One + Copy,
+{
+    assert_eq!(
+        x.len(),
+        m,
+        "Vector must have as many elements as the operator has columns"
+    );
+    assert_eq!(
+        y.len(),
+        n,
+        "Vector must have as many elements as the operator has rows"
+    );
+    if m == 0 {
+        y.fill(T::zero());
+        return;
+    }
+    if n == 0 {
+        return;
+    }
+    T::gemv(
+        Transpose::NoTrans,
+        n as i32,
+        m as i32,
+        T::one(),
+        a,
+        n as i32,
+        x,
+        1,
+        T::zero(),
+        y,
+        1,
+    );
+}
+//}}}
+//{{{ impl: LinearOperator for DMatrix
+#[allow(private_bounds)]
+impl<T> LinearOperator<T> for DMatrix<T>
+where
+    T: Gemv + Field + Zero + One + Copy,
+{
+    fn apply(
+        &self,
+        x: &[T],
+        y: &mut [T],
+    ) {
+        gemv_apply(&self.data, self.nrows, self.ncols, x, y);
+    }
+}
+//}}}
+//{{{ impl: LinearOperator for SMatrix
+#[allow(private_bounds)]
+impl<T, const N: usize, const M: usize> LinearOperator<T> for SMatrix<T, N, M>
+where
+    T: Gemv + Field + Zero + One + Copy,
+{
+    fn apply(
+        &self,
+        x: &[T],
+        y: &mut [T],
+    ) {
+        gemv_apply(self.as_slice(), N, M, x, y);
+    }
+}
+//}}}
+//{{{ impl: LinearOperator for MatrixView
+impl<'a, T, Mat> LinearOperator<T> for MatrixView<'a, Mat>
+where
+    T: Field + Zero + Copy,
+    Mat: Shape + Index<(usize, usize), Output = T>,
+{
+    fn apply(
+        &self,
+        x: &[T],
+        y: &mut [T],
+    ) {
+        assert_eq!(
+            x.len(),
+            self.ncols,
+            "Vector must have as many elements as the operator has columns"
+        );
+        assert_eq!(
+            y.len(),
+            self.nrows,
+            "Vector must have as many elements as the operator has rows"
+        );
+        y.fill(T::zero());
+        for (j, &xj) in x.iter().enumerate() {
+            for (i, yi) in y.iter_mut().enumerate() {
+                *yi += self[(i, j)] * xj;
+            }
+        }
+    }
+}
+//}}}
+//{{{ fun: check_apply_sizes
+/// Shared size checks of the sparse implementations.
+fn check_apply_sizes<T, L>(
+    op: &L,
+    x: &[T],
+    y: &[T],
+) where
+    L: Shape,
+{
+    assert_eq!(
+        x.len(),
+        op.ncols(),
+        "Vector must have as many elements as the operator has columns"
+    );
+    assert_eq!(
+        y.len(),
+        op.nrows(),
+        "Vector must have as many elements as the operator has rows"
+    );
+}
+//}}}
+//{{{ impl: LinearOperator for CsrMatrix
+impl<T> LinearOperator<T> for CsrMatrix<T>
+where
+    T: Field + Zero + Copy,
+{
+    fn apply(
+        &self,
+        x: &[T],
+        y: &mut [T],
+    ) {
+        check_apply_sizes(self, x, y);
+        y.copy_from_slice(&self.storage.mul_dense(x, 1));
+    }
+}
+//}}}
+//{{{ impl: LinearOperator for CscMatrix
+impl<T> LinearOperator<T> for CscMatrix<T>
+where
+    T: Field + Zero + Copy,
+{
+    fn apply(
+        &self,
+        x: &[T],
+        y: &mut [T],
+    ) {
+        check_apply_sizes(self, x, y);
+        y.copy_from_slice(&self.storage.tmul_dense(x, 1));
+    }
+}
+//}}}
@@ -0,0 +1,142 @@
+//! Preconditioned minimum residual method (MINRES).
+//!
+//! Solves `A x = b` for a symmetric, possibly indefinite, operator A by minimising the residual
+//! norm over the Krylov subspace generated by the symmetric Lanczos process (Paige and Saunders,
+//! 1975). The preconditioner must be symmetric positive definite; with it, the residual is
+//! minimised and monitored in the norm induced by its inverse.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::linear_operator::{LinearOperator, Preconditioner};
+use super::solver::{
+    axpy, dot, finish, initial_guess, precondition, residual, tolerance, Error, Options, Return,
+};
+use crate::common::{One, Zero};
+use crate::dmatrix::DVector;
+use crate::float::Float;
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ fun: minres
+/// Solves `A x = b` by the preconditioned MINRES method.
+///
+/// `op` must be symmetric but may be indefinite; `precond`, if given, must be symmetric positive
+/// definite. The residual history holds the recurrence estimate of the residual norm, measured in
+/// the `M`-norm when a preconditioner `M` is given, and `‖b‖` in the tolerance is measured in the
+/// same norm. The iteration starts from `x0`, or from zero if it is `None`.
+///
+/// # Errors
+///
+/// Returns [`Error::Breakdown`] if the preconditioner is found not to be positive definite.
+///
+/// # Panics
+///
+/// Panics if `op` is not square or the vectors do not match its size.
+pub fn minres<T, L>(
+    op: &L,
+    b: &DVector<T>,
+    x0: Option<&DVector<T>>,
+    precond: Option<&dyn Preconditioner<T>>,
+    options: &Options<T>,
+) -> Result<Return<T>, Error>
+where
+    T: Float + Zero + One + Copy,
+    L: LinearOperator<T>,
+{
+    let mut x = initial_guess("minres", op, b, x0);
+    let n = x.len();
+    let breakdown = |iteration| Error::Breakdown {
+        method: "minres",
+        iteration,
+    };
+
+    // ‖v‖_M = sqrt(vᵀ M v)
+    let mut y = vec![T::zero(); n];
+    precondition(precond, &b.data, &mut y);
+    let b_norm_sq = dot(&b.data, &y);
+    if b_norm_sq < T::zero() {
+        return Err(breakdown(0));
+    }
+    let tol = tolerance(b_norm_sq.sqrt(), options);
+
+    let mut r1 = vec![T::zero(); n];
+    residual(op, &b.data, &x, &mut r1);
+    precondition(precond, &r1, &mut y);
+    let beta1 = dot(&r1, &y);
+    if beta1 < T::zero() {
+        return Err(breakdown(0));
+    }
+    let beta1 = beta1.sqrt();
+    let mut history = vec![beta1];
+    if beta1 <= tol {
+        return Ok(finish(x, true, history, tol));
+    }
+
+    let mut r2 = r1.clone();
+    let (mut old_beta, mut beta) = (T::zero(), beta1);
+    let (mut dbar, mut epsilon) = (T::zero(), T::zero());
+    let mut phibar = beta1;
+    let (mut cs, mut sn) = (-T::one(), T::zero());
+    let mut v = vec![T::zero(); n];
+    let mut w = vec![T::zero(); n];
+    let mut w1 = vec![T::zero(); n];
+    let mut w2 = vec![T::zero(); n];
+    for iteration in 1..=options.max_iter {
+        // Lanczos step: v = y / β, y = A v − (β / β_old) r1 − (α / β) r2
+        let scale = T::one() / beta;
+        for (vi, &yi) in v.iter_mut().zip(&y) {
+            *vi = scale * yi;
+        }
+        op.apply(&v, &mut y);
+        if iteration >= 2 {
+            axpy(-beta / old_beta, &r1, &mut y);
+        }
+        let alpha = dot(&v, &y);
+        axpy(-alpha / beta, &r2, &mut y);
+        std::mem::swap(&mut r1, &mut r2);
+        r2.copy_from_slice(&y);
+        precondition(precond, &r2, &mut y);
+        old_beta = beta;
+        let beta_sq = dot(&r2, &y);
+        if beta_sq < T::zero() {
+            return Err(breakdown(iteration));
+        }
+        beta = beta_sq.sqrt();
+
+        // Apply the previous rotation, then compute the one eliminating β
+        let old_epsilon = epsilon;
+        let delta = cs * dbar + sn * alpha;
+        let gbar = sn * dbar - cs * alpha;
+        epsilon = sn * beta;
+        dbar = -cs * beta;
+        let gamma = gbar.hypot(beta).max(T::small());
+        cs = gbar / gamma;
+        sn = beta / gamma;
+        let phi = cs * phibar;
+        phibar = sn * phibar;
+
+        // Update the search direction and the iterate
+        std::mem::swap(&mut w1, &mut w2);
+        std::mem::swap(&mut w2, &mut w);
+        let inv_gamma = T::one() / gamma;
+        for i in 0..n {
+            w[i] = (v[i] - old_epsilon * w1[i] - delta * w2[i]) * inv_gamma;
+        }
+        axpy(phi, &w, &mut x);
+
+        history.push(phibar);
+        if phibar <= tol {
+            return Ok(finish(x, true, history, tol));
+        }
+        if beta == T::zero() {
+            // The Krylov subspace is invariant, so the estimate cannot decrease further
+            return Ok(finish(x, false, history, tol));
+        }
+    }
+    Ok(finish(x, false, history, tol))
+}
+//}}}
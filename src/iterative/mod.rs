@@ -0,0 +1,43 @@
+//! Matrix-free linear operators and Krylov subspace solvers.
+//!
+//! Defines the [`LinearOperator`] trait, which only asks for a shape and the product `y = A x`, and
+//! builds four iterative solvers for `A x = b` on top of it: the conjugate gradient method for
+//! symmetric positive definite operators, MINRES for symmetric indefinite ones, and restarted
+//! GMRES and BiCGSTAB for general ones. Each accepts an optional [`Preconditioner`] and an initial
+//! guess, and reports convergence, the iteration count, the residual history and the tolerance it
+//! tested. The implicitly restarted Lanczos and Arnoldi methods compute a few eigenpairs of
+//! symmetric and general operators, optionally in shift-invert mode. Dense
+//! [`DMatrix`](crate::DMatrix) and [`SMatrix`](crate::SMatrix) instances and their
+//! [`MatrixView`](crate::MatrixView) subviews are operators, and closures become operators through
+//! [`FnOperator`].
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+mod arnoldi;
+mod bicgstab;
+mod cg;
+mod eigensolver;
+mod gmres;
+mod lanczos;
+mod linear_operator;
+mod minres;
+mod solver;
+
+pub use arnoldi::{arnoldi, Return as ArnoldiReturn};
+pub use bicgstab::bicgstab;
+pub use cg::cg;
+pub use eigensolver::{
+    Error as KrylovEigError, Options as KrylovEigOptions, Which as KrylovEigWhich,
+};
+pub use gmres::gmres;
+pub use lanczos::{lanczos, Return as LanczosReturn};
+pub use linear_operator::{FnOperator, LinearOperator, Preconditioner};
+pub use minres::minres;
+pub use solver::{Error as IterativeError, Options as IterativeOptions, Return as IterativeReturn};
@@ -0,0 +1,246 @@
+//! Options, results, errors and vector kernels shared by the Krylov solvers.
+//!
+//! Every solver takes the same [`Options`] and returns the same [`Return`]: the approximate
+//! solution together with whether it converged, the iteration count, the residual norm history and
+//! the absolute tolerance that was tested. Failing to converge within the iteration budget is not
+//! an error, the caller inspects `converged`; [`Error::Breakdown`] is reserved for the recurrences
+//! dividing by zero, typically because the operator or preconditioner lacks a property the method
+//! requires.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::linear_operator::{LinearOperator, Preconditioner};
+use crate::common::Zero;
+use crate::dmatrix::{DMatrix, DVector};
+use crate::float::Float;
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors that can occur in a Krylov solver.
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("Error in {method}(), breakdown at iteration {iteration}")]
+    /// A recurrence divided by zero, or by a value of the wrong sign, at the given iteration. CG
+    /// and MINRES report this when the operator or the preconditioner is not symmetric positive
+    /// definite (MINRES only requires the preconditioner to be); BiCGSTAB when the shadow residual
+    /// becomes orthogonal to the residual.
+    Breakdown {
+        /// Name of the solver.
+        method: &'static str,
+        /// 1-based iteration at which the breakdown occurred.
+        iteration: usize,
+    },
+}
+//}}}
+//{{{ struct: Options
+/// Stopping criteria shared by the Krylov solvers.
+///
+/// A solver stops once its residual norm satisfies `‖r‖ ≤ max(rtol · ‖b‖, atol)` or after
+/// `max_iter` iterations.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Options<T> {
+    /// Tolerance relative to the norm of the right-hand side. Defaults to `1e-8` for `f64` and
+    /// `1e-5` for `f32`.
+    pub rtol: T,
+    /// Absolute tolerance on the residual norm. Defaults to zero.
+    pub atol: T,
+    /// Maximum number of iterations, counting every inner GMRES step. Defaults to 1000.
+    pub max_iter: usize,
+    /// Krylov subspace dimension after which GMRES restarts; ignored by the other solvers.
+    /// Defaults to 30.
+    pub restart: usize,
+}
+
+impl Default for Options<f64> {
+    fn default() -> Self {
+        Self {
+            rtol: 1e-8,
+            atol: 0.0,
+            max_iter: 1000,
+            restart: 30,
+        }
+    }
+}
+
+impl Default for Options<f32> {
+    fn default() -> Self {
+        Self {
+            rtol: 1e-5,
+            atol: 0.0,
+            max_iter: 1000,
+            restart: 30,
+        }
+    }
+}
+//}}}
+//{{{ struct: Return
+/// Represents the outcome of a Krylov solve.
+#[derive(Debug, Clone)]
+pub struct Return<T>
+where
+    T: Copy,
+{
+    /// Approximate solution, as a column vector.
+    pub x: DVector<T>,
+    /// Whether the residual criterion was met within `max_iter` iterations.
+    pub converged: bool,
+    /// Number of iterations performed.
+    pub iterations: usize,
+    /// Residual norm of the initial guess followed by one entry per iteration. The first entry is
+    /// computed from `b − A x₀`; later entries are the norm of the residual each method updates by
+    /// recurrence, which equals `‖b − A x‖₂` in exact arithmetic but can drift from it in floating
+    /// point. MINRES measures its residuals in the `M`-norm when a preconditioner `M` is given.
+    pub residual_history: Vec<T>,
+    /// The absolute tolerance the residual norm was tested against, `max(rtol · ‖b‖, atol)`.
+    pub tolerance: T,
+}
+//}}}
+//{{{ collection: vector kernels
+//{{{ fun: dot
+pub(crate) fn dot<T>(
+    x: &[T],
+    y: &[T],
+) -> T
+where
+    T: Float + Zero + Copy,
+{
+    x.iter().zip(y).fold(T::zero(), |acc, (&a, &b)| acc + a * b)
+}
+//}}}
+//{{{ fun: norm2
+pub(crate) fn norm2<T>(x: &[T]) -> T
+where
+    T: Float + Zero + Copy,
+{
+    dot(x, x).sqrt()
+}
+//}}}
+//{{{ fun: axpy
+/// `y += alpha x`
+pub(crate) fn axpy<T>(
+    alpha: T,
+    x: &[T],
+    y: &mut [T],
+) where
+    T: Float + Copy,
+{
+    for (yi, &xi) in y.iter_mut().zip(x) {
+        *yi += alpha * xi;
+    }
+}
+//}}}
+//}}}
+//{{{ fun: precondition
+/// Applies the optional preconditioner, `z = M r`, or copies `r` when there is none.
+pub(crate) fn precondition<T>(
+    precond: Option<&dyn Preconditioner<T>>,
+    r: &[T],
+    z: &mut [T],
+) where
+    T: Copy,
+{
+    match precond {
+        Some(m) => m.precondition(r, z),
+        None => z.copy_from_slice(r),
+    }
+}
+//}}}
+//{{{ fun: residual
+/// `r = b − A x`
+pub(crate) fn residual<T, L>(
+    op: &L,
+    b: &[T],
+    x: &[T],
+    r: &mut [T],
+) where
+    T: Float + Copy,
+    L: LinearOperator<T>,
+{
+    op.apply(x, r);
+    for (ri, &bi) in r.iter_mut().zip(b) {
+        *ri = bi - *ri;
+    }
+}
+//}}}
+//{{{ fun: initial_guess
+/// Checks the problem dimensions and returns the starting iterate, `x0` or zero.
+pub(crate) fn initial_guess<T, L>(
+    method: &str,
+    op: &L,
+    b: &DVector<T>,
+    x0: Option<&DVector<T>>,
+) -> Vec<T>
+where
+    T: Zero + Copy,
+    L: LinearOperator<T>,
+{
+    let n = op.nrows();
+    if n != op.ncols() {
+        panic!("Operator must be square for {}()", method);
+    }
+    assert_eq!(
+        b.data.len(),
+        n,
+        "Right-hand side must have as many elements as the operator"
+    );
+    match x0 {
+        Some(x0) => {
+            assert_eq!(
+                x0.data.len(),
+                n,
+                "Initial guess must have as many elements as the operator"
+            );
+            x0.data.clone()
+        }
+        None => vec![T::zero(); n],
+    }
+}
+//}}}
+//{{{ fun: tolerance
+/// `max(rtol · ‖b‖, atol)`
+pub(crate) fn tolerance<T>(
+    b_norm: T,
+    options: &Options<T>,
+) -> T
+where
+    T: Float + Copy,
+{
+    let rel = options.rtol * b_norm;
+    if rel > options.atol {
+        rel
+    } else {
+        options.atol
+    }
+}
+//}}}
+//{{{ fun: finish
+/// Packs the final iterate into a [`Return`].
+pub(crate) fn finish<T>(
+    x: Vec<T>,
+    converged: bool,
+    residual_history: Vec<T>,
+    tolerance: T,
+) -> Return<T>
+where
+    T: Copy,
+{
+    let n = x.len();
+    Return {
+        x: DMatrix {
+            data: x,
+            nrows: n,
+            ncols: 1,
+        },
+        converged,
+        iterations: residual_history.len() - 1,
+        residual_history,
+        tolerance,
+    }
+}
+//}}}
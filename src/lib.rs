@@ -6,17 +6,24 @@
 //! - a runtime-sized `DMatrix`
 //! - a compile-time-sized `SMatrix`
 //!
-//! Both use column-major memory layout and can hold floating-point, integral, and boolean data.
+//! Both use column-major memory layout and can hold floating-point, complex, integral, and boolean
+//! data.
 //! Both have the following features defined for them:
 //!
-//! - Accelerated matrix-matrix and matrix-mector multiplication via BLAS/LAPACK (floating point only)
-//! - Accelerated Matrix decompositions and linear system solution via BLAS/LAPACK (floating point only)
+//! - Accelerated matrix-matrix and matrix-mector multiplication via BLAS/LAPACK (real and complex
+//!   floating point only)
+//! - Accelerated Matrix decompositions and linear system solution via BLAS/LAPACK (real and complex
+//!   floating point only)
 //! - Lazily-evaluated, complex elementwide expressions.
 //! - Matrix subviews
 //! - Lazy element-wise comparisons and boolean masked selection
 //! - Reductions and transformations
 //! - Elementwise functions which mirror those supported for primitive integral and floating point
 //!   types.
+//! - Matrix-free linear operators and Krylov iterative solvers (CG, MINRES, GMRES, BiCGSTAB).
+//! - Implicitly restarted Lanczos and Arnoldi eigensolvers for a few eigenpairs of large operators.
+//! - Sparse matrices in COO, CSR and CSC formats that interoperate with `DMatrix`.
+//! - A runtime-selectable BLAS/LAPACK [`Backend`], with a pure-Rust reference implementation.
 //!
 //! For guides, tutorials, examples and developer documentation, see the
 //! [TopoHedral-Linalg documentation site](../../).
@@ -29,20 +36,42 @@
 //{{{ dep imports
 //}}}
 //--------------------------------------------------------------------------------------------------
+//{{{ macro: system_only
+/// Compiles the items it wraps only when the system BLAS/LAPACK library is linked, i.e. when the
+/// `system` feature is enabled and `pure_rust` is not. Only the system backend is wrapped in it;
+/// everything else dispatches through the [`Backend`] and is available with either library.
+macro_rules! system_only {
+    ($($item:item)*) => {$(
+        #[cfg(system_backend)]
+        $item
+    )*};
+}
+//}}}
 //{{{ collection: private modules
+mod banded;
 mod blaslapack;
 mod common;
 mod dmatrix;
 mod expression;
 mod float;
+mod iterative;
 mod smatrix;
+mod sparse;
 mod subviews;
+mod triangular;
 //}}}
 //{{{ collection: public API
 pub use crate::float::{Float, FloatTransformOps, FloatVectorOps};
+pub use banded::{BandMatrix, BandMatrixError, TridiagonalMatrix, TridiagonalMatrixError};
+pub use blaslapack::{current_backend, set_backend, with_backend, Backend, ReferenceBackend};
+system_only! {
+    pub use blaslapack::SystemBackend;
+}
 pub use common::{
-    Abs, Complex, Dimension, Field, MatMul, MatrixElementDisplay, MatrixExpr, MatrixOps, ReduceOps,
-    Shape, TransformOps, VectorOps,
+    Abs, AxisReduceOps, Complex, ComplexField, Diagonal, Dimension, Equilibration, Field, MatMul,
+    MatrixElementDisplay, MatrixExpr, MatrixNorms, MatrixNormsError, MatrixOps, ProcrustesMode,
+    RankTolerance, ReduceOps, Shape, Side, SolveExpertOptions, SymEigRange, TransformOps,
+    Transpose, Triangle, VectorOps,
 };
 pub use dmatrix::*;
 pub use expression::comparison_expr::ElementwiseCompare;
@@ -53,9 +82,16 @@ pub use expression::unary_expr::{
     midpoint, min, mul_add, next_down, next_up, powf, powi, recip, rem_euclid, round,
     round_ties_even, signum, sin, sinh, sqrt, tan, tanh, to_degrees, to_radians, trunc,
 };
+pub use iterative::{
+    arnoldi, bicgstab, cg, gmres, lanczos, minres, ArnoldiReturn, FnOperator, IterativeError,
+    IterativeOptions, IterativeReturn, KrylovEigError, KrylovEigOptions, KrylovEigWhich,
+    LanczosReturn, LinearOperator, Preconditioner,
+};
 pub use smatrix::*;
+pub use sparse::{CooMatrix, CscMatrix, CsrMatrix};
 pub use subviews::{
     IndexedMatrixView, IndexedMatrixViewMut, Maskable, MaskedView, MatrixView, MatrixViewMut,
     SubViewable, SubViewableMut,
 };
+pub use triangular::Triangular;
 //}}}
@@ -0,0 +1,147 @@
+//! Reusable Cholesky factorisation of an [`SMatrix`] via LAPACK `dpotrf`/`spotrf` and
+//! `dpotrs`/`spotrs`.
+//!
+//! Provides the `cholesky_factor()` method on square [`SMatrix<T, N, N>`] instances, returning a
+//! [`CholeskyFactor<T, N>`] handle that keeps the lower-triangular factor L in LAPACK's in-place
+//! layout. Any number of right-hand sides `SMatrix<T, N, K>` can then be solved against the stored
+//! factor with `potrs`.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use crate::blaslapack::{
+    cholesky_det_raw, cholesky_factor_raw, cholesky_logdet_raw, cholesky_solve_raw,
+    CholeskyFactorRawError, Potrf, Potrs,
+};
+use crate::common::{ComplexField, Field, One, Zero};
+use crate::smatrix::SMatrix;
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors that can occur when computing or using a Cholesky factorisation.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// The matrix is not positive definite, so no Cholesky factor exists.
+    #[error("Error in cholesky_factor(), matrix is not positive definite")]
+    NotPositiveDefinite,
+    /// Wraps a LAPACK `potrf`/`potrs` error.
+    #[error("Error in cholesky_factor(), exited with error:\n{0}")]
+    PotrsError(CholeskyFactorRawError),
+}
+
+impl From<CholeskyFactorRawError> for Error {
+    fn from(err: CholeskyFactorRawError) -> Self {
+        match err {
+            CholeskyFactorRawError::NotPositiveDefinite(_) => Error::NotPositiveDefinite,
+            err => Error::PotrsError(err),
+        }
+    }
+}
+//}}}
+//{{{ struct: CholeskyFactor
+/// Cholesky factorisation `A = L Lᵀ` of a symmetric positive-definite matrix.
+#[derive(Debug, Clone)]
+pub struct CholeskyFactor<T, const N: usize>
+where
+    T: Field + Copy,
+{
+    /// L on and below the diagonal; the strict upper triangle is not referenced.
+    pub(crate) l: SMatrix<T, N, N>,
+}
+//}}}
+//{{{ impl: CholeskyFactor<T, N>
+#[allow(private_bounds)]
+impl<T, const N: usize> CholeskyFactor<T, N>
+where
+    T: Potrs + Field + Zero + One + Copy,
+{
+    /// Returns the factor in LAPACK's layout: L on and below the diagonal, with the strict upper
+    /// triangle holding the corresponding entries of the original matrix.
+    pub fn packed(&self) -> &SMatrix<T, N, N> {
+        &self.l
+    }
+
+    /// Solves `A X = B` for `X` using the stored factor.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::PotrsError`] if the LAPACK `potrs` routine fails.
+    pub fn solve<const K: usize>(
+        &self,
+        b: &SMatrix<T, N, K>,
+    ) -> Result<SMatrix<T, N, K>, Error> {
+        let mut x = *b;
+        self.solve_in_place(&mut x)?;
+        Ok(x)
+    }
+
+    /// Solves `A X = B` using the stored factor, overwriting `b` with `X`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::PotrsError`] if the LAPACK `potrs` routine fails.
+    pub fn solve_in_place<const K: usize>(
+        &self,
+        b: &mut SMatrix<T, N, K>,
+    ) -> Result<(), Error> {
+        cholesky_solve_raw(self.l.as_slice(), N, b.as_mut_slice(), K)?;
+        Ok(())
+    }
+
+    /// Returns the determinant of `A`, the squared product of the diagonal of L.
+    pub fn det(&self) -> T {
+        cholesky_det_raw(self.l.as_slice(), N)
+    }
+
+    /// Returns the inverse of `A` by solving against the identity.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::PotrsError`] if the LAPACK `potrs` routine fails.
+    pub fn inverse(&self) -> Result<SMatrix<T, N, N>, Error> {
+        let mut inv = SMatrix::<T, N, N>::identity();
+        self.solve_in_place(&mut inv)?;
+        Ok(inv)
+    }
+}
+//}}}
+//{{{ impl: CholeskyFactor<T, N> (log-determinant)
+#[allow(private_bounds)]
+impl<T, const N: usize> CholeskyFactor<T, N>
+where
+    T: Potrs + ComplexField,
+{
+    /// Returns the natural log of the determinant of `A`, twice the sum of the logs of the
+    /// diagonal of L.
+    ///
+    /// The determinant of a positive definite matrix is positive, so no sign is needed.
+    pub fn logdet(&self) -> T::Real {
+        cholesky_logdet_raw(self.l.as_slice(), N)
+    }
+}
+//}}}
+//{{{ impl: SMatrix<T, N, N>
+#[allow(private_bounds)]
+impl<T, const N: usize> SMatrix<T, N, N>
+where
+    T: Potrf + Potrs + Field + Zero + One + Copy,
+{
+    /// Computes a Cholesky factorisation that can be reused for many solves.
+    ///
+    /// Only the lower triangle of `self` is referenced.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NotPositiveDefinite`] if the matrix is not positive definite, or
+    /// [`Error::PotrsError`] if the LAPACK `potrf` routine fails for any other reason.
+    pub fn cholesky_factor(&self) -> Result<CholeskyFactor<T, N>, Error> {
+        let l = cholesky_factor_raw(self.as_slice().to_vec(), N)?;
+        Ok(CholeskyFactor {
+            l: SMatrix::from_col_vec(l),
+        })
+    }
+}
+//}}}
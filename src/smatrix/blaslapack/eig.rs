@@ -1,15 +1,20 @@
-//! General eigendecomposition of an [`SMatrix`] via LAPACK `dgeev`/`sgeev`.
+//! General eigendecomposition of an [`SMatrix`] via LAPACK `?geev`.
 //!
 //! Provides the `eig()` method on square [`SMatrix<T, N, N>`] instances. Returns a const-generic
 //! `Return<T, N>` containing left and right eigenvector matrices (`SMatrix<T, N, N>`) and
-//! eigenvalues as a fixed-size array `[Complex<T>; N]`. Because the dimensions are compile-time
+//! eigenvalues as a fixed-size array `[T::Complex; N]`. Because the dimensions are compile-time
 //! constants, no heap allocation is needed for the result matrices.
+//!
+//! For real input the eigenvector matrices use the packed `geev` layout, where a complex-conjugate
+//! pair occupies two real columns. `Return::left_eigvec`, `Return::right_eigvec` and the
+//! `complex_*_eigvecs` methods unpack them. `eig_right()` and `eig_left()` compute only one side
+//! and return complex eigenvectors directly, and `eigvals()` skips the eigenvectors entirely.
 //--------------------------------------------------------------------------------------------------
 
 //{{{ crate imports
 use crate::blaslapack::AsI32;
 use crate::blaslapack::{eig_raw, EigRawError, Geev};
-use crate::common::{Complex, Field, One, Zero};
+use crate::common::ComplexField;
 use crate::smatrix::SMatrix;
 //}}}
 //{{{ dep imports
@@ -32,14 +37,85 @@ pub enum Error {
 #[derive(Debug)]
 pub struct Return<T, const N: usize>
 where
-    T: Field + Default + Copy,
+    T: ComplexField,
 {
     /// Matrix whose columns are the left eigenvectors.
     pub left_eigvecs: SMatrix<T, N, N>,
     /// Matrix whose columns are the right eigenvectors.
     pub right_eigvecs: SMatrix<T, N, N>,
     /// Complex eigenvalues in the order returned by LAPACK.
-    pub eigvals: [Complex<T>; N],
+    pub eigvals: [T::Complex; N],
+}
+//}}}
+//{{{ impl: Return<T, N>
+#[allow(private_bounds)]
+impl<T, const N: usize> Return<T, N>
+where
+    T: Geev,
+{
+    /// Returns the `i`-th left eigenvector as complex values, pairing the packed real columns of
+    /// a complex-conjugate eigenvalue pair.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i >= N`.
+    pub fn left_eigvec(
+        &self,
+        i: usize,
+    ) -> [T::Complex; N] {
+        assert!(i < N, "eigenvector index {i} out of range for order {N}");
+        let column = T::unpack_eigvec(self.left_eigvecs.as_slice(), &self.eigvals, N, i);
+        std::array::from_fn(|k| column[k])
+    }
+
+    /// Returns the `i`-th right eigenvector as complex values, pairing the packed real columns of
+    /// a complex-conjugate eigenvalue pair.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i >= N`.
+    pub fn right_eigvec(
+        &self,
+        i: usize,
+    ) -> [T::Complex; N] {
+        assert!(i < N, "eigenvector index {i} out of range for order {N}");
+        let column = T::unpack_eigvec(self.right_eigvecs.as_slice(), &self.eigvals, N, i);
+        std::array::from_fn(|k| column[k])
+    }
+
+    /// Returns all left eigenvectors as the columns of a complex matrix.
+    pub fn complex_left_eigvecs(&self) -> SMatrix<T::Complex, N, N> {
+        SMatrix::from_col_vec(T::unpack_eigvecs(
+            self.left_eigvecs.as_slice(),
+            &self.eigvals,
+            N,
+        ))
+    }
+
+    /// Returns all right eigenvectors as the columns of a complex matrix.
+    pub fn complex_right_eigvecs(&self) -> SMatrix<T::Complex, N, N> {
+        SMatrix::from_col_vec(T::unpack_eigvecs(
+            self.right_eigvecs.as_slice(),
+            &self.eigvals,
+            N,
+        ))
+    }
+}
+//}}}
+//{{{ struct: EigVecsReturn
+/// Eigenvalues together with one side (left or right) of complex eigenvectors.
+///
+/// Returned by [`SMatrix::eig_right`] and [`SMatrix::eig_left`]. Column `i` of `eigvecs` belongs
+/// to `eigvals[i]`.
+#[derive(Debug)]
+pub struct EigVecsReturn<T, const N: usize>
+where
+    T: ComplexField,
+{
+    /// Matrix whose columns are the complex eigenvectors.
+    pub eigvecs: SMatrix<T::Complex, N, N>,
+    /// Complex eigenvalues in the order returned by LAPACK.
+    pub eigvals: [T::Complex; N],
 }
 //}}}
 
@@ -47,7 +123,7 @@ where
 #[allow(private_bounds)]
 impl<T, const N: usize> SMatrix<T, N, N>
 where
-    T: One + Zero + Geev + Field + Default + Copy + AsI32,
+    T: Geev + AsI32,
 {
     /// Computes the general (non-symmetric) eigendecomposition of the square matrix.
     ///
@@ -57,13 +133,55 @@ where
     ///
     /// Returns an error if the LAPACK `geev` routine fails.
     pub fn eig(&self) -> Result<Return<T, N>, Error> {
-        let raw = eig_raw(self.as_slice().to_vec(), N)?;
-        let eigvals: [Complex<T>; N] = std::array::from_fn(|i| raw.eigvals[i]);
+        let raw = eig_raw(self.as_slice().to_vec(), N, true, true)?;
+        let eigvals: [T::Complex; N] = std::array::from_fn(|i| raw.eigvals[i]);
         Ok(Return {
             left_eigvecs: SMatrix::from_col_vec(raw.vl),
             right_eigvecs: SMatrix::from_col_vec(raw.vr),
             eigvals,
         })
     }
+
+    /// Computes the eigenvalues and right eigenvectors of the square matrix.
+    ///
+    /// Left eigenvectors are not formed. The returned eigenvectors are complex, with
+    /// complex-conjugate pairs already unpacked, and satisfy `A v = λ v`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the LAPACK `geev` routine fails.
+    pub fn eig_right(&self) -> Result<EigVecsReturn<T, N>, Error> {
+        let raw = eig_raw(self.as_slice().to_vec(), N, false, true)?;
+        Ok(EigVecsReturn {
+            eigvecs: SMatrix::from_col_vec(T::unpack_eigvecs(&raw.vr, &raw.eigvals, N)),
+            eigvals: std::array::from_fn(|i| raw.eigvals[i]),
+        })
+    }
+
+    /// Computes the eigenvalues and left eigenvectors of the square matrix.
+    ///
+    /// Right eigenvectors are not formed. The returned eigenvectors are complex, with
+    /// complex-conjugate pairs already unpacked, and satisfy `u^H A = λ u^H`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the LAPACK `geev` routine fails.
+    pub fn eig_left(&self) -> Result<EigVecsReturn<T, N>, Error> {
+        let raw = eig_raw(self.as_slice().to_vec(), N, true, false)?;
+        Ok(EigVecsReturn {
+            eigvecs: SMatrix::from_col_vec(T::unpack_eigvecs(&raw.vl, &raw.eigvals, N)),
+            eigvals: std::array::from_fn(|i| raw.eigvals[i]),
+        })
+    }
+
+    /// Computes the eigenvalues of the square matrix without forming any eigenvectors.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the LAPACK `geev` routine fails.
+    pub fn eigvals(&self) -> Result<[T::Complex; N], Error> {
+        let raw = eig_raw(self.as_slice().to_vec(), N, false, false)?;
+        Ok(std::array::from_fn(|i| raw.eigvals[i]))
+    }
 }
 //}}}
@@ -0,0 +1,80 @@
+//! Generalized eigendecomposition of an [`SMatrix`] pair via LAPACK `?ggev`.
+//!
+//! Provides the `generalized_eig()` method on square [`SMatrix<T, N, N>`] instances, solving
+//! A x = λ B x with the QZ algorithm. Returns a const-generic `Return<T, N>` holding the complex
+//! right generalized eigenvectors and the eigenvalues as `[T::Complex; N]` arrays of numerators α
+//! and denominators β, with λ = α/β. The implementation mirrors its
+//! [`DMatrix`](crate::dmatrix::DMatrix) counterpart.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use crate::blaslapack::{generalized_eig_raw, AsI32, GeneralizedEigRawError, Ggev};
+use crate::common::ComplexField;
+use crate::smatrix::SMatrix;
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors that can occur during generalized eigendecomposition.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// Wraps a LAPACK `ggev` error from the QZ algorithm.
+    #[error("Error in generalized_eig(), exited with error:\n{0}")]
+    GgevError(#[from] GeneralizedEigRawError),
+}
+//}}}
+
+//{{{ struct: Return
+/// Represents the generalized eigendecomposition of a square matrix pair (A, B).
+#[derive(Debug)]
+pub struct Return<T, const N: usize>
+where
+    T: ComplexField,
+{
+    /// Matrix whose columns are the right generalized eigenvectors.
+    pub eigvecs: SMatrix<T::Complex, N, N>,
+    /// Numerators α of the generalized eigenvalues.
+    pub alpha: [T::Complex; N],
+    /// Denominators β of the generalized eigenvalues, real-valued for real input.
+    pub beta: [T::Complex; N],
+}
+//}}}
+//{{{ impl: Return<T, N>
+impl<T, const N: usize> Return<T, N>
+where
+    T: ComplexField,
+{
+    /// Returns the generalized eigenvalues `α / β`; an infinite eigenvalue comes out non-finite.
+    pub fn eigvals(&self) -> [T::Complex; N] {
+        std::array::from_fn(|i| self.alpha[i] / self.beta[i])
+    }
+}
+//}}}
+
+//{{{ impl: SMatrix<T, N, N>
+#[allow(private_bounds)]
+impl<T, const N: usize> SMatrix<T, N, N>
+where
+    T: Ggev + AsI32,
+{
+    /// Computes the generalized eigendecomposition `A x = λ B x` of the pair (`self`, `b`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the LAPACK `ggev` routine fails.
+    pub fn generalized_eig(
+        &self,
+        b: &SMatrix<T, N, N>,
+    ) -> Result<Return<T, N>, Error> {
+        let raw = generalized_eig_raw(self.as_slice().to_vec(), b.as_slice().to_vec(), N)?;
+        Ok(Return {
+            eigvecs: SMatrix::from_col_vec(raw.eigvecs),
+            alpha: std::array::from_fn(|i| raw.alpha[i]),
+            beta: std::array::from_fn(|i| raw.beta[i]),
+        })
+    }
+}
+//}}}
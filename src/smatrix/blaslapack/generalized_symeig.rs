@@ -0,0 +1,84 @@
+//! Symmetric-definite generalized eigendecomposition of an [`SMatrix`] pair via LAPACK
+//! `?sygv`/`?hegv`.
+//!
+//! Provides the `generalized_symeig()` method on square [`SMatrix<T, N, N>`] instances, solving
+//! A x = λ B x for a symmetric (Hermitian) A and a positive-definite B. Returns a const-generic
+//! `Return<T, N>` with the B-orthonormal eigenvector matrix and the real eigenvalues as a
+//! `[T::Real; N]` array in ascending order.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use crate::blaslapack::{generalized_symeig_raw, AsI32, GeneralizedSymEigRawError, Sygv};
+use crate::common::ComplexField;
+use crate::smatrix::SMatrix;
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors that can occur during symmetric-definite generalized eigendecomposition.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// The matrix B is not positive definite.
+    #[error("Error in generalized_symeig(), matrix B is not positive definite")]
+    NotPositiveDefinite,
+    /// Wraps a LAPACK `sygv` error from the generalized symmetric eigenvalue routine.
+    #[error("Error in generalized_symeig(), exited with error:\n{0}")]
+    SygvError(GeneralizedSymEigRawError),
+}
+
+impl From<GeneralizedSymEigRawError> for Error {
+    fn from(err: GeneralizedSymEigRawError) -> Self {
+        match err {
+            GeneralizedSymEigRawError::NotPositiveDefinite(_) => Error::NotPositiveDefinite,
+            err => Error::SygvError(err),
+        }
+    }
+}
+//}}}
+
+//{{{ struct: Return
+/// Represents the generalized eigendecomposition of a symmetric-definite matrix pair (A, B).
+#[derive(Debug)]
+pub struct Return<T, const N: usize>
+where
+    T: ComplexField,
+{
+    /// Matrix of B-orthonormal eigenvectors (columns are the eigenvectors)
+    pub eigvecs: SMatrix<T, N, N>,
+
+    /// Real eigenvalues
+    pub eigvals: [T::Real; N],
+}
+//}}}
+
+//{{{ impl: SMatrix<T, N, N>
+#[allow(private_bounds)]
+impl<T, const N: usize> SMatrix<T, N, N>
+where
+    T: Sygv + AsI32,
+{
+    /// Computes the generalized eigendecomposition `A x = λ B x` of a symmetric matrix `self`
+    /// and a symmetric positive-definite matrix `b`.
+    ///
+    /// Only the lower triangles are referenced. The eigenvalues are sorted in ascending order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NotPositiveDefinite`] if `b` is not positive definite, or an error if the
+    /// LAPACK routine fails.
+    pub fn generalized_symeig(
+        &self,
+        b: &SMatrix<T, N, N>,
+    ) -> Result<Return<T, N>, Error> {
+        let raw = generalized_symeig_raw(self.as_slice().to_vec(), b.as_slice().to_vec(), N)?;
+        let eigvals: [T::Real; N] = raw.eigvals.try_into().unwrap_or_else(|_| unreachable!());
+        Ok(Return {
+            eigvecs: SMatrix::from_col_vec(raw.eigvecs_data),
+            eigvals,
+        })
+    }
+}
+//}}}
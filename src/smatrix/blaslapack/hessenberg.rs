@@ -0,0 +1,59 @@
+//! Hessenberg reduction of an [`SMatrix`] via LAPACK `?gehrd` and `?orghr`/`?unghr`.
+//!
+//! Provides the `hessenberg()` method on square [`SMatrix<T, N, N>`], computing A = Q H Q^H with Q
+//! orthogonal (unitary) and H upper Hessenberg. The static `Return<T, N>` struct carries H and Q
+//! as [`SMatrix`] instances. The implementation delegates to `Gehrd` and `Orghr` and is the static
+//! counterpart of the [`DMatrix`](crate::dmatrix::DMatrix) Hessenberg reduction.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use crate::blaslapack::{hessenberg_raw, AsI32, Gehrd, HessenbergRawError, Orghr};
+use crate::common::{Field, Zero};
+use crate::smatrix::SMatrix;
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors that can occur during Hessenberg reduction.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// Wraps a LAPACK `gehrd`/`orghr` error from the Hessenberg reduction routines.
+    #[error("Error in hessenberg(), exited with error:\n{0}")]
+    GehrdError(#[from] HessenbergRawError),
+}
+//}}}
+//{{{ struct: Return
+/// Result of a Hessenberg reduction: upper Hessenberg matrix H and orthogonal factor Q.
+pub struct Return<T, const N: usize>
+where
+    T: Field + Copy,
+{
+    /// Upper Hessenberg matrix H, zero below the first subdiagonal.
+    pub h: SMatrix<T, N, N>,
+    /// Orthogonal (unitary) transformation matrix Q such that A = Q H Q^H.
+    pub q: SMatrix<T, N, N>,
+}
+//}}}
+//{{{ impl: SMatrix<T, N, N>
+#[allow(private_bounds)]
+impl<T, const N: usize> SMatrix<T, N, N>
+where
+    T: Zero + Gehrd + Orghr + Field + Copy + AsI32,
+{
+    /// Reduces the matrix to upper Hessenberg form A = Q H Q^H.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the LAPACK `gehrd` or `orghr` routine fails.
+    pub fn hessenberg(&self) -> Result<Return<T, N>, Error> {
+        let raw = hessenberg_raw(self.as_slice().to_vec(), N)?;
+        Ok(Return {
+            h: SMatrix::from_col_vec(raw.h_data),
+            q: SMatrix::from_col_vec(raw.q_data),
+        })
+    }
+}
+//}}}
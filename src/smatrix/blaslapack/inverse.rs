@@ -0,0 +1,173 @@
+//! Matrix inverse of an [`SMatrix`] via closed-form cofactors or LAPACK `dgetri`/`sgetri`.
+//!
+//! Provides the `inverse()` method on square [`SMatrix<T, N, N>`] instances. For N = 2, 3 and 4
+//! the inverse is computed in closed form from the adjugate, avoiding LAPACK call overhead for the
+//! tiny matrices where it dominates; larger matrices are factored by `Getrf` and inverted by
+//! `Getri`. Singular input is reported through the typed [`Error::Singular`] variant on both
+//! paths.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use crate::blaslapack::{inverse_raw, AsI32, Getrf, Getri, InverseRawError};
+use crate::common::{Field, Zero};
+use crate::smatrix::SMatrix;
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors that can occur when inverting a matrix.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// The matrix is exactly singular, so no inverse exists.
+    #[error("Error in inverse(), matrix is singular")]
+    Singular,
+    /// Wraps a LAPACK `getrf`/`getri` error from the inversion routines.
+    #[error("Error in inverse(), exited with error:\n{0}")]
+    GetriError(InverseRawError),
+}
+
+impl From<InverseRawError> for Error {
+    fn from(err: InverseRawError) -> Self {
+        match err {
+            InverseRawError::Singular(_) => Error::Singular,
+            err => Error::GetriError(err),
+        }
+    }
+}
+//}}}
+//{{{ fun: inverse_2x2
+/// Closed-form inverse of a column-major 2×2 matrix, or `None` if it is singular.
+fn inverse_2x2<T>(a: &[T]) -> Option<Vec<T>>
+where
+    T: Field + Zero + Copy,
+{
+    let (a00, a10, a01, a11) = (a[0], a[1], a[2], a[3]);
+    let det = a00 * a11 - a01 * a10;
+    if det == T::zero() {
+        return None;
+    }
+    Some(vec![a11 / det, -a10 / det, -a01 / det, a00 / det])
+}
+//}}}
+//{{{ fun: inverse_3x3
+/// Closed-form inverse of a column-major 3×3 matrix, or `None` if it is singular.
+fn inverse_3x3<T>(a: &[T]) -> Option<Vec<T>>
+where
+    T: Field + Zero + Copy,
+{
+    let at = |i: usize, j: usize| a[i + j * 3];
+    let (a00, a01, a02) = (at(0, 0), at(0, 1), at(0, 2));
+    let (a10, a11, a12) = (at(1, 0), at(1, 1), at(1, 2));
+    let (a20, a21, a22) = (at(2, 0), at(2, 1), at(2, 2));
+
+    let c00 = a11 * a22 - a12 * a21;
+    let c10 = a12 * a20 - a10 * a22;
+    let c20 = a10 * a21 - a11 * a20;
+    let det = a00 * c00 + a01 * c10 + a02 * c20;
+    if det == T::zero() {
+        return None;
+    }
+
+    // Column-major adjugate divided by the determinant
+    Some(vec![
+        c00 / det,
+        c10 / det,
+        c20 / det,
+        (a02 * a21 - a01 * a22) / det,
+        (a00 * a22 - a02 * a20) / det,
+        (a01 * a20 - a00 * a21) / det,
+        (a01 * a12 - a02 * a11) / det,
+        (a02 * a10 - a00 * a12) / det,
+        (a00 * a11 - a01 * a10) / det,
+    ])
+}
+//}}}
+//{{{ fun: inverse_4x4
+/// Closed-form inverse of a column-major 4×4 matrix, or `None` if it is singular.
+///
+/// Uses the expansion of the determinant into products of 2×2 minors of the top two rows (`s*`)
+/// and the bottom two rows (`c*`).
+fn inverse_4x4<T>(a: &[T]) -> Option<Vec<T>>
+where
+    T: Field + Zero + Copy,
+{
+    let at = |i: usize, j: usize| a[i + j * 4];
+    let (a00, a01, a02, a03) = (at(0, 0), at(0, 1), at(0, 2), at(0, 3));
+    let (a10, a11, a12, a13) = (at(1, 0), at(1, 1), at(1, 2), at(1, 3));
+    let (a20, a21, a22, a23) = (at(2, 0), at(2, 1), at(2, 2), at(2, 3));
+    let (a30, a31, a32, a33) = (at(3, 0), at(3, 1), at(3, 2), at(3, 3));
+
+    let s0 = a00 * a11 - a10 * a01;
+    let s1 = a00 * a12 - a10 * a02;
+    let s2 = a00 * a13 - a10 * a03;
+    let s3 = a01 * a12 - a11 * a02;
+    let s4 = a01 * a13 - a11 * a03;
+    let s5 = a02 * a13 - a12 * a03;
+
+    let c0 = a20 * a31 - a30 * a21;
+    let c1 = a20 * a32 - a30 * a22;
+    let c2 = a20 * a33 - a30 * a23;
+    let c3 = a21 * a32 - a31 * a22;
+    let c4 = a21 * a33 - a31 * a23;
+    let c5 = a22 * a33 - a32 * a23;
+
+    let det = s0 * c5 - s1 * c4 + s2 * c3 + s3 * c2 - s4 * c1 + s5 * c0;
+    if det == T::zero() {
+        return None;
+    }
+
+    // Column-major adjugate divided by the determinant
+    Some(vec![
+        (a11 * c5 - a12 * c4 + a13 * c3) / det,
+        (-a10 * c5 + a12 * c2 - a13 * c1) / det,
+        (a10 * c4 - a11 * c2 + a13 * c0) / det,
+        (-a10 * c3 + a11 * c1 - a12 * c0) / det,
+        (-a01 * c5 + a02 * c4 - a03 * c3) / det,
+        (a00 * c5 - a02 * c2 + a03 * c1) / det,
+        (-a00 * c4 + a01 * c2 - a03 * c0) / det,
+        (a00 * c3 - a01 * c1 + a02 * c0) / det,
+        (a31 * s5 - a32 * s4 + a33 * s3) / det,
+        (-a30 * s5 + a32 * s2 - a33 * s1) / det,
+        (a30 * s4 - a31 * s2 + a33 * s0) / det,
+        (-a30 * s3 + a31 * s1 - a32 * s0) / det,
+        (-a21 * s5 + a22 * s4 - a23 * s3) / det,
+        (a20 * s5 - a22 * s2 + a23 * s1) / det,
+        (-a20 * s4 + a21 * s2 - a23 * s0) / det,
+        (a20 * s3 - a21 * s1 + a22 * s0) / det,
+    ])
+}
+//}}}
+//{{{ impl: SMatrix<T, N, N>
+#[allow(private_bounds)]
+impl<T, const N: usize> SMatrix<T, N, N>
+where
+    T: Zero + Getrf + Getri + Field + Copy + AsI32,
+{
+    /// Computes the inverse of the matrix.
+    ///
+    /// Matrices of size 2, 3 and 4 are inverted in closed form; larger matrices use an LU
+    /// factorisation with partial pivoting.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Singular`] if the matrix is exactly singular, or [`Error::GetriError`] if
+    /// the LAPACK routines fail for any other reason.
+    pub fn inverse(&self) -> Result<SMatrix<T, N, N>, Error> {
+        let closed_form = match N {
+            2 => inverse_2x2(self.as_slice()),
+            3 => inverse_3x3(self.as_slice()),
+            4 => inverse_4x4(self.as_slice()),
+            _ => {
+                let data = inverse_raw(self.as_slice().to_vec(), N)?;
+                return Ok(SMatrix::from_col_vec(data));
+            }
+        };
+        closed_form
+            .map(SMatrix::from_col_vec)
+            .ok_or(Error::Singular)
+    }
+}
+//}}}
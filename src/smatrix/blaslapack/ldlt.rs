@@ -0,0 +1,160 @@
+//! Symmetric-indefinite LDLᵀ factorisation of an [`SMatrix`] via LAPACK `?sytrf` and `?sytrs`.
+//!
+//! Provides the `ldlt()` method on square symmetric (complex: Hermitian) [`SMatrix<T, N, N>`]
+//! instances, returning an [`Ldlt<T, N>`] handle for the Bunch–Kaufman factorisation
+//! A = L D Lᵀ with fixed-size storage. The handle exposes D, L and the pivot information, solves
+//! right-hand sides `SMatrix<T, N, K>` with `sytrs`, and reports the inertia and determinant.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use crate::blaslapack::{
+    ldlt_d_raw, ldlt_det_raw, ldlt_factor_raw, ldlt_inertia_raw, ldlt_l_raw, ldlt_solve_raw, AsI32,
+    LdltRawError, Sytrf, Sytrs,
+};
+use crate::common::ComplexField;
+use crate::smatrix::SMatrix;
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors that can occur when computing or using an LDLᵀ factorisation.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// D has an exactly zero 1×1 block, so the factorisation cannot be used to solve.
+    #[error("Error in ldlt(), matrix is singular")]
+    Singular,
+    /// Wraps a LAPACK `sytrf`/`sytrs` error.
+    #[error("Error in ldlt(), exited with error:\n{0}")]
+    SytrsError(LdltRawError),
+}
+
+impl From<LdltRawError> for Error {
+    fn from(err: LdltRawError) -> Self {
+        match err {
+            LdltRawError::Singular(_) => Error::Singular,
+            err => Error::SytrsError(err),
+        }
+    }
+}
+//}}}
+//{{{ struct: Ldlt
+/// Bunch–Kaufman factorisation `A = L D Lᵀ` of a symmetric matrix, stored in LAPACK's compact
+/// form.
+#[derive(Debug, Clone)]
+pub struct Ldlt<T, const N: usize>
+where
+    T: ComplexField,
+{
+    /// D on the block diagonal and the multipliers of L below it.
+    pub(crate) ldl: SMatrix<T, N, N>,
+    /// 1-based pivot information: a positive entry marks a 1×1 block and its interchange, a pair
+    /// of equal negative entries marks a 2×2 block.
+    pub(crate) ipiv: [i32; N],
+}
+//}}}
+//{{{ impl: Ldlt<T, N>
+#[allow(private_bounds)]
+impl<T, const N: usize> Ldlt<T, N>
+where
+    T: Sytrs + ComplexField,
+{
+    /// Returns the packed factors: D on the block diagonal and the multipliers of L below it.
+    pub fn packed(&self) -> &SMatrix<T, N, N> {
+        &self.ldl
+    }
+
+    /// Returns LAPACK's 1-based pivot information, in the layout described on
+    /// [`DLdlt::ipiv`](crate::DLdlt::ipiv).
+    pub fn ipiv(&self) -> &[i32; N] {
+        &self.ipiv
+    }
+
+    /// Returns the block-diagonal factor D, with 1×1 and 2×2 blocks.
+    pub fn d(&self) -> SMatrix<T, N, N> {
+        SMatrix::from_col_vec(ldlt_d_raw(self.ldl.as_slice(), &self.ipiv, N))
+    }
+
+    /// Returns the factor L with the interchanges folded in, so that `A = L D Lᵀ` (`L D Lᴴ` for
+    /// complex types).
+    pub fn l(&self) -> SMatrix<T, N, N> {
+        SMatrix::from_col_vec(ldlt_l_raw(self.ldl.as_slice(), &self.ipiv, N))
+    }
+
+    /// Solves `A X = B` for `X` using the stored factorisation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Singular`] if D is exactly singular, or [`Error::SytrsError`] if the
+    /// LAPACK `sytrs` routine fails.
+    pub fn solve<const K: usize>(
+        &self,
+        b: &SMatrix<T, N, K>,
+    ) -> Result<SMatrix<T, N, K>, Error> {
+        let mut x = *b;
+        self.solve_in_place(&mut x)?;
+        Ok(x)
+    }
+
+    /// Solves `A X = B` using the stored factorisation, overwriting `b` with `X`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Singular`] if D is exactly singular, or [`Error::SytrsError`] if the
+    /// LAPACK `sytrs` routine fails.
+    pub fn solve_in_place<const K: usize>(
+        &self,
+        b: &mut SMatrix<T, N, K>,
+    ) -> Result<(), Error> {
+        ldlt_solve_raw(self.ldl.as_slice(), &self.ipiv, N, b.as_mut_slice(), K)?;
+        Ok(())
+    }
+
+    /// Returns the inertia of `A` as `(positive, negative, zero)` eigenvalue counts.
+    pub fn inertia(&self) -> (usize, usize, usize) {
+        ldlt_inertia_raw(self.ldl.as_slice(), &self.ipiv, N)
+    }
+
+    /// Returns the determinant of `A`.
+    pub fn det(&self) -> T {
+        ldlt_det_raw(self.ldl.as_slice(), &self.ipiv, N)
+    }
+
+    /// Returns the inverse of `A` by solving against the identity.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Singular`] if D is exactly singular, or [`Error::SytrsError`] if the
+    /// LAPACK `sytrs` routine fails.
+    pub fn inverse(&self) -> Result<SMatrix<T, N, N>, Error> {
+        let mut inv = SMatrix::<T, N, N>::identity();
+        self.solve_in_place(&mut inv)?;
+        Ok(inv)
+    }
+}
+//}}}
+//{{{ impl: SMatrix<T, N, N>
+#[allow(private_bounds)]
+impl<T, const N: usize> SMatrix<T, N, N>
+where
+    T: Sytrf + Sytrs + AsI32,
+{
+    /// Computes the symmetric-indefinite factorisation `A = L D Lᵀ` with Bunch–Kaufman pivoting.
+    ///
+    /// Only the lower triangle is referenced. An exactly singular matrix still factors; solving
+    /// with it returns [`Error::Singular`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::SytrsError`] if the LAPACK `sytrf` routine fails.
+    pub fn ldlt(&self) -> Result<Ldlt<T, N>, Error> {
+        let (ldl, ipiv) = ldlt_factor_raw(self.as_slice().to_vec(), N)?;
+        Ok(Ldlt {
+            ldl: SMatrix::from_col_vec(ldl),
+            ipiv: std::array::from_fn(|i| ipiv[i]),
+        })
+    }
+}
+//}}}
@@ -0,0 +1,100 @@
+//! Least-squares solver for [`SMatrix`] via LAPACK `dgels`/`sgels` and `dgelsd`/`sgelsd`.
+//!
+//! Provides the `lstsq()` and `lstsq_svd()` methods on [`SMatrix<T, N, M>`]. For a right-hand
+//! side `SMatrix<T, N, K>` the solution is returned as an `SMatrix<T, M, K>` inside the static
+//! `Return<T, M, K>` struct, so the shape of X is checked at compile time. The computation shares
+//! the QR-based `Gels` and SVD-based `Gelsd` drivers with the [`DMatrix`](crate::dmatrix::DMatrix)
+//! implementation.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use crate::blaslapack::{lstsq_raw, AsI32, Gels, Gelsd, LstsqRawError};
+use crate::common::{Field, Zero};
+use crate::smatrix::SMatrix;
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors that can occur when solving a least-squares problem.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// Wraps a LAPACK `gels`/`gelsd` error from the least-squares routines.
+    #[error("Error in lstsq(), exited with error:\n{0}")]
+    GelsError(#[from] LstsqRawError),
+}
+//}}}
+//{{{ struct: Return
+/// Result of a least-squares solve: the solution, residuals, effective rank and singular values.
+#[derive(Debug)]
+pub struct Return<T, const M: usize, const K: usize>
+where
+    T: Field + Copy,
+{
+    /// Least-squares (or minimum-norm) solution X.
+    pub x: SMatrix<T, M, K>,
+    /// Residual sum of squares for each of the `K` right-hand sides, only populated for
+    /// overdetermined systems of full column rank.
+    pub residuals: Vec<T>,
+    /// Effective rank of A.
+    pub rank: usize,
+    /// Singular values of A in descending order, empty for `lstsq()`.
+    pub s: Vec<T>,
+}
+//}}}
+//{{{ impl: SMatrix<T, N, M>
+#[allow(private_bounds)]
+impl<T, const N: usize, const M: usize> SMatrix<T, N, M>
+where
+    T: Zero + Gels + Gelsd + Field + Copy + AsI32,
+{
+    /// Solves the least-squares problem `min ‖B − A X‖₂` for a full-rank matrix using QR.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the LAPACK `gels` routine fails (e.g., rank-deficient matrix).
+    pub fn lstsq<const K: usize>(
+        &self,
+        b: &SMatrix<T, N, K>,
+    ) -> Result<Return<T, M, K>, Error> {
+        self.lstsq_impl(b, None)
+    }
+
+    /// Solves the least-squares problem `min ‖B − A X‖₂` for the minimum-norm solution using the
+    /// SVD, treating singular values no larger than `rcond * σ_max` as zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the LAPACK `gelsd` routine fails.
+    pub fn lstsq_svd<const K: usize>(
+        &self,
+        b: &SMatrix<T, N, K>,
+        rcond: T,
+    ) -> Result<Return<T, M, K>, Error> {
+        self.lstsq_impl(b, Some(rcond))
+    }
+
+    fn lstsq_impl<const K: usize>(
+        &self,
+        b: &SMatrix<T, N, K>,
+        rcond: Option<T>,
+    ) -> Result<Return<T, M, K>, Error> {
+        let raw = lstsq_raw(
+            self.as_slice().to_vec(),
+            b.as_slice().to_vec(),
+            N,
+            M,
+            K,
+            rcond,
+        )?;
+        Ok(Return {
+            x: SMatrix::from_col_vec(raw.x_data),
+            residuals: raw.residuals,
+            rank: raw.rank,
+            s: raw.s,
+        })
+    }
+}
+//}}}
@@ -0,0 +1,182 @@
+//! Reusable LU factorisation of an [`SMatrix`] via LAPACK `?getrf` and `?getrs`.
+//!
+//! Provides the `lu_factor()` method on square [`SMatrix<T, N, N>`] instances, returning an
+//! [`LuFactor<T, N>`] handle that keeps the packed L and U factors and the 1-based pivot indices
+//! as fixed-size storage. Any number of right-hand sides `SMatrix<T, N, K>` can then be solved
+//! against the stored factorisation with `getrs`.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use crate::blaslapack::{
+    lu_det_raw, lu_factor_raw, lu_rcond_raw, lu_slogdet_raw, lu_solve_raw, Gecon, GeconError,
+    Getrf, Getrs, LuFactorRawError,
+};
+use crate::common::{ComplexField, MatrixNorms};
+use crate::smatrix::SMatrix;
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors that can occur when computing or using an LU factorisation.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// The matrix is exactly singular, so the factorisation cannot be used to solve.
+    #[error("Error in lu_factor(), matrix is singular")]
+    Singular,
+    /// Wraps a LAPACK `getrf`/`getrs` error.
+    #[error("Error in lu_factor(), exited with error:\n{0}")]
+    GetrsError(LuFactorRawError),
+    /// LAPACK `gecon` rejected the stored factors, e.g. because `A` contained NaN.
+    #[error("Error in rcond(), exited with error:\n{0}")]
+    GeconError(#[from] GeconError),
+}
+
+impl From<LuFactorRawError> for Error {
+    fn from(err: LuFactorRawError) -> Self {
+        match err {
+            LuFactorRawError::Singular(_) => Error::Singular,
+            err => Error::GetrsError(err),
+        }
+    }
+}
+//}}}
+//{{{ struct: LuFactor
+/// LU factorisation `PA = LU` of a square matrix, stored in LAPACK's compact form.
+#[derive(Debug, Clone)]
+pub struct LuFactor<T, const N: usize>
+where
+    T: ComplexField,
+{
+    /// Unit lower-triangular L below the diagonal and U on and above it.
+    pub(crate) lu: SMatrix<T, N, N>,
+    /// 1-based pivot indices: row `i` was interchanged with row `ipiv[i]`.
+    pub(crate) ipiv: [i32; N],
+    /// 1-norm of `A`, which the condition number estimate needs alongside the factors.
+    pub(crate) anorm: T::Real,
+}
+//}}}
+//{{{ impl: LuFactor<T, N>
+#[allow(private_bounds)]
+impl<T, const N: usize> LuFactor<T, N>
+where
+    T: Getrs + ComplexField,
+{
+    /// Returns the packed factors: L (without its unit diagonal) below the diagonal and U on and
+    /// above it.
+    pub fn packed(&self) -> &SMatrix<T, N, N> {
+        &self.lu
+    }
+
+    /// Returns LAPACK's 1-based pivot indices.
+    pub fn ipiv(&self) -> &[i32; N] {
+        &self.ipiv
+    }
+
+    /// Solves `A X = B` for `X` using the stored factorisation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::GetrsError`] if the LAPACK `getrs` routine fails.
+    pub fn solve<const K: usize>(
+        &self,
+        b: &SMatrix<T, N, K>,
+    ) -> Result<SMatrix<T, N, K>, Error> {
+        let mut x = *b;
+        self.solve_in_place(&mut x)?;
+        Ok(x)
+    }
+
+    /// Solves `A X = B` using the stored factorisation, overwriting `b` with `X`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::GetrsError`] if the LAPACK `getrs` routine fails.
+    pub fn solve_in_place<const K: usize>(
+        &self,
+        b: &mut SMatrix<T, N, K>,
+    ) -> Result<(), Error> {
+        lu_solve_raw(self.lu.as_slice(), &self.ipiv, N, b.as_mut_slice(), K)?;
+        Ok(())
+    }
+
+    /// Returns the determinant of `A`.
+    pub fn det(&self) -> T {
+        lu_det_raw(self.lu.as_slice(), &self.ipiv, N)
+    }
+
+    /// Returns the inverse of `A` by solving against the identity.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::GetrsError`] if the LAPACK `getrs` routine fails.
+    pub fn inverse(&self) -> Result<SMatrix<T, N, N>, Error> {
+        let mut inv = SMatrix::<T, N, N>::identity();
+        self.solve_in_place(&mut inv)?;
+        Ok(inv)
+    }
+}
+//}}}
+//{{{ impl: LuFactor<T, N> (log-determinant)
+#[allow(private_bounds)]
+impl<T, const N: usize> LuFactor<T, N>
+where
+    T: Getrs + ComplexField,
+{
+    /// Returns the sign and the natural log of the absolute determinant of `A`.
+    ///
+    /// The pair `(sign, logdet)` satisfies `det(A) = sign * exp(logdet)` without forming the
+    /// product of the pivots, so it does not overflow or underflow. For real `T` the sign is ±1;
+    /// for complex `T` it is a unit-modulus phase.
+    pub fn slogdet(&self) -> (T, T::Real) {
+        lu_slogdet_raw(self.lu.as_slice(), &self.ipiv, N)
+    }
+}
+//}}
+//{{ impl: LuFactor<T, N> (condition number)
+#[allow(private_bounds)]
+impl<T, const N: usize> LuFactor<T, N>
+where
+    T: Gecon,
+{
+    /// Returns an estimate of the reciprocal 1-norm condition number of `A`,
+    /// `1 / (‖A‖₁ ‖A⁻¹‖₁)`, from the stored factors.
+    ///
+    /// The estimate costs O(n²) on top of the factorisation already done. Values near `0`
+    /// indicate an ill-conditioned matrix; as in LAPACK `?gecon`, an empty matrix returns `1`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::GeconError`] if the LAPACK `gecon` routine fails, which happens when `A`
+    /// contains NaN.
+    pub fn rcond(&self) -> Result<T::Real, Error> {
+        Ok(lu_rcond_raw(self.lu.as_slice(), N, b'1', self.anorm)?)
+    }
+}
+//}}}
+//{{{ impl: SMatrix<T, N, N>
+#[allow(private_bounds)]
+impl<T, const N: usize> SMatrix<T, N, N>
+where
+    T: Getrf + Getrs + ComplexField,
+{
+    /// Computes an LU factorisation with partial pivoting that can be reused for many solves.
+    ///
+    /// Unlike [`lu`](SMatrix::lu), the factors are kept in LAPACK's compact form.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Singular`] if the matrix is exactly singular, or [`Error::GetrsError`] if
+    /// the LAPACK `getrf` routine fails for any other reason.
+    pub fn lu_factor(&self) -> Result<LuFactor<T, N>, Error> {
+        let (lu, ipiv) = lu_factor_raw(self.as_slice().to_vec(), N)?;
+        Ok(LuFactor {
+            lu: SMatrix::from_col_vec(lu),
+            ipiv: std::array::from_fn(|i| ipiv[i]),
+            anorm: self.norm_one(),
+        })
+    }
+}
+//}}}
@@ -0,0 +1,105 @@
+//! Matrix exponential, logarithm, square root and integer powers of an [`SMatrix`].
+//!
+//! Provides the `expm()`, `logm()`, `sqrtm()` and `powm()` methods on square
+//! [`SMatrix<T, N, N>`] instances, sharing their algorithms with the [`DMatrix`] versions: Padé
+//! scaling and squaring via `Gesv` for the exponential, the Schur form from `Gees` for the
+//! principal logarithm and square root, and repeated squaring for integer powers.
+//!
+//! [`DMatrix`]: crate::DMatrix
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use crate::blaslapack::{expm_raw, logm_raw, powm_raw, sqrtm_raw, MatFun, MatFunRawError};
+use crate::smatrix::SMatrix;
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors that can occur when evaluating a matrix function.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// The matrix has no real principal logarithm.
+    #[error("Error in logm(), matrix has an eigenvalue on the closed negative real axis")]
+    NoRealLogarithm,
+    /// The matrix has no real principal square root.
+    #[error("Error in sqrtm(), matrix has an eigenvalue on the negative real axis")]
+    NoRealSquareRoot,
+    /// A negative power was requested of an exactly singular matrix.
+    #[error("Error in powm(), matrix is singular")]
+    Singular,
+    /// LAPACK `gees` or `gesv` reported a failure.
+    #[error("Error in matrix function, exited with error:\n{0}")]
+    LapackError(MatFunRawError),
+}
+
+impl From<MatFunRawError> for Error {
+    fn from(err: MatFunRawError) -> Self {
+        match err {
+            MatFunRawError::NoRealLogarithm => Error::NoRealLogarithm,
+            MatFunRawError::NoRealSquareRoot => Error::NoRealSquareRoot,
+            MatFunRawError::Singular => Error::Singular,
+            err => Error::LapackError(err),
+        }
+    }
+}
+//}}}
+//{{{ impl: SMatrix<T, N, N>
+#[allow(private_bounds)]
+impl<T, const N: usize> SMatrix<T, N, N>
+where
+    T: MatFun,
+{
+    /// Computes the matrix exponential `e^A`.
+    ///
+    /// Uses scaling and squaring with a Padé approximant of degree 3 to 13, chosen from the
+    /// 1-norm of the matrix.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Singular`] if the Padé denominator is singular, which only happens for
+    /// non-finite input, or [`Error::LapackError`] if `gesv` fails for any other reason.
+    pub fn expm(&self) -> Result<SMatrix<T, N, N>, Error> {
+        Ok(SMatrix::from_col_vec(expm_raw(self.as_slice(), N)?))
+    }
+
+    /// Computes the principal matrix logarithm, the unique real `X` with `e^X = A` whose
+    /// eigenvalues have imaginary parts in `(-π, π)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NoRealLogarithm`] if the matrix has a zero or negative real eigenvalue,
+    /// or [`Error::LapackError`] if `gees` fails.
+    pub fn logm(&self) -> Result<SMatrix<T, N, N>, Error> {
+        Ok(SMatrix::from_col_vec(logm_raw(self.as_slice(), N)?))
+    }
+
+    /// Computes the principal matrix square root, the unique real `X` with `X X = A` whose
+    /// eigenvalues have non-negative real parts.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NoRealSquareRoot`] if the matrix has a negative real eigenvalue or a
+    /// defective zero eigenvalue, or [`Error::LapackError`] if `gees` fails.
+    pub fn sqrtm(&self) -> Result<SMatrix<T, N, N>, Error> {
+        Ok(SMatrix::from_col_vec(sqrtm_raw(self.as_slice(), N)?))
+    }
+
+    /// Computes the integer matrix power `A^k` by repeated squaring.
+    ///
+    /// `powm(0)` is the identity and a negative `k` raises the inverse of the matrix to `-k`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Singular`] if `k` is negative and the matrix is exactly singular, or
+    /// [`Error::LapackError`] if `gesv` fails for any other reason.
+    pub fn powm(
+        &self,
+        k: i32,
+    ) -> Result<SMatrix<T, N, N>, Error> {
+        Ok(SMatrix::from_col_vec(powm_raw(self.as_slice(), N, k)?))
+    }
+}
+//}}}
@@ -12,18 +12,60 @@
 //--------------------------------------------------------------------------------------------------
 
 mod cholesky;
+mod cholesky_factor;
 mod eig;
+mod generalized_eig;
+mod generalized_symeig;
+mod hessenberg;
+mod inverse;
+mod ldlt;
+mod lstsq;
 mod lu;
+mod lu_factor;
 mod matmul;
+mod matrix_functions;
+mod pinv;
+mod polar;
 mod qr;
+mod qr_factor;
+mod qr_pivoted;
+mod rank;
 mod schur;
+mod slogdet;
 mod solve;
+mod solve_expert;
+mod svd;
 mod symeig;
+mod triangular;
 
 pub use cholesky::{Error as SCholeskyError, Return as SCholeskyReturn};
-pub use eig::{Error as SEigError, Return as SEigReturn};
+pub use cholesky_factor::{CholeskyFactor as SCholeskyFactor, Error as SCholeskyFactorError};
+pub use eig::{EigVecsReturn as SEigVecsReturn, Error as SEigError, Return as SEigReturn};
+pub use generalized_eig::{Error as SGeneralizedEigError, Return as SGeneralizedEigReturn};
+pub use generalized_symeig::{
+    Error as SGeneralizedSymEigError, Return as SGeneralizedSymEigReturn,
+};
+pub use hessenberg::{Error as SHessenbergError, Return as SHessenbergReturn};
+pub use inverse::Error as SInverseError;
+pub use ldlt::{Error as SLdltError, Ldlt as SLdlt};
+pub use lstsq::{Error as SLstsqError, Return as SLstsqReturn};
 pub use lu::{Error as SLuError, Return as SLuReturn};
+pub use lu_factor::{Error as SLuFactorError, LuFactor as SLuFactor};
+pub use matrix_functions::Error as SMatrixFunctionError;
+pub use pinv::Error as SPinvError;
+pub use polar::{Error as SPolarError, Return as SPolarReturn};
 pub use qr::{Error as SQrError, Return as SQrReturn};
-pub use schur::{Error as SSchurError, Return as SSchurReturn};
+pub use qr_factor::{Error as SQrFactorError, QrFactor as SQrFactor};
+pub use qr_pivoted::{Error as SQrPivotedError, Return as SQrPivotedReturn};
+pub use rank::Error as SRankError;
+pub use schur::{
+    Error as SSchurError, OrderedReturn as SSchurOrderedReturn, Return as SSchurReturn,
+};
+pub use slogdet::Error as SSlogdetError;
 pub use solve::Error as SSolveError;
-pub use symeig::{Error as SSymEigError, Return as SSymEigReturn};
+pub use solve_expert::{Error as SSolveExpertError, Return as SSolveExpertReturn};
+pub use svd::{Error as SSvdError, Return as SSvdReturn};
+pub use symeig::{
+    Error as SSymEigError, RangeReturn as SSymEigRangeReturn, Return as SSymEigReturn,
+};
+pub use triangular::Error as STriangularSolveError;
@@ -0,0 +1,49 @@
+//! Moore–Penrose pseudo-inverse of an [`SMatrix`] via the singular value decomposition.
+//!
+//! Provides the `pinv()` method on [`SMatrix<T, N, M>`], returning the `SMatrix<T, M, N>`
+//! pseudo-inverse A⁺ = V Σ⁺ Uᵀ with the transposed shape encoded at the type level. The
+//! implementation shares the SVD-based algorithm of its [`DMatrix`](crate::dmatrix::DMatrix)
+//! counterpart.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use crate::blaslapack::{pinv_raw, AsI32, Gemm, Gesdd, Gesvd, SvdRawError};
+use crate::common::{Field, One, Zero};
+use crate::smatrix::SMatrix;
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors that can occur when computing a pseudo-inverse.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// Wraps a LAPACK `gesdd`/`gesvd` error from the SVD routines.
+    #[error("Error in pinv(), exited with error:\n{0}")]
+    GesddError(#[from] SvdRawError),
+}
+//}}}
+//{{{ impl: SMatrix<T, N, M>
+#[allow(private_bounds)]
+impl<T, const N: usize, const M: usize> SMatrix<T, N, M>
+where
+    T: One + Zero + Gesdd<Real = T> + Gesvd<Real = T> + Gemm + Field + PartialOrd + Copy + AsI32,
+{
+    /// Computes the Moore–Penrose pseudo-inverse of the matrix.
+    ///
+    /// Singular values no larger than `rcond * σ_max` are treated as zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the LAPACK `gesdd` routine and its `gesvd` fallback fail.
+    pub fn pinv(
+        &self,
+        rcond: T,
+    ) -> Result<SMatrix<T, M, N>, Error> {
+        let data = pinv_raw(self.as_slice().to_vec(), N, M, rcond)?;
+        Ok(SMatrix::from_col_vec(data))
+    }
+}
+//}}}
@@ -0,0 +1,95 @@
+//! Polar decomposition and orthogonal Procrustes solver for [`SMatrix`] via the singular value
+//! decomposition.
+//!
+//! Provides the `polar()` method on square [`SMatrix<T, N, N>`] instances, factoring A = U P
+//! with U orthogonal and P symmetric positive semidefinite, and the `procrustes()` method on
+//! [`SMatrix<T, N, M>`], which finds the orthogonal M×M matrix that best maps the rows of one
+//! matrix onto another. This is the static-size counterpart of the
+//! [`DMatrix`](crate::dmatrix::DMatrix) polar decomposition, suited to 3×3 and 4×4 registration
+//! problems.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use crate::blaslapack::{
+    polar_raw, procrustes_raw, AsI32, Gemm, Gesdd, Gesvd, Getrf, PolarRaw, PolarRawError,
+};
+use crate::common::{Field, One, ProcrustesMode, Zero};
+use crate::float::Float;
+use crate::smatrix::SMatrix;
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors that can occur during the polar decomposition or a Procrustes solve.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// Wraps a LAPACK `gesdd`/`gesvd` or `getrf` error from the underlying routines.
+    #[error("Error in polar(), exited with error:\n{0}")]
+    GesddError(#[from] PolarRawError),
+}
+//}}}
+
+//{{{ struct: Return
+/// Represents the polar decomposition `A = U P` of a square matrix.
+pub struct Return<T, const N: usize>
+where
+    T: Field + Copy,
+{
+    /// Orthogonal factor U, the orthogonal matrix nearest to A in the Frobenius norm.
+    pub u: SMatrix<T, N, N>,
+    /// Symmetric positive semidefinite factor P = (AᵀA)^½.
+    pub p: SMatrix<T, N, N>,
+}
+//}}}
+
+//{{{ impl: SMatrix<T, N, N>
+#[allow(private_bounds)]
+impl<T, const N: usize> SMatrix<T, N, N>
+where
+    T: Gesdd<Real = T> + Gesvd<Real = T> + Gemm + Getrf + Float + Zero + One + Copy + AsI32,
+{
+    /// Computes the polar decomposition of the matrix.
+    ///
+    /// Factors `self` into `U` and `P` such that `A = U P`. `P` is unique, and `U` is unique when
+    /// `A` is nonsingular.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the LAPACK `gesdd` routine and its `gesvd` fallback fail.
+    pub fn polar(&self) -> Result<Return<T, N>, Error> {
+        let PolarRaw { u_data, p_data } = polar_raw(self.as_slice().to_vec(), N)?;
+        Ok(Return {
+            u: SMatrix::from_col_vec(u_data),
+            p: SMatrix::from_col_vec(p_data),
+        })
+    }
+}
+//}}}
+//{{{ impl: SMatrix<T, N, M>
+#[allow(private_bounds)]
+impl<T, const N: usize, const M: usize> SMatrix<T, N, M>
+where
+    T: Gesdd<Real = T> + Gesvd<Real = T> + Gemm + Getrf + Float + Zero + One + Copy + AsI32,
+{
+    /// Solves the orthogonal Procrustes problem for `self` (A) and `b` (B).
+    ///
+    /// Returns the orthogonal `R` minimising `‖A R − B‖_F`, the best orthogonal map of the rows of
+    /// A onto the rows of B. [`ProcrustesMode::Rotation`] forbids reflections, so that
+    /// `det(R) = 1`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the LAPACK SVD drivers or the LU factorisation fail.
+    pub fn procrustes(
+        &self,
+        b: &SMatrix<T, N, M>,
+        mode: ProcrustesMode,
+    ) -> Result<SMatrix<T, M, M>, Error> {
+        let data = procrustes_raw(self.as_slice(), b.as_slice(), N, M, mode)?;
+        Ok(SMatrix::from_col_vec(data))
+    }
+}
+//}}}
@@ -0,0 +1,166 @@
+//! Reusable QR factorisation of an [`SMatrix`] via LAPACK `?geqrf`, `?ormqr` and `?trtrs`.
+//!
+//! Provides the `qr_factor()` method on [`SMatrix<T, N, M>`] instances with `N >= M`, returning a
+//! [`QrFactor<T, N, M>`] handle that keeps R, the Householder vectors and their `tau` scalars in
+//! LAPACK's compact form. Each solve applies Qᵀ with `ormqr` and back-substitutes with R using
+//! `trtrs`; for a tall matrix the solve returns the least-squares solution.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use crate::blaslapack::{
+    qr_det_raw, qr_factor_raw, qr_solve_raw, AsI32, Geqrf, Ormqr, QrFactorRawError, Trtrs,
+};
+use crate::common::ComplexField;
+use crate::smatrix::SMatrix;
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors that can occur when computing or using a QR factorisation.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// R has an exactly zero diagonal entry, so the columns of the matrix are linearly dependent.
+    #[error("Error in qr_factor(), matrix is rank deficient")]
+    RankDeficient,
+    /// Wraps a LAPACK `geqrf`/`ormqr`/`trtrs` error.
+    #[error("Error in qr_factor(), exited with error:\n{0}")]
+    OrmqrError(QrFactorRawError),
+}
+
+impl From<QrFactorRawError> for Error {
+    fn from(err: QrFactorRawError) -> Self {
+        match err {
+            QrFactorRawError::RankDeficient(_) => Error::RankDeficient,
+            err => Error::OrmqrError(err),
+        }
+    }
+}
+//}}}
+//{{{ struct: QrFactor
+/// QR factorisation `A = QR` of an N×M matrix with `N >= M`, stored in LAPACK's compact form.
+#[derive(Debug, Clone)]
+pub struct QrFactor<T, const N: usize, const M: usize>
+where
+    T: ComplexField,
+{
+    /// R on and above the diagonal, Householder vectors below it.
+    pub(crate) qr: SMatrix<T, N, M>,
+    /// Scalar factors of the Householder reflectors.
+    pub(crate) tau: [T; M],
+}
+//}}}
+//{{{ impl: QrFactor<T, N, M>
+#[allow(private_bounds)]
+impl<T, const N: usize, const M: usize> QrFactor<T, N, M>
+where
+    T: Ormqr + Trtrs + ComplexField + AsI32,
+{
+    /// Returns the packed factors: R on and above the diagonal and the Householder vectors
+    /// (without their unit leading entries) below it.
+    pub fn packed(&self) -> &SMatrix<T, N, M> {
+        &self.qr
+    }
+
+    /// Returns the scalar factors of the Householder reflectors.
+    pub fn tau(&self) -> &[T; M] {
+        &self.tau
+    }
+
+    /// Solves `A X = B` for `X` using the stored factorisation.
+    ///
+    /// For a tall `A` this is the least-squares solution minimising `‖A X - B‖₂`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::RankDeficient`] if R is singular, or [`Error::OrmqrError`] if the LAPACK
+    /// routines fail for any other reason.
+    pub fn solve<const K: usize>(
+        &self,
+        b: &SMatrix<T, N, K>,
+    ) -> Result<SMatrix<T, M, K>, Error> {
+        let mut qtb = *b;
+        self.solve_in_place(&mut qtb)?;
+        let mut x = SMatrix::<T, M, K>::zeros();
+        for j in 0..K {
+            for i in 0..M {
+                x[(i, j)] = qtb[(i, j)];
+            }
+        }
+        Ok(x)
+    }
+
+    /// Solves `A X = B` using the stored factorisation, overwriting `b`.
+    ///
+    /// On exit the leading `M` rows of `b` hold `X`; for a tall `A` the remaining rows hold the
+    /// components of `QᵀB` orthogonal to the range of `A`, whose squared norm is the
+    /// least-squares residual.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::RankDeficient`] if R is singular, or [`Error::OrmqrError`] if the LAPACK
+    /// routines fail for any other reason.
+    pub fn solve_in_place<const K: usize>(
+        &self,
+        b: &mut SMatrix<T, N, K>,
+    ) -> Result<(), Error> {
+        qr_solve_raw(self.qr.as_slice(), &self.tau, N, M, b.as_mut_slice(), K)?;
+        Ok(())
+    }
+
+    /// Returns the determinant of a square `A`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `A` is not square.
+    pub fn det(&self) -> T {
+        assert_eq!(N, M, "Determinant is only defined for square matrices");
+        qr_det_raw(self.qr.as_slice(), &self.tau, N)
+    }
+
+    /// Returns the inverse of a square `A` by solving against the identity.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::RankDeficient`] if R is singular, or [`Error::OrmqrError`] if the LAPACK
+    /// routines fail for any other reason.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `A` is not square.
+    pub fn inverse(&self) -> Result<SMatrix<T, N, N>, Error> {
+        assert_eq!(N, M, "Matrix must be square for inversion");
+        let mut inv = SMatrix::<T, N, N>::identity();
+        self.solve_in_place(&mut inv)?;
+        Ok(inv)
+    }
+}
+//}}}
+//{{{ impl: SMatrix<T, N, M>
+#[allow(private_bounds)]
+impl<T, const N: usize, const M: usize> SMatrix<T, N, M>
+where
+    T: Geqrf + Ormqr + Trtrs + ComplexField + AsI32,
+{
+    /// Computes a Householder QR factorisation that can be reused for many solves.
+    ///
+    /// Unlike [`qr`](SMatrix::qr), Q is kept implicitly as Householder reflectors. Requires
+    /// `N >= M`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OrmqrError`] if the LAPACK `geqrf` routine fails.
+    pub fn qr_factor(&self) -> Result<QrFactor<T, N, M>, Error> {
+        if N < M {
+            panic!("Matrix must have at least as many rows as columns for a QR factorisation");
+        }
+        let (qr, tau) = qr_factor_raw(self.as_slice().to_vec(), N, M)?;
+        Ok(QrFactor {
+            qr: SMatrix::from_col_vec(qr),
+            tau: std::array::from_fn(|i| tau[i]),
+        })
+    }
+}
+//}}}
@@ -0,0 +1,74 @@
+//! Rank-revealing QR decomposition of an [`SMatrix`] via LAPACK `?geqp3` and `?orgqr`.
+//!
+//! Provides the `qr_pivoted()` method on [`SMatrix<T, N, M>`], computing A P = Q R with a column
+//! permutation P that orders the diagonal of R by non-increasing modulus. The static
+//! `Return<T, N, M>` struct carries the N×N factor Q, the N×M factor R, the permutation as a
+//! `[usize; M]` index array and the numerical rank for a caller-supplied tolerance. The
+//! implementation mirrors its [`DMatrix`](crate::dmatrix::DMatrix) counterpart.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use crate::blaslapack::{qr_pivoted_raw, AsI32, Geqp3, Orgqr, QrPivotedRawError};
+use crate::common::ComplexField;
+use crate::smatrix::SMatrix;
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors that can occur during a column-pivoted QR decomposition.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// Wraps a LAPACK `geqp3`/`orgqr` error from the factorisation steps.
+    #[error("Error in qr_pivoted(), exited with error:\n{0}")]
+    Geqp3Error(#[from] QrPivotedRawError),
+}
+//}}}
+//{{{ struct: Return
+/// Result of a column-pivoted QR decomposition `A P = Q R`.
+pub struct Return<T, const N: usize, const M: usize>
+where
+    T: ComplexField,
+{
+    /// Orthogonal (unitary) factor Q.
+    pub q: SMatrix<T, N, N>,
+    /// Upper-trapezoidal factor R with non-increasing diagonal moduli.
+    pub r: SMatrix<T, N, M>,
+    /// Column permutation: column `j` of `A P` is column `perm[j]` of `A`.
+    pub perm: [usize; M],
+    /// Numerical rank: the number of diagonal entries of R with `|R_ii| > tol · |R_00|`.
+    pub rank: usize,
+}
+//}}}
+//{{{ impl: SMatrix<T, N, M>
+#[allow(private_bounds)]
+impl<T, const N: usize, const M: usize> SMatrix<T, N, M>
+where
+    T: Geqp3 + Orgqr + AsI32,
+{
+    /// Computes the rank-revealing QR decomposition `A P = QR` with column pivoting.
+    ///
+    /// The numerical rank counts the diagonal entries of R whose modulus exceeds `tol` times that
+    /// of the first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either the LAPACK `geqp3` or `orgqr` routine fails.
+    pub fn qr_pivoted(
+        &self,
+        tol: T::Real,
+    ) -> Result<Return<T, N, M>, Error> {
+        let raw = qr_pivoted_raw(self.as_slice().to_vec(), N, M, tol)?;
+        let mut perm = [0; M];
+        perm.copy_from_slice(&raw.perm);
+        Ok(Return {
+            q: SMatrix::from_col_vec(raw.q_data),
+            r: SMatrix::from_col_vec(raw.r_data),
+            perm,
+            rank: raw.rank,
+        })
+    }
+}
+//}}}
@@ -0,0 +1,89 @@
+//! Numerical rank, null space and range basis of an [`SMatrix`] via the singular value
+//! decomposition.
+//!
+//! Provides the `rank()`, `null_space()` and `orth()` methods on [`SMatrix<T, N, M>`] instances.
+//! The singular values above a [`RankTolerance`] cutoff, computed by the `Gesdd` LAPACK driver,
+//! give the numerical rank. The number of basis vectors depends on that rank rather than on the
+//! shape, so the bases are returned as [`DMatrix`] columns.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use crate::blaslapack::{null_space_raw, orth_raw, rank_raw, AsI32, Gesdd, Gesvd, SvdRawError};
+use crate::common::{One, RankTolerance, Zero};
+use crate::dmatrix::DMatrix;
+use crate::float::Float;
+use crate::smatrix::SMatrix;
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors that can occur when computing the rank or a subspace basis.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// Wraps a LAPACK `gesdd`/`gesvd` error from the SVD routines.
+    #[error("Error in rank(), exited with error:\n{0}")]
+    GesddError(#[from] SvdRawError),
+}
+//}}}
+//{{{ impl: SMatrix<T, N, M>
+#[allow(private_bounds)]
+impl<T, const N: usize, const M: usize> SMatrix<T, N, M>
+where
+    T: Gesdd<Real = T> + Gesvd<Real = T> + Float + Zero + One + Copy + AsI32,
+{
+    /// Computes the numerical rank of the matrix.
+    ///
+    /// The rank is the number of singular values above the cutoff selected by `tol`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the LAPACK `gesdd` routine and its `gesvd` fallback fail.
+    pub fn rank(
+        &self,
+        tol: RankTolerance<T>,
+    ) -> Result<usize, Error> {
+        Ok(rank_raw(self.as_slice().to_vec(), N, M, tol)?)
+    }
+
+    /// Computes an orthonormal basis for the null space of the matrix.
+    ///
+    /// For a matrix of numerical rank `r` the result is an `M x (M - r)` [`DMatrix`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the LAPACK `gesdd` routine and its `gesvd` fallback fail.
+    pub fn null_space(
+        &self,
+        tol: RankTolerance<T>,
+    ) -> Result<DMatrix<T>, Error> {
+        let (data, nullity) = null_space_raw(self.as_slice().to_vec(), N, M, tol)?;
+        Ok(DMatrix {
+            data,
+            nrows: M,
+            ncols: nullity,
+        })
+    }
+
+    /// Computes an orthonormal basis for the range (column space) of the matrix.
+    ///
+    /// For a matrix of numerical rank `r` the result is an `N x r` [`DMatrix`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the LAPACK `gesdd` routine and its `gesvd` fallback fail.
+    pub fn orth(
+        &self,
+        tol: RankTolerance<T>,
+    ) -> Result<DMatrix<T>, Error> {
+        let (data, rank) = orth_raw(self.as_slice().to_vec(), N, M, tol)?;
+        Ok(DMatrix {
+            data,
+            nrows: N,
+            ncols: rank,
+        })
+    }
+}
+//}}}
@@ -3,12 +3,16 @@
 //! Provides the `schur()` method on [`SMatrix<T, N, M>`], computing A = Q T Q^H. The static
 //! `Return<T, N, M>` struct carries Q and T as [`SMatrix`] instances with compile-time dimensions.
 //! The implementation delegates to `Gees` and is the static counterpart of the [`DMatrix`](crate::dmatrix::DMatrix)
-//! Schur decomposition.
+//! Schur decomposition. `schur_ordered()` on square matrices reorders the factorisation with
+//! `Trsen` so that the eigenvalues accepted by a predicate lead the diagonal of T.
 //--------------------------------------------------------------------------------------------------
 
 //{{{ crate imports
-use crate::blaslapack::{schur_raw, Gees, ShurRawError};
-use crate::common::{Field, One, Zero};
+use crate::blaslapack::{
+    schur_ordered_raw, schur_raw, AsI32, Gees, SchurOrderedRawError, ShurRawError, Trsen,
+    TrsenRawError,
+};
+use crate::common::{Complex, Field, One, Zero};
 use crate::smatrix::SMatrix;
 //}}}
 //{{{ dep imports
@@ -23,6 +27,22 @@ pub enum Error {
     /// Wraps a LAPACK `gees` error from the Schur factorisation routine.
     #[error("Error in schur(), exited with error:\n{0}")]
     GeesError(#[from] ShurRawError),
+    /// The selected eigenvalues are too close to the others for `trsen` to reorder reliably.
+    #[error("Error in schur_ordered(), selected eigenvalues are too close to reorder")]
+    ReorderFailed,
+    /// Wraps a LAPACK `trsen` error from the Schur reordering routine.
+    #[error("Error in schur_ordered(), exited with error:\n{0}")]
+    TrsenError(SchurOrderedRawError),
+}
+
+impl From<SchurOrderedRawError> for Error {
+    fn from(err: SchurOrderedRawError) -> Self {
+        match err {
+            SchurOrderedRawError::Gees(err) => Error::GeesError(err),
+            SchurOrderedRawError::Trsen(TrsenRawError::LapackError(1)) => Error::ReorderFailed,
+            err => Error::TrsenError(err),
+        }
+    }
 }
 //}}}
 //{{{ struct: Return
@@ -37,6 +57,21 @@ where
     pub t: SMatrix<T, N, M>,
 }
 //}}}
+//{{{ struct: OrderedReturn
+/// Result of a reordered Schur decomposition, with the selected eigenvalues leading the diagonal.
+pub struct OrderedReturn<T, const N: usize>
+where
+    T: Field + Copy,
+{
+    /// Orthogonal transformation matrix Q; its first `sdim` columns span the selected invariant
+    /// subspace.
+    pub q: SMatrix<T, N, N>,
+    /// Quasi-upper-triangular Schur matrix T.
+    pub t: SMatrix<T, N, N>,
+    /// Number of selected eigenvalues, counting both members of a complex-conjugate pair.
+    pub sdim: usize,
+}
+//}}}
 //{{{ impl: SMatrix<T, N, M>
 #[allow(private_bounds)]
 impl<T, const N: usize, const M: usize> SMatrix<T, N, M>
@@ -58,6 +93,37 @@ where
 }
 //}}}
 
+//{{{ impl: SMatrix<T, N, N>
+#[allow(private_bounds)]
+impl<T, const N: usize> SMatrix<T, N, N>
+where
+    T: One + Zero + Gees + Trsen + Field + Default + Copy + AsI32,
+{
+    /// Computes the Schur decomposition with the eigenvalues accepted by `select` ordered first.
+    ///
+    /// Selecting either member of a complex-conjugate pair selects both.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ReorderFailed`] if the selected eigenvalues cannot be reordered reliably,
+    /// or another variant if the LAPACK `gees` or `trsen` routines fail.
+    pub fn schur_ordered<F>(
+        &self,
+        select: F,
+    ) -> Result<OrderedReturn<T, N>, Error>
+    where
+        F: FnMut(Complex<T>) -> bool,
+    {
+        let raw = schur_ordered_raw(self.as_slice().to_vec(), N, select)?;
+        Ok(OrderedReturn {
+            q: SMatrix::from_col_vec(raw.q_data),
+            t: SMatrix::from_col_vec(raw.t_data),
+            sdim: raw.sdim,
+        })
+    }
+}
+//}}}
+
 //-------------------------------------------------------------------------------------------------
 //{{{ mod: tests
 #[cfg(test)]
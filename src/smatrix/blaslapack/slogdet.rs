@@ -0,0 +1,50 @@
+//! Sign and log-determinant of an [`SMatrix`] via LAPACK `?potrf` or `?getrf`.
+//!
+//! Provides the `slogdet()` method on square [`SMatrix<T, N, N>`] instances, returning the
+//! determinant as a sign and the natural log of its absolute value. Summing logs of the factor
+//! diagonal keeps the result finite where the product of the pivots would overflow or underflow.
+//! Symmetric positive definite input takes a Cholesky fast path through `Potrf`; everything else
+//! is factored by `Getrf`. LAPACK errors propagate as a typed `Error`.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use crate::blaslapack::{slogdet_raw, Getrf, LuFactorRawError, Potrf};
+use crate::common::ComplexField;
+use crate::smatrix::SMatrix;
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors that can occur when computing the log-determinant.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// Wraps a LAPACK `getrf` error from the LU factorisation.
+    #[error("Error in slogdet(), exited with error:\n{0}")]
+    GetrfError(#[from] LuFactorRawError),
+}
+//}}}
+//{{{ impl: SMatrix<T, N, N>
+#[allow(private_bounds)]
+impl<T, const N: usize> SMatrix<T, N, N>
+where
+    T: Getrf + Potrf + ComplexField,
+{
+    /// Computes the sign and the natural log of the absolute determinant.
+    ///
+    /// Returns `(sign, logdet)` with `det(A) = sign * exp(logdet)`. Exactly symmetric (complex:
+    /// Hermitian) input is first tried with a Cholesky factorisation, which gives `sign = 1` when
+    /// it succeeds; otherwise an LU factorisation with partial pivoting is used and `sign` is ±1
+    /// for real `T` and a complex number of modulus 1 for complex `T`. A singular matrix returns
+    /// `(0, -∞)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::GetrfError`] if the LAPACK `getrf` routine fails with an invalid argument.
+    pub fn slogdet(&self) -> Result<(T, T::Real), Error> {
+        Ok(slogdet_raw(self.as_slice().to_vec(), N)?)
+    }
+}
+//}}}
@@ -0,0 +1,99 @@
+//! Expert linear system solver for [`SMatrix`] via LAPACK `?gesvx`.
+//!
+//! Provides the `solve_expert` method on square [`SMatrix<T, N, N>`], solving op(A) X = B for any
+//! number of right-hand sides K with the options in [`SolveExpertOptions`]. The static
+//! `Return<T, N, K>` struct carries the solution as an [`SMatrix`] and the per-column error bounds
+//! as fixed-size arrays. It is the static counterpart of the
+//! [`DMatrix`](crate::dmatrix::DMatrix) expert solver.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use crate::blaslapack::{solve_expert_raw, Gecon, Gesvx, Getrf, Getrs, SolveExpertRawError};
+use crate::common::{ComplexField, Equilibration, SolveExpertOptions};
+use crate::smatrix::SMatrix;
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors that can occur when solving a linear system with the expert driver.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// The LU factorisation has an exactly zero pivot, so no solution was computed.
+    #[error("Error in solve_expert(), matrix is exactly singular")]
+    Singular,
+    /// Wraps a LAPACK `gesvx` error from the expert solve routine.
+    #[error("Error in solve_expert(), exited with error:\n{0}")]
+    GesvxError(SolveExpertRawError),
+}
+
+impl From<SolveExpertRawError> for Error {
+    fn from(err: SolveExpertRawError) -> Self {
+        match err {
+            SolveExpertRawError::Singular(_) => Error::Singular,
+            err => Error::GesvxError(err),
+        }
+    }
+}
+//}}}
+//{{{ struct: Return
+/// Solution of a linear system with K right-hand sides together with its quality report.
+pub struct Return<T, const N: usize, const K: usize>
+where
+    T: ComplexField,
+{
+    /// Solution matrix X.
+    pub x: SMatrix<T, N, K>,
+    /// Reciprocal condition number estimate of the (equilibrated) matrix.
+    pub rcond: T::Real,
+    /// Estimated relative forward error bound for each column of X, if the solution was refined.
+    pub ferr: Option<[T::Real; K]>,
+    /// Componentwise relative backward error of each column of X, if the solution was refined.
+    pub berr: Option<[T::Real; K]>,
+    /// Reciprocal pivot growth factor `‖A‖ / ‖U‖`.
+    pub rpvgrw: T::Real,
+    /// Scaling applied to A before it was factored.
+    pub equilibration: Equilibration,
+    /// Whether A is singular to working precision.
+    pub singular: bool,
+}
+//}}}
+//{{{ impl: SMatrix<T, N, N>
+#[allow(private_bounds)]
+impl<T, const N: usize> SMatrix<T, N, N>
+where
+    T: Gesvx + Getrf + Getrs + Gecon,
+{
+    /// Solves `op(A) X = B` with the LAPACK expert driver, reporting the quality of the solution.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Singular`] if the matrix has an exactly zero pivot, or another variant if
+    /// the LAPACK routines fail. A matrix that is only singular to working precision is
+    /// not an error; see [`Return::singular`].
+    pub fn solve_expert<const K: usize>(
+        &self,
+        b: &SMatrix<T, N, K>,
+        options: SolveExpertOptions,
+    ) -> Result<Return<T, N, K>, Error> {
+        let raw = solve_expert_raw(
+            self.as_slice().to_vec(),
+            b.as_slice().to_vec(),
+            N,
+            K,
+            options,
+        )?;
+        Ok(Return {
+            x: SMatrix::from_col_vec(raw.x_data),
+            rcond: raw.rcond,
+            ferr: raw.ferr.map(|ferr| std::array::from_fn(|j| ferr[j])),
+            berr: raw.berr.map(|berr| std::array::from_fn(|j| berr[j])),
+            rpvgrw: raw.rpvgrw,
+            equilibration: raw.equilibration,
+            singular: raw.singular,
+        })
+    }
+}
+//}}}
@@ -0,0 +1,75 @@
+//! Singular value decomposition of an [`SMatrix`] via LAPACK `dgesdd`/`sgesdd`.
+//!
+//! Provides the `svd()` and `singular_values()` methods on [`SMatrix<T, N, M>`]. The static
+//! `Return<T, N, M>` struct carries the full factors U (`SMatrix<T, N, N>`) and Vᵀ
+//! (`SMatrix<T, M, M>`) with compile-time dimensions; the `min(N, M)` singular values are
+//! returned in descending order. The thin factorisation is only offered on
+//! [`DMatrix`](crate::dmatrix::DMatrix), since its shapes cannot be expressed with const generics.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use crate::blaslapack::{svd_raw, AsI32, Gesdd, Gesvd, SvdRawError};
+use crate::common::{Field, Zero};
+use crate::smatrix::SMatrix;
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors that can occur during singular value decomposition.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// Wraps a LAPACK `gesdd`/`gesvd` error from the SVD routines.
+    #[error("Error in svd(), exited with error:\n{0}")]
+    GesddError(#[from] SvdRawError),
+}
+//}}}
+//{{{ struct: Return
+/// Result of a singular value decomposition: orthogonal factors U and Vᵀ and the singular values.
+#[derive(Debug)]
+pub struct Return<T, const N: usize, const M: usize>
+where
+    T: Field + Copy,
+{
+    /// Orthogonal factor U whose columns are the left singular vectors.
+    pub u: SMatrix<T, N, N>,
+    /// The `min(N, M)` singular values in descending order.
+    pub s: Vec<T>,
+    /// Orthogonal factor Vᵀ whose rows are the right singular vectors.
+    pub vt: SMatrix<T, M, M>,
+}
+//}}}
+//{{{ impl: SMatrix<T, N, M>
+#[allow(private_bounds)]
+impl<T, const N: usize, const M: usize> SMatrix<T, N, M>
+where
+    T: Zero + Gesdd<Real = T> + Gesvd<Real = T> + Field + Copy + AsI32,
+{
+    /// Computes the full singular value decomposition A = U Σ Vᵀ of the matrix.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the LAPACK `gesdd` routine and its `gesvd` fallback fail.
+    pub fn svd(&self) -> Result<Return<T, N, M>, Error> {
+        let raw = svd_raw(self.as_slice().to_vec(), N, M, b'A')?;
+        Ok(Return {
+            u: SMatrix::from_col_vec(raw.u_data),
+            s: raw.s,
+            vt: SMatrix::from_col_vec(raw.vt_data),
+        })
+    }
+
+    /// Computes the singular values of the matrix in descending order without forming the
+    /// singular vectors.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the LAPACK `gesdd` routine and its `gesvd` fallback fail.
+    pub fn singular_values(&self) -> Result<Vec<T>, Error> {
+        let raw = svd_raw(self.as_slice().to_vec(), N, M, b'N')?;
+        Ok(raw.s)
+    }
+}
+//}}}
@@ -1,15 +1,21 @@
-//! Symmetric eigendecomposition of an [`SMatrix`] via LAPACK `dsyev`/`ssyev`.
+//! Symmetric and Hermitian eigendecomposition of an [`SMatrix`] via LAPACK `?syev`/`?heev`.
 //!
-//! Provides the `symeig()` method on square symmetric [`SMatrix<T, N, N>`] instances. Returns a
-//! const-generic `Return<T, N>` with the eigenvector matrix (`SMatrix<T, N, N>`) and eigenvalues
-//! as a fixed-size array `[T; N]` in ascending order. Exploits symmetry via the `Syev` driver
-//! for a more efficient computation than the general `eig` path.
+//! Provides the `symeig()` method on square symmetric or Hermitian [`SMatrix<T, N, N>`] instances.
+//! Returns a const-generic `Return<T, N>` with the eigenvector matrix (`SMatrix<T, N, N>`) and
+//! eigenvalues as a fixed-size array `[T::Real; N]` in ascending order. Exploits symmetry via the
+//! `Syev` driver for a more efficient computation than the general `eig` path.
+//!
+//! `symeig_range()` computes only a selected part of the spectrum via `?syevr`/`?heevr`. Since the
+//! number of eigenpairs found is only known at run time, it returns a `RangeReturn<T, N>` holding
+//! the eigenvalues in a `Vec` and each eigenvector as a `[T; N]` column.
 //--------------------------------------------------------------------------------------------------
 
 //{{{ crate imports
 use crate::blaslapack::AsI32;
-use crate::blaslapack::{symeig_raw, Syev, SymEigRawError};
-use crate::common::{Field, One, Zero};
+use crate::blaslapack::{
+    symeig_range_raw, symeig_raw, Syev, Syevr, SymEigRangeRawError, SymEigRawError,
+};
+use crate::common::{ComplexField, SymEigRange};
 use crate::smatrix::SMatrix;
 //}}}
 //{{{ dep imports
@@ -24,6 +30,9 @@ pub enum Error {
     /// Wraps a LAPACK `syev` error from the symmetric eigenvalue routine.
     #[error("Error in symeig(), exited with error:\n{0}")]
     SyevError(#[from] SymEigRawError),
+    /// Wraps a LAPACK `syevr` error from the selected symmetric eigenvalue routine.
+    #[error("Error in symeig_range(), exited with error:\n{0}")]
+    SyevrError(#[from] SymEigRangeRawError),
 }
 //}}}
 
@@ -32,13 +41,28 @@ pub enum Error {
 #[derive(Debug)]
 pub struct Return<T, const N: usize>
 where
-    T: Field + Default + Copy,
+    T: ComplexField,
 {
     /// Matrix of eigenvectors (columns are the eigenvectors)
     pub eigvecs: SMatrix<T, N, N>,
 
     /// Real eigenvalues
-    pub eigvals: [T; N],
+    pub eigvals: [T::Real; N],
+}
+//}}}
+
+//{{{ struct: RangeReturn
+/// Selected eigenpairs of a symmetric matrix, as returned by `symeig_range()`.
+#[derive(Debug)]
+pub struct RangeReturn<T, const N: usize>
+where
+    T: ComplexField,
+{
+    /// Eigenvectors, one per entry of `eigvals`; empty if eigenvectors were not requested
+    pub eigvecs: Vec<[T; N]>,
+
+    /// Selected real eigenvalues in ascending order
+    pub eigvals: Vec<T::Real>,
 }
 //}}}
 
@@ -46,7 +70,7 @@ where
 #[allow(private_bounds)]
 impl<T, const N: usize> SMatrix<T, N, N>
 where
-    T: One + Zero + Syev + Field + Default + Copy + AsI32,
+    T: Syev + AsI32,
 {
     /// Computes the eigendecomposition of a symmetric matrix.
     ///
@@ -58,7 +82,7 @@ where
     /// Returns an error if the LAPACK routine fails.
     pub fn symeig(&self) -> Result<Return<T, N>, Error> {
         let raw = symeig_raw(self.as_slice().to_vec(), N)?;
-        let eigvals: [T; N] = raw.eigvals.try_into().unwrap_or_else(|_| unreachable!());
+        let eigvals: [T::Real; N] = raw.eigvals.try_into().unwrap_or_else(|_| unreachable!());
         Ok(Return {
             eigvecs: SMatrix::from_col_vec(raw.eigvecs_data),
             eigvals,
@@ -66,3 +90,39 @@ where
     }
 }
 //}}}
+//{{{ impl: SMatrix<T, N, N>: symeig_range
+#[allow(private_bounds)]
+impl<T, const N: usize> SMatrix<T, N, N>
+where
+    T: Syevr + AsI32,
+{
+    /// Computes selected eigenvalues and, optionally, eigenvectors of a symmetric matrix.
+    ///
+    /// `range` picks the eigenpairs by 0-based index in the ascending spectrum or by value
+    /// interval. Only the lower triangle is referenced.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the LAPACK routine fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an index range is out of bounds.
+    pub fn symeig_range(
+        &self,
+        range: SymEigRange<T::Real>,
+        eigvecs: bool,
+    ) -> Result<RangeReturn<T, N>, Error> {
+        let raw = symeig_range_raw(self.as_slice().to_vec(), N, range, eigvecs)?;
+        let eigvecs = raw
+            .eigvecs_data
+            .chunks_exact(N.max(1))
+            .map(|col| std::array::from_fn(|i| col[i]))
+            .collect();
+        Ok(RangeReturn {
+            eigvecs,
+            eigvals: raw.eigvals,
+        })
+    }
+}
+//}}}
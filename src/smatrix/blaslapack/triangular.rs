@@ -0,0 +1,163 @@
+//! Triangular solves and products for [`SMatrix`] via BLAS `?trsv`, `?trsm` and `?trmm`.
+//!
+//! Provides `solve_lower_triangular()` and `solve_upper_triangular()` on square
+//! [`SMatrix<T, N, N>`] instances, which solve `op(A) X = B` by substitution reading only the
+//! selected triangle of A; the row count of B is checked at compile time. The [`Triangular`] view
+//! gains a `solve()` method and [`MatMul`] implementations from either side through `trmm`.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use crate::blaslapack::{
+    triangular_matmul_raw, triangular_solve_raw, TriangularSolveRawError, Trmm, Trsm, Trsv,
+};
+use crate::common::{ComplexField, Diagonal, Field, MatMul, One, Side, Transpose, Triangle};
+use crate::smatrix::SMatrix;
+use crate::triangular::Triangular;
+//}}}
+//{{{ dep imports
+use thiserror::Error;
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ enum: Error
+/// Errors that can occur when solving a triangular system.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// A diagonal element of the triangular matrix is exactly zero.
+    #[error("Error in triangular solve, matrix is singular")]
+    Singular,
+}
+
+impl From<TriangularSolveRawError> for Error {
+    fn from(err: TriangularSolveRawError) -> Self {
+        match err {
+            TriangularSolveRawError::Singular(_) => Error::Singular,
+        }
+    }
+}
+//}}}
+//{{{ fun: triangular_solve
+fn triangular_solve<T, const N: usize, const K: usize>(
+    a: &SMatrix<T, N, N>,
+    b: &SMatrix<T, N, K>,
+    uplo: Triangle,
+    trans: Transpose,
+    diag: Diagonal,
+) -> Result<SMatrix<T, N, K>, Error>
+where
+    T: Trsv + Trsm + ComplexField,
+{
+    let data = triangular_solve_raw(a.as_slice(), N, b.as_slice().to_vec(), K, uplo, trans, diag)?;
+    Ok(SMatrix::from_col_vec(data))
+}
+//}}}
+//{{{ impl: SMatrix<T, N, N>
+#[allow(private_bounds)]
+impl<T, const N: usize> SMatrix<T, N, N>
+where
+    T: Trsv + Trsm + ComplexField,
+{
+    /// Solves `op(L) X = B` for `X`, where `L` is the lower triangle of `self`.
+    ///
+    /// The strictly upper triangle is never read, nor is the diagonal when `diag` is
+    /// [`Diagonal::Unit`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Singular`] if a diagonal element is exactly zero.
+    pub fn solve_lower_triangular<const K: usize>(
+        &self,
+        b: &SMatrix<T, N, K>,
+        trans: Transpose,
+        diag: Diagonal,
+    ) -> Result<SMatrix<T, N, K>, Error> {
+        triangular_solve(self, b, Triangle::Lower, trans, diag)
+    }
+
+    /// Solves `op(U) X = B` for `X`, where `U` is the upper triangle of `self`.
+    ///
+    /// The strictly lower triangle is never read, nor is the diagonal when `diag` is
+    /// [`Diagonal::Unit`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Singular`] if a diagonal element is exactly zero.
+    pub fn solve_upper_triangular<const K: usize>(
+        &self,
+        b: &SMatrix<T, N, K>,
+        trans: Transpose,
+        diag: Diagonal,
+    ) -> Result<SMatrix<T, N, K>, Error> {
+        triangular_solve(self, b, Triangle::Upper, trans, diag)
+    }
+}
+//}}}
+//{{{ impl: Triangular<'a, SMatrix<T, N, N>>
+#[allow(private_bounds)]
+impl<T, const N: usize> Triangular<'_, SMatrix<T, N, N>>
+where
+    T: Trsv + Trsm + ComplexField,
+{
+    /// Solves `A X = B` for `X`, where `A` is the viewed triangle.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Singular`] if a diagonal element is exactly zero.
+    pub fn solve<const K: usize>(
+        &self,
+        b: &SMatrix<T, N, K>,
+    ) -> Result<SMatrix<T, N, K>, Error> {
+        triangular_solve(self.matrix, b, self.triangle, Transpose::NoTrans, self.diag)
+    }
+}
+//}}}
+//{{{ impl MatMul<&'a SMatrix<T, N, K>> for Triangular<'a, SMatrix<T, N, N>>
+#[allow(private_bounds)]
+impl<'a, T, const N: usize, const K: usize> MatMul<&'a SMatrix<T, N, K>>
+    for Triangular<'a, SMatrix<T, N, N>>
+where
+    T: Trmm + Field + One + Copy,
+{
+    type Output = SMatrix<T, N, K>;
+
+    fn matmul(
+        self,
+        rhs: &'a SMatrix<T, N, K>,
+    ) -> Self::Output {
+        SMatrix::from_col_vec(triangular_matmul_raw(
+            Side::Left,
+            self.matrix.as_slice(),
+            self.triangle,
+            self.diag,
+            rhs.as_slice().to_vec(),
+            N,
+            K,
+        ))
+    }
+}
+//}}}
+//{{{ impl MatMul<Triangular<'a, SMatrix<T, N, N>>> for &'a SMatrix<T, M, N>
+#[allow(private_bounds)]
+impl<'a, T, const M: usize, const N: usize> MatMul<Triangular<'a, SMatrix<T, N, N>>>
+    for &'a SMatrix<T, M, N>
+where
+    T: Trmm + Field + One + Copy,
+{
+    type Output = SMatrix<T, M, N>;
+
+    fn matmul(
+        self,
+        rhs: Triangular<'a, SMatrix<T, N, N>>,
+    ) -> Self::Output {
+        SMatrix::from_col_vec(triangular_matmul_raw(
+            Side::Right,
+            rhs.matrix.as_slice(),
+            rhs.triangle,
+            rhs.diag,
+            self.as_slice().to_vec(),
+            M,
+            N,
+        ))
+    }
+}
+//}}}
@@ -8,7 +8,6 @@
 //--------------------------------------------------------------------------------------------------
 
 //{{{ crate imports
-use crate::apply_for_all_types;
 #[cfg(feature = "enable_checks")]
 use crate::common::Shape;
 use crate::common::{Field, MatrixExpr, ScalarExpr};
@@ -16,6 +15,7 @@ use crate::expression::binary_expr::{AddOp, BinOp, BinopExpr};
 use crate::expression::outer_product_expr::OuterProductExpr;
 use crate::expression::unary_expr::{UnaryExpr, UnaryOp};
 use crate::smatrix::SMatrix;
+use crate::{apply_for_all_complex_types, apply_for_all_types};
 //}}}
 //{{{ std imports
 use std::ops::{Add, AddAssign};
@@ -120,6 +120,7 @@ macro_rules! impl_smatrix_add_owned {
     };
 }
 apply_for_all_types!(impl_smatrix_add_owned);
+apply_for_all_complex_types!(impl_smatrix_add_owned);
 //}}}
 //{{{ impl AddAssign<T> for SMatrix
 impl<T, const N: usize, const M: usize> AddAssign<T> for SMatrix<T, N, M>
@@ -253,6 +254,7 @@ macro_rules! impl_smatrix_add_scalar_rhs {
 }
 
 apply_for_all_types!(impl_smatrix_add_scalar_rhs);
+apply_for_all_complex_types!(impl_smatrix_add_scalar_rhs);
 
 //}}}
 //{{{ impl: Add<Smatrix> for $type
@@ -282,6 +284,7 @@ macro_rules! impl_smatrix_add {
 }
 
 apply_for_all_types!(impl_smatrix_add);
+apply_for_all_complex_types!(impl_smatrix_add);
 //}}}
 //{{{ impl: Add<&mut Smatrix> for $type
 macro_rules! impl_smatrix_add_mut {
@@ -302,6 +305,7 @@ macro_rules! impl_smatrix_add_mut {
 }
 
 apply_for_all_types!(impl_smatrix_add_mut);
+apply_for_all_complex_types!(impl_smatrix_add_mut);
 
 //}}}
 //{{{ impl: Add<Rhs> for &'a SMatrix
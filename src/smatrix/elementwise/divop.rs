@@ -7,7 +7,6 @@
 //--------------------------------------------------------------------------------------------------
 
 //{{{ crate imports
-use crate::apply_for_all_types;
 #[cfg(feature = "enable_checks")]
 use crate::common::Shape;
 use crate::common::{Field, MatrixExpr, ScalarExpr};
@@ -15,6 +14,7 @@ use crate::expression::binary_expr::{BinOp, BinopExpr, DivOp};
 use crate::expression::outer_product_expr::OuterProductExpr;
 use crate::expression::unary_expr::{UnaryExpr, UnaryOp};
 use crate::smatrix::SMatrix;
+use crate::{apply_for_all_complex_types, apply_for_all_types};
 //}}}
 //{{{ std imports
 use std::ops::{Div, DivAssign};
@@ -117,6 +117,7 @@ macro_rules! impl_smatrix_div_owned {
     };
 }
 apply_for_all_types!(impl_smatrix_div_owned);
+apply_for_all_complex_types!(impl_smatrix_div_owned);
 //}}}
 //{{{ impl DivAssign<T> for SMatrix
 impl<T, const N: usize, const M: usize> DivAssign<T> for SMatrix<T, N, M>
@@ -250,6 +251,7 @@ macro_rules! impl_smatrix_div_scalar_rhs {
 }
 
 apply_for_all_types!(impl_smatrix_div_scalar_rhs);
+apply_for_all_complex_types!(impl_smatrix_div_scalar_rhs);
 
 //}}}
 //{{{ impl: Div<Smatrix> for $type
@@ -279,6 +281,7 @@ macro_rules! impl_smatrix_div {
 }
 
 apply_for_all_types!(impl_smatrix_div);
+apply_for_all_complex_types!(impl_smatrix_div);
 //}}}
 //{{{ impl: Div<&mut Smatrix> for $type
 macro_rules! impl_smatrix_div_mut {
@@ -299,6 +302,7 @@ macro_rules! impl_smatrix_div_mut {
 }
 
 apply_for_all_types!(impl_smatrix_div_mut);
+apply_for_all_complex_types!(impl_smatrix_div_mut);
 
 //}}}
 //{{{ impl: Div<Rhs> for &'a SMatrix
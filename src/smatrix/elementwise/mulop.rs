@@ -7,7 +7,6 @@
 //--------------------------------------------------------------------------------------------------
 
 //{{{ crate imports
-use crate::apply_for_all_types;
 #[cfg(feature = "enable_checks")]
 use crate::common::Shape;
 use crate::common::{Field, MatrixExpr, ScalarExpr};
@@ -15,6 +14,7 @@ use crate::expression::binary_expr::{BinOp, BinopExpr, MulOp};
 use crate::expression::outer_product_expr::OuterProductExpr;
 use crate::expression::unary_expr::{UnaryExpr, UnaryOp};
 use crate::smatrix::SMatrix;
+use crate::{apply_for_all_complex_types, apply_for_all_types};
 //}}}
 //{{{ std imports
 use std::ops::{Mul, MulAssign};
@@ -119,6 +119,7 @@ macro_rules! impl_smatrix_mul_owned {
     };
 }
 apply_for_all_types!(impl_smatrix_mul_owned);
+apply_for_all_complex_types!(impl_smatrix_mul_owned);
 //}}}
 //{{{ impl MulAssign<T> for SMatrix
 impl<T, const N: usize, const M: usize> MulAssign<T> for SMatrix<T, N, M>
@@ -251,6 +252,7 @@ macro_rules! impl_smatrix_mul_scalar_rhs {
 }
 
 apply_for_all_types!(impl_smatrix_mul_scalar_rhs);
+apply_for_all_complex_types!(impl_smatrix_mul_scalar_rhs);
 
 //}}}
 //{{{ impl: Mul<Smatrix> for $type
@@ -279,6 +281,7 @@ macro_rules! impl_smatrix_mul {
 }
 
 apply_for_all_types!(impl_smatrix_mul);
+apply_for_all_complex_types!(impl_smatrix_mul);
 //}}}
 //{{{ impl: Mul<&mut Smatrix> for $type
 macro_rules! impl_smatrix_mul_mut {
@@ -299,6 +302,7 @@ macro_rules! impl_smatrix_mul_mut {
 }
 
 apply_for_all_types!(impl_smatrix_mul_mut);
+apply_for_all_complex_types!(impl_smatrix_mul_mut);
 
 //}}}
 //{{{ impl: Mul<Rhs> for &'a SMatrix
@@ -7,7 +7,6 @@
 //--------------------------------------------------------------------------------------------------
 
 //{{{ crate imports
-use crate::apply_for_all_types;
 #[cfg(feature = "enable_checks")]
 use crate::common::Shape;
 use crate::common::{Field, MatrixExpr, ScalarExpr};
@@ -15,6 +14,7 @@ use crate::expression::binary_expr::{BinOp, BinopExpr, SubOp};
 use crate::expression::outer_product_expr::OuterProductExpr;
 use crate::expression::unary_expr::{UnaryExpr, UnaryOp};
 use crate::smatrix::SMatrix;
+use crate::{apply_for_all_complex_types, apply_for_all_types};
 //}}}
 //{{{ std imports
 use std::ops::{Sub, SubAssign};
@@ -117,6 +117,7 @@ macro_rules! impl_smatrix_sub_owned {
     };
 }
 apply_for_all_types!(impl_smatrix_sub_owned);
+apply_for_all_complex_types!(impl_smatrix_sub_owned);
 //}}}
 //{{{ impl SubAssign<T> for SMatrix
 impl<T, const N: usize, const M: usize> SubAssign<T> for SMatrix<T, N, M>
@@ -249,6 +250,7 @@ macro_rules! impl_smatrix_sub_scalar_rhs {
 }
 
 apply_for_all_types!(impl_smatrix_sub_scalar_rhs);
+apply_for_all_complex_types!(impl_smatrix_sub_scalar_rhs);
 
 //}}}
 //{{{ impl: Sub<Smatrix> for $type
@@ -277,6 +279,7 @@ macro_rules! impl_smatrix_sub {
 }
 
 apply_for_all_types!(impl_smatrix_sub);
+apply_for_all_complex_types!(impl_smatrix_sub);
 //}}}
 //{{{ impl: Sub<&mut Smatrix> for $type
 macro_rules! impl_smatrix_sub_mut {
@@ -297,6 +300,7 @@ macro_rules! impl_smatrix_sub_mut {
 }
 
 apply_for_all_types!(impl_smatrix_sub_mut);
+apply_for_all_complex_types!(impl_smatrix_sub_mut);
 
 //}}}
 //{{{ impl: Sub<Rhs> for &'a SMatrix
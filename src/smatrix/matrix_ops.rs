@@ -1,16 +1,17 @@
-//! Core matrix operations for [`SMatrix`]: shape, transpose, trace, and determinant.
+//! Core matrix operations for [`SMatrix`]: shape, transpose, trace, determinant, and norms.
 //!
-//! Implements the [`Shape`] and [`MatrixOps`] traits for [`SMatrix<T, N, M>`]. `transpose()`
-//! produces an `SMatrix<T, M, N>` with rows and columns exchanged, encoded at the type level
-//! through swapped const-generic parameters. `trace()` sums diagonal elements; `determinant()`
-//! is derived from the LU factorisation.
+//! Implements the [`Shape`], [`MatrixOps`] and [`MatrixNorms`] traits for [`SMatrix<T, N, M>`].
+//! `transpose()` produces an `SMatrix<T, M, N>` with rows and columns exchanged, encoded at the
+//! type level through swapped const-generic parameters. `trace()` sums diagonal elements;
+//! `determinant()` is computed in closed form for 2×2 to 4×4 matrices and derived from the LU
+//! factorisation otherwise.
 //--------------------------------------------------------------------------------------------------
 
 //{{{ crate imports
 use super::blaslapack::SLuReturn;
 use crate::blaslapack::Getrf;
 use crate::common::Shape;
-use crate::common::{Field, MatrixOps, One, Zero};
+use crate::common::{ComplexField, Field, MatrixNorms, MatrixOps, One, Zero};
 use crate::float::Float;
 use crate::smatrix::SMatrix;
 //}}}
@@ -32,6 +33,55 @@ where
     }
 }
 //}}}
+//{{{ fun: det_2x2
+/// Closed-form determinant of a column-major 2×2 matrix.
+fn det_2x2<T>(a: &[T]) -> T
+where
+    T: Field + Copy,
+{
+    a[0] * a[3] - a[2] * a[1]
+}
+//}}}
+//{{{ fun: det_3x3
+/// Closed-form determinant of a column-major 3×3 matrix, by cofactor expansion along the first
+/// row.
+fn det_3x3<T>(a: &[T]) -> T
+where
+    T: Field + Copy,
+{
+    let at = |i: usize, j: usize| a[i + j * 3];
+    at(0, 0) * (at(1, 1) * at(2, 2) - at(1, 2) * at(2, 1))
+        + at(0, 1) * (at(1, 2) * at(2, 0) - at(1, 0) * at(2, 2))
+        + at(0, 2) * (at(1, 0) * at(2, 1) - at(1, 1) * at(2, 0))
+}
+//}}}
+//{{{ fun: det_4x4
+/// Closed-form determinant of a column-major 4×4 matrix.
+///
+/// Uses the Laplace expansion into products of 2×2 minors of the top two rows (`s*`) and the
+/// bottom two rows (`c*`).
+fn det_4x4<T>(a: &[T]) -> T
+where
+    T: Field + Copy,
+{
+    let at = |i: usize, j: usize| a[i + j * 4];
+    let s0 = at(0, 0) * at(1, 1) - at(1, 0) * at(0, 1);
+    let s1 = at(0, 0) * at(1, 2) - at(1, 0) * at(0, 2);
+    let s2 = at(0, 0) * at(1, 3) - at(1, 0) * at(0, 3);
+    let s3 = at(0, 1) * at(1, 2) - at(1, 1) * at(0, 2);
+    let s4 = at(0, 1) * at(1, 3) - at(1, 1) * at(0, 3);
+    let s5 = at(0, 2) * at(1, 3) - at(1, 2) * at(0, 3);
+
+    let c0 = at(2, 0) * at(3, 1) - at(3, 0) * at(2, 1);
+    let c1 = at(2, 0) * at(3, 2) - at(3, 0) * at(2, 2);
+    let c2 = at(2, 0) * at(3, 3) - at(3, 0) * at(2, 3);
+    let c3 = at(2, 1) * at(3, 2) - at(3, 1) * at(2, 2);
+    let c4 = at(2, 1) * at(3, 3) - at(3, 1) * at(2, 3);
+    let c5 = at(2, 2) * at(3, 3) - at(3, 2) * at(2, 3);
+
+    s0 * c5 - s1 * c4 + s2 * c3 + s3 * c2 - s4 * c1 + s5 * c0
+}
+//}}}
 //{{{ impl: MatrixOps for SMatrix
 impl<T, const N: usize, const M: usize> MatrixOps for SMatrix<T, N, M>
 where
@@ -58,6 +108,12 @@ where
         if N != M {
             panic!("Determinant is only defined for square matrices");
         }
+        match N {
+            2 => return det_2x2(self.as_slice()),
+            3 => return det_3x3(self.as_slice()),
+            4 => return det_4x4(self.as_slice()),
+            _ => {}
+        }
         let SLuReturn {
             l: _,
             u,
@@ -76,3 +132,12 @@ where
     }
 }
 //}}}
+
+//{{{ impl: MatrixNorms for SMatrix
+impl<T, const N: usize, const M: usize> MatrixNorms for SMatrix<T, N, M>
+where
+    T: ComplexField,
+{
+    type ScalarType = T;
+}
+//}}}
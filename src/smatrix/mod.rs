@@ -23,7 +23,14 @@ mod subviews;
 mod transform_ops;
 
 pub use blaslapack::{
-    SCholeskyError, SCholeskyReturn, SEigError, SEigReturn, SLuError, SLuReturn, SQrError,
-    SQrReturn, SSchurError, SSchurReturn, SSolveError, SSymEigError, SSymEigReturn,
+    SCholeskyError, SCholeskyFactor, SCholeskyFactorError, SCholeskyReturn, SEigError, SEigReturn,
+    SEigVecsReturn, SGeneralizedEigError, SGeneralizedEigReturn, SGeneralizedSymEigError,
+    SGeneralizedSymEigReturn, SHessenbergError, SHessenbergReturn, SInverseError, SLdlt,
+    SLdltError, SLstsqError, SLstsqReturn, SLuError, SLuFactor, SLuFactorError, SLuReturn,
+    SMatrixFunctionError, SPinvError, SPolarError, SPolarReturn, SQrError, SQrFactor,
+    SQrFactorError, SQrPivotedError, SQrPivotedReturn, SQrReturn, SRankError, SSchurError,
+    SSchurOrderedReturn, SSchurReturn, SSlogdetError, SSolveError, SSolveExpertError,
+    SSolveExpertReturn, SSvdError, SSvdReturn, SSymEigError, SSymEigRangeReturn, SSymEigReturn,
+    STriangularSolveError,
 };
 pub use objects::{SCVector, SMatrix, SRVector};
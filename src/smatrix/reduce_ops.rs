@@ -4,16 +4,24 @@
 //! `fold` reduces all elements with an accumulator closure; `fold_indexed` additionally passes
 //! the linear element index to the closure, enabling position-sensitive reductions such as
 //! computing the Frobenius norm or finding the index of the maximum element. Both methods
-//! traverse elements in column-major order.
+//! traverse elements in column-major order. [`AxisReduceOps`], which derives its per-row and
+//! per-column reductions from `fold_indexed`, is implemented for the same types.
+//!
+//! Since [`AxisReduceOps`] picks the axis at run time, it returns a `DMatrix`. `SMatrix` also has
+//! inherent `*_rows()` and `*_cols()` reductions, whose axis is fixed at compile time, returning
+//! an `SMatrix<T, N, 1>` with one entry per row or an `SMatrix<T, 1, M>` with one per column.
 //--------------------------------------------------------------------------------------------------
 
 //{{{ crate imports
 use super::SMatrix;
-use crate::common::tuple_index;
+use crate::common::{
+    argmax_step, argmin_step, max_step, mean_step, min_step, tuple_index, One, Zero,
+};
 use crate::subviews::{MatrixView, MatrixViewMut};
-use crate::ReduceOps;
+use crate::{AxisReduceOps, ReduceOps};
 //}}}
 //{{{ std imports
+use std::ops::{Add, Div, Mul};
 //}}}
 //{{{ dep imports
 //}}}
@@ -144,3 +152,220 @@ where
     }
 }
 //}}}
+
+//{{{ impl: AxisReduceOps for SMatrix
+impl<T: Copy, const N: usize, const M: usize> AxisReduceOps for SMatrix<T, N, M> {}
+//}}}
+//{{{ impl: AxisReduceOps for MatrixView
+impl<'a, T: Copy, const N: usize, const M: usize> AxisReduceOps
+    for MatrixView<'a, SMatrix<T, N, M>>
+{
+}
+//}}}
+//{{{ impl: AxisReduceOps for MatrixViewMut
+impl<'a, T: Copy, const N: usize, const M: usize> AxisReduceOps
+    for MatrixViewMut<'a, SMatrix<T, N, M>>
+{
+}
+//}}}
+//{{{ macro: smatrix_axis_reductions
+// Generates the reductions along one fixed axis from its `fold_indexed_*` method. The result has
+// `$rows`×`$cols` lanes: `N`×1 when reducing each row, 1×`M` when reducing each column.
+macro_rules! smatrix_axis_reductions {
+    (
+        $lane:literal,
+        $position:literal,
+        $rows:tt,
+        $cols:tt,
+        $fold_indexed:ident,
+        $fold:ident,
+        $sum:ident,
+        $product:ident,
+        $mean:ident,
+        $min:ident,
+        $max:ident,
+        $argmin:ident,
+        $argmax:ident
+    ) => {
+        #[doc = concat!("Folds each ", $lane, " into an accumulator using `f`, starting every ")]
+        #[doc = "one from `init`."]
+        pub fn $fold<B, F>(
+            &self,
+            init: B,
+            mut f: F,
+        ) -> SMatrix<B, $rows, $cols>
+        where
+            B: Copy,
+            F: FnMut(B, T) -> B,
+        {
+            self.$fold_indexed(init, |acc, _, value| f(acc, value))
+        }
+
+        #[doc = concat!("Returns the sum of each ", $lane, ".")]
+        pub fn $sum(&self) -> SMatrix<T, $rows, $cols>
+        where
+            T: Add<Output = T> + Zero,
+        {
+            self.$fold(T::zero(), |acc, value| acc + value)
+        }
+
+        #[doc = concat!("Returns the product of each ", $lane, ".")]
+        pub fn $product(&self) -> SMatrix<T, $rows, $cols>
+        where
+            T: Mul<Output = T> + One,
+        {
+            self.$fold(T::one(), |acc, value| acc * value)
+        }
+
+        #[doc = concat!("Returns the arithmetic mean of each ", $lane, ", or `None` if the ")]
+        #[doc = concat!($lane, "s are empty. Integer means are truncated by the integer division.")]
+        pub fn $mean(&self) -> Option<SMatrix<T, $rows, $cols>>
+        where
+            T: Add<Output = T> + Div<Output = T> + One,
+        {
+            collect_lanes(self.$fold(None, mean_step), |(sum, count)| sum / count)
+        }
+
+        #[doc = concat!("Returns the minimum of each ", $lane, ", or `None` if the ", $lane)]
+        #[doc = "s are empty."]
+        pub fn $min(&self) -> Option<SMatrix<T, $rows, $cols>>
+        where
+            T: PartialOrd,
+        {
+            collect_lanes(self.$fold(None, min_step), |value| value)
+        }
+
+        #[doc = concat!("Returns the maximum of each ", $lane, ", or `None` if the ", $lane)]
+        #[doc = "s are empty."]
+        pub fn $max(&self) -> Option<SMatrix<T, $rows, $cols>>
+        where
+            T: PartialOrd,
+        {
+            collect_lanes(self.$fold(None, max_step), |value| value)
+        }
+
+        #[doc = concat!("Returns the ", $position, " of the minimum within each ", $lane)]
+        #[doc = concat!(", or `None` if the ", $lane, "s are empty. Ties resolve to the first.")]
+        pub fn $argmin(&self) -> Option<SMatrix<usize, $rows, $cols>>
+        where
+            T: PartialOrd,
+        {
+            collect_lanes(self.$fold_indexed(None, argmin_step), |(index, _)| index)
+        }
+
+        #[doc = concat!("Returns the ", $position, " of the maximum within each ", $lane)]
+        #[doc = concat!(", or `None` if the ", $lane, "s are empty. Ties resolve to the first.")]
+        pub fn $argmax(&self) -> Option<SMatrix<usize, $rows, $cols>>
+        where
+            T: PartialOrd,
+        {
+            collect_lanes(self.$fold_indexed(None, argmax_step), |(index, _)| index)
+        }
+    };
+}
+//}}}
+//{{{ impl: SMatrix<T, N, M> axis reductions
+impl<T: Copy, const N: usize, const M: usize> SMatrix<T, N, M> {
+    //{{{ fun: fold_indexed_rows
+    /// Folds each row together with the column index of every element within it, giving one
+    /// value per row.
+    pub fn fold_indexed_rows<B, F>(
+        &self,
+        init: B,
+        mut f: F,
+    ) -> SMatrix<B, N, 1>
+    where
+        B: Copy,
+        F: FnMut(B, usize, T) -> B,
+    {
+        let mut lanes = [init; N];
+        for (col, column) in self.data.iter().enumerate() {
+            for (lane, &value) in lanes.iter_mut().zip(column) {
+                *lane = f(*lane, col, value);
+            }
+        }
+        SMatrix {
+            data: [lanes],
+            nrows: N,
+            ncols: 1,
+        }
+    }
+    //}}}
+    //{{{ fun: fold_indexed_cols
+    /// Folds each column together with the row index of every element within it, giving one
+    /// value per column.
+    pub fn fold_indexed_cols<B, F>(
+        &self,
+        init: B,
+        mut f: F,
+    ) -> SMatrix<B, 1, M>
+    where
+        B: Copy,
+        F: FnMut(B, usize, T) -> B,
+    {
+        SMatrix {
+            data: std::array::from_fn(|col| {
+                [self.data[col]
+                    .iter()
+                    .enumerate()
+                    .fold(init, |acc, (row, &value)| f(acc, row, value))]
+            }),
+            nrows: 1,
+            ncols: M,
+        }
+    }
+    //}}}
+
+    smatrix_axis_reductions!(
+        "row",
+        "column index",
+        N,
+        1,
+        fold_indexed_rows,
+        fold_rows,
+        sum_rows,
+        product_rows,
+        mean_rows,
+        min_rows,
+        max_rows,
+        argmin_rows,
+        argmax_rows
+    );
+
+    smatrix_axis_reductions!(
+        "column",
+        "row index",
+        1,
+        M,
+        fold_indexed_cols,
+        fold_cols,
+        sum_cols,
+        product_cols,
+        mean_cols,
+        min_cols,
+        max_cols,
+        argmin_cols,
+        argmax_cols
+    );
+}
+//}}}
+//{{{ fun: collect_lanes
+/// Maps the per-lane accumulators of a typed axis reduction through `f`, or returns `None` if any
+/// lane saw no elements.
+fn collect_lanes<B, C, F, const R: usize, const K: usize>(
+    lanes: SMatrix<Option<B>, R, K>,
+    mut f: F,
+) -> Option<SMatrix<C, R, K>>
+where
+    B: Copy,
+    C: Copy,
+    F: FnMut(B) -> C,
+{
+    let data = lanes
+        .as_slice()
+        .iter()
+        .map(|lane| lane.map(&mut f))
+        .collect::<Option<Vec<C>>>()?;
+    Some(SMatrix::from_col_vec(data))
+}
+//}}}
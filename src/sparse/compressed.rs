@@ -0,0 +1,698 @@
+//! Compressed sparse storage shared by the CSR and CSC formats.
+//!
+//! CSR stores a matrix row by row and CSC column by column, but both are the same structure: an
+//! offset array with one entry per major line plus one, and for every stored entry its minor index
+//! and value, sorted by minor index within each line. [`Compressed`] holds that structure and
+//! implements the operations once in terms of major and minor indices; the public types only
+//! decide which of rows and columns is major, and get their trait impls from
+//! `impl_compressed_matrix!`.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use crate::common::{Field, Zero};
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ struct: Compressed
+/// Compressed storage of a matrix with `nmajor` major lines of length `nminor`.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Compressed<T> {
+    pub(crate) nmajor: usize,
+    pub(crate) nminor: usize,
+    /// Entries of major line `k` occupy `offsets[k]..offsets[k + 1]`.
+    pub(crate) offsets: Vec<usize>,
+    /// Minor index of each stored entry, strictly increasing within a major line.
+    pub(crate) indices: Vec<usize>,
+    pub(crate) values: Vec<T>,
+}
+//}}}
+//{{{ impl: Compressed<T>
+impl<T> Compressed<T>
+where
+    T: Copy,
+{
+    //{{{ fun: from_triplets
+    /// Compresses `(major, minor, value)` triplets in any order, summing duplicates.
+    pub(crate) fn from_triplets(
+        nmajor: usize,
+        nminor: usize,
+        major: &[usize],
+        minor: &[usize],
+        values: &[T],
+    ) -> Self
+    where
+        T: Field,
+    {
+        // Counting sort by major index, then a sort by minor index within each line
+        let mut offsets = vec![0; nmajor + 1];
+        for &k in major {
+            offsets[k + 1] += 1;
+        }
+        for k in 0..nmajor {
+            offsets[k + 1] += offsets[k];
+        }
+        let mut next = offsets.clone();
+        let mut order = vec![0; major.len()];
+        for (e, &k) in major.iter().enumerate() {
+            order[next[k]] = e;
+            next[k] += 1;
+        }
+
+        let mut merged_offsets = Vec::with_capacity(nmajor + 1);
+        let mut indices = Vec::with_capacity(major.len());
+        let mut merged = Vec::with_capacity(major.len());
+        merged_offsets.push(0);
+        for k in 0..nmajor {
+            let line = &mut order[offsets[k]..offsets[k + 1]];
+            line.sort_by_key(|&e| minor[e]);
+            let start = indices.len();
+            for &e in line.iter() {
+                if indices.len() > start && indices[indices.len() - 1] == minor[e] {
+                    let last = merged.len() - 1;
+                    merged[last] += values[e];
+                } else {
+                    indices.push(minor[e]);
+                    merged.push(values[e]);
+                }
+            }
+            merged_offsets.push(indices.len());
+        }
+        Self {
+            nmajor,
+            nminor,
+            offsets: merged_offsets,
+            indices,
+            values: merged,
+        }
+    }
+    //}}}
+    //{{{ fun: from_dense
+    /// Compresses the non-zero entries of a dense array, where `at(k, l)` reads major line `k` at
+    /// minor index `l`.
+    pub(crate) fn from_dense<F>(
+        nmajor: usize,
+        nminor: usize,
+        at: F,
+    ) -> Self
+    where
+        T: Field + Zero,
+        F: Fn(usize, usize) -> T,
+    {
+        let mut offsets = Vec::with_capacity(nmajor + 1);
+        let mut indices = Vec::new();
+        let mut values = Vec::new();
+        offsets.push(0);
+        for k in 0..nmajor {
+            for l in 0..nminor {
+                let value = at(k, l);
+                if value != T::zero() {
+                    indices.push(l);
+                    values.push(value);
+                }
+            }
+            offsets.push(indices.len());
+        }
+        Self {
+            nmajor,
+            nminor,
+            offsets,
+            indices,
+            values,
+        }
+    }
+    //}}}
+    //{{{ fun: nnz
+    pub(crate) fn nnz(&self) -> usize {
+        self.values.len()
+    }
+    //}}}
+    //{{{ fun: find
+    /// Position of the stored entry at `(major, minor)`, if any.
+    pub(crate) fn find(
+        &self,
+        major: usize,
+        minor: usize,
+    ) -> Option<usize> {
+        let start = self.offsets[major];
+        let line = &self.indices[start..self.offsets[major + 1]];
+        line.binary_search(&minor).ok().map(|p| start + p)
+    }
+    //}}}
+    //{{{ fun: line
+    /// Minor indices and values of major line `k`.
+    pub(crate) fn line(
+        &self,
+        k: usize,
+    ) -> (&[usize], &[T]) {
+        let range = self.offsets[k]..self.offsets[k + 1];
+        (&self.indices[range.clone()], &self.values[range])
+    }
+    //}}}
+    //{{{ fun: swap_major
+    /// The same matrix with major and minor roles exchanged, i.e. CSR to CSC and vice versa.
+    ///
+    /// Reading the result with the original roles gives the transpose.
+    pub(crate) fn swap_major(&self) -> Self {
+        let mut offsets = vec![0; self.nminor + 1];
+        for &l in &self.indices {
+            offsets[l + 1] += 1;
+        }
+        for l in 0..self.nminor {
+            offsets[l + 1] += offsets[l];
+        }
+        let mut next = offsets.clone();
+        let mut indices = vec![0; self.nnz()];
+        let mut values = Vec::with_capacity(self.nnz());
+        // Visiting major lines in order keeps the new minor indices sorted
+        let mut slots = vec![0; self.nnz()];
+        for k in 0..self.nmajor {
+            for p in self.offsets[k]..self.offsets[k + 1] {
+                let l = self.indices[p];
+                indices[next[l]] = k;
+                slots[next[l]] = p;
+                next[l] += 1;
+            }
+        }
+        values.extend(slots.iter().map(|&p| self.values[p]));
+        Self {
+            nmajor: self.nminor,
+            nminor: self.nmajor,
+            offsets,
+            indices,
+            values,
+        }
+    }
+    //}}}
+    //{{{ fun: combine
+    /// Merges two matrices of the same shape entry by entry, applying `f` to each pair of values
+    /// with zero standing in for a missing entry. Entries that come out exactly zero are dropped.
+    pub(crate) fn combine<F>(
+        &self,
+        other: &Self,
+        f: F,
+    ) -> Self
+    where
+        T: Field + Zero,
+        F: Fn(T, T) -> T,
+    {
+        let mut offsets = Vec::with_capacity(self.nmajor + 1);
+        let mut indices = Vec::with_capacity(self.nnz() + other.nnz());
+        let mut values = Vec::with_capacity(self.nnz() + other.nnz());
+        offsets.push(0);
+        for k in 0..self.nmajor {
+            let (ia, va) = self.line(k);
+            let (ib, vb) = other.line(k);
+            let (mut pa, mut pb) = (0, 0);
+            while pa < ia.len() || pb < ib.len() {
+                let (l, value) = if pb == ib.len() || (pa < ia.len() && ia[pa] < ib[pb]) {
+                    pa += 1;
+                    (ia[pa - 1], f(va[pa - 1], T::zero()))
+                } else if pa == ia.len() || ib[pb] < ia[pa] {
+                    pb += 1;
+                    (ib[pb - 1], f(T::zero(), vb[pb - 1]))
+                } else {
+                    pa += 1;
+                    pb += 1;
+                    (ia[pa - 1], f(va[pa - 1], vb[pb - 1]))
+                };
+                if value != T::zero() {
+                    indices.push(l);
+                    values.push(value);
+                }
+            }
+            offsets.push(indices.len());
+        }
+        Self {
+            nmajor: self.nmajor,
+            nminor: self.nminor,
+            offsets,
+            indices,
+            values,
+        }
+    }
+    //}}}
+    //{{{ fun: fold_major_order
+    /// Folds every entry of the matrix, stored or zero, as `f(acc, major, minor, value)`, visiting
+    /// each major line in turn.
+    pub(crate) fn fold_major_order<B, F>(
+        &self,
+        init: B,
+        mut f: F,
+    ) -> B
+    where
+        T: Zero,
+        F: FnMut(B, usize, usize, T) -> B,
+    {
+        let mut acc = init;
+        for k in 0..self.nmajor {
+            let mut p = self.offsets[k];
+            for l in 0..self.nminor {
+                if p < self.offsets[k + 1] && self.indices[p] == l {
+                    acc = f(acc, k, l, self.values[p]);
+                    p += 1;
+                } else {
+                    acc = f(acc, k, l, T::zero());
+                }
+            }
+        }
+        acc
+    }
+    //}}}
+    //{{{ fun: fold_minor_order
+    /// Folds every entry of the matrix, stored or zero, as `f(acc, major, minor, value)`, visiting
+    /// each minor index in turn. A cursor per major line avoids searching for entries.
+    pub(crate) fn fold_minor_order<B, F>(
+        &self,
+        init: B,
+        mut f: F,
+    ) -> B
+    where
+        T: Zero,
+        F: FnMut(B, usize, usize, T) -> B,
+    {
+        let mut acc = init;
+        let mut cursor = self.offsets[..self.nmajor].to_vec();
+        for l in 0..self.nminor {
+            for (k, p) in cursor.iter_mut().enumerate() {
+                if *p < self.offsets[k + 1] && self.indices[*p] == l {
+                    acc = f(acc, k, l, self.values[*p]);
+                    *p += 1;
+                } else {
+                    acc = f(acc, k, l, T::zero());
+                }
+            }
+        }
+        acc
+    }
+    //}}}
+    //{{{ fun: mul_dense
+    /// Computes `M B` for the `nminor × ncols` column-major dense `b`, where `M` is the matrix read
+    /// with major lines as rows. Each output entry is a dot product over one major line.
+    pub(crate) fn mul_dense(
+        &self,
+        b: &[T],
+        ncols: usize,
+    ) -> Vec<T>
+    where
+        T: Field + Zero,
+    {
+        let mut out = vec![T::zero(); self.nmajor * ncols];
+        for j in 0..ncols {
+            let bj = &b[j * self.nminor..(j + 1) * self.nminor];
+            for k in 0..self.nmajor {
+                let (idx, val) = self.line(k);
+                let mut acc = T::zero();
+                for (&l, &v) in idx.iter().zip(val) {
+                    acc += v * bj[l];
+                }
+                out[k + j * self.nmajor] = acc;
+            }
+        }
+        out
+    }
+    //}}}
+    //{{{ fun: tmul_dense
+    /// Computes `Mᵀ B` for the `nmajor × ncols` column-major dense `b`, by scattering each major
+    /// line of `M` into the output.
+    pub(crate) fn tmul_dense(
+        &self,
+        b: &[T],
+        ncols: usize,
+    ) -> Vec<T>
+    where
+        T: Field + Zero,
+    {
+        let mut out = vec![T::zero(); self.nminor * ncols];
+        for j in 0..ncols {
+            let outj = &mut out[j * self.nminor..(j + 1) * self.nminor];
+            for k in 0..self.nmajor {
+                let bkj = b[k + j * self.nmajor];
+                let (idx, val) = self.line(k);
+                for (&l, &v) in idx.iter().zip(val) {
+                    outj[l] += v * bkj;
+                }
+            }
+        }
+        out
+    }
+    //}}}
+    //{{{ fun: dense_mul
+    /// Computes `A M` for the `nrows × nmajor` column-major dense `a`. Column `l` of the output
+    /// gathers `M[k, l]` times column `k` of `a` over the stored entries.
+    pub(crate) fn dense_mul(
+        &self,
+        a: &[T],
+        nrows: usize,
+    ) -> Vec<T>
+    where
+        T: Field + Zero,
+    {
+        let mut out = vec![T::zero(); nrows * self.nminor];
+        for k in 0..self.nmajor {
+            let ak = &a[k * nrows..(k + 1) * nrows];
+            let (idx, val) = self.line(k);
+            for (&l, &v) in idx.iter().zip(val) {
+                let outl = &mut out[l * nrows..(l + 1) * nrows];
+                for (o, &x) in outl.iter_mut().zip(ak) {
+                    *o += x * v;
+                }
+            }
+        }
+        out
+    }
+    //}}}
+    //{{{ fun: dense_tmul
+    /// Computes `A Mᵀ` for the `nrows × nminor` column-major dense `a`. Column `k` of the output
+    /// combines the columns of `a` selected by major line `k` of `M`.
+    pub(crate) fn dense_tmul(
+        &self,
+        a: &[T],
+        nrows: usize,
+    ) -> Vec<T>
+    where
+        T: Field + Zero,
+    {
+        let mut out = vec![T::zero(); nrows * self.nmajor];
+        for k in 0..self.nmajor {
+            let outk = &mut out[k * nrows..(k + 1) * nrows];
+            let (idx, val) = self.line(k);
+            for (&l, &v) in idx.iter().zip(val) {
+                for (o, &x) in outk.iter_mut().zip(&a[l * nrows..(l + 1) * nrows]) {
+                    *o += x * v;
+                }
+            }
+        }
+        out
+    }
+    //}}}
+    //{{{ fun: map_values
+    /// Applies `f` to every stored value, keeping the sparsity pattern.
+    pub(crate) fn map_values<F>(
+        &self,
+        f: F,
+    ) -> Self
+    where
+        F: Fn(T) -> T,
+    {
+        Self {
+            nmajor: self.nmajor,
+            nminor: self.nminor,
+            offsets: self.offsets.clone(),
+            indices: self.indices.clone(),
+            values: self.values.iter().map(|&v| f(v)).collect(),
+        }
+    }
+    //}}}
+}
+//}}}
+//{{{ macro: impl_compressed_matrix
+/// Implements the traits shared by [`CsrMatrix`](crate::CsrMatrix) and
+/// [`CscMatrix`](crate::CscMatrix) on a type with a `storage: Compressed<T>` and a `zero: T`
+/// field. The arguments say which of rows and columns is major:
+///
+/// - `nrows`, `ncols`: the [`Compressed`] field holding each dimension.
+/// - `key`: maps `(i, j)` to `(major, minor)`; as a swap or the identity it also maps back.
+/// - `fold`: the [`Compressed`] fold that visits entries in column-major order.
+/// - `mul_dense`, `dense_mul`: the [`Compressed`] products computing `M B` and `A M`.
+macro_rules! impl_compressed_matrix {
+    (
+        $name:ident,
+        nrows: $nrows:ident,
+        ncols: $ncols:ident,
+        key: |$i:ident, $j:ident| $key:expr,
+        fold: $fold:ident,
+        mul_dense: $mul_dense:ident,
+        dense_mul: $dense_mul:ident $(,)?
+    ) => {
+        //{{{ impl: Shape
+        impl<T> $crate::common::Shape for $name<T>
+        where
+            T: Copy,
+        {
+            fn nrows(&self) -> usize {
+                self.storage.$nrows
+            }
+
+            fn ncols(&self) -> usize {
+                self.storage.$ncols
+            }
+        }
+        //}}}
+        //{{{ impl: Index
+        impl<T> std::ops::Index<(usize, usize)> for $name<T>
+        where
+            T: Copy,
+        {
+            type Output = T;
+
+            fn index(
+                &self,
+                ($i, $j): (usize, usize),
+            ) -> &Self::Output {
+                assert!(
+                    $i < self.storage.$nrows && $j < self.storage.$ncols,
+                    "Index out of bounds"
+                );
+                let (major, minor) = $key;
+                match self.storage.find(major, minor) {
+                    Some(p) => &self.storage.values[p],
+                    None => &self.zero,
+                }
+            }
+        }
+        //}}}
+        //{{{ impl: SubViewable
+        impl<T> $crate::subviews::SubViewable for $name<T>
+        where
+            T: Copy,
+        {
+            fn subview_range<'a>(
+                &'a self,
+                start_row: usize,
+                end_row: usize,
+                start_col: usize,
+                end_col: usize,
+            ) -> $crate::subviews::MatrixView<'a, $name<T>> {
+                $crate::subviews::MatrixView {
+                    matrix: self,
+                    start_row,
+                    start_col,
+                    nrows: end_row - start_row + 1,
+                    ncols: end_col - start_col + 1,
+                }
+            }
+        }
+        //}}}
+        //{{{ impl: ReduceOps
+        impl<T> $crate::common::ReduceOps for $name<T>
+        where
+            T: $crate::common::Zero + Copy,
+        {
+            type Item = T;
+            type Index = (usize, usize);
+
+            fn fold<B, F>(
+                &self,
+                init: B,
+                mut f: F,
+            ) -> B
+            where
+                F: FnMut(B, Self::Item) -> B,
+            {
+                self.storage.$fold(init, |acc, _, _, value| f(acc, value))
+            }
+
+            fn fold_indexed<B, F>(
+                &self,
+                init: B,
+                mut f: F,
+            ) -> B
+            where
+                F: FnMut(B, Self::Index, Self::Item) -> B,
+            {
+                self.storage
+                    .$fold(init, |acc, $i, $j, value| f(acc, $key, value))
+            }
+        }
+        //}}}
+        //{{{ impl: ReduceOps for MatrixView
+        impl<'a, T> $crate::common::ReduceOps for $crate::subviews::MatrixView<'a, $name<T>>
+        where
+            T: Copy,
+        {
+            type Item = T;
+            type Index = (usize, usize);
+
+            fn fold<B, F>(
+                &self,
+                init: B,
+                mut f: F,
+            ) -> B
+            where
+                F: FnMut(B, Self::Item) -> B,
+            {
+                let mut acc = init;
+                for col in 0..self.ncols {
+                    for row in 0..self.nrows {
+                        acc = f(acc, self[(row, col)]);
+                    }
+                }
+                acc
+            }
+
+            fn fold_indexed<B, F>(
+                &self,
+                init: B,
+                mut f: F,
+            ) -> B
+            where
+                F: FnMut(B, Self::Index, Self::Item) -> B,
+            {
+                let mut acc = init;
+                for col in 0..self.ncols {
+                    for row in 0..self.nrows {
+                        acc = f(acc, (row, col), self[(row, col)]);
+                    }
+                }
+                acc
+            }
+        }
+        //}}}
+        //{{{ impl: MatrixNorms
+        impl<T> $crate::common::MatrixNorms for $name<T>
+        where
+            T: $crate::common::ComplexField,
+        {
+            type ScalarType = T;
+        }
+        //}}}
+        //{{{ impl: Add
+        impl<'a, T> std::ops::Add<&'a $name<T>> for &'a $name<T>
+        where
+            T: $crate::common::Field + $crate::common::Zero + Copy,
+        {
+            type Output = $name<T>;
+
+            /// Adds two sparse matrices. Entries that cancel exactly are not stored.
+            fn add(
+                self,
+                rhs: &'a $name<T>,
+            ) -> Self::Output {
+                assert_eq!(
+                    $crate::common::Shape::size(self),
+                    $crate::common::Shape::size(rhs),
+                    "Matrix dimensions must match for addition"
+                );
+                $name::from_compressed(self.storage.combine(&rhs.storage, |a, b| a + b))
+            }
+        }
+        //}}}
+        //{{{ impl: Sub
+        impl<'a, T> std::ops::Sub<&'a $name<T>> for &'a $name<T>
+        where
+            T: $crate::common::Field + $crate::common::Zero + Copy,
+        {
+            type Output = $name<T>;
+
+            /// Subtracts two sparse matrices. Entries that cancel exactly are not stored.
+            fn sub(
+                self,
+                rhs: &'a $name<T>,
+            ) -> Self::Output {
+                assert_eq!(
+                    $crate::common::Shape::size(self),
+                    $crate::common::Shape::size(rhs),
+                    "Matrix dimensions must match for subtraction"
+                );
+                $name::from_compressed(self.storage.combine(&rhs.storage, |a, b| a - b))
+            }
+        }
+        //}}}
+        //{{{ impl: Mul<T>
+        impl<T> std::ops::Mul<T> for &$name<T>
+        where
+            T: $crate::common::Field + $crate::common::Zero + Copy,
+        {
+            type Output = $name<T>;
+
+            /// Scales every entry, keeping the sparsity pattern even when `rhs` is zero.
+            fn mul(
+                self,
+                rhs: T,
+            ) -> Self::Output {
+                $name::from_compressed(self.storage.map_values(|v| v * rhs))
+            }
+        }
+
+        impl<T> std::ops::Mul<T> for $name<T>
+        where
+            T: $crate::common::Field + $crate::common::Zero + Copy,
+        {
+            type Output = $name<T>;
+
+            fn mul(
+                self,
+                rhs: T,
+            ) -> Self::Output {
+                &self * rhs
+            }
+        }
+        //}}}
+        //{{{ impl: MatMul<&'a DMatrix<T>>
+        impl<'a, T> $crate::common::MatMul<&'a $crate::dmatrix::DMatrix<T>> for &'a $name<T>
+        where
+            T: $crate::common::Field + $crate::common::Zero + Copy,
+        {
+            type Output = $crate::dmatrix::DMatrix<T>;
+
+            fn matmul(
+                self,
+                rhs: &'a $crate::dmatrix::DMatrix<T>,
+            ) -> Self::Output {
+                let (nrows, ncols) = (self.storage.$nrows, self.storage.$ncols);
+                assert_eq!(
+                    ncols, rhs.nrows,
+                    "Matrix dimensions are incompatible for multiplication: {}x{} and {}x{}",
+                    nrows, ncols, rhs.nrows, rhs.ncols
+                );
+                $crate::dmatrix::DMatrix {
+                    data: self.storage.$mul_dense(&rhs.data, rhs.ncols),
+                    nrows,
+                    ncols: rhs.ncols,
+                }
+            }
+        }
+        //}}}
+        //{{{ impl: MatMul<&'a $name<T>> for &'a DMatrix<T>
+        impl<'a, T> $crate::common::MatMul<&'a $name<T>> for &'a $crate::dmatrix::DMatrix<T>
+        where
+            T: $crate::common::Field + $crate::common::Zero + Copy,
+        {
+            type Output = $crate::dmatrix::DMatrix<T>;
+
+            fn matmul(
+                self,
+                rhs: &'a $name<T>,
+            ) -> Self::Output {
+                let (nrows, ncols) = (rhs.storage.$nrows, rhs.storage.$ncols);
+                assert_eq!(
+                    self.ncols, nrows,
+                    "Matrix dimensions are incompatible for multiplication: {}x{} and {}x{}",
+                    self.nrows, self.ncols, nrows, ncols
+                );
+                $crate::dmatrix::DMatrix {
+                    data: rhs.storage.$dense_mul(&self.data, self.nrows),
+                    nrows: self.nrows,
+                    ncols,
+                }
+            }
+        }
+        //}}}
+    };
+}
+pub(crate) use impl_compressed_matrix;
+//}}}
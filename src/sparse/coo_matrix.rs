@@ -0,0 +1,143 @@
+//! Coordinate-format builder for sparse matrices.
+//!
+//! Provides [`CooMatrix<T>`], which collects `(row, col, value)` triplets in any order and with
+//! repeats, as produced by finite-element or graph assembly. It is not meant for arithmetic;
+//! [`to_csr`](CooMatrix::to_csr) and [`to_csc`](CooMatrix::to_csc) compress it into the formats
+//! that are, summing the values of repeated entries.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::compressed::Compressed;
+use super::csc_matrix::CscMatrix;
+use super::csr_matrix::CsrMatrix;
+use crate::common::{Field, Zero};
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ struct: CooMatrix
+/// An m×n sparse matrix under assembly, held as a list of `(row, col, value)` triplets.
+///
+/// Triplets may repeat a position; the values of repeated positions are summed on conversion.
+#[derive(Debug, Clone)]
+pub struct CooMatrix<T>
+where
+    T: Copy,
+{
+    /// Row index of each triplet.
+    pub(crate) rows: Vec<usize>,
+    /// Column index of each triplet.
+    pub(crate) cols: Vec<usize>,
+    /// Value of each triplet.
+    pub(crate) values: Vec<T>,
+    /// Number of rows in the matrix.
+    pub(crate) nrows: usize,
+    /// Number of columns in the matrix.
+    pub(crate) ncols: usize,
+}
+//}}}
+//{{{ impl: CooMatrix<T>
+impl<T> CooMatrix<T>
+where
+    T: Copy,
+{
+    //{{{ fun: new
+    /// Creates an empty `nrows`×`ncols` matrix.
+    pub fn new(
+        nrows: usize,
+        ncols: usize,
+    ) -> Self {
+        Self::with_capacity(nrows, ncols, 0)
+    }
+    //}}}
+    //{{{ fun: with_capacity
+    /// Creates an empty `nrows`×`ncols` matrix with room for `capacity` triplets.
+    pub fn with_capacity(
+        nrows: usize,
+        ncols: usize,
+        capacity: usize,
+    ) -> Self {
+        Self {
+            rows: Vec::with_capacity(capacity),
+            cols: Vec::with_capacity(capacity),
+            values: Vec::with_capacity(capacity),
+            nrows,
+            ncols,
+        }
+    }
+    //}}}
+    //{{{ fun: push
+    /// Appends the triplet `(i, j, value)`, to be added to any other value at `(i, j)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `(i, j)` is out of bounds.
+    pub fn push(
+        &mut self,
+        i: usize,
+        j: usize,
+        value: T,
+    ) {
+        assert!(i < self.nrows && j < self.ncols, "Index out of bounds");
+        self.rows.push(i);
+        self.cols.push(j);
+        self.values.push(value);
+    }
+    //}}}
+    //{{{ fun: nrows
+    /// Returns the number of rows.
+    pub fn nrows(&self) -> usize {
+        self.nrows
+    }
+    //}}}
+    //{{{ fun: ncols
+    /// Returns the number of columns.
+    pub fn ncols(&self) -> usize {
+        self.ncols
+    }
+    //}}}
+    //{{{ fun: nnz
+    /// Returns the number of triplets pushed, counting repeated positions separately.
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+    //}}}
+    //{{{ fun: to_csr
+    /// Compresses the triplets into a [`CsrMatrix`], summing repeated positions.
+    ///
+    /// Sums that come out zero are kept as explicit entries.
+    pub fn to_csr(&self) -> CsrMatrix<T>
+    where
+        T: Field + Zero,
+    {
+        CsrMatrix::from_compressed(Compressed::from_triplets(
+            self.nrows,
+            self.ncols,
+            &self.rows,
+            &self.cols,
+            &self.values,
+        ))
+    }
+    //}}}
+    //{{{ fun: to_csc
+    /// Compresses the triplets into a [`CscMatrix`], summing repeated positions.
+    ///
+    /// Sums that come out zero are kept as explicit entries.
+    pub fn to_csc(&self) -> CscMatrix<T>
+    where
+        T: Field + Zero,
+    {
+        CscMatrix::from_compressed(Compressed::from_triplets(
+            self.ncols,
+            self.nrows,
+            &self.cols,
+            &self.rows,
+            &self.values,
+        ))
+    }
+    //}}}
+}
+//}}}
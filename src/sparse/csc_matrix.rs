@@ -0,0 +1,178 @@
+//! Compressed sparse column matrix.
+//!
+//! Provides [`CscMatrix<T>`], the column-wise counterpart of
+//! [`CsrMatrix<T>`](crate::CsrMatrix): the entries of column `j` occupy positions
+//! `col_offsets[j]..col_offsets[j + 1]` of the row index and value arrays, sorted by row. Column
+//! access is cheap, and since [`DMatrix`] is column-major the layout matches dense storage, so
+//! column-major reductions visit the stored entries in order. It implements the same traits as
+//! the row format.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::compressed::{impl_compressed_matrix, Compressed};
+use super::csr_matrix::CsrMatrix;
+use crate::common::{Field, Shape, Zero};
+use crate::dmatrix::DMatrix;
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ struct: CscMatrix
+/// An m×n sparse matrix in compressed sparse column format.
+///
+/// So, for example, the 3×4 matrix:
+/// ```ignore
+/// 1 0 2 0
+/// 0 0 0 0
+/// 0 3 0 4
+/// ```
+/// is stored as:
+/// ```ignore
+/// col_offsets = [0, 1, 2, 3, 4]
+/// row_indices = [0, 2, 0, 2]
+/// values      = [1, 3, 2, 4]
+/// ```
+#[derive(Debug, Clone)]
+pub struct CscMatrix<T>
+where
+    T: Copy,
+{
+    /// Columns as major lines, rows as minor indices.
+    pub(crate) storage: Compressed<T>,
+    /// Zero value that indexing returns a reference to for entries that are not stored.
+    pub(crate) zero: T,
+}
+//}}}
+//{{{ impl: CscMatrix<T>
+impl<T> CscMatrix<T>
+where
+    T: Copy,
+{
+    //{{{ fun: from_compressed
+    pub(crate) fn from_compressed(storage: Compressed<T>) -> Self
+    where
+        T: Zero,
+    {
+        Self {
+            storage,
+            zero: T::zero(),
+        }
+    }
+    //}}}
+    //{{{ fun: zeros
+    /// Creates an `nrows`×`ncols` matrix with no stored entries.
+    pub fn zeros(
+        nrows: usize,
+        ncols: usize,
+    ) -> Self
+    where
+        T: Zero,
+    {
+        Self::from_compressed(Compressed {
+            nmajor: ncols,
+            nminor: nrows,
+            offsets: vec![0; ncols + 1],
+            indices: Vec::new(),
+            values: Vec::new(),
+        })
+    }
+    //}}}
+    //{{{ fun: from_dmatrix
+    /// Creates a sparse matrix from the non-zero entries of `a`.
+    pub fn from_dmatrix(a: &DMatrix<T>) -> Self
+    where
+        T: Field + Zero,
+    {
+        Self::from_compressed(Compressed::from_dense(a.ncols, a.nrows, |j, i| {
+            a.data[i + j * a.nrows]
+        }))
+    }
+    //}}}
+    //{{{ fun: to_dmatrix
+    /// Expands the sparse matrix into a dense [`DMatrix`].
+    pub fn to_dmatrix(&self) -> DMatrix<T>
+    where
+        T: Zero,
+    {
+        let mut a = DMatrix::zeros(self.nrows(), self.ncols());
+        for j in 0..self.ncols() {
+            let (rows, values) = self.storage.line(j);
+            for (&i, &value) in rows.iter().zip(values) {
+                a.data[i + j * a.nrows] = value;
+            }
+        }
+        a
+    }
+    //}}}
+    //{{{ fun: to_csr
+    /// Converts to compressed sparse row format.
+    pub fn to_csr(&self) -> CsrMatrix<T>
+    where
+        T: Zero,
+    {
+        CsrMatrix::from_compressed(self.storage.swap_major())
+    }
+    //}}}
+    //{{{ fun: transpose
+    /// Returns the n×m transpose.
+    pub fn transpose(&self) -> Self
+    where
+        T: Zero,
+    {
+        Self::from_compressed(self.storage.swap_major())
+    }
+    //}}}
+    //{{{ fun: nnz
+    /// Returns the number of stored entries.
+    pub fn nnz(&self) -> usize {
+        self.storage.nnz()
+    }
+    //}}}
+    //{{{ fun: col_offsets
+    /// Returns the `ncols + 1` offsets at which each column starts in `row_indices` and `values`.
+    pub fn col_offsets(&self) -> &[usize] {
+        &self.storage.offsets
+    }
+    //}}}
+    //{{{ fun: row_indices
+    /// Returns the row index of each stored entry.
+    pub fn row_indices(&self) -> &[usize] {
+        &self.storage.indices
+    }
+    //}}}
+    //{{{ fun: values
+    /// Returns the value of each stored entry.
+    pub fn values(&self) -> &[T] {
+        &self.storage.values
+    }
+    //}}}
+    //{{{ fun: get
+    /// Returns `A[i, j]`, which is zero if the entry is not stored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `(i, j)` is out of bounds.
+    pub fn get(
+        &self,
+        i: usize,
+        j: usize,
+    ) -> T {
+        self[(i, j)]
+    }
+    //}}}
+}
+//}}}
+//{{{ impls: Shape, Index, SubViewable, ReduceOps, MatrixNorms, Add, Sub, Mul, MatMul
+impl_compressed_matrix! {
+    CscMatrix,
+    nrows: nminor,
+    ncols: nmajor,
+    key: |i, j| (j, i),
+    fold: fold_major_order,
+    mul_dense: tmul_dense,
+    dense_mul: dense_tmul,
+}
+//}}}
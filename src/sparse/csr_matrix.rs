@@ -0,0 +1,179 @@
+//! Compressed sparse row matrix.
+//!
+//! Provides [`CsrMatrix<T>`], which stores the non-zero entries of an m×n matrix row by row: the
+//! entries of row `i` occupy positions `row_offsets[i]..row_offsets[i + 1]` of the column index
+//! and value arrays, sorted by column. Row access and products with a dense right-hand side are
+//! cheap, as each output entry is a dot product over one row. The matrix implements [`Shape`],
+//! `Index<(usize, usize)>`, [`SubViewable`](crate::SubViewable) and
+//! [`ReduceOps`](crate::ReduceOps), so that views, norms and reductions written for dense matrices
+//! read it as if it were dense.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use super::compressed::{impl_compressed_matrix, Compressed};
+use super::csc_matrix::CscMatrix;
+use crate::common::{Field, Shape, Zero};
+use crate::dmatrix::DMatrix;
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ struct: CsrMatrix
+/// An m×n sparse matrix in compressed sparse row format.
+///
+/// So, for example, the 3×4 matrix:
+/// ```ignore
+/// 1 0 2 0
+/// 0 0 0 0
+/// 0 3 0 4
+/// ```
+/// is stored as:
+/// ```ignore
+/// row_offsets = [0, 2, 2, 4]
+/// col_indices = [0, 2, 1, 3]
+/// values      = [1, 2, 3, 4]
+/// ```
+#[derive(Debug, Clone)]
+pub struct CsrMatrix<T>
+where
+    T: Copy,
+{
+    /// Rows as major lines, columns as minor indices.
+    pub(crate) storage: Compressed<T>,
+    /// Zero value that indexing returns a reference to for entries that are not stored.
+    pub(crate) zero: T,
+}
+//}}}
+//{{{ impl: CsrMatrix<T>
+impl<T> CsrMatrix<T>
+where
+    T: Copy,
+{
+    //{{{ fun: from_compressed
+    pub(crate) fn from_compressed(storage: Compressed<T>) -> Self
+    where
+        T: Zero,
+    {
+        Self {
+            storage,
+            zero: T::zero(),
+        }
+    }
+    //}}}
+    //{{{ fun: zeros
+    /// Creates an `nrows`×`ncols` matrix with no stored entries.
+    pub fn zeros(
+        nrows: usize,
+        ncols: usize,
+    ) -> Self
+    where
+        T: Zero,
+    {
+        Self::from_compressed(Compressed {
+            nmajor: nrows,
+            nminor: ncols,
+            offsets: vec![0; nrows + 1],
+            indices: Vec::new(),
+            values: Vec::new(),
+        })
+    }
+    //}}}
+    //{{{ fun: from_dmatrix
+    /// Creates a sparse matrix from the non-zero entries of `a`.
+    pub fn from_dmatrix(a: &DMatrix<T>) -> Self
+    where
+        T: Field + Zero,
+    {
+        Self::from_compressed(Compressed::from_dense(a.nrows, a.ncols, |i, j| {
+            a.data[i + j * a.nrows]
+        }))
+    }
+    //}}}
+    //{{{ fun: to_dmatrix
+    /// Expands the sparse matrix into a dense [`DMatrix`].
+    pub fn to_dmatrix(&self) -> DMatrix<T>
+    where
+        T: Zero,
+    {
+        let mut a = DMatrix::zeros(self.nrows(), self.ncols());
+        for i in 0..self.nrows() {
+            let (cols, values) = self.storage.line(i);
+            for (&j, &value) in cols.iter().zip(values) {
+                a.data[i + j * a.nrows] = value;
+            }
+        }
+        a
+    }
+    //}}}
+    //{{{ fun: to_csc
+    /// Converts to compressed sparse column format.
+    pub fn to_csc(&self) -> CscMatrix<T>
+    where
+        T: Zero,
+    {
+        CscMatrix::from_compressed(self.storage.swap_major())
+    }
+    //}}}
+    //{{{ fun: transpose
+    /// Returns the n×m transpose.
+    pub fn transpose(&self) -> Self
+    where
+        T: Zero,
+    {
+        Self::from_compressed(self.storage.swap_major())
+    }
+    //}}}
+    //{{{ fun: nnz
+    /// Returns the number of stored entries.
+    pub fn nnz(&self) -> usize {
+        self.storage.nnz()
+    }
+    //}}}
+    //{{{ fun: row_offsets
+    /// Returns the `nrows + 1` offsets at which each row starts in `col_indices` and `values`.
+    pub fn row_offsets(&self) -> &[usize] {
+        &self.storage.offsets
+    }
+    //}}}
+    //{{{ fun: col_indices
+    /// Returns the column index of each stored entry.
+    pub fn col_indices(&self) -> &[usize] {
+        &self.storage.indices
+    }
+    //}}}
+    //{{{ fun: values
+    /// Returns the value of each stored entry.
+    pub fn values(&self) -> &[T] {
+        &self.storage.values
+    }
+    //}}}
+    //{{{ fun: get
+    /// Returns `A[i, j]`, which is zero if the entry is not stored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `(i, j)` is out of bounds.
+    pub fn get(
+        &self,
+        i: usize,
+        j: usize,
+    ) -> T {
+        self[(i, j)]
+    }
+    //}}}
+}
+//}}}
+//{{{ impls: Shape, Index, SubViewable, ReduceOps, MatrixNorms, Add, Sub, Mul, MatMul
+impl_compressed_matrix! {
+    CsrMatrix,
+    nrows: nmajor,
+    ncols: nminor,
+    key: |i, j| (i, j),
+    fold: fold_minor_order,
+    mul_dense: mul_dense,
+    dense_mul: dense_mul,
+}
+//}}}
@@ -0,0 +1,27 @@
+//! Sparse matrix types in coordinate and compressed formats.
+//!
+//! Defines [`CooMatrix<T>`], a triplet list for assembling a matrix entry by entry, and the two
+//! compressed formats it converts to: [`CsrMatrix<T>`], stored row by row, and [`CscMatrix<T>`],
+//! stored column by column. The compressed formats convert to and from
+//! [`DMatrix`](crate::DMatrix) and each other, support elementwise addition, subtraction and
+//! scaling, and multiply dense matrices on either side through [`MatMul`](crate::MatMul). They
+//! also implement [`Shape`](crate::Shape) and `Index<(usize, usize)>`, which is enough for the
+//! read-only views and reductions.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+mod compressed;
+mod coo_matrix;
+mod csc_matrix;
+mod csr_matrix;
+
+pub use coo_matrix::CooMatrix;
+pub use csc_matrix::CscMatrix;
+pub use csr_matrix::CsrMatrix;
@@ -8,11 +8,14 @@
 //--------------------------------------------------------------------------------------------------
 
 //{{{ crate imports
-use crate::apply_for_all_types;
-use crate::common::{tuple_index, Field, MatrixExpr, ReduceOps, ScalarExpr, Shape, TransformOps};
+use crate::common::{
+    tuple_index, AxisReduceOps, ComplexField, Field, MatrixExpr, MatrixNorms, ReduceOps,
+    ScalarExpr, Shape, TransformOps,
+};
 use crate::dmatrix::DMatrix;
 use crate::expression::binary_expr::{AddOp, BinopExpr, DivOp, MulOp, SubOp};
 use crate::expression::unary_expr::{NegOp, UnaryExpr};
+use crate::{apply_for_all_complex_types, apply_for_all_types};
 use std::collections::HashSet;
 //}}}
 //{{{ std imports
@@ -198,6 +201,15 @@ where
     }
 }
 //}}}
+//{{{ impl: MatrixNorms for MatrixView
+impl<'a, Mat, T> MatrixNorms for MatrixView<'a, Mat>
+where
+    Mat: Shape + Index<(usize, usize), Output = T>,
+    T: ComplexField,
+{
+    type ScalarType = T;
+}
+//}}}
 
 //{{{ struct: MatrixViewMut
 /// Mutable subview of a matrix, allowing in-place modification of a rectangular region.
@@ -437,6 +449,15 @@ where
     }
 }
 //}}}
+//{{{ impl: MatrixNorms for MatrixViewMut
+impl<'a, Mat, T> MatrixNorms for MatrixViewMut<'a, Mat>
+where
+    Mat: Shape + Index<(usize, usize), Output = T> + IndexMut<(usize, usize)>,
+    T: ComplexField,
+{
+    type ScalarType = T;
+}
+//}}}
 
 //{{{ struct: IndexedMatrixView
 /// Immutable subview of a matrix selected by row and column index lists.
@@ -640,6 +661,14 @@ where
     }
 }
 //}}}
+//{{{ impl: AxisReduceOps for IndexedMatrixView
+impl<'a, Mat, T> AxisReduceOps for IndexedMatrixView<'a, Mat>
+where
+    Mat: Shape + Index<(usize, usize), Output = T>,
+    T: Copy,
+{
+}
+//}}}
 
 //{{{ struct: IndexedMatrixViewMut
 /// Mutable subview of a matrix selected by unique row and column index lists.
@@ -936,6 +965,14 @@ where
     }
 }
 //}}}
+//{{{ impl: AxisReduceOps for IndexedMatrixViewMut
+impl<'a, Mat, T> AxisReduceOps for IndexedMatrixViewMut<'a, Mat>
+where
+    Mat: Shape + Index<(usize, usize), Output = T> + IndexMut<(usize, usize)>,
+    T: Copy,
+{
+}
+//}}}
 //{{{ impl: TransformOps for IndexedMatrixViewMut
 impl<'a, Mat, T> TransformOps for IndexedMatrixViewMut<'a, Mat>
 where
@@ -1321,9 +1358,13 @@ macro_rules! impl_indexed_matrix_view_mut_scalar_ops {
 }
 
 apply_for_all_types!(impl_matrix_view_scalar_ops);
+apply_for_all_complex_types!(impl_matrix_view_scalar_ops);
 apply_for_all_types!(impl_matrix_view_mut_scalar_ops);
+apply_for_all_complex_types!(impl_matrix_view_mut_scalar_ops);
 apply_for_all_types!(impl_indexed_matrix_view_scalar_ops);
+apply_for_all_complex_types!(impl_indexed_matrix_view_scalar_ops);
 apply_for_all_types!(impl_indexed_matrix_view_mut_scalar_ops);
+apply_for_all_complex_types!(impl_indexed_matrix_view_mut_scalar_ops);
 //}}}
 
 //{{{ trait: SubViewable
@@ -0,0 +1,119 @@
+//! Non-owning triangular view of a square matrix.
+//!
+//! Provides [`Triangular<'a, M>`], which borrows a square matrix and marks which of its triangles
+//! holds a triangular operand and whether its diagonal is implicitly one. The view reads only the
+//! selected triangle, so the factors returned by `lu()`, `qr()` or `cholesky()` can be used as-is
+//! even when the other triangle holds unrelated data. [`MatMul`](crate::MatMul) on the view
+//! dispatches to BLAS `?trmm`, and `solve` to `?trsv`/`?trsm`, for both `DMatrix` and `SMatrix`.
+//--------------------------------------------------------------------------------------------------
+
+//{{{ crate imports
+use crate::common::{Diagonal, Shape, Triangle};
+//}}}
+//{{{ std imports
+//}}}
+//{{{ dep imports
+//}}}
+//--------------------------------------------------------------------------------------------------
+
+//{{{ struct: Triangular
+/// A borrowed square matrix viewed as a lower- or upper-triangular operand.
+///
+/// Entries outside the selected triangle, and the diagonal when it is [`Diagonal::Unit`], are
+/// never read.
+#[derive(Debug)]
+pub struct Triangular<'a, M> {
+    /// The borrowed matrix.
+    pub(crate) matrix: &'a M,
+    /// The triangle that holds the operand.
+    pub(crate) triangle: Triangle,
+    /// Whether the diagonal is read or taken to be all ones.
+    pub(crate) diag: Diagonal,
+}
+//}}}
+//{{{ impl: Clone for Triangular<'a, M>
+impl<M> Clone for Triangular<'_, M> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<M> Copy for Triangular<'_, M> {}
+//}}}
+//{{{ impl: Triangular<'a, M>
+impl<'a, M> Triangular<'a, M>
+where
+    M: Shape,
+{
+    //{{{ fun: new
+    /// Creates a view of the given triangle of `matrix`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `matrix` is not square.
+    pub fn new(
+        matrix: &'a M,
+        triangle: Triangle,
+        diag: Diagonal,
+    ) -> Self {
+        assert_eq!(
+            matrix.nrows(),
+            matrix.ncols(),
+            "Matrix must be square for a triangular view"
+        );
+        Self {
+            matrix,
+            triangle,
+            diag,
+        }
+    }
+    //}}}
+    //{{{ fun: lower
+    /// Creates a view of the lower triangle of `matrix`, diagonal included.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `matrix` is not square.
+    pub fn lower(matrix: &'a M) -> Self {
+        Self::new(matrix, Triangle::Lower, Diagonal::NonUnit)
+    }
+    //}}}
+    //{{{ fun: upper
+    /// Creates a view of the upper triangle of `matrix`, diagonal included.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `matrix` is not square.
+    pub fn upper(matrix: &'a M) -> Self {
+        Self::new(matrix, Triangle::Upper, Diagonal::NonUnit)
+    }
+    //}}}
+    //{{{ fun: unit_diagonal
+    /// Returns the same view with the diagonal taken to be all ones.
+    pub fn unit_diagonal(self) -> Self {
+        Self {
+            diag: Diagonal::Unit,
+            ..self
+        }
+    }
+    //}}}
+    //{{{ fun: matrix
+    /// Returns the borrowed matrix.
+    pub fn matrix(&self) -> &'a M {
+        self.matrix
+    }
+    //}}}
+    //{{{ fun: triangle
+    /// Returns the triangle that holds the operand.
+    pub fn triangle(&self) -> Triangle {
+        self.triangle
+    }
+    //}}}
+    //{{{ fun: diagonal
+    /// Returns whether the diagonal is read or taken to be all ones.
+    pub fn diagonal(&self) -> Diagonal {
+        self.diag
+    }
+    //}}}
+}
+//}}}
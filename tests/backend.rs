@@ -0,0 +1,558 @@
+//{{{ mod: backend_tests
+mod backend_tests {
+
+    use approx::assert_relative_eq;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    type C32 = Complex<f32>;
+    type C64 = Complex<f64>;
+    use topohedral_linalg::{
+        current_backend, set_backend, with_backend, Backend, Complex, DMatrix, Diagonal, MatMul,
+        MatrixOps, ReferenceBackend, Shape, Side, Transpose, Triangle,
+    };
+
+    //{{{ collection: helpers
+    /// Runs `f` on the pure-Rust reference backend.
+    fn on_reference<F, R>(f: F) -> R
+    where
+        F: FnOnce() -> R,
+    {
+        with_backend(Arc::new(ReferenceBackend), f)
+    }
+
+    fn random(
+        nrows: usize,
+        ncols: usize,
+    ) -> DMatrix<f64> {
+        DMatrix::<f64>::from_uniform_random(-1.0, 1.0, nrows, ncols)
+    }
+
+    fn spd(n: usize) -> DMatrix<f64> {
+        let a = random(n, n);
+        let mut out = (&a).matmul(a.transpose());
+        for i in 0..n {
+            out[(i, i)] += n as f64;
+        }
+        out
+    }
+
+    fn assert_matrix_eq(
+        a: &DMatrix<f64>,
+        b: &DMatrix<f64>,
+    ) {
+        assert_eq!(a.size(), b.size());
+        for (x, y) in a.iter().zip(b.iter()) {
+            assert_relative_eq!(x, y, epsilon = 1e-10);
+        }
+    }
+
+    /// Compares matrices whose columns are determined only up to sign.
+    fn assert_columns_eq_up_to_sign(
+        a: &DMatrix<f64>,
+        b: &DMatrix<f64>,
+    ) {
+        assert_eq!(a.size(), b.size());
+        for j in 0..a.ncols() {
+            let sign = (0..a.nrows())
+                .map(|i| a[(i, j)] * b[(i, j)])
+                .sum::<f64>()
+                .signum();
+            for i in 0..a.nrows() {
+                assert_relative_eq!(a[(i, j)], sign * b[(i, j)], epsilon = 1e-10);
+            }
+        }
+    }
+
+    fn sorted(mut w: Vec<Complex<f64>>) -> Vec<Complex<f64>> {
+        w.sort_by(|x, y| x.re.total_cmp(&y.re).then(x.im.total_cmp(&y.im)));
+        w
+    }
+    //}}}
+    //{{{ collection: reference diff tests
+    #[test]
+    fn test_matmul_matches_reference() {
+        let a = random(7, 5);
+        let b = random(5, 4);
+        let x = random(5, 1);
+
+        assert_matrix_eq(&(&a).matmul(&b), &on_reference(|| (&a).matmul(&b)));
+        assert_matrix_eq(&(&a).matmul(&x), &on_reference(|| (&a).matmul(&x)));
+        assert_matrix_eq(
+            &(&a).matmul(a.transpose()),
+            &on_reference(|| (&a).matmul(a.transpose())),
+        );
+    }
+
+    #[test]
+    fn test_lu_solve_determinant_match_reference() {
+        let a = random(6, 6);
+        let b = random(6, 2);
+
+        let lu = a.lu().unwrap();
+        let lu_ref = on_reference(|| a.lu().unwrap());
+        assert_matrix_eq(&lu.l, &lu_ref.l);
+        assert_matrix_eq(&lu.u, &lu_ref.u);
+        assert_matrix_eq(&lu.p, &lu_ref.p);
+
+        let x = a.solve(&b).unwrap();
+        assert_matrix_eq(&x, &on_reference(|| a.solve(&b).unwrap()));
+        assert_relative_eq!(
+            a.determinant(),
+            on_reference(|| a.determinant()),
+            epsilon = 1e-10
+        );
+    }
+
+    #[test]
+    fn test_cholesky_matches_reference() {
+        let a = spd(6);
+        let l = a.cholesky().unwrap().l;
+        assert_matrix_eq(&l, &on_reference(|| a.cholesky().unwrap().l));
+    }
+
+    #[test]
+    fn test_qr_matches_reference() {
+        let a = random(7, 4);
+        let qr = a.qr().unwrap();
+        let qr_ref = on_reference(|| a.qr().unwrap());
+        assert_matrix_eq(&qr.q, &qr_ref.q);
+        assert_matrix_eq(&qr.r, &qr_ref.r);
+    }
+
+    #[test]
+    fn test_symeig_matches_reference() {
+        let a = spd(6);
+        let eig = a.symeig().unwrap();
+        let eig_ref = on_reference(|| a.symeig().unwrap());
+        for (w, w_ref) in eig.eigvals.iter().zip(eig_ref.eigvals.iter()) {
+            assert_relative_eq!(w, w_ref, epsilon = 1e-10);
+        }
+        assert_columns_eq_up_to_sign(&eig.eigvecs, &eig_ref.eigvecs);
+    }
+
+    #[test]
+    fn test_hermitian_symeig_matches_reference() {
+        let n = 5;
+        let re = random(n, n);
+        let im = random(n, n);
+        let mut a = DMatrix::<Complex<f64>>::zeros(n, n);
+        for i in 0..n {
+            for j in 0..n {
+                a[(i, j)] = Complex::new(re[(i, j)] + re[(j, i)], im[(i, j)] - im[(j, i)]);
+            }
+        }
+        let eigvals = a.symeig().unwrap().eigvals;
+        let eigvals_ref = on_reference(|| a.symeig().unwrap().eigvals);
+        for (w, w_ref) in eigvals.iter().zip(eigvals_ref.iter()) {
+            assert_relative_eq!(w, w_ref, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_eig_matches_reference() {
+        // Eigenvalue order depends on the deflation order of the QR iteration
+        let a = random(6, 6);
+        let eigvals = sorted(a.eig().unwrap().eigvals);
+        let eigvals_ref = sorted(on_reference(|| a.eig().unwrap().eigvals));
+        for (w, w_ref) in eigvals.iter().zip(eigvals_ref.iter()) {
+            assert_relative_eq!(w.re, w_ref.re, epsilon = 1e-10);
+            assert_relative_eq!(w.im, w_ref.im, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_schur_reconstructs_on_reference() {
+        let a = random(6, 6);
+        let schur = on_reference(|| a.schur().unwrap());
+        let q = &schur.q;
+        let qtqt = (&(q.matmul(&schur.t))).matmul(q.transpose());
+        assert_matrix_eq(&qtqt, &a);
+    }
+    //}}}
+    //{{{ collection: selection tests
+    /// Implements the listed `Backend` methods by forwarding them to `ReferenceBackend`.
+    macro_rules! forward_to_reference {
+        ($($name:ident($($arg:ident: $ty:ty),*) $(-> $ret:ty)?;)*) => {$(
+            fn $name(&self, $($arg: $ty),*) $(-> $ret)? {
+                ReferenceBackend.$name($($arg),*)
+            }
+        )*};
+    }
+
+    /// Counts the `dgemm` and `dgetrf` calls it receives and runs every routine on the reference
+    /// implementation.
+    #[derive(Default)]
+    struct CountingBackend {
+        gemm_calls: AtomicUsize,
+        getrf_calls: AtomicUsize,
+    }
+
+    #[rustfmt::skip]
+    impl Backend for CountingBackend {
+        fn dgemm(&self, transa: Transpose, transb: Transpose, m: i32, n: i32, k: i32, alpha: f64,
+                 a: &[f64], lda: i32, b: &[f64], ldb: i32, beta: f64, c: &mut [f64], ldc: i32) {
+            self.gemm_calls.fetch_add(1, Ordering::SeqCst);
+            ReferenceBackend.dgemm(transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc);
+        }
+
+        fn dgetrf(&self, m: i32, n: i32, a: &mut [f64], lda: i32, ipiv: &mut [i32]) -> i32 {
+            self.getrf_calls.fetch_add(1, Ordering::SeqCst);
+            ReferenceBackend.dgetrf(m, n, a, lda, ipiv)
+        }
+
+        forward_to_reference! {
+            sgemm(transa: Transpose, transb: Transpose, m: i32, n: i32, k: i32, alpha: f32,
+                a: &[f32], lda: i32, b: &[f32], ldb: i32, beta: f32, c: &mut [f32], ldc: i32);
+            cgemm(transa: Transpose, transb: Transpose, m: i32, n: i32, k: i32, alpha: C32,
+                a: &[C32], lda: i32, b: &[C32], ldb: i32, beta: C32, c: &mut [C32], ldc: i32);
+            zgemm(transa: Transpose, transb: Transpose, m: i32, n: i32, k: i32, alpha: C64,
+                a: &[C64], lda: i32, b: &[C64], ldb: i32, beta: C64, c: &mut [C64], ldc: i32);
+            sgemv(trans: Transpose, m: i32, n: i32, alpha: f32, a: &[f32], lda: i32, x: &[f32],
+                incx: i32, beta: f32, y: &mut [f32], incy: i32);
+            dgemv(trans: Transpose, m: i32, n: i32, alpha: f64, a: &[f64], lda: i32, x: &[f64],
+                incx: i32, beta: f64, y: &mut [f64], incy: i32);
+            cgemv(trans: Transpose, m: i32, n: i32, alpha: C32, a: &[C32], lda: i32, x: &[C32],
+                incx: i32, beta: C32, y: &mut [C32], incy: i32);
+            zgemv(trans: Transpose, m: i32, n: i32, alpha: C64, a: &[C64], lda: i32, x: &[C64],
+                incx: i32, beta: C64, y: &mut [C64], incy: i32);
+            sgetrf(m: i32, n: i32, a: &mut [f32], lda: i32, ipiv: &mut [i32]) -> i32;
+            cgetrf(m: i32, n: i32, a: &mut [C32], lda: i32, ipiv: &mut [i32]) -> i32;
+            zgetrf(m: i32, n: i32, a: &mut [C64], lda: i32, ipiv: &mut [i32]) -> i32;
+            sgesv(n: i32, nrhs: i32, a: &mut [f32], lda: i32, ipiv: &mut [i32], b: &mut [f32],
+                ldb: i32) -> i32;
+            dgesv(n: i32, nrhs: i32, a: &mut [f64], lda: i32, ipiv: &mut [i32], b: &mut [f64],
+                ldb: i32) -> i32;
+            spotrf(uplo: u8, n: i32, a: &mut [f32], lda: i32) -> i32;
+            dpotrf(uplo: u8, n: i32, a: &mut [f64], lda: i32) -> i32;
+            cpotrf(uplo: u8, n: i32, a: &mut [C32], lda: i32) -> i32;
+            zpotrf(uplo: u8, n: i32, a: &mut [C64], lda: i32) -> i32;
+            sgeqrf(m: i32, n: i32, a: &mut [f32], lda: i32, tau: &mut [f32], work: &mut [f32],
+                lwork: i32) -> i32;
+            dgeqrf(m: i32, n: i32, a: &mut [f64], lda: i32, tau: &mut [f64], work: &mut [f64],
+                lwork: i32) -> i32;
+            cgeqrf(m: i32, n: i32, a: &mut [C32], lda: i32, tau: &mut [C32], work: &mut [C32],
+                lwork: i32) -> i32;
+            zgeqrf(m: i32, n: i32, a: &mut [C64], lda: i32, tau: &mut [C64], work: &mut [C64],
+                lwork: i32) -> i32;
+            sorgqr(m: i32, n: i32, k: i32, a: &mut [f32], lda: i32, tau: &[f32],
+                work: &mut [f32], lwork: i32) -> i32;
+            dorgqr(m: i32, n: i32, k: i32, a: &mut [f64], lda: i32, tau: &[f64],
+                work: &mut [f64], lwork: i32) -> i32;
+            cungqr(m: i32, n: i32, k: i32, a: &mut [C32], lda: i32, tau: &[C32],
+                work: &mut [C32], lwork: i32) -> i32;
+            zungqr(m: i32, n: i32, k: i32, a: &mut [C64], lda: i32, tau: &[C64],
+                work: &mut [C64], lwork: i32) -> i32;
+            ssyev(jobz: u8, uplo: u8, n: i32, a: &mut [f32], lda: i32, w: &mut [f32],
+                work: &mut [f32], lwork: i32) -> i32;
+            dsyev(jobz: u8, uplo: u8, n: i32, a: &mut [f64], lda: i32, w: &mut [f64],
+                work: &mut [f64], lwork: i32) -> i32;
+            cheev(jobz: u8, uplo: u8, n: i32, a: &mut [C32], lda: i32, w: &mut [f32],
+                work: &mut [C32], lwork: i32, rwork: &mut [f32]) -> i32;
+            zheev(jobz: u8, uplo: u8, n: i32, a: &mut [C64], lda: i32, w: &mut [f64],
+                work: &mut [C64], lwork: i32, rwork: &mut [f64]) -> i32;
+            sgeev(jobvl: u8, jobvr: u8, n: i32, a: &mut [f32], lda: i32, wr: &mut [f32],
+                wi: &mut [f32], vl: &mut [f32], ldvl: i32, vr: &mut [f32], ldvr: i32,
+                work: &mut [f32], lwork: i32) -> i32;
+            dgeev(jobvl: u8, jobvr: u8, n: i32, a: &mut [f64], lda: i32, wr: &mut [f64],
+                wi: &mut [f64], vl: &mut [f64], ldvl: i32, vr: &mut [f64], ldvr: i32,
+                work: &mut [f64], lwork: i32) -> i32;
+            cgeev(jobvl: u8, jobvr: u8, n: i32, a: &mut [C32], lda: i32, w: &mut [C32],
+                vl: &mut [C32], ldvl: i32, vr: &mut [C32], ldvr: i32, work: &mut [C32],
+                lwork: i32, rwork: &mut [f32]) -> i32;
+            zgeev(jobvl: u8, jobvr: u8, n: i32, a: &mut [C64], lda: i32, w: &mut [C64],
+                vl: &mut [C64], ldvl: i32, vr: &mut [C64], ldvr: i32, work: &mut [C64],
+                lwork: i32, rwork: &mut [f64]) -> i32;
+            sgees(jobvs: u8, n: i32, a: &mut [f32], lda: i32, wr: &mut [f32], wi: &mut [f32],
+                vs: &mut [f32], ldvs: i32, work: &mut [f32], lwork: i32) -> i32;
+            dgees(jobvs: u8, n: i32, a: &mut [f64], lda: i32, wr: &mut [f64], wi: &mut [f64],
+                vs: &mut [f64], ldvs: i32, work: &mut [f64], lwork: i32) -> i32;
+            strsv(uplo: Triangle, trans: Transpose, diag: Diagonal, n: i32, a: &[f32], lda: i32,
+                x: &mut [f32], incx: i32);
+            dtrsv(uplo: Triangle, trans: Transpose, diag: Diagonal, n: i32, a: &[f64], lda: i32,
+                x: &mut [f64], incx: i32);
+            ctrsv(uplo: Triangle, trans: Transpose, diag: Diagonal, n: i32, a: &[C32], lda: i32,
+                x: &mut [C32], incx: i32);
+            ztrsv(uplo: Triangle, trans: Transpose, diag: Diagonal, n: i32, a: &[C64], lda: i32,
+                x: &mut [C64], incx: i32);
+            strsm(side: Side, uplo: Triangle, transa: Transpose, diag: Diagonal, m: i32, n: i32,
+                alpha: f32, a: &[f32], lda: i32, b: &mut [f32], ldb: i32);
+            dtrsm(side: Side, uplo: Triangle, transa: Transpose, diag: Diagonal, m: i32, n: i32,
+                alpha: f64, a: &[f64], lda: i32, b: &mut [f64], ldb: i32);
+            ctrsm(side: Side, uplo: Triangle, transa: Transpose, diag: Diagonal, m: i32, n: i32,
+                alpha: C32, a: &[C32], lda: i32, b: &mut [C32], ldb: i32);
+            ztrsm(side: Side, uplo: Triangle, transa: Transpose, diag: Diagonal, m: i32, n: i32,
+                alpha: C64, a: &[C64], lda: i32, b: &mut [C64], ldb: i32);
+            strmm(side: Side, uplo: Triangle, transa: Transpose, diag: Diagonal, m: i32, n: i32,
+                alpha: f32, a: &[f32], lda: i32, b: &mut [f32], ldb: i32);
+            dtrmm(side: Side, uplo: Triangle, transa: Transpose, diag: Diagonal, m: i32, n: i32,
+                alpha: f64, a: &[f64], lda: i32, b: &mut [f64], ldb: i32);
+            ctrmm(side: Side, uplo: Triangle, transa: Transpose, diag: Diagonal, m: i32, n: i32,
+                alpha: C32, a: &[C32], lda: i32, b: &mut [C32], ldb: i32);
+            ztrmm(side: Side, uplo: Triangle, transa: Transpose, diag: Diagonal, m: i32, n: i32,
+                alpha: C64, a: &[C64], lda: i32, b: &mut [C64], ldb: i32);
+            sgbmv(trans: Transpose, m: i32, n: i32, kl: i32, ku: i32, alpha: f32, a: &[f32],
+                lda: i32, x: &[f32], incx: i32, beta: f32, y: &mut [f32], incy: i32);
+            dgbmv(trans: Transpose, m: i32, n: i32, kl: i32, ku: i32, alpha: f64, a: &[f64],
+                lda: i32, x: &[f64], incx: i32, beta: f64, y: &mut [f64], incy: i32);
+            cgbmv(trans: Transpose, m: i32, n: i32, kl: i32, ku: i32, alpha: C32, a: &[C32],
+                lda: i32, x: &[C32], incx: i32, beta: C32, y: &mut [C32], incy: i32);
+            zgbmv(trans: Transpose, m: i32, n: i32, kl: i32, ku: i32, alpha: C64, a: &[C64],
+                lda: i32, x: &[C64], incx: i32, beta: C64, y: &mut [C64], incy: i32);
+            sgetrs(trans: u8, n: i32, nrhs: i32, a: &[f32], lda: i32, ipiv: &[i32], b: &mut [f32],
+                ldb: i32) -> i32;
+            dgetrs(trans: u8, n: i32, nrhs: i32, a: &[f64], lda: i32, ipiv: &[i32], b: &mut [f64],
+                ldb: i32) -> i32;
+            cgetrs(trans: u8, n: i32, nrhs: i32, a: &[C32], lda: i32, ipiv: &[i32], b: &mut [C32],
+                ldb: i32) -> i32;
+            zgetrs(trans: u8, n: i32, nrhs: i32, a: &[C64], lda: i32, ipiv: &[i32], b: &mut [C64],
+                ldb: i32) -> i32;
+            sgetri(n: i32, a: &mut [f32], lda: i32, ipiv: &[i32], work: &mut [f32],
+                lwork: i32) -> i32;
+            dgetri(n: i32, a: &mut [f64], lda: i32, ipiv: &[i32], work: &mut [f64],
+                lwork: i32) -> i32;
+            sgecon(norm: u8, n: i32, a: &[f32], lda: i32, anorm: f32, rcond: &mut f32,
+                work: &mut [f32], iwork: &mut [i32]) -> i32;
+            dgecon(norm: u8, n: i32, a: &[f64], lda: i32, anorm: f64, rcond: &mut f64,
+                work: &mut [f64], iwork: &mut [i32]) -> i32;
+            cgecon(norm: u8, n: i32, a: &[C32], lda: i32, anorm: f32, rcond: &mut f32,
+                work: &mut [C32], rwork: &mut [f32]) -> i32;
+            zgecon(norm: u8, n: i32, a: &[C64], lda: i32, anorm: f64, rcond: &mut f64,
+                work: &mut [C64], rwork: &mut [f64]) -> i32;
+            sgesvx(fact: u8, trans: u8, n: i32, nrhs: i32, a: &mut [f32], lda: i32, af: &mut [f32],
+                ldaf: i32, ipiv: &mut [i32], equed: &mut u8, r: &mut [f32], c: &mut [f32],
+                b: &mut [f32], ldb: i32, x: &mut [f32], ldx: i32, rcond: &mut f32, ferr: &mut [f32],
+                berr: &mut [f32], work: &mut [f32], iwork: &mut [i32]) -> i32;
+            dgesvx(fact: u8, trans: u8, n: i32, nrhs: i32, a: &mut [f64], lda: i32, af: &mut [f64],
+                ldaf: i32, ipiv: &mut [i32], equed: &mut u8, r: &mut [f64], c: &mut [f64],
+                b: &mut [f64], ldb: i32, x: &mut [f64], ldx: i32, rcond: &mut f64, ferr: &mut [f64],
+                berr: &mut [f64], work: &mut [f64], iwork: &mut [i32]) -> i32;
+            cgesvx(fact: u8, trans: u8, n: i32, nrhs: i32, a: &mut [C32], lda: i32, af: &mut [C32],
+                ldaf: i32, ipiv: &mut [i32], equed: &mut u8, r: &mut [f32], c: &mut [f32],
+                b: &mut [C32], ldb: i32, x: &mut [C32], ldx: i32, rcond: &mut f32, ferr: &mut [f32],
+                berr: &mut [f32], work: &mut [C32], rwork: &mut [f32]) -> i32;
+            zgesvx(fact: u8, trans: u8, n: i32, nrhs: i32, a: &mut [C64], lda: i32, af: &mut [C64],
+                ldaf: i32, ipiv: &mut [i32], equed: &mut u8, r: &mut [f64], c: &mut [f64],
+                b: &mut [C64], ldb: i32, x: &mut [C64], ldx: i32, rcond: &mut f64, ferr: &mut [f64],
+                berr: &mut [f64], work: &mut [C64], rwork: &mut [f64]) -> i32;
+            spotrs(uplo: u8, n: i32, nrhs: i32, a: &[f32], lda: i32, b: &mut [f32],
+                ldb: i32) -> i32;
+            dpotrs(uplo: u8, n: i32, nrhs: i32, a: &[f64], lda: i32, b: &mut [f64],
+                ldb: i32) -> i32;
+            ssytrf(uplo: u8, n: i32, a: &mut [f32], lda: i32, ipiv: &mut [i32], work: &mut [f32],
+                lwork: i32) -> i32;
+            dsytrf(uplo: u8, n: i32, a: &mut [f64], lda: i32, ipiv: &mut [i32], work: &mut [f64],
+                lwork: i32) -> i32;
+            chetrf(uplo: u8, n: i32, a: &mut [C32], lda: i32, ipiv: &mut [i32], work: &mut [C32],
+                lwork: i32) -> i32;
+            zhetrf(uplo: u8, n: i32, a: &mut [C64], lda: i32, ipiv: &mut [i32], work: &mut [C64],
+                lwork: i32) -> i32;
+            ssytrs(uplo: u8, n: i32, nrhs: i32, a: &[f32], lda: i32, ipiv: &[i32], b: &mut [f32],
+                ldb: i32) -> i32;
+            dsytrs(uplo: u8, n: i32, nrhs: i32, a: &[f64], lda: i32, ipiv: &[i32], b: &mut [f64],
+                ldb: i32) -> i32;
+            chetrs(uplo: u8, n: i32, nrhs: i32, a: &[C32], lda: i32, ipiv: &[i32], b: &mut [C32],
+                ldb: i32) -> i32;
+            zhetrs(uplo: u8, n: i32, nrhs: i32, a: &[C64], lda: i32, ipiv: &[i32], b: &mut [C64],
+                ldb: i32) -> i32;
+            strtrs(uplo: u8, trans: u8, diag: u8, n: i32, nrhs: i32, a: &[f32], lda: i32,
+                b: &mut [f32], ldb: i32) -> i32;
+            dtrtrs(uplo: u8, trans: u8, diag: u8, n: i32, nrhs: i32, a: &[f64], lda: i32,
+                b: &mut [f64], ldb: i32) -> i32;
+            ctrtrs(uplo: u8, trans: u8, diag: u8, n: i32, nrhs: i32, a: &[C32], lda: i32,
+                b: &mut [C32], ldb: i32) -> i32;
+            ztrtrs(uplo: u8, trans: u8, diag: u8, n: i32, nrhs: i32, a: &[C64], lda: i32,
+                b: &mut [C64], ldb: i32) -> i32;
+            sgbsv(n: i32, kl: i32, ku: i32, nrhs: i32, ab: &mut [f32], ldab: i32, ipiv: &mut [i32],
+                b: &mut [f32], ldb: i32) -> i32;
+            dgbsv(n: i32, kl: i32, ku: i32, nrhs: i32, ab: &mut [f64], ldab: i32, ipiv: &mut [i32],
+                b: &mut [f64], ldb: i32) -> i32;
+            cgbsv(n: i32, kl: i32, ku: i32, nrhs: i32, ab: &mut [C32], ldab: i32, ipiv: &mut [i32],
+                b: &mut [C32], ldb: i32) -> i32;
+            zgbsv(n: i32, kl: i32, ku: i32, nrhs: i32, ab: &mut [C64], ldab: i32, ipiv: &mut [i32],
+                b: &mut [C64], ldb: i32) -> i32;
+            spbsv(uplo: u8, n: i32, kd: i32, nrhs: i32, ab: &mut [f32], ldab: i32, b: &mut [f32],
+                ldb: i32) -> i32;
+            dpbsv(uplo: u8, n: i32, kd: i32, nrhs: i32, ab: &mut [f64], ldab: i32, b: &mut [f64],
+                ldb: i32) -> i32;
+            cpbsv(uplo: u8, n: i32, kd: i32, nrhs: i32, ab: &mut [C32], ldab: i32, b: &mut [C32],
+                ldb: i32) -> i32;
+            zpbsv(uplo: u8, n: i32, kd: i32, nrhs: i32, ab: &mut [C64], ldab: i32, b: &mut [C64],
+                ldb: i32) -> i32;
+            sgtsv(n: i32, nrhs: i32, dl: &mut [f32], d: &mut [f32], du: &mut [f32], b: &mut [f32],
+                ldb: i32) -> i32;
+            dgtsv(n: i32, nrhs: i32, dl: &mut [f64], d: &mut [f64], du: &mut [f64], b: &mut [f64],
+                ldb: i32) -> i32;
+            cgtsv(n: i32, nrhs: i32, dl: &mut [C32], d: &mut [C32], du: &mut [C32], b: &mut [C32],
+                ldb: i32) -> i32;
+            zgtsv(n: i32, nrhs: i32, dl: &mut [C64], d: &mut [C64], du: &mut [C64], b: &mut [C64],
+                ldb: i32) -> i32;
+            sptsv(n: i32, nrhs: i32, d: &mut [f32], e: &mut [f32], b: &mut [f32], ldb: i32) -> i32;
+            dptsv(n: i32, nrhs: i32, d: &mut [f64], e: &mut [f64], b: &mut [f64], ldb: i32) -> i32;
+            cptsv(n: i32, nrhs: i32, d: &mut [f32], e: &mut [C32], b: &mut [C32], ldb: i32) -> i32;
+            zptsv(n: i32, nrhs: i32, d: &mut [f64], e: &mut [C64], b: &mut [C64], ldb: i32) -> i32;
+            sormqr(side: u8, trans: u8, m: i32, n: i32, k: i32, a: &[f32], lda: i32, tau: &[f32],
+                c: &mut [f32], ldc: i32, work: &mut [f32], lwork: i32) -> i32;
+            dormqr(side: u8, trans: u8, m: i32, n: i32, k: i32, a: &[f64], lda: i32, tau: &[f64],
+                c: &mut [f64], ldc: i32, work: &mut [f64], lwork: i32) -> i32;
+            cunmqr(side: u8, trans: u8, m: i32, n: i32, k: i32, a: &[C32], lda: i32, tau: &[C32],
+                c: &mut [C32], ldc: i32, work: &mut [C32], lwork: i32) -> i32;
+            zunmqr(side: u8, trans: u8, m: i32, n: i32, k: i32, a: &[C64], lda: i32, tau: &[C64],
+                c: &mut [C64], ldc: i32, work: &mut [C64], lwork: i32) -> i32;
+            sgeqp3(m: i32, n: i32, a: &mut [f32], lda: i32, jpvt: &mut [i32], tau: &mut [f32],
+                work: &mut [f32], lwork: i32) -> i32;
+            dgeqp3(m: i32, n: i32, a: &mut [f64], lda: i32, jpvt: &mut [i32], tau: &mut [f64],
+                work: &mut [f64], lwork: i32) -> i32;
+            cgeqp3(m: i32, n: i32, a: &mut [C32], lda: i32, jpvt: &mut [i32], tau: &mut [C32],
+                work: &mut [C32], lwork: i32, rwork: &mut [f32]) -> i32;
+            zgeqp3(m: i32, n: i32, a: &mut [C64], lda: i32, jpvt: &mut [i32], tau: &mut [C64],
+                work: &mut [C64], lwork: i32, rwork: &mut [f64]) -> i32;
+            sgels(trans: u8, m: i32, n: i32, nrhs: i32, a: &mut [f32], lda: i32, b: &mut [f32],
+                ldb: i32, work: &mut [f32], lwork: i32) -> i32;
+            dgels(trans: u8, m: i32, n: i32, nrhs: i32, a: &mut [f64], lda: i32, b: &mut [f64],
+                ldb: i32, work: &mut [f64], lwork: i32) -> i32;
+            sgelsd(m: i32, n: i32, nrhs: i32, a: &mut [f32], lda: i32, b: &mut [f32], ldb: i32,
+                s: &mut [f32], rcond: f32, rank: &mut i32, work: &mut [f32], lwork: i32,
+                iwork: &mut [i32]) -> i32;
+            dgelsd(m: i32, n: i32, nrhs: i32, a: &mut [f64], lda: i32, b: &mut [f64], ldb: i32,
+                s: &mut [f64], rcond: f64, rank: &mut i32, work: &mut [f64], lwork: i32,
+                iwork: &mut [i32]) -> i32;
+            sgesdd(jobz: u8, m: i32, n: i32, a: &mut [f32], lda: i32, s: &mut [f32], u: &mut [f32],
+                ldu: i32, vt: &mut [f32], ldvt: i32, work: &mut [f32], lwork: i32,
+                iwork: &mut [i32]) -> i32;
+            dgesdd(jobz: u8, m: i32, n: i32, a: &mut [f64], lda: i32, s: &mut [f64], u: &mut [f64],
+                ldu: i32, vt: &mut [f64], ldvt: i32, work: &mut [f64], lwork: i32,
+                iwork: &mut [i32]) -> i32;
+            cgesdd(jobz: u8, m: i32, n: i32, a: &mut [C32], lda: i32, s: &mut [f32], u: &mut [C32],
+                ldu: i32, vt: &mut [C32], ldvt: i32, work: &mut [C32], lwork: i32,
+                rwork: &mut [f32], iwork: &mut [i32]) -> i32;
+            zgesdd(jobz: u8, m: i32, n: i32, a: &mut [C64], lda: i32, s: &mut [f64], u: &mut [C64],
+                ldu: i32, vt: &mut [C64], ldvt: i32, work: &mut [C64], lwork: i32,
+                rwork: &mut [f64], iwork: &mut [i32]) -> i32;
+            sgesvd(jobu: u8, jobvt: u8, m: i32, n: i32, a: &mut [f32], lda: i32, s: &mut [f32],
+                u: &mut [f32], ldu: i32, vt: &mut [f32], ldvt: i32, work: &mut [f32],
+                lwork: i32) -> i32;
+            dgesvd(jobu: u8, jobvt: u8, m: i32, n: i32, a: &mut [f64], lda: i32, s: &mut [f64],
+                u: &mut [f64], ldu: i32, vt: &mut [f64], ldvt: i32, work: &mut [f64],
+                lwork: i32) -> i32;
+            cgesvd(jobu: u8, jobvt: u8, m: i32, n: i32, a: &mut [C32], lda: i32, s: &mut [f32],
+                u: &mut [C32], ldu: i32, vt: &mut [C32], ldvt: i32, work: &mut [C32], lwork: i32,
+                rwork: &mut [f32]) -> i32;
+            zgesvd(jobu: u8, jobvt: u8, m: i32, n: i32, a: &mut [C64], lda: i32, s: &mut [f64],
+                u: &mut [C64], ldu: i32, vt: &mut [C64], ldvt: i32, work: &mut [C64], lwork: i32,
+                rwork: &mut [f64]) -> i32;
+            ssyevr(jobz: u8, range: u8, uplo: u8, n: i32, a: &mut [f32], lda: i32, vl: f32, vu: f32,
+                il: i32, iu: i32, abstol: f32, m: &mut i32, w: &mut [f32], z: &mut [f32], ldz: i32,
+                isuppz: &mut [i32], work: &mut [f32], lwork: i32, iwork: &mut [i32],
+                liwork: i32) -> i32;
+            dsyevr(jobz: u8, range: u8, uplo: u8, n: i32, a: &mut [f64], lda: i32, vl: f64, vu: f64,
+                il: i32, iu: i32, abstol: f64, m: &mut i32, w: &mut [f64], z: &mut [f64], ldz: i32,
+                isuppz: &mut [i32], work: &mut [f64], lwork: i32, iwork: &mut [i32],
+                liwork: i32) -> i32;
+            cheevr(jobz: u8, range: u8, uplo: u8, n: i32, a: &mut [C32], lda: i32, vl: f32, vu: f32,
+                il: i32, iu: i32, abstol: f32, m: &mut i32, w: &mut [f32], z: &mut [C32], ldz: i32,
+                isuppz: &mut [i32], work: &mut [C32], lwork: i32, rwork: &mut [f32], lrwork: i32,
+                iwork: &mut [i32], liwork: i32) -> i32;
+            zheevr(jobz: u8, range: u8, uplo: u8, n: i32, a: &mut [C64], lda: i32, vl: f64, vu: f64,
+                il: i32, iu: i32, abstol: f64, m: &mut i32, w: &mut [f64], z: &mut [C64], ldz: i32,
+                isuppz: &mut [i32], work: &mut [C64], lwork: i32, rwork: &mut [f64], lrwork: i32,
+                iwork: &mut [i32], liwork: i32) -> i32;
+            ssygv(itype: i32, jobz: u8, uplo: u8, n: i32, a: &mut [f32], lda: i32, b: &mut [f32],
+                ldb: i32, w: &mut [f32], work: &mut [f32], lwork: i32) -> i32;
+            dsygv(itype: i32, jobz: u8, uplo: u8, n: i32, a: &mut [f64], lda: i32, b: &mut [f64],
+                ldb: i32, w: &mut [f64], work: &mut [f64], lwork: i32) -> i32;
+            chegv(itype: i32, jobz: u8, uplo: u8, n: i32, a: &mut [C32], lda: i32, b: &mut [C32],
+                ldb: i32, w: &mut [f32], work: &mut [C32], lwork: i32, rwork: &mut [f32]) -> i32;
+            zhegv(itype: i32, jobz: u8, uplo: u8, n: i32, a: &mut [C64], lda: i32, b: &mut [C64],
+                ldb: i32, w: &mut [f64], work: &mut [C64], lwork: i32, rwork: &mut [f64]) -> i32;
+            sggev(jobvl: u8, jobvr: u8, n: i32, a: &mut [f32], lda: i32, b: &mut [f32], ldb: i32,
+                alphar: &mut [f32], alphai: &mut [f32], beta: &mut [f32], vl: &mut [f32], ldvl: i32,
+                vr: &mut [f32], ldvr: i32, work: &mut [f32], lwork: i32) -> i32;
+            dggev(jobvl: u8, jobvr: u8, n: i32, a: &mut [f64], lda: i32, b: &mut [f64], ldb: i32,
+                alphar: &mut [f64], alphai: &mut [f64], beta: &mut [f64], vl: &mut [f64], ldvl: i32,
+                vr: &mut [f64], ldvr: i32, work: &mut [f64], lwork: i32) -> i32;
+            cggev(jobvl: u8, jobvr: u8, n: i32, a: &mut [C32], lda: i32, b: &mut [C32], ldb: i32,
+                alpha: &mut [C32], beta: &mut [C32], vl: &mut [C32], ldvl: i32, vr: &mut [C32],
+                ldvr: i32, work: &mut [C32], lwork: i32, rwork: &mut [f32]) -> i32;
+            zggev(jobvl: u8, jobvr: u8, n: i32, a: &mut [C64], lda: i32, b: &mut [C64], ldb: i32,
+                alpha: &mut [C64], beta: &mut [C64], vl: &mut [C64], ldvl: i32, vr: &mut [C64],
+                ldvr: i32, work: &mut [C64], lwork: i32, rwork: &mut [f64]) -> i32;
+            sgehrd(n: i32, ilo: i32, ihi: i32, a: &mut [f32], lda: i32, tau: &mut [f32],
+                work: &mut [f32], lwork: i32) -> i32;
+            dgehrd(n: i32, ilo: i32, ihi: i32, a: &mut [f64], lda: i32, tau: &mut [f64],
+                work: &mut [f64], lwork: i32) -> i32;
+            cgehrd(n: i32, ilo: i32, ihi: i32, a: &mut [C32], lda: i32, tau: &mut [C32],
+                work: &mut [C32], lwork: i32) -> i32;
+            zgehrd(n: i32, ilo: i32, ihi: i32, a: &mut [C64], lda: i32, tau: &mut [C64],
+                work: &mut [C64], lwork: i32) -> i32;
+            sorghr(n: i32, ilo: i32, ihi: i32, a: &mut [f32], lda: i32, tau: &[f32],
+                work: &mut [f32], lwork: i32) -> i32;
+            dorghr(n: i32, ilo: i32, ihi: i32, a: &mut [f64], lda: i32, tau: &[f64],
+                work: &mut [f64], lwork: i32) -> i32;
+            cunghr(n: i32, ilo: i32, ihi: i32, a: &mut [C32], lda: i32, tau: &[C32],
+                work: &mut [C32], lwork: i32) -> i32;
+            zunghr(n: i32, ilo: i32, ihi: i32, a: &mut [C64], lda: i32, tau: &[C64],
+                work: &mut [C64], lwork: i32) -> i32;
+            strsen(job: u8, compq: u8, select: &[i32], n: i32, t: &mut [f32], ldt: i32,
+                q: &mut [f32], ldq: i32, wr: &mut [f32], wi: &mut [f32], m: &mut i32, s: &mut f32,
+                sep: &mut f32, work: &mut [f32], lwork: i32, iwork: &mut [i32], liwork: i32) -> i32;
+            dtrsen(job: u8, compq: u8, select: &[i32], n: i32, t: &mut [f64], ldt: i32,
+                q: &mut [f64], ldq: i32, wr: &mut [f64], wi: &mut [f64], m: &mut i32, s: &mut f64,
+                sep: &mut f64, work: &mut [f64], lwork: i32, iwork: &mut [i32], liwork: i32) -> i32;
+        }
+    }
+
+    #[test]
+    fn test_with_backend_routes_calls() {
+        let a = random(4, 4);
+        let b = random(4, 3);
+        let counting = Arc::new(CountingBackend::default());
+
+        let c = with_backend(counting.clone(), || (&a).matmul(&b));
+        assert_matrix_eq(&c, &on_reference(|| (&a).matmul(&b)));
+        assert_eq!(counting.gemm_calls.load(Ordering::SeqCst), 1);
+
+        with_backend(counting.clone(), || a.lu().unwrap());
+        assert_eq!(counting.getrf_calls.load(Ordering::SeqCst), 1);
+
+        // Outside the closure the previous backend is restored
+        let _ = (&a).matmul(&b);
+        assert_eq!(counting.gemm_calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_with_backend_nests_and_unwinds() {
+        let a = random(3, 3);
+        let outer = Arc::new(CountingBackend::default());
+        let inner = Arc::new(CountingBackend::default());
+
+        with_backend(outer.clone(), || {
+            with_backend(inner.clone(), || (&a).matmul(&a));
+            let _ = (&a).matmul(&a);
+
+            let result = catch_unwind(AssertUnwindSafe(|| {
+                with_backend(inner.clone(), || panic!("backend scope"));
+            }));
+            assert!(result.is_err());
+            let _ = (&a).matmul(&a);
+        });
+
+        assert_eq!(inner.gemm_calls.load(Ordering::SeqCst), 1);
+        assert_eq!(outer.gemm_calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_set_backend_returns_previous() {
+        let counting = Arc::new(CountingBackend::default());
+        let previous = set_backend(counting.clone());
+        let installed = set_backend(previous);
+        assert!(Arc::ptr_eq(&installed, &(counting as Arc<dyn Backend>)));
+
+        let scoped = Arc::new(CountingBackend::default());
+        let current = with_backend(scoped.clone(), current_backend);
+        assert!(Arc::ptr_eq(&current, &(scoped as Arc<dyn Backend>)));
+    }
+    //}}}
+}
+//}}}
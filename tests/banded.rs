@@ -0,0 +1,148 @@
+//{{{ mod: band_matrix_tests
+mod band_matrix_tests {
+
+    use approx::assert_relative_eq;
+    use topohedral_linalg::*;
+
+    fn pentadiagonal() -> DMatrix<f64> {
+        DMatrix::<f64>::from_row_slice(
+            &[
+                6.0, -1.0, 2.0, 0.0, 0.0, //
+                -1.0, 6.0, -1.0, 2.0, 0.0, //
+                0.5, -1.0, 6.0, -1.0, 2.0, //
+                0.0, 0.5, -1.0, 6.0, -1.0, //
+                0.0, 0.0, 0.5, -1.0, 6.0,
+            ],
+            5,
+            5,
+        )
+    }
+
+    #[test]
+    fn test_band_roundtrip() {
+        let a = pentadiagonal();
+        let band = BandMatrix::from_dmatrix(&a, 2, 2);
+        assert_eq!(band.band_data().len(), 5 * 5);
+        assert!(band.to_dmatrix().iter().eq(a.iter()));
+        assert_eq!(band.get(2, 0), 0.5);
+        assert_eq!(band.get(0, 2), 2.0);
+
+        // Narrowing the band discards the entries outside it.
+        let narrow = BandMatrix::from_dmatrix(&a, 1, 1);
+        assert_eq!(narrow.get(2, 0), 0.0);
+        assert_eq!(narrow.get(1, 0), -1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "lies outside the band")]
+    fn test_band_set_outside() {
+        let mut band = BandMatrix::<f64>::zeros(4, 4, 1, 0);
+        band.set(1, 0, 1.0);
+        band.set(0, 1, 1.0);
+    }
+
+    #[test]
+    fn test_band_matvec_and_solve() {
+        let a = pentadiagonal();
+        let band = BandMatrix::from_dmatrix(&a, 2, 2);
+        let x = DMatrix::<f64>::from_col_slice(&[1.0, -2.0, 3.0, -4.0, 5.0], 5, 1);
+
+        let b = band.matvec(&x);
+        let b_dense = a.matmul(&x);
+        for i in 0..5 {
+            assert_relative_eq!(b[(i, 0)], b_dense[(i, 0)], epsilon = 1e-12);
+        }
+
+        let x_solved = band.solve(&b).unwrap();
+        for i in 0..5 {
+            assert_relative_eq!(x_solved[(i, 0)], x[(i, 0)], epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_band_solve_spd() {
+        // Symmetric positive-definite with one off-diagonal on each side.
+        let a = DMatrix::<f64>::from_row_slice(
+            &[
+                4.0, 1.0, 0.0, 0.0, //
+                1.0, 4.0, 1.0, 0.0, //
+                0.0, 1.0, 4.0, 1.0, //
+                0.0, 0.0, 1.0, 4.0,
+            ],
+            4,
+            4,
+        );
+        let band = BandMatrix::from_dmatrix(&a, 1, 1);
+        let b = DMatrix::<f64>::from_col_slice(&[1.0, 2.0, 3.0, 4.0, 0.0, 1.0, 0.0, 1.0], 4, 2);
+        let x = band.solve_spd(&b).unwrap();
+        let residual = a.matmul(&x);
+        for j in 0..2 {
+            for i in 0..4 {
+                assert_relative_eq!(residual[(i, j)], b[(i, j)], epsilon = 1e-12);
+            }
+        }
+
+        let indefinite = BandMatrix::from_dmatrix(&(a * -1.0), 1, 1);
+        assert!(matches!(
+            indefinite.solve_spd(&b),
+            Err(BandMatrixError::NotPositiveDefinite)
+        ));
+    }
+}
+//}}}
+//{{{ mod: tridiagonal_matrix_tests
+mod tridiagonal_matrix_tests {
+
+    use approx::assert_relative_eq;
+    use topohedral_linalg::*;
+
+    #[test]
+    fn test_tridiagonal_roundtrip() {
+        let tri = TridiagonalMatrix::new(
+            vec![1.0, 2.0, 3.0],
+            vec![4.0, 5.0, 6.0, 7.0],
+            vec![8.0, 9.0, 10.0],
+        );
+        let a = tri.to_dmatrix();
+        assert_eq!(a[(1, 0)], 1.0);
+        assert_eq!(a[(3, 3)], 7.0);
+        assert_eq!(a[(2, 3)], 10.0);
+        assert_eq!(a[(3, 0)], 0.0);
+
+        let back = TridiagonalMatrix::from_dmatrix(&a);
+        assert_eq!(back.dl(), tri.dl());
+        assert_eq!(back.d(), tri.d());
+        assert_eq!(back.du(), tri.du());
+        assert!(tri.to_band().to_dmatrix().iter().eq(a.iter()));
+    }
+
+    #[test]
+    fn test_tridiagonal_solve() {
+        // Second-difference operator: symmetric positive definite.
+        let n = 6;
+        let tri = TridiagonalMatrix::new(vec![-1.0; n - 1], vec![2.0; n], vec![-1.0; n - 1]);
+        let a = tri.to_dmatrix();
+        let b = DMatrix::<f64>::from_col_slice(&[1.0, 0.0, 2.0, 0.0, 3.0, 1.0], n, 1);
+
+        for x in [tri.solve(&b).unwrap(), tri.solve_spd(&b).unwrap()] {
+            let residual = tri.matvec(&x);
+            let residual_dense = a.matmul(&x);
+            for i in 0..n {
+                assert_relative_eq!(residual[(i, 0)], b[(i, 0)], epsilon = 1e-12);
+                assert_relative_eq!(residual_dense[(i, 0)], b[(i, 0)], epsilon = 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_tridiagonal_singular() {
+        // The first two rows are equal.
+        let tri = TridiagonalMatrix::new(vec![1.0, 0.0], vec![1.0, 1.0, 1.0], vec![1.0, 0.0]);
+        let b = DMatrix::<f64>::from_col_slice(&[1.0, 1.0, 1.0], 3, 1);
+        assert!(matches!(
+            tri.solve(&b),
+            Err(TridiagonalMatrixError::Singular)
+        ));
+    }
+}
+//}}}
@@ -3,7 +3,8 @@ mod smatrix_tests {
 
     use approx::assert_relative_eq;
     use topohedral_linalg::{
-        abs, clamp, exp, mul_add, powf, powi, sin, sqrt, FloatTransformOps, OuterProduct, SCVector,
+        abs, clamp, exp, mul_add, powf, powi, sin, sqrt, Complex, FloatTransformOps, OuterProduct,
+        SCVector,
     };
     use topohedral_linalg::{SMatrix, SubViewable, SubViewableMut};
 
@@ -822,14 +823,44 @@ mod smatrix_tests {
         }
     }
     //}}}
+    //{{{ collection: complex tests
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn test_complex_expression() {
+        let i = Complex::new(0.0, 1.0);
+        let a = SMatrix::<Complex<f64>, 2, 2>::from_value(Complex::new(1.0, 2.0));
+        let b = SMatrix::<Complex<f64>, 2, 2>::from_value(Complex::new(3.0, -1.0));
+
+        let c: SMatrix<Complex<f64>, 2, 2> = (i * (&a + &b) - &a * &b).into();
+
+        for val in c {
+            assert_relative_eq!(val.re, -6.0, epsilon = 1e-12);
+            assert_relative_eq!(val.im, -1.0, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_complex_scalar_ops() {
+        let mut a = SMatrix::<Complex<f64>, 2, 2>::from_value(Complex::new(1.0, 1.0));
+
+        a *= Complex::new(0.0, 1.0);
+        a += Complex::new(2.0, 0.0);
+        let b = -(a / Complex::new(2.0, 0.0));
+
+        for val in &b {
+            assert_relative_eq!(val.re, -0.5, epsilon = 1e-12);
+            assert_relative_eq!(val.im, -0.5, epsilon = 1e-12);
+        }
+    }
+    //}}}
 }
 //}}}
 //{{{ mod: dmatrix_tests
 mod dmatrix_tests {
     use approx::assert_relative_eq;
     use topohedral_linalg::{
-        abs, clamp, exp, mul_add, powf, powi, sin, sqrt, DMatrix, DVector, FloatTransformOps,
-        OuterProduct, SubViewable, SubViewableMut, VecType,
+        abs, clamp, exp, mul_add, powf, powi, sin, sqrt, Complex, DMatrix, DVector,
+        FloatTransformOps, OuterProduct, SubViewable, SubViewableMut, VecType,
     };
 
     //{{{ collection: addition tests
@@ -1537,5 +1568,28 @@ mod dmatrix_tests {
         }
     }
     //}}}
+    //{{{ collection: complex tests
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn test_complex_expression() {
+        let i = Complex::new(0.0, 1.0);
+        let a = DMatrix::<Complex<f64>>::from_value(Complex::new(1.0, 2.0), 2, 2);
+        let b = DMatrix::<Complex<f64>>::from_value(Complex::new(3.0, -1.0), 2, 2);
+
+        let c: DMatrix<Complex<f64>> = (i * (&a + &b) - &a * &b).into();
+
+        for val in &c {
+            assert_relative_eq!(val.re, -6.0, epsilon = 1e-12);
+            assert_relative_eq!(val.im, -1.0, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_complex_display() {
+        let a = DMatrix::<Complex<f64>>::from_value(Complex::new(1.0, -2.0), 1, 1);
+
+        assert_eq!(format!("{}", a), "| 1.0000e0-2.0000e0i  |\n");
+    }
+    //}}}
 }
 //}}}
@@ -0,0 +1,493 @@
+//{{{ mod: linear_operator_tests
+mod linear_operator_tests {
+
+    use approx::assert_relative_eq;
+    use topohedral_linalg::*;
+
+    #[test]
+    fn test_matrix_and_closure_operators_agree() {
+        let a = DMatrix::<f64>::from_row_slice(&[2.0, -1.0, 0.0, 1.0, 3.0, 1.0], 2, 3);
+        let op = FnOperator::new(2, 3, |x: &[f64], y: &mut [f64]| {
+            y[0] = 2.0 * x[0] - x[1];
+            y[1] = x[0] + 3.0 * x[1] + x[2];
+        });
+        assert_eq!(op.size(), (2, 3));
+
+        let x = [1.0, -2.0, 0.5];
+        let mut y_dense = [0.0; 2];
+        let mut y_fn = [0.0; 2];
+        a.apply(&x, &mut y_dense);
+        op.apply(&x, &mut y_fn);
+        for i in 0..2 {
+            assert_relative_eq!(y_dense[i], y_fn[i], epsilon = 1e-14);
+        }
+    }
+}
+//}}}
+//{{{ mod: krylov_tests
+mod krylov_tests {
+
+    use approx::assert_relative_eq;
+    use topohedral_linalg::*;
+
+    /// Symmetric positive definite 1D Laplacian, tridiag(-1, 2, -1).
+    fn laplacian(n: usize) -> impl LinearOperator<f64> {
+        FnOperator::new(n, n, move |x: &[f64], y: &mut [f64]| {
+            for i in 0..n {
+                let left = if i > 0 { x[i - 1] } else { 0.0 };
+                let right = if i + 1 < n { x[i + 1] } else { 0.0 };
+                y[i] = 2.0 * x[i] - left - right;
+            }
+        })
+    }
+
+    /// Nonsymmetric convection-diffusion matrix, tridiag(-1.5, 3, -0.5).
+    fn convection_diffusion(n: usize) -> DMatrix<f64> {
+        let mut a = DMatrix::<f64>::zeros(n, n);
+        for i in 0..n {
+            a[(i, i)] = 3.0;
+            if i > 0 {
+                a[(i, i - 1)] = -1.5;
+            }
+            if i + 1 < n {
+                a[(i, i + 1)] = -0.5;
+            }
+        }
+        a
+    }
+
+    fn assert_solves<L: LinearOperator<f64>>(
+        op: &L,
+        b: &DVector<f64>,
+        result: &IterativeReturn<f64>,
+    ) {
+        let n = op.nrows();
+        let x: Vec<f64> = result.x.iter().copied().collect();
+        let mut ax = vec![0.0; n];
+        op.apply(&x, &mut ax);
+        for i in 0..n {
+            assert_relative_eq!(ax[i], b[i], epsilon = 1e-6);
+        }
+        assert!(result.converged);
+        assert_eq!(result.residual_history.len(), result.iterations + 1);
+        assert!(*result.residual_history.last().unwrap() <= result.tolerance);
+    }
+
+    #[test]
+    fn test_cg_laplacian() {
+        let n = 40;
+        let op = laplacian(n);
+        let b = DMatrix::<f64>::from_col_slice(&vec![1.0; n], n, 1);
+        let result = cg(&op, &b, None, None, &IterativeOptions::default()).unwrap();
+        assert_solves(&op, &b, &result);
+        // In exact arithmetic CG terminates in at most n steps
+        assert!(result.iterations <= n);
+    }
+
+    #[test]
+    fn test_cg_jacobi_preconditioner() {
+        // Badly scaled SPD diagonal plus a weak coupling
+        let n = 30;
+        let mut a = DMatrix::<f64>::zeros(n, n);
+        for i in 0..n {
+            a[(i, i)] = 10.0_f64.powi((i % 6) as i32);
+            if i > 0 {
+                a[(i, i - 1)] = 0.1;
+                a[(i - 1, i)] = 0.1;
+            }
+        }
+        let diag: Vec<f64> = (0..n).map(|i| a[(i, i)]).collect();
+        let jacobi = FnOperator::new(n, n, move |r: &[f64], z: &mut [f64]| {
+            for i in 0..r.len() {
+                z[i] = r[i] / diag[i];
+            }
+        });
+        let b = DMatrix::<f64>::from_col_slice(&vec![1.0; n], n, 1);
+        let options = IterativeOptions::default();
+
+        let plain = cg(&a, &b, None, None, &options).unwrap();
+        let preconditioned = cg(&a, &b, None, Some(&jacobi), &options).unwrap();
+        assert_solves(&a, &b, &preconditioned);
+        assert!(preconditioned.iterations < plain.iterations);
+    }
+
+    #[test]
+    fn test_cg_indefinite_breaks_down() {
+        let a = DMatrix::<f64>::from_row_slice(&[1.0, 0.0, 0.0, -1.0], 2, 2);
+        let b = DMatrix::<f64>::from_col_slice(&[1.0, 1.0], 2, 1);
+        let result = cg(&a, &b, None, None, &IterativeOptions::default());
+        assert!(matches!(
+            result,
+            Err(IterativeError::Breakdown {
+                method: "cg",
+                iteration: 1
+            })
+        ));
+    }
+
+    #[test]
+    fn test_minres_indefinite() {
+        // Symmetric with eigenvalues on both sides of zero
+        let n = 25;
+        let mut a = DMatrix::<f64>::zeros(n, n);
+        for i in 0..n {
+            a[(i, i)] = i as f64 - 12.5;
+            if i > 0 {
+                a[(i, i - 1)] = 1.0;
+                a[(i - 1, i)] = 1.0;
+            }
+        }
+        let b = DMatrix::<f64>::from_col_slice(&vec![1.0; n], n, 1);
+        let result = minres(&a, &b, None, None, &IterativeOptions::default()).unwrap();
+        assert_solves(&a, &b, &result);
+        // The MINRES residual never increases
+        for pair in result.residual_history.windows(2) {
+            assert!(pair[1] <= pair[0] * (1.0 + 1e-12));
+        }
+    }
+
+    #[test]
+    fn test_gmres_restarted() {
+        let n = 40;
+        let a = convection_diffusion(n);
+        let b = DMatrix::<f64>::from_col_slice(&vec![1.0; n], n, 1);
+        let options = IterativeOptions {
+            restart: 5,
+            ..Default::default()
+        };
+        let result = gmres(&a, &b, None, None, &options).unwrap();
+        assert_solves(&a, &b, &result);
+        // Restarts do not make the residual grow
+        for pair in result.residual_history.windows(2) {
+            assert!(pair[1] <= pair[0] * (1.0 + 1e-12));
+        }
+    }
+
+    #[test]
+    fn test_bicgstab_with_initial_guess() {
+        let n = 40;
+        let a = convection_diffusion(n);
+        let b = DMatrix::<f64>::from_col_slice(&vec![1.0; n], n, 1);
+        let x0 = DMatrix::<f64>::from_col_slice(&vec![0.5; n], n, 1);
+        let result = bicgstab(&a, &b, Some(&x0), None, &IterativeOptions::default()).unwrap();
+        assert_solves(&a, &b, &result);
+    }
+
+    #[test]
+    fn test_preconditioned_minres_gmres_bicgstab() {
+        // Symmetric indefinite with a strongly varying diagonal; |diag| is an SPD preconditioner
+        let n = 30;
+        let mut a = DMatrix::<f64>::zeros(n, n);
+        for i in 0..n {
+            let sign = if i % 2 == 0 { 1.0 } else { -1.0 };
+            a[(i, i)] = sign * 10.0_f64.powi((i % 5) as i32);
+            if i > 0 {
+                a[(i, i - 1)] = 0.5;
+                a[(i - 1, i)] = 0.5;
+            }
+        }
+        let inv_diag: Vec<f64> = (0..n).map(|i| 1.0 / a[(i, i)].abs()).collect();
+        let jacobi = FnOperator::new(n, n, move |r: &[f64], z: &mut [f64]| {
+            for i in 0..r.len() {
+                z[i] = r[i] * inv_diag[i];
+            }
+        });
+        let b = DMatrix::<f64>::from_col_slice(&vec![1.0; n], n, 1);
+        let options = IterativeOptions::default();
+
+        let result = minres(&a, &b, None, Some(&jacobi), &options).unwrap();
+        assert!(result.converged);
+        let x: Vec<f64> = result.x.iter().copied().collect();
+        let mut ax = vec![0.0; n];
+        a.apply(&x, &mut ax);
+        for i in 0..n {
+            assert_relative_eq!(ax[i], b[i], epsilon = 1e-5);
+        }
+
+        for solve in [gmres, bicgstab] {
+            let result = solve(&a, &b, None, Some(&jacobi), &options).unwrap();
+            assert_solves(&a, &b, &result);
+        }
+    }
+
+    #[test]
+    fn test_max_iter_reports_not_converged() {
+        let n = 50;
+        let op = laplacian(n);
+        let b = DMatrix::<f64>::from_col_slice(&vec![1.0; n], n, 1);
+        let options = IterativeOptions {
+            max_iter: 3,
+            ..Default::default()
+        };
+        for solve in [cg, minres, gmres, bicgstab] {
+            let result = solve(&op, &b, None, None, &options).unwrap();
+            assert!(!result.converged);
+            assert_eq!(result.iterations, 3);
+            assert_eq!(result.residual_history.len(), 4);
+        }
+    }
+
+    #[test]
+    fn test_smatrix_operator() {
+        let a =
+            SMatrix::<f64, 3, 3>::from_row_slice(&[4.0, 1.0, 0.0, 1.0, 3.0, 1.0, 0.0, 1.0, 2.0]);
+        let b = DMatrix::<f64>::from_col_slice(&[1.0, 2.0, 3.0], 3, 1);
+        let result = cg(&a, &b, None, None, &IterativeOptions::default()).unwrap();
+        assert_solves(&a, &b, &result);
+        assert!(result.iterations <= 3);
+    }
+}
+//}}}
+//{{{ mod: eigensolver_tests
+mod eigensolver_tests {
+
+    use approx::assert_relative_eq;
+    use topohedral_linalg::*;
+
+    /// Eigenvalues of tridiag(-1, 2, -1) of size n, ascending.
+    fn laplacian_eigvals(n: usize) -> Vec<f64> {
+        (1..=n)
+            .map(|k| 2.0 - 2.0 * (k as f64 * std::f64::consts::PI / (n as f64 + 1.0)).cos())
+            .collect()
+    }
+
+    fn laplacian(n: usize) -> FnOperator<impl Fn(&[f64], &mut [f64])> {
+        FnOperator::new(n, n, move |x: &[f64], y: &mut [f64]| {
+            for i in 0..n {
+                let left = if i > 0 { x[i - 1] } else { 0.0 };
+                let right = if i + 1 < n { x[i + 1] } else { 0.0 };
+                y[i] = 2.0 * x[i] - left - right;
+            }
+        })
+    }
+
+    /// Applies (tridiag(-1, 2, -1) − σI)⁻¹ through a dense LU solve.
+    fn shifted_laplacian_solve(
+        n: usize,
+        sigma: f64,
+    ) -> FnOperator<impl Fn(&[f64], &mut [f64])> {
+        let mut shifted = DMatrix::<f64>::zeros(n, n);
+        for i in 0..n {
+            shifted[(i, i)] = 2.0 - sigma;
+            if i > 0 {
+                shifted[(i, i - 1)] = -1.0;
+                shifted[(i - 1, i)] = -1.0;
+            }
+        }
+        FnOperator::new(n, n, move |b: &[f64], x: &mut [f64]| {
+            let rhs = DMatrix::<f64>::from_col_slice(b, n, 1);
+            let sol = shifted.solve(&rhs).unwrap();
+            for (xi, &si) in x.iter_mut().zip(sol.iter()) {
+                *xi = si;
+            }
+        })
+    }
+
+    fn assert_real_eigpairs<L: LinearOperator<f64>>(
+        op: &L,
+        result: &LanczosReturn<f64>,
+    ) {
+        let n = op.nrows();
+        for (j, &lambda) in result.eigvals.iter().enumerate() {
+            let x: Vec<f64> = (0..n).map(|i| result.eigvecs[(i, j)]).collect();
+            let mut ax = vec![0.0; n];
+            op.apply(&x, &mut ax);
+            for i in 0..n {
+                assert_relative_eq!(ax[i], lambda * x[i], epsilon = 1e-7);
+            }
+        }
+    }
+
+    #[test]
+    fn test_lanczos_largest() {
+        let n = 100;
+        let op = laplacian(n);
+        let result = lanczos(&op, 4, None, &KrylovEigOptions::default()).unwrap();
+        assert!(result.converged);
+        assert_eq!(result.nconv, 4);
+        let exact = laplacian_eigvals(n);
+        for (j, &lambda) in result.eigvals.iter().enumerate() {
+            assert_relative_eq!(lambda, exact[n - 1 - j], epsilon = 1e-8);
+        }
+        assert_real_eigpairs(&op, &result);
+        assert!(result.matvecs < n * 10);
+    }
+
+    #[test]
+    fn test_lanczos_shift_invert() {
+        // Interior eigenvalues nearest 1.0, through a user-supplied solve of (A − σI)
+        let n = 60;
+        let sigma = 1.0;
+        let options = KrylovEigOptions {
+            sigma: Some(sigma),
+            ..Default::default()
+        };
+        let result = lanczos(&shifted_laplacian_solve(n, sigma), 3, None, &options).unwrap();
+        assert!(result.converged);
+
+        let mut exact = laplacian_eigvals(n);
+        exact.sort_by(|a, b| (a - sigma).abs().partial_cmp(&(b - sigma).abs()).unwrap());
+        for (j, &lambda) in result.eigvals.iter().enumerate() {
+            assert_relative_eq!(lambda, exact[j], epsilon = 1e-8);
+        }
+        assert_real_eigpairs(&laplacian(n), &result);
+    }
+
+    #[test]
+    fn test_lanczos_smallest_real_on_view() {
+        // The leading 30x30 block of a larger symmetric matrix, used through a subview
+        let n = 40;
+        let mut a = DMatrix::<f64>::zeros(n, n);
+        for i in 0..n {
+            a[(i, i)] = (i as f64 - 10.0) * 0.5;
+            if i > 0 {
+                a[(i, i - 1)] = 0.3;
+                a[(i - 1, i)] = 0.3;
+            }
+        }
+        let view = a.subview_range(0, 29, 0, 29);
+        let options = KrylovEigOptions {
+            which: KrylovEigWhich::SmallestReal,
+            ..Default::default()
+        };
+        let result = lanczos(&view, 2, None, &options).unwrap();
+        assert!(result.converged);
+
+        let block = DMatrix::<f64>::from_col_slice(
+            &(0..30)
+                .flat_map(|j| (0..30).map(move |i| (i, j)))
+                .map(|(i, j)| a[(i, j)])
+                .collect::<Vec<f64>>(),
+            30,
+            30,
+        );
+        assert_real_eigpairs(&block, &result);
+        assert!(result.eigvals[0] <= result.eigvals[1]);
+        // Gershgorin: the smallest eigenvalue lies within 0.6 of the smallest diagonal entry
+        assert!((result.eigvals[0] + 5.0).abs() <= 0.6);
+    }
+
+    #[test]
+    fn test_lanczos_invariant_starting_subspace() {
+        // v0 spans an invariant subspace that misses the wanted eigenvectors
+        let n = 30;
+        let mut a = DMatrix::<f64>::zeros(n, n);
+        for i in 0..n {
+            a[(i, i)] = (i + 1) as f64;
+        }
+        let mut v0 = DMatrix::<f64>::zeros(n, 1);
+        v0[0] = 1.0;
+        v0[1] = 1.0;
+        let options = KrylovEigOptions {
+            ncv: Some(10),
+            ..Default::default()
+        };
+        let result = lanczos(&a, 2, Some(&v0), &options).unwrap();
+        assert!(result.converged);
+        assert_relative_eq!(result.eigvals[0], 30.0, epsilon = 1e-8);
+        assert_relative_eq!(result.eigvals[1], 29.0, epsilon = 1e-8);
+        assert_real_eigpairs(&a, &result);
+    }
+
+    /// Upper triangular matrix with diagonal 1..n-2 and a trailing 2x2 block with eigenvalues
+    /// (n - 1) ± 2i.
+    fn nonsymmetric(n: usize) -> DMatrix<f64> {
+        let mut a = DMatrix::<f64>::zeros(n, n);
+        for j in 0..n {
+            for i in 0..j {
+                a[(i, j)] = 0.1 * ((i * n + j) as f64).sin();
+            }
+        }
+        for i in 0..n - 2 {
+            a[(i, i)] = (i + 1) as f64;
+        }
+        let top = (n - 1) as f64;
+        a[(n - 2, n - 2)] = top;
+        a[(n - 1, n - 1)] = top;
+        a[(n - 2, n - 1)] = 2.0;
+        a[(n - 1, n - 2)] = -2.0;
+        a
+    }
+
+    fn assert_complex_eigpairs(
+        a: &DMatrix<f64>,
+        result: &ArnoldiReturn<f64>,
+    ) {
+        let n = a.nrows();
+        for (j, lambda) in result.eigvals.iter().enumerate() {
+            let re: Vec<f64> = (0..n).map(|i| result.eigvecs[(i, j)].re).collect();
+            let im: Vec<f64> = (0..n).map(|i| result.eigvecs[(i, j)].im).collect();
+            let mut a_re = vec![0.0; n];
+            let mut a_im = vec![0.0; n];
+            a.apply(&re, &mut a_re);
+            a.apply(&im, &mut a_im);
+            for i in 0..n {
+                let lx = *lambda * Complex::new(re[i], im[i]);
+                assert_relative_eq!(a_re[i], lx.re, epsilon = 1e-7);
+                assert_relative_eq!(a_im[i], lx.im, epsilon = 1e-7);
+            }
+        }
+    }
+
+    #[test]
+    fn test_arnoldi_complex_pair() {
+        let n = 50;
+        let a = nonsymmetric(n);
+        let result = arnoldi(&a, 4, None, &KrylovEigOptions::default()).unwrap();
+        assert!(result.converged);
+        let top = (n - 1) as f64;
+        assert_relative_eq!(result.eigvals[0].re, top, epsilon = 1e-8);
+        assert_relative_eq!(result.eigvals[0].im.abs(), 2.0, epsilon = 1e-8);
+        assert_relative_eq!(result.eigvals[1].re, top, epsilon = 1e-8);
+        assert_relative_eq!(result.eigvals[1].im, -result.eigvals[0].im, epsilon = 1e-8);
+        assert_relative_eq!(result.eigvals[2].re, top - 1.0, epsilon = 1e-8);
+        assert_relative_eq!(result.eigvals[3].re, top - 2.0, epsilon = 1e-8);
+        assert_complex_eigpairs(&a, &result);
+    }
+
+    #[test]
+    fn test_arnoldi_shift_invert() {
+        let n = 40;
+        let a = nonsymmetric(n);
+        let sigma = 10.3;
+        let mut shifted = a.clone();
+        for i in 0..n {
+            shifted[(i, i)] -= sigma;
+        }
+        let solve = FnOperator::new(n, n, move |b: &[f64], x: &mut [f64]| {
+            let rhs = DMatrix::<f64>::from_col_slice(b, b.len(), 1);
+            let sol = shifted.solve(&rhs).unwrap();
+            for (xi, &si) in x.iter_mut().zip(sol.iter()) {
+                *xi = si;
+            }
+        });
+        let options = KrylovEigOptions {
+            sigma: Some(sigma),
+            ..Default::default()
+        };
+        let result = arnoldi(&solve, 3, None, &options).unwrap();
+        assert!(result.converged);
+        for (lambda, exact) in result.eigvals.iter().zip([10.0, 11.0, 9.0]) {
+            assert_relative_eq!(lambda.re, exact, epsilon = 1e-8);
+            assert_relative_eq!(lambda.im, 0.0, epsilon = 1e-8);
+        }
+        assert_complex_eigpairs(&a, &result);
+    }
+
+    #[test]
+    fn test_restart_budget_reports_not_converged() {
+        let n = 200;
+        let options = KrylovEigOptions {
+            ncv: Some(8),
+            max_restarts: 0,
+            ..Default::default()
+        };
+        let result = lanczos(&laplacian(n), 3, None, &options).unwrap();
+        assert!(!result.converged);
+        assert!(result.nconv < 3);
+        assert_eq!(result.restarts, 0);
+        assert_eq!(result.matvecs, 8);
+        assert_eq!(result.residuals.len(), 3);
+    }
+}
+//}}}
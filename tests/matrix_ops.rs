@@ -2,7 +2,10 @@ mod smatrix_tests {
 
     use approx::assert_relative_eq;
     use topohedral_linalg::{
-        Complex, DVector, MatMul, MatrixOps, SMatrix, SQrReturn, SSchurReturn, Shape, VecType,
+        Complex, DVector, Diagonal, MatMul, MatrixNorms, MatrixOps, ProcrustesMode, RankTolerance,
+        SHessenbergReturn, SInverseError, SLstsqReturn, SMatrix, SMatrixFunctionError,
+        SPolarReturn, SQrPivotedReturn, SQrReturn, SSchurOrderedReturn, SSchurReturn, SSvdReturn,
+        Shape, SolveExpertOptions, SymEigRange, Transpose, Triangular, VecType,
     };
 
     //{{{ collection: eig tests
@@ -37,6 +40,7 @@ mod smatrix_tests {
             0.8755015285934659,
         ]);
 
+        #[cfg(system_backend)]
         for i in 0..3 {
             for j in 0..3 {
                 assert_relative_eq!(
@@ -46,6 +50,53 @@ mod smatrix_tests {
                 );
             }
         }
+        // The reference backend may pick the opposite sign for an eigenvector
+        #[cfg(not(system_backend))]
+        for j in 0..3 {
+            let sign = (0..3)
+                .map(|i| eig.left_eigvecs[(i, j)] * expected_left_eigenvecotors[(i, j)])
+                .sum::<f64>()
+                .signum();
+            for i in 0..3 {
+                assert_relative_eq!(
+                    sign * eig.left_eigvecs[(i, j)],
+                    expected_left_eigenvecotors[(i, j)],
+                    epsilon = 1e-10
+                );
+            }
+        }
+    }
+    #[test]
+    fn test_eig_right_complex_pairs() {
+        // Rotation block with eigenvalues ±i, plus a real eigenvalue 2.
+        let a =
+            SMatrix::<f64, 3, 3>::from_row_slice(&[0.0, -1.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 2.0]);
+
+        let eig = a.eig_right().unwrap();
+        let vals = a.eigvals().unwrap();
+
+        for (k, val) in vals.iter().enumerate() {
+            assert_relative_eq!(eig.eigvals[k].re, val.re, epsilon = 1e-10);
+            assert_relative_eq!(eig.eigvals[k].im, val.im, epsilon = 1e-10);
+            for i in 0..3 {
+                let mut av = Complex::new(0.0, 0.0);
+                for j in 0..3 {
+                    av += a[(i, j)] * eig.eigvecs[(j, k)];
+                }
+                let lv = eig.eigvals[k] * eig.eigvecs[(i, k)];
+                assert_relative_eq!(av.re, lv.re, epsilon = 1e-10);
+                assert_relative_eq!(av.im, lv.im, epsilon = 1e-10);
+            }
+        }
+
+        // The packed vectors of the full decomposition unpack to the same columns.
+        let full = a.eig().unwrap();
+        for k in 0..3 {
+            for (i, v) in full.right_eigvec(k).iter().enumerate() {
+                assert_relative_eq!(v.re, eig.eigvecs[(i, k)].re, epsilon = 1e-10);
+                assert_relative_eq!(v.im, eig.eigvecs[(i, k)].im, epsilon = 1e-10);
+            }
+        }
     }
     //}}}
     //{{{ collection: lu tests
@@ -481,6 +532,47 @@ mod smatrix_tests {
             }
         }
     }
+
+    #[test]
+    fn test_schur_ordered() {
+        let a =
+            SMatrix::<f64, 3, 3>::from_row_slice(&[4.0, 1.0, 2.0, 0.0, -1.0, 1.0, 0.0, 0.0, 2.0]);
+
+        // Move the single stable eigenvalue (-1) to the top-left
+        let SSchurOrderedReturn { q, t, sdim } = a.schur_ordered(|lambda| lambda.re < 0.0).unwrap();
+
+        assert_eq!(sdim, 1);
+        assert_relative_eq!(t[(0, 0)], -1.0, epsilon = 1e-10);
+        let reconstructed = q.matmul(&t).matmul(&q.transpose());
+        for i in 0..9 {
+            assert_relative_eq!(reconstructed[i], a[i], epsilon = 1e-10);
+        }
+        // The leading Schur vector is an eigenvector for -1
+        let v = SMatrix::<f64, 3, 1>::from_col_slice(&[q[(0, 0)], q[(1, 0)], q[(2, 0)]]);
+        let av = a.matmul(&v);
+        for i in 0..3 {
+            assert_relative_eq!(av[i], -v[i], epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_hessenberg() {
+        let a = SMatrix::<f64, 4, 4>::from_row_slice(&[
+            4.0, 1.0, -2.0, 2.0, 1.0, 2.0, 0.0, 1.0, -2.0, 0.0, 3.0, -2.0, 2.0, 1.0, -2.0, -1.0,
+        ]);
+
+        let SHessenbergReturn { h, q } = a.hessenberg().unwrap();
+
+        for j in 0..4 {
+            for i in (j + 2)..4 {
+                assert_eq!(h[(i, j)], 0.0);
+            }
+        }
+        let reconstructed = q.matmul(&h).matmul(&q.transpose());
+        for i in 0..16 {
+            assert_relative_eq!(reconstructed[i], a[i], epsilon = 1e-10);
+        }
+    }
     //}}}
     //{{{ collection: solve test
     #[test]
@@ -501,6 +593,63 @@ mod smatrix_tests {
             assert_relative_eq!(computed_b[i], b[i], max_relative = 1.0e-8);
         }
     }
+
+    #[test]
+    fn test_solve_expert() {
+        let a =
+            SMatrix::<f64, 3, 3>::from_row_slice(&[3.0, -1.0, 2.0, 1.0, 2.0, 0.0, 4.0, 0.0, 6.0]);
+        let b = SMatrix::<f64, 3, 2>::from_row_slice(&[7.0, -7.0, 1.0, 2.0, 22.0, -10.0]);
+
+        let report = a.solve_expert(&b, SolveExpertOptions::default()).unwrap();
+
+        let computed_b = a.matmul(&report.x);
+        for i in 0..6 {
+            assert_relative_eq!(computed_b[i], b[i], max_relative = 1.0e-10);
+        }
+        assert!(!report.singular);
+        assert!(report.rcond > 0.01 && report.rcond <= 1.0);
+        let ferr = report.ferr.unwrap();
+        let berr = report.berr.unwrap();
+        for j in 0..2 {
+            assert!(ferr[j] < 1e-10);
+            assert!(berr[j] < 1e-14);
+        }
+    }
+
+    #[test]
+    fn test_solve_triangular() {
+        // The upper triangle holds unrelated data that must be ignored.
+        let a =
+            SMatrix::<f64, 3, 3>::from_row_slice(&[2.0, 9.0, 9.0, 1.0, 4.0, 9.0, -1.0, 3.0, 5.0]);
+        let l =
+            SMatrix::<f64, 3, 3>::from_row_slice(&[2.0, 0.0, 0.0, 1.0, 4.0, 0.0, -1.0, 3.0, 5.0]);
+        let b = SMatrix::<f64, 3, 2>::from_row_slice(&[1.0, 0.0, 2.0, 1.0, 3.0, -1.0]);
+
+        let x = a
+            .solve_lower_triangular(&b, Transpose::NoTrans, Diagonal::NonUnit)
+            .unwrap();
+        let computed_b = l.matmul(&x);
+        for i in 0..6 {
+            assert_relative_eq!(computed_b[i], b[i], max_relative = 1.0e-12);
+        }
+
+        // Lᵀ X = B through the transpose option.
+        let x = a
+            .solve_lower_triangular(&b, Transpose::Trans, Diagonal::NonUnit)
+            .unwrap();
+        let computed_b = l.transpose().matmul(&x);
+        for i in 0..6 {
+            assert_relative_eq!(computed_b[i], b[i], max_relative = 1.0e-12);
+        }
+
+        let x_view = Triangular::lower(&a).solve(&b).unwrap();
+        let x = a
+            .solve_lower_triangular(&b, Transpose::NoTrans, Diagonal::NonUnit)
+            .unwrap();
+        for i in 0..6 {
+            assert_relative_eq!(x_view[i], x[i], max_relative = 1.0e-12);
+        }
+    }
     //}}}
     //{{{ collection: symeig tests
     #[test]
@@ -531,631 +680,2253 @@ mod smatrix_tests {
             -7.36976229099578e-01,
         ]);
 
+        #[cfg(system_backend)]
         for (val, exp) in eig.eigvecs.iter().zip(expected_eigenvectors.iter()) {
             assert_relative_eq!(val, exp, epsilon = 1e-10);
         }
+        // The reference backend may pick the opposite sign for an eigenvector
+        #[cfg(not(system_backend))]
+        for j in 0..3 {
+            let sign = (0..3)
+                .map(|i| eig.eigvecs[(i, j)] * expected_eigenvectors[(i, j)])
+                .sum::<f64>()
+                .signum();
+            for i in 0..3 {
+                assert_relative_eq!(
+                    sign * eig.eigvecs[(i, j)],
+                    expected_eigenvectors[(i, j)],
+                    epsilon = 1e-10
+                );
+            }
+        }
     }
-    //}}}
-}
-
-mod dmatrix_tests {
 
-    use approx::assert_relative_eq;
+    #[test]
+    fn test_symeig_range_index() {
+        let a = SMatrix::<f64, 3, 3>::from_row_slice(&[
+            2.0, -1.0, 0.0, -1.0, 2.0, -1.0, 0.0, -1.0, 2.0,
+        ]);
 
-    use topohedral_linalg::*;
+        // The two smallest eigenvalues of the 1D Laplacian are 2 - √2 and 2.
+        let res = a.symeig_range(SymEigRange::Index(0, 1), true).unwrap();
+        assert_eq!(res.eigvals.len(), 2);
+        assert_eq!(res.eigvecs.len(), 2);
+        assert_relative_eq!(res.eigvals[0], 2.0 - 2.0f64.sqrt(), epsilon = 1e-10);
+        assert_relative_eq!(res.eigvals[1], 2.0, epsilon = 1e-10);
+
+        for (x, &lambda) in res.eigvecs.iter().zip(res.eigvals.iter()) {
+            let x = SMatrix::<f64, 3, 1>::from_col_slice(x);
+            let ax: SMatrix<f64, 3, 1> = a.matmul(&x);
+            for i in 0..3 {
+                assert_relative_eq!(ax[i], lambda * x[i], epsilon = 1e-10);
+            }
+        }
+    }
+    //}}}
+    //{{{ collection: generalized eig tests
+    #[test]
+    fn test_generalized_symeig() {
+        let a = SMatrix::<f64, 2, 2>::from_row_slice(&[4.0, 1.0, 1.0, 3.0]);
+        let b = SMatrix::<f64, 2, 2>::from_row_slice(&[2.0, 0.5, 0.5, 1.0]);
+
+        let res = a.generalized_symeig(&b).unwrap();
+        assert!(res.eigvals[0] <= res.eigvals[1]);
+
+        for k in 0..2 {
+            let x =
+                SMatrix::<f64, 2, 1>::from_col_slice(&[res.eigvecs[(0, k)], res.eigvecs[(1, k)]]);
+            let ax: SMatrix<f64, 2, 1> = a.matmul(&x);
+            let bx: SMatrix<f64, 2, 1> = b.matmul(&x);
+
+            // Verify A x = λ B x
+            for i in 0..2 {
+                assert_relative_eq!(ax[i], res.eigvals[k] * bx[i], epsilon = 1e-10);
+            }
 
-    //{{{ collection: eig tests
+            // The eigenvectors are B-orthonormal.
+            assert_relative_eq!(x[0] * bx[0] + x[1] * bx[1], 1.0, epsilon = 1e-10);
+        }
+    }
+    //}}}
+    //{{{ collection: svd tests
     #[test]
-    fn test_eig_simple() {
-        let a =
-            DMatrix::<f64>::from_row_slice(&[1.0, 5.0, 0.0, 2.0, 4.0, -1.0, 0.0, 2.0, 3.0], 3, 3);
+    fn test_svd_reconstruction() {
+        let a = SMatrix::<f64, 2, 3>::from_row_slice(&[3.0, 2.0, 2.0, 2.0, 3.0, -2.0]);
 
-        let eig = a.eig().unwrap();
-        // Known eigenvalues for this matrix
-        let expected_eigenvalues = [
-            Complex::<f64>::new(-0.8595233886152194, 0.0),
-            Complex::<f64>::new(5.433664629783286, 0.0),
-            Complex::<f64>::new(3.42585875883193, 0.0),
-        ];
+        let SSvdReturn { u, s, vt } = a.svd().unwrap();
 
-        for (i, val) in expected_eigenvalues.iter().enumerate() {
-            assert_relative_eq!(eig.eigvals[i].re, val.re, epsilon = 1e-10);
-            assert_relative_eq!(eig.eigvals[i].im, val.im, epsilon = 1e-10);
+        assert_eq!(s.len(), 2);
+        assert_relative_eq!(s[0], 5.0, epsilon = 1e-10);
+        assert_relative_eq!(s[1], 3.0, epsilon = 1e-10);
+
+        // Verify U * Σ * Vᵀ = A
+        let mut sigma = SMatrix::<f64, 2, 3>::zeros();
+        for (i, &value) in s.iter().enumerate() {
+            sigma[(i, i)] = value;
         }
+        let reconstructed = u.matmul(&sigma).matmul(&vt);
 
-        // Known left eigenvectors for this matrix
-        let expected_left_eigenvecotors = DMatrix::<f64>::from_row_slice(
-            &[
-                -0.7212203345550064,
-                -0.3850687990747861,
-                -0.3073880480179293,
-                0.6705630402249634,
-                -0.8536329572455033,
-                -0.3728399943222721,
-                0.1737424476304467,
-                0.3507603088768719,
-                0.8755015285934659,
-            ],
-            3,
-            3,
-        );
+        for i in 0..6 {
+            assert_relative_eq!(reconstructed[i], a[i], epsilon = 1e-10);
+        }
 
+        // Verify U and Vᵀ are orthogonal
+        let u_identity = u.matmul(u.transpose());
+        let v_identity = vt.matmul(vt.transpose());
         for i in 0..3 {
             for j in 0..3 {
-                assert_relative_eq!(
-                    eig.left_eigvecs[(i, j)],
-                    expected_left_eigenvecotors[(i, j)],
-                    epsilon = 1e-10
-                );
+                let expected = if i == j { 1.0 } else { 0.0 };
+                if i < 2 && j < 2 {
+                    assert_relative_eq!(u_identity[(i, j)], expected, epsilon = 1e-10);
+                }
+                assert_relative_eq!(v_identity[(i, j)], expected, epsilon = 1e-10);
             }
         }
     }
+
+    #[test]
+    fn test_singular_values() {
+        let a = SMatrix::<f64, 3, 2>::from_row_slice(&[3.0, 2.0, 2.0, 3.0, 2.0, -2.0]);
+
+        let s = a.singular_values().unwrap();
+
+        assert_eq!(s.len(), 2);
+        assert_relative_eq!(s[0], 5.0, epsilon = 1e-10);
+        assert_relative_eq!(s[1], 3.0, epsilon = 1e-10);
+    }
     //}}}
-    //{{{ collection: lu tests
+    //{{{ collection: inverse tests
     #[test]
-    fn test_lu_non_diagonal_dominant() {
-        let a = DMatrix::<f64>::from_row_slice(
-            &[
-                1.0, 2000.0, 3000.0, 5000.0, 10.0, -8900.0, -10000.0, 9008.0, 0.0,
-            ],
-            3,
-            3,
-        );
+    fn test_inverse_2x2() {
+        let a = SMatrix::<f64, 2, 2>::from_row_slice(&[4.0, 7.0, 2.0, 6.0]);
 
-        let lu_ret = a.lu().unwrap();
+        let a_inv = a.inverse().unwrap();
 
-        let exp_p =
-            DMatrix::<f64>::from_row_slice(&[0.0, 0.0, 1.0, 0.0, 1.0, 0.0, 1.0, 0.0, 0.0], 3, 3);
+        let expected = SMatrix::<f64, 2, 2>::from_row_slice(&[0.6, -0.7, -0.2, 0.4]);
+        for i in 0..4 {
+            assert_relative_eq!(a_inv[i], expected[i], epsilon = 1e-12);
+        }
+    }
 
-        let exp_l = DMatrix::<f64>::from_row_slice(
-            &[
-                1.00000000e+00,
-                0.00000000e+00,
-                0.00000000e+00,
-                -5.00000000e-01,
-                1.00000000e+00,
-                0.00000000e+00,
-                -1.00000000e-04,
-                4.43265574e-01,
-                1.00000000e+00,
-            ],
-            3,
-            3,
-        );
+    #[test]
+    fn test_inverse_3x3() {
+        let a =
+            SMatrix::<f64, 3, 3>::from_row_slice(&[2.0, 0.0, 1.0, 1.0, 3.0, 2.0, 1.0, 1.0, 2.0]);
 
-        let exp_u = DMatrix::<f64>::from_row_slice(
-            &[
-                -10000.0,
-                9008.0,
-                0.0,
-                0.0,
-                4514.0,
-                -8900.0,
-                0.0,
-                0.0,
-                6945.06360656,
-            ],
-            3,
-            3,
-        );
+        let identity = a.matmul(&a.inverse().unwrap());
 
-        for i in 0..9 {
-            assert_relative_eq!(lu_ret.p[i], exp_p[i], max_relative = 1.0e-8);
-            assert_relative_eq!(lu_ret.l[i], exp_l[i], max_relative = 1.0e-8);
-            assert_relative_eq!(lu_ret.u[i], exp_u[i], max_relative = 1.0e-8);
+        for i in 0..3 {
+            for j in 0..3 {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert_relative_eq!(identity[(i, j)], expected, epsilon = 1e-12);
+            }
         }
     }
 
     #[test]
-    fn test_lu_diagonal_dominant() {
-        let a = DMatrix::<f64>::from_row_slice(
-            &[
-                100000.0, 10.0, 56.0, 10.0, -69.0, 1.56e6, 3.0, -9.0, 0.0, 0.0, -5.6e-5, -700.0,
-                890.0, 0.0, -7899.0, 8.0e5,
-            ],
-            4,
-            4,
-        );
+    fn test_inverse_4x4() {
+        let a = SMatrix::<f64, 4, 4>::from_row_slice(&[
+            4.0, 1.0, 0.0, 2.0, 1.0, 3.0, 1.0, 0.0, 0.0, 1.0, 5.0, 1.0, 2.0, 0.0, 1.0, 6.0,
+        ]);
 
-        let lu_ret = a.lu().unwrap();
+        let identity = a.matmul(&a.inverse().unwrap());
 
-        let exp_p = [
-            1., 0., 0., 0., 0., 1., 0., 0., 0., 0., 0., 1., 0., 0., 1., 0.,
-        ];
+        for i in 0..4 {
+            for j in 0..4 {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert_relative_eq!(identity[(i, j)], expected, epsilon = 1e-12);
+            }
+        }
+    }
 
-        let exp_l = DMatrix::<f64>::from_row_slice(
-            &[
-                1.00000000e+00,
-                0.00000000e+00,
-                0.00000000e+00,
-                0.00000000e+00,
-                -6.90000000e-04,
-                1.00000000e+00,
-                0.00000000e+00,
-                0.00000000e+00,
-                8.90000000e-03,
-                -5.70512818e-08,
-                1.00000000e+00,
-                0.00000000e+00,
-                0.00000000e+00,
-                0.00000000e+00,
-                7.08905771e-09,
-                1.00000000e+00,
-            ],
-            4,
-            4,
-        );
+    #[test]
+    fn test_inverse_5x5() {
+        let mut a = SMatrix::<f64, 5, 5>::zeros();
+        for i in 0..5 {
+            a[(i, i)] = 4.0;
+            if i + 1 < 5 {
+                a[(i, i + 1)] = 1.0;
+                a[(i + 1, i)] = 1.0;
+            }
+        }
 
-        let exp_u = DMatrix::<f64>::from_row_slice(
-            &[
-                1.00000000e+05,
-                1.00000000e+01,
-                5.60000000e+01,
-                1.00000000e+01,
-                0.00000000e+00,
-                1.56000001e+06,
-                3.03864000e+00,
-                -8.99310000e+00,
-                0.00000000e+00,
-                0.00000000e+00,
-                -7.89949840e+03,
-                7.99999911e+05,
-                0.00000000e+00,
-                0.00000000e+00,
-                0.00000000e+00,
-                -7.00005671e+02,
-            ],
-            4,
-            4,
-        );
+        let identity = a.matmul(&a.inverse().unwrap());
 
-        for i in 0..16 {
-            assert_relative_eq!(lu_ret.p[i], exp_p[i], max_relative = 1.0e-8);
-            assert_relative_eq!(lu_ret.l[i], exp_l[i], max_relative = 1.0e-8);
-            assert_relative_eq!(lu_ret.u[i], exp_u[i], max_relative = 1.0e-8);
+        for i in 0..5 {
+            for j in 0..5 {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert_relative_eq!(identity[(i, j)], expected, epsilon = 1e-12);
+            }
         }
     }
-    //}}}
-    //{{{ collection: cholesky tests
+
     #[test]
-    fn test_cholesky_spd() {
-        let a = DMatrix::<f64>::from_row_slice(
-            &[4.0, 12.0, -16.0, 12.0, 37.0, -43.0, -16.0, -43.0, 98.0],
-            3,
-            3,
-        );
+    fn test_inverse_singular_errors() {
+        let a =
+            SMatrix::<f64, 3, 3>::from_row_slice(&[1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 1.0, 0.0, 1.0]);
 
-        let chol = a.cholesky().unwrap();
-        let expected_l =
-            DMatrix::<f64>::from_row_slice(&[2.0, 0.0, 0.0, 6.0, 1.0, 0.0, -8.0, 5.0, 3.0], 3, 3);
-        let reconstructed = (&chol.l).matmul(chol.l.transpose());
-
-        for i in 0..9 {
-            assert_relative_eq!(chol.l[i], expected_l[i], epsilon = 1e-10);
-            assert_relative_eq!(reconstructed[i], a[i], epsilon = 1e-10);
-        }
+        assert!(matches!(a.inverse(), Err(SInverseError::Singular)));
     }
 
     #[test]
-    fn test_cholesky_non_positive_definite_errors() {
-        let a = DMatrix::<f64>::from_row_slice(&[1.0, 2.0, 2.0, 1.0], 2, 2);
+    fn test_pinv_rectangular() {
+        let a = SMatrix::<f64, 3, 2>::from_row_slice(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
 
-        assert!(a.cholesky().is_err());
+        let a_pinv: SMatrix<f64, 2, 3> = a.pinv(1e-12).unwrap();
+
+        // Verify A * A⁺ * A = A
+        let reconstructed = a.matmul(&a_pinv).matmul(&a);
+        for i in 0..6 {
+            assert_relative_eq!(reconstructed[i], a[i], epsilon = 1e-10);
+        }
     }
     //}}}
-    //{{{ collection: matmul tests
+    //{{{ collection: matrix function tests
     #[test]
-    fn test_matmul_f64_general() {
-        let a = DMatrix::<f64>::from_row_slice(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 2, 3);
-
-        let b = DMatrix::<f64>::from_row_slice(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 3, 2);
+    fn test_expm_rotation_generator() {
+        let theta = 0.7_f64;
+        let a = SMatrix::<f64, 2, 2>::from_row_slice(&[0.0, -theta, theta, 0.0]);
 
-        let expected = DMatrix::<f64>::from_row_slice(&[22.0, 28.0, 49.0, 64.0], 2, 2);
-
-        let result = (&a).matmul(&b);
+        let e = a.expm().unwrap();
 
-        for (res_val, exp_val) in result.iter().zip(expected.iter()) {
-            assert_relative_eq!(res_val, exp_val, epsilon = 1e-10);
+        let expected = SMatrix::<f64, 2, 2>::from_row_slice(&[
+            theta.cos(),
+            -theta.sin(),
+            theta.sin(),
+            theta.cos(),
+        ]);
+        for i in 0..4 {
+            assert_relative_eq!(e[i], expected[i], epsilon = 1e-12);
+        }
+        let log = e.logm().unwrap();
+        for i in 0..4 {
+            assert_relative_eq!(log[i], a[i], epsilon = 1e-12);
         }
     }
 
     #[test]
-    fn test_matmul_f64_general_mut_lhs_ref_rhs() {
-        let mut a = DMatrix::<f64>::from_row_slice(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 2, 3);
-        let b = DMatrix::<f64>::from_row_slice(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 3, 2);
-        let expected = DMatrix::<f64>::from_row_slice(&[22.0, 28.0, 49.0, 64.0], 2, 2);
+    fn test_sqrtm_logm_roundtrip() {
+        let a =
+            SMatrix::<f64, 3, 3>::from_row_slice(&[4.0, 1.0, 0.5, -1.0, 3.0, 1.0, 0.2, 0.0, 2.0]);
 
-        let result = (&mut a).matmul(&b);
+        let r = a.sqrtm().unwrap();
+        let r2 = r.matmul(&r);
+        for i in 0..9 {
+            assert_relative_eq!(r2[i], a[i], epsilon = 1e-10);
+        }
 
-        for (res_val, exp_val) in result.iter().zip(expected.iter()) {
-            assert_relative_eq!(res_val, exp_val, epsilon = 1e-10);
+        let e = a.logm().unwrap().expm().unwrap();
+        for i in 0..9 {
+            assert_relative_eq!(e[i], a[i], epsilon = 1e-10);
         }
     }
 
     #[test]
-    fn test_matmul_smatrix_dmatrix_f64_general() {
-        let a = SMatrix::<f64, 2, 3>::from_row_slice(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
-
-        let b = DMatrix::<f64>::from_row_slice(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 3, 2);
-
-        let expected = DMatrix::<f64>::from_row_slice(&[22.0, 28.0, 49.0, 64.0], 2, 2);
-
-        let result = (&a).matmul(&b);
+    fn test_powm() {
+        let a = SMatrix::<f64, 2, 2>::from_row_slice(&[1.0, 1.0, 1.0, 0.0]);
+
+        // Powers of the Fibonacci matrix
+        let p = a.powm(10).unwrap();
+        let expected = SMatrix::<f64, 2, 2>::from_row_slice(&[89.0, 55.0, 55.0, 34.0]);
+        for i in 0..4 {
+            assert_relative_eq!(p[i], expected[i], epsilon = 1e-12);
+        }
 
-        for (res_val, exp_val) in result.iter().zip(expected.iter()) {
-            assert_relative_eq!(res_val, exp_val, epsilon = 1e-10);
+        let identity = a.powm(-3).unwrap().matmul(&a.powm(3).unwrap());
+        for i in 0..2 {
+            for j in 0..2 {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert_relative_eq!(identity[(i, j)], expected, epsilon = 1e-12);
+            }
         }
     }
 
     #[test]
-    fn test_matmul_dmatrix_smatrix_f64_general() {
-        let a = DMatrix::<f64>::from_row_slice(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 2, 3);
-
-        let b = SMatrix::<f64, 3, 2>::from_row_slice(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
-
-        let expected = DMatrix::<f64>::from_row_slice(&[22.0, 28.0, 49.0, 64.0], 2, 2);
-
-        let result = (&a).matmul(&b);
-
-        for (res_val, exp_val) in result.iter().zip(expected.iter()) {
-            assert_relative_eq!(res_val, exp_val, epsilon = 1e-10);
-        }
+    fn test_sqrtm_logm_negative_eigenvalue_errors() {
+        let a = SMatrix::<f64, 2, 2>::from_row_slice(&[-1.0, 1.0, 0.0, 2.0]);
+
+        assert!(matches!(
+            a.sqrtm(),
+            Err(SMatrixFunctionError::NoRealSquareRoot)
+        ));
+        assert!(matches!(
+            a.logm(),
+            Err(SMatrixFunctionError::NoRealLogarithm)
+        ));
     }
-
+    //}}}
+    //{{{ collection: lstsq tests
     #[test]
-    fn test_matmul_dmatrix_smatrix_mutability_combinations() {
-        let mut a = DMatrix::<f64>::from_row_slice(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 2, 3);
-        let mut b = SMatrix::<f64, 3, 2>::from_row_slice(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
-        let expected = DMatrix::<f64>::from_row_slice(&[22.0, 28.0, 49.0, 64.0], 2, 2);
-
-        let result_ref_ref = (&a).matmul(&b);
-        let result_mut_ref = (&mut a).matmul(&b);
-        let result_ref_mut = (&a).matmul(&mut b);
-        let result_mut_mut = (&mut a).matmul(&mut b);
-
-        for (res_val, exp_val) in result_ref_ref.iter().zip(expected.iter()) {
-            assert_relative_eq!(res_val, exp_val, epsilon = 1e-10);
-        }
-        for (res_val, exp_val) in result_mut_ref.iter().zip(expected.iter()) {
-            assert_relative_eq!(res_val, exp_val, epsilon = 1e-10);
-        }
-        for (res_val, exp_val) in result_ref_mut.iter().zip(expected.iter()) {
-            assert_relative_eq!(res_val, exp_val, epsilon = 1e-10);
-        }
-        for (res_val, exp_val) in result_mut_mut.iter().zip(expected.iter()) {
-            assert_relative_eq!(res_val, exp_val, epsilon = 1e-10);
-        }
+    fn test_lstsq_line_fit() {
+        // Fit y = c0 + c1 * t through (0, 1), (1, 3), (2, 4), (3, 7)
+        let a = SMatrix::<f64, 4, 2>::from_row_slice(&[1.0, 0.0, 1.0, 1.0, 1.0, 2.0, 1.0, 3.0]);
+        let b = SMatrix::<f64, 4, 1>::from_row_slice(&[1.0, 3.0, 4.0, 7.0]);
+
+        let SLstsqReturn {
+            x,
+            residuals,
+            rank,
+            s,
+        } = a.lstsq(&b).unwrap();
+
+        assert_relative_eq!(x[0], 0.9, epsilon = 1e-10);
+        assert_relative_eq!(x[1], 1.9, epsilon = 1e-10);
+        assert_eq!(rank, 2);
+        assert_eq!(residuals.len(), 1);
+        assert_relative_eq!(residuals[0], 0.7, epsilon = 1e-10);
+        assert!(s.is_empty());
     }
 
     #[test]
-    #[should_panic(expected = "Matrix dimensions are incompatible for multiplication: 2x3 and 2x2")]
-    fn test_matmul_smatrix_dmatrix_dimension_mismatch_panics() {
-        let a = SMatrix::<f64, 2, 3>::from_row_slice(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
-
-        let b = DMatrix::<f64>::from_row_slice(&[1.0, 2.0, 3.0, 4.0], 2, 2);
+    fn test_lstsq_svd_rank_deficient() {
+        let a = SMatrix::<f64, 3, 2>::from_row_slice(&[1.0, 2.0, 2.0, 4.0, 3.0, 6.0]);
+        let b = SMatrix::<f64, 3, 1>::from_row_slice(&[1.0, 2.0, 3.0]);
 
-        let _ = (&a).matmul(&b);
+        let SLstsqReturn {
+            x,
+            residuals,
+            rank,
+            s,
+        } = a.lstsq_svd(&b, 1e-12).unwrap();
+
+        // Minimum-norm solution of x0 + 2 x1 = 1
+        assert_eq!(rank, 1);
+        assert_relative_eq!(x[0], 0.2, epsilon = 1e-10);
+        assert_relative_eq!(x[1], 0.4, epsilon = 1e-10);
+        assert!(residuals.is_empty());
+        assert_eq!(s.len(), 2);
+        assert_relative_eq!(s[1], 0.0, epsilon = 1e-10);
     }
-
+    //}}}
+    //{{{ collection: factor tests
     #[test]
-    fn test_matmul_dmatrix_scvector_col_vector() {
-        let a = DMatrix::<f64>::from_row_slice(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 2, 3);
-        let b = SCVector::<f64, 3>::from_col_slice(&[1.0, 2.0, 3.0]);
-        let expected = DMatrix::<f64>::from_row_slice(&[14.0, 32.0], 2, 1);
-
-        let result = (&a).matmul(&b);
-
-        for (res_val, exp_val) in result.iter().zip(expected.iter()) {
-            assert_relative_eq!(res_val, exp_val, epsilon = 1e-10);
+    fn test_lu_factor_solve_many() {
+        let a = SMatrix::<f64, 3, 3>::from_row_slice(&[
+            4.0, -2.0, 1.0, -2.0, 4.0, -2.0, 1.0, -2.0, 4.0,
+        ]);
+        let lu = a.lu_factor().unwrap();
+        assert_relative_eq!(lu.det(), 36.0, epsilon = 1e-10);
+
+        for x_true in [[1.0, 2.0, 3.0], [-1.0, 0.5, 0.0]] {
+            let x_true = SMatrix::<f64, 3, 1>::from_col_slice(&x_true);
+            let b = a.matmul(&x_true);
+            let x = lu.solve(&b).unwrap();
+            for i in 0..3 {
+                assert_relative_eq!(x[i], x_true[i], epsilon = 1e-10);
+            }
         }
     }
 
     #[test]
-    fn test_matmul_smatrix_dvector_col_vector() {
-        let a = SMatrix::<f64, 2, 3>::from_row_slice(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
-        let b = DVector::<f64>::from_slice_vec(&[1.0, 2.0, 3.0], 3, VecType::Col);
-        let expected = DMatrix::<f64>::from_row_slice(&[14.0, 32.0], 2, 1);
-
-        let result = (&a).matmul(&b);
+    fn test_cholesky_factor() {
+        let a = SMatrix::<f64, 3, 3>::from_row_slice(&[
+            4.0, -2.0, 1.0, -2.0, 4.0, -2.0, 1.0, -2.0, 4.0,
+        ]);
+        let chol = a.cholesky_factor().unwrap();
+        assert_relative_eq!(chol.det(), 36.0, epsilon = 1e-10);
 
-        for (res_val, exp_val) in result.iter().zip(expected.iter()) {
-            assert_relative_eq!(res_val, exp_val, epsilon = 1e-10);
+        let inv = chol.inverse().unwrap();
+        let eye = a.matmul(&inv);
+        for i in 0..3 {
+            for j in 0..3 {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert_relative_eq!(eye[(i, j)], expected, epsilon = 1e-10);
+            }
         }
     }
 
     #[test]
-    fn test_matmul_srvector_dmatrix_row_vector() {
-        let a = SRVector::<f64, 3>::from_row_slice(&[1.0, 2.0, 3.0]);
-        let b = DMatrix::<f64>::from_row_slice(&[4.0, 5.0, 6.0, 7.0, 8.0, 9.0], 3, 2);
-        let expected = DMatrix::<f64>::from_row_slice(&[40.0, 46.0], 1, 2);
-
-        let result = (&a).matmul(&b);
-
-        for (res_val, exp_val) in result.iter().zip(expected.iter()) {
-            assert_relative_eq!(res_val, exp_val, epsilon = 1e-10);
-        }
+    fn test_slogdet() {
+        // Non-symmetric with a negative determinant takes the LU path
+        let a =
+            SMatrix::<f64, 3, 3>::from_row_slice(&[0.0, 2.0, 1.0, 3.0, 1.0, 0.0, 1.0, 1.0, 1.0]);
+        let (sign, logdet) = a.slogdet().unwrap();
+        assert_relative_eq!(sign, -1.0);
+        assert_relative_eq!(logdet, 4.0_f64.ln(), epsilon = 1e-12);
+        let (sign, logdet) = a.lu_factor().unwrap().slogdet();
+        assert_relative_eq!(sign, -1.0);
+        assert_relative_eq!(logdet, 4.0_f64.ln(), epsilon = 1e-12);
+
+        // Symmetric positive definite input takes the Cholesky path
+        let spd = SMatrix::<f64, 3, 3>::from_row_slice(&[
+            4.0, -2.0, 1.0, -2.0, 4.0, -2.0, 1.0, -2.0, 4.0,
+        ]);
+        let (sign, logdet) = spd.slogdet().unwrap();
+        assert_relative_eq!(sign, 1.0);
+        assert_relative_eq!(logdet, 36.0_f64.ln(), epsilon = 1e-12);
+        assert_relative_eq!(
+            spd.cholesky_factor().unwrap().logdet(),
+            logdet,
+            epsilon = 1e-12
+        );
     }
 
     #[test]
-    fn test_matmul_dvector_smatrix_row_vector() {
-        let a = DVector::<f64>::from_slice_vec(&[1.0, 2.0, 3.0], 3, VecType::Row);
-        let b = SMatrix::<f64, 3, 2>::from_row_slice(&[4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
-        let expected = DMatrix::<f64>::from_row_slice(&[40.0, 46.0], 1, 2);
+    fn test_determinant_closed_form() {
+        let a2 = SMatrix::<f64, 2, 2>::from_row_slice(&[1.0, 2.0, 3.0, 4.0]);
+        assert_relative_eq!(a2.determinant(), -2.0, epsilon = 1e-12);
 
-        let result = (&a).matmul(&b);
+        let a3 =
+            SMatrix::<f64, 3, 3>::from_row_slice(&[0.0, 2.0, 1.0, 3.0, 1.0, 0.0, 1.0, 1.0, 1.0]);
+        assert_relative_eq!(a3.determinant(), -4.0, epsilon = 1e-12);
 
-        for (res_val, exp_val) in result.iter().zip(expected.iter()) {
-            assert_relative_eq!(res_val, exp_val, epsilon = 1e-10);
-        }
+        let a4 = SMatrix::<f64, 4, 4>::from_row_slice(&[
+            1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 2.0, 6.0, 4.0, 8.0, 3.0, 1.0, 1.0, 2.0,
+        ]);
+        assert_relative_eq!(a4.determinant(), 72.0, epsilon = 1e-12);
     }
 
     #[test]
+    fn test_qr_factor_least_squares() {
+        let a = SMatrix::<f64, 4, 2>::from_row_slice(&[1.0, 0.0, 1.0, 1.0, 1.0, 2.0, 1.0, 3.0]);
+        let b = SMatrix::<f64, 4, 1>::from_row_slice(&[1.0, 3.0, 4.0, 7.0]);
+        let qr = a.qr_factor().unwrap();
+
+        let x = qr.solve(&b).unwrap();
+        assert_relative_eq!(x[0], 0.9, epsilon = 1e-10);
+        assert_relative_eq!(x[1], 1.9, epsilon = 1e-10);
+
+        // The trailing rows of Qᵀb carry the residual.
+        let mut qtb = b;
+        qr.solve_in_place(&mut qtb).unwrap();
+        let rss = qtb[2] * qtb[2] + qtb[3] * qtb[3];
+        assert_relative_eq!(rss, 0.7, epsilon = 1e-10);
+    }
 
-    fn test_matmul_f64_col_vector() {
-        let a = DMatrix::<f64>::from_row_slice(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 2, 3);
-
-        let b = DMatrix::<f64>::from_row_slice(&[1.0, 2.0, 3.0], 3, 1);
+    #[test]
+    fn test_ldlt_kkt() {
+        // A saddle-point matrix: Cholesky fails, but LDLᵀ factors it.
+        let a =
+            SMatrix::<f64, 3, 3>::from_row_slice(&[2.0, 0.0, 1.0, 0.0, 2.0, 1.0, 1.0, 1.0, 0.0]);
+        let ldlt = a.ldlt().unwrap();
 
-        let expected = DMatrix::<f64>::from_row_slice(&[14.0, 32.0], 2, 1);
+        assert_eq!(ldlt.inertia(), (2, 1, 0));
+        assert_relative_eq!(ldlt.det(), -4.0, epsilon = 1e-10);
 
-        let result = (&a).matmul(&b);
+        // Verify L * D * Lᵀ = A
+        let l = ldlt.l();
+        let ld: SMatrix<f64, 3, 3> = l.matmul(&ldlt.d());
+        let reconstructed: SMatrix<f64, 3, 3> = ld.matmul(&l.transpose());
+        for i in 0..9 {
+            assert_relative_eq!(reconstructed[i], a[i], epsilon = 1e-10);
+        }
 
-        for (res_val, exp_val) in result.iter().zip(expected.iter()) {
-            assert_relative_eq!(res_val, exp_val, epsilon = 1e-10);
+        let x_true = SMatrix::<f64, 3, 1>::from_col_slice(&[1.0, -2.0, 3.0]);
+        let b: SMatrix<f64, 3, 1> = a.matmul(&x_true);
+        let x = ldlt.solve(&b).unwrap();
+        for i in 0..3 {
+            assert_relative_eq!(x[i], x_true[i], epsilon = 1e-10);
         }
     }
-
+    //}}}
+    //{{{ collection: qr_pivoted tests
     #[test]
-    fn test_matmul_f32_general() {
-        let a = DMatrix::<f32>::from_row_slice(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 2, 3);
-
-        let b = DMatrix::<f32>::from_row_slice(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 3, 2);
+    fn test_qr_pivoted_rank_deficient() {
+        let a =
+            SMatrix::<f64, 3, 3>::from_row_slice(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
 
-        let expected = DMatrix::<f32>::from_row_slice(&[22.0, 28.0, 49.0, 64.0], 2, 2);
+        let SQrPivotedReturn { q, r, perm, rank } = a.qr_pivoted(1e-10).unwrap();
+        assert_eq!(rank, 2);
 
-        let result = (&a).matmul(&b);
+        let mut sorted = perm;
+        sorted.sort();
+        assert_eq!(sorted, [0, 1, 2]);
 
-        for (res_val, exp_val) in result.iter().zip(expected.iter()) {
-            assert_relative_eq!(res_val, exp_val, epsilon = 1e-10);
+        // Verify Q*R = A*P
+        let qr: SMatrix<f64, 3, 3> = q.matmul(&r);
+        for j in 0..3 {
+            for i in 0..3 {
+                assert_relative_eq!(qr[(i, j)], a[(i, perm[j])], epsilon = 1e-10);
+            }
         }
-    }
 
+        // The diagonal of R is non-increasing in modulus.
+        assert!(r[(0, 0)].abs() >= r[(1, 1)].abs());
+        assert!(r[(1, 1)].abs() >= r[(2, 2)].abs());
+    }
+    //}}}
+    //{{{ collection: rank tests
     #[test]
-    fn test_matmul_f32_col_vector() {
-        let a = DMatrix::<f32>::from_row_slice(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 2, 3);
-
-        let b = DMatrix::<f32>::from_row_slice(&[1.0, 2.0, 3.0], 3, 1);
+    fn test_rank_null_space_orth() {
+        let a =
+            SMatrix::<f64, 3, 3>::from_row_slice(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
 
-        let expected = DMatrix::<f32>::from_row_slice(&[14.0, 32.0], 2, 1);
+        assert_eq!(a.rank(RankTolerance::default()).unwrap(), 2);
+        assert_eq!(a.rank(RankTolerance::Relative(0.5)).unwrap(), 1);
+        assert_eq!(a.rank(RankTolerance::Absolute(100.0)).unwrap(), 0);
 
-        let result = (&a).matmul(&b);
+        // The null space is spanned by (1, -2, 1) / sqrt(6)
+        let null = a.null_space(RankTolerance::default()).unwrap();
+        assert_eq!((null.nrows(), null.ncols()), (3, 1));
+        let sign = null[(0, 0)].signum();
+        for (i, x) in [1.0, -2.0, 1.0].iter().enumerate() {
+            assert_relative_eq!(sign * null[(i, 0)], x / 6.0_f64.sqrt(), epsilon = 1e-10);
+        }
 
-        for (res_val, exp_val) in result.iter().zip(expected.iter()) {
-            assert_relative_eq!(res_val, exp_val, epsilon = 1e-10);
+        // Projecting the columns of A onto the range basis leaves them unchanged
+        let range = a.orth(RankTolerance::default()).unwrap();
+        assert_eq!((range.nrows(), range.ncols()), (3, 2));
+        for j in 0..3 {
+            for i in 0..3 {
+                let mut proj = 0.0;
+                for l in 0..2 {
+                    let coeff: f64 = (0..3).map(|k| range[(k, l)] * a[(k, j)]).sum();
+                    proj += range[(i, l)] * coeff;
+                }
+                assert_relative_eq!(proj, a[(i, j)], epsilon = 1e-10);
+            }
         }
     }
-
+    //}}}
+    //{{{ collection: polar tests
     #[test]
-    fn test_matmul_i32() {
-        let a = DMatrix::<i32>::from_row_slice(&[1, 2, 3, 4], 2, 2);
-
-        let b = DMatrix::<i32>::from_row_slice(&[5, 6, 7, 8], 2, 2);
-
-        let expected = DMatrix::<i32>::from_row_slice(&[19, 22, 43, 50], 2, 2);
-
-        let result = (&a).matmul(&b);
-
-        for (res_val, exp_val) in result.iter().zip(expected.iter()) {
-            assert_eq!(res_val, exp_val);
+    fn test_polar() {
+        let a =
+            SMatrix::<f64, 3, 3>::from_row_slice(&[2.0, -1.0, 0.0, 1.0, 3.0, 1.0, 0.0, 1.0, 4.0]);
+        let SPolarReturn { u, p } = a.polar().unwrap();
+
+        let up: SMatrix<f64, 3, 3> = u.matmul(&p);
+        let utu: SMatrix<f64, 3, 3> = u.transpose().matmul(&u);
+        for j in 0..3 {
+            for i in 0..3 {
+                assert_relative_eq!(up[(i, j)], a[(i, j)], epsilon = 1e-10);
+                assert_relative_eq!(p[(i, j)], p[(j, i)], epsilon = 1e-12);
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert_relative_eq!(utu[(i, j)], expected, epsilon = 1e-10);
+            }
         }
+        assert!(u.determinant() > 0.0);
     }
 
     #[test]
-    fn test_matmul_u64() {
-        let a = DMatrix::<i64>::from_row_slice(&[1, 2, 3, 4], 2, 2);
-
-        let b = DMatrix::<i64>::from_row_slice(&[5, 6], 2, 1);
-
-        let expected = DMatrix::<i64>::from_row_slice(&[17, 39], 2, 1);
-
-        let result = (&a).matmul(&b);
-
-        for (res_val, exp_val) in result.iter().zip(expected.iter()) {
-            assert_eq!(res_val, exp_val);
+    fn test_procrustes_kabsch() {
+        let a = SMatrix::<f64, 4, 3>::from_row_slice(&[
+            1.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 3.0, 1.0, 1.0, 1.0,
+        ]);
+        let (c, s) = (0.5_f64.cos(), 0.5_f64.sin());
+        let rot = SMatrix::<f64, 3, 3>::from_row_slice(&[c, -s, 0.0, s, c, 0.0, 0.0, 0.0, 1.0]);
+        let b: SMatrix<f64, 4, 3> = a.matmul(&rot);
+
+        let r = a.procrustes(&b, ProcrustesMode::Rotation).unwrap();
+        for j in 0..3 {
+            for i in 0..3 {
+                assert_relative_eq!(r[(i, j)], rot[(i, j)], epsilon = 1e-10);
+            }
         }
-    }
 
+        // A mirrored target is matched exactly only when reflections are allowed
+        let mirror =
+            SMatrix::<f64, 3, 3>::from_row_slice(&[1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, -1.0]);
+        let b: SMatrix<f64, 4, 3> = a.matmul(&mirror);
+        let r = a.procrustes(&b, ProcrustesMode::Orthogonal).unwrap();
+        assert_relative_eq!(r.determinant(), -1.0, epsilon = 1e-10);
+        assert_relative_eq!(r[(2, 2)], -1.0, epsilon = 1e-10);
+        let r = a.procrustes(&b, ProcrustesMode::Rotation).unwrap();
+        assert_relative_eq!(r.determinant(), 1.0, epsilon = 1e-10);
+    }
+    //}}}
+    //{{{ collection: norm tests
     #[test]
-    fn test_matmul_f64_row_vector() {
-        let a = DMatrix::<f64>::from_row_slice(&[1.0, 2.0, 3.0], 1, 3);
+    fn test_norms_elementwise() {
+        let a = SMatrix::<f64, 2, 2>::from_row_slice(&[1.0, -2.0, 3.0, 4.0]);
+        assert_relative_eq!(a.norm_fro(), 30.0f64.sqrt(), epsilon = 1e-12);
+        assert_relative_eq!(a.norm_one(), 6.0, epsilon = 1e-12);
+        assert_relative_eq!(a.norm_inf(), 7.0, epsilon = 1e-12);
+        assert_relative_eq!(a.norm_max(), 4.0, epsilon = 1e-12);
+    }
 
-        let b = DMatrix::<f64>::from_row_slice(&[4.0, 5.0, 6.0, 7.0, 8.0, 9.0], 3, 2);
+    #[test]
+    fn test_norm_2_and_cond() {
+        let a = SMatrix::<f64, 2, 2>::from_row_slice(&[1.0, -2.0, 3.0, 4.0]);
+        // Singular values squared are 15 ± 5√5.
+        let s_max = (15.0 + 5.0 * 5.0f64.sqrt()).sqrt();
+        let s_min = (15.0 - 5.0 * 5.0f64.sqrt()).sqrt();
+        assert_relative_eq!(a.norm_2().unwrap(), s_max, epsilon = 1e-10);
+        assert_relative_eq!(a.cond().unwrap(), s_max / s_min, epsilon = 1e-10);
+    }
 
-        let expected = DMatrix::<f64>::from_row_slice(&[40.0, 46.0], 1, 2);
+    #[test]
+    fn test_rcond() {
+        let a = SMatrix::<f64, 2, 2>::from_row_slice(&[1.0, -2.0, 3.0, 4.0]);
+        // ‖A‖₁ = 6 and ‖A⁻¹‖₁ = 0.7.
+        assert_relative_eq!(a.rcond().unwrap(), 1.0 / 4.2, epsilon = 1e-10);
+    }
 
-        let result = (&a).matmul(&b);
+    #[test]
+    fn test_lu_factor_rcond() {
+        let a = SMatrix::<f64, 2, 2>::from_row_slice(&[1.0, -2.0, 3.0, 4.0]);
+        let lu = a.lu_factor().unwrap();
+        assert_relative_eq!(lu.rcond().unwrap(), 1.0 / 4.2, epsilon = 1e-10);
+    }
 
-        for (res_val, exp_val) in result.iter().zip(expected.iter()) {
-            assert_eq!(res_val, exp_val);
+    #[test]
+    fn test_complex_norms() {
+        let a = SMatrix::<Complex<f64>, 2, 2>::from_row_slice(&[
+            Complex::new(3.0, 4.0),
+            Complex::new(0.0, 1.0),
+            Complex::new(0.0, 0.0),
+            Complex::new(1.0, 0.0),
+        ]);
+        let fro: f64 = a.norm_fro();
+        assert_relative_eq!(fro, 27.0f64.sqrt(), epsilon = 1e-12);
+        assert_relative_eq!(a.norm_one(), 5.0, epsilon = 1e-12);
+        assert_relative_eq!(a.norm_inf(), 6.0, epsilon = 1e-12);
+        assert_relative_eq!(a.norm_max(), 5.0, epsilon = 1e-12);
+    }
+    //}}}
+    //{{{ collection: complex tests
+    #[test]
+    fn test_complex_matmul() {
+        let i = Complex::new(0.0, 1.0);
+        let one = Complex::new(1.0, 0.0);
+        let zero = Complex::new(0.0, 0.0);
+        let a = SMatrix::<Complex<f64>, 2, 2>::from_row_slice(&[one + i, 2.0 * one, zero, i]);
+        let b = SMatrix::<Complex<f64>, 2, 2>::from_row_slice(&[i, zero, one, one - i]);
+
+        let c = a.matmul(&b);
+
+        let exp_c =
+            SMatrix::<Complex<f64>, 2, 2>::from_row_slice(&[one + i, 2.0 * (one - i), i, one + i]);
+        for k in 0..4 {
+            assert_relative_eq!(c[k].re, exp_c[k].re, epsilon = 1e-12);
+            assert_relative_eq!(c[k].im, exp_c[k].im, epsilon = 1e-12);
         }
     }
 
     #[test]
-    fn test_matmul_f32_row_vector() {
-        let a = DMatrix::<f32>::from_row_slice(&[1.0, 2.0], 1, 2);
-
-        let b = DMatrix::<f32>::from_row_slice(&[3.0, 4.0, 5.0, 6.0, 7.0, 8.0], 2, 3);
-
-        let expected = DMatrix::<f32>::from_row_slice(&[15.0, 18.0, 21.0], 1, 3);
+    fn test_complex_symeig_hermitian() {
+        let a = SMatrix::<Complex<f64>, 2, 2>::from_row_slice(&[
+            Complex::new(2.0, 0.0),
+            Complex::new(1.0, -1.0),
+            Complex::new(1.0, 1.0),
+            Complex::new(3.0, 0.0),
+        ]);
 
-        let result = (&a).matmul(&b);
+        let eigvals: [f64; 2] = a.symeig().unwrap().eigvals;
 
-        for (res_val, exp_val) in result.iter().zip(expected.iter()) {
-            assert_relative_eq!(res_val, exp_val, epsilon = 1e-10);
-        }
+        assert_relative_eq!(eigvals[0], 1.0, epsilon = 1e-10);
+        assert_relative_eq!(eigvals[1], 4.0, epsilon = 1e-10);
     }
+    //}}}
+}
 
-    #[test]
-    fn test_matmul_i32_row_vector() {
-        let a = DMatrix::<i32>::from_row_slice(&[2, 3], 1, 2);
+mod dmatrix_tests {
 
-        let b = DMatrix::<i32>::from_row_slice(&[1, 2, 3, 4], 2, 2);
+    use approx::assert_relative_eq;
 
-        let expected = DMatrix::<i32>::from_row_slice(&[11, 16], 1, 2);
+    use topohedral_linalg::*;
+
+    //{{{ collection: eig tests
+    #[test]
+    fn test_eig_simple() {
+        let a =
+            DMatrix::<f64>::from_row_slice(&[1.0, 5.0, 0.0, 2.0, 4.0, -1.0, 0.0, 2.0, 3.0], 3, 3);
+
+        let eig = a.eig().unwrap();
+        // Known eigenvalues for this matrix
+        let expected_eigenvalues = [
+            Complex::<f64>::new(-0.8595233886152194, 0.0),
+            Complex::<f64>::new(5.433664629783286, 0.0),
+            Complex::<f64>::new(3.42585875883193, 0.0),
+        ];
+
+        for (i, val) in expected_eigenvalues.iter().enumerate() {
+            assert_relative_eq!(eig.eigvals[i].re, val.re, epsilon = 1e-10);
+            assert_relative_eq!(eig.eigvals[i].im, val.im, epsilon = 1e-10);
+        }
+
+        // Known left eigenvectors for this matrix
+        let expected_left_eigenvecotors = DMatrix::<f64>::from_row_slice(
+            &[
+                -0.7212203345550064,
+                -0.3850687990747861,
+                -0.3073880480179293,
+                0.6705630402249634,
+                -0.8536329572455033,
+                -0.3728399943222721,
+                0.1737424476304467,
+                0.3507603088768719,
+                0.8755015285934659,
+            ],
+            3,
+            3,
+        );
+
+        #[cfg(system_backend)]
+        for i in 0..3 {
+            for j in 0..3 {
+                assert_relative_eq!(
+                    eig.left_eigvecs[(i, j)],
+                    expected_left_eigenvecotors[(i, j)],
+                    epsilon = 1e-10
+                );
+            }
+        }
+        // The reference backend may pick the opposite sign for an eigenvector
+        #[cfg(not(system_backend))]
+        for j in 0..3 {
+            let sign = (0..3)
+                .map(|i| eig.left_eigvecs[(i, j)] * expected_left_eigenvecotors[(i, j)])
+                .sum::<f64>()
+                .signum();
+            for i in 0..3 {
+                assert_relative_eq!(
+                    sign * eig.left_eigvecs[(i, j)],
+                    expected_left_eigenvecotors[(i, j)],
+                    epsilon = 1e-10
+                );
+            }
+        }
+    }
+    #[test]
+    fn test_eig_left_complex_pairs() {
+        let a =
+            DMatrix::<f64>::from_row_slice(&[0.0, -2.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 3.0], 3, 3);
+
+        let eig = a.eig_left().unwrap();
+        assert_eq!(eig.eigvals.iter().filter(|v| v.im != 0.0).count(), 2);
+
+        // Left eigenvectors satisfy A^T u = conj(λ) u for real A.
+        for k in 0..3 {
+            for i in 0..3 {
+                let mut atu = Complex::new(0.0, 0.0);
+                for j in 0..3 {
+                    atu += a[(j, i)] * eig.eigvecs[(j, k)];
+                }
+                let lu = eig.eigvals[k].conj() * eig.eigvecs[(i, k)];
+                assert_relative_eq!(atu.re, lu.re, epsilon = 1e-10);
+                assert_relative_eq!(atu.im, lu.im, epsilon = 1e-10);
+            }
+        }
+
+        let full = a.eig().unwrap();
+        let unpacked = full.complex_left_eigvecs();
+        for j in 0..3 {
+            let column = full.left_eigvec(j);
+            for i in 0..3 {
+                assert_relative_eq!(unpacked[(i, j)].re, eig.eigvecs[(i, j)].re, epsilon = 1e-10);
+                assert_relative_eq!(unpacked[(i, j)].im, eig.eigvecs[(i, j)].im, epsilon = 1e-10);
+                assert_relative_eq!(column[i].re, eig.eigvecs[(i, j)].re, epsilon = 1e-10);
+                assert_relative_eq!(column[i].im, eig.eigvecs[(i, j)].im, epsilon = 1e-10);
+            }
+        }
+    }
+    //}}}
+    //{{{ collection: lu tests
+    #[test]
+    fn test_lu_non_diagonal_dominant() {
+        let a = DMatrix::<f64>::from_row_slice(
+            &[
+                1.0, 2000.0, 3000.0, 5000.0, 10.0, -8900.0, -10000.0, 9008.0, 0.0,
+            ],
+            3,
+            3,
+        );
+
+        let lu_ret = a.lu().unwrap();
+
+        let exp_p =
+            DMatrix::<f64>::from_row_slice(&[0.0, 0.0, 1.0, 0.0, 1.0, 0.0, 1.0, 0.0, 0.0], 3, 3);
+
+        let exp_l = DMatrix::<f64>::from_row_slice(
+            &[
+                1.00000000e+00,
+                0.00000000e+00,
+                0.00000000e+00,
+                -5.00000000e-01,
+                1.00000000e+00,
+                0.00000000e+00,
+                -1.00000000e-04,
+                4.43265574e-01,
+                1.00000000e+00,
+            ],
+            3,
+            3,
+        );
+
+        let exp_u = DMatrix::<f64>::from_row_slice(
+            &[
+                -10000.0,
+                9008.0,
+                0.0,
+                0.0,
+                4514.0,
+                -8900.0,
+                0.0,
+                0.0,
+                6945.06360656,
+            ],
+            3,
+            3,
+        );
+
+        for i in 0..9 {
+            assert_relative_eq!(lu_ret.p[i], exp_p[i], max_relative = 1.0e-8);
+            assert_relative_eq!(lu_ret.l[i], exp_l[i], max_relative = 1.0e-8);
+            assert_relative_eq!(lu_ret.u[i], exp_u[i], max_relative = 1.0e-8);
+        }
+    }
+
+    #[test]
+    fn test_lu_diagonal_dominant() {
+        let a = DMatrix::<f64>::from_row_slice(
+            &[
+                100000.0, 10.0, 56.0, 10.0, -69.0, 1.56e6, 3.0, -9.0, 0.0, 0.0, -5.6e-5, -700.0,
+                890.0, 0.0, -7899.0, 8.0e5,
+            ],
+            4,
+            4,
+        );
+
+        let lu_ret = a.lu().unwrap();
+
+        let exp_p = [
+            1., 0., 0., 0., 0., 1., 0., 0., 0., 0., 0., 1., 0., 0., 1., 0.,
+        ];
+
+        let exp_l = DMatrix::<f64>::from_row_slice(
+            &[
+                1.00000000e+00,
+                0.00000000e+00,
+                0.00000000e+00,
+                0.00000000e+00,
+                -6.90000000e-04,
+                1.00000000e+00,
+                0.00000000e+00,
+                0.00000000e+00,
+                8.90000000e-03,
+                -5.70512818e-08,
+                1.00000000e+00,
+                0.00000000e+00,
+                0.00000000e+00,
+                0.00000000e+00,
+                7.08905771e-09,
+                1.00000000e+00,
+            ],
+            4,
+            4,
+        );
+
+        let exp_u = DMatrix::<f64>::from_row_slice(
+            &[
+                1.00000000e+05,
+                1.00000000e+01,
+                5.60000000e+01,
+                1.00000000e+01,
+                0.00000000e+00,
+                1.56000001e+06,
+                3.03864000e+00,
+                -8.99310000e+00,
+                0.00000000e+00,
+                0.00000000e+00,
+                -7.89949840e+03,
+                7.99999911e+05,
+                0.00000000e+00,
+                0.00000000e+00,
+                0.00000000e+00,
+                -7.00005671e+02,
+            ],
+            4,
+            4,
+        );
+
+        for i in 0..16 {
+            assert_relative_eq!(lu_ret.p[i], exp_p[i], max_relative = 1.0e-8);
+            assert_relative_eq!(lu_ret.l[i], exp_l[i], max_relative = 1.0e-8);
+            assert_relative_eq!(lu_ret.u[i], exp_u[i], max_relative = 1.0e-8);
+        }
+    }
+    //}}}
+    //{{{ collection: cholesky tests
+    #[test]
+    fn test_cholesky_spd() {
+        let a = DMatrix::<f64>::from_row_slice(
+            &[4.0, 12.0, -16.0, 12.0, 37.0, -43.0, -16.0, -43.0, 98.0],
+            3,
+            3,
+        );
+
+        let chol = a.cholesky().unwrap();
+        let expected_l =
+            DMatrix::<f64>::from_row_slice(&[2.0, 0.0, 0.0, 6.0, 1.0, 0.0, -8.0, 5.0, 3.0], 3, 3);
+        let reconstructed = (&chol.l).matmul(chol.l.transpose());
+
+        for i in 0..9 {
+            assert_relative_eq!(chol.l[i], expected_l[i], epsilon = 1e-10);
+            assert_relative_eq!(reconstructed[i], a[i], epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_cholesky_non_positive_definite_errors() {
+        let a = DMatrix::<f64>::from_row_slice(&[1.0, 2.0, 2.0, 1.0], 2, 2);
+
+        assert!(a.cholesky().is_err());
+    }
+    //}}}
+    //{{{ collection: matmul tests
+    #[test]
+    fn test_matmul_f64_general() {
+        let a = DMatrix::<f64>::from_row_slice(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 2, 3);
+
+        let b = DMatrix::<f64>::from_row_slice(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 3, 2);
+
+        let expected = DMatrix::<f64>::from_row_slice(&[22.0, 28.0, 49.0, 64.0], 2, 2);
 
         let result = (&a).matmul(&b);
 
         for (res_val, exp_val) in result.iter().zip(expected.iter()) {
-            assert_eq!(res_val, exp_val);
+            assert_relative_eq!(res_val, exp_val, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_matmul_f64_general_mut_lhs_ref_rhs() {
+        let mut a = DMatrix::<f64>::from_row_slice(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 2, 3);
+        let b = DMatrix::<f64>::from_row_slice(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 3, 2);
+        let expected = DMatrix::<f64>::from_row_slice(&[22.0, 28.0, 49.0, 64.0], 2, 2);
+
+        let result = (&mut a).matmul(&b);
+
+        for (res_val, exp_val) in result.iter().zip(expected.iter()) {
+            assert_relative_eq!(res_val, exp_val, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_matmul_smatrix_dmatrix_f64_general() {
+        let a = SMatrix::<f64, 2, 3>::from_row_slice(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+        let b = DMatrix::<f64>::from_row_slice(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 3, 2);
+
+        let expected = DMatrix::<f64>::from_row_slice(&[22.0, 28.0, 49.0, 64.0], 2, 2);
+
+        let result = (&a).matmul(&b);
+
+        for (res_val, exp_val) in result.iter().zip(expected.iter()) {
+            assert_relative_eq!(res_val, exp_val, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_matmul_dmatrix_smatrix_f64_general() {
+        let a = DMatrix::<f64>::from_row_slice(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 2, 3);
+
+        let b = SMatrix::<f64, 3, 2>::from_row_slice(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+        let expected = DMatrix::<f64>::from_row_slice(&[22.0, 28.0, 49.0, 64.0], 2, 2);
+
+        let result = (&a).matmul(&b);
+
+        for (res_val, exp_val) in result.iter().zip(expected.iter()) {
+            assert_relative_eq!(res_val, exp_val, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_matmul_dmatrix_smatrix_mutability_combinations() {
+        let mut a = DMatrix::<f64>::from_row_slice(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 2, 3);
+        let mut b = SMatrix::<f64, 3, 2>::from_row_slice(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let expected = DMatrix::<f64>::from_row_slice(&[22.0, 28.0, 49.0, 64.0], 2, 2);
+
+        let result_ref_ref = (&a).matmul(&b);
+        let result_mut_ref = (&mut a).matmul(&b);
+        let result_ref_mut = (&a).matmul(&mut b);
+        let result_mut_mut = (&mut a).matmul(&mut b);
+
+        for (res_val, exp_val) in result_ref_ref.iter().zip(expected.iter()) {
+            assert_relative_eq!(res_val, exp_val, epsilon = 1e-10);
+        }
+        for (res_val, exp_val) in result_mut_ref.iter().zip(expected.iter()) {
+            assert_relative_eq!(res_val, exp_val, epsilon = 1e-10);
+        }
+        for (res_val, exp_val) in result_ref_mut.iter().zip(expected.iter()) {
+            assert_relative_eq!(res_val, exp_val, epsilon = 1e-10);
+        }
+        for (res_val, exp_val) in result_mut_mut.iter().zip(expected.iter()) {
+            assert_relative_eq!(res_val, exp_val, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Matrix dimensions are incompatible for multiplication: 2x3 and 2x2")]
+    fn test_matmul_smatrix_dmatrix_dimension_mismatch_panics() {
+        let a = SMatrix::<f64, 2, 3>::from_row_slice(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+        let b = DMatrix::<f64>::from_row_slice(&[1.0, 2.0, 3.0, 4.0], 2, 2);
+
+        let _ = (&a).matmul(&b);
+    }
+
+    #[test]
+    fn test_matmul_dmatrix_scvector_col_vector() {
+        let a = DMatrix::<f64>::from_row_slice(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 2, 3);
+        let b = SCVector::<f64, 3>::from_col_slice(&[1.0, 2.0, 3.0]);
+        let expected = DMatrix::<f64>::from_row_slice(&[14.0, 32.0], 2, 1);
+
+        let result = (&a).matmul(&b);
+
+        for (res_val, exp_val) in result.iter().zip(expected.iter()) {
+            assert_relative_eq!(res_val, exp_val, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_matmul_smatrix_dvector_col_vector() {
+        let a = SMatrix::<f64, 2, 3>::from_row_slice(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let b = DVector::<f64>::from_slice_vec(&[1.0, 2.0, 3.0], 3, VecType::Col);
+        let expected = DMatrix::<f64>::from_row_slice(&[14.0, 32.0], 2, 1);
+
+        let result = (&a).matmul(&b);
+
+        for (res_val, exp_val) in result.iter().zip(expected.iter()) {
+            assert_relative_eq!(res_val, exp_val, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_matmul_srvector_dmatrix_row_vector() {
+        let a = SRVector::<f64, 3>::from_row_slice(&[1.0, 2.0, 3.0]);
+        let b = DMatrix::<f64>::from_row_slice(&[4.0, 5.0, 6.0, 7.0, 8.0, 9.0], 3, 2);
+        let expected = DMatrix::<f64>::from_row_slice(&[40.0, 46.0], 1, 2);
+
+        let result = (&a).matmul(&b);
+
+        for (res_val, exp_val) in result.iter().zip(expected.iter()) {
+            assert_relative_eq!(res_val, exp_val, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_matmul_dvector_smatrix_row_vector() {
+        let a = DVector::<f64>::from_slice_vec(&[1.0, 2.0, 3.0], 3, VecType::Row);
+        let b = SMatrix::<f64, 3, 2>::from_row_slice(&[4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+        let expected = DMatrix::<f64>::from_row_slice(&[40.0, 46.0], 1, 2);
+
+        let result = (&a).matmul(&b);
+
+        for (res_val, exp_val) in result.iter().zip(expected.iter()) {
+            assert_relative_eq!(res_val, exp_val, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+
+    fn test_matmul_f64_col_vector() {
+        let a = DMatrix::<f64>::from_row_slice(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 2, 3);
+
+        let b = DMatrix::<f64>::from_row_slice(&[1.0, 2.0, 3.0], 3, 1);
+
+        let expected = DMatrix::<f64>::from_row_slice(&[14.0, 32.0], 2, 1);
+
+        let result = (&a).matmul(&b);
+
+        for (res_val, exp_val) in result.iter().zip(expected.iter()) {
+            assert_relative_eq!(res_val, exp_val, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_matmul_f32_general() {
+        let a = DMatrix::<f32>::from_row_slice(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 2, 3);
+
+        let b = DMatrix::<f32>::from_row_slice(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 3, 2);
+
+        let expected = DMatrix::<f32>::from_row_slice(&[22.0, 28.0, 49.0, 64.0], 2, 2);
+
+        let result = (&a).matmul(&b);
+
+        for (res_val, exp_val) in result.iter().zip(expected.iter()) {
+            assert_relative_eq!(res_val, exp_val, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_matmul_f32_col_vector() {
+        let a = DMatrix::<f32>::from_row_slice(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 2, 3);
+
+        let b = DMatrix::<f32>::from_row_slice(&[1.0, 2.0, 3.0], 3, 1);
+
+        let expected = DMatrix::<f32>::from_row_slice(&[14.0, 32.0], 2, 1);
+
+        let result = (&a).matmul(&b);
+
+        for (res_val, exp_val) in result.iter().zip(expected.iter()) {
+            assert_relative_eq!(res_val, exp_val, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_matmul_i32() {
+        let a = DMatrix::<i32>::from_row_slice(&[1, 2, 3, 4], 2, 2);
+
+        let b = DMatrix::<i32>::from_row_slice(&[5, 6, 7, 8], 2, 2);
+
+        let expected = DMatrix::<i32>::from_row_slice(&[19, 22, 43, 50], 2, 2);
+
+        let result = (&a).matmul(&b);
+
+        for (res_val, exp_val) in result.iter().zip(expected.iter()) {
+            assert_eq!(res_val, exp_val);
+        }
+    }
+
+    #[test]
+    fn test_matmul_u64() {
+        let a = DMatrix::<i64>::from_row_slice(&[1, 2, 3, 4], 2, 2);
+
+        let b = DMatrix::<i64>::from_row_slice(&[5, 6], 2, 1);
+
+        let expected = DMatrix::<i64>::from_row_slice(&[17, 39], 2, 1);
+
+        let result = (&a).matmul(&b);
+
+        for (res_val, exp_val) in result.iter().zip(expected.iter()) {
+            assert_eq!(res_val, exp_val);
+        }
+    }
+
+    #[test]
+    fn test_matmul_f64_row_vector() {
+        let a = DMatrix::<f64>::from_row_slice(&[1.0, 2.0, 3.0], 1, 3);
+
+        let b = DMatrix::<f64>::from_row_slice(&[4.0, 5.0, 6.0, 7.0, 8.0, 9.0], 3, 2);
+
+        let expected = DMatrix::<f64>::from_row_slice(&[40.0, 46.0], 1, 2);
+
+        let result = (&a).matmul(&b);
+
+        for (res_val, exp_val) in result.iter().zip(expected.iter()) {
+            assert_eq!(res_val, exp_val);
+        }
+    }
+
+    #[test]
+    fn test_matmul_f32_row_vector() {
+        let a = DMatrix::<f32>::from_row_slice(&[1.0, 2.0], 1, 2);
+
+        let b = DMatrix::<f32>::from_row_slice(&[3.0, 4.0, 5.0, 6.0, 7.0, 8.0], 2, 3);
+
+        let expected = DMatrix::<f32>::from_row_slice(&[15.0, 18.0, 21.0], 1, 3);
+
+        let result = (&a).matmul(&b);
+
+        for (res_val, exp_val) in result.iter().zip(expected.iter()) {
+            assert_relative_eq!(res_val, exp_val, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_matmul_i32_row_vector() {
+        let a = DMatrix::<i32>::from_row_slice(&[2, 3], 1, 2);
+
+        let b = DMatrix::<i32>::from_row_slice(&[1, 2, 3, 4], 2, 2);
+
+        let expected = DMatrix::<i32>::from_row_slice(&[11, 16], 1, 2);
+
+        let result = (&a).matmul(&b);
+
+        for (res_val, exp_val) in result.iter().zip(expected.iter()) {
+            assert_eq!(res_val, exp_val);
+        }
+    }
+
+    #[test]
+    fn test_matmul_u64_row_vector() {
+        let a = DMatrix::<i64>::from_row_slice(&[1, 2, 3], 1, 3);
+
+        let b = DMatrix::<i64>::from_row_slice(&[4, 5, 6], 3, 1);
+
+        let expected = DMatrix::<i64>::from_row_slice(&[32], 1, 1);
+
+        let result = (&a).matmul(&b);
+
+        for (res_val, exp_val) in result.iter().zip(expected.iter()) {
+            assert_eq!(res_val, exp_val);
+        }
+    }
+    //}}}
+    //{{{ collectoin: qr
+    #[test]
+    fn test_qr_decomposition() {
+        let a = DMatrix::<f64>::from_row_slice(
+            &[12.0, -51.0, 4.0, 6.0, 167.0, -68.0, -4.0, 24.0, -41.0],
+            3,
+            3,
+        );
+
+        let DQrReturn { q, r } = a.qr().unwrap();
+
+        // Verify Q*R = A
+        let reconstructed: DMatrix<f64> = q.matmul(&r);
+
+        for i in 0..9 {
+            assert_relative_eq!(reconstructed[i], a[i], max_relative = 1.0e-8);
+        }
+
+        // Verify Q is orthogonal (Q^T * Q = I)
+        let q_transpose = q.transpose();
+        let identity: DMatrix<f64> = q.matmul(&q_transpose);
+
+        for i in 0..3 {
+            for j in 0..3 {
+                if i == j {
+                    assert_relative_eq!(identity[i + j * 3], 1.0, max_relative = 1.0e-8);
+                } else {
+                    assert_relative_eq!(identity[i + j * 3], 0.0, max_relative = 1.0e-8);
+                }
+            }
+        }
+    }
+    //}}}
+    //{{{ collection: schur
+    #[test]
+    fn test_schur_decomposition() {
+        let a =
+            DMatrix::<f64>::from_row_slice(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0], 3, 3);
+
+        let DSchurReturn { q, t } = a.schur().unwrap();
+
+        // Verify Q*T*Q^T = A
+        let q_t = q.matmul(&t);
+        let q_transpose = q.transpose();
+        let reconstructed = q_t.matmul(&q_transpose);
+
+        for i in 0..9 {
+            assert_relative_eq!(reconstructed[i], a[i], max_relative = 1.0e-8);
+        }
+
+        // Verify Q is orthogonal (Q^T * Q = I)
+        let identity = q.matmul(&q_transpose);
+
+        for i in 0..3 {
+            for j in 0..3 {
+                if i == j {
+                    assert_relative_eq!(
+                        identity[i + j * 3],
+                        1.0,
+                        max_relative = 1.0e-8,
+                        epsilon = 1.0e-10
+                    );
+                } else {
+                    assert_relative_eq!(
+                        identity[i + j * 3],
+                        0.0,
+                        max_relative = 1.0e-8,
+                        epsilon = 1.0e-10
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_schur_ordered_complex_pair() {
+        // Eigenvalues 3 and 1 ± 2i; select the complex pair by one of its members
+        let a =
+            DMatrix::<f64>::from_row_slice(&[3.0, 1.0, 0.0, 0.0, 1.0, -2.0, 0.0, 2.0, 1.0], 3, 3);
+
+        let DSchurOrderedReturn { q, t, sdim } = a.schur_ordered(|lambda| lambda.im > 0.0).unwrap();
+
+        assert_eq!(sdim, 2);
+        assert_relative_eq!(t[(2, 2)], 3.0, epsilon = 1e-10);
+        assert_relative_eq!(t[(2, 0)], 0.0, epsilon = 1e-10);
+        assert_relative_eq!(t[(2, 1)], 0.0, epsilon = 1e-10);
+        let reconstructed = q.matmul(&t).matmul(&q.transpose());
+        for i in 0..9 {
+            assert_relative_eq!(reconstructed[i], a[i], epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_hessenberg_complex() {
+        let i = Complex::new(0.0, 1.0);
+        let one = Complex::new(1.0, 0.0);
+        let a = DMatrix::<Complex<f64>>::from_row_slice(
+            &[
+                one,
+                i,
+                one * 2.0,
+                -i,
+                one * 3.0,
+                one + i,
+                one,
+                i * 2.0,
+                one * 4.0,
+            ],
+            3,
+            3,
+        );
+
+        let DHessenbergReturn { h, q } = a.hessenberg().unwrap();
+
+        assert_eq!(h[(2, 0)], Complex::new(0.0, 0.0));
+        // Verify Q*H*Q^H = A
+        let q_h = q.matmul(&h);
+        for r in 0..3 {
+            for c in 0..3 {
+                let mut sum = Complex::new(0.0, 0.0);
+                for k in 0..3 {
+                    sum += q_h[(r, k)] * q[(c, k)].conj();
+                }
+                assert_relative_eq!((sum - a[(r, c)]).norm(), 0.0, epsilon = 1e-10);
+            }
+        }
+    }
+    //}}}
+    //{{{ collection: solve
+    #[test]
+    fn test_solve() {
+        let a =
+            DMatrix::<f64>::from_row_slice(&[3.0, -1.0, 2.0, 1.0, 2.0, 0.0, 4.0, 0.0, 6.0], 3, 3);
+
+        let b = DMatrix::<f64>::from_row_slice(
+            &[7.0, -7.0, 2.0, 1.0, 2.0, 3.0, 22.0, -10.0, 3.0],
+            3,
+            3,
+        );
+
+        let x = a.solve(&b).unwrap();
+
+        // Verify A * X = B
+        let computed_b = a.matmul(&x);
+
+        for i in 0..9 {
+            assert_relative_eq!(computed_b[i], b[i], max_relative = 1.0e-8);
+        }
+    }
+
+    #[test]
+    fn test_solve_expert_equilibration() {
+        // Rows differ in scale by twelve orders of magnitude
+        let a = DMatrix::<f64>::from_row_slice(&[1.0e6, 2.0e6, 3.0e-6, -1.0e-6], 2, 2);
+        let b = DMatrix::<f64>::from_row_slice(&[5.0e6, 1.0e-6], 2, 1);
+
+        let report = a.solve_expert(&b, SolveExpertOptions::default()).unwrap();
+
+        assert_ne!(report.equilibration, Equilibration::None);
+        assert!(!report.singular);
+        assert_relative_eq!(report.x[(0, 0)], 1.0, max_relative = 1.0e-10);
+        assert_relative_eq!(report.x[(1, 0)], 2.0, max_relative = 1.0e-10);
+        assert_eq!(report.ferr.map(|ferr| ferr.len()), Some(1));
+        assert_eq!(report.berr.map(|berr| berr.len()), Some(1));
+    }
+
+    #[test]
+    fn test_solve_expert_unrefined() {
+        let a =
+            DMatrix::<f64>::from_row_slice(&[4.0, 1.0, 2.0, 1.0, 3.0, 0.0, 2.0, 1.0, 5.0], 3, 3);
+        let b = DMatrix::<f64>::from_row_slice(&[7.0, 1.0, 4.0, -2.0, 8.0, 3.0], 3, 2);
+        let refined = a.solve_expert(&b, SolveExpertOptions::default()).unwrap();
+        for transpose in [Transpose::NoTrans, Transpose::Trans] {
+            let options = SolveExpertOptions {
+                equilibrate: false,
+                transpose,
+                refine: false,
+            };
+            let report = a.solve_expert(&b, options).unwrap();
+
+            let op_a = match transpose {
+                Transpose::NoTrans => a.clone(),
+                _ => a.transpose(),
+            };
+            let computed_b = op_a.matmul(&report.x);
+            for i in 0..6 {
+                assert_relative_eq!(computed_b[i], b[i], max_relative = 1.0e-12);
+            }
+            assert!(report.ferr.is_none());
+            assert!(report.berr.is_none());
+            assert_eq!(report.equilibration, Equilibration::None);
+            assert!(!report.singular);
+            // GESVX factors A itself whatever the transpose, so the growth factor is shared
+            assert_relative_eq!(report.rpvgrw, refined.rpvgrw, max_relative = 1.0e-12);
+            if transpose == Transpose::NoTrans {
+                assert_relative_eq!(report.rcond, refined.rcond, max_relative = 1.0e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_solve_expert_singular() {
+        let a =
+            DMatrix::<f64>::from_row_slice(&[1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 1.0, 0.0, 1.0], 3, 3);
+        let b = DMatrix::<f64>::from_row_slice(&[1.0, 2.0, 3.0], 3, 1);
+        let options = SolveExpertOptions {
+            equilibrate: false,
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            a.solve_expert(&b, options),
+            Err(DSolveExpertError::Singular)
+        ));
+        let unrefined = SolveExpertOptions {
+            refine: false,
+            ..options
+        };
+        assert!(matches!(
+            a.solve_expert(&b, unrefined),
+            Err(DSolveExpertError::Singular)
+        ));
+
+        // Singular to working precision: a solution and the flag are returned
+        let eps = f64::EPSILON;
+        let near = DMatrix::<f64>::from_row_slice(&[1.0, 1.0, 1.0, 1.0 + eps], 2, 2);
+        let b = DMatrix::<f64>::from_row_slice(&[2.0, 2.0], 2, 1);
+        let report = near.solve_expert(&b, options).unwrap();
+        assert!(report.singular);
+        assert!(report.rcond < eps);
+        let report = near.solve_expert(&b, unrefined).unwrap();
+        assert!(report.singular);
+        assert!(report.rcond < eps);
+    }
+
+    #[test]
+    fn test_triangular_view_matmul() {
+        // The lower triangle and the diagonal hold unrelated data that must be ignored.
+        let a =
+            DMatrix::<f64>::from_row_slice(&[7.0, 2.0, -1.0, 9.0, 7.0, 3.0, 9.0, 9.0, 7.0], 3, 3);
+        let u =
+            DMatrix::<f64>::from_row_slice(&[1.0, 2.0, -1.0, 0.0, 1.0, 3.0, 0.0, 0.0, 1.0], 3, 3);
+        let b =
+            DMatrix::<f64>::from_row_slice(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0], 3, 3);
+        let view = Triangular::upper(&a).unit_diagonal();
+
+        let left = view.matmul(&b);
+        let expected = u.matmul(&b);
+        for i in 0..9 {
+            assert_relative_eq!(left[i], expected[i], max_relative = 1.0e-12);
+        }
+
+        let right = (&b).matmul(view);
+        let expected = b.matmul(&u);
+        for i in 0..9 {
+            assert_relative_eq!(right[i], expected[i], max_relative = 1.0e-12);
+        }
+
+        let x = a
+            .solve_upper_triangular(&left, Transpose::NoTrans, Diagonal::Unit)
+            .unwrap();
+        for i in 0..9 {
+            assert_relative_eq!(x[i], b[i], max_relative = 1.0e-12);
+        }
+
+        let singular = DMatrix::<f64>::from_row_slice(&[1.0, 0.0, 2.0, 0.0], 2, 2);
+        let rhs = DMatrix::<f64>::from_row_slice(&[1.0, 1.0], 2, 1);
+        assert!(matches!(
+            singular.solve_lower_triangular(&rhs, Transpose::NoTrans, Diagonal::NonUnit),
+            Err(DTriangularSolveError::Singular)
+        ));
+    }
+    //}}}
+    //{{{ collection: symeig tests
+    #[test]
+    fn test_symeig_simple() {
+        let a =
+            DMatrix::<f64>::from_col_slice(&[1.0, 2.0, 3.0, 2.0, 4.0, 5.0, 3.0, 5.0, 6.0], 3, 3);
+
+        let eig = a.symeig().unwrap();
+        let expected_eigenvalues = [
+            -5.15729471589257e-01,
+            1.70915188827179e-01,
+            1.13448142827621e+01,
+        ];
+
+        for (val, exp) in eig.eigvals.iter().zip(expected_eigenvalues.iter()) {
+            assert_relative_eq!(val, exp, epsilon = 1e-10);
+        }
+
+        let expected_eigenvectors = DMatrix::<f64>::from_row_slice(
+            &[
+                -7.36976229099578e-01,
+                5.91009048506104e-01,
+                -3.27985277605682e-01,
+                -3.27985277605682e-01,
+                -7.36976229099578e-01,
+                -5.91009048506103e-01,
+                5.91009048506104e-01,
+                3.27985277605681e-01,
+                -7.36976229099578e-01,
+            ],
+            3,
+            3,
+        );
+
+        #[cfg(system_backend)]
+        for (val, exp) in eig.eigvecs.iter().zip(expected_eigenvectors.iter()) {
+            assert_relative_eq!(val, exp, epsilon = 1e-10);
+        }
+        // The reference backend may pick the opposite sign for an eigenvector
+        #[cfg(not(system_backend))]
+        for j in 0..3 {
+            let sign = (0..3)
+                .map(|i| eig.eigvecs[(i, j)] * expected_eigenvectors[(i, j)])
+                .sum::<f64>()
+                .signum();
+            for i in 0..3 {
+                assert_relative_eq!(
+                    sign * eig.eigvecs[(i, j)],
+                    expected_eigenvectors[(i, j)],
+                    epsilon = 1e-10
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_symeig_range_value() {
+        let a = DMatrix::<f64>::from_row_slice(
+            &[2.0, -1.0, 0.0, -1.0, 2.0, -1.0, 0.0, -1.0, 2.0],
+            3,
+            3,
+        );
+
+        // Only the middle eigenvalue, 2, lies in (1, 3].
+        let DSymEigReturn { eigvecs, eigvals } =
+            a.symeig_range(SymEigRange::Value(1.0, 3.0), false).unwrap();
+        assert_eq!(eigvals.len(), 1);
+        assert_relative_eq!(eigvals[0], 2.0, epsilon = 1e-10);
+        assert_eq!(eigvecs.ncols(), 0);
+    }
+    //}}}
+    //{{{ collection: generalized eig tests
+    #[test]
+    fn test_generalized_eig_infinite() {
+        // B is singular, so one eigenvalue is infinite; the finite one solves -2 - 4λ = 0.
+        let a = DMatrix::<f64>::from_row_slice(&[1.0, 2.0, 3.0, 4.0], 2, 2);
+        let b = DMatrix::<f64>::from_row_slice(&[1.0, 0.0, 0.0, 0.0], 2, 2);
+
+        let res = a.generalized_eig(&b).unwrap();
+        let finite: Vec<_> = (0..2).filter(|&k| res.beta[k].norm() > 1e-12).collect();
+        assert_eq!(finite.len(), 1);
+        let lambda = res.eigvals()[finite[0]];
+        assert_relative_eq!(lambda.re, -0.5, epsilon = 1e-10);
+        assert_relative_eq!(lambda.im, 0.0, epsilon = 1e-10);
+
+        // Verify β A x = α B x for every column.
+        for k in 0..2 {
+            for i in 0..2 {
+                let mut ax = Complex::new(0.0, 0.0);
+                let mut bx = Complex::new(0.0, 0.0);
+                for j in 0..2 {
+                    ax += a[(i, j)] * res.eigvecs[(j, k)];
+                    bx += b[(i, j)] * res.eigvecs[(j, k)];
+                }
+                let diff = res.beta[k] * ax - res.alpha[k] * bx;
+                assert_relative_eq!(diff.norm(), 0.0, epsilon = 1e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn test_generalized_symeig_not_positive_definite() {
+        let a = DMatrix::<f64>::from_row_slice(&[4.0, 1.0, 1.0, 3.0], 2, 2);
+        let b = DMatrix::<f64>::from_row_slice(&[1.0, 2.0, 2.0, 1.0], 2, 2);
+
+        let res = a.generalized_symeig(&b);
+        assert!(matches!(
+            res,
+            Err(DGeneralizedSymEigError::NotPositiveDefinite)
+        ));
+    }
+    //}}}
+    //{{{ collection: svd tests
+    #[test]
+    fn test_svd_reconstruction() {
+        let a = DMatrix::<f64>::from_row_slice(&[3.0, 2.0, 2.0, 2.0, 3.0, -2.0], 2, 3);
+
+        let DSvdReturn { u, s, vt } = a.svd().unwrap();
+
+        assert_eq!(u.size(), (2, 2));
+        assert_eq!(vt.size(), (3, 3));
+        assert_relative_eq!(s[0], 5.0, epsilon = 1e-10);
+        assert_relative_eq!(s[1], 3.0, epsilon = 1e-10);
+
+        // Verify U * Σ * Vᵀ = A
+        let mut sigma = DMatrix::<f64>::zeros(2, 3);
+        for (i, &value) in s.iter().enumerate() {
+            sigma[(i, i)] = value;
+        }
+        let reconstructed = u.matmul(&sigma).matmul(&vt);
+
+        for i in 0..6 {
+            assert_relative_eq!(reconstructed[i], a[i], epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_svd_thin() {
+        let a = DMatrix::<f64>::from_row_slice(
+            &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 10.0, 1.0, 0.0, 1.0],
+            4,
+            3,
+        );
+
+        let DSvdReturn { u, s, vt } = a.svd_thin().unwrap();
+
+        assert_eq!(u.size(), (4, 3));
+        assert_eq!(s.len(), 3);
+        assert_eq!(vt.size(), (3, 3));
+        assert!(s[0] >= s[1] && s[1] >= s[2]);
+
+        // Verify U * Σ * Vᵀ = A using the thin factors
+        let mut u_sigma = u.clone();
+        for j in 0..3 {
+            for i in 0..4 {
+                u_sigma[(i, j)] *= s[j];
+            }
+        }
+        let reconstructed = u_sigma.matmul(&vt);
+
+        for i in 0..12 {
+            assert_relative_eq!(reconstructed[i], a[i], epsilon = 1e-10);
+        }
+
+        // Verify the columns of U are orthonormal
+        let identity = u.transpose().matmul(&u);
+        for i in 0..3 {
+            for j in 0..3 {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert_relative_eq!(identity[(i, j)], expected, epsilon = 1e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn test_singular_values() {
+        let a = DMatrix::<f64>::from_row_slice(&[3.0, 2.0, 2.0, 3.0, 2.0, -2.0], 3, 2);
+
+        let s = a.singular_values().unwrap();
+
+        assert_eq!(s.len(), 2);
+        assert_relative_eq!(s[0], 5.0, epsilon = 1e-10);
+        assert_relative_eq!(s[1], 3.0, epsilon = 1e-10);
+    }
+    //}}}
+    //{{{ collection: inverse tests
+    #[test]
+    fn test_inverse() {
+        let a =
+            DMatrix::<f64>::from_row_slice(&[2.0, 0.0, 1.0, 1.0, 3.0, 2.0, 1.0, 1.0, 2.0], 3, 3);
+
+        let identity = a.matmul(&a.inverse().unwrap());
+
+        for i in 0..3 {
+            for j in 0..3 {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert_relative_eq!(identity[(i, j)], expected, epsilon = 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_inverse_singular_errors() {
+        let a =
+            DMatrix::<f64>::from_row_slice(&[1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 1.0, 0.0, 1.0], 3, 3);
+
+        assert!(matches!(a.inverse(), Err(DInverseError::Singular)));
+    }
+
+    #[test]
+    fn test_pinv_rectangular() {
+        let a = DMatrix::<f64>::from_row_slice(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 2, 3);
+
+        let a_pinv = a.pinv(1e-12).unwrap();
+
+        assert_eq!(a_pinv.nrows(), 3);
+        assert_eq!(a_pinv.ncols(), 2);
+
+        // Verify A * A⁺ * A = A
+        let reconstructed = a.matmul(&a_pinv).matmul(&a);
+        for i in 0..6 {
+            assert_relative_eq!(reconstructed[i], a[i], epsilon = 1e-10);
         }
     }
 
     #[test]
-    fn test_matmul_u64_row_vector() {
-        let a = DMatrix::<i64>::from_row_slice(&[1, 2, 3], 1, 3);
+    fn test_pinv_rank_deficient() {
+        let a = DMatrix::<f64>::from_row_slice(&[1.0, 2.0, 2.0, 4.0, 3.0, 6.0], 3, 2);
 
-        let b = DMatrix::<i64>::from_row_slice(&[4, 5, 6], 3, 1);
+        let a_pinv = a.pinv(1e-12).unwrap();
 
-        let expected = DMatrix::<i64>::from_row_slice(&[32], 1, 1);
+        // Verify A⁺ * A * A⁺ = A⁺
+        let reconstructed = a_pinv.matmul(&a).matmul(&a_pinv);
+        for i in 0..6 {
+            assert_relative_eq!(reconstructed[i], a_pinv[i], epsilon = 1e-10);
+        }
+    }
+    //}}}
+    //{{{ collection: matrix function tests
+    #[test]
+    fn test_expm_logm_roundtrip() {
+        let a = DMatrix::<f64>::from_row_slice(
+            &[
+                0.5, 1.0, 0.0, 0.3, -0.2, 0.4, 1.0, 0.0, 0.1, 0.0, 0.6, 0.2, 0.0, 0.3, -0.5, 0.7,
+            ],
+            4,
+            4,
+        );
 
-        let result = (&a).matmul(&b);
+        let log = a.expm().unwrap().logm().unwrap();
 
-        for (res_val, exp_val) in result.iter().zip(expected.iter()) {
-            assert_eq!(res_val, exp_val);
+        for i in 0..16 {
+            assert_relative_eq!(log[i], a[i], epsilon = 1e-10);
         }
     }
-    //}}}
-    //{{{ collectoin: qr
+
     #[test]
-    fn test_qr_decomposition() {
+    fn test_expm_scaling_and_squaring() {
+        let a = DMatrix::<f64>::from_row_slice(&[10.0, 1.0, 0.0, 10.0], 2, 2);
+
+        let e = a.expm().unwrap();
+
+        // exp([[λ, 1], [0, λ]]) = e^λ [[1, 1], [0, 1]]
+        let scale = 10.0_f64.exp();
+        assert_relative_eq!(e[(0, 0)], scale, max_relative = 1e-12);
+        assert_relative_eq!(e[(0, 1)], scale, max_relative = 1e-12);
+        assert_relative_eq!(e[(1, 0)], 0.0, epsilon = 1e-12);
+        assert_relative_eq!(e[(1, 1)], scale, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn test_sqrtm_complex_eigenvalues() {
+        // Rotation by 1.2 rad; its principal square root is the rotation by 0.6 rad.
         let a = DMatrix::<f64>::from_row_slice(
-            &[12.0, -51.0, 4.0, 6.0, 167.0, -68.0, -4.0, 24.0, -41.0],
-            3,
-            3,
+            &[1.2_f64.cos(), -1.2_f64.sin(), 1.2_f64.sin(), 1.2_f64.cos()],
+            2,
+            2,
         );
 
-        let DQrReturn { q, r } = a.qr().unwrap();
+        let r = a.sqrtm().unwrap();
 
-        // Verify Q*R = A
-        let reconstructed: DMatrix<f64> = q.matmul(&r);
+        assert_relative_eq!(r[(0, 0)], 0.6_f64.cos(), epsilon = 1e-12);
+        assert_relative_eq!(r[(0, 1)], -(0.6_f64.sin()), epsilon = 1e-12);
+        assert_relative_eq!(r[(1, 0)], 0.6_f64.sin(), epsilon = 1e-12);
+        assert_relative_eq!(r[(1, 1)], 0.6_f64.cos(), epsilon = 1e-12);
+    }
 
-        for i in 0..9 {
-            assert_relative_eq!(reconstructed[i], a[i], max_relative = 1.0e-8);
-        }
+    #[test]
+    fn test_powm_singular_errors() {
+        let a = DMatrix::<f64>::from_row_slice(&[1.0, 2.0, 2.0, 4.0], 2, 2);
 
-        // Verify Q is orthogonal (Q^T * Q = I)
-        let q_transpose = q.transpose();
-        let identity: DMatrix<f64> = q.matmul(&q_transpose);
+        assert!(matches!(a.powm(-2), Err(DMatrixFunctionError::Singular)));
+    }
+
+    #[test]
+    fn test_logm_negative_eigenvalue_errors() {
+        let a = DMatrix::<f64>::from_row_slice(&[-2.0, 0.0, 1.0, 3.0], 2, 2);
+
+        assert!(matches!(
+            a.logm(),
+            Err(DMatrixFunctionError::NoRealLogarithm)
+        ));
+    }
+    //}}}
+    //{{{ collection: lstsq tests
+    #[test]
+    fn test_lstsq_line_fit() {
+        // Fit y = c0 + c1 * t through (0, 1), (1, 3), (2, 4), (3, 7)
+        let a = DMatrix::<f64>::from_row_slice(&[1.0, 0.0, 1.0, 1.0, 1.0, 2.0, 1.0, 3.0], 4, 2);
+        let b = DMatrix::<f64>::from_row_slice(&[1.0, 3.0, 4.0, 7.0], 4, 1);
+
+        let DLstsqReturn {
+            x,
+            residuals,
+            rank,
+            s,
+        } = a.lstsq(&b).unwrap();
+
+        assert_eq!(x.nrows(), 2);
+        assert_eq!(x.ncols(), 1);
+        assert_relative_eq!(x[0], 0.9, epsilon = 1e-10);
+        assert_relative_eq!(x[1], 1.9, epsilon = 1e-10);
+        assert_eq!(rank, 2);
+        assert_eq!(residuals.len(), 1);
+        assert_relative_eq!(residuals[0], 0.7, epsilon = 1e-10);
+        assert!(s.is_empty());
+    }
+
+    #[test]
+    fn test_lstsq_underdetermined() {
+        let a = DMatrix::<f64>::from_row_slice(&[1.0, 1.0, 1.0], 1, 3);
+        let b = DMatrix::<f64>::from_row_slice(&[3.0], 1, 1);
+
+        let DLstsqReturn { x, residuals, .. } = a.lstsq(&b).unwrap();
 
+        // Minimum-norm solution of x0 + x1 + x2 = 3
+        assert_eq!(x.nrows(), 3);
         for i in 0..3 {
-            for j in 0..3 {
-                if i == j {
-                    assert_relative_eq!(identity[i + j * 3], 1.0, max_relative = 1.0e-8);
-                } else {
-                    assert_relative_eq!(identity[i + j * 3], 0.0, max_relative = 1.0e-8);
-                }
-            }
+            assert_relative_eq!(x[i], 1.0, epsilon = 1e-10);
         }
+        assert!(residuals.is_empty());
+    }
+
+    #[test]
+    fn test_lstsq_svd_rank_deficient() {
+        let a = DMatrix::<f64>::from_row_slice(&[1.0, 2.0, 2.0, 4.0, 3.0, 6.0], 3, 2);
+        let b = DMatrix::<f64>::from_row_slice(&[1.0, 2.0, 3.0], 3, 1);
+
+        let DLstsqReturn {
+            x,
+            residuals,
+            rank,
+            s,
+        } = a.lstsq_svd(&b, 1e-12).unwrap();
+
+        // Minimum-norm solution of x0 + 2 x1 = 1
+        assert_eq!(rank, 1);
+        assert_relative_eq!(x[0], 0.2, epsilon = 1e-10);
+        assert_relative_eq!(x[1], 0.4, epsilon = 1e-10);
+        assert!(residuals.is_empty());
+        assert_eq!(s.len(), 2);
+        assert_relative_eq!(s[0], 70.0_f64.sqrt(), epsilon = 1e-10);
     }
     //}}}
-    //{{{ collection: schur
+    //{{{ collection: factor tests
     #[test]
-    fn test_schur_decomposition() {
+    fn test_lu_factor_solve_in_place() {
         let a =
-            DMatrix::<f64>::from_row_slice(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0], 3, 3);
+            DMatrix::<f64>::from_row_slice(&[0.0, 2.0, 1.0, 1.0, 1.0, 0.0, 3.0, 0.0, 1.0], 3, 3);
+        let lu = a.lu_factor().unwrap();
+        assert_relative_eq!(lu.det(), -5.0, epsilon = 1e-10);
 
-        let DSchurReturn { q, t } = a.schur().unwrap();
+        let x_true = DMatrix::<f64>::from_col_slice(&[1.0, -1.0, 2.0, 0.5, 0.0, 1.0], 3, 2);
+        let mut b = a.matmul(&x_true);
+        lu.solve_in_place(&mut b).unwrap();
+        for i in 0..3 {
+            for j in 0..2 {
+                assert_relative_eq!(b[(i, j)], x_true[(i, j)], epsilon = 1e-10);
+            }
+        }
 
-        // Verify Q*T*Q^T = A
-        let q_t = q.matmul(&t);
-        let q_transpose = q.transpose();
-        let reconstructed = q_t.matmul(&q_transpose);
+        let inv = lu.inverse().unwrap();
+        let expected = a.inverse().unwrap();
+        for i in 0..3 {
+            for j in 0..3 {
+                assert_relative_eq!(inv[(i, j)], expected[(i, j)], epsilon = 1e-10);
+            }
+        }
+    }
 
-        for i in 0..9 {
-            assert_relative_eq!(reconstructed[i], a[i], max_relative = 1.0e-8);
+    #[test]
+    fn test_lu_factor_singular() {
+        let a = DMatrix::<f64>::from_row_slice(&[1.0, 2.0, 2.0, 4.0], 2, 2);
+        assert!(matches!(a.lu_factor(), Err(DLuFactorError::Singular)));
+    }
+
+    #[test]
+    fn test_cholesky_factor_not_positive_definite() {
+        let a = DMatrix::<f64>::from_row_slice(&[1.0, 2.0, 2.0, 1.0], 2, 2);
+        assert!(matches!(
+            a.cholesky_factor(),
+            Err(DCholeskyFactorError::NotPositiveDefinite)
+        ));
+    }
+
+    #[test]
+    fn test_slogdet_overflow_and_underflow() {
+        // Lower triangular with two rows swapped: det = -1000^n overflows f64
+        let n = 300;
+        let mut a = DMatrix::<f64>::zeros(n, n);
+        for j in 0..n {
+            a[(j, j)] = 1000.0;
+            for i in j + 1..n {
+                a[(i, j)] = 1.0;
+            }
+        }
+        for j in 0..n {
+            let tmp = a[(0, j)];
+            a[(0, j)] = a[(1, j)];
+            a[(1, j)] = tmp;
         }
+        let (sign, logdet) = a.slogdet().unwrap();
+        assert_relative_eq!(sign, -1.0);
+        assert_relative_eq!(logdet, n as f64 * 1000.0_f64.ln(), epsilon = 1e-9);
+
+        // Scaled identity: det = 0.002^n underflows f64, Cholesky path
+        let mut b = DMatrix::<f64>::zeros(n, n);
+        for i in 0..n {
+            b[(i, i)] = 0.002;
+        }
+        let (sign, logdet) = b.slogdet().unwrap();
+        assert_relative_eq!(sign, 1.0);
+        assert_relative_eq!(logdet, n as f64 * 0.002_f64.ln(), epsilon = 1e-9);
+    }
 
-        // Verify Q is orthogonal (Q^T * Q = I)
-        let identity = q.matmul(&q_transpose);
+    #[test]
+    fn test_slogdet_singular() {
+        let a = DMatrix::<f64>::from_row_slice(&[1.0, 2.0, 2.0, 4.0], 2, 2);
+        let (sign, logdet) = a.slogdet().unwrap();
+        assert_eq!(sign, 0.0);
+        assert!(logdet.is_infinite() && logdet < 0.0);
+    }
+
+    #[test]
+    fn test_slogdet_complex_diagonal_not_hermitian() {
+        // Symmetric in its off-diagonal part but not Hermitian: a00 is not real
+        let a = DMatrix::<Complex<f64>>::from_row_slice(
+            &[
+                Complex::new(2.0, 1.0),
+                Complex::new(0.0, 0.0),
+                Complex::new(0.0, 0.0),
+                Complex::new(3.0, 0.0),
+            ],
+            2,
+            2,
+        );
+        let (sign, logdet) = a.slogdet().unwrap();
+
+        // det = 6 + 3i
+        let modulus = 45.0f64.sqrt();
+        assert_relative_eq!(logdet, modulus.ln(), epsilon = 1e-12);
+        assert_relative_eq!(sign.re, 6.0 / modulus, epsilon = 1e-12);
+        assert_relative_eq!(sign.im, 3.0 / modulus, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_qr_factor_det_and_inverse() {
+        let a =
+            DMatrix::<f64>::from_row_slice(&[2.0, 0.0, 1.0, 1.0, 3.0, 2.0, 1.0, 1.0, 2.0], 3, 3);
+        let qr = a.qr_factor().unwrap();
+        assert_relative_eq!(qr.det(), 6.0, epsilon = 1e-10);
 
+        let inv = qr.inverse().unwrap();
+        let eye = a.matmul(&inv);
         for i in 0..3 {
             for j in 0..3 {
-                if i == j {
-                    assert_relative_eq!(
-                        identity[i + j * 3],
-                        1.0,
-                        max_relative = 1.0e-8,
-                        epsilon = 1.0e-10
-                    );
-                } else {
-                    assert_relative_eq!(
-                        identity[i + j * 3],
-                        0.0,
-                        max_relative = 1.0e-8,
-                        epsilon = 1.0e-10
-                    );
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert_relative_eq!(eye[(i, j)], expected, epsilon = 1e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn test_complex_lu_factor_solve() {
+        let i = Complex::new(0.0, 1.0);
+        let one = Complex::new(1.0, 0.0);
+        let a = DMatrix::<Complex<f64>>::from_row_slice(&[2.0 * one, i, -i, 3.0 * one], 2, 2);
+        let x_true = DMatrix::<Complex<f64>>::from_col_slice(&[one + i, 2.0 * one], 2, 1);
+        let b = a.matmul(&x_true);
+
+        let x = a.lu_factor().unwrap().solve(&b).unwrap();
+        for k in 0..2 {
+            assert_relative_eq!(x[k].re, x_true[k].re, epsilon = 1e-10);
+            assert_relative_eq!(x[k].im, x_true[k].im, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_ldlt_singular() {
+        let a = DMatrix::<f64>::from_row_slice(&[1.0, 1.0, 1.0, 1.0], 2, 2);
+        let ldlt = a.ldlt().unwrap();
+
+        assert_eq!(ldlt.inertia(), (1, 0, 1));
+        assert_relative_eq!(ldlt.det(), 0.0, epsilon = 1e-12);
+
+        let b = DMatrix::<f64>::from_col_slice(&[1.0, 2.0], 2, 1);
+        assert!(matches!(ldlt.solve(&b), Err(DLdltError::Singular)));
+    }
+    //}}}
+    //{{{ collection: qr_pivoted tests
+    #[test]
+    fn test_qr_pivoted_wide() {
+        let a = DMatrix::<f64>::from_row_slice(&[1.0, 0.0, 3.0, 1.0, 0.0, 1.0, 4.0, 1.0], 2, 4);
+
+        let DQrPivotedReturn { q, r, perm, rank } = a.qr_pivoted(1e-10).unwrap();
+        assert_eq!(rank, 2);
+        assert_eq!((q.nrows(), q.ncols()), (2, 2));
+        assert_eq!((r.nrows(), r.ncols()), (2, 4));
+
+        // The column of largest norm is chosen first.
+        assert_eq!(perm[0], 2);
+        assert_relative_eq!(r[(0, 0)].abs(), 5.0, epsilon = 1e-10);
+
+        // Verify Q*R = A*P
+        let qr: DMatrix<f64> = q.matmul(&r);
+        for (j, &pj) in perm.iter().enumerate() {
+            for i in 0..2 {
+                assert_relative_eq!(qr[(i, j)], a[(i, pj)], epsilon = 1e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn test_qr_pivoted_no_rows() {
+        let a = DMatrix::<f64>::zeros(0, 3);
+
+        let DQrPivotedReturn { q, r, perm, rank } = a.qr_pivoted(1e-10).unwrap();
+        assert_eq!(rank, 0);
+        assert_eq!((q.nrows(), q.ncols()), (0, 0));
+        assert_eq!((r.nrows(), r.ncols()), (0, 3));
+        assert_eq!(perm.len(), 3);
+    }
+    //}}}
+    //{{{ collection: rank tests
+    #[test]
+    fn test_null_space_wide() {
+        let a = DMatrix::<f64>::from_row_slice(&[1.0, 0.0, 3.0, 1.0, 0.0, 1.0, 4.0, 1.0], 2, 4);
+        assert_eq!(a.rank(RankTolerance::default()).unwrap(), 2);
+
+        let null = a.null_space(RankTolerance::default()).unwrap();
+        assert_eq!((null.nrows(), null.ncols()), (4, 2));
+        let an: DMatrix<f64> = a.matmul(&null);
+        let ntn: DMatrix<f64> = null.transpose().matmul(&null);
+        for j in 0..2 {
+            for i in 0..2 {
+                assert_relative_eq!(an[(i, j)], 0.0, epsilon = 1e-10);
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert_relative_eq!(ntn[(i, j)], expected, epsilon = 1e-10);
+            }
+        }
+
+        let range = a.orth(RankTolerance::default()).unwrap();
+        assert_eq!((range.nrows(), range.ncols()), (2, 2));
+    }
+
+    #[test]
+    fn test_null_space_full_rank() {
+        let a = DMatrix::<f64>::from_row_slice(&[2.0, 1.0, 1.0, 3.0], 2, 2);
+        let null = a.null_space(RankTolerance::Relative(1e-12)).unwrap();
+        assert_eq!((null.nrows(), null.ncols()), (2, 0));
+        assert_eq!(a.orth(RankTolerance::Relative(1e-12)).unwrap().ncols(), 2);
+    }
+    //}}}
+    //{{{ collection: polar tests
+    #[test]
+    fn test_polar_singular() {
+        let a = DMatrix::<f64>::from_row_slice(&[1.0, 1.0, 1.0, 1.0], 2, 2);
+        let DPolarReturn { u, p } = a.polar().unwrap();
+
+        let up: DMatrix<f64> = u.matmul(&p);
+        for j in 0..2 {
+            for i in 0..2 {
+                assert_relative_eq!(up[(i, j)], a[(i, j)], epsilon = 1e-10);
+                // P = (AᵀA)^½ = A for this symmetric positive semidefinite A
+                assert_relative_eq!(p[(i, j)], a[(i, j)], epsilon = 1e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn test_procrustes_2d() {
+        let a = DMatrix::<f64>::from_row_slice(&[1.0, 0.0, 0.0, 1.0, -1.0, 2.0], 3, 2);
+        let rot = DMatrix::<f64>::from_row_slice(&[0.0, -1.0, 1.0, 0.0], 2, 2);
+        let b: DMatrix<f64> = a.matmul(&rot);
+
+        for mode in [ProcrustesMode::Orthogonal, ProcrustesMode::Rotation] {
+            let r = a.procrustes(&b, mode).unwrap();
+            assert_eq!((r.nrows(), r.ncols()), (2, 2));
+            for j in 0..2 {
+                for i in 0..2 {
+                    assert_relative_eq!(r[(i, j)], rot[(i, j)], epsilon = 1e-10);
                 }
             }
         }
     }
     //}}}
-    //{{{ collection: solve
+    //{{{ collection: norm tests
     #[test]
-    fn test_solve() {
+    fn test_norms_elementwise() {
+        let a = DMatrix::<f64>::from_row_slice(&[1.0, -2.0, 0.0, 3.0, 4.0, -5.0], 2, 3);
+        assert_relative_eq!(a.norm_fro(), 55.0f64.sqrt(), epsilon = 1e-12);
+        assert_relative_eq!(a.norm_one(), 6.0, epsilon = 1e-12);
+        assert_relative_eq!(a.norm_inf(), 12.0, epsilon = 1e-12);
+        assert_relative_eq!(a.norm_max(), 5.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_view_norms() {
         let a =
-            DMatrix::<f64>::from_row_slice(&[3.0, -1.0, 2.0, 1.0, 2.0, 0.0, 4.0, 0.0, 6.0], 3, 3);
+            DMatrix::<f64>::from_row_slice(&[9.0, 9.0, 9.0, 9.0, 1.0, -2.0, 9.0, 3.0, 4.0], 3, 3);
+        let view = a.subview_range(1, 2, 1, 2);
+        assert_relative_eq!(view.norm_fro(), 30.0f64.sqrt(), epsilon = 1e-12);
+        assert_relative_eq!(view.norm_one(), 6.0, epsilon = 1e-12);
+        assert_relative_eq!(view.norm_inf(), 7.0, epsilon = 1e-12);
+        assert_relative_eq!(view.norm_max(), 4.0, epsilon = 1e-12);
+        assert_relative_eq!(view.rcond().unwrap(), 1.0 / 4.2, epsilon = 1e-10);
+    }
 
-        let b = DMatrix::<f64>::from_row_slice(
-            &[7.0, -7.0, 2.0, 1.0, 2.0, 3.0, 22.0, -10.0, 3.0],
-            3,
-            3,
+    #[test]
+    fn test_norm_2_rectangular() {
+        // Orthogonal columns with norms 3 and 2.
+        let a = DMatrix::<f64>::from_row_slice(&[3.0, 0.0, 0.0, 2.0, 0.0, 0.0], 3, 2);
+        assert_relative_eq!(a.norm_2().unwrap(), 3.0, epsilon = 1e-10);
+        assert_relative_eq!(a.cond().unwrap(), 1.5, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_rcond_singular() {
+        let a = DMatrix::<f64>::from_row_slice(&[1.0, 2.0, 2.0, 4.0], 2, 2);
+        assert_eq!(a.rcond().unwrap(), 0.0);
+    }
+
+    #[test]
+    fn test_rcond_empty() {
+        let a = DMatrix::<f64>::zeros(0, 0);
+        assert_eq!(a.rcond().unwrap(), 1.0);
+    }
+
+    #[test]
+    fn test_cond_empty() {
+        let a = DMatrix::<f64>::zeros(0, 0);
+        assert_eq!(a.norm_2().unwrap(), 0.0);
+        assert_eq!(a.cond().unwrap(), 1.0);
+    }
+
+    #[test]
+    fn test_lu_factor_rcond() {
+        let a = DMatrix::<f64>::from_row_slice(&[1.0, -2.0, 3.0, 4.0], 2, 2);
+        let lu = a.lu_factor().unwrap();
+        assert_relative_eq!(lu.rcond().unwrap(), a.rcond().unwrap(), epsilon = 1e-14);
+        assert_relative_eq!(lu.rcond().unwrap(), 1.0 / 4.2, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_norm_fro_extreme_scales() {
+        let big = DMatrix::<f64>::from_row_slice(&[3e300, 4e300], 1, 2);
+        assert_relative_eq!(big.norm_fro(), 5e300, max_relative = 1e-12);
+        let small = DMatrix::<f64>::from_row_slice(&[3e-300, 4e-300], 1, 2);
+        assert_relative_eq!(small.norm_fro(), 5e-300, max_relative = 1e-12);
+        assert_eq!(DMatrix::<f64>::zeros(2, 2).norm_fro(), 0.0);
+    }
+
+    #[test]
+    fn test_complex_norm_2_and_cond() {
+        // Columns are orthogonal with norms 3 and 2.
+        let a = DMatrix::<Complex<f64>>::from_row_slice(
+            &[
+                Complex::new(0.0, 3.0),
+                Complex::new(0.0, 0.0),
+                Complex::new(0.0, 0.0),
+                Complex::new(1.2, -1.6),
+            ],
+            2,
+            2,
+        );
+        assert_relative_eq!(a.norm_2().unwrap(), 3.0, epsilon = 1e-10);
+        assert_relative_eq!(a.cond().unwrap(), 1.5, epsilon = 1e-10);
+    }
+    //}}}
+    //{{{ collection: complex tests
+    #[test]
+    fn test_complex_lu() {
+        let a = DMatrix::<Complex<f64>>::from_row_slice(
+            &[
+                Complex::new(4.0, 0.0),
+                Complex::new(1.0, 1.0),
+                Complex::new(1.0, -1.0),
+                Complex::new(3.0, 0.0),
+            ],
+            2,
+            2,
         );
 
-        let x = a.solve(&b).unwrap();
+        let DLuReturn { p, l, u, .. } = a.lu().unwrap();
 
-        // Verify A * X = B
-        let computed_b = a.matmul(&x);
+        // |a00| dominates the first column so no rows are swapped
+        let exp_l = DMatrix::<Complex<f64>>::from_row_slice(
+            &[
+                Complex::new(1.0, 0.0),
+                Complex::new(0.0, 0.0),
+                Complex::new(0.25, -0.25),
+                Complex::new(1.0, 0.0),
+            ],
+            2,
+            2,
+        );
+        let exp_u = DMatrix::<Complex<f64>>::from_row_slice(
+            &[
+                Complex::new(4.0, 0.0),
+                Complex::new(1.0, 1.0),
+                Complex::new(0.0, 0.0),
+                Complex::new(2.5, 0.0),
+            ],
+            2,
+            2,
+        );
+        let exp_p = DMatrix::<Complex<f64>>::identity(2, 2);
+        for k in 0..4 {
+            assert_relative_eq!(p[k].re, exp_p[k].re, epsilon = 1e-12);
+            assert_relative_eq!(l[k].re, exp_l[k].re, epsilon = 1e-12);
+            assert_relative_eq!(l[k].im, exp_l[k].im, epsilon = 1e-12);
+            assert_relative_eq!(u[k].re, exp_u[k].re, epsilon = 1e-12);
+            assert_relative_eq!(u[k].im, exp_u[k].im, epsilon = 1e-12);
+        }
+    }
 
-        for i in 0..9 {
-            assert_relative_eq!(computed_b[i], b[i], max_relative = 1.0e-8);
+    #[test]
+    fn test_complex_qr() {
+        let a = DMatrix::<Complex<f64>>::from_row_slice(
+            &[
+                Complex::new(1.0, 1.0),
+                Complex::new(2.0, 0.0),
+                Complex::new(0.0, -1.0),
+                Complex::new(3.0, 2.0),
+            ],
+            2,
+            2,
+        );
+
+        let DQrReturn { q, r } = a.qr().unwrap();
+
+        // Verify Q*R = A
+        let reconstructed = q.matmul(&r);
+        for k in 0..4 {
+            assert_relative_eq!(reconstructed[k].re, a[k].re, epsilon = 1e-12);
+            assert_relative_eq!(reconstructed[k].im, a[k].im, epsilon = 1e-12);
         }
+
+        // Verify R is upper-triangular
+        assert_relative_eq!(r[(1, 0)].norm(), 0.0, epsilon = 1e-12);
     }
-    //}}}
-    //{{{ collection: symeig tests
+
     #[test]
-    fn test_symeig_simple() {
-        let a =
-            DMatrix::<f64>::from_col_slice(&[1.0, 2.0, 3.0, 2.0, 4.0, 5.0, 3.0, 5.0, 6.0], 3, 3);
+    fn test_complex_eig() {
+        let a = DMatrix::<Complex<f64>>::from_row_slice(
+            &[
+                Complex::new(1.0, 0.0),
+                Complex::new(2.0, 0.0),
+                Complex::new(0.0, 0.0),
+                Complex::new(0.0, 1.0),
+            ],
+            2,
+            2,
+        );
 
-        let eig = a.symeig().unwrap();
-        let expected_eigenvalues = [
-            -5.15729471589257e-01,
-            1.70915188827179e-01,
-            1.13448142827621e+01,
-        ];
+        let DEigReturn { eigvals, .. } = a.eig().unwrap();
 
-        for (val, exp) in eig.eigvals.iter().zip(expected_eigenvalues.iter()) {
-            assert_relative_eq!(val, exp, epsilon = 1e-10);
+        // Eigenvalues of a triangular matrix are its diagonal entries
+        for expected in [Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)] {
+            assert!(eigvals.iter().any(|val| (val - expected).norm() < 1e-10));
         }
+    }
 
-        let expected_eigenvectors = DMatrix::<f64>::from_row_slice(
+    #[test]
+    fn test_complex_symeig_hermitian() {
+        let a = DMatrix::<Complex<f64>>::from_row_slice(
             &[
-                -7.36976229099578e-01,
-                5.91009048506104e-01,
-                -3.27985277605682e-01,
-                -3.27985277605682e-01,
-                -7.36976229099578e-01,
-                -5.91009048506103e-01,
-                5.91009048506104e-01,
-                3.27985277605681e-01,
-                -7.36976229099578e-01,
+                Complex::new(2.0, 0.0),
+                Complex::new(1.0, -1.0),
+                Complex::new(1.0, 1.0),
+                Complex::new(3.0, 0.0),
             ],
-            3,
-            3,
+            2,
+            2,
         );
 
-        for (val, exp) in eig.eigvecs.iter().zip(expected_eigenvectors.iter()) {
-            assert_relative_eq!(val, exp, epsilon = 1e-10);
+        let DSymEigReturn { eigvecs, eigvals } = a.symeig().unwrap();
+
+        assert_relative_eq!(eigvals[0], 1.0, epsilon = 1e-10);
+        assert_relative_eq!(eigvals[1], 4.0, epsilon = 1e-10);
+
+        // Verify A v = λ v for each eigenpair
+        let av = a.matmul(&eigvecs);
+        for j in 0..2 {
+            for i in 0..2 {
+                let lambda_v = eigvecs[(i, j)] * eigvals[j];
+                assert_relative_eq!(av[(i, j)].re, lambda_v.re, epsilon = 1e-10);
+                assert_relative_eq!(av[(i, j)].im, lambda_v.im, epsilon = 1e-10);
+            }
         }
     }
     //}}}
@@ -112,6 +112,120 @@ mod dmatrix_tests {
 
         assert_rectangular_view_reductions(&view);
     }
+
+    fn assert_along<T>(
+        result: &DMatrix<T>,
+        size: (usize, usize),
+        expected: &[T],
+    ) where
+        T: Copy + PartialEq + std::fmt::Debug,
+    {
+        assert_eq!(result.size(), size);
+        assert_eq!(result.iter().copied().collect::<Vec<T>>(), expected);
+    }
+
+    fn assert_rectangular_view_axis_reductions<R>(view: &R)
+    where
+        R: AxisReduceOps<Item = i32>,
+    {
+        assert_along(&view.sum_along(Dimension::Rows), (3, 1), &[13, 21, 29]);
+        assert_along(&view.sum_along(Dimension::Cols), (1, 2), &[30, 33]);
+        assert_along(&view.sum_along(Dimension::All), (1, 1), &[63]);
+        assert_along(
+            &view.min_along(Dimension::Rows).unwrap(),
+            (3, 1),
+            &[6, 10, 14],
+        );
+        assert_along(
+            &view.argmax_along(Dimension::Cols).unwrap(),
+            (1, 2),
+            &[2, 2],
+        );
+    }
+
+    #[test]
+    fn test_sum_and_product_along() {
+        let a = DMatrix::<i32>::from_row_slice(&[3, -2, 5, 4, -7, 1], 2, 3);
+
+        assert_along(&a.sum_along(Dimension::Rows), (2, 1), &[6, -2]);
+        assert_along(&a.sum_along(Dimension::Cols), (1, 3), &[7, -9, 6]);
+        assert_along(&a.sum_along(Dimension::All), (1, 1), &[4]);
+        assert_along(&a.product_along(Dimension::Rows), (2, 1), &[-30, -28]);
+        assert_along(&a.product_along(Dimension::Cols), (1, 3), &[12, 14, 5]);
+    }
+
+    #[test]
+    fn test_min_max_and_arg_reductions_along() {
+        let a = DMatrix::<i32>::from_row_slice(&[3, -2, 5, 4, -7, 1], 2, 3);
+
+        assert_along(&a.min_along(Dimension::Rows).unwrap(), (2, 1), &[-2, -7]);
+        assert_along(&a.max_along(Dimension::Cols).unwrap(), (1, 3), &[4, -2, 5]);
+        assert_along(&a.max_along(Dimension::All).unwrap(), (1, 1), &[5]);
+        assert_along(&a.argmin_along(Dimension::Rows).unwrap(), (2, 1), &[1, 1]);
+        assert_along(
+            &a.argmax_along(Dimension::Cols).unwrap(),
+            (1, 3),
+            &[1, 0, 0],
+        );
+        assert_along(&a.argmin_along(Dimension::All).unwrap(), (1, 1), &[3]);
+    }
+
+    #[test]
+    fn test_mean_and_fold_along() {
+        let a = DMatrix::<f64>::from_row_slice(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 2, 3);
+
+        assert_along(&a.mean_along(Dimension::Rows).unwrap(), (2, 1), &[2.0, 5.0]);
+        assert_along(
+            &a.mean_along(Dimension::Cols).unwrap(),
+            (1, 3),
+            &[2.5, 3.5, 4.5],
+        );
+
+        let counts = a.fold_along(Dimension::Cols, 0, |acc, value| {
+            acc + (value > 2.0) as usize
+        });
+        assert_along(&counts, (1, 3), &[1, 1, 2]);
+        let weighted = a.fold_indexed_along(Dimension::Rows, 0.0, |acc, col, value| {
+            acc + col as f64 * value
+        });
+        assert_along(&weighted, (2, 1), &[8.0, 17.0]);
+    }
+
+    #[test]
+    fn test_empty_axis_reductions_along() {
+        let a = DMatrix::<i32>::zeros(0, 3);
+
+        assert_along(&a.sum_along(Dimension::Cols), (1, 3), &[0, 0, 0]);
+        assert_along(&a.sum_along(Dimension::Rows), (0, 1), &[]);
+        assert!(a.min_along(Dimension::Cols).is_none());
+        assert!(a.argmax_along(Dimension::Cols).is_none());
+        assert!(a.mean_along(Dimension::All).is_none());
+        assert_along(&a.max_along(Dimension::Rows).unwrap(), (0, 1), &[]);
+    }
+
+    #[test]
+    fn test_immutable_subview_reductions_along() {
+        let matrix = DMatrix::<i32>::from_row_slice(
+            &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16],
+            4,
+            4,
+        );
+        let view = matrix.subview_range(1, 3, 1, 2);
+
+        assert_rectangular_view_axis_reductions(&view);
+    }
+
+    #[test]
+    fn test_mutable_subview_reductions_along() {
+        let mut matrix = DMatrix::<i32>::from_row_slice(
+            &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16],
+            4,
+            4,
+        );
+        let view = matrix.subview_range_mut(1, 3, 1, 2);
+
+        assert_rectangular_view_axis_reductions(&view);
+    }
 }
 
 mod smatrix_tests {
@@ -224,4 +338,191 @@ mod smatrix_tests {
 
         assert_rectangular_view_reductions(&view);
     }
+
+    fn assert_along<T>(
+        result: &DMatrix<T>,
+        size: (usize, usize),
+        expected: &[T],
+    ) where
+        T: Copy + PartialEq + std::fmt::Debug,
+    {
+        assert_eq!(result.size(), size);
+        assert_eq!(result.iter().copied().collect::<Vec<T>>(), expected);
+    }
+
+    fn assert_rectangular_view_axis_reductions<R>(view: &R)
+    where
+        R: AxisReduceOps<Item = i32>,
+    {
+        assert_along(&view.sum_along(Dimension::Rows), (3, 1), &[13, 21, 29]);
+        assert_along(&view.sum_along(Dimension::Cols), (1, 2), &[30, 33]);
+        assert_along(&view.sum_along(Dimension::All), (1, 1), &[63]);
+        assert_along(
+            &view.min_along(Dimension::Rows).unwrap(),
+            (3, 1),
+            &[6, 10, 14],
+        );
+        assert_along(
+            &view.argmax_along(Dimension::Cols).unwrap(),
+            (1, 2),
+            &[2, 2],
+        );
+    }
+
+    #[test]
+    fn test_sum_and_product_along() {
+        let a = SMatrix::<i32, 2, 3>::from_row_slice(&[3, -2, 5, 4, -7, 1]);
+
+        assert_along(&a.sum_along(Dimension::Rows), (2, 1), &[6, -2]);
+        assert_along(&a.sum_along(Dimension::Cols), (1, 3), &[7, -9, 6]);
+        assert_along(&a.sum_along(Dimension::All), (1, 1), &[4]);
+        assert_along(&a.product_along(Dimension::Rows), (2, 1), &[-30, -28]);
+        assert_along(&a.product_along(Dimension::Cols), (1, 3), &[12, 14, 5]);
+    }
+
+    #[test]
+    fn test_min_max_and_arg_reductions_along() {
+        let a = SMatrix::<i32, 2, 3>::from_row_slice(&[3, -2, 5, 4, -7, 1]);
+
+        assert_along(&a.min_along(Dimension::Rows).unwrap(), (2, 1), &[-2, -7]);
+        assert_along(&a.max_along(Dimension::Cols).unwrap(), (1, 3), &[4, -2, 5]);
+        assert_along(&a.max_along(Dimension::All).unwrap(), (1, 1), &[5]);
+        assert_along(&a.argmin_along(Dimension::Rows).unwrap(), (2, 1), &[1, 1]);
+        assert_along(
+            &a.argmax_along(Dimension::Cols).unwrap(),
+            (1, 3),
+            &[1, 0, 0],
+        );
+        assert_along(&a.argmin_along(Dimension::All).unwrap(), (1, 1), &[3]);
+    }
+
+    #[test]
+    fn test_mean_and_fold_along() {
+        let a = SMatrix::<f64, 2, 3>::from_row_slice(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+        assert_along(&a.mean_along(Dimension::Rows).unwrap(), (2, 1), &[2.0, 5.0]);
+        assert_along(
+            &a.mean_along(Dimension::Cols).unwrap(),
+            (1, 3),
+            &[2.5, 3.5, 4.5],
+        );
+
+        let counts = a.fold_along(Dimension::Cols, 0, |acc, value| {
+            acc + (value > 2.0) as usize
+        });
+        assert_along(&counts, (1, 3), &[1, 1, 2]);
+        let weighted = a.fold_indexed_along(Dimension::Rows, 0.0, |acc, col, value| {
+            acc + col as f64 * value
+        });
+        assert_along(&weighted, (2, 1), &[8.0, 17.0]);
+    }
+
+    #[test]
+    fn test_empty_axis_reductions_along() {
+        let a = SMatrix::<i32, 0, 3>::zeros();
+
+        assert_along(&a.sum_along(Dimension::Cols), (1, 3), &[0, 0, 0]);
+        assert_along(&a.sum_along(Dimension::Rows), (0, 1), &[]);
+        assert!(a.min_along(Dimension::Cols).is_none());
+        assert!(a.argmax_along(Dimension::Cols).is_none());
+        assert!(a.mean_along(Dimension::All).is_none());
+        assert_along(&a.max_along(Dimension::Rows).unwrap(), (0, 1), &[]);
+    }
+
+    fn assert_lanes<T, const R: usize, const C: usize>(
+        result: &SMatrix<T, R, C>,
+        expected: &[T],
+    ) where
+        T: Copy + PartialEq + std::fmt::Debug,
+    {
+        assert_eq!(result.iter().copied().collect::<Vec<T>>(), expected);
+    }
+
+    #[test]
+    fn test_typed_sum_and_product_rows_cols() {
+        let a = SMatrix::<i32, 2, 3>::from_row_slice(&[3, -2, 5, 4, -7, 1]);
+
+        let row_sums: SMatrix<i32, 2, 1> = a.sum_rows();
+        let col_sums: SMatrix<i32, 1, 3> = a.sum_cols();
+        let row_products: SMatrix<i32, 2, 1> = a.product_rows();
+        let col_products: SMatrix<i32, 1, 3> = a.product_cols();
+
+        assert_lanes(&row_sums, &[6, -2]);
+        assert_lanes(&col_sums, &[7, -9, 6]);
+        assert_lanes(&row_products, &[-30, -28]);
+        assert_lanes(&col_products, &[12, 14, 5]);
+    }
+
+    #[test]
+    fn test_typed_min_max_and_arg_reductions_rows_cols() {
+        let a = SMatrix::<i32, 2, 3>::from_row_slice(&[3, -2, 5, 4, -7, 1]);
+
+        let row_mins: SMatrix<i32, 2, 1> = a.min_rows().unwrap();
+        let col_maxs: SMatrix<i32, 1, 3> = a.max_cols().unwrap();
+        let row_argmins: SMatrix<usize, 2, 1> = a.argmin_rows().unwrap();
+        let col_argmaxs: SMatrix<usize, 1, 3> = a.argmax_cols().unwrap();
+
+        assert_lanes(&row_mins, &[-2, -7]);
+        assert_lanes(&a.max_rows().unwrap(), &[5, 4]);
+        assert_lanes(&a.min_cols().unwrap(), &[3, -7, 1]);
+        assert_lanes(&col_maxs, &[4, -2, 5]);
+        assert_lanes(&row_argmins, &[1, 1]);
+        assert_lanes(&a.argmax_rows().unwrap(), &[2, 0]);
+        assert_lanes(&a.argmin_cols().unwrap(), &[0, 1, 1]);
+        assert_lanes(&col_argmaxs, &[1, 0, 0]);
+    }
+
+    #[test]
+    fn test_typed_mean_and_fold_rows_cols() {
+        let a = SMatrix::<f64, 2, 3>::from_row_slice(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+        let row_means: SMatrix<f64, 2, 1> = a.mean_rows().unwrap();
+        let col_means: SMatrix<f64, 1, 3> = a.mean_cols().unwrap();
+        assert_lanes(&row_means, &[2.0, 5.0]);
+        assert_lanes(&col_means, &[2.5, 3.5, 4.5]);
+
+        let counts: SMatrix<usize, 1, 3> =
+            a.fold_cols(0, |acc, value| acc + (value > 2.0) as usize);
+        assert_lanes(&counts, &[1, 1, 2]);
+        let weighted: SMatrix<f64, 2, 1> =
+            a.fold_indexed_rows(0.0, |acc, col, value| acc + col as f64 * value);
+        assert_lanes(&weighted, &[8.0, 17.0]);
+        let weighted: SMatrix<f64, 1, 3> =
+            a.fold_indexed_cols(0.0, |acc, row, value| acc + row as f64 * value);
+        assert_lanes(&weighted, &[4.0, 5.0, 6.0]);
+    }
+
+    #[test]
+    fn test_typed_empty_axis_reductions() {
+        let a = SMatrix::<i32, 0, 3>::zeros();
+
+        let col_sums: SMatrix<i32, 1, 3> = a.sum_cols();
+        let row_sums: SMatrix<i32, 0, 1> = a.sum_rows();
+        assert_lanes(&col_sums, &[0, 0, 0]);
+        assert_lanes(&row_sums, &[]);
+        assert!(a.min_cols().is_none());
+        assert!(a.argmax_cols().is_none());
+        assert!(a.mean_cols().is_none());
+        assert_lanes(&a.max_rows().unwrap(), &[]);
+    }
+
+    #[test]
+    fn test_immutable_subview_reductions_along() {
+        let matrix = SMatrix::<i32, 4, 4>::from_row_slice(&[
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
+        ]);
+        let view = matrix.subview_range(1, 3, 1, 2);
+
+        assert_rectangular_view_axis_reductions(&view);
+    }
+
+    #[test]
+    fn test_mutable_subview_reductions_along() {
+        let mut matrix = SMatrix::<i32, 4, 4>::from_row_slice(&[
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
+        ]);
+        let view = matrix.subview_range_mut(1, 3, 1, 2);
+
+        assert_rectangular_view_axis_reductions(&view);
+    }
 }
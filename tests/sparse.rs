@@ -0,0 +1,288 @@
+//{{{ mod: sparse_format_tests
+mod sparse_format_tests {
+
+    use topohedral_linalg::*;
+
+    /// The 3×4 matrix
+    /// ```text
+    /// 1 0 2 0
+    /// 0 0 0 0
+    /// 0 3 0 4
+    /// ```
+    fn example() -> DMatrix<f64> {
+        DMatrix::<f64>::from_row_slice(
+            &[
+                1.0, 0.0, 2.0, 0.0, //
+                0.0, 0.0, 0.0, 0.0, //
+                0.0, 3.0, 0.0, 4.0,
+            ],
+            3,
+            4,
+        )
+    }
+
+    #[test]
+    fn test_coo_sums_duplicates() {
+        let mut coo = CooMatrix::<f64>::new(3, 4);
+        coo.push(2, 3, 1.5);
+        coo.push(0, 2, 2.0);
+        coo.push(2, 1, 3.0);
+        coo.push(0, 0, 1.0);
+        coo.push(2, 3, 2.5);
+        assert_eq!(coo.nnz(), 5);
+
+        let csr = coo.to_csr();
+        assert_eq!(csr.nnz(), 4);
+        assert_eq!(csr.row_offsets(), &[0, 2, 2, 4]);
+        assert_eq!(csr.col_indices(), &[0, 2, 1, 3]);
+        assert_eq!(csr.values(), &[1.0, 2.0, 3.0, 4.0]);
+
+        let csc = coo.to_csc();
+        assert_eq!(csc.col_offsets(), &[0, 1, 2, 3, 4]);
+        assert_eq!(csc.row_indices(), &[0, 2, 0, 2]);
+        assert_eq!(csc.values(), &[1.0, 3.0, 2.0, 4.0]);
+
+        assert!(csr.to_dmatrix().iter().eq(example().iter()));
+        assert!(csc.to_dmatrix().iter().eq(example().iter()));
+    }
+
+    #[test]
+    #[should_panic(expected = "Index out of bounds")]
+    fn test_coo_push_out_of_bounds() {
+        let mut coo = CooMatrix::<f64>::new(2, 2);
+        coo.push(2, 0, 1.0);
+    }
+
+    #[test]
+    fn test_dense_roundtrip_and_conversion() {
+        let a = example();
+        let csr = CsrMatrix::from_dmatrix(&a);
+        let csc = CscMatrix::from_dmatrix(&a);
+        assert_eq!(csr.nnz(), 4);
+        assert_eq!(csc.nnz(), 4);
+        assert_eq!(csr.size(), (3, 4));
+        assert_eq!(csc.size(), (3, 4));
+
+        assert_eq!(csr.to_csc().col_offsets(), csc.col_offsets());
+        assert_eq!(csr.to_csc().row_indices(), csc.row_indices());
+        assert_eq!(csc.to_csr().col_indices(), csr.col_indices());
+        assert_eq!(csc.to_csr().values(), csr.values());
+
+        for i in 0..3 {
+            for j in 0..4 {
+                assert_eq!(csr[(i, j)], a[(i, j)]);
+                assert_eq!(csc.get(i, j), a[(i, j)]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_transpose() {
+        let a = example();
+        let at = a.transpose();
+        let csr_t = CsrMatrix::from_dmatrix(&a).transpose();
+        let csc_t = CscMatrix::from_dmatrix(&a).transpose();
+        assert_eq!(csr_t.size(), (4, 3));
+        assert_eq!(csc_t.size(), (4, 3));
+        assert!(csr_t.to_dmatrix().iter().eq(at.iter()));
+        assert!(csc_t.to_dmatrix().iter().eq(at.iter()));
+    }
+}
+//}}}
+//{{{ mod: sparse_arithmetic_tests
+mod sparse_arithmetic_tests {
+
+    use approx::assert_relative_eq;
+    use topohedral_linalg::*;
+
+    fn random_sparse(
+        nrows: usize,
+        ncols: usize,
+        seed: usize,
+    ) -> DMatrix<f64> {
+        let mut a = DMatrix::<f64>::zeros(nrows, ncols);
+        for j in 0..ncols {
+            for i in 0..nrows {
+                let k = (i * 7 + j * 13 + seed) % 5;
+                if k < 2 {
+                    a[(i, j)] = (k + 1) as f64 * ((i + 2 * j + seed) as f64).sin();
+                }
+            }
+        }
+        a
+    }
+
+    #[test]
+    fn test_add_sub_scale() {
+        let a = random_sparse(5, 6, 0);
+        let b = random_sparse(5, 6, 3);
+        let (csr_a, csr_b) = (CsrMatrix::from_dmatrix(&a), CsrMatrix::from_dmatrix(&b));
+        let (csc_a, csc_b) = (CscMatrix::from_dmatrix(&a), CscMatrix::from_dmatrix(&b));
+
+        let sum = (&csr_a + &csr_b).to_dmatrix();
+        let diff = (&csc_a - &csc_b).to_dmatrix();
+        let scaled = (&csr_a * 2.5).to_dmatrix();
+        for j in 0..6 {
+            for i in 0..5 {
+                assert_relative_eq!(sum[(i, j)], a[(i, j)] + b[(i, j)], epsilon = 1e-14);
+                assert_relative_eq!(diff[(i, j)], a[(i, j)] - b[(i, j)], epsilon = 1e-14);
+                assert_relative_eq!(scaled[(i, j)], 2.5 * a[(i, j)], epsilon = 1e-14);
+            }
+        }
+    }
+
+    #[test]
+    fn test_cancellation_is_not_stored() {
+        let a = random_sparse(4, 4, 1);
+        let csr = CsrMatrix::from_dmatrix(&a);
+        let zero = &csr - &csr;
+        assert_eq!(zero.nnz(), 0);
+        assert_eq!(zero.row_offsets(), &[0; 5]);
+        // Scaling keeps the pattern, even by zero
+        assert_eq!((csr.clone() * 0.0).nnz(), csr.nnz());
+    }
+
+    #[test]
+    #[should_panic(expected = "Matrix dimensions must match")]
+    fn test_add_shape_mismatch() {
+        let a = CsrMatrix::<f64>::zeros(2, 3);
+        let b = CsrMatrix::<f64>::zeros(3, 2);
+        let _ = &a + &b;
+    }
+
+    #[test]
+    fn test_matmul_with_dense() {
+        let a = random_sparse(5, 4, 2);
+        let b = random_sparse(4, 3, 5);
+        let c = random_sparse(3, 5, 7);
+        let expected_ab = (&a).matmul(&b);
+        let expected_ca = (&c).matmul(&a);
+
+        let csr = CsrMatrix::from_dmatrix(&a);
+        let csc = CscMatrix::from_dmatrix(&a);
+        for (left, right) in [
+            ((&csr).matmul(&b), (&c).matmul(&csr)),
+            ((&csc).matmul(&b), (&c).matmul(&csc)),
+        ] {
+            assert_eq!(left.size(), (5, 3));
+            assert_eq!(right.size(), (3, 4));
+            for (x, y) in left.iter().zip(expected_ab.iter()) {
+                assert_relative_eq!(x, y, epsilon = 1e-14);
+            }
+            for (x, y) in right.iter().zip(expected_ca.iter()) {
+                assert_relative_eq!(x, y, epsilon = 1e-14);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "incompatible for multiplication")]
+    fn test_matmul_shape_mismatch() {
+        let a = CscMatrix::<f64>::zeros(2, 3);
+        let b = DMatrix::<f64>::zeros(2, 2);
+        let _ = (&a).matmul(&b);
+    }
+
+    #[test]
+    fn test_linear_operator() {
+        // Symmetric positive definite 1D Laplacian assembled from element contributions
+        let n = 20;
+        let mut coo = CooMatrix::<f64>::with_capacity(n, n, 4 * n);
+        for e in 0..n - 1 {
+            coo.push(e, e, 1.0);
+            coo.push(e + 1, e + 1, 1.0);
+            coo.push(e, e + 1, -1.0);
+            coo.push(e + 1, e, -1.0);
+        }
+        coo.push(0, 0, 1.0);
+        coo.push(n - 1, n - 1, 1.0);
+        let csr = coo.to_csr();
+        let csc = coo.to_csc();
+        assert_eq!(csr.nnz(), 3 * n - 2);
+
+        let b = DMatrix::<f64>::from_col_slice(&vec![1.0; n], n, 1);
+        let options = IterativeOptions::default();
+        let x_csr = cg(&csr, &b, None, None, &options).unwrap().x;
+        let x_csc = cg(&csc, &b, None, None, &options).unwrap().x;
+        let r = (&csr).matmul(&x_csr);
+        for i in 0..n {
+            assert_relative_eq!(r[i], 1.0, epsilon = 1e-8);
+            assert_relative_eq!(x_csr[i], x_csc[i], epsilon = 1e-8);
+        }
+    }
+}
+//}}}
+//{{{ mod: sparse_view_tests
+mod sparse_view_tests {
+
+    use approx::assert_relative_eq;
+    use topohedral_linalg::*;
+
+    fn example() -> DMatrix<f64> {
+        DMatrix::<f64>::from_row_slice(
+            &[
+                1.0, 0.0, -2.0, 0.0, //
+                0.0, 0.0, 0.0, 5.0, //
+                0.0, 3.0, 0.0, 4.0,
+            ],
+            3,
+            4,
+        )
+    }
+
+    #[test]
+    fn test_reductions_match_dense() {
+        let a = example();
+        let csr = CsrMatrix::from_dmatrix(&a);
+        let csc = CscMatrix::from_dmatrix(&a);
+        assert_eq!(csr.sum(), a.sum());
+        assert_eq!(csc.sum(), a.sum());
+        assert_eq!(csr.argmax(), Some(((1, 3), 5.0)));
+        assert_eq!(csc.argmin(), Some(((0, 2), -2.0)));
+        assert_relative_eq!(csr.norm_fro(), a.norm_fro(), epsilon = 1e-14);
+
+        // Both formats visit entries, zeros included, in column-major order
+        let order: Vec<(usize, usize)> = csr.fold_indexed(Vec::new(), |mut acc, idx, _| {
+            acc.push(idx);
+            acc
+        });
+        let dense_order: Vec<(usize, usize)> = a.fold_indexed(Vec::new(), |mut acc, idx, _| {
+            acc.push(idx);
+            acc
+        });
+        assert_eq!(order, dense_order);
+        assert!(csc
+            .fold(Vec::new(), |mut acc, v| {
+                acc.push(v);
+                acc
+            })
+            .iter()
+            .eq(a.iter()));
+    }
+
+    #[test]
+    fn test_subviews() {
+        let a = example();
+        let csr = CsrMatrix::from_dmatrix(&a);
+        let csc = CscMatrix::from_dmatrix(&a);
+
+        let view = csr.subview_range(1, 2, 1, 3);
+        assert_eq!(view.size(), (2, 3));
+        assert_eq!(view[(1, 0)], 3.0);
+        assert_eq!(view[(0, 0)], 0.0);
+        assert_eq!(view.sum(), 12.0);
+
+        let col = csc.col(3);
+        assert_eq!(col.sum(), 9.0);
+        assert_eq!(col.argmax(), Some(((1, 0), 5.0)));
+        assert_eq!(csc.row(0).allmin(), Some(-2.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Index out of bounds")]
+    fn test_index_out_of_bounds() {
+        let csr = CsrMatrix::from_dmatrix(&example());
+        let _ = csr[(3, 0)];
+    }
+}
+//}}}